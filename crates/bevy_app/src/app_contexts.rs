@@ -36,12 +36,30 @@ pub enum CompanionPlacementKind {
     BucketStorage,
 }
 
+impl CompanionPlacementKind {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::BucketStorage => "ui.companion.bucket_storage",
+        }
+    }
+}
+
 /// companion 配置の親建物種別
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum CompanionParentKind {
     Tank,
 }
 
+impl CompanionParentKind {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Tank => "building.tank",
+        }
+    }
+}
+
 /// companion 配置中の状態
 #[derive(Debug, Clone, Reflect)]
 pub struct CompanionPlacement {
@@ -0,0 +1,164 @@
+//! セーブファイルのオフライン検査・修復ツール。ウィンドウを開かない。
+//!
+//! ```text
+//! cargo run -p bevy_app --bin hw_save_tool -- info <save>
+//! cargo run -p bevy_app --bin hw_save_tool -- check <save>
+//! cargo run -p bevy_app --bin hw_save_tool -- diff <before> <after>
+//! cargo run -p bevy_app --bin hw_save_tool -- extract <save> (--component|--resource) <type> [--output <json>]
+//! cargo run -p bevy_app --bin hw_save_tool -- salvage <save> [--output <save>]
+//! ```
+//!
+//! 終了コードは 0 = 問題なし、1 = 問題あり、2 = 引数か入出力の誤り。
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use bevy_app::systems::save::inspect::{ExtractTarget, SaveFile};
+
+const USAGE: &str = "usage: hw_save_tool <command> ...
+  info <save>                       print header, upgrade steps and entity/component/resource counts
+  check <save>                      run the loader's deserialize and schema validation
+  diff <before> <after>             compare resources and entities grouped by persisted root
+  extract <save> --component <type> print every entity carrying <type> as JSON
+  extract <save> --resource <type>  print matching resources as JSON
+      [--output <file>]             write the JSON to <file> instead of stdout
+  salvage <save> [--output <file>]  strip types outside the persisted schema and rootless
+                                    entities; without --output only report what would change
+<type> is a full type path or its last segment (e.g. Stockpile).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("hw_save_tool: {error}");
+            if error.usage {
+                eprintln!("{USAGE}");
+            }
+            ExitCode::from(2)
+        }
+    }
+}
+
+struct ToolError {
+    message: String,
+    usage: bool,
+}
+
+impl ToolError {
+    fn usage(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            usage: true,
+        }
+    }
+}
+
+impl std::fmt::Display for ToolError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+impl<E: std::error::Error> From<E> for ToolError {
+    fn from(error: E) -> Self {
+        Self {
+            message: error.to_string(),
+            usage: false,
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, ToolError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err(ToolError::usage("missing command"));
+    };
+    let (positional, output) = split_output_flag(rest)?;
+    match (command.as_str(), positional.as_slice()) {
+        ("info", [save]) => {
+            print!("{}", open(save)?.summary()?);
+            Ok(ExitCode::SUCCESS)
+        }
+        ("check", [save]) => match open(save)?.check() {
+            Ok(()) => {
+                println!("ok");
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(failure) => {
+                println!("{failure}");
+                Ok(ExitCode::FAILURE)
+            }
+        },
+        ("diff", [before, after]) => {
+            let diff = open(before)?.diff(&open(after)?)?;
+            print!("{diff}");
+            Ok(if diff.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        ("extract", [save, flag, query]) => {
+            let target = match flag.as_str() {
+                "--component" => ExtractTarget::Component(query.clone()),
+                "--resource" => ExtractTarget::Resource(query.clone()),
+                other => return Err(ToolError::usage(format!("unknown extract flag `{other}`"))),
+            };
+            let json = open(save)?.extract(&target)?;
+            match output {
+                Some(path) => std::fs::write(path, json + "\n")?,
+                None => println!("{json}"),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        ("salvage", [save]) => {
+            let report = open(save)?.salvage()?;
+            print!("{report}");
+            let Some(path) = output else {
+                return Ok(ExitCode::SUCCESS);
+            };
+            if Path::new(path) == Path::new(save) {
+                return Err(ToolError::usage(
+                    "salvage never overwrites its input; choose another --output",
+                ));
+            }
+            let salvaged = SaveFile::from_contents(report.contents.clone())?;
+            std::fs::write(path, &report.contents)?;
+            println!("wrote {path}");
+            match salvaged.check() {
+                Ok(()) => Ok(ExitCode::SUCCESS),
+                Err(failure) => {
+                    println!("salvaged file still fails to load: {failure}");
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+        }
+        (command, _) => Err(ToolError::usage(format!(
+            "unknown command or wrong arguments: `{command}`"
+        ))),
+    }
+}
+
+fn split_output_flag(args: &[String]) -> Result<(Vec<String>, Option<&str>), ToolError> {
+    let mut positional = Vec::new();
+    let mut output = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--output" {
+            let path = iter
+                .next()
+                .ok_or_else(|| ToolError::usage("--output needs a path"))?;
+            output = Some(path.as_str());
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+    Ok((positional, output))
+}
+
+fn open(path: &str) -> Result<SaveFile, ToolError> {
+    SaveFile::read(Path::new(path)).map_err(|error| ToolError {
+        message: format!("{path}: {error}"),
+        usage: false,
+    })
+}
@@ -42,7 +42,7 @@ fn select_soul_image<'a>(
         IdleBehavior::GoingToRest => &game_assets.soul,
         IdleBehavior::ExhaustedGathering => &game_assets.soul_exhausted,
         IdleBehavior::Escaping => &game_assets.soul,
-        IdleBehavior::Drifting => &game_assets.soul,
+        IdleBehavior::Drifting | IdleBehavior::Redeeming => &game_assets.soul,
         IdleBehavior::Gathering => match idle.gathering_behavior {
             GatheringBehavior::Sleeping if allow_sleep_visual => &game_assets.soul_sleep,
             GatheringBehavior::Sleeping
@@ -50,16 +50,20 @@ pub fn on_soul_recruited(
     let event = on.event();
     if let Ok((_soul, mut idle, mut path)) = q_souls.get_mut(soul_entity) {
         idle.total_idle_time = 0.0;
-        if idle.behavior == IdleBehavior::Drifting {
+        if matches!(
+            idle.behavior,
+            IdleBehavior::Drifting | IdleBehavior::Redeeming
+        ) {
             idle.behavior = IdleBehavior::Wandering;
             idle.idle_timer = 0.0;
             idle.behavior_duration = 3.0;
         }
         path.waypoints.clear();
         path.current_index = 0;
-        commands
-            .entity(soul_entity)
-            .remove::<crate::entities::damned_soul::DriftingState>();
+        commands.entity(soul_entity).remove::<(
+            crate::entities::damned_soul::DriftingState,
+            hw_core::redemption::RedemptionPilgrimage,
+        )>();
     }
     info!(
         "OBSERVER: Soul {:?} recruited by Familiar {:?}",
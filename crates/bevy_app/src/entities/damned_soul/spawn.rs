@@ -6,6 +6,8 @@ use crate::plugins::startup::{PerfScenarioConfig, PerfScenarioRandomStreams};
 use crate::systems::soul_ai::execute::task_execution::AssignedTask;
 use crate::world::map::{RIVER_X_MAX, RIVER_X_MIN, RIVER_Y_MIN, WorldMap, WorldMapRead};
 use hw_core::constants::*;
+use hw_core::edicts::DreamEdicts;
+use hw_core::redemption::SOUL_POPULATION_PER_REDEMPTION;
 #[cfg(feature = "profiling")]
 use hw_core::simulation_rng::SimulationRandomState;
 use hw_core::visual_mirror::logistics::InventoryItemVisual;
@@ -112,18 +114,20 @@ pub fn population_tracking_system(
 ) {
     population.current_count = q_souls.iter().count() as u32;
     population.population_cap = SOUL_POPULATION_BASE_CAP
-        + q_rest_areas.iter().count() as u32 * SOUL_POPULATION_PER_REST_AREA;
+        + q_rest_areas.iter().count() as u32 * SOUL_POPULATION_PER_REST_AREA
+        + population.total_redeemed * SOUL_POPULATION_PER_REDEMPTION;
     population.escape_cooldown_remaining =
         (population.escape_cooldown_remaining - time.delta_secs()).max(0.0);
 }
 
-/// 定期スポーン（人口上限と不足時ボーナスを考慮）
+/// 定期スポーン（人口上限・不足時ボーナス・勅令の追加数を考慮）
 pub fn periodic_spawn_system(
     time: Res<Time>,
     world_map: WorldMapRead,
     mut population: ResMut<PopulationManager>,
     mut spawn_events: MessageWriter<DamnedSoulSpawnEvent>,
     perf_config: Res<PerfScenarioConfig>,
+    edicts: Res<DreamEdicts>,
 ) {
     if perf_config.enabled() {
         return;
@@ -161,6 +165,7 @@ pub fn periodic_spawn_system(
     if current * 2 <= cap {
         spawn_count += 1;
     }
+    spawn_count += edicts.modifiers().spawn_count_bonus;
     spawn_count = spawn_count.min(cap.saturating_sub(current));
     if spawn_count == 0 {
         return;
@@ -22,6 +22,74 @@ pub(crate) enum InputBindingContext {
     DebugVisible,
 }
 
+impl InputBindingContext {
+    pub(crate) const ALL: [Self; 13] = [
+        Self::Global,
+        Self::WorldNormal,
+        Self::Familiar,
+        Self::Help,
+        Self::LoadConfirm,
+        Self::Settings,
+        Self::Pause,
+        Self::OperationDialog,
+        Self::ActiveMode,
+        Self::OpenMenu,
+        Self::AreaEdit,
+        Self::Debug,
+        Self::DebugVisible,
+    ];
+
+    /// UI 表示名の文言 ID
+    pub(crate) const fn text_id(self) -> &'static str {
+        match self {
+            Self::Global => "ui.input_context.global",
+            Self::WorldNormal => "ui.input_context.world_normal",
+            Self::Familiar => "ui.input_context.familiar",
+            Self::Help => "ui.input_context.help",
+            Self::LoadConfirm => "ui.input_context.load_confirm",
+            Self::Settings => "ui.input_context.settings",
+            Self::Pause => "ui.input_context.pause",
+            Self::OperationDialog => "ui.input_context.operation_dialog",
+            Self::ActiveMode => "ui.input_context.active_mode",
+            Self::OpenMenu => "ui.input_context.open_menu",
+            Self::AreaEdit => "ui.input_context.area_edit",
+            Self::Debug => "ui.input_context.debug",
+            Self::DebugVisible => "ui.input_context.debug_visible",
+        }
+    }
+
+    /// settings.ron のキーバインド上書きで使う安定 ID
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::WorldNormal => "world-normal",
+            Self::Familiar => "familiar",
+            Self::Help => "help",
+            Self::LoadConfirm => "load-confirm",
+            Self::Settings => "settings",
+            Self::Pause => "pause",
+            Self::OperationDialog => "operation-dialog",
+            Self::ActiveMode => "active-mode",
+            Self::OpenMenu => "open-menu",
+            Self::AreaEdit => "area-edit",
+            Self::Debug => "debug",
+            Self::DebugVisible => "debug-visible",
+        }
+    }
+
+    pub(crate) fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|context| context.as_str() == id)
+    }
+
+    /// 最前面オーバーレイ専用の文脈。オーバーレイ同士は同時に有効にならない
+    pub(crate) const fn is_overlay(self) -> bool {
+        matches!(
+            self,
+            Self::Help | Self::LoadConfirm | Self::Settings | Self::Pause | Self::OperationDialog
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct InputBinding {
     pub chord: InputChord,
@@ -353,6 +421,86 @@ pub(crate) const DEFAULT_BINDINGS: &[InputBinding] = &[
         InputConflictLane::SelectionOrMode,
         true,
     ),
+    modified_binding(
+        KeyCode::Digit1,
+        CTRL,
+        InputAction::CameraStoreBookmark1,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit2,
+        CTRL,
+        InputAction::CameraStoreBookmark2,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit3,
+        CTRL,
+        InputAction::CameraStoreBookmark3,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit4,
+        CTRL,
+        InputAction::CameraStoreBookmark4,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit1,
+        ALT,
+        InputAction::CameraJumpBookmark1,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit2,
+        ALT,
+        InputAction::CameraJumpBookmark2,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit3,
+        ALT,
+        InputAction::CameraJumpBookmark3,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    modified_binding(
+        KeyCode::Digit4,
+        ALT,
+        InputAction::CameraJumpBookmark4,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
+    binding(
+        KeyCode::KeyF,
+        InputAction::ToggleCameraFollow,
+        InputBindingContext::WorldNormal,
+        resolution(40, None, 0, 20),
+        InputConflictLane::ViewDebug,
+        false,
+    ),
     binding(
         KeyCode::KeyB,
         InputAction::ToggleArchitect,
@@ -593,8 +741,32 @@ pub(crate) const DEFAULT_BINDINGS: &[InputBinding] = &[
         InputConflictLane::SelectionOrMode,
         true,
     ),
+    binding(
+        KeyCode::KeyR,
+        InputAction::RotateLayout,
+        InputBindingContext::ActiveMode,
+        resolution(80, None, 0, 80),
+        InputConflictLane::SelectionOrMode,
+        true,
+    ),
 ];
 
+/// 同じ chord を共有すると resolver が一意に勝者を決められない組み合わせか。
+///
+/// 同一文脈での重複に加え、同じ conflict lane かつ同じ文脈優先度の
+/// ワールド文脈同士（例: `Global` と `WorldNormal`）も衝突として扱う。
+pub(crate) fn bindings_conflict(left: &InputBinding, right: &InputBinding) -> bool {
+    if left.chord != right.chord {
+        return false;
+    }
+    if left.context == right.context {
+        return true;
+    }
+    left.conflict_lane == right.conflict_lane
+        && left.context_priority == right.context_priority
+        && !(left.context.is_overlay() && right.context.is_overlay())
+}
+
 pub(crate) fn binding_matches_context(
     binding: &InputBinding,
     context: &InputContextSnapshot,
@@ -701,6 +873,15 @@ fn is_independent_view_debug_pair(left: InputAction, right: InputAction) -> bool
                 | InputAction::ToggleDebug
                 | InputAction::DebugSpawnSoul
                 | InputAction::DebugSpawnFamiliar
+                | InputAction::CameraStoreBookmark1
+                | InputAction::CameraStoreBookmark2
+                | InputAction::CameraStoreBookmark3
+                | InputAction::CameraStoreBookmark4
+                | InputAction::CameraJumpBookmark1
+                | InputAction::CameraJumpBookmark2
+                | InputAction::CameraJumpBookmark3
+                | InputAction::CameraJumpBookmark4
+                | InputAction::ToggleCameraFollow
         )
     };
 
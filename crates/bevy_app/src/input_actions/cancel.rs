@@ -100,6 +100,7 @@ impl ActiveModeCleanupParams<'_, '_> {
             TaskMode::WallPlace(Some(_)) => TaskMode::WallPlace(None),
             TaskMode::DreamPlanting(Some(_)) => TaskMode::DreamPlanting(None),
             TaskMode::StockpilePolicyEdit(Some(_)) => TaskMode::StockpilePolicyEdit(None),
+            TaskMode::LayoutExport(Some(_)) => TaskMode::LayoutExport(None),
             mode => mode,
         };
 
@@ -205,6 +206,7 @@ mod tests {
             .resource_mut::<AreaEditSession>()
             .active_drag = Some(AreaEditDrag {
             familiar_entity: familiar,
+            rect_index: 0,
             operation: AreaEditOperation::Move,
             original_area: original_area.clone(),
             original_destination: Vec2::ONE,
@@ -397,6 +399,10 @@ mod tests {
                 TaskMode::StockpilePolicyEdit(Some(point)),
                 TaskMode::StockpilePolicyEdit(None),
             ),
+            (
+                TaskMode::LayoutExport(Some(point)),
+                TaskMode::LayoutExport(None),
+            ),
         ];
 
         for (before, expected) in cases {
@@ -412,6 +418,7 @@ mod tests {
         for preserved in [
             TaskMode::SelectBuildTarget,
             TaskMode::SoulSpaPlace(Some(point)),
+            TaskMode::LayoutImport,
         ] {
             let mut app = cleanup_app(InputAction::CloseOpenMenu);
             app.add_systems(Update, rollback_gesture);
@@ -478,6 +485,7 @@ mod tests {
             .resource_mut::<AreaEditSession>()
             .active_drag = Some(AreaEditDrag {
             familiar_entity: familiar,
+            rect_index: 0,
             operation: AreaEditOperation::Move,
             original_area: original_area.clone(),
             original_destination: Vec2::ONE,
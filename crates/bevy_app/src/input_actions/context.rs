@@ -192,6 +192,7 @@ fn task_mode_has_in_progress_gesture(task_mode: TaskMode) -> bool {
             | TaskMode::DreamPlanting(Some(_))
             | TaskMode::StockpilePolicyEdit(Some(_))
             | TaskMode::SoulSpaPlace(Some(_))
+            | TaskMode::LayoutExport(Some(_))
     )
 }
 
@@ -2,8 +2,8 @@ use std::fmt;
 
 use bevy::prelude::KeyCode;
 
-use super::bindings::DEFAULT_BINDINGS;
-use super::{InputAction, InputChord};
+use super::bindings::InputBinding;
+use super::{InputAction, InputChord, InputModifiers};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct UnsupportedPublicKey(pub KeyCode);
@@ -15,13 +15,11 @@ impl fmt::Display for UnsupportedPublicKey {
 }
 
 pub(crate) fn binding_labels_for_action(
+    bindings: &[InputBinding],
     action: InputAction,
 ) -> Result<Vec<String>, UnsupportedPublicKey> {
     let mut labels = Vec::new();
-    for binding in DEFAULT_BINDINGS
-        .iter()
-        .filter(|binding| binding.action == action)
-    {
+    for binding in bindings.iter().filter(|binding| binding.action == action) {
         let label = format_input_chord(binding.chord)?;
         if !labels.contains(&label) {
             labels.push(label);
@@ -48,77 +46,108 @@ pub(crate) fn format_input_chord(chord: InputChord) -> Result<String, Unsupporte
     Ok(parts.join("+"))
 }
 
+/// プレイヤーに公開するキーと表示名。キーバインド編集で割り当て可能なキーもこの表に限る
+const PUBLIC_KEYS: &[(KeyCode, &str)] = &[
+    (KeyCode::KeyA, "A"),
+    (KeyCode::KeyB, "B"),
+    (KeyCode::KeyC, "C"),
+    (KeyCode::KeyD, "D"),
+    (KeyCode::KeyE, "E"),
+    (KeyCode::KeyF, "F"),
+    (KeyCode::KeyG, "G"),
+    (KeyCode::KeyH, "H"),
+    (KeyCode::KeyI, "I"),
+    (KeyCode::KeyJ, "J"),
+    (KeyCode::KeyK, "K"),
+    (KeyCode::KeyL, "L"),
+    (KeyCode::KeyM, "M"),
+    (KeyCode::KeyN, "N"),
+    (KeyCode::KeyO, "O"),
+    (KeyCode::KeyP, "P"),
+    (KeyCode::KeyQ, "Q"),
+    (KeyCode::KeyR, "R"),
+    (KeyCode::KeyS, "S"),
+    (KeyCode::KeyT, "T"),
+    (KeyCode::KeyU, "U"),
+    (KeyCode::KeyV, "V"),
+    (KeyCode::KeyW, "W"),
+    (KeyCode::KeyX, "X"),
+    (KeyCode::KeyY, "Y"),
+    (KeyCode::KeyZ, "Z"),
+    (KeyCode::Digit0, "0"),
+    (KeyCode::Digit1, "1"),
+    (KeyCode::Digit2, "2"),
+    (KeyCode::Digit3, "3"),
+    (KeyCode::Digit4, "4"),
+    (KeyCode::Digit5, "5"),
+    (KeyCode::Digit6, "6"),
+    (KeyCode::Digit7, "7"),
+    (KeyCode::Digit8, "8"),
+    (KeyCode::Digit9, "9"),
+    (KeyCode::F1, "F1"),
+    (KeyCode::F2, "F2"),
+    (KeyCode::F3, "F3"),
+    (KeyCode::F4, "F4"),
+    (KeyCode::F5, "F5"),
+    (KeyCode::F6, "F6"),
+    (KeyCode::F7, "F7"),
+    (KeyCode::F8, "F8"),
+    (KeyCode::F9, "F9"),
+    (KeyCode::F10, "F10"),
+    (KeyCode::F11, "F11"),
+    (KeyCode::F12, "F12"),
+    (KeyCode::Escape, "Esc"),
+    (KeyCode::Delete, "Delete"),
+    (KeyCode::Tab, "Tab"),
+    (KeyCode::Space, "Space"),
+    (KeyCode::ArrowUp, "↑"),
+    (KeyCode::ArrowDown, "↓"),
+    (KeyCode::ArrowLeft, "←"),
+    (KeyCode::ArrowRight, "→"),
+    (KeyCode::PageUp, "PageUp"),
+    (KeyCode::PageDown, "PageDown"),
+    (KeyCode::Home, "Home"),
+    (KeyCode::End, "End"),
+];
+
 fn key_label(key: KeyCode) -> Result<&'static str, UnsupportedPublicKey> {
-    let label = match key {
-        KeyCode::KeyA => "A",
-        KeyCode::KeyB => "B",
-        KeyCode::KeyC => "C",
-        KeyCode::KeyD => "D",
-        KeyCode::KeyE => "E",
-        KeyCode::KeyF => "F",
-        KeyCode::KeyG => "G",
-        KeyCode::KeyH => "H",
-        KeyCode::KeyI => "I",
-        KeyCode::KeyJ => "J",
-        KeyCode::KeyK => "K",
-        KeyCode::KeyL => "L",
-        KeyCode::KeyM => "M",
-        KeyCode::KeyN => "N",
-        KeyCode::KeyO => "O",
-        KeyCode::KeyP => "P",
-        KeyCode::KeyQ => "Q",
-        KeyCode::KeyR => "R",
-        KeyCode::KeyS => "S",
-        KeyCode::KeyT => "T",
-        KeyCode::KeyU => "U",
-        KeyCode::KeyV => "V",
-        KeyCode::KeyW => "W",
-        KeyCode::KeyX => "X",
-        KeyCode::KeyY => "Y",
-        KeyCode::KeyZ => "Z",
-        KeyCode::Digit0 => "0",
-        KeyCode::Digit1 => "1",
-        KeyCode::Digit2 => "2",
-        KeyCode::Digit3 => "3",
-        KeyCode::Digit4 => "4",
-        KeyCode::Digit5 => "5",
-        KeyCode::Digit6 => "6",
-        KeyCode::Digit7 => "7",
-        KeyCode::Digit8 => "8",
-        KeyCode::Digit9 => "9",
-        KeyCode::F1 => "F1",
-        KeyCode::F2 => "F2",
-        KeyCode::F3 => "F3",
-        KeyCode::F4 => "F4",
-        KeyCode::F5 => "F5",
-        KeyCode::F6 => "F6",
-        KeyCode::F7 => "F7",
-        KeyCode::F8 => "F8",
-        KeyCode::F9 => "F9",
-        KeyCode::F10 => "F10",
-        KeyCode::F11 => "F11",
-        KeyCode::F12 => "F12",
-        KeyCode::Escape => "Esc",
-        KeyCode::Delete => "Delete",
-        KeyCode::Tab => "Tab",
-        KeyCode::Space => "Space",
-        KeyCode::ArrowUp => "↑",
-        KeyCode::ArrowDown => "↓",
-        KeyCode::ArrowLeft => "←",
-        KeyCode::ArrowRight => "→",
-        KeyCode::PageUp => "PageUp",
-        KeyCode::PageDown => "PageDown",
-        KeyCode::Home => "Home",
-        KeyCode::End => "End",
-        _ => return Err(UnsupportedPublicKey(key)),
-    };
-    Ok(label)
+    PUBLIC_KEYS
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, label)| *label)
+        .ok_or(UnsupportedPublicKey(key))
+}
+
+/// `format_input_chord` の逆変換。settings.ron に保存されたキー表記を読む
+pub(crate) fn parse_input_chord(text: &str) -> Option<InputChord> {
+    let mut parts: Vec<&str> = text.split('+').collect();
+    // `+` 自体は公開キーに含まれないため、末尾要素が常にキー名になる
+    let key_part = parts.pop()?;
+    let key = PUBLIC_KEYS
+        .iter()
+        .find(|(_, label)| *label == key_part)
+        .map(|(key, _)| *key)?;
+    let mut modifiers = InputModifiers::default();
+    for part in parts {
+        let flag = match part {
+            "Ctrl" => &mut modifiers.ctrl,
+            "Alt" => &mut modifiers.alt,
+            "Shift" => &mut modifiers.shift,
+            "Super" => &mut modifiers.super_key,
+            _ => return None,
+        };
+        if *flag {
+            return None;
+        }
+        *flag = true;
+    }
+    Some(InputChord { key, modifiers })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_actions::InputModifiers;
+    use crate::input_actions::bindings::DEFAULT_BINDINGS;
 
     #[test]
     fn public_key_labels_use_stable_modifier_and_alias_format() {
@@ -150,12 +179,23 @@ mod tests {
     #[test]
     fn action_lookup_preserves_binding_alias_order() {
         assert_eq!(
-            binding_labels_for_action(InputAction::FamiliarChop),
+            binding_labels_for_action(DEFAULT_BINDINGS, InputAction::FamiliarChop),
             Ok(vec!["C".to_string(), "1".to_string()])
         );
         assert_eq!(
-            binding_labels_for_action(InputAction::AreaRedo),
+            binding_labels_for_action(DEFAULT_BINDINGS, InputAction::AreaRedo),
             Ok(vec!["Ctrl+Y".to_string(), "Ctrl+Shift+Z".to_string()])
         );
     }
+
+    #[test]
+    fn chord_labels_round_trip_through_parser() {
+        for binding in DEFAULT_BINDINGS {
+            let label = format_input_chord(binding.chord).expect("public default key");
+            assert_eq!(parse_input_chord(&label), Some(binding.chord), "{label}");
+        }
+        assert_eq!(parse_input_chord("Ctrl+Ctrl+A"), None);
+        assert_eq!(parse_input_chord("Hyper+A"), None);
+        assert_eq!(parse_input_chord("Insert"), None);
+    }
 }
@@ -4,6 +4,7 @@ mod capture;
 mod context;
 mod key_labels;
 mod model;
+mod rebinding;
 mod resolver;
 
 #[cfg(test)]
@@ -15,6 +16,7 @@ use hw_ui::UiIntent;
 
 use crate::systems::GameSystemSet;
 
+pub(crate) use bindings::InputBinding;
 pub(crate) use cancel::{ActiveModeCleanupParams, cancel_or_close_input_action_system};
 pub(crate) use capture::{
     ForegroundUiGate, PendingWorldInputCapture, request_capture_from_menu_buttons_system,
@@ -22,9 +24,13 @@ pub(crate) use capture::{
     rollback_in_progress_gesture_system, sync_world_input_capture_system,
 };
 pub use context::{InputContextSnapshot, InputOverlay};
-pub(crate) use key_labels::binding_labels_for_action;
+pub(crate) use key_labels::{binding_labels_for_action, format_input_chord};
 use model::InputConflictLane;
 pub use model::{InputAction, InputActionFamily, InputChord, InputModifiers};
+pub(crate) use rebinding::{
+    InputBindingTable, KeyRebindCapture, binding_row_label, key_rebind_capture_system,
+    sync_input_binding_table_system,
+};
 pub(crate) use resolver::resolve_input_frame_system;
 pub use resolver::{ResolvedInputFrame, resolve_input_chords};
 
@@ -157,6 +163,16 @@ fn ui_intent_for_action(action: InputAction) -> Option<UiIntent> {
         | InputAction::AreaLoadPreset2
         | InputAction::AreaLoadPreset3
         | InputAction::ListNext
-        | InputAction::ListPrevious => None,
+        | InputAction::ListPrevious
+        | InputAction::CameraStoreBookmark1
+        | InputAction::CameraStoreBookmark2
+        | InputAction::CameraStoreBookmark3
+        | InputAction::CameraStoreBookmark4
+        | InputAction::CameraJumpBookmark1
+        | InputAction::CameraJumpBookmark2
+        | InputAction::CameraJumpBookmark3
+        | InputAction::CameraJumpBookmark4
+        | InputAction::ToggleCameraFollow
+        | InputAction::RotateLayout => None,
     }
 }
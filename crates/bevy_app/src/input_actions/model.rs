@@ -52,6 +52,214 @@ pub enum InputAction {
     AreaLoadPreset3,
     ListNext,
     ListPrevious,
+    CameraStoreBookmark1,
+    CameraStoreBookmark2,
+    CameraStoreBookmark3,
+    CameraStoreBookmark4,
+    CameraJumpBookmark1,
+    CameraJumpBookmark2,
+    CameraJumpBookmark3,
+    CameraJumpBookmark4,
+    ToggleCameraFollow,
+    RotateLayout,
+}
+
+impl InputAction {
+    pub const ALL: [Self; 59] = [
+        Self::OpenHelp,
+        Self::CloseHelp,
+        Self::HelpPreviousTopic,
+        Self::HelpNextTopic,
+        Self::HelpPageUp,
+        Self::HelpPageDown,
+        Self::HelpHome,
+        Self::HelpEnd,
+        Self::SaveGame,
+        Self::RequestLoadGame,
+        Self::CycleElevation,
+        Self::ToggleRender3d,
+        Self::CycleRttQuality,
+        Self::ToggleRttDirectionalLight,
+        Self::ToggleRttTerrain,
+        Self::ToggleRttSceneObjects,
+        Self::ToggleDebug,
+        Self::DebugSpawnSoul,
+        Self::DebugSpawnFamiliar,
+        Self::ToggleArchitect,
+        Self::ToggleZones,
+        Self::TogglePause,
+        Self::TimePaused,
+        Self::TimeNormal,
+        Self::TimeFast,
+        Self::TimeSuper,
+        Self::FamiliarChop,
+        Self::FamiliarMine,
+        Self::FamiliarHaul,
+        Self::FamiliarBuild,
+        Self::FamiliarCancelDesignation,
+        Self::ToggleFamiliarIdlePatrol,
+        Self::CancelLoadConfirm,
+        Self::CloseSettings,
+        Self::CloseOperationDialog,
+        Self::CancelActiveMode,
+        Self::CloseOpenMenu,
+        Self::AreaCopy,
+        Self::AreaPaste,
+        Self::AreaUndo,
+        Self::AreaRedo,
+        Self::AreaSavePreset1,
+        Self::AreaSavePreset2,
+        Self::AreaSavePreset3,
+        Self::AreaLoadPreset1,
+        Self::AreaLoadPreset2,
+        Self::AreaLoadPreset3,
+        Self::ListNext,
+        Self::ListPrevious,
+        Self::CameraStoreBookmark1,
+        Self::CameraStoreBookmark2,
+        Self::CameraStoreBookmark3,
+        Self::CameraStoreBookmark4,
+        Self::CameraJumpBookmark1,
+        Self::CameraJumpBookmark2,
+        Self::CameraJumpBookmark3,
+        Self::CameraJumpBookmark4,
+        Self::ToggleCameraFollow,
+        Self::RotateLayout,
+    ];
+
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::OpenHelp => "ui.input_action.open_help",
+            Self::CloseHelp => "ui.input_action.close_help",
+            Self::HelpPreviousTopic => "ui.input_action.help_previous_topic",
+            Self::HelpNextTopic => "ui.input_action.help_next_topic",
+            Self::HelpPageUp => "ui.input_action.help_page_up",
+            Self::HelpPageDown => "ui.input_action.help_page_down",
+            Self::HelpHome => "ui.input_action.help_home",
+            Self::HelpEnd => "ui.input_action.help_end",
+            Self::SaveGame => "ui.input_action.save_game",
+            Self::RequestLoadGame => "ui.input_action.request_load_game",
+            Self::CycleElevation => "ui.input_action.cycle_elevation",
+            Self::ToggleRender3d => "ui.input_action.toggle_render_3d",
+            Self::CycleRttQuality => "ui.input_action.cycle_rtt_quality",
+            Self::ToggleRttDirectionalLight => "ui.input_action.toggle_rtt_directional_light",
+            Self::ToggleRttTerrain => "ui.input_action.toggle_rtt_terrain",
+            Self::ToggleRttSceneObjects => "ui.input_action.toggle_rtt_scene_objects",
+            Self::ToggleDebug => "ui.input_action.toggle_debug",
+            Self::DebugSpawnSoul => "ui.input_action.debug_spawn_soul",
+            Self::DebugSpawnFamiliar => "ui.input_action.debug_spawn_familiar",
+            Self::ToggleArchitect => "ui.input_action.toggle_architect",
+            Self::ToggleZones => "ui.input_action.toggle_zones",
+            Self::TogglePause => "ui.input_action.toggle_pause",
+            Self::TimePaused => "ui.input_action.time_paused",
+            Self::TimeNormal => "ui.input_action.time_normal",
+            Self::TimeFast => "ui.input_action.time_fast",
+            Self::TimeSuper => "ui.input_action.time_super",
+            Self::FamiliarChop => "ui.input_action.familiar_chop",
+            Self::FamiliarMine => "ui.input_action.familiar_mine",
+            Self::FamiliarHaul => "ui.input_action.familiar_haul",
+            Self::FamiliarBuild => "ui.input_action.familiar_build",
+            Self::FamiliarCancelDesignation => "ui.input_action.familiar_cancel_designation",
+            Self::ToggleFamiliarIdlePatrol => "ui.input_action.toggle_familiar_idle_patrol",
+            Self::CancelLoadConfirm => "ui.input_action.cancel_load_confirm",
+            Self::CloseSettings => "ui.input_action.close_settings",
+            Self::CloseOperationDialog => "ui.input_action.close_operation_dialog",
+            Self::CancelActiveMode => "ui.input_action.cancel_active_mode",
+            Self::CloseOpenMenu => "ui.input_action.close_open_menu",
+            Self::AreaCopy => "ui.input_action.area_copy",
+            Self::AreaPaste => "ui.input_action.area_paste",
+            Self::AreaUndo => "ui.input_action.area_undo",
+            Self::AreaRedo => "ui.input_action.area_redo",
+            Self::AreaSavePreset1 => "ui.input_action.area_save_preset_1",
+            Self::AreaSavePreset2 => "ui.input_action.area_save_preset_2",
+            Self::AreaSavePreset3 => "ui.input_action.area_save_preset_3",
+            Self::AreaLoadPreset1 => "ui.input_action.area_load_preset_1",
+            Self::AreaLoadPreset2 => "ui.input_action.area_load_preset_2",
+            Self::AreaLoadPreset3 => "ui.input_action.area_load_preset_3",
+            Self::ListNext => "ui.input_action.list_next",
+            Self::ListPrevious => "ui.input_action.list_previous",
+            Self::CameraStoreBookmark1 => "ui.input_action.camera_store_bookmark_1",
+            Self::CameraStoreBookmark2 => "ui.input_action.camera_store_bookmark_2",
+            Self::CameraStoreBookmark3 => "ui.input_action.camera_store_bookmark_3",
+            Self::CameraStoreBookmark4 => "ui.input_action.camera_store_bookmark_4",
+            Self::CameraJumpBookmark1 => "ui.input_action.camera_jump_bookmark_1",
+            Self::CameraJumpBookmark2 => "ui.input_action.camera_jump_bookmark_2",
+            Self::CameraJumpBookmark3 => "ui.input_action.camera_jump_bookmark_3",
+            Self::CameraJumpBookmark4 => "ui.input_action.camera_jump_bookmark_4",
+            Self::ToggleCameraFollow => "ui.input_action.toggle_camera_follow",
+            Self::RotateLayout => "ui.input_action.rotate_layout",
+        }
+    }
+
+    /// settings.ron のキーバインド上書きで使う安定 ID
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OpenHelp => "open-help",
+            Self::CloseHelp => "close-help",
+            Self::HelpPreviousTopic => "help-previous-topic",
+            Self::HelpNextTopic => "help-next-topic",
+            Self::HelpPageUp => "help-page-up",
+            Self::HelpPageDown => "help-page-down",
+            Self::HelpHome => "help-home",
+            Self::HelpEnd => "help-end",
+            Self::SaveGame => "save-game",
+            Self::RequestLoadGame => "request-load-game",
+            Self::CycleElevation => "cycle-elevation",
+            Self::ToggleRender3d => "toggle-render-3d",
+            Self::CycleRttQuality => "cycle-rtt-quality",
+            Self::ToggleRttDirectionalLight => "toggle-rtt-directional-light",
+            Self::ToggleRttTerrain => "toggle-rtt-terrain",
+            Self::ToggleRttSceneObjects => "toggle-rtt-scene-objects",
+            Self::ToggleDebug => "toggle-debug",
+            Self::DebugSpawnSoul => "debug-spawn-soul",
+            Self::DebugSpawnFamiliar => "debug-spawn-familiar",
+            Self::ToggleArchitect => "toggle-architect",
+            Self::ToggleZones => "toggle-zones",
+            Self::TogglePause => "toggle-pause",
+            Self::TimePaused => "time-paused",
+            Self::TimeNormal => "time-normal",
+            Self::TimeFast => "time-fast",
+            Self::TimeSuper => "time-super",
+            Self::FamiliarChop => "familiar-chop",
+            Self::FamiliarMine => "familiar-mine",
+            Self::FamiliarHaul => "familiar-haul",
+            Self::FamiliarBuild => "familiar-build",
+            Self::FamiliarCancelDesignation => "familiar-cancel-designation",
+            Self::ToggleFamiliarIdlePatrol => "toggle-familiar-idle-patrol",
+            Self::CancelLoadConfirm => "cancel-load-confirm",
+            Self::CloseSettings => "close-settings",
+            Self::CloseOperationDialog => "close-operation-dialog",
+            Self::CancelActiveMode => "cancel-active-mode",
+            Self::CloseOpenMenu => "close-open-menu",
+            Self::AreaCopy => "area-copy",
+            Self::AreaPaste => "area-paste",
+            Self::AreaUndo => "area-undo",
+            Self::AreaRedo => "area-redo",
+            Self::AreaSavePreset1 => "area-save-preset-1",
+            Self::AreaSavePreset2 => "area-save-preset-2",
+            Self::AreaSavePreset3 => "area-save-preset-3",
+            Self::AreaLoadPreset1 => "area-load-preset-1",
+            Self::AreaLoadPreset2 => "area-load-preset-2",
+            Self::AreaLoadPreset3 => "area-load-preset-3",
+            Self::ListNext => "list-next",
+            Self::ListPrevious => "list-previous",
+            Self::CameraStoreBookmark1 => "camera-store-bookmark-1",
+            Self::CameraStoreBookmark2 => "camera-store-bookmark-2",
+            Self::CameraStoreBookmark3 => "camera-store-bookmark-3",
+            Self::CameraStoreBookmark4 => "camera-store-bookmark-4",
+            Self::CameraJumpBookmark1 => "camera-jump-bookmark-1",
+            Self::CameraJumpBookmark2 => "camera-jump-bookmark-2",
+            Self::CameraJumpBookmark3 => "camera-jump-bookmark-3",
+            Self::CameraJumpBookmark4 => "camera-jump-bookmark-4",
+            Self::ToggleCameraFollow => "toggle-camera-follow",
+            Self::RotateLayout => "rotate-layout",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.as_str() == id)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
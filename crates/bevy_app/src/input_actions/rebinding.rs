@@ -0,0 +1,376 @@
+//! プレイヤーによるキーバインド上書き。
+//!
+//! `GameSettings::key_binding_overrides` を既定テーブルへ適用した結果を
+//! [`InputBindingTable`] として保持し、resolver・Help 表示・設定パネルが共有する。
+
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_core::{GameSettings, KeyBindingOverride};
+use hw_ui::components::MenuState;
+
+use super::bindings::{DEFAULT_BINDINGS, InputBinding, InputBindingContext, bindings_conflict};
+use super::key_labels::{UnsupportedPublicKey, format_input_chord, parse_input_chord};
+use super::{InputAction, InputChord, InputModifiers};
+use crate::systems::settings::persistence::save_settings_to_disk;
+
+/// 上書き適用後の実効キーバインド。行の並びと件数は `DEFAULT_BINDINGS` と同じ
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub(crate) struct InputBindingTable {
+    bindings: Vec<InputBinding>,
+}
+
+impl Default for InputBindingTable {
+    fn default() -> Self {
+        Self {
+            bindings: DEFAULT_BINDINGS.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RebindError {
+    UnknownBinding(usize),
+    UnsupportedKey(UnsupportedPublicKey),
+    Conflict {
+        action: InputAction,
+        context: InputBindingContext,
+    },
+}
+
+impl RebindError {
+    /// 設定パネルに出す編集失敗メッセージ
+    pub(crate) fn message(self, text: &Localization) -> String {
+        match self {
+            Self::UnknownBinding(index) => {
+                text.format("ui.settings.rebind.unknown_row", &[("index", &index)])
+            }
+            Self::UnsupportedKey(UnsupportedPublicKey(key)) => text.format(
+                "ui.settings.rebind.unsupported_key",
+                &[("key", &format!("{key:?}"))],
+            ),
+            Self::Conflict { action, context } => text.format(
+                "ui.settings.rebind.conflict",
+                &[
+                    ("action", &text.text(action.text_id())),
+                    ("context", &text.text(context.text_id())),
+                ],
+            ),
+        }
+    }
+}
+
+impl InputBindingTable {
+    pub(crate) fn bindings(&self) -> &[InputBinding] {
+        &self.bindings
+    }
+
+    /// 設定の上書きを適用する。解釈できない上書きや衝突する上書きは既定へ戻し、
+    /// その理由を返す。
+    pub(crate) fn from_overrides(overrides: &[KeyBindingOverride]) -> (Self, Vec<String>) {
+        let mut table = Self::default();
+        let mut rejected = Vec::new();
+        for entry in overrides {
+            let Some(index) = default_index(entry) else {
+                rejected.push(format!(
+                    "unknown binding {} [{}] {}",
+                    entry.action, entry.context, entry.default_chord
+                ));
+                continue;
+            };
+            let Some(chord) = parse_input_chord(&entry.chord) else {
+                rejected.push(format!("unsupported chord {}", entry.chord));
+                continue;
+            };
+            table.bindings[index].chord = chord;
+        }
+
+        // 入れ替え（A↔B）を許すため、全件適用後に衝突した上書きだけを既定へ戻す。
+        // 既定テーブル同士は衝突しないので、この繰り返しは必ず終わる。
+        while let Some(index) = table.first_conflicting_override() {
+            let binding = table.bindings[index];
+            rejected.push(format!(
+                "{} [{}] conflicts on {}",
+                binding.action.as_str(),
+                binding.context.as_str(),
+                format_input_chord(binding.chord).unwrap_or_default()
+            ));
+            table.bindings[index].chord = DEFAULT_BINDINGS[index].chord;
+        }
+        (table, rejected)
+    }
+
+    /// 既定から変更された行を設定保存用の上書きとして書き出す
+    pub(crate) fn overrides(&self) -> Vec<KeyBindingOverride> {
+        self.bindings
+            .iter()
+            .zip(DEFAULT_BINDINGS)
+            .filter(|(binding, default)| binding.chord != default.chord)
+            .filter_map(|(binding, default)| {
+                Some(KeyBindingOverride {
+                    action: binding.action.as_str().to_string(),
+                    context: binding.context.as_str().to_string(),
+                    default_chord: format_input_chord(default.chord).ok()?,
+                    chord: format_input_chord(binding.chord).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// resolver と同じ lane/context 規則で衝突を確認してから差し替える
+    pub(crate) fn rebind(&mut self, index: usize, chord: InputChord) -> Result<(), RebindError> {
+        let Some(current) = self.bindings.get(index).copied() else {
+            return Err(RebindError::UnknownBinding(index));
+        };
+        format_input_chord(chord).map_err(RebindError::UnsupportedKey)?;
+        let candidate = InputBinding { chord, ..current };
+        if let Some(other) = self.conflict_with(index, &candidate) {
+            return Err(RebindError::Conflict {
+                action: other.action,
+                context: other.context,
+            });
+        }
+        self.bindings[index].chord = chord;
+        Ok(())
+    }
+
+    fn conflict_with(&self, index: usize, candidate: &InputBinding) -> Option<&InputBinding> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .find(|(other_index, other)| {
+                *other_index != index && bindings_conflict(candidate, other)
+            })
+            .map(|(_, other)| other)
+    }
+
+    fn first_conflicting_override(&self) -> Option<usize> {
+        (0..self.bindings.len()).find(|&index| {
+            self.bindings[index].chord != DEFAULT_BINDINGS[index].chord
+                && self.conflict_with(index, &self.bindings[index]).is_some()
+        })
+    }
+}
+
+fn default_index(entry: &KeyBindingOverride) -> Option<usize> {
+    let action = InputAction::from_id(&entry.action)?;
+    let context = InputBindingContext::from_id(&entry.context)?;
+    let default_chord = parse_input_chord(&entry.default_chord)?;
+    DEFAULT_BINDINGS.iter().position(|binding| {
+        binding.action == action && binding.context == context && binding.chord == default_chord
+    })
+}
+
+/// 設定パネルの 1 行分の表示内容
+pub(crate) fn binding_row_label(binding: &InputBinding, text: &Localization) -> String {
+    text.format(
+        "ui.settings.binding_row",
+        &[
+            ("action", &text.text(binding.action.text_id())),
+            ("context", &text.text(binding.context.text_id())),
+        ],
+    )
+}
+
+/// キー入力待ち中の行と、直前の編集結果
+#[derive(Resource, Debug, Default)]
+pub(crate) struct KeyRebindCapture {
+    target: Option<usize>,
+    status: Option<RebindError>,
+}
+
+impl KeyRebindCapture {
+    pub(crate) fn begin(&mut self, index: usize) {
+        self.target = Some(index);
+        self.status = None;
+    }
+
+    pub(crate) fn cancel(&mut self) {
+        self.target = None;
+    }
+
+    pub(crate) fn target(&self) -> Option<usize> {
+        self.target
+    }
+
+    pub(crate) fn is_capturing(&self) -> bool {
+        self.target.is_some()
+    }
+
+    pub(crate) fn status(&self) -> Option<RebindError> {
+        self.status
+    }
+
+    fn finish(&mut self, status: Option<RebindError>) {
+        self.target = None;
+        self.status = status;
+    }
+}
+
+const MODIFIER_KEYS: [KeyCode; 8] = [
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
+
+/// 設定変更（読込・リセット・編集）を実効テーブルへ反映する
+pub(crate) fn sync_input_binding_table_system(
+    settings: Res<GameSettings>,
+    mut table: ResMut<InputBindingTable>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let (next, rejected) = InputBindingTable::from_overrides(&settings.key_binding_overrides);
+    for reason in rejected {
+        warn!("Ignoring key binding override: {reason}");
+    }
+    if *table != next {
+        *table = next;
+    }
+}
+
+/// 入力待ち中に押されたキーを対象行へ割り当てる。Esc は取り消し。
+///
+/// resolver より後に走り、入力待ち中のフレームは resolver 側でアクションを出さない。
+pub(crate) fn key_rebind_capture_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    menu_state: Res<MenuState>,
+    table: Res<InputBindingTable>,
+    mut capture: ResMut<KeyRebindCapture>,
+    mut settings: ResMut<GameSettings>,
+) {
+    let Some(index) = capture.target() else {
+        return;
+    };
+    if *menu_state != MenuState::Settings {
+        capture.cancel();
+        return;
+    }
+    let Some(key) = keyboard
+        .get_just_pressed()
+        .copied()
+        .find(|key| !MODIFIER_KEYS.contains(key))
+    else {
+        return;
+    };
+    if key == KeyCode::Escape {
+        capture.finish(None);
+        return;
+    }
+
+    let chord = InputChord {
+        key,
+        modifiers: InputModifiers::from_keyboard(&keyboard),
+    };
+    let mut next = table.clone();
+    match next.rebind(index, chord) {
+        Ok(()) => {
+            settings.key_binding_overrides = next.overrides();
+            if let Err(err) = save_settings_to_disk(&settings) {
+                warn!("Failed to save settings: {err}");
+            }
+            capture.finish(None);
+        }
+        Err(error) => capture.finish(Some(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(action: InputAction, context: InputBindingContext) -> usize {
+        DEFAULT_BINDINGS
+            .iter()
+            .position(|binding| binding.action == action && binding.context == context)
+            .expect("default row")
+    }
+
+    #[test]
+    fn default_table_has_no_rebind_conflicts() {
+        let table = InputBindingTable::default();
+        for (index, binding) in table.bindings().iter().enumerate() {
+            assert!(
+                table.conflict_with(index, binding).is_none(),
+                "{binding:?} conflicts in the default table"
+            );
+        }
+    }
+
+    #[test]
+    fn rebind_rejects_same_context_and_same_lane_claims() {
+        let mut table = InputBindingTable::default();
+        let architect = row(
+            InputAction::ToggleArchitect,
+            InputBindingContext::WorldNormal,
+        );
+
+        assert_eq!(
+            table.rebind(architect, InputChord::plain(KeyCode::Tab)),
+            Err(RebindError::Conflict {
+                action: InputAction::ListNext,
+                context: InputBindingContext::WorldNormal,
+            })
+        );
+        // Familiar 文脈の C は文脈優先度が異なるため共存できる
+        assert_eq!(
+            table.rebind(architect, InputChord::plain(KeyCode::KeyC)),
+            Ok(())
+        );
+        assert!(matches!(
+            table.rebind(architect, InputChord::plain(KeyCode::Insert)),
+            Err(RebindError::UnsupportedKey(_))
+        ));
+    }
+
+    #[test]
+    fn overrides_round_trip_and_allow_swaps() {
+        let mut table = InputBindingTable::default();
+        let architect = row(
+            InputAction::ToggleArchitect,
+            InputBindingContext::WorldNormal,
+        );
+        let zones = row(InputAction::ToggleZones, InputBindingContext::WorldNormal);
+        table
+            .rebind(architect, InputChord::plain(KeyCode::KeyN))
+            .unwrap();
+        table
+            .rebind(zones, InputChord::plain(KeyCode::KeyB))
+            .unwrap();
+        table
+            .rebind(architect, InputChord::plain(KeyCode::KeyZ))
+            .unwrap();
+
+        let overrides = table.overrides();
+        assert_eq!(overrides.len(), 2);
+        let (restored, rejected) = InputBindingTable::from_overrides(&overrides);
+        assert!(rejected.is_empty(), "{rejected:?}");
+        assert_eq!(restored, table);
+    }
+
+    #[test]
+    fn invalid_or_conflicting_overrides_fall_back_to_defaults() {
+        let overrides = vec![
+            KeyBindingOverride {
+                action: "toggle-architect".to_string(),
+                context: "world-normal".to_string(),
+                default_chord: "B".to_string(),
+                chord: "Tab".to_string(),
+            },
+            KeyBindingOverride {
+                action: "no-such-action".to_string(),
+                context: "global".to_string(),
+                default_chord: "F1".to_string(),
+                chord: "F2".to_string(),
+            },
+        ];
+        let (table, rejected) = InputBindingTable::from_overrides(&overrides);
+        assert_eq!(rejected.len(), 2);
+        assert_eq!(table, InputBindingTable::default());
+    }
+}
@@ -5,6 +5,7 @@ use super::bindings::{
     DEFAULT_BINDINGS, InputBinding, actions_are_compatible, binding_matches_context,
 };
 use super::context::InputContextParams;
+use super::rebinding::{InputBindingTable, KeyRebindCapture};
 use super::{InputAction, InputChord, InputContextSnapshot, InputModifiers};
 
 /// Actions and modifier state resolved once for the current frame.
@@ -157,11 +158,14 @@ pub(crate) fn resolve_input_frame_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     context_params: InputContextParams,
     area_edit_session: Option<Res<AreaEditSession>>,
+    binding_table: Res<InputBindingTable>,
+    rebind_capture: Res<KeyRebindCapture>,
     mut resolved_frame: ResMut<ResolvedInputFrame>,
 ) {
+    let bindings = binding_table.bindings();
     let modifiers = InputModifiers::from_keyboard(&keyboard);
     let mut pressed_chords: Vec<InputChord> = Vec::new();
-    for binding in DEFAULT_BINDINGS {
+    for binding in bindings {
         let chord = InputChord {
             key: binding.chord.key,
             modifiers,
@@ -172,8 +176,13 @@ pub(crate) fn resolve_input_frame_system(
     }
     let has_in_progress_gesture = area_edit_session.is_some_and(|session| session.is_dragging());
     let (context, selected_familiar) = context_params.snapshot(has_in_progress_gesture);
-    let actions = resolve_input_chords(&pressed_chords, context);
-    let pointer_selection_suppressed = DEFAULT_BINDINGS
+    // キーバインド編集の入力待ち中は、押されたキーを割り当て対象としてだけ扱う
+    let actions = if rebind_capture.is_capturing() {
+        Vec::new()
+    } else {
+        resolve_input_chords_with_bindings(&pressed_chords, context, bindings)
+    };
+    let pointer_selection_suppressed = bindings
         .iter()
         .any(|binding| binding.suppresses_pointer_selection && actions.contains(&binding.action));
 
@@ -111,6 +111,8 @@ fn every_action_has_exactly_one_consumer_owner() {
         ActiveModeCancel,
         AreaEdit,
         ListNavigation,
+        CameraControl,
+        LayoutBlueprint,
     }
 
     fn owner(action: InputAction) -> ConsumerOwner {
@@ -165,6 +167,16 @@ fn every_action_has_exactly_one_consumer_owner() {
             | InputAction::AreaLoadPreset2
             | InputAction::AreaLoadPreset3 => ConsumerOwner::AreaEdit,
             InputAction::ListNext | InputAction::ListPrevious => ConsumerOwner::ListNavigation,
+            InputAction::CameraStoreBookmark1
+            | InputAction::CameraStoreBookmark2
+            | InputAction::CameraStoreBookmark3
+            | InputAction::CameraStoreBookmark4
+            | InputAction::CameraJumpBookmark1
+            | InputAction::CameraJumpBookmark2
+            | InputAction::CameraJumpBookmark3
+            | InputAction::CameraJumpBookmark4
+            | InputAction::ToggleCameraFollow => ConsumerOwner::CameraControl,
+            InputAction::RotateLayout => ConsumerOwner::LayoutBlueprint,
         }
     }
 
@@ -218,6 +230,16 @@ fn every_action_has_exactly_one_consumer_owner() {
         InputAction::AreaLoadPreset3,
         InputAction::ListNext,
         InputAction::ListPrevious,
+        InputAction::CameraStoreBookmark1,
+        InputAction::CameraStoreBookmark2,
+        InputAction::CameraStoreBookmark3,
+        InputAction::CameraStoreBookmark4,
+        InputAction::CameraJumpBookmark1,
+        InputAction::CameraJumpBookmark2,
+        InputAction::CameraJumpBookmark3,
+        InputAction::CameraJumpBookmark4,
+        InputAction::ToggleCameraFollow,
+        InputAction::RotateLayout,
     ] {
         let _ = owner(action);
     }
@@ -364,6 +386,36 @@ fn list_navigation_is_exact_and_world_normal_only() {
     assert!(resolve_input_chords(&[plain(KeyCode::Tab)], area_edit_context()).is_empty());
 }
 
+#[test]
+fn camera_bookmarks_are_world_normal_and_yield_to_area_presets() {
+    let alt = |key| {
+        modified(
+            key,
+            InputModifiers {
+                alt: true,
+                ..default()
+            },
+        )
+    };
+    assert_eq!(
+        resolve_input_chords(&[ctrl(KeyCode::Digit2)], InputContextSnapshot::default()),
+        [InputAction::CameraStoreBookmark2]
+    );
+    assert_eq!(
+        resolve_input_chords(&[alt(KeyCode::Digit4)], familiar_context()),
+        [InputAction::CameraJumpBookmark4]
+    );
+    assert_eq!(
+        resolve_input_chords(&[plain(KeyCode::KeyF)], InputContextSnapshot::default()),
+        [InputAction::ToggleCameraFollow]
+    );
+    assert_eq!(
+        resolve_input_chords(&[ctrl(KeyCode::Digit1)], area_edit_context()),
+        [InputAction::AreaSavePreset1]
+    );
+    assert!(resolve_input_chords(&[plain(KeyCode::KeyF)], area_edit_context()).is_empty());
+}
+
 #[test]
 fn debug_spawn_uses_resolver_time_visibility_snapshot() {
     assert!(
@@ -571,6 +623,8 @@ fn every_task_mode_owner_claims_escape_before_the_familiar_toggle() {
         TaskMode::DreamPlanting(None),
         TaskMode::StockpilePolicyEdit(None),
         TaskMode::SoulSpaPlace(None),
+        TaskMode::LayoutExport(None),
+        TaskMode::LayoutImport,
     ] {
         assert_eq!(
             resolve_input_chords(
@@ -880,6 +934,8 @@ fn resolver_app() -> App {
     app.init_resource::<ButtonInput<KeyCode>>()
         .init_resource::<UiInputState>()
         .init_resource::<ResolvedInputFrame>()
+        .init_resource::<crate::input_actions::InputBindingTable>()
+        .init_resource::<crate::input_actions::KeyRebindCapture>()
         .init_resource::<TaskContext>()
         .init_resource::<MenuState>()
         .init_resource::<SelectedEntity>()
@@ -0,0 +1,300 @@
+//! カメラブックマークと選択エンティティ追従
+//!
+//! `PanCamera` の手動パン/ズームに加え、Ctrl+数字で現在位置を保存し Alt+数字で戻る。
+//! F で選択中の Soul / Familiar への追従を切り替え、プレイヤーがパンした時点で解除する。
+
+use crate::entities::damned_soul::DamnedSoul;
+use crate::entities::familiar::Familiar;
+use crate::input_actions::{InputAction, ResolvedInputFrame};
+use crate::interface::selection::SelectedEntity;
+use bevy::camera_controller::pan_camera::PanCamera;
+use bevy::prelude::*;
+use bevy::time::Real;
+use hw_core::camera::{CameraBookmark, CameraBookmarks, CameraFollow, MainCamera};
+use hw_core::locale::Localization;
+use hw_ui::notifications::{NotificationRetention, NotificationSeverity, UserFacingNotification};
+
+/// 追従の収束速度（1/秒）。フレームレートに依存しないよう指数補間で使う。
+const FOLLOW_RATE: f32 = 8.0;
+/// 追従システム以外がカメラを動かしたとみなす距離
+const FOLLOW_BREAK_DISTANCE: f32 = 0.5;
+
+type FollowTargetFilter = Or<(With<DamnedSoul>, With<Familiar>)>;
+
+enum BookmarkCommand {
+    Store(usize),
+    Jump(usize),
+}
+
+fn bookmark_command(action: InputAction) -> Option<BookmarkCommand> {
+    match action {
+        InputAction::CameraStoreBookmark1 => Some(BookmarkCommand::Store(0)),
+        InputAction::CameraStoreBookmark2 => Some(BookmarkCommand::Store(1)),
+        InputAction::CameraStoreBookmark3 => Some(BookmarkCommand::Store(2)),
+        InputAction::CameraStoreBookmark4 => Some(BookmarkCommand::Store(3)),
+        InputAction::CameraJumpBookmark1 => Some(BookmarkCommand::Jump(0)),
+        InputAction::CameraJumpBookmark2 => Some(BookmarkCommand::Jump(1)),
+        InputAction::CameraJumpBookmark3 => Some(BookmarkCommand::Jump(2)),
+        InputAction::CameraJumpBookmark4 => Some(BookmarkCommand::Jump(3)),
+        _ => None,
+    }
+}
+
+fn camera_notification(
+    key: impl Into<String>,
+    severity: NotificationSeverity,
+    title: impl Into<String>,
+) -> UserFacingNotification {
+    UserFacingNotification::new(
+        key.into(),
+        severity,
+        title,
+        "",
+        NotificationRetention::ToastOnly,
+    )
+}
+
+/// Ctrl+数字でブックマークを保存し、Alt+数字でその位置と拡大率へ戻る。
+pub fn camera_bookmark_shortcut_system(
+    resolved_frame: Res<ResolvedInputFrame>,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    mut follow: ResMut<CameraFollow>,
+    mut q_camera: Query<(&mut Transform, &mut PanCamera), With<MainCamera>>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    let Ok((mut transform, mut pan_camera)) = q_camera.single_mut() else {
+        return;
+    };
+    for command in resolved_frame
+        .actions()
+        .iter()
+        .copied()
+        .filter_map(bookmark_command)
+    {
+        match command {
+            BookmarkCommand::Store(slot) => {
+                bookmarks.store(
+                    slot,
+                    CameraBookmark {
+                        position: transform.translation.truncate(),
+                        zoom: pan_camera.zoom_factor,
+                    },
+                );
+                notifications.write(camera_notification(
+                    format!("camera-bookmark:{slot}:stored"),
+                    NotificationSeverity::Info,
+                    localization.format("notify.camera.bookmark_stored", &[("slot", &(slot + 1))]),
+                ));
+            }
+            BookmarkCommand::Jump(slot) => {
+                let Some(bookmark) = bookmarks.get(slot) else {
+                    notifications.write(camera_notification(
+                        format!("camera-bookmark:{slot}:empty"),
+                        NotificationSeverity::Warning,
+                        localization
+                            .format("notify.camera.bookmark_empty", &[("slot", &(slot + 1))]),
+                    ));
+                    continue;
+                };
+                follow.stop();
+                transform.translation.x = bookmark.position.x;
+                transform.translation.y = bookmark.position.y;
+                pan_camera.zoom_factor = bookmark.zoom;
+            }
+        }
+    }
+}
+
+/// F で選択中の Soul / Familiar への追従を開始・解除する。
+pub fn camera_follow_toggle_system(
+    resolved_frame: Res<ResolvedInputFrame>,
+    selected: Res<SelectedEntity>,
+    mut follow: ResMut<CameraFollow>,
+    q_targets: Query<(), FollowTargetFilter>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    if !resolved_frame.contains(InputAction::ToggleCameraFollow) {
+        return;
+    }
+    if follow.target.is_some() {
+        follow.stop();
+        notifications.write(camera_notification(
+            "camera-follow:stopped",
+            NotificationSeverity::Info,
+            localization.text("notify.camera.follow_stopped"),
+        ));
+        return;
+    }
+    match selected.0.filter(|&entity| q_targets.contains(entity)) {
+        Some(target) => {
+            follow.start(target);
+            notifications.write(camera_notification(
+                "camera-follow:started",
+                NotificationSeverity::Info,
+                localization.text("notify.camera.follow_started"),
+            ));
+        }
+        None => {
+            notifications.write(camera_notification(
+                "camera-follow:no-target",
+                NotificationSeverity::Warning,
+                localization.text("notify.camera.follow_no_target"),
+            ));
+        }
+    }
+}
+
+/// 追従対象へカメラを滑らかに寄せる。
+///
+/// 前フレームに自分が置いた位置からカメラが動いていればプレイヤー操作とみなして解除する。
+/// 一時停止中も追従できるよう実時間で補間する。
+pub fn camera_follow_system(
+    time: Res<Time<Real>>,
+    mut follow: ResMut<CameraFollow>,
+    mut q_camera: Query<&mut Transform, With<MainCamera>>,
+    q_targets: Query<&GlobalTransform, FollowTargetFilter>,
+) {
+    let Some(target) = follow.target else {
+        return;
+    };
+    let Ok(mut transform) = q_camera.single_mut() else {
+        return;
+    };
+    let current = transform.translation.truncate();
+    let panned_away = follow
+        .last_position
+        .is_some_and(|last| last.distance(current) > FOLLOW_BREAK_DISTANCE);
+    let Ok(target_transform) = q_targets.get(target) else {
+        follow.stop();
+        return;
+    };
+    if panned_away {
+        follow.stop();
+        return;
+    }
+
+    let blend = 1.0 - (-FOLLOW_RATE * time.delta_secs()).exp();
+    let next = current.lerp(target_transform.translation().truncate(), blend);
+    transform.translation.x = next.x;
+    transform.translation.y = next.y;
+    follow.last_position = Some(next);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_actions::InputModifiers;
+    use crate::test_support::minimal_app;
+
+    fn press(app: &mut App, action: InputAction) {
+        app.world_mut()
+            .resource_mut::<ResolvedInputFrame>()
+            .replace(InputModifiers::default(), vec![action], None, true);
+    }
+
+    fn camera_app() -> (App, Entity) {
+        let mut app = minimal_app();
+        app.add_message::<UserFacingNotification>()
+            .init_resource::<Localization>()
+            .init_resource::<ResolvedInputFrame>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<CameraBookmarks>()
+            .init_resource::<CameraFollow>()
+            .add_systems(
+                Update,
+                (
+                    camera_bookmark_shortcut_system,
+                    camera_follow_toggle_system,
+                    camera_follow_system,
+                )
+                    .chain(),
+            );
+        let camera = app
+            .world_mut()
+            .spawn((Transform::default(), PanCamera::default(), MainCamera))
+            .id();
+        (app, camera)
+    }
+
+    #[test]
+    fn stored_bookmark_restores_position_and_zoom() {
+        let (mut app, camera) = camera_app();
+        app.world_mut()
+            .get_mut::<Transform>(camera)
+            .unwrap()
+            .translation = Vec3::new(40.0, 12.0, 0.0);
+        app.world_mut()
+            .get_mut::<PanCamera>(camera)
+            .unwrap()
+            .zoom_factor = 2.0;
+        press(&mut app, InputAction::CameraStoreBookmark2);
+        app.update();
+
+        app.world_mut()
+            .get_mut::<Transform>(camera)
+            .unwrap()
+            .translation = Vec3::new(-100.0, 3.0, 0.0);
+        app.world_mut()
+            .get_mut::<PanCamera>(camera)
+            .unwrap()
+            .zoom_factor = 1.0;
+        press(&mut app, InputAction::CameraJumpBookmark2);
+        app.update();
+
+        let translation = app.world().get::<Transform>(camera).unwrap().translation;
+        assert_eq!(translation.truncate(), Vec2::new(40.0, 12.0));
+        assert_eq!(
+            app.world().get::<PanCamera>(camera).unwrap().zoom_factor,
+            2.0
+        );
+
+        press(&mut app, InputAction::CameraJumpBookmark3);
+        app.update();
+        let translation = app.world().get::<Transform>(camera).unwrap().translation;
+        assert_eq!(translation.truncate(), Vec2::new(40.0, 12.0));
+    }
+
+    #[test]
+    fn follow_requires_a_soul_or_familiar_and_stops_when_the_player_pans() {
+        let (mut app, camera) = camera_app();
+        let rock = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::X))
+            .id();
+        app.world_mut().resource_mut::<SelectedEntity>().0 = Some(rock);
+        press(&mut app, InputAction::ToggleCameraFollow);
+        app.update();
+        assert_eq!(app.world().resource::<CameraFollow>().target, None);
+
+        let familiar = app
+            .world_mut()
+            .spawn((
+                Familiar::default(),
+                GlobalTransform::from_translation(Vec3::new(100.0, 0.0, 0.0)),
+            ))
+            .id();
+        app.world_mut().resource_mut::<SelectedEntity>().0 = Some(familiar);
+        press(&mut app, InputAction::ToggleCameraFollow);
+        app.update();
+        assert_eq!(
+            app.world().resource::<CameraFollow>().target,
+            Some(familiar)
+        );
+
+        press(&mut app, InputAction::ListNext);
+        app.update();
+        assert_eq!(
+            app.world().resource::<CameraFollow>().target,
+            Some(familiar)
+        );
+
+        app.world_mut()
+            .get_mut::<Transform>(camera)
+            .unwrap()
+            .translation = Vec3::new(-500.0, 0.0, 0.0);
+        app.update();
+        assert_eq!(app.world().resource::<CameraFollow>().target, None);
+    }
+}
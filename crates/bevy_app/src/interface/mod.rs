@@ -1,2 +1,3 @@
+pub mod camera_control;
 pub mod selection;
 pub mod ui;
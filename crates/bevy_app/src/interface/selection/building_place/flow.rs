@@ -5,7 +5,9 @@ use crate::app_contexts::{CompanionPlacementKind, CompanionPlacementState};
 use crate::assets::GameAssets;
 use crate::world::map::{RIVER_Y_MIN, WorldMap};
 use bevy::prelude::*;
-use hw_ui::selection::{PlacementRejectReason, PlacementTileRejection, building_occupied_grids};
+use hw_ui::selection::{
+    PlacementRejectReason, PlacementTileRejection, bucket_storage_geometry, building_occupied_grids,
+};
 
 /// Handles the companion placement flow when `companion_state` is active.
 /// Returns `true` if the flow consumed the click (caller should `return`).
@@ -52,7 +54,7 @@ pub(super) fn handle_companion_flow(
                 world_map,
                 parent_blueprint,
                 &parent_occupied_grids,
-                grid,
+                bucket_storage_geometry(grid),
             ) {
                 Ok(()) => {
                     companion_state.0 = None;
@@ -23,7 +23,8 @@ use hw_world::zones::{Site, Yard};
 
 use companion::make_companion_placement;
 use flow::handle_companion_flow;
-use placement::{place_building_blueprint, validate_building_blueprint_placement};
+use placement::validate_building_blueprint_placement;
+pub(crate) use placement::{place_building_blueprint, place_tank_with_bucket_storage};
 
 #[derive(SystemParam)]
 pub struct BuildPlaceInput<'w, 's> {
@@ -50,7 +51,7 @@ pub struct BuildPlaceState<'w> {
     pub placement_feedback: ResMut<'w, PlacementFeedbackState>,
 }
 
-pub(super) struct PlacementQueries<'a, 'w, 's> {
+pub(crate) struct PlacementQueries<'a, 'w, 's> {
     pub q_buildings: &'a Query<'w, 's, &'static Building>,
     pub q_blueprints_by_entity: &'a Query<'w, 's, &'static Blueprint>,
     pub q_sites: &'a Query<'w, 's, &'static Site>,
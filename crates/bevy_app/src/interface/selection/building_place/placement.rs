@@ -7,8 +7,8 @@ use hw_core::constants::*;
 use hw_core::visual_mirror::construction::BlueprintVisualState;
 use hw_ui::selection::{
     BuildingPlacementContext, PlacementGeometry, PlacementTileRejection, PlacementValidation,
-    TANK_NEARBY_BUCKET_STORAGE_TILES, bucket_storage_geometry, building_geometry,
-    validate_bucket_storage_placement, validate_building_placement,
+    TANK_NEARBY_BUCKET_STORAGE_TILES, building_geometry, validate_bucket_storage_placement,
+    validate_building_placement,
 };
 
 type PlaceBlueprintResult = Result<(Entity, Vec<(i32, i32)>, Vec2), PlacementTileRejection>;
@@ -77,7 +77,7 @@ fn validate_blueprint_geometry(
 }
 
 /// Revalidates a BuildingPlace candidate without mutating WorldMap or spawning a Blueprint.
-pub(super) fn validate_building_blueprint_placement(
+pub(crate) fn validate_building_blueprint_placement(
     world_map: &WorldMap,
     building_type: BuildingType,
     grid: (i32, i32),
@@ -89,7 +89,7 @@ pub(super) fn validate_building_blueprint_placement(
 
 /// Attempts to spawn a Blueprint entity for the given building type at the given grid position.
 /// Returns the spawned entity and geometry on success, or the typed rejection when blocked.
-pub(super) fn place_building_blueprint(
+pub(crate) fn place_building_blueprint(
     commands: &mut Commands,
     world_map: &mut WorldMap,
     game_assets: &GameAssets,
@@ -132,6 +132,7 @@ pub(super) fn place_building_blueprint(
         BuildingType::WheelbarrowParking => game_assets.wheelbarrow_parking.clone(),
         BuildingType::SoulSpa => game_assets.bone_pile.clone(), // placeholder — SoulSpa uses own spawn
         BuildingType::OutdoorLamp => game_assets.bone_pile.clone(),
+        BuildingType::RedemptionShrine => game_assets.rest_area.clone(),
     };
 
     let entity = commands
@@ -169,9 +170,9 @@ pub(super) fn try_place_bucket_storage_companion(
     world_map: &mut WorldMap,
     parent_blueprint: Entity,
     parent_occupied_grids: &[(i32, i32)],
-    anchor_grid: (i32, i32),
+    geometry: PlacementGeometry,
 ) -> Result<(), PlacementTileRejection> {
-    let geometry = bucket_storage_geometry(anchor_grid);
+    let anchor_grid = geometry.occupied_grids.first().copied().unwrap_or_default();
     let read_world = WorldMapRef(world_map);
     let validation = validate_bucket_storage_placement(
         &read_world,
@@ -209,3 +210,37 @@ pub(super) fn try_place_bucket_storage_companion(
     }
     Ok(())
 }
+
+/// Places a Tank blueprint together with its BucketStorage companion cells.
+/// The Tank is rolled back when the companion cannot be placed.
+pub(crate) fn place_tank_with_bucket_storage(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    game_assets: &GameAssets,
+    anchor_grid: (i32, i32),
+    companion: PlacementGeometry,
+    pq: &PlacementQueries<'_, '_, '_>,
+) -> Result<(), PlacementTileRejection> {
+    let (parent_blueprint, parent_occupied_grids, _) = place_building_blueprint(
+        commands,
+        world_map,
+        game_assets,
+        BuildingType::Tank,
+        anchor_grid,
+        pq,
+    )?;
+    try_place_bucket_storage_companion(
+        commands,
+        world_map,
+        parent_blueprint,
+        &parent_occupied_grids,
+        companion,
+    )
+    .inspect_err(|_| {
+        // 親Blueprintの確定に成功したが companion が置けない場合は巻き戻す
+        for &grid in &parent_occupied_grids {
+            world_map.clear_building_occupancy(grid);
+        }
+        commands.entity(parent_blueprint).despawn();
+    })
+}
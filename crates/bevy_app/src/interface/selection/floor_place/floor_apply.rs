@@ -6,7 +6,7 @@ use hw_core::constants::*;
 use hw_core::visual_mirror::construction::{FloorSiteVisualState, FloorTileVisualMirror};
 use hw_ui::selection::AreaPlacementPlan;
 
-pub(super) fn apply_floor_placement(
+pub(crate) fn apply_floor_placement(
     commands: &mut Commands,
     area: &crate::systems::command::TaskArea,
     plan: &AreaPlacementPlan,
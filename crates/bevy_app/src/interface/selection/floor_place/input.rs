@@ -80,7 +80,10 @@ pub(super) fn handle_release(
             );
             if plan.valid_tiles.is_empty() {
                 if let Some(feedback) = plan.feedback() {
-                    warn!("Floor placement rejected: {}", feedback.body());
+                    warn!(
+                        "Floor placement rejected: {:?} at {:?}",
+                        feedback.reason, feedback.target_grid
+                    );
                     state
                         .placement_feedback
                         .show_recent_failure(feedback, state.now);
@@ -101,7 +104,10 @@ pub(super) fn handle_release(
             );
             if plan.valid_tiles.is_empty() {
                 if let Some(feedback) = plan.feedback() {
-                    warn!("Wall placement rejected: {}", feedback.body());
+                    warn!(
+                        "Wall placement rejected: {:?} at {:?}",
+                        feedback.reason, feedback.target_grid
+                    );
                     state
                         .placement_feedback
                         .show_recent_failure(feedback, state.now);
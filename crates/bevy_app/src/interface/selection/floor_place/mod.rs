@@ -28,9 +28,14 @@ use input::{
     FloorQueryGroup, FloorReleaseData, FloorReleaseState, handle_cancel, handle_drag_start,
     handle_release,
 };
-use validation::{
-    build_floor_placement_plan, build_wall_placement_plan, existing_floor_building_grids,
+use validation::{build_floor_placement_plan, build_wall_placement_plan};
+
+pub(crate) use floor_apply::apply_floor_placement;
+pub(crate) use validation::{
+    existing_floor_building_grids, validate_floor_tile, validate_wall_tile,
+    validate_wall_tile_no_floor_check,
 };
+pub(crate) use wall_apply::apply_wall_placement;
 
 #[derive(SystemParam)]
 pub struct FloorPlaceInput<'w, 's> {
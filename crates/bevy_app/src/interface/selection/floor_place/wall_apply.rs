@@ -6,7 +6,7 @@ use hw_core::constants::*;
 use hw_core::visual_mirror::construction::{WallSiteVisualState, WallTileVisualMirror};
 use hw_ui::selection::AreaPlacementPlan;
 
-pub(super) fn apply_wall_placement(
+pub(crate) fn apply_wall_placement(
     commands: &mut Commands,
     world_map: &mut WorldMap,
     area: &crate::systems::command::TaskArea,
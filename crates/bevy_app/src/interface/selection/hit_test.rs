@@ -134,3 +134,50 @@ pub(super) fn hovered_entity_at_world_pos(
 
     None
 }
+
+/// Collects the selectable entities whose position lies inside `[min, max]` for box selection.
+///
+/// Order follows the click-priority tiers (familiars, souls, stockpile cells, other targets).
+/// Items stored on a managed stockpile cell are skipped so the cell itself stands for its tile.
+pub(super) fn entities_in_world_rect(
+    min: Vec2,
+    max: Vec2,
+    q_souls: &Query<(Entity, &GlobalTransform), With<DamnedSoul>>,
+    q_familiars: &Query<(Entity, &GlobalTransform), With<Familiar>>,
+    q_stockpile_cells: &ManagedStockpileQuery,
+    q_targets: &SelectionTargetQuery,
+) -> Vec<Entity> {
+    let inside = |transform: &GlobalTransform| {
+        let pos = transform.translation().truncate();
+        pos.cmpge(min).all() && pos.cmple(max).all()
+    };
+
+    let mut entities: Vec<Entity> = q_familiars
+        .iter()
+        .chain(q_souls.iter())
+        .filter(|(_, transform)| inside(transform))
+        .map(|(entity, _)| entity)
+        .collect();
+
+    let cells: Vec<(Entity, Vec2)> = q_stockpile_cells
+        .iter()
+        .filter(|(_, transform)| inside(transform))
+        .map(|(entity, transform)| (entity, transform.translation().truncate()))
+        .collect();
+    entities.extend(cells.iter().map(|(entity, _)| *entity));
+
+    let targets: Vec<Entity> = q_targets
+        .iter()
+        .filter(|(entity, transform, _)| {
+            let pos = transform.translation().truncate();
+            inside(transform)
+                && !entities.contains(entity)
+                && !cells
+                    .iter()
+                    .any(|(_, cell)| cell.distance_squared(pos) < TILE_HALF_SIZE_SQ)
+        })
+        .map(|(entity, _, _)| entity)
+        .collect();
+    entities.extend(targets);
+    entities
+}
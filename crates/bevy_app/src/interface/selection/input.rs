@@ -10,8 +10,13 @@ use hw_core::game_state::PlayMode;
 use hw_ui::camera::MainCamera;
 use hw_ui::selection::SelectionIntent;
 
-use super::hit_test::{hovered_entity_at_world_pos, hovered_task_area_border_entity};
-use super::state::{HoveredEntity, SelectedEntity};
+use super::hit_test::{
+    entities_in_world_rect, hovered_entity_at_world_pos, hovered_task_area_border_entity,
+};
+use super::state::{HoveredEntity, SelectionBoxDrag, SelectionWriter};
+
+/// これ未満の辺しかないドラッグは範囲選択ではなくクリックとして扱う（ワールド px）。
+const BOX_SELECTION_MIN_EXTENT: f32 = 4.0;
 
 type SelectionTargetQuery<'w, 's> = Query<
     'w,
@@ -50,6 +55,12 @@ pub struct SelectionWorldQueries<'w, 's> {
     pub q_targets: SelectionTargetQuery<'w, 's>,
 }
 
+#[derive(SystemParam)]
+pub struct SelectionState<'w> {
+    pub selection: SelectionWriter<'w>,
+    pub box_drag: ResMut<'w, SelectionBoxDrag>,
+}
+
 #[derive(SystemParam)]
 pub struct HoverUpdateParams<'w, 's> {
     pub ui_input_state: Res<'w, UiInputState>,
@@ -62,6 +73,9 @@ pub struct HoverUpdateParams<'w, 's> {
 }
 
 /// Determines the SelectionIntent for a left-click at `world_pos`.
+///
+/// With `additive` (Shift held) a hit toggles the entity in the multi-selection and a press on
+/// empty ground starts a box selection instead of clearing.
 fn resolve_left_click_intent(
     world_pos: Vec2,
     current_selected: Option<Entity>,
@@ -89,6 +103,16 @@ fn resolve_left_click_intent(
     }
 }
 
+/// Shift 押下中の左クリックを複数選択向けに読み替える。
+/// 対象上なら選択への追加・除外、何もない地面なら範囲選択の開始になる。
+fn additive_left_click_intent(intent: SelectionIntent, world_pos: Vec2) -> SelectionIntent {
+    match intent {
+        SelectionIntent::Select(entity) => SelectionIntent::ToggleSelection(entity),
+        SelectionIntent::ClearSelection => SelectionIntent::BeginBoxSelection { origin: world_pos },
+        other => other,
+    }
+}
+
 /// Returns whether the current pointer position is on a task-area border that would start
 /// `AreaSelection` when the normal world-selection ingress handles the same press.
 pub(crate) fn pointer_hits_task_area_border(
@@ -136,7 +160,7 @@ fn resolve_right_click_intent(
 pub fn handle_mouse_input(
     input: SelectionInput,
     world_queries: SelectionWorldQueries,
-    mut selected_entity: ResMut<SelectedEntity>,
+    mut selection: SelectionState,
     mut next_play_mode: ResMut<NextState<PlayMode>>,
     mut task_context: ResMut<TaskContext>,
     mut q_dest: Query<&mut Destination>,
@@ -156,6 +180,7 @@ pub fn handle_mouse_input(
         q_targets,
     } = world_queries;
     if ui_input_state.world_input_blocked() || resolved_frame.pointer_selection_suppressed() {
+        selection.box_drag.origin = None;
         return;
     }
 
@@ -163,19 +188,43 @@ pub fn handle_mouse_input(
         return;
     };
 
+    if selection.box_drag.origin.is_some() {
+        selection.box_drag.current = world_pos;
+        if !buttons.pressed(MouseButton::Left) {
+            let intent = resolve_box_selection_intent(
+                &selection.box_drag,
+                &q_souls,
+                &q_familiars,
+                &q_stockpile_cells,
+                &q_targets,
+            );
+            selection.box_drag.origin = None;
+            apply_selection_intent(
+                intent,
+                &mut selection,
+                &mut next_play_mode,
+                &mut task_context,
+                &mut q_dest,
+            );
+        }
+    }
+
     if buttons.just_pressed(MouseButton::Left) {
-        let intent = resolve_left_click_intent(
+        let mut intent = resolve_left_click_intent(
             world_pos,
-            selected_entity.0,
+            selection.selection.primary(),
             &q_souls,
             &q_familiars,
             &q_task_areas,
             &q_stockpile_cells,
             &q_targets,
         );
+        if resolved_frame.modifiers.shift {
+            intent = additive_left_click_intent(intent, world_pos);
+        }
         apply_selection_intent(
             intent,
-            &mut selected_entity,
+            &mut selection,
             &mut next_play_mode,
             &mut task_context,
             &mut q_dest,
@@ -185,7 +234,7 @@ pub fn handle_mouse_input(
     if buttons.just_pressed(MouseButton::Right) {
         let intent = resolve_right_click_intent(
             world_pos,
-            selected_entity.0,
+            selection.selection.primary(),
             &q_souls,
             &q_familiars,
             &q_stockpile_cells,
@@ -193,7 +242,7 @@ pub fn handle_mouse_input(
         );
         apply_selection_intent(
             intent,
-            &mut selected_entity,
+            &mut selection,
             &mut next_play_mode,
             &mut task_context,
             &mut q_dest,
@@ -201,23 +250,49 @@ pub fn handle_mouse_input(
     }
 }
 
+/// Determines the SelectionIntent when a box-selection drag is released.
+fn resolve_box_selection_intent(
+    drag: &SelectionBoxDrag,
+    q_souls: &Query<(Entity, &GlobalTransform), With<DamnedSoul>>,
+    q_familiars: &Query<(Entity, &GlobalTransform), With<Familiar>>,
+    q_stockpile_cells: &ManagedStockpileQuery,
+    q_targets: &SelectionTargetQuery,
+) -> SelectionIntent {
+    let Some((min, max)) = drag.bounds() else {
+        return SelectionIntent::None;
+    };
+    let extent = max - min;
+    if extent.x < BOX_SELECTION_MIN_EXTENT && extent.y < BOX_SELECTION_MIN_EXTENT {
+        return SelectionIntent::None;
+    }
+    let entities =
+        entities_in_world_rect(min, max, q_souls, q_familiars, q_stockpile_cells, q_targets);
+    if entities.is_empty() {
+        SelectionIntent::None
+    } else {
+        SelectionIntent::AddToSelection(entities)
+    }
+}
+
 /// Applies a `SelectionIntent` to ECS state. This is the root-side adapter.
 fn apply_selection_intent(
     intent: SelectionIntent,
-    selected_entity: &mut SelectedEntity,
+    selection: &mut SelectionState,
     next_play_mode: &mut NextState<PlayMode>,
     task_context: &mut TaskContext,
     q_dest: &mut Query<&mut Destination>,
 ) {
     match intent {
-        SelectionIntent::Select(entity) => {
-            selected_entity.0 = Some(entity);
-        }
-        SelectionIntent::ClearSelection => {
-            selected_entity.0 = None;
+        SelectionIntent::Select(entity) => selection.selection.select(Some(entity)),
+        SelectionIntent::ToggleSelection(entity) => selection.selection.toggle(entity),
+        SelectionIntent::AddToSelection(entities) => selection.selection.extend(entities),
+        SelectionIntent::BeginBoxSelection { origin } => {
+            selection.box_drag.origin = Some(origin);
+            selection.box_drag.current = origin;
         }
+        SelectionIntent::ClearSelection => selection.selection.select(None),
         SelectionIntent::StartAreaSelection { familiar } => {
-            selected_entity.0 = Some(familiar);
+            selection.selection.select(Some(familiar));
             task_context.0 = TaskMode::AreaSelection(None);
             next_play_mode.set(PlayMode::TaskDesignation);
         }
@@ -288,6 +363,7 @@ pub fn update_hover_entity(
 mod tests {
     use super::*;
     use crate::input_actions::{InputAction, InputModifiers, ResolvedInputFrame};
+    use crate::interface::selection::{SelectedEntities, SelectedEntity};
     use crate::test_support::minimal_app;
     use bevy::camera::{ComputedCameraValues, RenderTargetInfo};
     use bevy::window::{PrimaryWindow, WindowResolution};
@@ -298,6 +374,8 @@ mod tests {
             .init_resource::<UiInputState>()
             .init_resource::<ResolvedInputFrame>()
             .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
+            .init_resource::<SelectionBoxDrag>()
             .init_resource::<TaskContext>()
             .init_resource::<NextState<PlayMode>>()
             .add_systems(Update, handle_mouse_input);
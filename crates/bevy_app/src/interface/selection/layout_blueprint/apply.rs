@@ -0,0 +1,245 @@
+use super::super::building_place::{
+    PlacementQueries, place_building_blueprint, place_tank_with_bucket_storage,
+};
+use super::super::floor_place::{
+    apply_floor_placement, apply_wall_placement, validate_floor_tile, validate_wall_tile,
+    validate_wall_tile_no_floor_check,
+};
+use crate::assets::GameAssets;
+use crate::systems::command::TaskArea;
+use crate::systems::command::zone_placement::try_spawn_stockpile_cell;
+use crate::systems::layout_blueprint::{LayoutBlueprint, LayoutBuildingKind};
+use crate::world::map::WorldMap;
+use bevy::prelude::*;
+use hw_core::constants::{FLOOR_MAX_AREA_SIZE, TILE_SIZE};
+use hw_logistics::StockpilePolicy;
+use hw_ui::selection::{
+    AreaPlacementPlan, PlacementGeometry, PlacementRejectReason, PlacementTileRejection,
+};
+use hw_world::zones::Yard;
+use std::collections::{BTreeMap, HashSet};
+
+pub(super) struct LayoutApplyContext<'a, 'w, 's> {
+    pub game_assets: &'a GameAssets,
+    pub placement: PlacementQueries<'a, 'w, 's>,
+    pub q_yard_entities: &'a Query<'w, 's, (Entity, &'static Yard)>,
+    pub existing_floor_tile_grids: HashSet<(i32, i32)>,
+    pub existing_floor_building_grids: HashSet<(i32, i32)>,
+    pub bypass_floor_check: bool,
+}
+
+/// 設計図を `origin` を左下の角として配置する。
+///
+/// 各要素は通常の配置と同じ検証を通り、置けない要素だけを飛ばす。
+/// 戻り値の `valid_tiles` は配置できた要素の基準セル、`total_tile_count` は要素数。
+pub(super) fn apply_layout_blueprint(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    context: &LayoutApplyContext<'_, '_, '_>,
+    blueprint: &LayoutBlueprint,
+    origin: (i32, i32),
+) -> AreaPlacementPlan {
+    let at = |(x, y): (i32, i32)| (origin.0 + x, origin.1 + y);
+    let mut placed = Vec::new();
+    let mut first_reject = None;
+    let mut record = |grid, outcome: Result<(), PlacementTileRejection>| match outcome {
+        Ok(()) => placed.push(grid),
+        Err(rejection) => {
+            first_reject.get_or_insert(rejection);
+        }
+    };
+
+    for building in &blueprint.buildings {
+        let anchor = at(building.offset);
+        let outcome =
+            if building.kind == LayoutBuildingKind::Tank && !building.bucket_storage.is_empty() {
+                let companion =
+                    companion_geometry(building.bucket_storage.iter().map(|&cell| at(cell)));
+                place_tank_with_bucket_storage(
+                    commands,
+                    world_map,
+                    context.game_assets,
+                    anchor,
+                    companion,
+                    &context.placement,
+                )
+            } else {
+                place_building_blueprint(
+                    commands,
+                    world_map,
+                    context.game_assets,
+                    building.kind.building_type(),
+                    anchor,
+                    &context.placement,
+                )
+                .map(|_| ())
+            };
+        record(anchor, outcome);
+    }
+
+    for tiles in chunk_cells(blueprint.floors.iter().map(|&cell| at(cell)), origin).into_values() {
+        let plan = tile_plan(&tiles, |(gx, gy)| {
+            validate_floor_tile(
+                gx,
+                gy,
+                world_map,
+                &context.existing_floor_tile_grids,
+                &context.existing_floor_building_grids,
+            )
+        });
+        if !plan.valid_tiles.is_empty() {
+            apply_floor_placement(commands, &bounding_area(&plan.valid_tiles), &plan);
+        }
+        record_plan(&plan, &mut record);
+    }
+
+    for tiles in chunk_cells(blueprint.walls.iter().map(|&cell| at(cell)), origin).into_values() {
+        let plan = tile_plan(&tiles, |(gx, gy)| {
+            if context.bypass_floor_check {
+                validate_wall_tile_no_floor_check(gx, gy, world_map)
+            } else {
+                validate_wall_tile(gx, gy, world_map, &context.existing_floor_building_grids)
+            }
+        });
+        if !plan.valid_tiles.is_empty() {
+            apply_wall_placement(
+                commands,
+                world_map,
+                &bounding_area(&plan.valid_tiles),
+                &plan,
+            );
+        }
+        record_plan(&plan, &mut record);
+    }
+
+    for stockpile in &blueprint.stockpiles {
+        let grid = at(stockpile.offset);
+        let outcome = try_spawn_stockpile_cell(
+            commands,
+            world_map,
+            grid,
+            StockpilePolicy::from(&stockpile.policy),
+            context.q_yard_entities,
+        )
+        .map(|_| ())
+        .map_err(|reason| PlacementTileRejection { grid, reason });
+        record(grid, outcome);
+    }
+
+    AreaPlacementPlan {
+        valid_tiles: placed,
+        total_tile_count: blueprint.piece_count(),
+        first_reject,
+    }
+}
+
+fn record_plan(
+    plan: &AreaPlacementPlan,
+    record: &mut impl FnMut((i32, i32), Result<(), PlacementTileRejection>),
+) {
+    for &grid in &plan.valid_tiles {
+        record(grid, Ok(()));
+    }
+    if let Some(rejection) = plan.first_reject.clone() {
+        record(rejection.grid, Err(rejection));
+    }
+}
+
+fn tile_plan(
+    tiles: &[(i32, i32)],
+    mut validate_tile: impl FnMut((i32, i32)) -> Option<PlacementRejectReason>,
+) -> AreaPlacementPlan {
+    let mut valid_tiles = Vec::with_capacity(tiles.len());
+    let mut first_reject = None;
+    for &grid in tiles {
+        match validate_tile(grid) {
+            Some(reason) => {
+                first_reject.get_or_insert(PlacementTileRejection { grid, reason });
+            }
+            None => valid_tiles.push(grid),
+        }
+    }
+    AreaPlacementPlan {
+        valid_tiles,
+        total_tile_count: tiles.len(),
+        first_reject,
+    }
+}
+
+/// 床・壁の施工予定を 1 サイトの上限（`FLOOR_MAX_AREA_SIZE` 四方）ごとに分ける。
+fn chunk_cells(
+    cells: impl Iterator<Item = (i32, i32)>,
+    origin: (i32, i32),
+) -> BTreeMap<(i32, i32), Vec<(i32, i32)>> {
+    let mut chunks: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for (gx, gy) in cells {
+        let key = (
+            (gx - origin.0).div_euclid(FLOOR_MAX_AREA_SIZE),
+            (gy - origin.1).div_euclid(FLOOR_MAX_AREA_SIZE),
+        );
+        chunks.entry(key).or_default().push((gx, gy));
+    }
+    chunks
+}
+
+fn cell_bounds(cells: &[(i32, i32)]) -> ((i32, i32), (i32, i32)) {
+    cells
+        .iter()
+        .fold((cells[0], cells[0]), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        })
+}
+
+fn bounding_area(cells: &[(i32, i32)]) -> TaskArea {
+    let (min, max) = cell_bounds(cells);
+    let half = Vec2::splat(TILE_SIZE * 0.5);
+    TaskArea::from_points(
+        WorldMap::grid_to_world(min.0, min.1) - half,
+        WorldMap::grid_to_world(max.0, max.1) + half,
+    )
+}
+
+/// 回転後の BucketStorage セルから配置ジオメトリを組み立てる。
+fn companion_geometry(cells: impl Iterator<Item = (i32, i32)>) -> PlacementGeometry {
+    let occupied_grids: Vec<_> = cells.collect();
+    let (min, max) = cell_bounds(&occupied_grids);
+    let min_pos = WorldMap::grid_to_world(min.0, min.1);
+    let max_pos = WorldMap::grid_to_world(max.0, max.1);
+    PlacementGeometry {
+        occupied_grids,
+        draw_pos: (min_pos + max_pos) * 0.5,
+        size: (max_pos - min_pos) + Vec2::splat(TILE_SIZE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction_cells_are_split_into_site_sized_chunks() {
+        let origin = (5, 5);
+        let cells = (0..12).map(|dx| (origin.0 + dx, origin.1));
+        let chunks = chunk_cells(cells, origin);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[&(0, 0)].len(), FLOOR_MAX_AREA_SIZE as usize);
+        assert_eq!(chunks[&(1, 0)], vec![(15, 5), (16, 5)]);
+    }
+
+    #[test]
+    fn rotated_companion_geometry_matches_the_horizontal_default() {
+        let expected = hw_ui::selection::bucket_storage_geometry((3, 4));
+        let horizontal = companion_geometry([(3, 4), (4, 4)].into_iter());
+        assert_eq!(horizontal.occupied_grids, expected.occupied_grids);
+        assert_eq!(horizontal.size, expected.size);
+        assert!(horizontal.draw_pos.abs_diff_eq(expected.draw_pos, 1e-3));
+
+        let vertical = companion_geometry([(3, 4), (3, 5)].into_iter());
+        assert_eq!(vertical.size, Vec2::new(TILE_SIZE, TILE_SIZE * 2.0));
+        assert!(vertical.draw_pos.abs_diff_eq(
+            WorldMap::grid_to_world(3, 4) + Vec2::new(0.0, TILE_SIZE * 0.5),
+            1e-3
+        ));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::systems::jobs::floor_construction::FloorTileBlueprint;
+use crate::systems::jobs::wall_construction::WallTileBlueprint;
+use crate::systems::jobs::{Blueprint, Building, BuildingType};
+use crate::systems::layout_blueprint::{
+    LayoutBlueprint, LayoutBuilding, LayoutBuildingKind, LayoutStockpile,
+};
+use crate::systems::logistics::{BelongsTo, BucketStorage, PendingBelongsToBlueprint, Stockpile};
+use crate::world::map::WorldMap;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_logistics::StockpilePolicy;
+use hw_ui::selection::{move_anchor_grid, move_occupied_grids};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+type CaptureBucketStorageQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        Option<&'static BelongsTo>,
+        Option<&'static PendingBelongsToBlueprint>,
+    ),
+    With<BucketStorage>,
+>;
+
+type CaptureStockpileQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static StockpilePolicy),
+    (With<Stockpile>, Without<BucketStorage>),
+>;
+
+#[derive(SystemParam)]
+pub struct LayoutCaptureQueries<'w, 's> {
+    q_blueprints: Query<'w, 's, (Entity, &'static Blueprint)>,
+    q_buildings: Query<'w, 's, (Entity, &'static Building, &'static Transform)>,
+    q_bucket_storages: CaptureBucketStorageQuery<'w, 's>,
+    q_floor_tiles: Query<'w, 's, &'static FloorTileBlueprint>,
+    q_wall_tiles: Query<'w, 's, &'static WallTileBlueprint>,
+    q_stockpiles: CaptureStockpileQuery<'w, 's>,
+}
+
+/// 矩形 `[min_grid, max_grid]` 内の建物・施工予定・Stockpile セルを設計図にまとめる。
+///
+/// 建物は占有セルがすべて範囲内にあるものだけを含める。Tank の付属 BucketStorage は
+/// 範囲外にあっても親と一緒に書き出す（配置時に親と切り離せないため）。
+pub(super) fn capture_layout(
+    queries: &LayoutCaptureQueries,
+    min_grid: (i32, i32),
+    max_grid: (i32, i32),
+) -> LayoutBlueprint {
+    let in_area = |(gx, gy): (i32, i32)| {
+        (min_grid.0..=max_grid.0).contains(&gx) && (min_grid.1..=max_grid.1).contains(&gy)
+    };
+
+    let mut buildings = Vec::new();
+    let mut building_index_by_entity = HashMap::new();
+    let mut floors = BTreeSet::new();
+    let mut walls = BTreeSet::new();
+
+    let placed = queries
+        .q_blueprints
+        .iter()
+        .map(|(entity, blueprint)| (entity, blueprint.kind, blueprint.occupied_grids.clone()))
+        .chain(
+            queries
+                .q_buildings
+                .iter()
+                .map(|(entity, building, transform)| {
+                    let anchor = move_anchor_grid(building.kind, transform.translation.truncate());
+                    (
+                        entity,
+                        building.kind,
+                        move_occupied_grids(building.kind, anchor),
+                    )
+                }),
+        );
+    for (entity, kind, occupied_grids) in placed {
+        if occupied_grids.is_empty() || !occupied_grids.iter().all(|&grid| in_area(grid)) {
+            continue;
+        }
+        match kind {
+            BuildingType::Floor => floors.extend(occupied_grids),
+            BuildingType::Wall => walls.extend(occupied_grids),
+            _ => {
+                let Some(kind) = LayoutBuildingKind::from_building_type(kind) else {
+                    continue;
+                };
+                let offset = occupied_grids
+                    .iter()
+                    .copied()
+                    .reduce(|min, (x, y)| (min.0.min(x), min.1.min(y)))
+                    .expect("occupied grids checked non-empty");
+                building_index_by_entity.insert(entity, buildings.len());
+                buildings.push(LayoutBuilding {
+                    kind,
+                    offset,
+                    bucket_storage: Vec::new(),
+                });
+            }
+        }
+    }
+
+    for (transform, belongs_to, pending) in queries.q_bucket_storages.iter() {
+        let owner = belongs_to
+            .map(|belongs_to| belongs_to.0)
+            .or(pending.map(|pending| pending.0));
+        let Some(&index) = owner.and_then(|owner| building_index_by_entity.get(&owner)) else {
+            continue;
+        };
+        if buildings[index].kind != LayoutBuildingKind::Tank {
+            continue;
+        }
+        buildings[index]
+            .bucket_storage
+            .push(WorldMap::world_to_grid(transform.translation.truncate()));
+    }
+
+    floors.extend(
+        queries
+            .q_floor_tiles
+            .iter()
+            .map(|tile| tile.grid_pos)
+            .filter(|&grid| in_area(grid)),
+    );
+    walls.extend(
+        queries
+            .q_wall_tiles
+            .iter()
+            .map(|tile| tile.grid_pos)
+            .filter(|&grid| in_area(grid)),
+    );
+
+    let stockpiles: BTreeMap<_, _> = queries
+        .q_stockpiles
+        .iter()
+        .map(|(transform, policy)| {
+            (
+                WorldMap::world_to_grid(transform.translation.truncate()),
+                *policy,
+            )
+        })
+        .filter(|&(grid, _)| in_area(grid))
+        .collect();
+
+    LayoutBlueprint::from_absolute(
+        buildings,
+        floors.into_iter().collect(),
+        walls.into_iter().collect(),
+        stockpiles
+            .into_iter()
+            .map(|(offset, policy)| LayoutStockpile {
+                offset,
+                policy: policy.into(),
+            })
+            .collect(),
+    )
+}
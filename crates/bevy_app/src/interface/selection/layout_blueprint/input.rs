@@ -0,0 +1,297 @@
+use super::super::building_place::{BuildingStateQueries, PlacementQueries};
+use super::super::floor_place::existing_floor_building_grids;
+use super::apply::{LayoutApplyContext, apply_layout_blueprint};
+use super::capture::{LayoutCaptureQueries, capture_layout};
+use crate::app_contexts::TaskContext;
+use crate::assets::GameAssets;
+use crate::input_actions::{InputAction, ResolvedInputFrame};
+use crate::interface::ui::UiInputState;
+use crate::systems::command::{TaskArea, TaskMode};
+use crate::systems::jobs::Building;
+use crate::systems::jobs::floor_construction::FloorTileBlueprint;
+use crate::systems::layout_blueprint::{
+    ActiveLayoutBlueprint, LAYOUT_BLUEPRINT_DIR, LayoutBlueprint, latest_layout_blueprint_path,
+    read_layout_blueprint, write_layout_blueprint,
+};
+use crate::world::map::{WorldMap, WorldMapWrite};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::time::Real;
+use bevy::window::PrimaryWindow;
+use hw_core::game_state::PlayMode;
+use hw_core::locale::Localization;
+use hw_ui::camera::MainCamera;
+use hw_ui::notifications::{NotificationRetention, NotificationSeverity, UserFacingNotification};
+use hw_ui::selection::PlacementFeedbackState;
+use hw_world::zones::Yard;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(SystemParam)]
+pub struct LayoutBlueprintInput<'w, 's> {
+    buttons: Res<'w, ButtonInput<MouseButton>>,
+    q_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    q_camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+    ui_input_state: Res<'w, UiInputState>,
+    task_context: ResMut<'w, TaskContext>,
+    next_play_mode: ResMut<'w, NextState<PlayMode>>,
+}
+
+impl LayoutBlueprintInput<'_, '_> {
+    fn cancel_on_right_click(&mut self) -> bool {
+        if !self.buttons.just_pressed(MouseButton::Right) {
+            return false;
+        }
+        self.exit_mode();
+        true
+    }
+
+    fn exit_mode(&mut self) {
+        self.task_context.0 = TaskMode::None;
+        self.next_play_mode.set(PlayMode::Normal);
+    }
+}
+
+#[derive(SystemParam)]
+pub struct LayoutImportRuntime<'w, 's> {
+    game_assets: Res<'w, GameAssets>,
+    building_queries: BuildingStateQueries<'w, 's>,
+    q_yard_entities: Query<'w, 's, (Entity, &'static Yard)>,
+    q_existing_floor_tiles: Query<'w, 's, &'static FloorTileBlueprint>,
+    q_floor_buildings: Query<'w, 's, (&'static Building, &'static Transform)>,
+    debug_instant_build: Res<'w, crate::DebugInstantBuild>,
+    placement_feedback: ResMut<'w, PlacementFeedbackState>,
+    real_time: Res<'w, Time<Real>>,
+    localization: Res<'w, Localization>,
+}
+
+fn layout_notification(
+    key: &'static str,
+    severity: NotificationSeverity,
+    title: impl Into<String>,
+) -> UserFacingNotification {
+    UserFacingNotification::new(
+        key.to_string(),
+        severity,
+        title,
+        "",
+        NotificationRetention::ToastOnly,
+    )
+}
+
+/// 範囲ドラッグで設計図を `blueprints/layout-<unix秒>.ron` へ書き出す。
+pub fn layout_export_system(
+    mut input: LayoutBlueprintInput,
+    queries: LayoutCaptureQueries,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    if input.ui_input_state.world_input_blocked() {
+        return;
+    }
+
+    let TaskMode::LayoutExport(start_pos_opt) = input.task_context.0 else {
+        return;
+    };
+
+    let Some(world_pos) = hw_ui::camera::world_cursor_pos(&input.q_window, &input.q_camera) else {
+        return;
+    };
+    let snapped_pos = WorldMap::snap_to_grid_edge(world_pos);
+
+    if input.buttons.just_pressed(MouseButton::Left) {
+        input.task_context.0 = TaskMode::LayoutExport(Some(snapped_pos));
+        return;
+    }
+
+    if input.buttons.just_released(MouseButton::Left) {
+        if let Some(start_pos) = start_pos_opt {
+            let area = TaskArea::from_points(start_pos, snapped_pos);
+            let min_grid = WorldMap::world_to_grid(area.min() + Vec2::splat(0.1));
+            let max_grid = WorldMap::world_to_grid(area.max() - Vec2::splat(0.1));
+            let blueprint = capture_layout(&queries, min_grid, max_grid);
+            notifications.write(export_layout(&blueprint, &localization));
+            input.task_context.0 = TaskMode::LayoutExport(None);
+        }
+        return;
+    }
+
+    let _ = input.cancel_on_right_click();
+}
+
+fn export_layout(blueprint: &LayoutBlueprint, text: &Localization) -> UserFacingNotification {
+    if blueprint.is_empty() {
+        return layout_notification(
+            "layout-blueprint:export-empty",
+            NotificationSeverity::Warning,
+            text.text("notify.layout.export_empty"),
+        );
+    }
+    let name = format!(
+        "layout-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default()
+    );
+    match write_layout_blueprint(Path::new(LAYOUT_BLUEPRINT_DIR), &name, blueprint) {
+        Ok(path) => {
+            info!(
+                "LAYOUT: exported {} pieces to {}",
+                blueprint.piece_count(),
+                path.display()
+            );
+            layout_notification(
+                "layout-blueprint:exported",
+                NotificationSeverity::Success,
+                text.format("notify.layout.exported", &[("path", &path.display())]),
+            )
+        }
+        Err(err) => {
+            warn!("LAYOUT: export failed: {err}");
+            layout_notification(
+                "layout-blueprint:export-failed",
+                NotificationSeverity::Error,
+                text.format("notify.layout.export_failed", &[("error", &err)]),
+            )
+        }
+    }
+}
+
+/// 最新の設計図を読み込み、クリック位置を左下の角として配置する。
+///
+/// モード外では読み込み済みの設計図を破棄し、次に入ったときに最新ファイルを読み直す。
+pub fn layout_import_system(
+    mut input: LayoutBlueprintInput,
+    resolved_frame: Res<ResolvedInputFrame>,
+    mut active_layout: ResMut<ActiveLayoutBlueprint>,
+    mut runtime: LayoutImportRuntime,
+    mut world_map: WorldMapWrite,
+    mut commands: Commands,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    if !matches!(input.task_context.0, TaskMode::LayoutImport) {
+        if active_layout.blueprint.is_some() {
+            *active_layout = ActiveLayoutBlueprint::default();
+        }
+        return;
+    }
+
+    if active_layout.blueprint.is_none() {
+        match load_latest_layout(&runtime.localization) {
+            Ok(loaded) => *active_layout = loaded,
+            Err(message) => {
+                warn!("LAYOUT: {message}");
+                notifications.write(layout_notification(
+                    "layout-blueprint:import-failed",
+                    NotificationSeverity::Warning,
+                    message,
+                ));
+                input.exit_mode();
+                return;
+            }
+        }
+    }
+
+    if resolved_frame.contains(InputAction::RotateLayout) {
+        active_layout.rotation = active_layout.rotation.next();
+    }
+
+    if input.ui_input_state.world_input_blocked() {
+        return;
+    }
+
+    if input.cancel_on_right_click() || !input.buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(world_pos) = hw_ui::camera::world_cursor_pos(&input.q_window, &input.q_camera) else {
+        return;
+    };
+    let Some(blueprint) = active_layout.rotated() else {
+        return;
+    };
+    let origin = WorldMap::world_to_grid(world_pos);
+
+    let queries = &runtime.building_queries;
+    let context = LayoutApplyContext {
+        game_assets: &runtime.game_assets,
+        placement: PlacementQueries {
+            q_buildings: &queries.q_buildings,
+            q_blueprints_by_entity: &queries.q_blueprints_by_entity,
+            q_sites: &queries.q_sites,
+            q_yards: &queries.q_yards,
+        },
+        q_yard_entities: &runtime.q_yard_entities,
+        existing_floor_tile_grids: runtime
+            .q_existing_floor_tiles
+            .iter()
+            .map(|tile| tile.grid_pos)
+            .collect(),
+        existing_floor_building_grids: existing_floor_building_grids(&runtime.q_floor_buildings),
+        bypass_floor_check: runtime.debug_instant_build.0,
+    };
+    let plan = apply_layout_blueprint(&mut commands, &mut world_map, &context, &blueprint, origin);
+
+    let now = runtime.real_time.elapsed();
+    if plan.valid_tiles.is_empty() {
+        if let Some(feedback) = plan.feedback() {
+            warn!(
+                "Layout placement rejected: {:?} at {:?}",
+                feedback.reason, feedback.target_grid
+            );
+            runtime
+                .placement_feedback
+                .show_recent_failure(feedback, now);
+        }
+        return;
+    }
+    runtime.placement_feedback.clear_recent_failure();
+    info!(
+        "LAYOUT: placed {}/{} pieces at {:?} ({}°)",
+        plan.valid_tiles.len(),
+        plan.total_tile_count,
+        origin,
+        active_layout.rotation.degrees()
+    );
+    if plan.rejected_tile_count() > 0 {
+        notifications.write(layout_notification(
+            "layout-blueprint:partial",
+            NotificationSeverity::Info,
+            runtime.localization.format(
+                "notify.layout.partial",
+                &[
+                    ("placed", &plan.valid_tiles.len()),
+                    ("total", &plan.total_tile_count),
+                ],
+            ),
+        ));
+    }
+}
+
+fn load_latest_layout(text: &Localization) -> Result<ActiveLayoutBlueprint, String> {
+    let path = latest_layout_blueprint_path(Path::new(LAYOUT_BLUEPRINT_DIR))
+        .map_err(|err| text.format("notify.layout.unreadable", &[("error", &err)]))?
+        .ok_or_else(|| {
+            text.format(
+                "notify.layout.none",
+                &[("dir", &format!("{LAYOUT_BLUEPRINT_DIR}/"))],
+            )
+        })?;
+    let blueprint = read_layout_blueprint(&path).map_err(|err| {
+        text.format(
+            "notify.layout.load_failed",
+            &[("path", &path.display()), ("error", &err)],
+        )
+    })?;
+    info!(
+        "LAYOUT: loaded {} ({} pieces)",
+        path.display(),
+        blueprint.piece_count()
+    );
+    Ok(ActiveLayoutBlueprint {
+        source: Some(path),
+        blueprint: Some(blueprint),
+        rotation: Default::default(),
+    })
+}
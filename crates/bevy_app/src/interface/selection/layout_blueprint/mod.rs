@@ -0,0 +1,10 @@
+//! Layout blueprint export / import (root shell)
+//!
+//! Root shell: ECS からの範囲キャプチャと、Blueprint / 施工予定 / Stockpile の spawn に依存。
+//! 設計図のデータ構造とファイル入出力は `crate::systems::layout_blueprint` を参照。
+
+mod apply;
+mod capture;
+mod input;
+
+pub use input::{layout_export_system, layout_import_system};
@@ -3,13 +3,15 @@ pub(crate) mod building_place;
 mod floor_place;
 mod hit_test;
 mod input;
+mod layout_blueprint;
 mod mode;
 pub(crate) mod soul_spa_place;
 mod state {
     pub use hw_ui::selection::{
-        HoveredEntity, SelectedEntity, SelectionIndicator, cleanup_selection_references_system,
+        HoveredEntity, SelectedEntities, SelectedEntity, SelectionBoxDrag, SelectionIndicator,
+        SelectionWriter, cleanup_selection_references_system,
     };
-    pub use hw_visual::update_selection_indicator;
+    pub use hw_visual::{update_selection_box_visual, update_selection_indicator};
 }
 
 pub use building_move::{building_move_preview_system, building_move_system};
@@ -17,9 +19,11 @@ pub use building_place::blueprint_placement;
 pub use floor_place::{floor_placement_preview_system, floor_placement_system};
 pub(crate) use input::pointer_hits_task_area_border;
 pub use input::{handle_mouse_input, update_hover_entity};
+pub use layout_blueprint::{layout_export_system, layout_import_system};
 pub use mode::clear_companion_state_outside_build_mode;
 pub use soul_spa_place::soul_spa_place_input_system;
 pub use state::{
-    HoveredEntity, SelectedEntity, SelectionIndicator, cleanup_selection_references_system,
+    HoveredEntity, SelectedEntities, SelectedEntity, SelectionBoxDrag, SelectionIndicator,
+    SelectionWriter, cleanup_selection_references_system, update_selection_box_visual,
     update_selection_indicator,
 };
@@ -137,6 +137,64 @@ pub fn toggle_rtt_scene_objects_button_system(
     }
 }
 
+/// ヒートマップボタンのクリックで表示レイヤーを切り替える
+pub fn cycle_heatmap_layer_button_system(
+    q_button: Query<&Interaction, (Changed<Interaction>, With<HeatmapLayerButton>)>,
+    mut overlay: ResMut<HeatmapOverlay>,
+) {
+    for interaction in q_button.iter() {
+        if *interaction == Interaction::Pressed {
+            overlay.cycle();
+        }
+    }
+}
+
+/// CSV ボタンのクリックで現在のヒートマップ集計を書き出し、結果をトーストで通知する
+pub fn export_heatmap_csv_button_system(
+    q_button: Query<&Interaction, (Changed<Interaction>, With<ExportHeatmapCsvButton>)>,
+    heatmap: Res<HeatmapAccumulator>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    if !q_button
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+
+    let (severity, title, body) =
+        match write_heatmap_csv(Path::new(HEATMAP_EXPORT_DIR), &heatmap.totals()) {
+            Ok((tiles, flows)) => {
+                info!(
+                    "HEATMAP: exported {} and {}",
+                    tiles.display(),
+                    flows.display()
+                );
+                (
+                    NotificationSeverity::Success,
+                    localization.text("notify.dev.heatmap_exported"),
+                    format!("{} / {}", tiles.display(), flows.display()),
+                )
+            }
+            Err(error) => {
+                error!("HEATMAP: export failed: {error}");
+                (
+                    NotificationSeverity::Error,
+                    localization.text("notify.dev.heatmap_export_failed"),
+                    error.to_string(),
+                )
+            }
+        };
+    notifications.write(UserFacingNotification::new(
+        "dev-heatmap-export",
+        severity,
+        title,
+        body,
+        NotificationRetention::ToastOnly,
+    ));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +265,18 @@ mod tests {
         );
         assert!(app.world().resource::<InputFocus>().get().is_none());
     }
+
+    #[test]
+    fn heatmap_button_cycles_the_overlay_layer() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HeatmapOverlay>()
+            .add_systems(Update, cycle_heatmap_layer_button_system);
+        app.world_mut()
+            .spawn((Interaction::Pressed, HeatmapLayerButton));
+
+        app.update();
+
+        assert!(app.world().resource::<HeatmapOverlay>().active.is_some());
+    }
 }
@@ -47,3 +47,11 @@ pub struct ToggleRender3dButton;
 /// 即時ビルドトグルボタンのマーカー
 #[derive(Component)]
 pub struct InstantBuildButton;
+
+/// ヒートマップレイヤー切り替えボタンのマーカー
+#[derive(Component)]
+pub struct HeatmapLayerButton;
+
+/// ヒートマップ CSV 出力ボタンのマーカー
+#[derive(Component)]
+pub struct ExportHeatmapCsvButton;
@@ -1,5 +1,17 @@
 use super::*;
 
+/// トグル系ボタンと状態表示の「名前: ON/OFF」
+fn toggle_label(text: &Localization, name_id: &str, enabled: bool) -> String {
+    let state_id = if enabled { "ui.dev.on" } else { "ui.dev.off" };
+    text.format(
+        "ui.dev.toggle",
+        &[
+            ("name", &text.text(name_id)),
+            ("state", &text.text(state_id)),
+        ],
+    )
+}
+
 /// 3D表示ボタンのラベルと色を Render3dVisible に合わせて更新
 pub fn update_render3d_button_visual_system(
     render3d: Res<crate::Render3dVisible>,
@@ -8,8 +20,9 @@ pub fn update_render3d_button_visual_system(
         With<ToggleRender3dButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !render3d.is_changed() {
+    if !render3d.is_changed() && !localization.is_changed() {
         return;
     }
     for (children, mut bg, mut border) in q_button.iter_mut() {
@@ -22,11 +35,7 @@ pub fn update_render3d_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if render3d.0 {
-                    "3D: ON".to_string()
-                } else {
-                    "3D: OFF".to_string()
-                };
+                text.0 = toggle_label(&localization, "ui.dev.render_3d", render3d.0);
             }
         }
     }
@@ -54,9 +63,10 @@ pub fn update_lod_indicator_system(
 pub fn update_render_perf_status_system(
     quality: Res<QualitySettings>,
     perf_toggles: Res<crate::RenderPerfToggles>,
+    localization: Res<Localization>,
     mut q_text: Query<&mut Text, With<RenderPerfStatusText>>,
 ) {
-    if !quality.is_changed() && !perf_toggles.is_changed() {
+    if !quality.is_changed() && !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -65,34 +75,35 @@ pub fn update_render_perf_status_system(
         RttQualityPreset::Medium => "M",
         RttQualityPreset::Low => "L",
     };
-    let mask = if perf_toggles.soul_mask_enabled {
-        "ON"
-    } else {
-        "OFF"
-    };
-    let light = if perf_toggles.directional_light_enabled {
-        "ON"
-    } else {
-        "OFF"
-    };
-    let light2 = if perf_toggles.extra_directional_light_enabled {
-        "ON"
-    } else {
-        "OFF"
-    };
-    let terrain = if perf_toggles.terrain_enabled {
-        "ON"
-    } else {
-        "OFF"
-    };
-    let scene_objects = if perf_toggles.scene_objects_enabled {
-        "ON"
-    } else {
-        "OFF"
-    };
-    let text = format!(
-        "RTT:{rtt} Mask:{mask} Light:{light} Light2:{light2} Terrain:{terrain} Objs:{scene_objects}"
-    );
+    let text = [
+        localization.format("ui.dev.toggle", &[("name", &"RTT"), ("state", &rtt)]),
+        toggle_label(
+            &localization,
+            "ui.dev.soul_mask",
+            perf_toggles.soul_mask_enabled,
+        ),
+        toggle_label(
+            &localization,
+            "ui.dev.light",
+            perf_toggles.directional_light_enabled,
+        ),
+        toggle_label(
+            &localization,
+            "ui.dev.extra_light",
+            perf_toggles.extra_directional_light_enabled,
+        ),
+        toggle_label(
+            &localization,
+            "ui.dev.terrain",
+            perf_toggles.terrain_enabled,
+        ),
+        toggle_label(
+            &localization,
+            "ui.dev.scene_objects",
+            perf_toggles.scene_objects_enabled,
+        ),
+    ]
+    .join(" ");
 
     for mut label in q_text.iter_mut() {
         label.0 = text.clone();
@@ -107,8 +118,9 @@ pub fn update_soul_mask_button_visual_system(
         With<ToggleSoulMaskButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !perf_toggles.is_changed() {
+    if !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -122,11 +134,11 @@ pub fn update_soul_mask_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if perf_toggles.soul_mask_enabled {
-                    "Mask: ON".to_string()
-                } else {
-                    "Mask: OFF".to_string()
-                };
+                text.0 = toggle_label(
+                    &localization,
+                    "ui.dev.soul_mask",
+                    perf_toggles.soul_mask_enabled,
+                );
             }
         }
     }
@@ -140,8 +152,9 @@ pub fn update_rtt_light_button_visual_system(
         With<ToggleRttLightButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !perf_toggles.is_changed() {
+    if !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -155,11 +168,11 @@ pub fn update_rtt_light_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if perf_toggles.directional_light_enabled {
-                    "Light: ON".to_string()
-                } else {
-                    "Light: OFF".to_string()
-                };
+                text.0 = toggle_label(
+                    &localization,
+                    "ui.dev.light",
+                    perf_toggles.directional_light_enabled,
+                );
             }
         }
     }
@@ -173,8 +186,9 @@ pub fn update_rtt_extra_light_button_visual_system(
         With<ToggleRttExtraLightButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !perf_toggles.is_changed() {
+    if !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -188,11 +202,11 @@ pub fn update_rtt_extra_light_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if perf_toggles.extra_directional_light_enabled {
-                    "Light2: ON".to_string()
-                } else {
-                    "Light2: OFF".to_string()
-                };
+                text.0 = toggle_label(
+                    &localization,
+                    "ui.dev.extra_light",
+                    perf_toggles.extra_directional_light_enabled,
+                );
             }
         }
     }
@@ -206,8 +220,9 @@ pub fn update_rtt_terrain_button_visual_system(
         With<ToggleRttTerrainButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !perf_toggles.is_changed() {
+    if !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -221,11 +236,11 @@ pub fn update_rtt_terrain_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if perf_toggles.terrain_enabled {
-                    "Terrain: ON".to_string()
-                } else {
-                    "Terrain: OFF".to_string()
-                };
+                text.0 = toggle_label(
+                    &localization,
+                    "ui.dev.terrain",
+                    perf_toggles.terrain_enabled,
+                );
             }
         }
     }
@@ -239,8 +254,9 @@ pub fn update_rtt_scene_objects_button_visual_system(
         With<ToggleRttSceneObjectsButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !perf_toggles.is_changed() {
+    if !perf_toggles.is_changed() && !localization.is_changed() {
         return;
     }
 
@@ -254,11 +270,11 @@ pub fn update_rtt_scene_objects_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if perf_toggles.scene_objects_enabled {
-                    "Objs: ON".to_string()
-                } else {
-                    "Objs: OFF".to_string()
-                };
+                text.0 = toggle_label(
+                    &localization,
+                    "ui.dev.scene_objects",
+                    perf_toggles.scene_objects_enabled,
+                );
             }
         }
     }
@@ -272,8 +288,9 @@ pub fn update_instant_build_button_visual_system(
         With<InstantBuildButton>,
     >,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
-    if !instant_build.is_changed() {
+    if !instant_build.is_changed() && !localization.is_changed() {
         return;
     }
     for (children, mut bg, mut border) in q_button.iter_mut() {
@@ -286,11 +303,46 @@ pub fn update_instant_build_button_visual_system(
         }
         for child in children.iter() {
             if let Ok(mut text) = q_text.get_mut(child) {
-                text.0 = if instant_build.0 {
-                    "IBuild: ON".to_string()
-                } else {
-                    "IBuild: OFF".to_string()
-                };
+                text.0 = toggle_label(&localization, "ui.dev.instant_build", instant_build.0);
+            }
+        }
+    }
+}
+
+/// ヒートマップボタンのラベルと色を表示中レイヤーに合わせて更新
+pub fn update_heatmap_layer_button_visual_system(
+    overlay: Res<HeatmapOverlay>,
+    mut q_button: Query<
+        (&Children, &mut BackgroundColor, &mut BorderColor),
+        With<HeatmapLayerButton>,
+    >,
+    mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
+) {
+    if !overlay.is_changed() && !localization.is_changed() {
+        return;
+    }
+    for (children, mut bg, mut border) in q_button.iter_mut() {
+        if overlay.active.is_some() {
+            *bg = BackgroundColor(Color::srgb(0.38, 0.16, 0.08));
+            *border = BorderColor::all(Color::srgb(0.66, 0.32, 0.16));
+        } else {
+            *bg = BackgroundColor(Color::srgb(0.18, 0.10, 0.08));
+            *border = BorderColor::all(Color::srgb(0.40, 0.24, 0.18));
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = q_text.get_mut(child) {
+                text.0 = localization.format(
+                    "ui.dev.toggle",
+                    &[
+                        ("name", &localization.text("ui.dev.heatmap")),
+                        (
+                            "state",
+                            &localization
+                                .text(overlay.active.map_or("ui.dev.off", |layer| layer.text_id())),
+                        ),
+                    ],
+                );
             }
         }
     }
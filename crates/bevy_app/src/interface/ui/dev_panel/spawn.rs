@@ -267,6 +267,58 @@ pub fn spawn_dev_panel_system(
                 ));
             });
 
+        parent
+            .spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.18, 0.10, 0.08)),
+                BorderColor::all(Color::srgb(0.40, 0.24, 0.18)),
+                HeatmapLayerButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("Heat: OFF"),
+                    TextFont {
+                        font_size: FontSize::Px(11.0),
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+        parent
+            .spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.14, 0.12, 0.10)),
+                BorderColor::all(Color::srgb(0.34, 0.30, 0.24)),
+                ExportHeatmapCsvButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    LocalizedText("ui.dev.heatmap_csv"),
+                    TextFont {
+                        font_size: FontSize::Px(11.0),
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
         // ── セパレーター ──
         parent.spawn((
             Node {
@@ -253,7 +253,33 @@ coverage_table! {
         },
         "input::list-previous" => unit(ListPrevious) => {
             published("entity-list-selection")
-        }
+        },
+        "input::camera-store-bookmark-1" => unit(CameraStoreBookmark1) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-store-bookmark-2" => unit(CameraStoreBookmark2) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-store-bookmark-3" => unit(CameraStoreBookmark3) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-store-bookmark-4" => unit(CameraStoreBookmark4) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-jump-bookmark-1" => unit(CameraJumpBookmark1) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-jump-bookmark-2" => unit(CameraJumpBookmark2) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-jump-bookmark-3" => unit(CameraJumpBookmark3) => {
+            published("camera-bookmarks")
+        },
+        "input::camera-jump-bookmark-4" => unit(CameraJumpBookmark4) => {
+            published("camera-bookmarks")
+        },
+        "input::toggle-camera-follow" => unit(ToggleCameraFollow) => published("camera-follow"),
+        "input::rotate-layout" => unit(RotateLayout) => published("layout-blueprints")
     }
 }
 
@@ -272,6 +298,8 @@ coverage_table! {
         "ui-intent::orders-toggle" => unit(ToggleOrders) => published("orders-designation"),
         "ui-intent::dream-toggle" => unit(ToggleDream) => published("dream-planting"),
         "ui-intent::settings-toggle" => unit(ToggleSettings) => published("settings"),
+        "ui-intent::statistics-toggle" => unit(ToggleStatistics) => published("statistics"),
+        "ui-intent::alerts-toggle" => unit(ToggleAlerts) => published("alerts"),
         "ui-intent::settings-close" => unit(CloseSettings) => published("settings"),
         "ui-intent::settings-ui-scale" => tuple(SetUiScale(_)) => published("settings"),
         "ui-intent::settings-camera-pan-speed" => tuple(SetCameraPanSpeed(_)) => {
@@ -289,8 +317,24 @@ coverage_table! {
         "ui-intent::settings-fps-display" => tuple(SetFpsDisplayEnabled(_)) => {
             published("settings")
         },
+        "ui-intent::settings-locale" => tuple(SetLocale(_)) => published("settings"),
+        "ui-intent::settings-key-rebind" => tuple(BeginKeyRebind(_)) => published("settings"),
+        "ui-intent::settings-key-reset" => unit(ResetKeyBindings) => published("settings"),
         "ui-intent::inspect-entity" => tuple(InspectEntity(_)) => published("info-panel-pin"),
         "ui-intent::clear-inspect-pin" => unit(ClearInspectPin) => published("info-panel-pin"),
+        "ui-intent::group-familiar-fatigue" => tuple(AdjustSelectedFatigueThreshold(_)) => {
+            published("group-selection")
+        },
+        "ui-intent::group-familiar-max-souls" => tuple(AdjustSelectedMaxControlledSoul(_)) => {
+            published("group-selection")
+        },
+        "ui-intent::group-doors-locked" => tuple(SetSelectedDoorsLocked(_)) => {
+            published("group-selection")
+        },
+        "ui-intent::group-task-priority" => tuple(AdjustSelectedTaskPriority(_)) => {
+            published("group-selection")
+        },
+        "ui-intent::group-task-cancel" => unit(CancelSelectedTasks) => published("group-selection"),
         "ui-intent::select-build" => tuple(SelectBuild(_)) => published("architect-building"),
         "ui-intent::select-floor-place" => unit(SelectFloorPlace) => {
             published("architect-building")
@@ -302,6 +346,9 @@ coverage_table! {
         "ui-intent::remove-yard-zone" => tuple(RemoveZone(ZoneType::Yard)) => {
             unreachable_player_flow()
         },
+        "ui-intent::remove-forbidden-zone" => tuple(RemoveZone(ZoneType::Forbidden)) => {
+            published("forbidden-zones")
+        },
         "ui-intent::task-mode-none" => tuple(SelectTaskMode(TaskMode::None)) => internal(),
         "ui-intent::task-mode-designate-chop" => tuple(SelectTaskMode(TaskMode::DesignateChop(_))) => {
             published("orders-designation")
@@ -335,6 +382,10 @@ coverage_table! {
             TaskModeZoneType::Yard,
             _
         ))) => unreachable_player_flow(),
+        "ui-intent::task-mode-remove-forbidden" => tuple(SelectTaskMode(TaskMode::ZoneRemoval(
+            TaskModeZoneType::Forbidden,
+            _
+        ))) => published("forbidden-zones"),
         "ui-intent::task-mode-floor-place" => tuple(SelectTaskMode(TaskMode::FloorPlace(_))) => {
             published("architect-building")
         },
@@ -350,10 +401,17 @@ coverage_table! {
         "ui-intent::task-mode-soul-spa-place" => tuple(SelectTaskMode(TaskMode::SoulSpaPlace(_))) => {
             published("architect-building")
         },
+        "ui-intent::task-mode-layout-export" => tuple(SelectTaskMode(TaskMode::LayoutExport(_))) => {
+            published("layout-blueprints")
+        },
+        "ui-intent::task-mode-layout-import" => tuple(SelectTaskMode(TaskMode::LayoutImport)) => {
+            published("layout-blueprints")
+        },
         "ui-intent::select-area-task" => unit(SelectAreaTask) => published("orders-designation"),
         "ui-intent::select-dream-planting" => unit(SelectDreamPlanting) => {
             published("dream-planting")
         },
+        "ui-intent::toggle-edict" => tuple(ToggleEdict(_)) => published("dream-edicts"),
         "ui-intent::door-lock" => tuple(ToggleDoorLock(_)) => published("world-selection"),
         "ui-intent::operation-open" => unit(OpenOperationDialog) => published("soul-assignment"),
         "ui-intent::operation-fatigue-threshold" => tuple(AdjustFatigueThreshold(_)) => {
@@ -384,6 +442,12 @@ coverage_table! {
         "ui-intent::stockpile-policy-range" => record(BeginStockpilePolicyRangeEdit { .. }) => {
             published("zones-workflow")
         },
+        "ui-intent::supply-route-source" => tuple(SetSupplyRouteSource(_)) => {
+            published("zones-workflow")
+        },
+        "ui-intent::supply-route-edit" => tuple(EditSupplyRoute(_)) => {
+            published("zones-workflow")
+        },
         "ui-intent::task-priority" => record(AdjustTaskPriority { .. }) => {
             published("task-dashboard-actions")
         },
@@ -462,7 +526,10 @@ coverage_table! {
             published("architect-building")
         },
         "building-type::soul-spa" => unit(SoulSpa) => published("architect-building"),
-        "building-type::outdoor-lamp" => unit(OutdoorLamp) => published("architect-building")
+        "building-type::outdoor-lamp" => unit(OutdoorLamp) => published("architect-building"),
+        "building-type::redemption-shrine" => unit(RedemptionShrine) => {
+            published("soul-redemption")
+        }
     }
 }
 
@@ -555,13 +622,18 @@ coverage_table! {
         "task-mode::remove-yard" => tuple(ZoneRemoval(TaskModeZoneType::Yard, _)) => {
             unreachable_player_flow()
         },
+        "task-mode::remove-forbidden" => tuple(ZoneRemoval(TaskModeZoneType::Forbidden, _)) => {
+            published("forbidden-zones")
+        },
         "task-mode::floor-place" => tuple(FloorPlace(_)) => published("architect-building"),
         "task-mode::wall-place" => tuple(WallPlace(_)) => published("architect-building"),
         "task-mode::dream-planting" => tuple(DreamPlanting(_)) => published("dream-planting"),
         "task-mode::stockpile-policy" => tuple(StockpilePolicyEdit(_)) => {
             published("zones-workflow")
         },
-        "task-mode::soul-spa-place" => tuple(SoulSpaPlace(_)) => published("architect-building")
+        "task-mode::soul-spa-place" => tuple(SoulSpaPlace(_)) => published("architect-building"),
+        "task-mode::layout-export" => tuple(LayoutExport(_)) => published("layout-blueprints"),
+        "task-mode::layout-import" => unit(LayoutImport) => published("layout-blueprints")
     }
 }
 
@@ -571,7 +643,8 @@ coverage_table! {
     fn task_mode_zone_type_decisions();
     {
         "task-mode-zone-type::stockpile" => unit(Stockpile) => published("zones-workflow"),
-        "task-mode-zone-type::yard" => unit(Yard) => published("zones-workflow")
+        "task-mode-zone-type::yard" => unit(Yard) => published("zones-workflow"),
+        "task-mode-zone-type::forbidden" => unit(Forbidden) => published("forbidden-zones")
     }
 }
 
@@ -593,7 +666,8 @@ coverage_table! {
     fn zone_type_decisions();
     {
         "zone-type::stockpile" => unit(Stockpile) => published("zones-workflow"),
-        "zone-type::yard" => unit(Yard) => published("zones-workflow")
+        "zone-type::yard" => unit(Yard) => published("zones-workflow"),
+        "zone-type::forbidden" => unit(Forbidden) => published("forbidden-zones")
     }
 }
 
@@ -639,7 +713,8 @@ coverage_table! {
         "transport-request-kind::return-wheelbarrow" => unit(ReturnWheelbarrow) => internal(),
         "transport-request-kind::batch-wheelbarrow" => unit(BatchWheelbarrow) => internal(),
         "transport-request-kind::consolidate-stockpile" => unit(ConsolidateStockpile) => internal(),
-        "transport-request-kind::deliver-soul-spa" => unit(DeliverToSoulSpa) => internal()
+        "transport-request-kind::deliver-soul-spa" => unit(DeliverToSoulSpa) => internal(),
+        "transport-request-kind::supply-route" => unit(SupplyRoute) => internal()
     }
 }
 
@@ -757,33 +832,45 @@ coverage_table! {
 enum DescriptiveSurface {
     GettingStartedFirstSteps,
     CameraPanZoom,
+    Minimap,
     HelpPauseBehavior,
     SoulEnergyStatus,
     SoulEnergyRecovery,
     SoulRename,
+    SoulRelationships,
+    SoulLifeLog,
     Notifications,
+    KingContracts,
 }
 
 impl DescriptiveSurface {
-    const ALL: [Self; 7] = [
+    const ALL: [Self; 11] = [
         Self::GettingStartedFirstSteps,
         Self::CameraPanZoom,
+        Self::Minimap,
         Self::HelpPauseBehavior,
         Self::SoulEnergyStatus,
         Self::SoulEnergyRecovery,
         Self::SoulRename,
+        Self::SoulRelationships,
+        Self::SoulLifeLog,
         Self::Notifications,
+        Self::KingContracts,
     ];
 
     const fn as_str(self) -> &'static str {
         match self {
             Self::GettingStartedFirstSteps => "descriptive::getting-started-first-steps",
             Self::CameraPanZoom => "descriptive::camera-pan-zoom",
+            Self::Minimap => "descriptive::minimap",
             Self::HelpPauseBehavior => "descriptive::help-pause-behavior",
             Self::SoulEnergyStatus => "descriptive::soul-energy-status",
             Self::SoulEnergyRecovery => "descriptive::soul-energy-recovery",
             Self::SoulRename => "descriptive::soul-rename",
+            Self::SoulRelationships => "descriptive::soul-relationships",
+            Self::SoulLifeLog => "descriptive::soul-life-log",
             Self::Notifications => "descriptive::notifications",
+            Self::KingContracts => "descriptive::king-contracts",
         }
     }
 }
@@ -792,11 +879,15 @@ const fn descriptive_surface_coverage(surface: DescriptiveSurface) -> SurfaceCov
     match surface {
         DescriptiveSurface::GettingStartedFirstSteps => published("getting-started-first-steps"),
         DescriptiveSurface::CameraPanZoom => published("camera-pan-zoom"),
+        DescriptiveSurface::Minimap => published("minimap"),
         DescriptiveSurface::HelpPauseBehavior => published("help-pause-behavior"),
         DescriptiveSurface::SoulEnergyStatus => published("soul-energy-status"),
         DescriptiveSurface::SoulEnergyRecovery => published("soul-energy-recovery"),
         DescriptiveSurface::SoulRename => published("soul-rename"),
+        DescriptiveSurface::SoulRelationships => published("soul-relationships"),
+        DescriptiveSurface::SoulLifeLog => published("soul-life-log"),
         DescriptiveSurface::Notifications => published("notifications"),
+        DescriptiveSurface::KingContracts => published("king-contracts"),
     }
 }
 
@@ -1008,7 +1099,10 @@ mod tests {
     #[test]
     fn familiar_build_binding_stays_unpublished_until_completion_exists() {
         assert_eq!(
-            crate::input_actions::binding_labels_for_action(InputAction::FamiliarBuild),
+            crate::input_actions::binding_labels_for_action(
+                crate::input_actions::InputBindingTable::default().bindings(),
+                InputAction::FamiliarBuild
+            ),
             Ok(Vec::new())
         );
         assert_eq!(
@@ -9,26 +9,84 @@ mod tests;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use hw_core::locale::Localization;
 use hw_ui::help::{
-    HelpPanelChrome, HelpPanelContent, HelpPanelCopy, HelpPanelCopySpec, HelpSection,
-    HelpSectionId, HelpShortcutPair, HelpTopic,
+    HelpEntry, HelpEntryId, HelpPanelChrome, HelpPanelContent, HelpPanelCopy, HelpPanelCopySpec,
+    HelpSection, HelpSectionId, HelpShortcutPair, HelpTopic, HelpTopicId,
 };
 
-use crate::input_actions::{InputAction, binding_labels_for_action};
+use crate::input_actions::{
+    InputAction, InputBinding, InputBindingTable, binding_labels_for_action,
+};
 
 use manifest::{FeatureSpec, HelpOwnerId, PlayerFeatureId};
 
-pub(crate) type ProviderFn = fn() -> Result<HelpContribution, HelpCatalogError>;
-type OrderedSectionTopics = BTreeMap<(u16, HelpSectionId), (&'static str, Vec<(u16, HelpTopic)>)>;
+pub(crate) type ProviderFn = fn(&HelpSource<'_>) -> Result<HelpContribution, HelpCatalogError>;
+type OrderedSectionTopics = BTreeMap<(u16, HelpSectionId), (String, Vec<(u16, HelpTopic)>)>;
 
 pub(crate) struct HelpContribution {
     feature: PlayerFeatureId,
     owner: HelpOwnerId,
     section_id: HelpSectionId,
-    section_title: &'static str,
+    section_title: String,
     topic: HelpTopic,
 }
 
+/// provider が文言とショートカット表記を引く入力
+///
+/// 文言は `help.section.<id>` / `help.topic.<id>` / `help.<entry-id>.title` /
+/// `help.<entry-id>.body`（段落を改行で区切る）の ID で現在のロケールから引く。
+/// 欠けた ID はフォールバックせずカタログエラーにする。
+pub(crate) struct HelpSource<'a> {
+    localization: &'a Localization,
+    bindings: &'a [InputBinding],
+}
+
+impl<'a> HelpSource<'a> {
+    pub(crate) fn new(localization: &'a Localization, bindings: &'a [InputBinding]) -> Self {
+        Self {
+            localization,
+            bindings,
+        }
+    }
+
+    fn text(&self, id: &str) -> Result<&'a str, HelpCatalogError> {
+        self.localization.get(id).ok_or_else(|| {
+            HelpCatalogError::new(format!(
+                "missing {} help text: {id}",
+                self.localization.locale().code()
+            ))
+        })
+    }
+
+    pub(crate) fn section(
+        &self,
+        id: &'static str,
+    ) -> Result<(HelpSectionId, String), HelpCatalogError> {
+        let title = self.text(&format!("help.section.{id}"))?;
+        Ok((HelpSectionId::new(id), title.to_string()))
+    }
+
+    pub(crate) fn topic(
+        &self,
+        id: &'static str,
+        entries: impl IntoIterator<Item = HelpEntry>,
+    ) -> Result<HelpTopic, HelpCatalogError> {
+        let title = self.text(&format!("help.topic.{id}"))?;
+        Ok(HelpTopic::new(HelpTopicId::new(id), title, entries))
+    }
+
+    pub(crate) fn entry(&self, id: &'static str) -> Result<HelpEntry, HelpCatalogError> {
+        let title = self.text(&format!("help.{id}.title"))?;
+        let body = self.text(&format!("help.{id}.body"))?;
+        Ok(HelpEntry::new(HelpEntryId::new(id), title, body.lines()))
+    }
+
+    pub(crate) fn shortcut(&self, action: InputAction) -> Result<String, HelpCatalogError> {
+        shortcut(self.bindings, action)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct HelpCatalogError(String);
 
@@ -47,34 +105,59 @@ impl fmt::Display for HelpCatalogError {
 impl std::error::Error for HelpCatalogError {}
 
 pub(crate) fn build_help_panel_content() -> Result<HelpPanelContent, HelpCatalogError> {
-    build_from_specs(manifest::feature_specs())
+    build_help_panel_content_for(
+        &Localization::default(),
+        InputBindingTable::default().bindings(),
+    )
+}
+
+/// 現在のロケールとプレイヤーのキーバインド上書きを反映した Help 本文を組み立てる
+pub(crate) fn build_help_panel_content_for(
+    localization: &Localization,
+    bindings: &[InputBinding],
+) -> Result<HelpPanelContent, HelpCatalogError> {
+    build_from_specs(
+        manifest::feature_specs(),
+        &HelpSource::new(localization, bindings),
+    )
 }
 
 pub(crate) fn build_help_panel_chrome() -> Result<HelpPanelChrome, HelpCatalogError> {
+    build_help_panel_chrome_for(
+        &Localization::default(),
+        InputBindingTable::default().bindings(),
+    )
+}
+
+pub(crate) fn build_help_panel_chrome_for(
+    localization: &Localization,
+    bindings: &[InputBinding],
+) -> Result<HelpPanelChrome, HelpCatalogError> {
+    let source = HelpSource::new(localization, bindings);
     let chrome = HelpPanelChrome::new(
         HelpPanelCopy::new(HelpPanelCopySpec {
-            launcher_label: "Help",
-            launcher_tooltip: "操作とゲームのヘルプ",
-            panel_title: "Hell Workers ヘルプ",
-            close_label: "閉じる",
-            topic_navigation_label: "項目移動",
-            page_navigation_label: "ページ移動",
-            document_bounds_label: "先頭 / 末尾",
-            shortcut_label: "操作",
+            launcher_label: source.text("help.chrome.launcher")?,
+            launcher_tooltip: source.text("help.chrome.launcher_tooltip")?,
+            panel_title: source.text("help.chrome.panel_title")?,
+            close_label: source.text("help.chrome.close")?,
+            topic_navigation_label: source.text("help.chrome.topic_navigation")?,
+            page_navigation_label: source.text("help.chrome.page_navigation")?,
+            document_bounds_label: source.text("help.chrome.document_bounds")?,
+            shortcut_label: source.text("help.chrome.shortcut")?,
         }),
-        shortcut(InputAction::OpenHelp)?,
-        shortcut(InputAction::CloseHelp)?,
+        shortcut(bindings, InputAction::OpenHelp)?,
+        shortcut(bindings, InputAction::CloseHelp)?,
         HelpShortcutPair::new(
-            shortcut(InputAction::HelpPreviousTopic)?,
-            shortcut(InputAction::HelpNextTopic)?,
+            shortcut(bindings, InputAction::HelpPreviousTopic)?,
+            shortcut(bindings, InputAction::HelpNextTopic)?,
         ),
         HelpShortcutPair::new(
-            shortcut(InputAction::HelpPageUp)?,
-            shortcut(InputAction::HelpPageDown)?,
+            shortcut(bindings, InputAction::HelpPageUp)?,
+            shortcut(bindings, InputAction::HelpPageDown)?,
         ),
         HelpShortcutPair::new(
-            shortcut(InputAction::HelpHome)?,
-            shortcut(InputAction::HelpEnd)?,
+            shortcut(bindings, InputAction::HelpHome)?,
+            shortcut(bindings, InputAction::HelpEnd)?,
         ),
     );
     let copy = chrome.copy();
@@ -98,7 +181,10 @@ pub(crate) fn build_help_panel_chrome() -> Result<HelpPanelChrome, HelpCatalogEr
     Ok(chrome)
 }
 
-fn build_from_specs(specs: Vec<FeatureSpec>) -> Result<HelpPanelContent, HelpCatalogError> {
+fn build_from_specs(
+    specs: Vec<FeatureSpec>,
+    source: &HelpSource<'_>,
+) -> Result<HelpPanelContent, HelpCatalogError> {
     let mut contributions = Vec::with_capacity(specs.len());
     let mut seen_features = BTreeSet::new();
 
@@ -109,7 +195,7 @@ fn build_from_specs(specs: Vec<FeatureSpec>) -> Result<HelpPanelContent, HelpCat
                 spec.feature.as_str()
             )));
         }
-        let contribution = (spec.provider)()?;
+        let contribution = (spec.provider)(source)?;
         if contribution.feature != spec.feature {
             return Err(HelpCatalogError::new(format!(
                 "provider feature mismatch for {}",
@@ -139,7 +225,7 @@ fn build_from_specs(specs: Vec<FeatureSpec>) -> Result<HelpPanelContent, HelpCat
     for (spec, contribution) in contributions {
         let section = section_topics
             .entry((spec.section_order, contribution.section_id))
-            .or_insert((contribution.section_title, Vec::new()));
+            .or_insert_with(|| (contribution.section_title.clone(), Vec::new()));
         if section.0 != contribution.section_title {
             return Err(HelpCatalogError::new(format!(
                 "section title mismatch: {}",
@@ -186,7 +272,7 @@ fn validate_contributions(
         }
         if let Some((section_order, section_title)) = sections.insert(
             contribution.section_id,
-            (spec.section_order, contribution.section_title),
+            (spec.section_order, contribution.section_title.as_str()),
         ) && (section_order != spec.section_order || section_title != contribution.section_title)
         {
             return Err(HelpCatalogError::new(format!(
@@ -250,8 +336,11 @@ fn validate_contributions(
     Ok(())
 }
 
-pub(super) fn shortcut(action: InputAction) -> Result<String, HelpCatalogError> {
-    let labels = binding_labels_for_action(action)
+pub(super) fn shortcut(
+    bindings: &[InputBinding],
+    action: InputAction,
+) -> Result<String, HelpCatalogError> {
+    let labels = binding_labels_for_action(bindings, action)
         .map_err(|error| HelpCatalogError::new(error.to_string()))?;
     if labels.is_empty() {
         return Err(HelpCatalogError::new(format!(
@@ -1,7 +1,20 @@
 use super::*;
 
-fn familiar_commands_with_blocked_entry() -> Result<HelpContribution, HelpCatalogError> {
-    let mut contribution = providers::familiars::familiar_commands()?;
+fn default_bindings() -> InputBindingTable {
+    InputBindingTable::default()
+}
+
+fn default_source<'a>(
+    localization: &'a Localization,
+    bindings: &'a InputBindingTable,
+) -> HelpSource<'a> {
+    HelpSource::new(localization, bindings.bindings())
+}
+
+fn familiar_commands_with_blocked_entry(
+    source: &HelpSource<'_>,
+) -> Result<HelpContribution, HelpCatalogError> {
+    let mut contribution = providers::familiars::familiar_commands(source)?;
     let topic = &contribution.topic;
     let mut entries = topic.entries().to_vec();
     entries.push(hw_ui::help::HelpEntry::new(
@@ -14,11 +27,14 @@ fn familiar_commands_with_blocked_entry() -> Result<HelpContribution, HelpCatalo
 }
 
 fn normalized_approval_snapshot(content: &HelpPanelContent) -> String {
+    let localization = Localization::default();
+    let bindings = default_bindings();
+    let source = default_source(&localization, &bindings);
     let feature_by_topic: BTreeMap<hw_ui::help::HelpTopicId, (PlayerFeatureId, HelpOwnerId)> =
         manifest::feature_specs()
             .into_iter()
             .map(|spec| {
-                let contribution = (spec.provider)().expect("production provider");
+                let contribution = (spec.provider)(&source).expect("production provider");
                 (contribution.topic.id(), (spec.feature, spec.owner))
             })
             .collect();
@@ -93,7 +109,10 @@ fn production_catalog_is_valid_and_provider_order_independent() {
     let forward = build_help_panel_content().expect("production Help catalog must be valid");
     let mut reversed_specs = manifest::feature_specs();
     reversed_specs.reverse();
-    let reversed = build_from_specs(reversed_specs).expect("reordered providers must validate");
+    let localization = Localization::default();
+    let bindings = default_bindings();
+    let reversed = build_from_specs(reversed_specs, &default_source(&localization, &bindings))
+        .expect("reordered providers must validate");
 
     assert_eq!(
         normalized_approval_snapshot(&forward),
@@ -124,9 +143,29 @@ fn regenerate_help_approval_snapshot() {
     });
 }
 
+#[test]
+fn catalog_and_chrome_build_in_every_shipped_locale() {
+    let bindings = default_bindings();
+    let source_content = build_help_panel_content().expect("source locale catalog");
+    for locale in hw_core::locale::Locale::ALL {
+        let localization = Localization::shipped(locale);
+        let content = build_help_panel_content_for(&localization, bindings.bindings())
+            .unwrap_or_else(|error| panic!("{} Help catalog: {error}", locale.code()));
+        build_help_panel_chrome_for(&localization, bindings.bindings())
+            .unwrap_or_else(|error| panic!("{} Help chrome: {error}", locale.code()));
+        assert_eq!(
+            content.topic_ids().collect::<Vec<_>>(),
+            source_content.topic_ids().collect::<Vec<_>>()
+        );
+    }
+}
+
 #[test]
 fn help_shortcut_is_generated_from_the_canonical_binding() {
-    assert_eq!(shortcut(InputAction::OpenHelp).as_deref(), Ok("F1"));
+    assert_eq!(
+        shortcut(default_bindings().bindings(), InputAction::OpenHelp).as_deref(),
+        Ok("F1")
+    );
 }
 
 #[test]
@@ -137,16 +176,23 @@ fn coverage_rejects_blocked_entries() {
         .find(|spec| spec.feature == PlayerFeatureId::FamiliarCommands)
         .expect("Familiar command feature")
         .provider = familiar_commands_with_blocked_entry;
-    let blocked_error = build_from_specs(blocked_specs).unwrap_err().to_string();
+    let localization = Localization::default();
+    let bindings = default_bindings();
+    let blocked_error = build_from_specs(blocked_specs, &default_source(&localization, &bindings))
+        .unwrap_err()
+        .to_string();
     assert!(blocked_error.contains("blocked surface is present"));
 }
 
 #[test]
 fn validator_rejects_ambiguous_section_and_topic_ordering() {
+    let localization = Localization::default();
+    let bindings = default_bindings();
+    let source = default_source(&localization, &bindings);
     let mut split_section = manifest::feature_specs();
     split_section[2].section_order += 1;
     assert!(
-        build_from_specs(split_section)
+        build_from_specs(split_section, &source)
             .unwrap_err()
             .to_string()
             .contains("section metadata mismatch")
@@ -155,7 +201,7 @@ fn validator_rejects_ambiguous_section_and_topic_ordering() {
     let mut duplicate_topic_order = manifest::feature_specs();
     duplicate_topic_order[2].topic_order = duplicate_topic_order[1].topic_order;
     assert!(
-        build_from_specs(duplicate_topic_order)
+        build_from_specs(duplicate_topic_order, &source)
             .unwrap_err()
             .to_string()
             .contains("duplicate topic order")
@@ -1,7 +1,13 @@
 use bevy::prelude::*;
-use hw_ui::help::{HelpNavigationScrollArea, HelpPanelContent, HelpPanelState, HelpScrollArea};
+use hw_core::locale::Localization;
+use hw_ui::help::{
+    HelpNavigationScrollArea, HelpPanelChrome, HelpPanelContent, HelpPanelState, HelpScrollArea,
+};
 
-use crate::input_actions::{InputOverlay, PendingWorldInputCapture};
+use crate::input_actions::{InputBindingTable, InputOverlay, PendingWorldInputCapture};
+use crate::interface::ui::help_content::{
+    build_help_panel_chrome_for, build_help_panel_content_for,
+};
 
 pub(crate) type HelpScrollAreas<'w, 's> = Query<
     'w,
@@ -42,6 +48,30 @@ pub(crate) fn apply_accepted_help_open_system(
     );
 }
 
+/// キーバインドかロケールが変わったら Help 本文と chrome を作り直す
+pub(crate) fn refresh_help_shortcut_labels_system(
+    bindings: Res<InputBindingTable>,
+    localization: Res<Localization>,
+    mut content: ResMut<HelpPanelContent>,
+    mut chrome: ResMut<HelpPanelChrome>,
+) {
+    if !bindings.is_changed() && !localization.is_changed() {
+        return;
+    }
+    match (
+        build_help_panel_content_for(&localization, bindings.bindings()),
+        build_help_panel_chrome_for(&localization, bindings.bindings()),
+    ) {
+        (Ok(next_content), Ok(next_chrome)) => {
+            content.set_if_neq(next_content);
+            chrome.set_if_neq(next_chrome);
+        }
+        (Err(error), _) | (_, Err(error)) => {
+            warn!("Failed to rebuild Help for key bindings or locale: {error}");
+        }
+    }
+}
+
 pub(crate) fn handle_help_intent(
     intent: hw_ui::UiIntent,
     pending: &PendingWorldInputCapture,
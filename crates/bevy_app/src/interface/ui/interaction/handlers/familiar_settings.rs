@@ -15,7 +15,7 @@ pub(crate) fn handle(
     ui_queries: &mut IntentUiQueries<'_, '_>,
     ev_max_soul_changed: &mut MessageWriter<FamiliarOperationMaxSoulChangedEvent>,
 ) {
-    let selected = sel_ctx.selected_entity.0;
+    let selected = sel_ctx.selection.primary();
     match intent {
         UiIntent::AdjustFatigueThreshold(delta) => {
             adjust_fatigue_threshold(selected, &mut familiar_queries.q_familiar_ops, delta);
@@ -42,6 +42,28 @@ pub(crate) fn handle(
                 ev_max_soul_changed,
             );
         }
+        UiIntent::AdjustSelectedFatigueThreshold(delta) => {
+            for familiar in sel_ctx.selection.entities().iter() {
+                adjust_fatigue_threshold(
+                    Some(familiar),
+                    &mut familiar_queries.q_familiar_ops,
+                    delta,
+                );
+            }
+        }
+        UiIntent::AdjustSelectedMaxControlledSoul(delta) => {
+            for familiar in sel_ctx.selection.entities().iter() {
+                adjust_max_controlled_soul(
+                    Some(familiar),
+                    &mut familiar_queries.q_familiar_ops,
+                    &familiar_queries.q_familiar_meta,
+                    &sel_ctx.node_index,
+                    &mut ui_queries.q_text,
+                    delta,
+                    ev_max_soul_changed,
+                );
+            }
+        }
         _ => {}
     }
 }
@@ -13,7 +13,7 @@ pub(crate) fn handle_selection(intent: UiIntent, ctx: &mut IntentSelectionCtx<'_
             if ctx.resolved_frame.pointer_selection_suppressed() {
                 return;
             }
-            ctx.selected_entity.0 = Some(entity);
+            ctx.selection.select(Some(entity));
             ctx.info_panel_pin.entity = Some(entity);
         }
         UiIntent::ClearInspectPin => {
@@ -107,6 +107,7 @@ mod tests {
     fn inspect_app(pointer_selection_suppressed: bool) -> App {
         let mut app = minimal_app();
         app.init_resource::<crate::interface::selection::SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<InfoPanelPinState>()
             .init_resource::<EntityListNodeIndex>()
             .init_resource::<ResolvedInputFrame>()
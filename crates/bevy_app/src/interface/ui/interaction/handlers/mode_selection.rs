@@ -1,3 +1,4 @@
+use bevy::log::info;
 use hw_ui::UiIntent;
 
 use super::super::intent_context::{
@@ -18,6 +19,15 @@ pub(crate) fn handle_mode_select(
     ) {
         return;
     }
+    if let UiIntent::SelectBuild(kind) = &intent
+        && mode_ctx.contracts.is_building_locked(*kind)
+    {
+        info!(
+            "CONTRACT: {:?} is locked until its contract is fulfilled",
+            kind
+        );
+        return;
+    }
 
     mode_ctx.cancel_active_mode_if_needed();
     match intent {
@@ -58,7 +68,7 @@ pub(crate) fn handle_mode_select(
         }
         UiIntent::SelectTaskMode(task_mode) => {
             ensure_familiar_selected(
-                &mut sel_ctx.selected_entity,
+                &mut sel_ctx.selection,
                 &familiar_queries.q_familiars_for_area,
                 "Task designation",
             );
@@ -72,7 +82,7 @@ pub(crate) fn handle_mode_select(
         }
         UiIntent::SelectAreaTask => {
             ensure_familiar_selected(
-                &mut sel_ctx.selected_entity,
+                &mut sel_ctx.selection,
                 &familiar_queries.q_familiars_for_area,
                 "Area Edit",
             );
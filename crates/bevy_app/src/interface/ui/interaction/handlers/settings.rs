@@ -6,6 +6,7 @@ use hw_ui::components::MenuState;
 
 use super::begin_overlay_open;
 use crate::DebugVisible;
+use crate::input_actions::KeyRebindCapture;
 use crate::systems::settings::apply::sync_debug_gizmos;
 use crate::systems::settings::persistence::save_settings_to_disk;
 
@@ -16,11 +17,13 @@ pub fn handle(
     debug_visible: &mut DebugVisible,
     config_store: &mut GizmoConfigStore,
     input_focus: &mut InputFocus,
+    rebind_capture: &mut KeyRebindCapture,
 ) -> bool {
     match intent {
         UiIntent::ToggleSettings => {
             if *menu_state == MenuState::Settings {
                 *menu_state = MenuState::Hidden;
+                rebind_capture.cancel();
                 true
             } else {
                 begin_overlay_open(input_focus);
@@ -30,6 +33,7 @@ pub fn handle(
         }
         UiIntent::CloseSettings => {
             *menu_state = MenuState::Hidden;
+            rebind_capture.cancel();
             true
         }
         UiIntent::SetUiScale(value) => {
@@ -57,6 +61,19 @@ pub fn handle(
             settings.fps_display_enabled = enabled;
             false
         }
+        UiIntent::SetLocale(locale) => {
+            settings.locale = locale;
+            false
+        }
+        UiIntent::BeginKeyRebind(index) => {
+            rebind_capture.begin(index);
+            false
+        }
+        UiIntent::ResetKeyBindings => {
+            rebind_capture.cancel();
+            settings.key_binding_overrides.clear();
+            true
+        }
         _ => false,
     }
 }
@@ -82,6 +99,7 @@ mod tests {
         let mut debug_visible = DebugVisible::default();
         let mut config_store = GizmoConfigStore::default();
         let mut input_focus = InputFocus::from_entity(Entity::PLACEHOLDER);
+        let mut rebind_capture = KeyRebindCapture::default();
 
         handle(
             UiIntent::ToggleSettings,
@@ -90,6 +108,7 @@ mod tests {
             &mut debug_visible,
             &mut config_store,
             &mut input_focus,
+            &mut rebind_capture,
         );
 
         assert_eq!(menu_state, MenuState::Settings);
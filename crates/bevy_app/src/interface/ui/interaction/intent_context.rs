@@ -4,16 +4,19 @@ use bevy::prelude::*;
 
 use crate::entities::familiar::{Familiar, FamiliarOperation};
 use crate::input_actions::ActiveModeCleanupParams;
-use crate::interface::selection::SelectedEntity;
+use crate::interface::selection::{SelectedEntities, SelectionWriter};
 use crate::interface::ui::{EntityListNodeIndex, InfoPanelPinState};
-use crate::systems::command::TaskArea;
+use crate::systems::command::{SupplyRouteDraft, TaskArea};
+use crate::systems::contracts::ContractBook;
+use crate::systems::edicts::EdictToggleRequest;
 use crate::systems::familiar_ai::FamiliarAiState;
 use crate::systems::save::{SaveLoadState, SavePath};
+use hw_core::edicts::EdictKind;
 use hw_core::game_state::PlayMode;
 use hw_core::relationships::Commanding;
 use hw_core::world::DoorState;
 use hw_jobs::{Building, BuildingCategory, Door};
-use hw_logistics::{StockpilePolicyChangeRequest, StockpilePolicyPatch};
+use hw_logistics::{StockpilePolicyChangeRequest, StockpilePolicyPatch, SupplyRouteChangeRequest};
 use hw_spatial::StockpileSpatialGrid;
 use hw_ui::components::{ArchitectCategoryState, LoadConfirmDialog, OperationDialog};
 use hw_ui::intents::StockpilePolicyEditTarget;
@@ -24,6 +27,7 @@ pub(crate) struct IntentModeCtx<'w, 's> {
     pub(crate) cleanup: ActiveModeCleanupParams<'w, 's>,
     pub(crate) play_mode: Res<'w, State<PlayMode>>,
     pub(crate) time: ResMut<'w, Time<Virtual>>,
+    pub(crate) contracts: Res<'w, ContractBook>,
 }
 
 impl IntentModeCtx<'_, '_> {
@@ -48,6 +52,9 @@ pub(crate) struct IntentDomainActionCtx<'w, 's> {
     door_visual_handles: Res<'w, DoorVisualHandles>,
     stockpile_grid: Res<'w, StockpileSpatialGrid>,
     stockpile_policy_requests: MessageWriter<'w, StockpilePolicyChangeRequest>,
+    supply_route_draft: ResMut<'w, SupplyRouteDraft>,
+    supply_route_requests: MessageWriter<'w, SupplyRouteChangeRequest>,
+    edict_requests: MessageWriter<'w, EdictToggleRequest>,
 }
 
 impl IntentDomainActionCtx<'_, '_> {
@@ -85,21 +92,53 @@ impl IntentDomainActionCtx<'_, '_> {
         );
     }
 
+    /// 選択中のドアをまとめて施錠 / 解錠する。ドア以外と、すでに目的の状態のドアは無視する。
+    pub(crate) fn set_doors_locked(&mut self, selection: &SelectedEntities, locked: bool) {
+        for entity in selection.iter() {
+            let Ok((_, door, _)) = self.q_doors.get(entity) else {
+                continue;
+            };
+            if (door.state == DoorState::Locked) != locked {
+                self.toggle_door_lock(entity);
+            }
+        }
+    }
+
     pub(crate) fn request_stockpile_policy_change(
         &mut self,
         target: StockpilePolicyEditTarget,
         patch: StockpilePolicyPatch,
+        selection: &SelectedEntities,
     ) {
-        let targets =
-            crate::systems::command::resolve_stockpile_policy_targets(target, &self.stockpile_grid);
+        let targets = crate::systems::command::resolve_stockpile_policy_targets(
+            target,
+            &self.stockpile_grid,
+            selection,
+        );
         self.stockpile_policy_requests
             .write(StockpilePolicyChangeRequest { targets, patch });
     }
+
+    pub(crate) fn set_supply_route_source(&mut self, source: Option<Entity>) {
+        self.supply_route_draft.source = source;
+    }
+
+    /// 作成要求を出したら仮指定の搬出元は使い終わりとして解除する。
+    pub(crate) fn request_supply_route_change(&mut self, request: SupplyRouteChangeRequest) {
+        if matches!(request, SupplyRouteChangeRequest::Create { .. }) {
+            self.supply_route_draft.source = None;
+        }
+        self.supply_route_requests.write(request);
+    }
+
+    pub(crate) fn request_edict_toggle(&mut self, kind: EdictKind) {
+        self.edict_requests.write(EdictToggleRequest(kind));
+    }
 }
 
 #[derive(SystemParam)]
 pub(crate) struct IntentSelectionCtx<'w> {
-    pub(crate) selected_entity: ResMut<'w, SelectedEntity>,
+    pub(crate) selection: SelectionWriter<'w>,
     pub(crate) info_panel_pin: ResMut<'w, InfoPanelPinState>,
     pub(crate) node_index: Res<'w, EntityListNodeIndex>,
     pub(crate) resolved_frame: Res<'w, crate::input_actions::ResolvedInputFrame>,
@@ -136,12 +175,12 @@ pub(crate) struct IntentUiQueries<'w, 's> {
 }
 
 pub(crate) fn ensure_familiar_selected(
-    selected_entity: &mut ResMut<SelectedEntity>,
+    selection: &mut SelectionWriter,
     q_familiars_for_area: &Query<(Entity, Option<&TaskArea>), With<Familiar>>,
     _mode_label: &str,
 ) {
-    let selected_is_familiar = selected_entity
-        .0
+    let selected_is_familiar = selection
+        .primary()
         .is_some_and(|entity| q_familiars_for_area.get(entity).is_ok());
 
     if selected_is_familiar {
@@ -161,6 +200,6 @@ pub(crate) fn ensure_familiar_selected(
         .or_else(|| familiars.first().map(|(entity, _)| *entity));
 
     if let Some(familiar_entity) = fallback {
-        selected_entity.0 = Some(familiar_entity);
+        selection.select(Some(familiar_entity));
     }
 }
@@ -25,6 +25,7 @@ pub(crate) struct IntentSettingsCtx<'w> {
     settings: ResMut<'w, hw_core::GameSettings>,
     debug_visible: ResMut<'w, crate::DebugVisible>,
     config_store: ResMut<'w, GizmoConfigStore>,
+    rebind_capture: ResMut<'w, crate::input_actions::KeyRebindCapture>,
 }
 
 #[derive(SystemParam)]
@@ -88,7 +89,7 @@ pub(crate) fn handle_ui_intent(
                 false
             }
             UiIntent::OpenOperationDialog | UiIntent::CloseDialog => {
-                let can_open_operation = selection_ctx.selected_entity.0.is_some_and(|entity| {
+                let can_open_operation = selection_ctx.selection.primary().is_some_and(|entity| {
                     familiar_queries.q_familiars_for_area.get(entity).is_ok()
                 });
                 handlers::handle_dialog(intent, can_open_operation, &mut ui_queries);
@@ -96,7 +97,9 @@ pub(crate) fn handle_ui_intent(
             }
             UiIntent::AdjustFatigueThreshold(_)
             | UiIntent::AdjustMaxControlledSoul(_)
-            | UiIntent::AdjustMaxControlledSoulFor(..) => {
+            | UiIntent::AdjustMaxControlledSoulFor(..)
+            | UiIntent::AdjustSelectedFatigueThreshold(_)
+            | UiIntent::AdjustSelectedMaxControlledSoul(_) => {
                 handlers::handle_familiar_settings(
                     intent,
                     &mut selection_ctx,
@@ -128,7 +131,10 @@ pub(crate) fn handle_ui_intent(
             | UiIntent::SetCameraMousePanEnabled(_)
             | UiIntent::SetDefaultTimeSpeed(_)
             | UiIntent::SetDebugGizmosEnabled(_)
-            | UiIntent::SetFpsDisplayEnabled(_) => {
+            | UiIntent::SetFpsDisplayEnabled(_)
+            | UiIntent::SetLocale(_)
+            | UiIntent::BeginKeyRebind(_)
+            | UiIntent::ResetKeyBindings => {
                 let mut mode_ctx = action_contexts.p0();
                 handlers::handle_settings(
                     intent,
@@ -137,12 +143,23 @@ pub(crate) fn handle_ui_intent(
                     &mut aux_ctx.settings.debug_visible,
                     &mut aux_ctx.settings.config_store,
                     &mut ui_queries.input_focus,
+                    &mut aux_ctx.settings.rebind_capture,
                 )
             }
             UiIntent::ToggleDoorLock(entity) => {
                 action_contexts.p1().toggle_door_lock(entity);
                 false
             }
+            UiIntent::SetSelectedDoorsLocked(locked) => {
+                action_contexts
+                    .p1()
+                    .set_doors_locked(selection_ctx.selection.entities(), locked);
+                false
+            }
+            UiIntent::ToggleEdict(kind) => {
+                action_contexts.p1().request_edict_toggle(kind);
+                false
+            }
             UiIntent::SelectArchitectCategory(category) => {
                 action_contexts.p1().toggle_architect_category(category);
                 false
@@ -153,7 +170,7 @@ pub(crate) fn handle_ui_intent(
                 if target_is_valid {
                     let mut mode_ctx = action_contexts.p0();
                     mode_ctx.cancel_active_mode_if_needed();
-                    selection_ctx.selected_entity.0 = Some(entity);
+                    selection_ctx.selection.select(Some(entity));
                     mode_ctx.cleanup.move_context.0 = Some(entity);
                     mode_ctx.cleanup.move_placement_state.0 = None;
                     mode_ctx.cleanup.companion_state.0 = None;
@@ -165,12 +182,27 @@ pub(crate) fn handle_ui_intent(
                 false
             }
             UiIntent::ApplyStockpilePolicy { target, patch } => {
-                action_contexts
-                    .p1()
-                    .request_stockpile_policy_change(target, patch);
+                action_contexts.p1().request_stockpile_policy_change(
+                    target,
+                    patch,
+                    selection_ctx.selection.entities(),
+                );
+                false
+            }
+            UiIntent::SetSupplyRouteSource(source) => {
+                action_contexts.p1().set_supply_route_source(source);
+                false
+            }
+            UiIntent::EditSupplyRoute(request) => {
+                action_contexts.p1().request_supply_route_change(request);
                 false
             }
-            UiIntent::AdjustTaskPriority { .. } | UiIntent::CancelTask { .. } => false,
+            UiIntent::AdjustTaskPriority { .. }
+            | UiIntent::CancelTask { .. }
+            | UiIntent::AdjustSelectedTaskPriority(_)
+            | UiIntent::CancelSelectedTasks => false,
+            // hw_ui の統計・アラートパネルが直接読む
+            UiIntent::ToggleStatistics | UiIntent::ToggleAlerts => false,
         };
 
         handlers::save_if_requested(should_save_settings, &aux_ctx.settings.settings);
@@ -188,7 +220,9 @@ mod tests {
     use crate::input_actions::{InputModifiers, ResolvedInputFrame};
     use crate::interface::selection::SelectedEntity;
     use crate::interface::ui::{EntityListNodeIndex, InfoPanelPinState};
-    use crate::systems::command::{StockpilePolicyRangeEditState, ZoneRemovalPreviewState};
+    use crate::systems::command::{
+        StockpilePolicyRangeEditState, SupplyRouteDraft, ZoneRemovalPreviewState,
+    };
     use crate::systems::save::{SaveLoadState, SavePath};
     use crate::test_support::minimal_app;
     use bevy::ecs::system::{IntoSystem, System};
@@ -216,6 +250,8 @@ mod tests {
             .add_message::<UiIntent>()
             .add_message::<FamiliarOperationMaxSoulChangedEvent>()
             .add_message::<hw_logistics::StockpilePolicyChangeRequest>()
+            .add_message::<hw_logistics::SupplyRouteChangeRequest>()
+            .add_message::<crate::systems::edicts::EdictToggleRequest>()
             .init_state::<PlayMode>()
             .init_resource::<BuildContext>()
             .init_resource::<MoveContext>()
@@ -226,10 +262,12 @@ mod tests {
             .init_resource::<AreaEditSession>()
             .init_resource::<ZoneRemovalPreviewState>()
             .init_resource::<StockpilePolicyRangeEditState>()
+            .init_resource::<SupplyRouteDraft>()
             .init_resource::<StockpileSpatialGrid>()
             .init_resource::<WorldMap>()
             .init_resource::<MenuState>()
             .init_resource::<SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<InfoPanelPinState>()
             .init_resource::<EntityListNodeIndex>()
             .init_resource::<ResolvedInputFrame>()
@@ -237,12 +275,14 @@ mod tests {
             .init_resource::<SaveLoadState>()
             .init_resource::<SavePath>()
             .init_resource::<hw_core::GameSettings>()
+            .init_resource::<crate::input_actions::KeyRebindCapture>()
             .init_resource::<crate::DebugVisible>()
             .init_resource::<GizmoConfigStore>()
             .init_resource::<ArchitectCategoryState>()
             .init_resource::<PendingWorldInputCapture>()
             .init_resource::<hw_ui::help::HelpPanelState>()
             .init_resource::<HelpPauseGuard>()
+            .init_resource::<crate::systems::contracts::ContractBook>()
             .insert_resource(hw_ui::help::HelpPanelContent::new([
                 hw_ui::help::HelpSection::new(
                     hw_ui::help::HelpSectionId::new("test"),
@@ -341,6 +381,47 @@ mod tests {
         );
     }
 
+    #[derive(Resource, Default)]
+    struct SupplyRouteRequests(Vec<hw_logistics::SupplyRouteChangeRequest>);
+
+    fn collect_supply_route_requests(
+        mut requests: MessageReader<hw_logistics::SupplyRouteChangeRequest>,
+        mut receipts: ResMut<SupplyRouteRequests>,
+    ) {
+        receipts.0.extend(requests.read().copied());
+    }
+
+    #[test]
+    fn supply_route_create_consumes_the_marked_source() {
+        let mut app = domain_action_app();
+        app.init_resource::<SupplyRouteRequests>().add_systems(
+            Update,
+            collect_supply_route_requests.after(handle_ui_intent),
+        );
+        let source = app.world_mut().spawn_empty().id();
+        let destination = app.world_mut().spawn_empty().id();
+
+        write_intent(&mut app, UiIntent::SetSupplyRouteSource(Some(source)));
+        app.update();
+        assert_eq!(
+            app.world().resource::<SupplyRouteDraft>().source,
+            Some(source)
+        );
+
+        let create = hw_logistics::SupplyRouteChangeRequest::Create {
+            source_cell: source,
+            destination_cell: destination,
+        };
+        write_intent(&mut app, UiIntent::EditSupplyRoute(create));
+        app.update();
+
+        assert_eq!(app.world().resource::<SupplyRouteDraft>().source, None);
+        assert_eq!(
+            app.world().resource::<SupplyRouteRequests>().0,
+            vec![create]
+        );
+    }
+
     #[test]
     fn begin_stockpile_policy_range_intent_owns_mode_and_patch() {
         let mut app = domain_action_app();
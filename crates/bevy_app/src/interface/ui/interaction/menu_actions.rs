@@ -46,12 +46,27 @@ pub(super) fn handle_pressed_action(
         MenuAction::ToggleSettings => {
             ui_intents.write(UiIntent::ToggleSettings);
         }
+        MenuAction::ToggleStatistics => {
+            ui_intents.write(UiIntent::ToggleStatistics);
+        }
+        MenuAction::ToggleAlerts => {
+            ui_intents.write(UiIntent::ToggleAlerts);
+        }
         MenuAction::CloseSettings => {
             ui_intents.write(UiIntent::CloseSettings);
         }
         MenuAction::SetDefaultTimeSpeed(speed) => {
             ui_intents.write(UiIntent::SetDefaultTimeSpeed(speed));
         }
+        MenuAction::SetLocale(locale) => {
+            ui_intents.write(UiIntent::SetLocale(locale));
+        }
+        MenuAction::BeginKeyRebind(index) => {
+            ui_intents.write(UiIntent::BeginKeyRebind(index));
+        }
+        MenuAction::ResetKeyBindings => {
+            ui_intents.write(UiIntent::ResetKeyBindings);
+        }
         MenuAction::SelectBuild(kind) => {
             ui_intents.write(UiIntent::SelectBuild(kind));
         }
@@ -73,6 +88,9 @@ pub(super) fn handle_pressed_action(
         MenuAction::SelectDreamPlanting => {
             ui_intents.write(UiIntent::SelectDreamPlanting);
         }
+        MenuAction::ToggleEdict(kind) => {
+            ui_intents.write(UiIntent::ToggleEdict(kind));
+        }
         MenuAction::OpenOperationDialog => {
             ui_intents.write(UiIntent::OpenOperationDialog);
         }
@@ -88,6 +106,15 @@ pub(super) fn handle_pressed_action(
         MenuAction::AdjustMaxControlledSoulFor(entity, delta) => {
             ui_intents.write(UiIntent::AdjustMaxControlledSoulFor(entity, delta));
         }
+        MenuAction::AdjustSelectedFatigueThreshold(delta) => {
+            ui_intents.write(UiIntent::AdjustSelectedFatigueThreshold(delta));
+        }
+        MenuAction::AdjustSelectedMaxControlledSoul(delta) => {
+            ui_intents.write(UiIntent::AdjustSelectedMaxControlledSoul(delta));
+        }
+        MenuAction::SetSelectedDoorsLocked(locked) => {
+            ui_intents.write(UiIntent::SetSelectedDoorsLocked(locked));
+        }
         MenuAction::SetTimeSpeed(speed) => {
             ui_intents.write(UiIntent::SetTimeSpeed(speed));
         }
@@ -121,6 +148,12 @@ pub(super) fn handle_pressed_action(
         MenuAction::BeginStockpilePolicyRangeEdit { patch } => {
             ui_intents.write(UiIntent::BeginStockpilePolicyRangeEdit { patch });
         }
+        MenuAction::SetSupplyRouteSource(source) => {
+            ui_intents.write(UiIntent::SetSupplyRouteSource(source));
+        }
+        MenuAction::EditSupplyRoute(request) => {
+            ui_intents.write(UiIntent::EditSupplyRoute(request));
+        }
         MenuAction::AdjustTaskPriority {
             entity,
             expected_work_type,
@@ -143,6 +176,12 @@ pub(super) fn handle_pressed_action(
                 expected_kind,
             });
         }
+        MenuAction::AdjustSelectedTaskPriority(adjustment) => {
+            ui_intents.write(UiIntent::AdjustSelectedTaskPriority(adjustment));
+        }
+        MenuAction::CancelSelectedTasks => {
+            ui_intents.write(UiIntent::CancelSelectedTasks);
+        }
         MenuAction::SetUiScale(_)
         | MenuAction::SetCameraPanSpeed(_)
         | MenuAction::SetCameraMousePanEnabled(_)
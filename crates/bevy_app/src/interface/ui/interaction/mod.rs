@@ -17,9 +17,10 @@ pub(crate) use intent_handler::handle_ui_intent;
 
 pub use hw_ui::interaction::hover_action::hover_action_button_system;
 pub use status_display::{
-    task_summary_ui_system, update_area_edit_preview_ui_system, update_dream_loss_popup_ui_system,
-    update_dream_pool_display_system, update_fps_display_system, update_mode_text_system,
-    update_speed_button_highlight_system,
+    task_summary_ui_system, update_area_edit_preview_ui_system, update_contract_panel_system,
+    update_dream_loss_popup_ui_system, update_dream_pool_display_system,
+    update_edict_status_system, update_fps_display_system, update_mode_text_system,
+    update_population_display_system, update_speed_button_highlight_system,
 };
 pub(crate) use tooltip::hover_tooltip_system;
 
@@ -5,6 +5,7 @@ use crate::systems::command::{TaskMode, to_task_mode_zone_type};
 use crate::systems::jobs::BuildingType;
 use crate::systems::logistics::ZoneType;
 use hw_core::game_state::PlayMode;
+use hw_core::locale::Localization;
 use hw_ui::components::MenuState;
 
 pub(super) fn toggle_menu_and_reset_mode(
@@ -127,13 +128,69 @@ pub(super) struct ModeDisplayInfo<'a> {
     pub selected_familiar_name: Option<&'a str>,
     pub selected_area_size_tiles: Option<UVec2>,
     pub area_edit_dragging: bool,
-    pub area_edit_operation: Option<&'a str>,
+    /// ドラッグ中の操作名の文言 ID
+    pub area_edit_operation: Option<&'static str>,
     pub area_overlap: Option<(usize, f32)>,
     pub clipboard_has_area: bool,
     pub unassigned_tasks_in_area: Option<usize>,
 }
 
-pub(super) fn build_mode_text(ctx: ModeCtxRefs, info: ModeDisplayInfo) -> String {
+/// エリア編集の状態表示（モード表示とカーソル横プレビューで共用）
+pub(super) fn area_edit_state_text(
+    text: &Localization,
+    dragging: bool,
+    operation: Option<&'static str>,
+) -> String {
+    match (dragging, operation) {
+        (true, Some(op)) => text.format("ui.area_edit.dragging_op", &[("op", &text.text(op))]),
+        (true, None) => text.text("ui.area_edit.dragging").to_string(),
+        (false, _) => text.text("ui.area_edit.ready").to_string(),
+    }
+}
+
+pub(super) fn area_overlap_text(text: &Localization, overlap: Option<(usize, f32)>) -> String {
+    match overlap {
+        Some((count, max_ratio)) if count > 0 => text.format(
+            "ui.area_edit.overlap",
+            &[
+                ("count", &count),
+                ("percent", &format!("{:.0}", max_ratio * 100.0)),
+            ],
+        ),
+        Some(_) => text.text("ui.area_edit.overlap_none").to_string(),
+        None => text.text("ui.area_edit.overlap_unknown").to_string(),
+    }
+}
+
+pub(super) fn area_clip_text(text: &Localization, has_area: bool) -> &str {
+    if has_area {
+        text.text("ui.area_edit.clip_ready")
+    } else {
+        text.text("ui.area_edit.clip_empty")
+    }
+}
+
+pub(super) fn area_tasks_text(text: &Localization, count: Option<usize>) -> String {
+    count
+        .map(|count| text.format("ui.area_edit.tasks", &[("count", &count)]))
+        .unwrap_or_else(|| text.text("ui.area_edit.tasks_unknown").to_string())
+}
+
+/// 重なりが大きいときだけ警告文言を返す
+pub(super) fn area_overlap_warning(
+    text: &Localization,
+    overlap: Option<(usize, f32)>,
+) -> Option<&str> {
+    overlap
+        .is_some_and(|(count, max_ratio)| count > 0 && max_ratio >= 0.5)
+        .then(|| text.text("ui.area_edit.warn_overlap"))
+}
+
+pub(super) fn build_mode_text(
+    ctx: ModeCtxRefs,
+    info: ModeDisplayInfo,
+    text: &Localization,
+) -> String {
     let ModeCtxRefs {
         play_mode,
         build_context,
@@ -149,112 +206,99 @@ pub(super) fn build_mode_text(ctx: ModeCtxRefs, info: ModeDisplayInfo) -> String
         clipboard_has_area,
         unassigned_tasks_in_area,
     } = info;
+    let fixed = |id: &str| text.text(id).to_string();
+    let familiar_name =
+        || selected_familiar_name.unwrap_or_else(|| text.text("ui.mode.no_familiar"));
     match play_mode {
-        PlayMode::Normal => "Mode: Normal".to_string(),
+        PlayMode::Normal => fixed("ui.mode.normal"),
         PlayMode::BuildingPlace => {
             if let Some(companion) = companion_state.0.as_ref() {
-                format!(
-                    "Mode: Companion ({:?} -> {:?})",
-                    companion.parent_kind, companion.kind
+                text.format(
+                    "ui.mode.companion",
+                    &[
+                        ("parent", &text.text(companion.parent_kind.text_id())),
+                        ("kind", &text.text(companion.kind.text_id())),
+                    ],
                 )
             } else if let Some(kind) = build_context.0 {
-                format!("Mode: Build ({:?})", kind)
+                text.format(
+                    "ui.mode.build_kind",
+                    &[("kind", &text.text(kind.text_id()))],
+                )
             } else {
-                "Mode: Build".to_string()
+                fixed("ui.mode.build")
             }
         }
         PlayMode::TaskDesignation => match task_context.0 {
-            TaskMode::DesignateChop(None) => "Mode: Chop (Drag to select)".to_string(),
-            TaskMode::DesignateChop(Some(_)) => "Mode: Chop (Dragging...)".to_string(),
-            TaskMode::DesignateMine(None) => "Mode: Mine (Drag to select)".to_string(),
-            TaskMode::DesignateMine(Some(_)) => "Mode: Mine (Dragging...)".to_string(),
-            TaskMode::DesignateHaul(None) => "Mode: Haul (Drag to select)".to_string(),
-            TaskMode::DesignateHaul(Some(_)) => "Mode: Haul (Dragging...)".to_string(),
-            TaskMode::CancelDesignation(None) => "Mode: Cancel (Drag to select)".to_string(),
-            TaskMode::CancelDesignation(Some(_)) => "Mode: Cancel (Dragging...)".to_string(),
+            TaskMode::DesignateChop(None) => fixed("ui.mode.chop"),
+            TaskMode::DesignateChop(Some(_)) => fixed("ui.mode.chop.dragging"),
+            TaskMode::DesignateMine(None) => fixed("ui.mode.mine"),
+            TaskMode::DesignateMine(Some(_)) => fixed("ui.mode.mine.dragging"),
+            TaskMode::DesignateHaul(None) => fixed("ui.mode.haul"),
+            TaskMode::DesignateHaul(Some(_)) => fixed("ui.mode.haul.dragging"),
+            TaskMode::CancelDesignation(None) => fixed("ui.mode.cancel"),
+            TaskMode::CancelDesignation(Some(_)) => fixed("ui.mode.cancel.dragging"),
             TaskMode::AreaSelection(None) => {
-                let target_name = selected_familiar_name.unwrap_or("No Familiar");
                 let size = selected_area_size_tiles
-                    .map(|v| format!("{}x{}t", v.x, v.y))
-                    .unwrap_or_else(|| "?x?t".to_string());
-                let state = if area_edit_dragging {
-                    if let Some(op) = area_edit_operation {
-                        format!("Dragging {}", op)
-                    } else {
-                        "Dragging".to_string()
-                    }
-                } else {
-                    "Ready".to_string()
-                };
-                let overlap_text = if let Some((count, max_ratio)) = area_overlap {
-                    if count > 0 {
-                        format!("Overlap:{}({:.0}%)", count, max_ratio * 100.0)
-                    } else {
-                        "Overlap:0".to_string()
-                    }
-                } else {
-                    "Overlap:-".to_string()
-                };
-                let clip = if clipboard_has_area {
-                    "Clip:Ready"
-                } else {
-                    "Clip:Empty"
-                };
-                let tasks = unassigned_tasks_in_area
-                    .map(|count| format!("Tasks:{}", count))
-                    .unwrap_or_else(|| "Tasks:-".to_string());
-                let overlap_warn =
-                    area_overlap.is_some_and(|(count, max_ratio)| count > 0 && max_ratio >= 0.5);
-                let warn = if overlap_warn {
-                    " WARN:HighOverlap"
-                } else {
-                    ""
-                };
-                format!(
-                    "Mode: Area Edit [{}] {} {} {} {} {}{} (Drag:Apply, Esc:Exit, Shift+Release:Exit, Ctrl+C/V, Ctrl+Z/Y, Ctrl+1..3 Save, Alt+1..3 Apply)",
-                    target_name, size, state, overlap_text, tasks, clip, warn
+                    .map(|v| text.format("ui.area_edit.size", &[("width", &v.x), ("height", &v.y)]))
+                    .unwrap_or_else(|| fixed("ui.area_edit.size_unknown"));
+                let warn = area_overlap_warning(text, area_overlap)
+                    .map(|warning| format!(" {warning}"))
+                    .unwrap_or_default();
+                text.format(
+                    "ui.mode.area_edit",
+                    &[
+                        ("familiar", &familiar_name()),
+                        ("size", &size),
+                        (
+                            "state",
+                            &area_edit_state_text(text, area_edit_dragging, area_edit_operation),
+                        ),
+                        ("overlap", &area_overlap_text(text, area_overlap)),
+                        ("tasks", &area_tasks_text(text, unassigned_tasks_in_area)),
+                        ("clip", &area_clip_text(text, clipboard_has_area)),
+                        ("warn", &warn),
+                    ],
                 )
             }
             TaskMode::AreaSelection(Some(_)) => {
-                let target_name = selected_familiar_name.unwrap_or("No Familiar");
-                format!("Mode: Area Edit [{}] (New Area Dragging...)", target_name)
+                text.format("ui.mode.area_edit.new", &[("familiar", &familiar_name())])
             }
-            TaskMode::AssignTask(_) => "Mode: Assign Task".to_string(),
+            TaskMode::AssignTask(_) => fixed("ui.mode.assign_task"),
             TaskMode::ZonePlacement(kind, start_pos) => {
-                if start_pos.is_some() {
-                    format!("Mode: Zone {:?} (Dragging...)", kind)
+                let id = if start_pos.is_some() {
+                    "ui.mode.zone.dragging"
                 } else {
-                    format!("Mode: Zone {:?} (Drag to place)", kind)
-                }
+                    "ui.mode.zone"
+                };
+                text.format(id, &[("zone", &text.text(kind.text_id()))])
             }
             TaskMode::ZoneRemoval(kind, start_pos) => {
-                if start_pos.is_some() {
-                    format!("Mode: Remove Zone {:?} (Dragging...)", kind)
+                let id = if start_pos.is_some() {
+                    "ui.mode.zone_removal.dragging"
                 } else {
-                    format!("Mode: Remove Zone {:?} (Drag to remove)", kind)
-                }
-            }
-            TaskMode::DreamPlanting(None) => {
-                "Mode: Dream Planting (Drag to select area)".to_string()
-            }
-            TaskMode::DreamPlanting(Some(_)) => "Mode: Dream Planting (Dragging...)".to_string(),
-            TaskMode::StockpilePolicyEdit(None) => {
-                "Mode: Stockpile Policy (Drag one area, Esc:Cancel)".to_string()
-            }
-            TaskMode::StockpilePolicyEdit(Some(_)) => {
-                "Mode: Stockpile Policy (Dragging...)".to_string()
+                    "ui.mode.zone_removal"
+                };
+                text.format(id, &[("zone", &text.text(kind.text_id()))])
             }
-            TaskMode::SoulSpaPlace(_) => "Mode: Soul Spa (Click to place 2×2)".to_string(),
-            _ => "Mode: Task".to_string(),
+            TaskMode::DreamPlanting(None) => fixed("ui.mode.dream_planting"),
+            TaskMode::DreamPlanting(Some(_)) => fixed("ui.mode.dream_planting.dragging"),
+            TaskMode::StockpilePolicyEdit(None) => fixed("ui.mode.stockpile_policy"),
+            TaskMode::StockpilePolicyEdit(Some(_)) => fixed("ui.mode.stockpile_policy.dragging"),
+            TaskMode::SoulSpaPlace(_) => fixed("ui.mode.soul_spa"),
+            TaskMode::LayoutExport(None) => fixed("ui.mode.layout_export"),
+            TaskMode::LayoutExport(Some(_)) => fixed("ui.mode.layout_export.dragging"),
+            TaskMode::LayoutImport => fixed("ui.mode.layout_import"),
+            _ => fixed("ui.mode.task"),
         },
         PlayMode::FloorPlace => match task_context.0 {
-            TaskMode::FloorPlace(None) => "Mode: Floor (Drag to place)".to_string(),
-            TaskMode::FloorPlace(Some(_)) => "Mode: Floor (Dragging...)".to_string(),
-            TaskMode::WallPlace(None) => "Mode: Wall (Drag to place 1xn)".to_string(),
-            TaskMode::WallPlace(Some(_)) => "Mode: Wall (Dragging 1xn...)".to_string(),
-            _ => "Mode: Floor".to_string(),
+            TaskMode::FloorPlace(None) => fixed("ui.mode.floor"),
+            TaskMode::FloorPlace(Some(_)) => fixed("ui.mode.floor.dragging"),
+            TaskMode::WallPlace(None) => fixed("ui.mode.wall"),
+            TaskMode::WallPlace(Some(_)) => fixed("ui.mode.wall.dragging"),
+            _ => fixed("ui.mode.floor_fallback"),
         },
-        PlayMode::BuildingMove => "Mode: Move Building".to_string(),
+        PlayMode::BuildingMove => fixed("ui.mode.building_move"),
     }
 }
 
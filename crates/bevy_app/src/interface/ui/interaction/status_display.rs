@@ -1,9 +1,15 @@
+mod contracts;
 mod dream;
+mod edicts;
 mod mode_panel;
+mod population;
 mod runtime;
 
+pub use contracts::update_contract_panel_system;
 pub use dream::{update_dream_loss_popup_ui_system, update_dream_pool_display_system};
+pub use edicts::update_edict_status_system;
 pub use mode_panel::{
     task_summary_ui_system, update_area_edit_preview_ui_system, update_mode_text_system,
 };
+pub use population::update_population_display_system;
 pub use runtime::{update_fps_display_system, update_speed_button_highlight_system};
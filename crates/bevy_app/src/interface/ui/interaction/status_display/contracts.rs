@@ -0,0 +1,75 @@
+//! 契約パネルの中継レイヤー（hw_ui 側実装へ委譲）
+
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_ui::interaction::status_display::ContractPanelPayload;
+
+use crate::systems::contracts::{Contract, ContractBook};
+
+fn format_deadline(text: &Localization, seconds: f32) -> String {
+    let total_mins = (seconds / 60.0) as u32;
+    let total_hours = total_mins / 60;
+    text.format(
+        "ui.contract.deadline",
+        &[
+            ("day", &(total_hours / 24 + 1)),
+            (
+                "time",
+                &format!("{:02}:{:02}", total_hours % 24, total_mins % 60),
+            ),
+        ],
+    )
+}
+
+fn contract_line(text: &Localization, contract: &Contract) -> String {
+    text.format(
+        "ui.contract.line",
+        &[
+            ("title", &contract.objective.title(text)),
+            (
+                "progress",
+                &contract.objective.progress_label(text, contract.progress),
+            ),
+            ("deadline", &format_deadline(text, contract.deadline)),
+            ("reward", &contract.reward.label(text)),
+        ],
+    )
+}
+
+fn contract_panel_payload(text: &Localization, book: &ContractBook) -> ContractPanelPayload {
+    let mut lines: Vec<String> = book
+        .active
+        .iter()
+        .map(|contract| contract_line(text, contract))
+        .collect();
+    let locked: Vec<&str> = book
+        .locked_buildings()
+        .map(|kind| text.text(kind.text_id()))
+        .collect();
+    if !locked.is_empty() {
+        lines.push(text.format("ui.contract.locked", &[("buildings", &locked.join(", "))]));
+    }
+
+    ContractPanelPayload {
+        visible: !book.active.is_empty(),
+        header: text.format(
+            "ui.contract.header",
+            &[("done", &book.completed), ("failed", &book.failed)],
+        ),
+        body: lines.join("\n"),
+    }
+}
+
+pub fn update_contract_panel_system(
+    book: Res<ContractBook>,
+    localization: Res<Localization>,
+    ui_nodes: Res<hw_ui::components::UiNodeRegistry>,
+    q_node: Query<&mut Node>,
+    q_text: Query<&mut Text>,
+) {
+    let payload = (book.is_changed() || localization.is_changed())
+        .then(|| contract_panel_payload(&localization, &book));
+    hw_ui::interaction::status_display::update_contract_panel_system(
+        payload, ui_nodes, q_node, q_text,
+    );
+}
@@ -0,0 +1,22 @@
+//! 勅令パネル表示の中継レイヤー（hw_ui 側実装へ委譲）
+
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::edicts::DreamEdicts;
+use hw_core::locale::Localization;
+
+pub fn update_edict_status_system(
+    edicts: Res<DreamEdicts>,
+    game_time: Res<GameTime>,
+    localization: Res<Localization>,
+    ui_nodes: Res<hw_ui::components::UiNodeRegistry>,
+    q_text: Query<&mut Text>,
+) {
+    hw_ui::interaction::status_display::update_edict_status_system(
+        edicts,
+        game_time,
+        localization,
+        ui_nodes,
+        q_text,
+    );
+}
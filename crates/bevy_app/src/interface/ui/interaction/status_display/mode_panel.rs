@@ -5,6 +5,7 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use hw_core::constants::TILE_SIZE;
 use hw_core::game_state::PlayMode;
+use hw_core::locale::Localization;
 
 use crate::app_contexts::{BuildContext, CompanionPlacementState, TaskContext, ZoneContext};
 use crate::interface::selection::SelectedEntity;
@@ -43,6 +44,7 @@ pub fn update_mode_text_system(
     selection_data: ModeSelectionData,
     q_text: Query<&mut Text>,
     ui_nodes: Res<UiNodeRegistry>,
+    localization: Res<Localization>,
 ) {
     let ModeState {
         play_mode,
@@ -77,6 +79,7 @@ pub fn update_mode_text_system(
         && !area_edit_clipboard.is_changed()
         && !selected_area_changed
         && !area_mode_active
+        && !localization.is_changed()
     {
         return;
     }
@@ -130,11 +133,12 @@ pub fn update_mode_text_system(
             selected_familiar_name,
             selected_area_size_tiles,
             area_edit_dragging: area_edit_session.is_dragging(),
-            area_edit_operation: area_edit_session.operation_label(),
+            area_edit_operation: area_edit_session.operation_text_id(),
             area_overlap,
             clipboard_has_area: area_edit_clipboard.has_area(),
             unassigned_tasks_in_area,
         },
+        &localization,
     );
 
     hw_ui::interaction::status_display::update_mode_text_system(
@@ -148,6 +152,7 @@ pub fn task_summary_ui_system(
     mut dirty: Option<ResMut<TaskListDirty>>,
     state: Option<Res<TaskListState>>,
     theme: Res<hw_ui::theme::UiTheme>,
+    localization: Res<Localization>,
     ui_nodes: Res<UiNodeRegistry>,
     q_text: Query<(&mut Text, &mut TextColor)>,
 ) {
@@ -158,7 +163,7 @@ pub fn task_summary_ui_system(
         return;
     };
 
-    if !theme.is_changed() && !dirty.summary_dirty() {
+    if !theme.is_changed() && !localization.is_changed() && !dirty.summary_dirty() {
         return;
     }
 
@@ -171,6 +176,7 @@ pub fn task_summary_ui_system(
             total: state.summary_total as u32,
             high: state.summary_high as u32,
         }),
+        &localization,
         theme.colors.task_high_warning,
         theme.colors.panel_accent_time_control,
         ui_nodes,
@@ -199,6 +205,7 @@ pub fn update_area_edit_preview_ui_system(
     ui_nodes: Res<UiNodeRegistry>,
     q_node: Query<&mut Node>,
     q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
     let AreaEditContext {
         task_context,
@@ -258,16 +265,7 @@ pub fn update_area_edit_preview_ui_system(
     let width_tiles = (size.x.abs() / TILE_SIZE).round().max(1.0) as i32;
     let height_tiles = (size.y.abs() / TILE_SIZE).round().max(1.0) as i32;
 
-    let state = if area_edit_session.is_dragging() {
-        if let Some(op) = area_edit_session.operation_label() {
-            format!("Dragging {}", op)
-        } else {
-            "Dragging".to_string()
-        }
-    } else {
-        "Ready".to_string()
-    };
-
+    let text = &*localization;
     let overlap = overlap_summary_from_areas(
         selected,
         area,
@@ -275,37 +273,44 @@ pub fn update_area_edit_preview_ui_system(
             .iter()
             .map(|(entity, area)| (entity, (*area).clone())),
     );
-    let overlap_text = if let Some((count, ratio)) = overlap {
-        if count > 0 {
-            format!("Overlap:{} ({:.0}%)", count, ratio * 100.0)
-        } else {
-            "Overlap:0".to_string()
-        }
-    } else {
-        "Overlap:-".to_string()
-    };
-    let clip_text = if area_edit_clipboard.has_area() {
-        "Clip:Ready"
-    } else {
-        "Clip:Empty"
-    };
     let tasks_in_area = count_positions_in_area(
         area,
         q_unassigned_tasks
             .iter()
             .map(|transform| transform.translation.truncate()),
     );
-    let warn_text = if overlap.is_some_and(|(count, ratio)| count > 0 && ratio >= 0.5) {
-        " | WARN:HighOverlap"
-    } else {
-        ""
-    };
+    let warn_text = mode::area_overlap_warning(text, overlap)
+        .map(|warning| format!(" | {warning}"))
+        .unwrap_or_default();
 
     payload = Some(hw_ui::interaction::status_display::AreaEditPreviewPayload {
         display: true,
-        text: format!(
-            "Area {}x{}t | {} | {} | Tasks:{} | {}{}",
-            width_tiles, height_tiles, state, overlap_text, tasks_in_area, clip_text, warn_text,
+        text: text.format(
+            "ui.area_edit.preview",
+            &[
+                (
+                    "size",
+                    &text.format(
+                        "ui.area_edit.size",
+                        &[("width", &width_tiles), ("height", &height_tiles)],
+                    ),
+                ),
+                (
+                    "state",
+                    &mode::area_edit_state_text(
+                        text,
+                        area_edit_session.is_dragging(),
+                        area_edit_session.operation_text_id(),
+                    ),
+                ),
+                ("overlap", &mode::area_overlap_text(text, overlap)),
+                ("tasks", &mode::area_tasks_text(text, Some(tasks_in_area))),
+                (
+                    "clip",
+                    &mode::area_clip_text(text, area_edit_clipboard.has_area()),
+                ),
+                ("warn", &warn_text),
+            ],
         ),
         left: (cursor.x + 14.0).min(window.width() - 360.0).max(4.0),
         top: (cursor.y + 18.0).min(window.height() - 34.0).max(4.0),
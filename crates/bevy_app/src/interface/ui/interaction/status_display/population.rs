@@ -0,0 +1,19 @@
+//! 人口統計表示の中継レイヤー（hw_ui 側実装へ委譲）
+
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_core::population::PopulationManager;
+
+pub fn update_population_display_system(
+    population: Res<PopulationManager>,
+    localization: Res<Localization>,
+    ui_nodes: Res<hw_ui::components::UiNodeRegistry>,
+    q_text: Query<&mut Text>,
+) {
+    hw_ui::interaction::status_display::update_population_display_system(
+        population,
+        localization,
+        ui_nodes,
+        q_text,
+    );
+}
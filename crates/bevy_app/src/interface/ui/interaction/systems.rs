@@ -2,6 +2,7 @@ use crate::entities::familiar::{Familiar, FamiliarOperation};
 use crate::input_actions::ForegroundUiGate;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
+use hw_core::locale::Localization;
 use hw_jobs::{Building, BuildingCategory};
 use hw_ui::UiIntent;
 use hw_ui::components::*;
@@ -80,13 +81,15 @@ pub fn update_operation_dialog_system(
     q_familiars: Query<(&Familiar, &FamiliarOperation)>,
     mut q_dialog: Query<&mut Node, With<OperationDialog>>,
     mut q_text: Query<&mut Text>,
+    localization: Res<Localization>,
 ) {
     if let Some(selected) = selected_entity.0 {
         if let Ok((familiar, op)) = q_familiars.get(selected) {
             if let Some(entity) = ui_nodes.get_slot(UiSlot::DialogFamiliarName)
                 && let Ok(mut text) = q_text.get_mut(entity)
             {
-                text.0 = format!("Editing: {}", familiar.name);
+                text.0 =
+                    localization.format("ui.dialog.familiar.editing", &[("name", &familiar.name)]);
             }
             if let Some(entity) = ui_nodes.get_slot(UiSlot::DialogThresholdText)
                 && let Ok(mut text) = q_text.get_mut(entity)
@@ -94,7 +97,9 @@ pub fn update_operation_dialog_system(
                 let val_str = if op.recruit_fatigue_threshold().is_some() {
                     format!("{:.0}%", op.fatigue_threshold * 100.0)
                 } else {
-                    "0% (Recruit Off)".to_string()
+                    localization
+                        .text("ui.dialog.familiar.recruit_off")
+                        .to_string()
                 };
                 if text.0 != val_str {
                     text.0 = val_str;
@@ -8,6 +8,7 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::time::Real;
 use bevy::ui_widgets::popover::Popover;
+use hw_core::locale::Localization;
 use hw_core::{EpochLocal, WorldEpoch};
 use hw_ui::components::{HoverTooltip, MenuState, TooltipTemplate, UiNodeRegistry};
 use hw_ui::interaction::tooltip;
@@ -59,6 +60,7 @@ pub(crate) struct TooltipStateInput<'w, 's> {
     pub ui_nodes: Res<'w, UiNodeRegistry>,
     pub game_assets: Res<'w, crate::assets::GameAssets>,
     pub theme: Res<'w, hw_ui::theme::UiTheme>,
+    pub localization: Res<'w, Localization>,
     pub q_window: Query<'w, 's, &'static Window, With<bevy::window::PrimaryWindow>>,
     pub q_tooltip: Query<
         'w,
@@ -93,6 +95,7 @@ pub(crate) fn hover_tooltip_system(
         ui_nodes,
         game_assets,
         theme,
+        localization,
         q_window,
         q_tooltip,
     } = state_input;
@@ -115,6 +118,7 @@ pub(crate) fn hover_tooltip_system(
         tooltip::TooltipHandlers {
             game_assets: &*game_assets,
             theme: &theme,
+            text: &localization,
             inspection: &inspection,
             tooltip_renderer: &TooltipRenderer,
         },
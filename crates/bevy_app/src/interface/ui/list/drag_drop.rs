@@ -3,6 +3,7 @@ use crate::{SquadManagementOperation, SquadManagementRequest};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
+use hw_core::locale::Localization;
 use hw_core::relationships::CommandedBy;
 use hw_ui::components::{FamiliarListItem, SoulListItem, UiInputState, UiNodeRegistry, UiSlot};
 pub use hw_ui::list::DragState;
@@ -18,6 +19,7 @@ pub struct DragDropResources<'w> {
     ui_nodes: Res<'w, UiNodeRegistry>,
     game_assets: Res<'w, crate::assets::GameAssets>,
     theme: Res<'w, UiTheme>,
+    localization: Res<'w, Localization>,
     resolved_frame: Res<'w, crate::input_actions::ResolvedInputFrame>,
     ui_input_state: Res<'w, UiInputState>,
     drag_state: ResMut<'w, DragState>,
@@ -43,6 +45,7 @@ pub fn entity_list_drag_drop_system(
         ui_nodes,
         game_assets,
         theme,
+        localization,
         resolved_frame,
         ui_input_state,
         mut drag_state,
@@ -73,13 +76,21 @@ pub fn entity_list_drag_drop_system(
             {
                 drag_state.active_soul = Some(soul_entity);
                 drag_state.drop_target = None;
+                let label = localization.format(
+                    "ui.entity_list.drag_soul",
+                    &[(
+                        "name",
+                        &q_soul_names
+                            .get(soul_entity)
+                            .map_or("Soul", |identity| identity.name.as_str()),
+                    )],
+                );
                 spawn_drag_ghost(
                     &mut commands,
-                    soul_entity,
+                    label,
                     &ui_nodes,
                     &game_assets,
                     &theme,
-                    &q_soul_names,
                     &mut drag_state,
                 );
             }
@@ -137,21 +148,15 @@ fn hovered_familiar_row(
 
 fn spawn_drag_ghost(
     commands: &mut Commands,
-    soul_entity: Entity,
+    label: String,
     ui_nodes: &UiNodeRegistry,
     game_assets: &crate::assets::GameAssets,
     theme: &UiTheme,
-    q_soul_names: &Query<&SoulIdentity, With<DamnedSoul>>,
     drag_state: &mut DragState,
 ) {
     let Some(anchor) = ui_nodes.get_slot(UiSlot::TooltipAnchor) else {
         return;
     };
-    let label = q_soul_names
-        .get(soul_entity)
-        .map(|identity| format!("Drag: {}", identity.name))
-        .unwrap_or_else(|_| "Drag: Soul".to_string());
-
     let ghost = commands
         .spawn((
             DragGhost,
@@ -60,7 +60,7 @@ pub struct FocusQueries<'w, 's> {
 
 #[derive(SystemParam)]
 pub struct EntityListInteractionResources<'w> {
-    selected_entity: ResMut<'w, crate::interface::selection::SelectedEntity>,
+    selection: crate::interface::selection::SelectionWriter<'w>,
     ui_intents: MessageWriter<'w, UiIntent>,
     theme: Res<'w, UiTheme>,
     resolved_frame: Res<'w, crate::input_actions::ResolvedInputFrame>,
@@ -70,17 +70,11 @@ pub struct EntityListInteractionResources<'w> {
 fn focus_list_entity(
     entity: Entity,
     label: &'static str,
-    selected_entity: &mut ResMut<crate::interface::selection::SelectedEntity>,
+    selection: &mut crate::interface::selection::SelectionWriter,
     q_camera: &mut Query<&mut Transform, With<MainCamera>>,
     q_transforms: &Query<&GlobalTransform>,
 ) {
-    hw_ui::list::select_entity_and_focus_camera(
-        entity,
-        label,
-        selected_entity,
-        q_camera,
-        q_transforms,
-    );
+    hw_ui::list::select_entity_and_focus_camera(entity, label, selection, q_camera, q_transforms);
 }
 
 /// エンティティリストのゲーム側インタラクション
@@ -94,7 +88,7 @@ pub fn entity_list_interaction_system(
     resources: EntityListInteractionResources,
 ) {
     let EntityListInteractionResources {
-        mut selected_entity,
+        mut selection,
         mut ui_intents,
         theme,
         resolved_frame,
@@ -109,7 +103,7 @@ pub fn entity_list_interaction_system(
                 focus_list_entity(
                     item.0,
                     "soul",
-                    &mut selected_entity,
+                    &mut selection,
                     &mut focus_queries.q_camera,
                     &focus_queries.q_transforms,
                 );
@@ -121,7 +115,7 @@ pub fn entity_list_interaction_system(
                 focus_list_entity(
                     item.0,
                     "familiar",
-                    &mut selected_entity,
+                    &mut selection,
                     &mut focus_queries.q_camera,
                     &focus_queries.q_transforms,
                 );
@@ -166,6 +160,7 @@ mod tests {
         let mut app = minimal_app();
         app.add_message::<UiIntent>()
             .init_resource::<crate::interface::selection::SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<ResolvedInputFrame>()
             .init_resource::<UiInputState>()
             .init_resource::<UiTheme>()
@@ -198,6 +193,7 @@ mod tests {
             let mut app = minimal_app();
             app.add_message::<UiIntent>()
                 .init_resource::<crate::interface::selection::SelectedEntity>()
+                .init_resource::<crate::interface::selection::SelectedEntities>()
                 .init_resource::<ResolvedInputFrame>()
                 .init_resource::<UiInputState>()
                 .init_resource::<UiTheme>()
@@ -239,9 +235,12 @@ mod tests {
             .init_resource::<ButtonInput<KeyCode>>()
             .init_resource::<UiInputState>()
             .init_resource::<ResolvedInputFrame>()
+            .init_resource::<crate::input_actions::InputBindingTable>()
+            .init_resource::<crate::input_actions::KeyRebindCapture>()
             .init_resource::<TaskContext>()
             .init_resource::<MenuState>()
             .init_resource::<crate::interface::selection::SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<crate::DebugVisible>()
             .init_resource::<Time<Virtual>>()
             .init_resource::<UiTheme>()
@@ -13,7 +13,7 @@ use hw_ui::components::{
 pub struct EntityListTabFocusCtx<'w, 's> {
     pub resolved_frame: Res<'w, ResolvedInputFrame>,
     pub task_context: Res<'w, TaskContext>,
-    pub selected_entity: ResMut<'w, crate::interface::selection::SelectedEntity>,
+    pub selection: crate::interface::selection::SelectionWriter<'w>,
     pub soul_items: Query<'w, 's, &'static SoulListItem>,
     pub familiar_items: Query<'w, 's, &'static FamiliarListItem>,
     pub camera: Query<'w, 's, &'static mut Transform, With<MainCamera>>,
@@ -46,8 +46,8 @@ pub fn entity_list_tab_focus_system(mut ctx: EntityListTabFocusCtx) {
     }
 
     let current_index = ctx
-        .selected_entity
-        .0
+        .selection
+        .primary()
         .and_then(|selected| candidates.iter().position(|&entity| entity == selected));
     let next_index = if reverse {
         current_index
@@ -62,7 +62,7 @@ pub fn entity_list_tab_focus_system(mut ctx: EntityListTabFocusCtx) {
     hw_ui::list::select_entity_and_focus_camera(
         candidates[next_index],
         "tab-focus",
-        &mut ctx.selected_entity,
+        &mut ctx.selection,
         &mut ctx.camera,
         &ctx.transforms,
     );
@@ -125,6 +125,7 @@ mod tests {
         let mut app = minimal_app();
         app.init_resource::<TaskContext>()
             .init_resource::<crate::interface::selection::SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<ResolvedInputFrame>()
             .add_systems(Update, entity_list_tab_focus_system);
         app.world_mut().spawn((Transform::default(), MainCamera));
@@ -0,0 +1,205 @@
+//! ミニマップ root adapter
+//!
+//! `WorldMap` と ECS からミニマップ表示データを組み立て、`hw_ui::minimap::MinimapState` へ流し込む。
+//! ベースレイヤーは `WorldMap` が変化したフレームだけ再走査し、
+//! オーバーレイとドットは `MinimapState::refresh_due` の間隔で更新する。
+
+use crate::entities::damned_soul::DamnedSoul;
+use crate::entities::familiar::Familiar;
+use crate::systems::jobs::Designation;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::time::Real;
+use hw_core::area::TaskArea;
+use hw_core::camera::MainCamera;
+use hw_energy::{PowerGrid, YardPowerGrid};
+use hw_ui::minimap::{MinimapDot, MinimapDotKind, MinimapPowerArea, MinimapState, MinimapTile};
+use hw_world::zones::Yard;
+use hw_world::{TerrainType, WorldMap, WorldMapRead};
+
+#[derive(SystemParam)]
+pub(crate) struct MinimapOverlaySources<'w, 's> {
+    q_task_areas: Query<'w, 's, &'static TaskArea>,
+    q_power_grids: Query<'w, 's, (&'static PowerGrid, &'static YardPowerGrid)>,
+    q_yards: Query<'w, 's, &'static Yard>,
+    q_designations: Query<'w, 's, &'static Transform, With<Designation>>,
+    q_souls: Query<'w, 's, &'static Transform, With<DamnedSoul>>,
+    q_familiars: Query<'w, 's, &'static Transform, With<Familiar>>,
+}
+
+fn terrain_tile(terrain: TerrainType) -> MinimapTile {
+    match terrain {
+        TerrainType::Grass => MinimapTile::Grass,
+        TerrainType::Dirt => MinimapTile::Dirt,
+        TerrainType::Sand => MinimapTile::Sand,
+        TerrainType::River => MinimapTile::River,
+    }
+}
+
+/// 建物 > ストックパイル > 障害物 > 地形 の優先順でタイル種別を決める。
+fn base_tiles(world_map: &WorldMap) -> Vec<MinimapTile> {
+    let mut tiles: Vec<MinimapTile> = world_map
+        .tiles
+        .iter()
+        .zip(&world_map.obstacles)
+        .map(|(&terrain, &obstacle)| {
+            if obstacle {
+                MinimapTile::Obstacle
+            } else {
+                terrain_tile(terrain)
+            }
+        })
+        .collect();
+    let overlays = world_map
+        .stockpiles
+        .keys()
+        .map(|&grid| (grid, MinimapTile::Stockpile))
+        .chain(
+            world_map
+                .buildings
+                .keys()
+                .map(|&grid| (grid, MinimapTile::Building)),
+        );
+    for ((x, y), tile) in overlays {
+        if let Some(slot) = world_map
+            .pos_to_idx(x, y)
+            .and_then(|idx| tiles.get_mut(idx))
+        {
+            *slot = tile;
+        }
+    }
+    tiles
+}
+
+/// 障害物・建物・ストックパイルの変化をベースレイヤーへ反映する。
+pub(crate) fn sync_minimap_base_system(world_map: WorldMapRead, mut state: ResMut<MinimapState>) {
+    if !world_map.is_changed() && !state.needs_full_sync() {
+        return;
+    }
+    let tiles = base_tiles(&world_map);
+    state.sync_base_tiles(tiles);
+}
+
+/// タスクエリア・電力・禁止区域・指定・ドットを一定間隔で更新する。
+///
+/// 一時停止中もカメラ操作に追従できるよう実時間で間引く。
+pub(crate) fn sync_minimap_overlays_system(
+    time: Res<Time<Real>>,
+    world_map: WorldMapRead,
+    sources: MinimapOverlaySources,
+    mut state: ResMut<MinimapState>,
+) {
+    if !state.bypass_change_detection().refresh_due(time.elapsed()) {
+        return;
+    }
+
+    state.set_task_areas(
+        sources
+            .q_task_areas
+            .iter()
+            .flat_map(|area| {
+                area.rects()
+                    .map(|rect| Rect::from_corners(rect.min, rect.max))
+            })
+            .collect(),
+    );
+    state.set_power_areas(
+        sources
+            .q_power_grids
+            .iter()
+            .filter_map(|(grid, yard_link)| {
+                let yard = sources.q_yards.get(yard_link.0).ok()?;
+                Some(MinimapPowerArea {
+                    rect: Rect::from_corners(yard.min, yard.max),
+                    powered: grid.powered,
+                })
+            })
+            .collect(),
+    );
+    state.set_forbidden_tiles(world_map.forbidden_tiles.iter().copied());
+    state.set_designation_positions(
+        sources
+            .q_designations
+            .iter()
+            .map(|transform| transform.translation.truncate()),
+    );
+
+    let souls = sources.q_souls.iter().map(|transform| MinimapDot {
+        position: transform.translation.truncate(),
+        kind: MinimapDotKind::Soul,
+    });
+    let familiars = sources.q_familiars.iter().map(|transform| MinimapDot {
+        position: transform.translation.truncate(),
+        kind: MinimapDotKind::Familiar,
+    });
+    state.set_dots(souls.chain(familiars).collect());
+}
+
+/// メインカメラの表示範囲をワールド矩形として渡す。
+pub(crate) fn sync_minimap_viewport_system(
+    q_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut state: ResMut<MinimapState>,
+) {
+    let viewport = q_camera.single().ok().and_then(|(camera, transform)| {
+        let rect = camera.logical_viewport_rect()?;
+        let a = camera.viewport_to_world_2d(transform, rect.min).ok()?;
+        let b = camera.viewport_to_world_2d(transform, rect.max).ok()?;
+        Some(Rect::from_corners(a, b))
+    });
+    if state.viewport() != viewport {
+        state.set_viewport(viewport);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::minimal_app;
+
+    #[test]
+    fn base_sync_prefers_buildings_over_stockpiles_and_obstacles() {
+        let mut app = minimal_app();
+        let mut world_map = WorldMap::default();
+        let building = app.world_mut().spawn_empty().id();
+        world_map.buildings.insert((10, 10), building);
+        world_map.stockpiles.insert((10, 10), building);
+        world_map.stockpiles.insert((11, 10), building);
+        if let Some(idx) = world_map.pos_to_idx(12, 10) {
+            world_map.obstacles[idx] = true;
+        }
+        app.insert_resource(world_map)
+            .init_resource::<MinimapState>()
+            .add_systems(Update, sync_minimap_base_system);
+
+        app.update();
+
+        let state = app.world().resource::<MinimapState>();
+        assert!(!state.needs_full_sync());
+        assert_eq!(state.base_tile((10, 10)), Some(MinimapTile::Building));
+        assert_eq!(state.base_tile((11, 10)), Some(MinimapTile::Stockpile));
+        assert_eq!(state.base_tile((12, 10)), Some(MinimapTile::Obstacle));
+    }
+
+    #[test]
+    fn overlay_sync_draws_soul_and_familiar_dots() {
+        let mut app = minimal_app();
+        app.init_resource::<WorldMap>()
+            .init_resource::<MinimapState>()
+            .add_systems(Update, sync_minimap_overlays_system);
+        let empty = app.world().resource::<MinimapState>().render_rgba();
+        app.world_mut()
+            .spawn((DamnedSoul::default(), Transform::from_xyz(32.0, 0.0, 0.0)));
+        app.world_mut()
+            .spawn((Familiar::default(), Transform::from_xyz(-64.0, 0.0, 0.0)));
+
+        app.update();
+
+        let rendered = app.world().resource::<MinimapState>().render_rgba();
+        let changed_pixels = rendered
+            .chunks_exact(4)
+            .zip(empty.chunks_exact(4))
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(changed_pixels, 2);
+    }
+}
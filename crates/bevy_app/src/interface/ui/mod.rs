@@ -7,6 +7,7 @@ pub(crate) mod help_content;
 pub(crate) mod help_controller;
 pub mod interaction;
 pub mod list;
+pub(crate) mod minimap;
 pub(crate) mod notifications;
 pub mod panels;
 pub mod plugins;
@@ -21,10 +22,12 @@ pub use hw_ui::selection::PlacementFeedbackState;
 // interaction から外部が使うシンボル
 pub use interaction::{
     hover_action_button_system, task_summary_ui_system, ui_interaction_system,
-    update_area_edit_preview_ui_system, update_dream_loss_popup_ui_system,
-    update_dream_pool_display_system, update_fps_display_system, update_mode_text_system,
+    update_area_edit_preview_ui_system, update_contract_panel_system,
+    update_dream_loss_popup_ui_system, update_dream_pool_display_system,
+    update_edict_status_system, update_fps_display_system, update_mode_text_system,
     update_move_plant_hover_target_system, update_operation_dialog_system,
-    update_speed_button_highlight_system, update_ui_input_state_system,
+    update_population_display_system, update_speed_button_highlight_system,
+    update_ui_input_state_system,
 };
 
 // list から外部が使うシンボル
@@ -1,73 +1,324 @@
 use bevy::prelude::*;
+use hw_core::edicts::EdictEnactError;
+use hw_core::locale::Localization;
 use hw_ui::notifications::{NotificationRetention, NotificationSeverity, UserFacingNotification};
 
+use crate::systems::alerts::{AlertFinding, ColonyAlert};
+use crate::systems::contracts::{ContractOutcome, ContractOutcomeKind};
+use crate::systems::edicts::{EdictChange, EdictOutcome};
 use crate::systems::save::{
     SaveLoadFailureKind, SaveLoadOperation, SaveLoadOutcome, SaveLoadResult,
 };
-use hw_logistics::StockpilePolicyChangeOutcome;
+use crate::systems::soul_ai::adapters::SoulRedemptionOutcome;
+use hw_logistics::{StockpilePolicyChangeOutcome, SupplyRouteChangeOutcome, SupplyRouteRejection};
 
 pub(crate) fn adapt_save_load_outcomes(
     mut outcomes: MessageReader<SaveLoadOutcome>,
+    localization: Res<Localization>,
     mut notifications: MessageWriter<UserFacingNotification>,
 ) {
     for outcome in outcomes.read() {
-        notifications.write(notification_from_outcome(outcome));
+        notifications.write(notification_from_outcome(&localization, outcome));
+    }
+}
+
+pub(crate) fn adapt_contract_outcomes(
+    mut outcomes: MessageReader<ContractOutcome>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    for outcome in outcomes.read() {
+        notifications.write(contract_notification(&localization, outcome));
+    }
+}
+
+fn contract_notification(text: &Localization, outcome: &ContractOutcome) -> UserFacingNotification {
+    let contract = &outcome.contract;
+    let title = contract.objective.title(text);
+    let reward = contract.reward.label(text);
+    let args: [(&str, &dyn std::fmt::Display); 2] = [("title", &title), ("reward", &reward)];
+    let (key, severity, heading, body, retention) = match outcome.kind {
+        ContractOutcomeKind::Issued => (
+            "issued",
+            NotificationSeverity::Info,
+            text.text("notify.contract.issued.title"),
+            text.format("notify.contract.issued.body", &args),
+            NotificationRetention::ToastOnly,
+        ),
+        ContractOutcomeKind::Completed => (
+            "completed",
+            NotificationSeverity::Success,
+            text.text("notify.contract.completed.title"),
+            text.format("notify.contract.completed.body", &args),
+            NotificationRetention::Important,
+        ),
+        ContractOutcomeKind::Failed => (
+            "failed",
+            NotificationSeverity::Warning,
+            text.text("notify.contract.failed.title"),
+            text.format("notify.contract.failed.body", &args),
+            NotificationRetention::Important,
+        ),
+    };
+    UserFacingNotification::new(
+        format!("contract:{}:{key}", contract.id),
+        severity,
+        heading.to_string(),
+        body,
+        retention,
+    )
+}
+
+pub(crate) fn adapt_edict_outcomes(
+    mut outcomes: MessageReader<EdictOutcome>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    for outcome in outcomes.read() {
+        notifications.write(edict_notification(&localization, outcome));
+    }
+}
+
+fn edict_notification(text: &Localization, outcome: &EdictOutcome) -> UserFacingNotification {
+    let label = text.text(outcome.kind.text_id());
+    let (key, severity, heading, body) = match outcome.change {
+        EdictChange::Enacted { .. } => (
+            "enacted",
+            NotificationSeverity::Success,
+            "notify.edict.enacted.title",
+            text.format(
+                "notify.edict.enacted.body",
+                &[
+                    ("edict", &label),
+                    ("effect", &text.text(outcome.kind.effect_text_id())),
+                ],
+            ),
+        ),
+        EdictChange::Revoked => (
+            "revoked",
+            NotificationSeverity::Info,
+            "notify.edict.revoked.title",
+            text.format("notify.edict.revoked.body", &[("edict", &label)]),
+        ),
+        EdictChange::Expired => (
+            "expired",
+            NotificationSeverity::Info,
+            "notify.edict.expired.title",
+            text.format("notify.edict.expired.body", &[("edict", &label)]),
+        ),
+        EdictChange::Rejected(EdictEnactError::InsufficientDream { cost, available }) => (
+            "rejected",
+            NotificationSeverity::Warning,
+            "notify.edict.insufficient_dream.title",
+            text.format(
+                "notify.edict.insufficient_dream.body",
+                &[
+                    ("edict", &label),
+                    ("cost", &format!("{cost:.0}")),
+                    ("available", &format!("{available:.0}")),
+                ],
+            ),
+        ),
+        EdictChange::Rejected(EdictEnactError::AlreadyActive) => (
+            "rejected",
+            NotificationSeverity::Warning,
+            "notify.edict.already_active.title",
+            text.format("notify.edict.already_active.body", &[("edict", &label)]),
+        ),
+    };
+    UserFacingNotification::new(
+        format!("edict:{:?}:{key}", outcome.kind),
+        severity,
+        text.text(heading).to_string(),
+        body,
+        NotificationRetention::ToastOnly,
+    )
+}
+
+pub(crate) fn adapt_soul_redemption_outcomes(
+    mut outcomes: MessageReader<SoulRedemptionOutcome>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    for outcome in outcomes.read() {
+        notifications.write(
+            UserFacingNotification::new(
+                format!(
+                    "soul_redeemed:{}:{}",
+                    outcome.soul.index_u32(),
+                    outcome.soul.generation().to_bits()
+                ),
+                NotificationSeverity::Success,
+                localization.text("notify.soul_redeemed.title").to_string(),
+                localization.format(
+                    "notify.soul_redeemed.body",
+                    &[
+                        ("name", &outcome.name),
+                        ("dream", &format!("{:.0}", outcome.dream_reward)),
+                        ("total", &outcome.total_redeemed),
+                    ],
+                ),
+                NotificationRetention::Important,
+            )
+            .with_focus(outcome.shrine),
+        );
+    }
+}
+
+pub(crate) fn adapt_colony_alerts(
+    mut alerts: MessageReader<ColonyAlert>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    for alert in alerts.read() {
+        notifications.write(alert_notification(&localization, alert));
+    }
+}
+
+fn alert_notification(text: &Localization, alert: &ColonyAlert) -> UserFacingNotification {
+    let (severity, title, body) = match &alert.finding {
+        AlertFinding::ResourceLow {
+            resource,
+            stocked,
+            threshold,
+        } => {
+            let resource = text.text(resource.text_id());
+            (
+                NotificationSeverity::Warning,
+                text.format(
+                    "notify.alert.resource_low.title",
+                    &[("resource", &resource)],
+                ),
+                text.format(
+                    "notify.alert.resource_low.body",
+                    &[
+                        ("stocked", stocked),
+                        ("resource", &resource),
+                        ("threshold", threshold),
+                    ],
+                ),
+            )
+        }
+        AlertFinding::NoRestCapacity { souls, capacity } => (
+            NotificationSeverity::Warning,
+            text.text("notify.alert.no_rest.title").to_string(),
+            text.format(
+                "notify.alert.no_rest.body",
+                &[("souls", souls), ("capacity", capacity)],
+            ),
+        ),
+        AlertFinding::GridBlackout {
+            generation,
+            consumption,
+        } => (
+            NotificationSeverity::Error,
+            text.text("notify.alert.blackout.title").to_string(),
+            text.format(
+                "notify.alert.blackout.body",
+                &[
+                    ("generation", &format!("{generation:.1}")),
+                    ("consumption", &format!("{consumption:.1}")),
+                ],
+            ),
+        ),
+        AlertFinding::SoulStress {
+            count,
+            peak,
+            threshold,
+        } => (
+            NotificationSeverity::Warning,
+            text.text("notify.alert.stress.title").to_string(),
+            text.format(
+                "notify.alert.stress.body",
+                &[
+                    ("count", count),
+                    ("threshold", &format!("{threshold:.2}")),
+                    ("peak", &format!("{peak:.2}")),
+                ],
+            ),
+        ),
+        AlertFinding::FamiliarWithoutWorkers { name, seconds } => (
+            NotificationSeverity::Warning,
+            text.text("notify.alert.idle_familiar.title").to_string(),
+            text.format(
+                "notify.alert.idle_familiar.body",
+                &[("name", name), ("seconds", &format!("{seconds:.0}"))],
+            ),
+        ),
+    };
+    let key = match alert.scope {
+        Some(scope) => format!("alert:{}:{}", alert.rule_id, scope.to_bits()),
+        None => format!("alert:{}", alert.rule_id),
+    };
+    let notification =
+        UserFacingNotification::new(key, severity, title, body, NotificationRetention::Important);
+    match alert.focus {
+        Some(target) => notification.with_focus(target),
+        None => notification,
     }
 }
 
 pub(crate) fn adapt_stockpile_policy_change_outcomes(
     mut outcomes: MessageReader<StockpilePolicyChangeOutcome>,
+    localization: Res<Localization>,
     mut notifications: MessageWriter<UserFacingNotification>,
 ) {
     for outcome in outcomes.read().copied() {
-        notifications.write(stockpile_policy_notification(outcome));
+        notifications.write(stockpile_policy_notification(&localization, outcome));
     }
 }
 
-fn stockpile_policy_notification(outcome: StockpilePolicyChangeOutcome) -> UserFacingNotification {
+fn stockpile_policy_notification(
+    text: &Localization,
+    outcome: StockpilePolicyChangeOutcome,
+) -> UserFacingNotification {
     let (severity, title) = if outcome.eligible() == 0 {
         (
             NotificationSeverity::Warning,
-            "Stockpile policy not applied",
+            "notify.stockpile_policy.not_applied",
         )
     } else if outcome.has_adjustments_or_skips() {
         (
             NotificationSeverity::Warning,
-            "Stockpile policy partially applied",
+            "notify.stockpile_policy.partial",
         )
     } else if outcome.applied == 0 {
-        (NotificationSeverity::Info, "Stockpile policy unchanged")
+        (
+            NotificationSeverity::Info,
+            "notify.stockpile_policy.unchanged",
+        )
     } else {
-        (NotificationSeverity::Success, "Stockpile policy updated")
+        (
+            NotificationSeverity::Success,
+            "notify.stockpile_policy.updated",
+        )
     };
 
-    let mut details = vec![format!(
-        "Changed {} managed cell(s); {} already matched.",
-        outcome.applied, outcome.unchanged
+    let mut details = vec![text.format(
+        "notify.stockpile_policy.changed",
+        &[
+            ("applied", &outcome.applied),
+            ("unchanged", &outcome.unchanged),
+        ],
     )];
-    if outcome.skipped_stale > 0 {
-        details.push(format!(
-            "Skipped {} cell(s) that no longer exist.",
-            outcome.skipped_stale
-        ));
-    }
-    if outcome.skipped_unmanaged > 0 {
-        details.push(format!(
-            "Skipped {} unsupported or special storage target(s).",
-            outcome.skipped_unmanaged
-        ));
-    }
-    if outcome.target_clamped > 0 {
-        details.push(format!(
-            "Clamped target amount to capacity on {} cell(s).",
-            outcome.target_clamped
-        ));
-    }
-    if outcome.requested > outcome.unique {
-        details.push(format!(
-            "Ignored {} duplicate target(s).",
-            outcome.requested - outcome.unique
-        ));
+    for (count, id) in [
+        (
+            outcome.skipped_stale,
+            "notify.stockpile_policy.skipped_stale",
+        ),
+        (
+            outcome.skipped_unmanaged,
+            "notify.stockpile_policy.skipped_unmanaged",
+        ),
+        (outcome.target_clamped, "notify.stockpile_policy.clamped"),
+        (
+            outcome.requested.saturating_sub(outcome.unique),
+            "notify.stockpile_policy.duplicates",
+        ),
+    ] {
+        if count > 0 {
+            details.push(text.format(id, &[("count", &count)]));
+        }
     }
 
     UserFacingNotification::new(
@@ -82,76 +333,164 @@ fn stockpile_policy_notification(outcome: StockpilePolicyChangeOutcome) -> UserF
             outcome.unique,
         ),
         severity,
-        title,
+        text.text(title).to_string(),
         details.join(" "),
         NotificationRetention::ToastOnly,
     )
 }
 
-fn notification_from_outcome(outcome: &SaveLoadOutcome) -> UserFacingNotification {
-    let target = safe_target(&outcome.target);
+pub(crate) fn adapt_supply_route_change_outcomes(
+    mut outcomes: MessageReader<SupplyRouteChangeOutcome>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    for outcome in outcomes.read().copied() {
+        if let Some(notification) = supply_route_notification(&localization, outcome) {
+            notifications.write(notification);
+        }
+    }
+}
+
+/// Target/filter edits are reflected in the inspector itself, so only lifecycle changes toast.
+fn supply_route_notification(
+    text: &Localization,
+    outcome: SupplyRouteChangeOutcome,
+) -> Option<UserFacingNotification> {
+    let (key, severity, title, body) = match outcome {
+        SupplyRouteChangeOutcome::Created {
+            route,
+            source_cells,
+            destination_cells,
+        } => (
+            format!("supply_route:created:{}", route.to_bits()),
+            NotificationSeverity::Success,
+            "notify.supply_route.created",
+            text.format(
+                "notify.supply_route.created_body",
+                &[
+                    ("sources", &source_cells),
+                    ("destinations", &destination_cells),
+                ],
+            ),
+        ),
+        SupplyRouteChangeOutcome::Removed { route } => (
+            format!("supply_route:removed:{}", route.to_bits()),
+            NotificationSeverity::Info,
+            "notify.supply_route.removed",
+            text.text("notify.supply_route.removed_body").to_string(),
+        ),
+        SupplyRouteChangeOutcome::Rejected(rejection) => {
+            let reason = match rejection {
+                SupplyRouteRejection::StaleCell => "notify.supply_route.stale_cell",
+                SupplyRouteRejection::UnmanagedCell => "notify.supply_route.unmanaged_cell",
+                SupplyRouteRejection::DifferentOwner => "notify.supply_route.different_owner",
+                SupplyRouteRejection::SameCluster => "notify.supply_route.same_cluster",
+                SupplyRouteRejection::DestinationAlreadySupplied => {
+                    "notify.supply_route.destination_supplied"
+                }
+                SupplyRouteRejection::NoAcceptedResources => {
+                    "notify.supply_route.no_accepted_resources"
+                }
+                SupplyRouteRejection::StaleRoute => "notify.supply_route.stale_route",
+            };
+            (
+                format!("supply_route:rejected:{reason}"),
+                NotificationSeverity::Warning,
+                "notify.supply_route.rejected",
+                text.text(reason).to_string(),
+            )
+        }
+        SupplyRouteChangeOutcome::Updated { .. } => return None,
+    };
+
+    Some(UserFacingNotification::new(
+        key,
+        severity,
+        text.text(title).to_string(),
+        body,
+        NotificationRetention::ToastOnly,
+    ))
+}
+
+fn notification_from_outcome(
+    text: &Localization,
+    outcome: &SaveLoadOutcome,
+) -> UserFacingNotification {
+    let target = safe_target(text, &outcome.target);
+    let (from_version, to_version) = match outcome.result {
+        SaveLoadResult::Upgraded {
+            from_version,
+            to_version,
+        } => (from_version, to_version),
+        _ => (0, 0),
+    };
     let (severity, title, body) = match outcome.result {
         SaveLoadResult::Succeeded => match outcome.operation {
             SaveLoadOperation::Save => (
                 NotificationSeverity::Success,
-                "Game saved",
-                format!("Saved {target}."),
+                "notify.save.saved.title",
+                "notify.save.saved.body",
             ),
             SaveLoadOperation::Load => (
                 NotificationSeverity::Success,
-                "Game loaded",
-                format!("Loaded {target}."),
+                "notify.save.loaded.title",
+                "notify.save.loaded.body",
             ),
         },
+        SaveLoadResult::Upgraded { .. } => (
+            NotificationSeverity::Success,
+            "notify.save.upgraded.title",
+            "notify.save.upgraded.body",
+        ),
         SaveLoadResult::Failed(SaveLoadFailureKind::SaveSerialize) => (
             NotificationSeverity::Error,
-            "Save failed",
-            format!("Could not prepare save data for {target}."),
+            "notify.save.save_failed.title",
+            "notify.save.serialize_failed.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::SaveWrite) => (
             NotificationSeverity::Error,
-            "Save failed",
-            format!("Could not write {target}."),
+            "notify.save.save_failed.title",
+            "notify.save.write_failed.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::LoadNotFound) => (
             NotificationSeverity::Warning,
-            "Save not found",
-            format!("{target} does not exist."),
+            "notify.save.not_found.title",
+            "notify.save.not_found.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::LoadRead) => (
             NotificationSeverity::Error,
-            "Load failed",
-            format!("Could not read {target}."),
+            "notify.save.load_failed.title",
+            "notify.save.read_failed.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::UnsupportedFormat) => (
             NotificationSeverity::Error,
-            "Unsupported save",
-            format!("{target} uses an unsupported save format."),
+            "notify.save.unsupported.title",
+            "notify.save.unsupported.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::InvalidData) => (
             NotificationSeverity::Error,
-            "Invalid save data",
-            format!("{target} is invalid or damaged."),
+            "notify.save.invalid.title",
+            "notify.save.invalid.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::SeedMismatch) => (
             NotificationSeverity::Error,
-            "World seed mismatch",
-            format!("{target} belongs to a different generated world."),
+            "notify.save.seed_mismatch.title",
+            "notify.save.seed_mismatch.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::MissingPrerequisite) => (
             NotificationSeverity::Error,
-            "Load unavailable",
-            format!("The current session cannot prepare {target} for loading."),
+            "notify.save.unavailable.title",
+            "notify.save.unavailable.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::ApplyRecovered) => (
             NotificationSeverity::Warning,
-            "Load failed; world restored",
-            format!("Could not apply {target}. The previous world was restored."),
+            "notify.save.restored.title",
+            "notify.save.restored.body",
         ),
         SaveLoadResult::Failed(SaveLoadFailureKind::RecoveryFailed) => (
             NotificationSeverity::Error,
-            "Load recovery failed",
-            format!("Could not load {target}, and the previous world could not be restored."),
+            "notify.save.recovery_failed.title",
+            "notify.save.recovery_failed.body",
         ),
     };
 
@@ -163,19 +502,26 @@ fn notification_from_outcome(outcome: &SaveLoadOutcome) -> UserFacingNotificatio
             outcome.result.key_part()
         ),
         severity,
-        title,
-        body,
+        text.text(title).to_string(),
+        text.format(
+            body,
+            &[
+                ("target", &target),
+                ("from", &from_version),
+                ("to", &to_version),
+            ],
+        ),
         NotificationRetention::Important,
     )
 }
 
-fn safe_target(target: &str) -> &str {
+fn safe_target<'a>(text: &'a Localization, target: &'a str) -> &'a str {
     if target.is_empty()
         || target.len() > 96
         || target.contains(['/', '\\'])
         || target.chars().any(char::is_control)
     {
-        "Current save"
+        text.text("notify.save.current_save")
     } else {
         target
     }
@@ -184,6 +530,11 @@ fn safe_target(target: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hw_core::locale::Locale;
+
+    fn english() -> Localization {
+        Localization::shipped(Locale::En)
+    }
 
     const FAILURES: [SaveLoadFailureKind; 10] = [
         SaveLoadFailureKind::SaveSerialize,
@@ -203,11 +554,14 @@ mod tests {
         for result in std::iter::once(SaveLoadResult::Succeeded)
             .chain(FAILURES.into_iter().map(SaveLoadResult::Failed))
         {
-            let notification = notification_from_outcome(&SaveLoadOutcome {
-                operation: SaveLoadOperation::Load,
-                target: "/private/user/secret.ron\nraw error".to_owned(),
-                result,
-            });
+            let notification = notification_from_outcome(
+                &english(),
+                &SaveLoadOutcome {
+                    operation: SaveLoadOperation::Load,
+                    target: "/private/user/secret.ron\nraw error".to_owned(),
+                    result,
+                },
+            );
 
             assert_eq!(notification.retention, NotificationRetention::Important);
             assert!(!notification.body.contains("/private"));
@@ -218,21 +572,30 @@ mod tests {
 
     #[test]
     fn severity_and_dedupe_key_keep_distinct_terminal_meanings() {
-        let success = notification_from_outcome(&SaveLoadOutcome {
-            operation: SaveLoadOperation::Save,
-            target: "world.scn.ron".to_owned(),
-            result: SaveLoadResult::Succeeded,
-        });
-        let missing = notification_from_outcome(&SaveLoadOutcome {
-            operation: SaveLoadOperation::Load,
-            target: "world.scn.ron".to_owned(),
-            result: SaveLoadResult::Failed(SaveLoadFailureKind::LoadNotFound),
-        });
-        let recovered = notification_from_outcome(&SaveLoadOutcome {
-            operation: SaveLoadOperation::Load,
-            target: "world.scn.ron".to_owned(),
-            result: SaveLoadResult::Failed(SaveLoadFailureKind::ApplyRecovered),
-        });
+        let success = notification_from_outcome(
+            &english(),
+            &SaveLoadOutcome {
+                operation: SaveLoadOperation::Save,
+                target: "world.scn.ron".to_owned(),
+                result: SaveLoadResult::Succeeded,
+            },
+        );
+        let missing = notification_from_outcome(
+            &english(),
+            &SaveLoadOutcome {
+                operation: SaveLoadOperation::Load,
+                target: "world.scn.ron".to_owned(),
+                result: SaveLoadResult::Failed(SaveLoadFailureKind::LoadNotFound),
+            },
+        );
+        let recovered = notification_from_outcome(
+            &english(),
+            &SaveLoadOutcome {
+                operation: SaveLoadOperation::Load,
+                target: "world.scn.ron".to_owned(),
+                result: SaveLoadResult::Failed(SaveLoadFailureKind::ApplyRecovered),
+            },
+        );
 
         assert_eq!(success.severity, NotificationSeverity::Success);
         assert_eq!(missing.severity, NotificationSeverity::Warning);
@@ -241,35 +604,67 @@ mod tests {
         assert_ne!(missing.key, recovered.key);
     }
 
+    #[test]
+    fn upgraded_load_names_both_format_versions() {
+        let notification = notification_from_outcome(
+            &english(),
+            &SaveLoadOutcome {
+                operation: SaveLoadOperation::Load,
+                target: "world.scn.ron".to_owned(),
+                result: SaveLoadResult::Upgraded {
+                    from_version: 1,
+                    to_version: 2,
+                },
+            },
+        );
+
+        assert_eq!(notification.severity, NotificationSeverity::Success);
+        assert_eq!(notification.retention, NotificationRetention::Important);
+        assert_eq!(notification.title, "Save upgraded");
+        assert!(notification.body.contains("world.scn.ron"));
+        assert!(notification.body.contains("v1"));
+        assert!(notification.body.contains("v2"));
+        assert!(notification.key.as_str().ends_with(":upgraded"));
+    }
+
     #[test]
     fn stockpile_policy_outcomes_distinguish_success_partial_and_no_target() {
-        let success = stockpile_policy_notification(StockpilePolicyChangeOutcome {
-            requested: 2,
-            unique: 2,
-            applied: 2,
-            unchanged: 0,
-            skipped_stale: 0,
-            skipped_unmanaged: 0,
-            target_clamped: 0,
-        });
-        let partial = stockpile_policy_notification(StockpilePolicyChangeOutcome {
-            requested: 3,
-            unique: 3,
-            applied: 1,
-            unchanged: 0,
-            skipped_stale: 1,
-            skipped_unmanaged: 1,
-            target_clamped: 1,
-        });
-        let none = stockpile_policy_notification(StockpilePolicyChangeOutcome {
-            requested: 1,
-            unique: 1,
-            applied: 0,
-            unchanged: 0,
-            skipped_stale: 0,
-            skipped_unmanaged: 1,
-            target_clamped: 0,
-        });
+        let success = stockpile_policy_notification(
+            &english(),
+            StockpilePolicyChangeOutcome {
+                requested: 2,
+                unique: 2,
+                applied: 2,
+                unchanged: 0,
+                skipped_stale: 0,
+                skipped_unmanaged: 0,
+                target_clamped: 0,
+            },
+        );
+        let partial = stockpile_policy_notification(
+            &english(),
+            StockpilePolicyChangeOutcome {
+                requested: 3,
+                unique: 3,
+                applied: 1,
+                unchanged: 0,
+                skipped_stale: 1,
+                skipped_unmanaged: 1,
+                target_clamped: 1,
+            },
+        );
+        let none = stockpile_policy_notification(
+            &english(),
+            StockpilePolicyChangeOutcome {
+                requested: 1,
+                unique: 1,
+                applied: 0,
+                unchanged: 0,
+                skipped_stale: 0,
+                skipped_unmanaged: 1,
+                target_clamped: 0,
+            },
+        );
 
         assert_eq!(success.severity, NotificationSeverity::Success);
         assert_eq!(partial.severity, NotificationSeverity::Warning);
@@ -278,4 +673,65 @@ mod tests {
         assert!(partial.body.contains("unsupported or special storage"));
         assert!(partial.body.contains("Clamped target amount"));
     }
+
+    #[test]
+    fn supply_route_lifecycle_toasts_but_edits_stay_quiet() {
+        let route = Entity::from_raw_u32(3).expect("valid test entity");
+        let created = supply_route_notification(
+            &english(),
+            SupplyRouteChangeOutcome::Created {
+                route,
+                source_cells: 4,
+                destination_cells: 2,
+            },
+        )
+        .expect("created toast");
+        let rejected = supply_route_notification(
+            &english(),
+            SupplyRouteChangeOutcome::Rejected(SupplyRouteRejection::DifferentOwner),
+        )
+        .expect("rejected toast");
+
+        assert_eq!(created.severity, NotificationSeverity::Success);
+        assert!(created.body.contains("4 source cell(s)"));
+        assert_eq!(rejected.severity, NotificationSeverity::Warning);
+        assert!(rejected.body.contains("same yard"));
+        assert!(
+            supply_route_notification(&english(), SupplyRouteChangeOutcome::Updated { route })
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn alerts_are_important_focused_and_keyed_per_rule_and_scope() {
+        let entity = Entity::from_raw_u32(7).expect("valid test entity");
+        let grid = ColonyAlert {
+            rule_id: 3,
+            scope: Some(entity),
+            focus: None,
+            finding: AlertFinding::GridBlackout {
+                generation: 1.0,
+                consumption: 2.0,
+            },
+        };
+        let stress = ColonyAlert {
+            rule_id: 4,
+            scope: None,
+            focus: Some(entity),
+            finding: AlertFinding::SoulStress {
+                count: 2,
+                peak: 0.97,
+                threshold: 0.9,
+            },
+        };
+
+        let grid = alert_notification(&english(), &grid);
+        let stress = alert_notification(&english(), &stress);
+        assert_eq!(grid.retention, NotificationRetention::Important);
+        assert_eq!(grid.severity, NotificationSeverity::Error);
+        assert_eq!(grid.key.as_str(), format!("alert:3:{}", entity.to_bits()));
+        assert_eq!(stress.key.as_str(), "alert:4");
+        assert_eq!(stress.focus, Some(entity));
+        assert!(stress.body.contains("2 Soul(s) above 0.90"));
+    }
 }
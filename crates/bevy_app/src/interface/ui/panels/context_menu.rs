@@ -9,6 +9,7 @@ use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
 use bevy::ui_widgets::popover::{Popover, PopoverAlign, PopoverPlacement, PopoverSide};
 use hw_ui::components::*;
+use hw_ui::locale::LocalizedText;
 use hw_ui::theme::UiTheme;
 
 type BuildingOrBlueprintQuery<'w, 's> = Query<
@@ -68,7 +69,7 @@ pub struct ContextMenuRenderAssets<'w> {
 pub fn context_menu_system(
     mut commands: Commands,
     input: ContextMenuInput,
-    mut selected_entity: ResMut<crate::interface::selection::SelectedEntity>,
+    mut selection: crate::interface::selection::SelectionWriter,
     q_context_menu: Query<Entity, With<ContextMenu>>,
     classify_queries: ContextMenuClassifyQueries,
     render_assets: ContextMenuRenderAssets,
@@ -130,7 +131,7 @@ pub fn context_menu_system(
         return;
     };
 
-    selected_entity.0 = Some(target_entity);
+    selection.select(Some(target_entity));
 
     let anchor = commands
         .spawn((
@@ -190,21 +191,21 @@ pub fn context_menu_system(
                 ContextTarget::Familiar(entity) => {
                     spawn_menu_item(
                         menu,
-                        "Inspect (Pin)",
+                        "ui.context_menu.inspect",
                         MenuAction::InspectEntity(entity),
                         &game_assets,
                         &theme,
                     );
                     spawn_menu_item(
                         menu,
-                        "Edit Task Area",
+                        "ui.context_menu.edit_task_area",
                         MenuAction::SelectAreaTask,
                         &game_assets,
                         &theme,
                     );
                     spawn_menu_item(
                         menu,
-                        "Open Operation",
+                        "ui.context_menu.open_operation",
                         MenuAction::OpenOperationDialog,
                         &game_assets,
                         &theme,
@@ -213,7 +214,7 @@ pub fn context_menu_system(
                 ContextTarget::Soul(entity) => {
                     spawn_menu_item(
                         menu,
-                        "Inspect (Pin)",
+                        "ui.context_menu.inspect",
                         MenuAction::InspectEntity(entity),
                         &game_assets,
                         &theme,
@@ -222,7 +223,7 @@ pub fn context_menu_system(
                 ContextTarget::Building(entity) => {
                     spawn_menu_item(
                         menu,
-                        "Inspect (Pin)",
+                        "ui.context_menu.inspect",
                         MenuAction::InspectEntity(entity),
                         &game_assets,
                         &theme,
@@ -230,9 +231,15 @@ pub fn context_menu_system(
                     if let Ok(door) = q_doors.get(entity) {
                         let (label, action) =
                             if door.state == crate::systems::jobs::DoorState::Locked {
-                                ("Unlock Door", MenuAction::ToggleDoorLock(entity))
+                                (
+                                    "ui.context_menu.unlock_door",
+                                    MenuAction::ToggleDoorLock(entity),
+                                )
                             } else {
-                                ("Lock Door", MenuAction::ToggleDoorLock(entity))
+                                (
+                                    "ui.context_menu.lock_door",
+                                    MenuAction::ToggleDoorLock(entity),
+                                )
                             };
                         spawn_menu_item(menu, label, action, &game_assets, &theme);
                     }
@@ -240,7 +247,7 @@ pub fn context_menu_system(
                 ContextTarget::Resource(entity) => {
                     spawn_menu_item(
                         menu,
-                        "Inspect (Pin)",
+                        "ui.context_menu.inspect",
                         MenuAction::InspectEntity(entity),
                         &game_assets,
                         &theme,
@@ -272,7 +279,7 @@ fn classify_target(
 
 fn spawn_menu_item(
     parent: &mut ChildSpawnerCommands,
-    label: &str,
+    label_id: &'static str,
     action: MenuAction,
     game_assets: &crate::assets::GameAssets,
     theme: &UiTheme,
@@ -292,7 +299,7 @@ fn spawn_menu_item(
         ))
         .with_children(|row| {
             row.spawn((
-                Text::new(label),
+                LocalizedText(label_id),
                 TextFont {
                     font: game_assets.font_ui.clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -3,6 +3,7 @@
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use hw_core::jobs::WorkType;
+use hw_core::locale::Localization;
 use hw_core::relationships::TaskWorkers;
 use hw_familiar_ai::AutoGatherDesignation;
 use hw_jobs::{
@@ -22,6 +23,7 @@ use hw_ui::panels::task_list::{
     TaskCancelKind, TaskDashboardActionState, TaskListDirty, TaskPriorityAdjustment,
     TaskPriorityTier,
 };
+use hw_ui::selection::SelectedEntities;
 
 use crate::input_actions::{ForegroundUiGate, PendingWorldInputCapture};
 use crate::systems::command::area_selection::cancel_single_designation;
@@ -179,6 +181,16 @@ pub struct TaskActionOutcome {
     pub result: TaskActionResult,
 }
 
+/// 複数選択に対する一括アクションの集計結果。
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskGroupActionOutcome {
+    pub action: TaskActionKind,
+    /// 選択全体が拒否された理由（`Paused` / `Captured`）
+    pub blocked: Option<TaskActionResult>,
+    pub applied: usize,
+    pub skipped: usize,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TaskActionKind {
     AdjustPriority(TaskPriorityAdjustment),
@@ -222,18 +234,51 @@ pub struct TaskActionApplyQueries<'w, 's> {
     targets: TaskActionTargetQuery<'w, 's>,
     floor_sites: Query<'w, 's, (), With<FloorConstructionSite>>,
     wall_sites: Query<'w, 's, (), With<WallConstructionSite>>,
+    selection: Res<'w, SelectedEntities>,
 }
 
 pub fn apply_task_action_intents_system(
     mut commands: Commands,
     mut intents: MessageReader<UiIntent>,
-    mut outcomes: MessageWriter<TaskActionOutcome>,
+    (mut outcomes, mut group_outcomes): (
+        MessageWriter<TaskActionOutcome>,
+        MessageWriter<TaskGroupActionOutcome>,
+    ),
     time: Res<Time<Virtual>>,
     ui_input_state: Res<UiInputState>,
     pending_capture: Res<PendingWorldInputCapture>,
     mut queries: TaskActionApplyQueries,
 ) {
     for intent in intents.read().copied() {
+        let blocked = if time.is_paused() {
+            Some(TaskActionResult::Paused)
+        } else if ui_input_state.world_input_captured || pending_capture.overlay().is_some() {
+            Some(TaskActionResult::Captured)
+        } else {
+            None
+        };
+
+        let group_request = match intent {
+            UiIntent::AdjustSelectedTaskPriority(adjustment) => {
+                Some(TaskActionRequest::AdjustPriority(adjustment))
+            }
+            UiIntent::CancelSelectedTasks => Some(TaskActionRequest::CancelAny),
+            _ => None,
+        };
+        if let Some(request) = group_request {
+            let mut outcome = TaskGroupActionOutcome {
+                action: request.action_kind(),
+                blocked,
+                applied: 0,
+                skipped: 0,
+            };
+            if blocked.is_none() {
+                apply_group_task_action(&mut commands, &mut queries, request, &mut outcome);
+            }
+            group_outcomes.write(outcome);
+            continue;
+        }
+
         let request = match intent {
             UiIntent::AdjustTaskPriority {
                 entity,
@@ -260,11 +305,7 @@ pub fn apply_task_action_intents_system(
         };
         let action = request.action_kind();
 
-        let result = if time.is_paused() {
-            TaskActionResult::Paused
-        } else if ui_input_state.world_input_captured || pending_capture.overlay().is_some() {
-            TaskActionResult::Captured
-        } else {
+        let result = blocked.unwrap_or_else(|| {
             apply_live_task_action(
                 &mut commands,
                 &mut queries,
@@ -272,7 +313,7 @@ pub fn apply_task_action_intents_system(
                 expected_work_type,
                 request,
             )
-        };
+        });
         outcomes.write(TaskActionOutcome {
             entity,
             action,
@@ -285,13 +326,45 @@ pub fn apply_task_action_intents_system(
 enum TaskActionRequest {
     AdjustPriority(TaskPriorityAdjustment),
     Cancel(TaskCancelKind),
+    /// 一括取り消し用。対象ごとの live capability が示す取り消し方法を使う。
+    CancelAny,
 }
 
 impl TaskActionRequest {
     const fn action_kind(self) -> TaskActionKind {
         match self {
             Self::AdjustPriority(adjustment) => TaskActionKind::AdjustPriority(adjustment),
-            Self::Cancel(_) => TaskActionKind::Cancel,
+            Self::Cancel(_) | Self::CancelAny => TaskActionKind::Cancel,
+        }
+    }
+}
+
+/// 選択中の Designation へ同じアクションを 1 件ずつ live 検証しながら適用する。
+/// Designation を持たない選択（Soul・ドアなど）は対象外として数えない。
+fn apply_group_task_action(
+    commands: &mut Commands,
+    queries: &mut TaskActionApplyQueries<'_, '_>,
+    request: TaskActionRequest,
+    outcome: &mut TaskGroupActionOutcome,
+) {
+    let targets: Vec<(Entity, WorkType)> = queries
+        .selection
+        .iter()
+        .filter_map(|entity| {
+            queries
+                .targets
+                .get(entity)
+                .ok()
+                .map(|(designation, ..)| (entity, designation.work_type))
+        })
+        .collect();
+
+    for (entity, work_type) in targets {
+        match apply_live_task_action(commands, queries, entity, work_type, request) {
+            TaskActionResult::PriorityChanged(_)
+            | TaskActionResult::CancellationRequested
+            | TaskActionResult::MalformedRequestClosed => outcome.applied += 1,
+            _ => outcome.skipped += 1,
         }
     }
 }
@@ -353,11 +426,14 @@ fn apply_live_task_action(
             priority.0 = next;
             TaskActionResult::PriorityChanged(TaskPriorityTier::from_priority(next))
         }
-        TaskActionRequest::Cancel(expected_kind) => {
-            if capabilities.cancel != Some(expected_kind) {
-                return TaskActionResult::Stale;
-            }
-            match expected_kind {
+        TaskActionRequest::Cancel(_) | TaskActionRequest::CancelAny => {
+            let cancel_kind = match (request, capabilities.cancel) {
+                (TaskActionRequest::Cancel(expected), Some(live)) if expected == live => live,
+                (TaskActionRequest::CancelAny, Some(live)) => live,
+                (TaskActionRequest::CancelAny, None) => return TaskActionResult::Unsupported,
+                _ => return TaskActionResult::Stale,
+            };
+            match cancel_kind {
                 TaskCancelKind::GenericDesignation => {
                     cancel_single_designation(commands, entity, workers, false, false, None);
                     TaskActionResult::CancellationRequested
@@ -411,44 +487,49 @@ fn apply_live_task_action(
 
 pub fn adapt_task_action_outcomes(
     mut outcomes: MessageReader<TaskActionOutcome>,
+    localization: Res<Localization>,
     mut notifications: MessageWriter<UserFacingNotification>,
 ) {
+    let text = &*localization;
     for outcome in outcomes.read() {
         let (severity, title, body) = match outcome.result {
             TaskActionResult::PriorityChanged(tier) => (
                 NotificationSeverity::Success,
-                "Priority changed",
-                format!("Task priority is now {}.", tier.label()),
+                "notify.task.priority_changed.title",
+                text.format(
+                    "notify.task.priority_changed.body",
+                    &[("priority", &text.text(tier.text_id()))],
+                ),
             ),
             TaskActionResult::CancellationRequested => (
                 NotificationSeverity::Success,
-                "Cancellation requested",
-                "The task owner will finish cleanup.".to_string(),
+                "notify.task.cancel_requested.title",
+                text.text("notify.task.cancel_requested.body").to_string(),
             ),
             TaskActionResult::MalformedRequestClosed => (
                 NotificationSeverity::Warning,
-                "Task closed",
-                "Incomplete transport data was cleaned up safely.".to_string(),
+                "notify.task.closed.title",
+                text.text("notify.task.closed.body").to_string(),
             ),
             TaskActionResult::Stale => (
                 NotificationSeverity::Warning,
-                "Task changed",
-                "The selected task is no longer available.".to_string(),
+                "notify.task.stale.title",
+                text.text("notify.task.stale.body").to_string(),
             ),
             TaskActionResult::Unsupported => (
                 NotificationSeverity::Warning,
-                "Action unavailable",
-                "This task is read-only.".to_string(),
+                "notify.task.unavailable.title",
+                text.text("notify.task.unavailable.body").to_string(),
             ),
             TaskActionResult::Paused => (
                 NotificationSeverity::Warning,
-                "Action paused",
-                "Resume the simulation before changing tasks.".to_string(),
+                "notify.task.paused.title",
+                text.text("notify.task.paused.body").to_string(),
             ),
             TaskActionResult::Captured => (
                 NotificationSeverity::Warning,
-                "Action blocked",
-                "Close the active dialog before changing tasks.".to_string(),
+                "notify.task.blocked.title",
+                text.text("notify.task.blocked.body").to_string(),
             ),
         };
         notifications.write(UserFacingNotification::new(
@@ -460,7 +541,52 @@ pub fn adapt_task_action_outcomes(
                 result_key(outcome.result),
             ),
             severity,
-            title,
+            text.text(title),
+            body,
+            NotificationRetention::ToastOnly,
+        ));
+    }
+}
+
+pub fn adapt_task_group_action_outcomes(
+    mut outcomes: MessageReader<TaskGroupActionOutcome>,
+    localization: Res<Localization>,
+    mut notifications: MessageWriter<UserFacingNotification>,
+) {
+    let text = &*localization;
+    for outcome in outcomes.read() {
+        let (severity, title, body) = match outcome.blocked {
+            Some(TaskActionResult::Paused) => (
+                NotificationSeverity::Warning,
+                "notify.task.paused.title",
+                text.text("notify.task.paused.body").to_string(),
+            ),
+            Some(_) => (
+                NotificationSeverity::Warning,
+                "notify.task.blocked.title",
+                text.text("notify.task.blocked.body").to_string(),
+            ),
+            None if outcome.applied == 0 => (
+                NotificationSeverity::Warning,
+                "notify.task.unavailable.title",
+                text.text("notify.task.group_unavailable.body").to_string(),
+            ),
+            None => (
+                NotificationSeverity::Success,
+                match outcome.action {
+                    TaskActionKind::AdjustPriority(_) => "notify.task.priority_changed.title",
+                    TaskActionKind::Cancel => "notify.task.cancel_requested.title",
+                },
+                text.format(
+                    "notify.task.group_updated.body",
+                    &[("applied", &outcome.applied), ("skipped", &outcome.skipped)],
+                ),
+            ),
+        };
+        notifications.write(UserFacingNotification::new(
+            format!("task-group-action:{}", action_key(outcome.action)),
+            severity,
+            text.text(title),
             body,
             NotificationRetention::ToastOnly,
         ));
@@ -807,6 +933,8 @@ mod tests {
             .init_resource::<OutcomeReceipts>()
             .add_message::<UiIntent>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
+            .init_resource::<SelectedEntities>()
             .add_message::<hw_core::events::SoulTaskUnassignRequest>()
             .add_systems(
                 Update,
@@ -911,6 +1039,8 @@ mod tests {
             .init_resource::<OutcomeReceipts>()
             .add_message::<UiIntent>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
+            .init_resource::<SelectedEntities>()
             .add_systems(
                 Update,
                 (apply_task_action_intents_system, collect_outcomes).chain(),
@@ -1031,4 +1161,69 @@ mod tests {
             "rejected intents must be drained instead of applying later",
         );
     }
+
+    #[test]
+    fn task_group_action_raises_priority_on_every_selected_designation() {
+        #[derive(Resource, Default)]
+        struct GroupReceipts(Vec<TaskGroupActionOutcome>);
+
+        fn collect_group_outcomes(
+            mut outcomes: MessageReader<TaskGroupActionOutcome>,
+            mut receipts: ResMut<GroupReceipts>,
+        ) {
+            receipts.0.extend(outcomes.read().copied());
+        }
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<UiInputState>()
+            .init_resource::<PendingWorldInputCapture>()
+            .init_resource::<GroupReceipts>()
+            .add_message::<UiIntent>()
+            .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
+            .init_resource::<SelectedEntities>()
+            .add_systems(
+                Update,
+                (apply_task_action_intents_system, collect_group_outcomes).chain(),
+            );
+
+        let tasks: Vec<Entity> = (0..2)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((
+                        Designation {
+                            work_type: WorkType::Chop,
+                        },
+                        Tree,
+                        PlayerIssuedDesignation,
+                        Priority(2),
+                    ))
+                    .id()
+            })
+            .collect();
+        let bystander = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<SelectedEntities>()
+            .extend(tasks.iter().copied().chain([bystander]));
+        app.world_mut()
+            .write_message(UiIntent::AdjustSelectedTaskPriority(
+                TaskPriorityAdjustment::Increase,
+            ));
+
+        app.update();
+
+        for task in &tasks {
+            assert_eq!(app.world().get::<Priority>(*task).unwrap().0, 5);
+        }
+        assert_eq!(
+            app.world().resource::<GroupReceipts>().0,
+            vec![TaskGroupActionOutcome {
+                action: TaskActionKind::AdjustPriority(TaskPriorityAdjustment::Increase),
+                blocked: None,
+                applied: 2,
+                skipped: 0,
+            }]
+        );
+    }
 }
@@ -10,6 +10,7 @@ use crate::systems::logistics::transport_request::{
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use hw_core::ecs::drain_removed;
+use hw_core::locale::Localization;
 use hw_core::relationships::TaskWorkers;
 use hw_familiar_ai::FamiliarTaskCandidateDiagnostics;
 use hw_jobs::TaskDiagnosticInputRevisions;
@@ -44,6 +45,7 @@ pub fn detect_task_list_changed_components(
     diagnostics: Res<FamiliarTaskCandidateDiagnostics>,
     auto_build_diagnostics: Res<BlueprintAutoBuildDiagnostics>,
     revisions: Res<TaskDiagnosticInputRevisions>,
+    localization: Res<Localization>,
     detectors: TaskChangedDetectors,
 ) {
     let TaskChangedDetectors {
@@ -82,7 +84,8 @@ pub fn detect_task_list_changed_components(
         || !q_bone_piles.is_empty()
         || diagnostics.is_changed()
         || auto_build_diagnostics.is_changed()
-        || revisions.is_changed();
+        || revisions.is_changed()
+        || localization.is_changed();
 
     if task_data_changed {
         dirty.mark_all();
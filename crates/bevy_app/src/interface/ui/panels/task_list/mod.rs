@@ -6,8 +6,9 @@ mod update;
 mod view_model;
 
 pub use actions::{
-    TaskActionKind, TaskActionOutcome, TaskActionResult, adapt_task_action_outcomes,
-    apply_task_action_intents_system, task_dashboard_action_button_system,
+    TaskActionKind, TaskActionOutcome, TaskActionResult, TaskGroupActionOutcome,
+    adapt_task_action_outcomes, adapt_task_group_action_outcomes, apply_task_action_intents_system,
+    task_dashboard_action_button_system,
 };
 pub use dirty::{detect_task_list_changed_components, detect_task_list_removed_components};
 pub use hw_ui::panels::task_list::{
@@ -1,9 +1,10 @@
 // WorkType の説明文言
 
-use crate::systems::jobs::{Blueprint, BonePile, BuildingType, Rock, SandPile, Tree, WorkType};
-use crate::systems::logistics::ResourceItem;
+use crate::systems::jobs::{Blueprint, BonePile, Rock, SandPile, Tree, WorkType};
 use crate::systems::logistics::transport_request::{TransportRequest, TransportRequestKind};
+use crate::systems::logistics::{ResourceItem, ResourceType};
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 
 pub struct TaskComponentRefs<'a> {
     pub blueprint: Option<&'a Blueprint>,
@@ -15,7 +16,12 @@ pub struct TaskComponentRefs<'a> {
     pub bone_pile: Option<&'a BonePile>,
 }
 
-pub fn generate_task_description(wt: WorkType, entity: Entity, refs: TaskComponentRefs) -> String {
+pub fn generate_task_description(
+    wt: WorkType,
+    entity: Entity,
+    refs: TaskComponentRefs,
+    text: &Localization,
+) -> String {
     let TaskComponentRefs {
         blueprint,
         transport_req,
@@ -25,81 +31,77 @@ pub fn generate_task_description(wt: WorkType, entity: Entity, refs: TaskCompone
         _sand_pile: _,
         bone_pile,
     } = refs;
+    let with_resource = |id: &str, resource: ResourceType| {
+        text.format(id, &[("resource", &text.text(resource.text_id()))])
+    };
     match wt {
         WorkType::Build => {
             if let Some(bp) = blueprint {
-                match bp.kind {
-                    BuildingType::Wall => "Construct Wall".to_string(),
-                    BuildingType::Door => "Construct Door".to_string(),
-                    BuildingType::Floor => "Construct Floor".to_string(),
-                    BuildingType::Tank => "Construct Tank".to_string(),
-                    BuildingType::MudMixer => "Construct Mixer".to_string(),
-                    BuildingType::RestArea => "Construct RestArea".to_string(),
-                    BuildingType::Bridge => "Construct Bridge".to_string(),
-                    BuildingType::SandPile => "Construct SandPile".to_string(),
-                    BuildingType::BonePile => "Construct BonePile".to_string(),
-                    BuildingType::WheelbarrowParking => "Construct Parking".to_string(),
-                    BuildingType::SoulSpa => "Construct Soul Spa".to_string(),
-                    BuildingType::OutdoorLamp => "Construct Outdoor Lamp".to_string(),
-                }
+                text.format(
+                    "ui.task.construct",
+                    &[("building", &text.text(bp.kind.text_id()))],
+                )
             } else {
-                format!("Construct {:?}", entity)
+                text.format("ui.task.construct", &[("building", &format!("{entity:?}"))])
             }
         }
-        WorkType::Move => "Move Building".to_string(),
-        WorkType::Mine => {
-            if rock.is_some() {
-                "Mine Rock".to_string()
+        WorkType::Move => text.text("ui.task.move_building").to_string(),
+        WorkType::Mine => text
+            .text(if rock.is_some() {
+                "ui.task.mine_rock"
             } else {
-                "Mine".to_string()
-            }
-        }
-        WorkType::Chop => {
-            if tree.is_some() {
-                "Chop Tree".to_string()
+                "ui.work_type.mine"
+            })
+            .to_string(),
+        WorkType::Chop => text
+            .text(if tree.is_some() {
+                "ui.task.chop_tree"
             } else {
-                "Chop".to_string()
-            }
-        }
+                "ui.work_type.chop"
+            })
+            .to_string(),
         WorkType::Haul => {
             if let Some(req) = transport_req {
-                if req.kind == TransportRequestKind::DeliverToBlueprint {
-                    format!("Haul {:?} to Build", req.resource_type)
-                } else if req.kind == TransportRequestKind::DeliverToWallConstruction {
-                    format!("Haul {:?} to Wall", req.resource_type)
-                } else if req.kind == TransportRequestKind::DeliverToProvisionalWall {
-                    "Haul StasisMud to Wall".to_string()
-                } else {
-                    format!("Haul {:?} (Req)", req.resource_type)
+                match req.kind {
+                    TransportRequestKind::DeliverToBlueprint => {
+                        with_resource("ui.task.haul_to_build", req.resource_type)
+                    }
+                    TransportRequestKind::DeliverToWallConstruction => {
+                        with_resource("ui.task.haul_to_wall", req.resource_type)
+                    }
+                    TransportRequestKind::DeliverToProvisionalWall => {
+                        with_resource("ui.task.haul_to_wall", ResourceType::StasisMud)
+                    }
+                    _ => with_resource("ui.task.haul_request", req.resource_type),
                 }
             } else if let Some(item) = resource_item {
-                format!("Haul {:?}", item.0)
+                with_resource("ui.task.haul_item", item.0)
             } else {
-                "Haul".to_string()
+                text.text("ui.work_type.haul").to_string()
             }
         }
         WorkType::HaulToMixer => {
             if let Some(req) = transport_req {
-                format!("Haul {:?} to Mixer", req.resource_type)
+                with_resource("ui.task.haul_to_mixer", req.resource_type)
             } else {
-                "Haul to Mixer".to_string()
+                text.text("ui.task.haul_to_mixer_any").to_string()
             }
         }
-        WorkType::HaulWaterToMixer => "Haul Water to Mixer".to_string(),
-        WorkType::GatherWater => "Gather Water".to_string(),
-        WorkType::Refine => "Refine".to_string(),
-        WorkType::WheelbarrowHaul => "Wheelbarrow Haul".to_string(),
-        WorkType::CollectBone => {
-            if bone_pile.is_some() {
-                "Collect Bone Pile".to_string()
+        WorkType::HaulWaterToMixer => text.text("ui.task.haul_water_to_mixer").to_string(),
+        WorkType::GatherWater => text.text("ui.task.gather_water").to_string(),
+        WorkType::Refine => text.text("ui.work_type.refine").to_string(),
+        WorkType::WheelbarrowHaul => text.text("ui.task.wheelbarrow_haul").to_string(),
+        WorkType::CollectBone => text
+            .text(if bone_pile.is_some() {
+                "ui.task.collect_bone_pile"
             } else {
-                "Collect Bone".to_string()
-            }
-        }
-        WorkType::ReinforceFloorTile => "Reinforce Floor".to_string(),
-        WorkType::PourFloorTile => "Pour Floor".to_string(),
-        WorkType::FrameWallTile => "Frame Wall".to_string(),
-        WorkType::CoatWall => "Coat Wall".to_string(),
-        WorkType::GeneratePower => "Generate Power".to_string(),
+                "ui.task.collect_bone"
+            })
+            .to_string(),
+        WorkType::ReinforceFloorTile => text.text("ui.task.reinforce_floor").to_string(),
+        WorkType::PourFloorTile => text.text("ui.task.pour_floor").to_string(),
+        WorkType::FrameWallTile => text.text("ui.task.frame_wall").to_string(),
+        WorkType::CoatWall => text.text("ui.task.coat_wall").to_string(),
+        WorkType::GeneratePower => text.text("ui.task.generate_power").to_string(),
     }
 }
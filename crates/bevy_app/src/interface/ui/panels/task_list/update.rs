@@ -2,9 +2,12 @@
 
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 use hw_ui::components::{LeftPanelMode, TaskListBody};
 use hw_ui::panels::info_panel::InfoPanelPinState;
-use hw_ui::panels::task_list::{TaskDashboardActionState, TaskDashboardViewState};
+use hw_ui::panels::task_list::{
+    TaskDashboardActionState, TaskDashboardViewState, TaskListRenderContext,
+};
 use hw_ui::theme::UiTheme;
 
 use super::{TaskListDirty, view_model::TaskListState};
@@ -13,6 +16,7 @@ use super::{TaskListDirty, view_model::TaskListState};
 pub struct TaskListRenderState<'w> {
     game_assets: Res<'w, crate::assets::GameAssets>,
     theme: Res<'w, UiTheme>,
+    localization: Res<'w, Localization>,
     mode: Res<'w, LeftPanelMode>,
     state: Res<'w, TaskListState>,
     view_state: Res<'w, TaskDashboardViewState>,
@@ -52,8 +56,11 @@ pub fn task_list_update_system(
             &render_state.view_state,
             render_state.pin_state.entity,
             &render_state.action_state,
-            &*render_state.game_assets,
-            &render_state.theme,
+            &TaskListRenderContext {
+                game_assets: &*render_state.game_assets,
+                theme: &render_state.theme,
+                text: &render_state.localization,
+            },
         );
     });
     dirty.clear_list();
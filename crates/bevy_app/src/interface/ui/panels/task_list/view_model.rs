@@ -13,7 +13,9 @@ use crate::systems::logistics::ResourceItem;
 use crate::systems::logistics::transport_request::{
     ManualTransportRequest, TransportRequest, TransportRequestFixedSource,
 };
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 use hw_core::relationships::{ManagedBy, TaskWorkers};
 use hw_familiar_ai::{AutoGatherDesignation, FamiliarTaskCandidateDiagnostics};
 use hw_jobs::{
@@ -81,13 +83,25 @@ type TaskCapabilityQuery<'w, 's> = Query<
     ),
 >;
 
+/// Producer diagnostics that decide each dashboard row's status.
+#[derive(SystemParam)]
+pub struct TaskDiagnosticSources<'w> {
+    familiar: Res<'w, FamiliarTaskCandidateDiagnostics>,
+    auto_build: Res<'w, BlueprintAutoBuildDiagnostics>,
+    revisions: Res<'w, TaskDiagnosticInputRevisions>,
+}
+
 pub fn build_task_list_snapshot(
     designations: &DesignationQuery,
     capabilities: &TaskCapabilityQuery,
-    familiar_diagnostics: &FamiliarTaskCandidateDiagnostics,
-    auto_build_diagnostics: &BlueprintAutoBuildDiagnostics,
-    revisions: &TaskDiagnosticInputRevisions,
+    diagnostics: &TaskDiagnosticSources,
+    text: &Localization,
 ) -> Vec<TaskEntry> {
+    let TaskDiagnosticSources {
+        familiar: familiar_diagnostics,
+        auto_build: auto_build_diagnostics,
+        revisions,
+    } = diagnostics;
     let mut entries = Vec::new();
 
     for (
@@ -120,6 +134,7 @@ pub fn build_task_list_snapshot(
                 _sand_pile: sand_pile,
                 bone_pile,
             },
+            text,
         );
         let status = derive_task_status(
             entity,
@@ -293,9 +308,8 @@ pub fn build_task_summary(designations: &DesignationQuery) -> (usize, usize) {
 pub fn update_task_list_state_system(
     designations: DesignationQuery,
     capabilities: TaskCapabilityQuery,
-    familiar_diagnostics: Res<FamiliarTaskCandidateDiagnostics>,
-    auto_build_diagnostics: Res<BlueprintAutoBuildDiagnostics>,
-    revisions: Res<TaskDiagnosticInputRevisions>,
+    diagnostics: TaskDiagnosticSources,
+    localization: Res<Localization>,
     mut dirty: ResMut<TaskListDirty>,
     mut state: ResMut<TaskListState>,
 ) {
@@ -303,13 +317,8 @@ pub fn update_task_list_state_system(
         return;
     }
 
-    let snapshot = build_task_list_snapshot(
-        &designations,
-        &capabilities,
-        &familiar_diagnostics,
-        &auto_build_diagnostics,
-        &revisions,
-    );
+    let snapshot =
+        build_task_list_snapshot(&designations, &capabilities, &diagnostics, &localization);
     let (summary_total, summary_high) = build_task_summary(&designations);
     let list_changed = !state.initialized || snapshot != state.snapshot;
     let summary_changed = !state.initialized
@@ -428,6 +437,7 @@ mod tests {
             .init_resource::<FamiliarTaskCandidateDiagnostics>()
             .init_resource::<BlueprintAutoBuildDiagnostics>()
             .init_resource::<TaskDiagnosticInputRevisions>()
+            .init_resource::<Localization>()
             .init_resource::<TaskListDirty>()
             .init_resource::<TaskListState>()
             .add_systems(Update, update_task_list_state_system);
@@ -0,0 +1,22 @@
+use crate::systems::GameSystemSet;
+use bevy::prelude::*;
+use hw_ui::alerts::{
+    alert_panel_intent_system, alert_panel_interaction_system, sync_alert_panel_system,
+};
+
+pub struct UiAlertsPlugin;
+
+impl Plugin for UiAlertsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                alert_panel_intent_system,
+                alert_panel_interaction_system,
+                sync_alert_panel_system,
+            )
+                .chain()
+                .in_set(GameSystemSet::Interface),
+        );
+    }
+}
@@ -7,12 +7,14 @@ use crate::interface::selection::floor_placement_system;
 use crate::interface::selection::soul_spa_place_input_system;
 use crate::interface::selection::{
     cleanup_selection_references_system, clear_companion_state_outside_build_mode,
-    update_hover_entity, update_selection_indicator,
+    update_hover_entity, update_selection_box_visual, update_selection_indicator,
 };
+use crate::interface::selection::{layout_export_system, layout_import_system};
 use crate::interface::ui::interaction::handle_ui_intent;
 use crate::interface::ui::vignette::update_vignette_system;
 use crate::systems::GameSystemSet;
-use crate::systems::command::StockpilePolicyRangeEditState;
+use crate::systems::command::{StockpilePolicyRangeEditState, SupplyRouteDraft};
+use crate::systems::layout_blueprint::ActiveLayoutBlueprint;
 use crate::systems::time::game_time_system;
 use bevy::prelude::*;
 use hw_core::game_state::PlayMode;
@@ -28,6 +30,8 @@ pub fn ui_core_plugin() -> UiCorePlugin {
 fn register_ui_core_plugin_systems(app: &mut App) {
     configure_placement_feedback_sets(app);
     app.init_resource::<StockpilePolicyRangeEditState>();
+    app.init_resource::<SupplyRouteDraft>();
+    app.init_resource::<ActiveLayoutBlueprint>();
     app.add_systems(
         Update,
         (
@@ -44,6 +48,7 @@ fn register_ui_core_plugin_systems(app: &mut App) {
         (
             clear_companion_state_outside_build_mode,
             update_selection_indicator,
+            update_selection_box_visual,
         )
             .in_set(GameSystemSet::Interface),
     )
@@ -62,6 +67,9 @@ fn register_ui_core_plugin_systems(app: &mut App) {
             floor_placement_system.run_if(in_state(PlayMode::FloorPlace)),
             building_move_system.run_if(in_state(PlayMode::BuildingMove)),
             soul_spa_place_input_system.run_if(in_state(PlayMode::TaskDesignation)),
+            layout_export_system.run_if(in_state(PlayMode::TaskDesignation)),
+            // モード外では読み込み済みの設計図を破棄するため常に走らせる
+            layout_import_system,
         )
             .in_set(PlacementFeedbackSet::Commit),
     )
@@ -75,6 +83,8 @@ fn register_ui_core_plugin_systems(app: &mut App) {
             hw_ui::interaction::handle_help_navigation_system,
             hw_logistics::apply_stockpile_policy_change_requests_system
                 .before(NotificationSystemSet::Adapt),
+            hw_logistics::apply_supply_route_change_requests_system
+                .before(NotificationSystemSet::Adapt),
             crate::interface::ui::panels::task_list::apply_task_action_intents_system
                 .before(NotificationSystemSet::Adapt),
             crate::interface::ui::menu_visibility_system,
@@ -82,6 +92,8 @@ fn register_ui_core_plugin_systems(app: &mut App) {
             hw_ui::interaction::update_settings_panel_visibility,
             hw_ui::interaction::update_help_panel_visibility_system,
             hw_ui::interaction::update_help_topic_presentation_system,
+            crate::interface::ui::help_controller::refresh_help_shortcut_labels_system,
+            hw_ui::interaction::sync_help_shortcut_text_system,
             hw_ui::interaction::sync_settings_slider_thumbs_system,
             hw_ui::interaction::sync_settings_checkmarks_system,
             crate::interface::ui::update_mode_text_system,
@@ -100,6 +112,9 @@ fn register_ui_core_plugin_systems(app: &mut App) {
             game_time_system,
             crate::interface::ui::update_fps_display_system,
             crate::interface::ui::update_dream_pool_display_system,
+            crate::interface::ui::update_population_display_system,
+            crate::interface::ui::update_contract_panel_system,
+            crate::interface::ui::update_edict_status_system,
             crate::interface::ui::update_dream_loss_popup_ui_system,
             crate::interface::ui::update_speed_button_highlight_system,
             update_vignette_system,
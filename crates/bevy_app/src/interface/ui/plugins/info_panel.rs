@@ -14,8 +14,9 @@ use crate::interface::ui::{
 use crate::systems::GameSystemSet;
 use bevy::prelude::*;
 use bevy::time::Real;
-use hw_ui::components::{LeftPanelMode, SoulRenameState};
+use hw_ui::components::{InfoPanelTab, LeftPanelMode, SoulRenameState};
 use hw_ui::interaction::{soul_rename_button_system, soul_rename_cleanup_system};
+use hw_ui::panels::info_panel_tab_system;
 
 const INSPECTION_REFRESH_INTERVAL_SECS: f32 = 0.1;
 
@@ -47,6 +48,7 @@ fn advance_inspection_refresh_cadence_system(
 
 fn inspection_refresh_should_run(
     selected: Res<crate::interface::selection::SelectedEntity>,
+    selected_entities: Res<crate::interface::selection::SelectedEntities>,
     pin_state: Res<InfoPanelPinState>,
     rename_state: Res<SoulRenameState>,
     cadence: Res<InspectionRefreshCadence>,
@@ -57,6 +59,7 @@ fn inspection_refresh_should_run(
         .or(selected.0)
         .is_some_and(|entity| changed_stockpiles.get(entity).is_ok());
     selected.is_changed()
+        || selected_entities.is_changed()
         || pin_state.is_changed()
         || rename_state.is_changed()
         || inspected_policy_changed
@@ -74,6 +77,7 @@ fn register_ui_info_panel_plugin_systems(app: &mut App) {
     app.init_resource::<InfoPanelPinState>();
     app.init_resource::<InfoPanelNodes>();
     app.init_resource::<LeftPanelMode>();
+    app.init_resource::<InfoPanelTab>();
     app.init_resource::<EntityInspectionViewModel>();
     app.init_resource::<InspectionRefreshCadence>();
     app.init_resource::<TaskListDirty>();
@@ -105,6 +109,7 @@ fn register_ui_info_panel_plugin_systems(app: &mut App) {
             task_list_visual_feedback_system.after(task_list_click_system),
             soul_rename_button_system::<crate::assets::GameAssets>,
             soul_rename_cleanup_system,
+            info_panel_tab_system,
         )
             .in_set(GameSystemSet::Interface),
     );
@@ -112,7 +117,8 @@ fn register_ui_info_panel_plugin_systems(app: &mut App) {
         Update,
         (
             update_entity_inspection_view_model_system
-                .after(hw_logistics::apply_stockpile_policy_change_requests_system),
+                .after(hw_logistics::apply_stockpile_policy_change_requests_system)
+                .after(hw_logistics::apply_supply_route_change_requests_system),
             info_panel_system::<crate::assets::GameAssets>
                 .after(update_entity_inspection_view_model_system)
                 .after(crate::interface::ui::menu_visibility_system)
@@ -189,10 +195,13 @@ mod tests {
         app.insert_resource(Time::<Fixed>::from_hz(60.0))
             .insert_resource(TimeUpdateStrategy::FixedTimesteps(1))
             .init_resource::<SelectedEntity>()
+            .init_resource::<crate::interface::selection::SelectedEntities>()
             .init_resource::<InfoPanelPinState>()
             .init_resource::<EntityInspectionViewModel>()
             .init_resource::<InspectionRefreshCadence>()
+            .init_resource::<crate::systems::command::SupplyRouteDraft>()
             .init_resource::<SoulRenameState>()
+            .init_resource::<hw_core::locale::Localization>()
             .init_resource::<FamiliarSpatialGrid>()
             .init_resource::<StockpileSpatialGrid>()
             .init_resource::<CachedActiveYards>()
@@ -0,0 +1,25 @@
+use crate::systems::GameSystemSet;
+use bevy::prelude::*;
+use hw_ui::minimap::{
+    minimap_interaction_system, sync_minimap_viewport_frame_system, upload_minimap_texture_system,
+};
+
+pub struct UiMinimapPlugin;
+
+impl Plugin for UiMinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                minimap_interaction_system,
+                crate::interface::ui::minimap::sync_minimap_base_system,
+                crate::interface::ui::minimap::sync_minimap_overlays_system,
+                crate::interface::ui::minimap::sync_minimap_viewport_system,
+                sync_minimap_viewport_frame_system,
+                upload_minimap_texture_system,
+            )
+                .chain()
+                .in_set(GameSystemSet::Interface),
+        );
+    }
+}
@@ -1,22 +1,31 @@
+mod alerts;
 mod core;
 mod entity_list;
 mod foundation;
 mod info_panel;
+mod minimap;
 mod notifications;
+mod statistics;
 mod tooltip;
+use alerts::UiAlertsPlugin;
 use bevy::prelude::*;
 pub use core::ui_core_plugin;
 pub use entity_list::ui_entity_list_plugin;
 pub use foundation::UiFoundationPlugin;
 use hw_ui::HwUiPlugin;
 pub use info_panel::ui_info_panel_plugin;
+use minimap::UiMinimapPlugin;
 use notifications::UiNotificationsPlugin;
+use statistics::UiStatisticsPlugin;
 pub use tooltip::ui_tooltip_plugin;
 
 pub fn register_ui_plugins(app: &mut App) {
     let help_content = crate::interface::ui::help_content::build_help_panel_content()
         .expect("player Help catalog must be valid");
+    let help_chrome = crate::interface::ui::help_content::build_help_panel_chrome()
+        .expect("validated Help chrome");
     app.insert_resource(help_content);
+    app.insert_resource(help_chrome);
     app.add_plugins((
         HwUiPlugin,
         UiFoundationPlugin,
@@ -25,6 +34,9 @@ pub fn register_ui_plugins(app: &mut App) {
         ui_tooltip_plugin(),
         ui_info_panel_plugin(),
         ui_entity_list_plugin(),
+        UiMinimapPlugin,
+        UiStatisticsPlugin,
+        UiAlertsPlugin,
     ));
     crate::systems::save::register_load_reset_hook(app, "hw-ui", hw_ui::reset_for_world_replace);
     crate::systems::save::register_load_reset_hook(
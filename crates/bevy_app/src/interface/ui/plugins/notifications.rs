@@ -1,8 +1,8 @@
 use crate::systems::GameSystemSet;
 use bevy::prelude::*;
 use hw_ui::notifications::{
-    NotificationSystemSet, apply_notification_ui_state_system, present_notifications_system,
-    reduce_notifications_system,
+    NotificationSystemSet, apply_notification_ui_state_system, notification_focus_click_system,
+    present_notifications_system, reduce_notifications_system,
 };
 
 pub struct UiNotificationsPlugin;
@@ -24,7 +24,13 @@ impl Plugin for UiNotificationsPlugin {
             (
                 crate::interface::ui::notifications::adapt_save_load_outcomes,
                 crate::interface::ui::notifications::adapt_stockpile_policy_change_outcomes,
+                crate::interface::ui::notifications::adapt_supply_route_change_outcomes,
+                crate::interface::ui::notifications::adapt_soul_redemption_outcomes,
+                crate::interface::ui::notifications::adapt_contract_outcomes,
+                crate::interface::ui::notifications::adapt_edict_outcomes,
+                crate::interface::ui::notifications::adapt_colony_alerts,
                 crate::interface::ui::panels::task_list::adapt_task_action_outcomes,
+                crate::interface::ui::panels::task_list::adapt_task_group_action_outcomes,
             )
                 .in_set(NotificationSystemSet::Adapt),
         )
@@ -33,6 +39,7 @@ impl Plugin for UiNotificationsPlugin {
             (
                 reduce_notifications_system,
                 apply_notification_ui_state_system,
+                notification_focus_click_system,
             )
                 .chain()
                 .in_set(NotificationSystemSet::Reduce),
@@ -47,12 +54,14 @@ impl Plugin for UiNotificationsPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::interface::selection::{SelectedEntities, SelectedEntity};
     use crate::interface::ui::panels::task_list::{
-        TaskActionKind, TaskActionOutcome, TaskActionResult,
+        TaskActionKind, TaskActionOutcome, TaskActionResult, TaskGroupActionOutcome,
     };
     use crate::systems::save::{
         SaveLoadFailureKind, SaveLoadOperation, SaveLoadOutcome, SaveLoadResult,
     };
+    use hw_core::locale::{Locale, Localization};
     use hw_ui::HwUiPlugin;
     use hw_ui::components::UiInputState;
     use hw_ui::notifications::{
@@ -84,9 +93,17 @@ mod tests {
         app.add_plugins((MinimalPlugins, HwUiPlugin, UiNotificationsPlugin))
             .add_message::<SaveLoadOutcome>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
             .add_message::<hw_logistics::StockpilePolicyChangeOutcome>()
+            .add_message::<hw_logistics::SupplyRouteChangeOutcome>()
+            .add_message::<crate::systems::soul_ai::adapters::SoulRedemptionOutcome>()
+            .add_message::<crate::systems::contracts::ContractOutcome>()
+            .add_message::<crate::systems::edicts::EdictOutcome>()
+            .add_message::<crate::systems::alerts::ColonyAlert>()
             .init_resource::<UiTheme>()
             .init_resource::<UiInputState>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
             .init_resource::<PresentTrace>()
             .add_systems(Update, adapt.in_set(NotificationSystemSet::Adapt))
             .add_systems(Update, trace_present.in_set(NotificationSystemSet::Present));
@@ -102,9 +119,17 @@ mod tests {
         app.add_plugins((MinimalPlugins, HwUiPlugin, UiNotificationsPlugin))
             .add_message::<SaveLoadOutcome>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
             .add_message::<hw_logistics::StockpilePolicyChangeOutcome>()
+            .add_message::<hw_logistics::SupplyRouteChangeOutcome>()
+            .add_message::<crate::systems::soul_ai::adapters::SoulRedemptionOutcome>()
+            .add_message::<crate::systems::contracts::ContractOutcome>()
+            .add_message::<crate::systems::edicts::EdictOutcome>()
+            .add_message::<crate::systems::alerts::ColonyAlert>()
             .init_resource::<UiTheme>()
-            .init_resource::<UiInputState>();
+            .init_resource::<UiInputState>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>();
         let outcome = SaveLoadOutcome {
             operation: SaveLoadOperation::Load,
             target: "world.scn.ron".to_owned(),
@@ -126,9 +151,17 @@ mod tests {
         app.add_plugins((MinimalPlugins, HwUiPlugin, UiNotificationsPlugin))
             .add_message::<SaveLoadOutcome>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
             .add_message::<hw_logistics::StockpilePolicyChangeOutcome>()
+            .add_message::<hw_logistics::SupplyRouteChangeOutcome>()
+            .add_message::<crate::systems::soul_ai::adapters::SoulRedemptionOutcome>()
+            .add_message::<crate::systems::contracts::ContractOutcome>()
+            .add_message::<crate::systems::edicts::EdictOutcome>()
+            .add_message::<crate::systems::alerts::ColonyAlert>()
             .init_resource::<UiTheme>()
-            .init_resource::<UiInputState>();
+            .init_resource::<UiInputState>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>();
         let entity = app.world_mut().spawn_empty().id();
         app.world_mut().write_message(TaskActionOutcome {
             entity,
@@ -155,9 +188,18 @@ mod tests {
         app.add_plugins((MinimalPlugins, HwUiPlugin, UiNotificationsPlugin))
             .add_message::<SaveLoadOutcome>()
             .add_message::<TaskActionOutcome>()
+            .add_message::<TaskGroupActionOutcome>()
             .add_message::<hw_logistics::StockpilePolicyChangeOutcome>()
+            .add_message::<hw_logistics::SupplyRouteChangeOutcome>()
+            .add_message::<crate::systems::soul_ai::adapters::SoulRedemptionOutcome>()
+            .add_message::<crate::systems::contracts::ContractOutcome>()
+            .add_message::<crate::systems::edicts::EdictOutcome>()
+            .add_message::<crate::systems::alerts::ColonyAlert>()
             .init_resource::<UiTheme>()
-            .init_resource::<UiInputState>();
+            .init_resource::<UiInputState>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
+            .insert_resource(Localization::shipped(Locale::En));
         app.world_mut()
             .write_message(hw_logistics::StockpilePolicyChangeOutcome {
                 requested: 2,
@@ -0,0 +1,23 @@
+use crate::systems::GameSystemSet;
+use bevy::prelude::*;
+use hw_ui::statistics::{
+    render_statistics_chart_system, statistics_panel_intent_system,
+    statistics_panel_interaction_system,
+};
+
+pub struct UiStatisticsPlugin;
+
+impl Plugin for UiStatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                statistics_panel_intent_system,
+                statistics_panel_interaction_system,
+                render_statistics_chart_system,
+            )
+                .chain()
+                .in_set(GameSystemSet::Interface),
+        );
+    }
+}
@@ -1,13 +1,17 @@
 use super::{
     EntityInspectionQuery, InspectionAccumulator, SoulInspectionFields, format_escape_info,
-    format_inventory_str, format_task_str,
+    format_inventory_str, format_life_log_lines, format_penance_str, format_relationships_str,
+    format_task_str,
 };
 use crate::entities::damned_soul::Gender;
 use bevy::prelude::*;
 use hw_core::constants::{DREAM_DRAIN_RATE_REST, DREAM_MAX, MUD_MIXER_MUD_CAPACITY};
-use hw_energy::SoulSpaPhase;
-use hw_logistics::{StockpilePolicyState, derive_stockpile_policy_state};
-use hw_ui::models::inspection::{InspectionSoulGender, StockpileInspectionFields};
+use hw_core::locale::Localization;
+use hw_energy::{PowerGrid, SoulSpaPhase};
+use hw_logistics::{StockpilePolicyState, StockpileResourceSet, derive_stockpile_policy_state};
+use hw_ui::models::inspection::{
+    InboundSupplyRouteFields, InspectionSoulGender, StockpileInspectionFields,
+};
 
 impl EntityInspectionQuery<'_, '_> {
     pub(super) fn build_soul_model(
@@ -15,21 +19,55 @@ impl EntityInspectionQuery<'_, '_> {
         entity: Entity,
         model: &mut InspectionAccumulator,
     ) -> bool {
-        let Ok((soul, task, transform, idle, under_command, inventory_opt, identity_opt)) =
-            self.q_souls.get(entity)
+        let Ok((
+            soul,
+            task,
+            transform,
+            idle,
+            under_command,
+            inventory_opt,
+            identity_opt,
+            relationships_opt,
+            life_log_opt,
+            penance_opt,
+        )) = self.q_souls.get(entity)
         else {
             return false;
         };
 
+        let text = &*self.localization;
+        let percent = |id: &str, value: f32| {
+            text.format(id, &[("percent", &format!("{:.0}", value * 100.0))])
+        };
         let name = identity_opt
             .map(|identity| identity.name.clone())
-            .unwrap_or("Damned Soul".to_string());
-        let motivation = format!("Motivation: {:.0}%", soul.motivation * 100.0);
-        let stress = format!("Stress: {:.0}%", soul.stress * 100.0);
-        let fatigue = format!("Fatigue: {:.0}%", soul.fatigue * 100.0);
-        let dream = format!("Dream: {:.0}/{:.0}", soul.dream, DREAM_MAX);
-        let task_str = format!("Task: {}", format_task_str(task));
-        let inventory = format_inventory_str(inventory_opt, &self.q_items);
+            .unwrap_or_else(|| text.text("ui.inspect.unnamed_soul").to_string());
+        let motivation = percent("ui.inspect.motivation", soul.motivation);
+        let stress = percent("ui.inspect.stress", soul.stress);
+        let fatigue = percent("ui.inspect.fatigue", soul.fatigue);
+        let dream = text.format(
+            "ui.inspect.dream",
+            &[
+                ("current", &format!("{:.0}", soul.dream)),
+                ("max", &format!("{DREAM_MAX:.0}")),
+            ],
+        );
+        let penance = format_penance_str(penance_opt, text);
+        let task_str = text.format("ui.inspect.task", &[("task", &format_task_str(task, text))]);
+        let inventory = format_inventory_str(inventory_opt, &self.q_items, text);
+        let relationships = format_relationships_str(
+            relationships_opt,
+            |other| {
+                self.q_souls
+                    .get(other)
+                    .ok()
+                    .and_then(|(.., identity, _, _, _)| {
+                        identity.map(|identity| identity.name.clone())
+                    })
+                    .unwrap_or_else(|| text.text("ui.inspect.unnamed_soul").to_string())
+            },
+            text,
+        );
         let common = format_escape_info(
             soul,
             transform,
@@ -37,13 +75,15 @@ impl EntityInspectionQuery<'_, '_> {
             under_command,
             &self.familiar_grid,
             &self.q_familiars_escape,
+            text,
         );
 
         model.header = name.clone();
-        model.push_tooltip(format!("Soul: {}", name));
+        model.push_tooltip(text.format("ui.inspect.soul", &[("name", &name)]));
         model.push_tooltip(motivation.clone());
         model.push_tooltip(stress.clone());
         model.push_tooltip(dream.clone());
+        model.push_tooltip(penance.clone());
         model.push_tooltip(task_str.clone());
         model.push_tooltip(inventory.clone());
         model.push_common(common.clone());
@@ -57,8 +97,11 @@ impl EntityInspectionQuery<'_, '_> {
             stress,
             fatigue,
             dream,
+            penance,
             task: task_str,
             inventory,
+            relationships,
+            life_log: format_life_log_lines(life_log_opt, text),
             common,
         });
 
@@ -74,10 +117,17 @@ impl EntityInspectionQuery<'_, '_> {
             return false;
         };
 
-        model.header = "Blueprint Info".to_string();
-        model.push_common(format!("Type: {:?}", bp.kind));
-        model.push_common(format!("Progress: {:.0}%", bp.progress * 100.0));
-        model.push_tooltip("Target: Blueprint".to_string());
+        let text = &*self.localization;
+        model.header = text.text("ui.inspect.blueprint").to_string();
+        model.push_common(text.format(
+            "ui.inspect.type",
+            &[("kind", &text.text(bp.kind.text_id()))],
+        ));
+        model.push_common(text.format(
+            "ui.inspect.progress",
+            &[("percent", &format!("{:.0}", bp.progress * 100.0))],
+        ));
+        model.push_tooltip(text.text("ui.inspect.target_blueprint"));
         true
     }
 
@@ -90,19 +140,24 @@ impl EntityInspectionQuery<'_, '_> {
             return false;
         };
 
+        let text = &*self.localization;
         model.header = familiar.name.clone();
-        model.push_common(format!("Type: {:?}", familiar.familiar_type));
-        model.push_common(format!(
-            "Range: {:.0} tiles",
-            familiar.command_radius / 16.0
+        model.push_common(text.format(
+            "ui.inspect.type",
+            &[("kind", &text.text(familiar.familiar_type.text_id()))],
+        ));
+        model.push_common(text.format(
+            "ui.inspect.range",
+            &[("tiles", &format!("{:.0}", familiar.command_radius / 16.0))],
         ));
         let threshold = if op.recruit_fatigue_threshold().is_some() {
             format!("{:.0}%", op.fatigue_threshold * 100.0)
         } else {
-            "0% (Recruit Off)".to_string()
+            text.text("ui.inspect.recruit_off").to_string()
         };
-        model.push_common(format!("Fatigue Threshold: {threshold}"));
-        model.push_tooltip(format!("Familiar: {}", familiar.name));
+        model
+            .push_common(text.format("ui.inspect.fatigue_threshold", &[("threshold", &threshold)]));
+        model.push_tooltip(text.format("ui.inspect.familiar", &[("name", &familiar.name)]));
         true
     }
 
@@ -115,10 +170,11 @@ impl EntityInspectionQuery<'_, '_> {
             return false;
         };
 
-        model.header = "Resource Item".to_string();
-        let line = format!("Type: {:?}", item.0);
-        model.push_common(line.clone());
-        model.push_tooltip(format!("Item: {:?}", item.0));
+        let text = &*self.localization;
+        let resource = text.text(item.0.text_id());
+        model.header = text.text("ui.inspect.resource_item").to_string();
+        model.push_common(text.format("ui.inspect.type", &[("kind", &resource)]));
+        model.push_tooltip(text.format("ui.inspect.item", &[("item", &resource)]));
         true
     }
 
@@ -131,9 +187,13 @@ impl EntityInspectionQuery<'_, '_> {
             return false;
         }
 
-        model.header = "Tree".to_string();
-        model.push_common("Natural resource: Wood".to_string());
-        model.push_tooltip("Target: Tree".to_string());
+        let text = &*self.localization;
+        model.header = text.text("ui.inspect.tree").to_string();
+        model.push_common(text.format(
+            "ui.inspect.natural_resource",
+            &[("resource", &text.text("resource.wood"))],
+        ));
+        model.push_tooltip(text.text("ui.inspect.target_tree"));
         true
     }
 
@@ -146,9 +206,13 @@ impl EntityInspectionQuery<'_, '_> {
             return false;
         }
 
-        model.header = "Rock".to_string();
-        model.push_common("Natural resource: Stone".to_string());
-        model.push_tooltip("Target: Rock".to_string());
+        let text = &*self.localization;
+        model.header = text.text("ui.inspect.rock").to_string();
+        model.push_common(text.format(
+            "ui.inspect.natural_resource",
+            &[("resource", &text.text("resource.rock"))],
+        ));
+        model.push_tooltip(text.text("ui.inspect.target_rock"));
         true
     }
 
@@ -169,41 +233,76 @@ impl EntityInspectionQuery<'_, '_> {
             stockpile.resource_type,
             incoming_amount,
         );
-        let resource = stockpile
-            .resource_type
-            .map(|resource| resource.display_name().to_string())
-            .unwrap_or_else(|| "Empty".to_string());
+        let text = &*self.localization;
+        let resource = text.text(
+            stockpile
+                .resource_type
+                .map_or("ui.info_panel.empty", |resource| resource.text_id()),
+        );
         let export = if state == StockpilePolicyState::Draining && !policy.allow_export {
-            "Off (draining override active)"
+            "ui.inspect.stockpile.export_draining"
         } else if policy.allow_export {
-            "On"
+            "ui.inspect.stockpile.export_on"
         } else {
-            "Off"
+            "ui.inspect.stockpile.export_off"
         };
 
-        model.header = "Stockpile".to_string();
-        model.push_common("Player-managed stockpile cell".to_string());
-        model.push_tooltip(format!("State: {state:?}"));
-        model.push_tooltip(format!(
-            "Stored: {current_amount}/{} ({resource}) | Incoming: {incoming_amount}",
-            stockpile.capacity
+        model.header = text.text("ui.inspect.stockpile").to_string();
+        model.push_common(text.text("ui.inspect.stockpile.managed"));
+        model.push_tooltip(text.format(
+            "ui.info_panel.stockpile_state",
+            &[("state", &text.text(state.text_id()))],
+        ));
+        model.push_tooltip(text.format(
+            "ui.info_panel.stockpile_stored",
+            &[
+                ("current", &current_amount),
+                ("capacity", &stockpile.capacity),
+                ("resource", &resource),
+                ("incoming", &incoming_amount),
+            ],
+        ));
+        model.push_tooltip(text.format(
+            "ui.inspect.stockpile.target",
+            &[("amount", &policy.target_amount)],
         ));
-        model.push_tooltip(format!("Target: {}", policy.target_amount));
         let acceptance = if policy.acceptance.is_all() {
-            "All".to_string()
+            text.text("ui.info_panel.all").to_string()
         } else if policy.acceptance.is_none() {
-            "None".to_string()
+            text.text("ui.info_panel.none").to_string()
         } else {
             policy
                 .acceptance
                 .accepted_resources()
-                .map(|resource| resource.display_name())
+                .map(|resource| text.text(resource.text_id()))
                 .collect::<Vec<_>>()
                 .join(", ")
         };
-        model.push_tooltip(format!("Acceptance: {acceptance}"));
-        model.push_tooltip(format!("Inbound priority: {:?}", policy.inbound_priority));
-        model.push_tooltip(format!("Export: {export}"));
+        model.push_tooltip(text.format(
+            "ui.inspect.stockpile.acceptance",
+            &[("resources", &acceptance)],
+        ));
+        model.push_tooltip(text.format(
+            "ui.inspect.stockpile.inbound_priority",
+            &[("priority", &text.text(policy.inbound_priority.text_id()))],
+        ));
+        model.push_tooltip(text.text(export));
+        let inbound_route = self.inbound_supply_route(entity);
+        if let Some(route) = inbound_route {
+            model.push_tooltip(text.format(
+                "ui.inspect.stockpile.supply_route",
+                &[
+                    ("level", &route.level),
+                    ("target", &route.target_amount),
+                    ("rate", &route.deliveries_per_minute),
+                ],
+            ));
+        }
+        let outbound_routes = self
+            .q_supply_routes
+            .iter()
+            .filter(|(_, route, _)| route.draws_from(entity))
+            .count();
         model.stockpile_fields = Some(StockpileInspectionFields {
             state,
             current_amount,
@@ -214,10 +313,50 @@ impl EntityInspectionQuery<'_, '_> {
             inbound_priority: policy.inbound_priority,
             target_amount: policy.target_amount,
             allow_export: policy.allow_export,
+            inbound_route,
+            outbound_routes,
+            supply_route_source: self.supply_route_draft.source,
         });
         true
     }
 
+    /// Aggregates the route supplying `cell` over its whole destination cluster.
+    fn inbound_supply_route(&self, cell: Entity) -> Option<InboundSupplyRouteFields> {
+        let (route_entity, route, stats) = self
+            .q_supply_routes
+            .iter()
+            .find(|(_, route, _)| route.supplies(cell))?;
+        let mut level = 0;
+        let mut capacity = 0;
+        let mut destination_acceptance = StockpileResourceSet::empty();
+        for (stockpile, policy, stored_items, _) in
+            self.q_stockpiles.iter_many(&route.destination_cells)
+        {
+            capacity += stockpile.capacity;
+            for resource_type in policy.acceptance.accepted_resources() {
+                destination_acceptance = destination_acceptance.with(resource_type, true);
+            }
+            if stockpile
+                .resource_type
+                .is_some_and(|resource_type| route.resources.contains(resource_type))
+            {
+                level += stored_items.map_or(0, hw_core::relationships::StoredItems::len);
+            }
+        }
+        Some(InboundSupplyRouteFields {
+            route: route_entity,
+            resources: route.resources,
+            destination_acceptance,
+            level,
+            target_amount: route.target_amount,
+            capacity,
+            source_cells: route.source_cells.len(),
+            destination_cells: route.destination_cells.len(),
+            deliveries_per_minute: stats.deliveries_per_minute(self.time.elapsed_secs_f64()),
+            delivered_total: stats.delivered_total,
+        })
+    }
+
     pub(super) fn append_building_model(&self, entity: Entity, model: &mut InspectionAccumulator) {
         let Ok((
             building,
@@ -232,40 +371,45 @@ impl EntityInspectionQuery<'_, '_> {
             return;
         };
 
+        let text = &*self.localization;
+        let kind = text.text(building.kind.text_id());
+        let building_label = text.format("ui.inspect.building", &[("kind", &kind)]);
         if model.header.is_empty() {
-            model.header = format!("Building: {:?}", building.kind);
+            model.header = building_label.clone();
         }
 
-        let mut building_info = format!("Building: {:?}", building.kind);
-        if building.is_provisional {
-            building_info.push_str(" (Provisional)");
-        }
+        let mut building_info = if building.is_provisional {
+            text.format("ui.inspect.building_provisional", &[("kind", &kind)])
+        } else {
+            building_label
+        };
         if let Some(stockpile) = stockpile_opt {
             let current = stored_items_opt
                 .map(|stored_items| stored_items.len())
                 .unwrap_or(0);
-            let resource_name = stockpile
-                .resource_type
-                .map(|resource| format!("{:?}", resource))
-                .unwrap_or_else(|| "Items".to_string());
+            let resource_name = text.text(
+                stockpile
+                    .resource_type
+                    .map_or("ui.inspect.items", |resource| resource.text_id()),
+            );
             building_info = format!(
                 "{}: {} ({}/{})",
                 building_info, resource_name, current, stockpile.capacity
             );
         }
-        model.push_tooltip(building_info.clone());
+        model.push_tooltip(building_info);
 
         if building.kind == crate::systems::jobs::BuildingType::Wall && building.is_provisional {
             let wall_status = provisional_wall_opt
                 .map(|provisional| {
                     if provisional.mud_delivered {
-                        "Wall Upgrade: Mud delivered (ready to coat)"
+                        "ui.inspect.wall_upgrade.mud_delivered"
                     } else {
-                        "Wall Upgrade: Waiting for StasisMud"
+                        "ui.inspect.wall_upgrade.waiting"
                     }
                 })
-                .unwrap_or("Wall Upgrade: Pending");
-            model.push_tooltip(wall_status.to_string());
+                .unwrap_or("ui.inspect.wall_upgrade.pending");
+            model.push_tooltip(text.text(wall_status));
         }
 
         if let Some(storage) = mixer_storage_opt {
@@ -278,11 +422,16 @@ impl EntityInspectionQuery<'_, '_> {
                 }
                 _ => 0,
             };
-            let storage_line = format!(
-                "Storage: Sand {}, Rock {}, Water {}, Mud {}/{}",
-                storage.sand, storage.rock, water_count, storage.mud, MUD_MIXER_MUD_CAPACITY
-            );
-            model.push_tooltip(storage_line.clone());
+            model.push_tooltip(text.format(
+                "ui.inspect.mixer_storage",
+                &[
+                    ("sand", &storage.sand),
+                    ("rock", &storage.rock),
+                    ("water", &water_count),
+                    ("mud", &storage.mud),
+                    ("capacity", &MUD_MIXER_MUD_CAPACITY),
+                ],
+            ));
         }
 
         if let Some(rest_area) = rest_area_opt {
@@ -291,11 +440,14 @@ impl EntityInspectionQuery<'_, '_> {
                 .unwrap_or(0)
                 .min(rest_area.capacity);
             let dream_rate = resting_count as f32 * DREAM_DRAIN_RATE_REST;
-            let line = format!(
-                "Resting: {}/{} | Dream: {:.2}/s",
-                resting_count, rest_area.capacity, dream_rate
-            );
-            model.push_tooltip(line);
+            model.push_tooltip(text.format(
+                "ui.inspect.resting",
+                &[
+                    ("count", &resting_count),
+                    ("capacity", &rest_area.capacity),
+                    ("rate", &format!("{dream_rate:.2}")),
+                ],
+            ));
         }
     }
 
@@ -309,34 +461,37 @@ impl EntityInspectionQuery<'_, '_> {
             return;
         };
 
-        let task_line = format!("Task: {:?}", designation.work_type);
-        model.push_tooltip(task_line.clone());
+        let text = &*self.localization;
+        model.push_tooltip(text.format(
+            "ui.inspect.task",
+            &[("task", &text.text(designation.work_type.text_id()))],
+        ));
 
         if let Some(issued_by) = issued_by_opt
             && let Ok((familiar, _)) = self.q_familiars.get(issued_by.0)
         {
-            let line = format!("Issued by: {}", familiar.name);
-            model.push_tooltip(line.clone());
+            model.push_tooltip(text.format("ui.inspect.issued_by", &[("name", &familiar.name)]));
         }
 
         if let Some(workers) = task_workers_opt {
             let worker_names: Vec<String> = workers
                 .iter()
                 .filter_map(|&soul_entity| {
-                    self.q_souls
-                        .get(soul_entity)
-                        .ok()
-                        .map(|(_, _, _, _, _, _, identity_opt)| {
+                    self.q_souls.get(soul_entity).ok().map(
+                        |(_, _, _, _, _, _, identity_opt, ..)| {
                             identity_opt
                                 .map(|identity| identity.name.clone())
-                                .unwrap_or("Unknown".to_string())
-                        })
+                                .unwrap_or_else(|| text.text("ui.inspect.unknown").to_string())
+                        },
+                    )
                 })
                 .collect();
 
             if !worker_names.is_empty() {
-                let line = format!("Assigned to: {}", worker_names.join(", "));
-                model.push_tooltip(line.clone());
+                model.push_tooltip(text.format(
+                    "ui.inspect.assigned_to",
+                    &[("names", &worker_names.join(", "))],
+                ));
             }
         }
     }
@@ -351,22 +506,24 @@ impl EntityInspectionQuery<'_, '_> {
             return;
         };
 
-        let status = if unpowered_opt.is_some() {
-            "UNPOWERED"
+        let text = &*self.localization;
+        let status = text.text(if unpowered_opt.is_some() {
+            "ui.inspect.power.unpowered"
         } else {
-            "ACTIVE"
-        };
-        model.push_tooltip(format!("Demand: {:.1}W [{}]", consumer.demand, status));
+            "ui.inspect.power.active"
+        });
+        model.push_tooltip(text.format(
+            "ui.inspect.power.demand",
+            &[
+                ("demand", &format!("{:.1}", consumer.demand)),
+                ("status", &status),
+            ],
+        ));
 
         if let Some(cf) = consumes_from_opt
             && let Ok(grid) = self.q_power_grids.get(cf.0)
         {
-            model.push_tooltip(format!(
-                "Grid: {:.1}W / {:.1}W [{}]",
-                grid.generation,
-                grid.consumption,
-                if grid.powered { "POWERED" } else { "BLACKOUT" }
-            ));
+            model.push_tooltip(grid_line(text, grid));
         }
     }
 
@@ -375,15 +532,19 @@ impl EntityInspectionQuery<'_, '_> {
             return;
         };
 
+        let text = &*self.localization;
         if model.header.is_empty() {
-            model.header = "Soul Spa".to_string();
+            model.header = text.text("building.soul_spa").to_string();
         }
 
         match site.phase {
             SoulSpaPhase::Constructing => {
-                model.push_tooltip(format!(
-                    "Status: Constructing ({}/{})",
-                    site.bones_delivered, site.bones_required
+                model.push_tooltip(text.format(
+                    "ui.inspect.soul_spa.constructing",
+                    &[
+                        ("delivered", &site.bones_delivered),
+                        ("required", &site.bones_required),
+                    ],
                 ));
             }
             SoulSpaPhase::Operational => {
@@ -392,23 +553,39 @@ impl EntityInspectionQuery<'_, '_> {
                 } else {
                     0
                 };
-                model.push_tooltip("Status: Operational".to_string());
-                model.push_tooltip(format!(
-                    "Active: {}/{} souls",
-                    active_souls, site.active_slots
+                model.push_tooltip(text.text("ui.inspect.soul_spa.operational"));
+                model.push_tooltip(text.format(
+                    "ui.inspect.soul_spa.active",
+                    &[("active", &active_souls), ("slots", &site.active_slots)],
+                ));
+                model.push_tooltip(text.format(
+                    "ui.inspect.soul_spa.output",
+                    &[("output", &format!("{:.1}", generator.current_output))],
                 ));
-                model.push_tooltip(format!("Output: {:.1}W", generator.current_output));
                 if let Some(gen_for) = generates_for_opt
                     && let Ok(grid) = self.q_power_grids.get(gen_for.0)
                 {
-                    model.push_tooltip(format!(
-                        "Grid: {:.1}W / {:.1}W [{}]",
-                        grid.generation,
-                        grid.consumption,
-                        if grid.powered { "POWERED" } else { "BLACKOUT" }
-                    ));
+                    model.push_tooltip(grid_line(text, grid));
                 }
             }
         }
     }
 }
+
+fn grid_line(text: &Localization, grid: &PowerGrid) -> String {
+    text.format(
+        "ui.inspect.power.grid",
+        &[
+            ("generation", &format!("{:.1}", grid.generation)),
+            ("consumption", &format!("{:.1}", grid.consumption)),
+            (
+                "status",
+                &text.text(if grid.powered {
+                    "ui.inspect.power.powered"
+                } else {
+                    "ui.inspect.power.blackout"
+                }),
+            ),
+        ],
+    )
+}
@@ -2,15 +2,19 @@ mod builders;
 
 use crate::entities::damned_soul::{DamnedSoul, IdleBehavior, IdleState};
 use crate::entities::familiar::Familiar;
-use crate::interface::selection::SelectedEntity;
+use crate::interface::selection::{SelectedEntities, SelectedEntity};
 use crate::interface::ui::panels::InfoPanelPinState;
 use crate::systems::jobs::Blueprint;
 use crate::systems::soul_ai::execute::task_execution::AssignedTask;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use hw_core::constants::ESCAPE_STRESS_THRESHOLD;
+use hw_core::life_log::{SoulLifeEventKind, SoulLifeLog};
+use hw_core::locale::Localization;
+use hw_core::redemption::{REDEMPTION_PENANCE_THRESHOLD, SoulPenance};
 use hw_core::relationships::CommandedBy;
 use hw_core::relationships::{IncomingDeliveries, StoredItems, TaskWorkers};
+use hw_core::social::{SOCIAL_PANEL_MAX_ENTRIES, SoulBond, SoulRelationships};
 use hw_energy::{
     ConsumesFrom, GeneratesFor, PowerConsumer, PowerGenerator, PowerGrid, SoulSpaSite, Unpowered,
 };
@@ -19,7 +23,7 @@ use hw_spatial::FamiliarSpatialGrid;
 use hw_ui::components::TooltipTemplate;
 
 pub use hw_ui::models::inspection::{
-    EntityInspectionModel, EntityInspectionViewModel, SoulInspectionFields,
+    EntityInspectionModel, EntityInspectionViewModel, SelectionGroupSummary, SoulInspectionFields,
     StockpileInspectionFields,
 };
 
@@ -34,6 +38,9 @@ type SoulInspectionQuery<'w, 's> = Query<
         Option<&'static CommandedBy>,
         Option<&'static crate::systems::logistics::Inventory>,
         Option<&'static crate::entities::damned_soul::SoulIdentity>,
+        Option<&'static SoulRelationships>,
+        Option<&'static SoulLifeLog>,
+        Option<&'static SoulPenance>,
     ),
 >;
 
@@ -92,6 +99,19 @@ pub struct EntityInspectionQuery<'w, 's> {
     q_designations: DesignationInspectionQuery<'w, 's>,
     q_buildings: BuildingInspectionQuery<'w, 's>,
     q_stockpiles: StockpileInspectionQuery<'w, 's>,
+    q_doors: Query<'w, 's, &'static hw_jobs::Door>,
+    q_supply_routes: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static hw_logistics::SupplyRoute,
+            &'static hw_logistics::SupplyRouteStats,
+        ),
+    >,
+    supply_route_draft: Res<'w, crate::systems::command::SupplyRouteDraft>,
+    time: Res<'w, Time>,
+    localization: Res<'w, Localization>,
     pub(super) q_power_consumers: Query<
         'w,
         's,
@@ -153,10 +173,18 @@ impl InspectionAccumulator {
 
 pub fn update_entity_inspection_view_model_system(
     selected_entity: Res<SelectedEntity>,
+    selected_entities: Res<SelectedEntities>,
     mut pin_state: ResMut<InfoPanelPinState>,
     inspection: EntityInspectionQuery,
     mut view_model: ResMut<EntityInspectionViewModel>,
 ) {
+    if pin_state.entity.is_none() && selected_entities.is_multi() {
+        view_model.model = None;
+        view_model.group = Some(inspection.build_group_summary(&selected_entities));
+        return;
+    }
+    view_model.group = None;
+
     let mut inspected_entity = pin_state.entity.or(selected_entity.0);
     let mut model = inspected_entity.and_then(|entity| inspection.build_model(entity));
 
@@ -190,6 +218,29 @@ impl EntityInspectionQuery<'_, '_> {
         model.finalize(entity)
     }
 
+    /// 複数選択を一括操作の対象種別ごとに数える。
+    pub fn build_group_summary(&self, selection: &SelectedEntities) -> SelectionGroupSummary {
+        let mut summary = SelectionGroupSummary::default();
+        for entity in selection.iter() {
+            summary.total += 1;
+            if self.q_souls.get(entity).is_ok() {
+                summary.souls += 1;
+            } else if self.q_familiars.get(entity).is_ok() {
+                summary.familiars += 1;
+            } else if let Ok(door) = self.q_doors.get(entity) {
+                summary.doors += 1;
+                if door.state == hw_core::world::DoorState::Locked {
+                    summary.locked_doors += 1;
+                }
+            } else if self.q_stockpiles.get(entity).is_ok() {
+                summary.stockpiles += 1;
+            } else if self.q_designations.get(entity).is_ok() {
+                summary.designations += 1;
+            }
+        }
+        summary
+    }
+
     pub fn classify_template(&self, entity: Entity) -> TooltipTemplate {
         if self.q_souls.get(entity).is_ok() {
             TooltipTemplate::Soul
@@ -209,44 +260,170 @@ impl EntityInspectionQuery<'_, '_> {
     }
 }
 
-pub(super) fn format_task_str(task: &AssignedTask) -> String {
+pub(super) fn format_task_str(task: &AssignedTask, text: &Localization) -> String {
     if let Some(data) = task.bucket_transport_data() {
-        return format!("BucketTransport ({:?})", data.phase);
+        return format!(
+            "{} ({:?})",
+            text.text("ui.inspect.task.bucket_transport"),
+            data.phase
+        );
     }
 
-    match task {
-        AssignedTask::None => "Idle".to_string(),
-        AssignedTask::Gather(data) => format!("Gather ({:?})", data.phase),
-        AssignedTask::Haul(data) => format!("Haul ({:?})", data.phase),
-        AssignedTask::HaulToBlueprint(data) => format!("HaulToBp ({:?})", data.phase),
-        AssignedTask::Build(data) => format!("Build ({:?})", data.phase),
-        AssignedTask::MovePlant(data) => format!("MovePlant ({:?})", data.phase),
-        AssignedTask::CollectBone(data) => format!("CollectBone ({:?})", data.phase),
-        AssignedTask::Refine(data) => format!("Refine ({:?})", data.phase),
-        AssignedTask::HaulToMixer(data) => format!("HaulToMixer ({:?})", data.phase),
-        AssignedTask::HaulWithWheelbarrow(data) => format!("HaulWheelbarrow ({:?})", data.phase),
-        AssignedTask::ReinforceFloorTile(data) => format!("ReinforceFloor ({:?})", data.phase),
-        AssignedTask::PourFloorTile(data) => format!("PourFloor ({:?})", data.phase),
-        AssignedTask::FrameWallTile(data) => format!("FrameWall ({:?})", data.phase),
-        AssignedTask::CoatWall(data) => format!("CoatWall ({:?})", data.phase),
-        AssignedTask::GeneratePower(data) => format!("GeneratePower ({:?})", data.phase),
-        _ => "BucketTransport".to_string(),
-    }
+    let (id, phase) = match task {
+        AssignedTask::None => return text.text("ui.inspect.task.idle").to_string(),
+        AssignedTask::Gather(data) => ("ui.inspect.task.gather", format!("{:?}", data.phase)),
+        AssignedTask::Haul(data) => ("ui.inspect.task.haul", format!("{:?}", data.phase)),
+        AssignedTask::HaulToBlueprint(data) => (
+            "ui.inspect.task.haul_to_blueprint",
+            format!("{:?}", data.phase),
+        ),
+        AssignedTask::Build(data) => ("ui.inspect.task.build", format!("{:?}", data.phase)),
+        AssignedTask::MovePlant(data) => {
+            ("ui.inspect.task.move_plant", format!("{:?}", data.phase))
+        }
+        AssignedTask::CollectBone(data) => {
+            ("ui.inspect.task.collect_bone", format!("{:?}", data.phase))
+        }
+        AssignedTask::Refine(data) => ("ui.inspect.task.refine", format!("{:?}", data.phase)),
+        AssignedTask::HaulToMixer(data) => {
+            ("ui.inspect.task.haul_to_mixer", format!("{:?}", data.phase))
+        }
+        AssignedTask::HaulWithWheelbarrow(data) => (
+            "ui.inspect.task.haul_wheelbarrow",
+            format!("{:?}", data.phase),
+        ),
+        AssignedTask::ReinforceFloorTile(data) => (
+            "ui.inspect.task.reinforce_floor",
+            format!("{:?}", data.phase),
+        ),
+        AssignedTask::PourFloorTile(data) => {
+            ("ui.inspect.task.pour_floor", format!("{:?}", data.phase))
+        }
+        AssignedTask::FrameWallTile(data) => {
+            ("ui.inspect.task.frame_wall", format!("{:?}", data.phase))
+        }
+        AssignedTask::CoatWall(data) => ("ui.inspect.task.coat_wall", format!("{:?}", data.phase)),
+        AssignedTask::GeneratePower(data) => (
+            "ui.inspect.task.generate_power",
+            format!("{:?}", data.phase),
+        ),
+        _ => return text.text("ui.inspect.task.bucket_transport").to_string(),
+    };
+    format!("{} ({phase})", text.text(id))
 }
 
 pub(super) fn format_inventory_str(
     inventory_opt: Option<&crate::systems::logistics::Inventory>,
     q_items: &Query<&crate::systems::logistics::ResourceItem>,
+    text: &Localization,
+) -> String {
+    let carrying =
+        if let Some(crate::systems::logistics::Inventory(Some(item_entity))) = inventory_opt {
+            if let Ok(item) = q_items.get(*item_entity) {
+                text.text(item.0.text_id()).to_string()
+            } else {
+                format!("{item_entity:?}")
+            }
+        } else {
+            text.text("ui.inspect.none").to_string()
+        };
+    text.format("ui.inspect.carrying", &[("item", &carrying)])
+}
+
+pub(super) fn format_relationships_str(
+    relationships: Option<&SoulRelationships>,
+    name_of: impl Fn(Entity) -> String,
+    text: &Localization,
 ) -> String {
-    if let Some(crate::systems::logistics::Inventory(Some(item_entity))) = inventory_opt {
-        if let Ok(item) = q_items.get(*item_entity) {
-            format!("Carrying: {:?}", item.0)
+    let list = |bonds: Vec<SoulBond>| {
+        if bonds.is_empty() {
+            text.text("ui.inspect.none").to_string()
         } else {
-            format!("Carrying: Entity {:?}", item_entity)
+            bonds
+                .iter()
+                .map(|bond| format!("{} ({:+.0})", name_of(bond.other), bond.affinity * 100.0))
+                .collect::<Vec<_>>()
+                .join(", ")
         }
-    } else {
-        "Carrying: None".to_string()
-    }
+    };
+    let (friends, rivals) = relationships.map_or_else(
+        || (Vec::new(), Vec::new()),
+        |relationships| {
+            (
+                relationships.top_friends(SOCIAL_PANEL_MAX_ENTRIES),
+                relationships.top_rivals(SOCIAL_PANEL_MAX_ENTRIES),
+            )
+        },
+    );
+    format!(
+        "{}\n{}",
+        text.format("ui.inspect.friends", &[("list", &list(friends))]),
+        text.format("ui.inspect.rivals", &[("list", &list(rivals))])
+    )
+}
+
+pub(super) fn format_penance_str(penance: Option<&SoulPenance>, text: &Localization) -> String {
+    let score = penance.map_or(0.0, |penance| penance.score);
+    let ready = penance.is_some_and(SoulPenance::is_ready);
+    text.format(
+        if ready {
+            "ui.inspect.penance_ready"
+        } else {
+            "ui.inspect.penance"
+        },
+        &[
+            (
+                "score",
+                &format!("{:.0}", score.min(REDEMPTION_PENANCE_THRESHOLD)),
+            ),
+            ("threshold", &format!("{REDEMPTION_PENANCE_THRESHOLD:.0}")),
+        ],
+    )
+}
+
+pub(super) fn format_life_log_lines(
+    life_log: Option<&SoulLifeLog>,
+    text: &Localization,
+) -> Vec<String> {
+    let Some(life_log) = life_log else {
+        return Vec::new();
+    };
+    life_log
+        .iter_newest_first()
+        .map(|entry| {
+            let label = match entry.kind {
+                SoulLifeEventKind::Recruited => text.text("ui.life_log.recruited").to_string(),
+                SoulLifeEventKind::Released => text.text("ui.life_log.released").to_string(),
+                SoulLifeEventKind::TaskCompleted(work_type) => text.format(
+                    "ui.life_log.task_completed",
+                    &[("work", &text.text(work_type.text_id()))],
+                ),
+                SoulLifeEventKind::TaskAbandoned => {
+                    text.text("ui.life_log.task_abandoned").to_string()
+                }
+                SoulLifeEventKind::Exhausted => text.text("ui.life_log.exhausted").to_string(),
+                SoulLifeEventKind::StressBreakdown => {
+                    text.text("ui.life_log.stress_breakdown").to_string()
+                }
+                SoulLifeEventKind::RestStarted => text.text("ui.life_log.rest_started").to_string(),
+                SoulLifeEventKind::DreamReleased { amount } => text.format(
+                    "ui.life_log.dream_released",
+                    &[("amount", &format!("{amount:.1}"))],
+                ),
+                SoulLifeEventKind::JoinedGathering => {
+                    text.text("ui.life_log.joined_gathering").to_string()
+                }
+            };
+            text.format(
+                "ui.life_log.entry",
+                &[
+                    ("day", &entry.day),
+                    ("time", &format!("{:02}:{:02}", entry.hour, entry.minute)),
+                    ("event", &label),
+                ],
+            )
+        })
+        .collect()
 }
 
 pub(super) fn format_escape_info(
@@ -256,6 +433,7 @@ pub(super) fn format_escape_info(
     under_command: Option<&CommandedBy>,
     familiar_grid: &FamiliarSpatialGrid,
     q_familiars_escape: &Query<(&Transform, &Familiar)>,
+    text: &Localization,
 ) -> String {
     let mut scratch = Vec::new();
     let escape_threat_close = is_escape_threat_close(
@@ -268,25 +446,44 @@ pub(super) fn format_escape_info(
         && idle.behavior != IdleBehavior::ExhaustedGathering
         && soul.stress > ESCAPE_STRESS_THRESHOLD
         && escape_threat_close;
-    format!(
-        "Idle: {:?}\nEscape: {}\n- stress_ok: {}\n- threat_close: {}\n- commanded: {}\n- exhausted: {}",
-        idle.behavior,
-        if escape_allowed {
-            "eligible"
+    let check = |id: &str, value: bool| {
+        let value = text.text(if value {
+            "ui.inspect.yes"
         } else {
-            "blocked"
-        },
-        soul.stress > ESCAPE_STRESS_THRESHOLD,
-        escape_threat_close,
-        under_command.is_some(),
-        idle.behavior == IdleBehavior::ExhaustedGathering
-    )
+            "ui.inspect.no"
+        });
+        text.format(id, &[("value", &value)])
+    };
+    [
+        text.format(
+            "ui.inspect.idle",
+            &[("behavior", &text.text(idle.behavior.text_id()))],
+        ),
+        text.text(if escape_allowed {
+            "ui.inspect.escape_eligible"
+        } else {
+            "ui.inspect.escape_blocked"
+        })
+        .to_string(),
+        check(
+            "ui.inspect.escape.stress",
+            soul.stress > ESCAPE_STRESS_THRESHOLD,
+        ),
+        check("ui.inspect.escape.threat", escape_threat_close),
+        check("ui.inspect.escape.commanded", under_command.is_some()),
+        check(
+            "ui.inspect.escape.exhausted",
+            idle.behavior == IdleBehavior::ExhaustedGathering,
+        ),
+    ]
+    .join("\n")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_support::minimal_app;
+    use hw_core::locale::Locale;
     use hw_core::relationships::{DeliveringTo, StoredIn};
     use hw_logistics::transport_request::TransportPriority;
     use hw_logistics::{
@@ -311,6 +508,8 @@ mod tests {
     fn stockpile_policy_inspection_reports_live_counts_and_draining_state() {
         let mut app = minimal_app();
         app.init_resource::<FamiliarSpatialGrid>()
+            .init_resource::<crate::systems::command::SupplyRouteDraft>()
+            .insert_resource(Localization::shipped(Locale::En))
             .init_resource::<InspectionReceipt>()
             .add_systems(Update, inspect);
         let acceptance = StockpileAcceptance::none()
@@ -374,6 +573,8 @@ mod tests {
     fn special_storage_does_not_expose_the_stockpile_policy_editor() {
         let mut app = minimal_app();
         app.init_resource::<FamiliarSpatialGrid>()
+            .init_resource::<crate::systems::command::SupplyRouteDraft>()
+            .insert_resource(Localization::shipped(Locale::En))
             .init_resource::<InspectionReceipt>()
             .add_systems(Update, inspect);
         let tank = app
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
-use hw_ui::setup::{SettingsPanelInitial, SetupUiParams, setup_ui as hwui_setup_ui};
+use hw_ui::setup::{
+    KeyBindingRowSpec, SettingsPanelInitial, SetupUiParams, setup_ui as hwui_setup_ui,
+};
+
+use crate::input_actions::{InputBindingTable, format_input_chord};
 
 pub fn setup_ui(
     commands: Commands,
@@ -8,7 +12,10 @@ pub fn setup_ui(
     ui_nodes: ResMut<hw_ui::components::UiNodeRegistry>,
     info_panel_nodes: ResMut<hw_ui::components::InfoPanelNodes>,
     settings: Res<hw_core::GameSettings>,
-    help_content: Res<hw_ui::help::HelpPanelContent>,
+    (help_content, help_chrome): (
+        Res<hw_ui::help::HelpPanelContent>,
+        Res<hw_ui::help::HelpPanelChrome>,
+    ),
 ) {
     let theme_ref = &theme;
     let settings_initial = SettingsPanelInitial {
@@ -18,9 +25,9 @@ pub fn setup_ui(
         default_time_speed: settings.default_time_speed,
         debug_gizmos_enabled: settings.debug_gizmos_enabled,
         fps_display_enabled: settings.fps_display_enabled,
+        locale: settings.locale,
+        key_bindings: key_binding_rows(&settings),
     };
-    let help_chrome = crate::interface::ui::help_content::build_help_panel_chrome()
-        .expect("validated Help chrome");
 
     hwui_setup_ui(
         commands,
@@ -49,6 +56,18 @@ pub fn setup_ui(
     );
 }
 
+/// 設定パネルのキーバインド行。以降の表示更新は `InputBindingTable` の変更で行う
+fn key_binding_rows(settings: &hw_core::GameSettings) -> Vec<KeyBindingRowSpec> {
+    let (table, _) = InputBindingTable::from_overrides(&settings.key_binding_overrides);
+    table
+        .bindings()
+        .iter()
+        .map(|binding| KeyBindingRowSpec {
+            chord: format_input_chord(binding.chord).unwrap_or_default(),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -20,7 +20,9 @@ use crate::{
         startup::{PerfScenarioConfig, StartupPlugin},
         visual::VisualPlugin,
     },
-    systems::{GameSystemSet, save::SavePlugin, settings::SettingsPlugin},
+    systems::{
+        GameSystemSet, invariants::InvariantCheckPlugin, save::SavePlugin, settings::SettingsPlugin,
+    },
 };
 use hw_core::game_state::PlayMode;
 
@@ -97,6 +99,11 @@ impl Plugin for HellWorkersGamePlugin {
             .add_plugins(SettingsPlugin)
             .add_plugins(SavePlugin);
 
+        // perf summary は違反数を必ず書き出す。通常起動では環境変数で明示したときだけ検査する。
+        if self.perf_config.enabled() || crate::env_flag_is_true("HW_CHECK_INVARIANTS") {
+            app.add_plugins(InvariantCheckPlugin::counting());
+        }
+
         #[cfg(feature = "profiling")]
         if !fixed_step_audit {
             app.add_plugins(FrameTimeDiagnosticsPlugin::default());
@@ -146,28 +153,7 @@ fn configure_game_system_sets(app: &mut App) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bevy::{
-        app::PluginGroup,
-        asset::AssetApp,
-        core_pipeline::CorePipelinePlugin,
-        gilrs::GilrsPlugin,
-        gizmos_render::GizmoRenderPlugin,
-        image::ImagePlugin,
-        pbr::PbrPlugin,
-        render::RenderPlugin,
-        shader::{Shader, ShaderLoader},
-        winit::WinitPlugin,
-    };
-
-    #[derive(Default)]
-    struct HeadlessShaderAssetsPlugin;
-
-    impl Plugin for HeadlessShaderAssetsPlugin {
-        fn build(&self, app: &mut App) {
-            app.init_asset::<Shader>()
-                .init_asset_loader::<ShaderLoader>();
-        }
-    }
+    use crate::test_support::headless_game_app;
 
     #[derive(Resource, Default)]
     struct SystemOrder(Vec<&'static str>);
@@ -224,21 +210,7 @@ mod tests {
 
     #[test]
     fn production_game_plugin_has_one_owner_for_each_root_plugin() {
-        let mut app = App::new();
-        // Material plugins load shader libraries during build. Add the shader asset support
-        // before ImagePlugin, which normally relies on RenderPlugin to provide it.
-        app.add_plugins(
-            DefaultPlugins
-                .build()
-                .disable::<WinitPlugin>()
-                .disable::<RenderPlugin>()
-                .disable::<GilrsPlugin>()
-                .disable::<CorePipelinePlugin>()
-                .disable::<GizmoRenderPlugin>()
-                .disable::<PbrPlugin>()
-                .add_before::<ImagePlugin>(HeadlessShaderAssetsPlugin),
-        )
-        .add_plugins(HellWorkersGamePlugin::default());
+        let app = headless_game_app();
 
         assert!(app.world().contains_resource::<DebugVisible>());
         assert!(app.world().contains_resource::<DebugInstantBuild>());
@@ -3,13 +3,17 @@
 use crate::app_contexts::TaskContext;
 use crate::entities::familiar::Familiar;
 use crate::input_actions::{
-    InputAction, InputPreUpdateSet, InputResolutionSet, PendingWorldInputCapture,
-    ResolvedInputFrame, cancel_or_close_input_action_system, configure_input_resolution_sets,
-    input_action_to_ui_intent_system, request_capture_from_menu_buttons_system,
-    request_capture_from_resolved_actions_system, reset_pending_world_input_capture_system,
-    resolve_input_frame_system, rollback_in_progress_gesture_system,
+    InputAction, InputBindingTable, InputPreUpdateSet, InputResolutionSet, KeyRebindCapture,
+    PendingWorldInputCapture, ResolvedInputFrame, cancel_or_close_input_action_system,
+    configure_input_resolution_sets, input_action_to_ui_intent_system, key_rebind_capture_system,
+    request_capture_from_menu_buttons_system, request_capture_from_resolved_actions_system,
+    reset_pending_world_input_capture_system, resolve_input_frame_system,
+    rollback_in_progress_gesture_system, sync_input_binding_table_system,
     sync_world_input_capture_system,
 };
+use crate::interface::camera_control::{
+    camera_bookmark_shortcut_system, camera_follow_system, camera_follow_toggle_system,
+};
 use crate::interface::selection::{
     SelectedEntity, handle_mouse_input, pointer_hits_task_area_border,
 };
@@ -21,6 +25,7 @@ use bevy::ecs::system::SystemParam;
 use bevy::picking::PickingSystems;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
+use hw_core::camera::{CameraBookmarks, CameraFollow};
 use hw_core::game_state::{PlayMode, TaskMode};
 use hw_core::quality::QualitySettings;
 use hw_ui::camera::MainCamera;
@@ -31,21 +36,28 @@ impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(PanCameraPlugin);
         app.init_resource::<ResolvedInputFrame>();
+        app.init_resource::<InputBindingTable>();
+        app.init_resource::<KeyRebindCapture>();
         app.init_resource::<PendingWorldInputCapture>();
         app.init_resource::<UiInputState>();
         app.init_resource::<TaskAreaPointerClaim>();
         app.init_resource::<HelpPauseGuard>();
+        app.init_resource::<CameraBookmarks>();
+        app.init_resource::<CameraFollow>();
         configure_input_resolution_sets(app);
         app.add_systems(
             PreUpdate,
             (
                 (
+                    sync_input_binding_table_system,
                     reset_pending_world_input_capture_system,
                     request_capture_from_menu_buttons_system,
                 )
                     .chain()
                     .in_set(InputPreUpdateSet::CaptureRequest),
-                resolve_input_frame_system.in_set(InputPreUpdateSet::Resolve),
+                (resolve_input_frame_system, key_rebind_capture_system)
+                    .chain()
+                    .in_set(InputPreUpdateSet::Resolve),
                 (
                     request_capture_from_resolved_actions_system,
                     sync_world_input_capture_system,
@@ -86,6 +98,16 @@ impl Plugin for InputPlugin {
             )
                 .in_set(InputResolutionSet::Consume),
         );
+        app.add_systems(
+            Update,
+            (
+                camera_bookmark_shortcut_system,
+                camera_follow_toggle_system,
+                camera_follow_system,
+            )
+                .chain()
+                .in_set(InputResolutionSet::Consume),
+        );
     }
 }
 
@@ -135,18 +157,22 @@ struct PanCameraGuardParams<'w, 's> {
 /// UI / text input が world input を遮断している間、または task area が一次ポインタを
 /// release まで所有している間は PanCamera を無効化する。
 fn pan_camera_world_input_guard_system(mut params: PanCameraGuardParams) {
-    let direct_area_selection_press = params.mouse_buttons.just_pressed(MouseButton::Left)
+    let world_selection_press = params.mouse_buttons.just_pressed(MouseButton::Left)
         && normal_pointer_ingress_will_run(&params.play_mode, &params.next_play_mode)
         && !params.ui_input_state.world_input_blocked()
-        && !params.resolved_frame.pointer_selection_suppressed()
+        && !params.resolved_frame.pointer_selection_suppressed();
+    let direct_area_selection_press = world_selection_press
         && hw_ui::camera::world_cursor_pos(&params.q_window, &params.q_world_camera).is_some_and(
             |world_pos| {
                 pointer_hits_task_area_border(world_pos, params.selected.0, &params.q_task_areas)
             },
         );
+    // Shift+ドラッグは範囲選択なので、カメラのドラッグパンに渡さない。
+    let box_selection_press = world_selection_press && params.resolved_frame.modifiers.shift;
     let starts_task_area_drag = task_mode_uses_area_drag(params.task_context.0)
         || resolved_action_starts_task_area_drag(&params.resolved_frame)
-        || direct_area_selection_press;
+        || direct_area_selection_press
+        || box_selection_press;
     let task_area_drag_claimed = params
         .task_area_pointer_claim
         .update(starts_task_area_drag, &params.mouse_buttons);
@@ -188,6 +214,7 @@ fn task_mode_uses_area_drag(task_mode: TaskMode) -> bool {
             | TaskMode::WallPlace(_)
             | TaskMode::DreamPlanting(_)
             | TaskMode::StockpilePolicyEdit(_)
+            | TaskMode::LayoutExport(_)
     )
 }
 
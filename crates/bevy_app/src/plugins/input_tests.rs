@@ -91,6 +91,8 @@ fn pan_camera_drag_app() -> (App, Entity, Entity, Location) {
     app.init_resource::<ButtonInput<KeyCode>>()
         .init_resource::<AccumulatedMouseScroll>()
         .init_resource::<PendingTestDrag>()
+        .init_resource::<crate::interface::selection::SelectedEntities>()
+        .init_resource::<crate::interface::selection::SelectionBoxDrag>()
         .add_plugins(PanCameraPlugin)
         .add_systems(
             PreUpdate,
@@ -407,6 +409,7 @@ fn all_task_area_drag_modes_reserve_the_primary_pointer() {
         TaskMode::WallPlace(Some(point)),
         TaskMode::DreamPlanting(None),
         TaskMode::StockpilePolicyEdit(Some(point)),
+        TaskMode::LayoutExport(None),
     ] {
         assert!(task_mode_uses_area_drag(mode), "mode: {mode:?}");
     }
@@ -414,6 +417,7 @@ fn all_task_area_drag_modes_reserve_the_primary_pointer() {
     assert!(!task_mode_uses_area_drag(TaskMode::None));
     assert!(!task_mode_uses_area_drag(TaskMode::SelectBuildTarget));
     assert!(!task_mode_uses_area_drag(TaskMode::SoulSpaPlace(None)));
+    assert!(!task_mode_uses_area_drag(TaskMode::LayoutImport));
 }
 
 #[test]
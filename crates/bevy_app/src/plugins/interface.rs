@@ -1,15 +1,16 @@
 //! インターフェース関連のプラグイン
 
 use crate::interface::ui::dev_panel::{
+    cycle_heatmap_layer_button_system, export_heatmap_csv_button_system,
     toggle_dev_panel_minimize_button_system, toggle_instant_build_button_system,
     toggle_render3d_button_system, toggle_rtt_extra_light_button_system,
     toggle_rtt_light_button_system, toggle_rtt_scene_objects_button_system,
     toggle_rtt_terrain_button_system, toggle_soul_mask_button_system,
-    update_instant_build_button_visual_system, update_lod_indicator_system,
-    update_render_perf_status_system, update_render3d_button_visual_system,
-    update_rtt_extra_light_button_visual_system, update_rtt_light_button_visual_system,
-    update_rtt_scene_objects_button_visual_system, update_rtt_terrain_button_visual_system,
-    update_soul_mask_button_visual_system,
+    update_heatmap_layer_button_visual_system, update_instant_build_button_visual_system,
+    update_lod_indicator_system, update_render_perf_status_system,
+    update_render3d_button_visual_system, update_rtt_extra_light_button_visual_system,
+    update_rtt_light_button_visual_system, update_rtt_scene_objects_button_visual_system,
+    update_rtt_terrain_button_visual_system, update_soul_mask_button_visual_system,
 };
 use crate::interface::ui::plugins;
 use crate::plugins::interface_debug::debug_spawn_system;
@@ -44,6 +45,9 @@ impl Plugin for InterfacePlugin {
                 update_rtt_terrain_button_visual_system,
                 toggle_rtt_scene_objects_button_system.run_if(world_ui_actions_enabled),
                 update_rtt_scene_objects_button_visual_system,
+                cycle_heatmap_layer_button_system.run_if(world_ui_actions_enabled),
+                update_heatmap_layer_button_visual_system,
+                export_heatmap_csv_button_system.run_if(world_ui_actions_enabled),
                 update_lod_indicator_system,
                 update_render_perf_status_system,
             )
@@ -2,6 +2,8 @@
 
 use crate::entities::familiar::{familiar_movement, familiar_spawning_system};
 use crate::systems::GameSystemSet;
+use crate::systems::alerts::AlertsPlugin;
+use crate::systems::analytics::AnalyticsPlugin;
 use crate::systems::command::{
     AreaEditClipboard, AreaEditHistory, AreaEditPresets, AreaEditSession,
     StockpilePolicyRangeEditState, ZoneRemovalPreviewState, assign_task_system,
@@ -9,7 +11,9 @@ use crate::systems::command::{
     stockpile_policy_range_selection_system, task_area_edit_history_shortcuts_system,
     task_area_selection_system, zone_placement_system, zone_removal_system,
 };
+use crate::systems::contracts::ContractPlugin;
 use crate::systems::dream_tree_planting::dream_tree_planting_system;
+use crate::systems::edicts::EdictPlugin;
 use crate::systems::energy::grid_lifecycle::{
     on_power_consumer_added, on_yard_added, on_yard_removed,
 };
@@ -37,6 +41,7 @@ use crate::systems::jobs::{
 use crate::systems::logistics::item_lifetime::despawn_expired_items_system;
 use crate::systems::logistics::transport_request::{TransportRequestPlugin, TransportRequestSet};
 use crate::systems::soul_ai::SoulAiPlugin;
+use crate::systems::statistics::StatisticsPlugin;
 use crate::world::regrowth::{RegrowthManager, tree_regrowth_system};
 use bevy::prelude::*;
 use hw_core::game_state::PlayMode;
@@ -54,6 +59,7 @@ use hw_jobs::visual_sync::{
     sync_wall_tile_visual_system,
 };
 use hw_jobs::{GeneratePowerData, GeneratePowerPhase, TargetSoulSpaSite};
+use hw_logistics::supply_route::{on_supply_route_item_stored, on_supply_route_item_unstored};
 use hw_logistics::visual_sync::{
     on_stockpile_added_sync_visual, on_wheelbarrow_added, sync_inventory_item_visual_system,
     sync_stockpile_visual_system,
@@ -76,6 +82,11 @@ impl Plugin for LogicPlugin {
         app.add_plugins(FamiliarAiPlugin);
         app.add_plugins(TransportRequestPlugin);
         app.add_plugins(hw_logistics::LogisticsPlugin);
+        app.add_plugins(ContractPlugin);
+        app.add_plugins(EdictPlugin);
+        app.add_plugins(AnalyticsPlugin);
+        app.add_plugins(StatisticsPlugin);
+        app.add_plugins(AlertsPlugin);
 
         // パスファインディング用の作業メモリを登録
         app.init_resource::<RegrowthManager>();
@@ -231,7 +242,9 @@ impl Plugin for LogicPlugin {
         .add_observer(on_power_consumer_added)
         .add_observer(on_power_consumer_visual_added)
         .add_observer(on_unpowered_added)
-        .add_observer(on_unpowered_removed);
+        .add_observer(on_unpowered_removed)
+        .add_observer(on_supply_route_item_unstored)
+        .add_observer(on_supply_route_item_stored);
 
         register_construction_systems(app);
 
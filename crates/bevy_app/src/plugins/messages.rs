@@ -2,7 +2,11 @@ use bevy::prelude::*;
 
 use crate::entities::damned_soul::DamnedSoulSpawnEvent;
 use crate::entities::familiar::FamiliarSpawnEvent;
-use crate::interface::ui::panels::task_list::TaskActionOutcome;
+use crate::interface::ui::panels::task_list::{TaskActionOutcome, TaskGroupActionOutcome};
+use crate::systems::alerts::ColonyAlert;
+use crate::systems::contracts::ContractOutcome;
+use crate::systems::edicts::{EdictOutcome, EdictToggleRequest};
+use crate::systems::soul_ai::adapters::SoulRedemptionOutcome;
 use crate::{
     DesignationRequest, EncouragementRequest, EscapeRequest, FamiliarAiStateChangedEvent,
     FamiliarIdleVisualRequest, FamiliarOperationMaxSoulChangedEvent, FamiliarStateRequest,
@@ -11,12 +15,15 @@ use crate::{
     TaskAssignmentRequest,
 };
 use hw_core::events::{
-    DreamTransferredVisualMessage, OnGatheringJoined, OnGatheringParticipated,
+    DreamTransferredVisualMessage, OnDreamContributed, OnGatheringJoined, OnGatheringParticipated,
     OnReleasedFromService, OnTaskAbandoned, OnTaskAssigned, SoulEncouragedVisualMessage,
     SoulExhaustedVisualMessage, SoulRecruitedVisualMessage, SoulStressBreakdownVisualMessage,
     TaskCompletedVisualMessage,
 };
-use hw_logistics::{StockpilePolicyChangeOutcome, StockpilePolicyChangeRequest};
+use hw_logistics::{
+    StockpilePolicyChangeOutcome, StockpilePolicyChangeRequest, SupplyRouteChangeOutcome,
+    SupplyRouteChangeRequest,
+};
 use hw_visual::speech::conversation::events::{
     ConversationCompleted, ConversationToneTriggered, RequestConversation,
 };
@@ -48,6 +55,7 @@ macro_rules! root_message_types {
             TaskCompletedVisualMessage,
             SoulEncouragedVisualMessage,
             DreamTransferredVisualMessage,
+            OnDreamContributed,
             OnReleasedFromService,
             OnGatheringJoined,
             OnTaskAbandoned,
@@ -56,8 +64,16 @@ macro_rules! root_message_types {
             GatheringSpawnRequest,
             SoulTaskUnassignRequest,
             TaskActionOutcome,
+            TaskGroupActionOutcome,
+            SoulRedemptionOutcome,
+            ContractOutcome,
+            EdictToggleRequest,
+            EdictOutcome,
+            ColonyAlert,
             StockpilePolicyChangeRequest,
             StockpilePolicyChangeOutcome,
+            SupplyRouteChangeRequest,
+            SupplyRouteChangeOutcome,
         );
     };
 }
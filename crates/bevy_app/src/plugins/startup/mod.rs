@@ -37,7 +37,9 @@ use crate::app_contexts::{
     BuildContext, CompanionPlacementState, MoveContext, MovePlacementState, TaskContext,
     ZoneContext,
 };
-use crate::interface::selection::{HoveredEntity, SelectedEntity};
+use crate::interface::selection::{
+    HoveredEntity, SelectedEntities, SelectedEntity, SelectionBoxDrag,
+};
 use crate::interface::ui::{MenuState, setup_ui};
 #[cfg(feature = "profiling")]
 use crate::systems::GameSystemSet;
@@ -62,6 +64,8 @@ impl Plugin for StartupPlugin {
             .register_type::<RttQualityPreset>()
             .init_resource::<QualitySettings>()
             .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
+            .init_resource::<SelectionBoxDrag>()
             .init_resource::<HoveredEntity>()
             .init_resource::<MenuState>()
             .init_resource::<ArchitectCategoryState>()
@@ -168,9 +172,15 @@ impl Plugin for StartupPlugin {
                     perf_scenario::start_perf_capture_system
                         .in_set(PerfScenarioSet::InitialCheckpoint),
                 )
+                .init_resource::<perf_scenario::PerfMapgenMetrics>()
                 .add_systems(
                     Update,
-                    perf_scenario::drive_perf_workload_system.in_set(PerfScenarioSet::Driver),
+                    (
+                        perf_scenario::drive_perf_workload_system,
+                        perf_scenario::drive_save_load_workload_system,
+                        perf_scenario::drive_mapgen_workload_system,
+                    )
+                        .in_set(PerfScenarioSet::Driver),
                 )
                 .add_systems(
                     Update,
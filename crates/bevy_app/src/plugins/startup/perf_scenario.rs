@@ -9,20 +9,36 @@ use crate::entities::familiar::{ActiveCommand, Familiar, FamiliarCommand, Famili
 #[cfg(feature = "profiling")]
 use crate::systems::command::TaskArea;
 #[cfg(feature = "profiling")]
+use crate::systems::command::zone_placement::placement::STOCKPILE_CELL_CAPACITY;
+#[cfg(feature = "profiling")]
+use crate::systems::command::zone_placement::try_spawn_stockpile_cell;
+#[cfg(feature = "profiling")]
 use crate::systems::energy::grid_recalc::EnergyPerfMetrics;
 #[cfg(feature = "profiling")]
 use crate::systems::familiar_ai::FamiliarAiState;
 #[cfg(feature = "profiling")]
 use crate::systems::familiar_ai::perceive::resource_sync::ReservationSyncPerfMetrics;
 #[cfg(feature = "profiling")]
+use crate::systems::invariants::InvariantReport;
+#[cfg(feature = "profiling")]
 use crate::systems::jobs::{
-    Blueprint, BuildingType, ConstructionPerfMetrics, Designation, Door, DoorState, Priority, Rock,
-    TaskSlots, Tree, WorkType,
+    Blueprint, Building, BuildingType, ConstructionPerfMetrics, Designation, Door, DoorState,
+    MudMixerStorage, ObstaclePosition, ObstacleSourceKind, Priority, Rock, TaskSlots, Tree,
+    WorkType,
+};
+#[cfg(feature = "profiling")]
+use crate::systems::logistics::{
+    BelongsTo, ResourceItem, ResourceType, Stockpile, Wheelbarrow, WheelbarrowParking,
+};
+#[cfg(feature = "profiling")]
+use crate::systems::save::{
+    SaveLoadOperation, SaveLoadOutcome, SaveLoadPerfMetrics, SaveLoadResult, SaveLoadState,
+    SavePath,
 };
 #[cfg(feature = "profiling")]
 use crate::systems::soul_ai::execute::task_execution::AssignedTask;
 #[cfg(feature = "profiling")]
-use crate::world::map::{WorldMap, WorldMapWrite};
+use crate::world::map::{RIVER_Y_MIN, WorldMap, WorldMapWrite};
 use crate::{Render3dVisible, RenderPerfToggles};
 #[cfg(feature = "profiling")]
 use bevy::ecs::system::SystemParam;
@@ -30,7 +46,12 @@ use bevy::prelude::*;
 #[cfg(feature = "profiling")]
 use bevy::time::{Fixed, Real};
 #[cfg(feature = "profiling")]
-use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH, TILE_SIZE, Z_MAP};
+use hw_core::constants::{
+    MAP_HEIGHT, MAP_WIDTH, MUD_MIXER_CAPACITY, TILE_SIZE, WHEELBARROW_CAPACITY, Z_ITEM_OBSTACLE,
+    Z_ITEM_PICKUP, Z_MAP,
+};
+#[cfg(feature = "profiling")]
+use hw_core::relationships::{LoadedItems, ParkedAt, StoredIn};
 #[cfg(feature = "profiling")]
 use hw_core::simulation_rng::SimulationRandomState;
 #[cfg(feature = "profiling")]
@@ -44,6 +65,10 @@ use hw_jobs::construction::{
     FloorConstructionPhase, FloorConstructionSite, FloorTileBlueprint, FloorTileState,
 };
 #[cfg(feature = "profiling")]
+use hw_logistics::transport_request::TransportPriority;
+#[cfg(feature = "profiling")]
+use hw_logistics::{StockpileAcceptance, StockpilePolicy};
+#[cfg(feature = "profiling")]
 use hw_soul_ai::soul_ai::execute::task_execution::TaskExecutionPerfMetrics;
 #[cfg(feature = "profiling")]
 use hw_soul_ai::soul_ai::pathfinding::RuntimePathDeferMetrics;
@@ -52,11 +77,16 @@ use hw_soul_ai::soul_ai::update::slow_simulation::SlowSimulationPerfMetrics;
 #[cfg(feature = "profiling")]
 use hw_spatial::DoorPerfMetrics;
 #[cfg(feature = "profiling")]
+use hw_ui::selection::{PlacementGeometry, building_geometry};
+#[cfg(feature = "profiling")]
 use hw_visual::visual3d::{
     Building3dVisual, FamiliarProxy3d, SoulMaskProxy3d, SoulProxy3d, SoulShadowProxy3d,
 };
 #[cfg(feature = "profiling")]
-use hw_world::{DoorVisualHandles, RuntimePathSearchBudget, RuntimePathSearchMetrics};
+use hw_world::{
+    DoorVisualHandles, RuntimePathSearchBudget, RuntimePathSearchMetrics, Yard,
+    generate_world_layout,
+};
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 use std::env;
@@ -89,7 +119,10 @@ pub(crate) use fixture::{PerfScenarioApplied, PerfScenarioDriverState, PerfScena
 #[cfg(feature = "profiling")]
 pub use fixture::{setup_perf_scenario_if_enabled, setup_perf_scenario_runtime_if_enabled};
 #[cfg(feature = "profiling")]
-pub(crate) use workload_driver::drive_perf_workload_system;
+pub(crate) use workload_driver::{
+    PerfMapgenMetrics, drive_mapgen_workload_system, drive_perf_workload_system,
+    drive_save_load_workload_system,
+};
 
 #[cfg(feature = "profiling")]
 use audit_checksum::{
@@ -101,7 +134,7 @@ use audit_encoding::*;
 #[cfg(feature = "profiling")]
 use config::{FIXED_STEP_AUDIT_EARLY_UPDATE_TICKS, PERF_SUMMARY_SCHEMA_VERSION};
 #[cfg(feature = "profiling")]
-use fixture::{PerfFixtureKind, PerfFixtureMarker};
+use fixture::{PerfFixtureKind, PerfFixtureMarker, perf_save_load_path};
 #[cfg(feature = "profiling")]
 use output::{
     PerfCaptureWriteInput, fnv1a, fnv1a_bytes, write_determinism_audit, write_perf_capture,
@@ -294,4 +327,7 @@ pub(crate) struct PerfCaptureParams<'w, 's> {
     energy_metrics: ResMut<'w, EnergyPerfMetrics>,
     runtime_path_budget: ResMut<'w, RuntimePathSearchBudget>,
     runtime_path_defer_metrics: ResMut<'w, RuntimePathDeferMetrics>,
+    invariant_report: Option<Res<'w, InvariantReport>>,
+    save_load_metrics: ResMut<'w, SaveLoadPerfMetrics>,
+    mapgen_metrics: ResMut<'w, PerfMapgenMetrics>,
 }
@@ -195,6 +195,8 @@ pub(super) fn collect_audit_actor_records(
                     write_grid_pos(&mut record, *grid);
                 }
             }
+            // Facility kind follows from the ordinal; the transform is already recorded.
+            PerfFixtureKind::LogisticsFacility => {}
         }
         records.push(PerfAuditActorRecord {
             actor_kind: "fixture",
@@ -87,6 +87,7 @@ pub(super) fn write_idle_behavior(record: &mut Vec<u8>, behavior: IdleBehavior)
         IdleBehavior::GoingToRest => 6,
         IdleBehavior::Escaping => 7,
         IdleBehavior::Drifting => 8,
+        IdleBehavior::Redeeming => 9,
     });
 }
 
@@ -259,6 +260,7 @@ pub(super) fn write_building_type(record: &mut Vec<u8>, kind: BuildingType) {
         BuildingType::WheelbarrowParking => 9,
         BuildingType::SoulSpa => 10,
         BuildingType::OutdoorLamp => 11,
+        BuildingType::RedemptionShrine => 12,
     });
 }
 
@@ -146,6 +146,8 @@ pub(crate) fn drive_perf_capture_system(
                     *params.construction_metrics = ConstructionPerfMetrics::default();
                     *params.slow_simulation_metrics = SlowSimulationPerfMetrics::default();
                     *params.energy_metrics = EnergyPerfMetrics::default();
+                    *params.save_load_metrics = SaveLoadPerfMetrics::default();
+                    *params.mapgen_metrics = PerfMapgenMetrics::default();
                     params.runtime_path_budget.clear_metrics();
                     params.runtime_path_defer_metrics.clear();
                     eprintln!(
@@ -219,6 +221,9 @@ pub(crate) fn drive_perf_capture_system(
                             energy_metrics: &params.energy_metrics,
                             runtime_path_metrics: params.runtime_path_budget.metrics(),
                             runtime_path_defer_metrics: &params.runtime_path_defer_metrics,
+                            invariant_report: params.invariant_report.as_deref(),
+                            save_load_metrics: &params.save_load_metrics,
+                            mapgen_metrics: &params.mapgen_metrics,
                         })
                     }
                     _ => Err(std::io::Error::other(
@@ -228,6 +233,9 @@ pub(crate) fn drive_perf_capture_system(
             };
 
             capture.phase = PerfCapturePhase::Finished;
+            if params.config.workload == PerfWorkload::SaveLoad {
+                let _ = std::fs::remove_file(perf_save_load_path());
+            }
             if let Err(error) = result {
                 error!("PERF_CAPTURE: failed to write CSV: {error}");
                 exit.write(AppExit::error());
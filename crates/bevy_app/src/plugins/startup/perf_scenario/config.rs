@@ -6,7 +6,7 @@ use parse::*;
 const DEFAULT_WARMUP_SECS: f32 = 30.0;
 const DEFAULT_MEASURE_SECS: f32 = 60.0;
 #[cfg(feature = "profiling")]
-pub(super) const PERF_SUMMARY_SCHEMA_VERSION: u32 = 10;
+pub(super) const PERF_SUMMARY_SCHEMA_VERSION: u32 = 12;
 pub(super) const FIXED_STEP_AUDIT_EARLY_UPDATE_TICKS: [u64; 4] = [1, 8, 32, 128];
 const DEFAULT_FIXED_STEP_HZ: u32 = 64;
 const DEFAULT_FIXED_WARMUP_TICKS: u64 = 1_920;
@@ -18,6 +18,9 @@ pub enum PerfWorkload {
     PathDoor,
     Construction,
     UiGpu,
+    Logistics,
+    SaveLoad,
+    Mapgen,
 }
 
 impl PerfWorkload {
@@ -27,6 +30,9 @@ impl PerfWorkload {
             "path-door" => Some(Self::PathDoor),
             "construction" => Some(Self::Construction),
             "ui-gpu" => Some(Self::UiGpu),
+            "logistics" => Some(Self::Logistics),
+            "save-load" => Some(Self::SaveLoad),
+            "mapgen" => Some(Self::Mapgen),
             _ => None,
         }
     }
@@ -37,6 +43,9 @@ impl PerfWorkload {
             Self::PathDoor => "path-door",
             Self::Construction => "construction",
             Self::UiGpu => "ui-gpu",
+            Self::Logistics => "logistics",
+            Self::SaveLoad => "save-load",
+            Self::Mapgen => "mapgen",
         }
     }
 
@@ -195,7 +204,7 @@ impl PerfScenarioConfig {
         let workload = parse_value_or_default(
             value_from_args_or_env(&args, "--perf-workload", "HW_PERF_WORKLOAD")?,
             "--perf-workload",
-            "gather|path-door|construction|ui-gpu",
+            "gather|path-door|construction|ui-gpu|logistics|save-load|mapgen",
             PerfWorkload::parse,
             PerfWorkload::Gather,
         )?;
@@ -67,3 +67,21 @@ fn fixed_clock_mode_is_explicit() {
     assert_eq!(PerfClockMode::parse("auto"), None);
     assert_eq!(PerfClockMode::Fixed.as_str(), "fixed");
 }
+
+#[test]
+fn workload_names_round_trip() {
+    use super::PerfWorkload;
+
+    for workload in [
+        PerfWorkload::Gather,
+        PerfWorkload::PathDoor,
+        PerfWorkload::Construction,
+        PerfWorkload::UiGpu,
+        PerfWorkload::Logistics,
+        PerfWorkload::SaveLoad,
+        PerfWorkload::Mapgen,
+    ] {
+        assert_eq!(PerfWorkload::parse(workload.as_str()), Some(workload));
+    }
+    assert_eq!(PerfWorkload::parse("save_load"), None);
+}
@@ -21,6 +21,7 @@ pub(super) enum PerfFixtureKind {
     ConstructionSite,
     ConstructionTile,
     UiBlueprint,
+    LogisticsFacility,
 }
 
 #[cfg(feature = "profiling")]
@@ -31,6 +32,7 @@ impl PerfFixtureKind {
             Self::ConstructionSite => 1,
             Self::ConstructionTile => 2,
             Self::UiBlueprint => 3,
+            Self::LogisticsFacility => 4,
         }
     }
 }
@@ -40,6 +42,8 @@ impl PerfFixtureKind {
 #[derive(Resource, Default)]
 pub(crate) struct PerfScenarioDriverState {
     pub(super) last_path_door_toggle_slot: Option<u64>,
+    pub(super) last_save_load_slot: Option<u64>,
+    pub(super) next_mapgen_seed_offset: u64,
 }
 
 #[cfg(feature = "profiling")]
@@ -68,6 +72,8 @@ type PerfSetupSoulQuery<'w, 's> = Query<
 type PerfTreeQuery<'w, 's> = Query<'w, 's, Entity, With<Tree>>;
 #[cfg(feature = "profiling")]
 type PerfRockQuery<'w, 's> = Query<'w, 's, Entity, With<Rock>>;
+#[cfg(feature = "profiling")]
+type PerfYardQuery<'w, 's> = Query<'w, 's, (Entity, &'static Yard)>;
 
 #[cfg(feature = "profiling")]
 #[derive(SystemParam)]
@@ -79,6 +85,7 @@ pub struct PerfWorkloadSetupParams<'w, 's> {
     q_souls: PerfSetupSoulQuery<'w, 's>,
     q_trees: PerfTreeQuery<'w, 's>,
     q_rocks: PerfRockQuery<'w, 's>,
+    q_yards: PerfYardQuery<'w, 's>,
     world_map: WorldMapWrite<'w>,
 }
 
@@ -101,31 +108,12 @@ pub fn setup_perf_scenario_if_enabled(params: PerfWorkloadSetupParams) {
 }
 
 #[cfg(feature = "profiling")]
-fn setup_perf_workload_if_needed(params: PerfWorkloadSetupParams) {
-    let PerfWorkloadSetupParams {
-        config,
-        mut commands,
-        mut applied,
-        mut q_familiars,
-        mut q_souls,
-        q_trees,
-        q_rocks,
-        mut world_map,
-    } = params;
-
-    if applied.0 || !config.enabled() || q_familiars.is_empty() {
+fn setup_perf_workload_if_needed(mut params: PerfWorkloadSetupParams) {
+    if params.applied.0 || !params.config.enabled() || params.q_familiars.is_empty() {
         return;
     }
 
-    applied.0 = configure_perf_workload(
-        &config,
-        &mut commands,
-        &mut q_familiars,
-        &mut q_souls,
-        &q_trees,
-        &q_rocks,
-        &mut world_map,
-    );
+    params.applied.0 = configure_perf_workload(&mut params);
 }
 
 #[cfg(feature = "profiling")]
@@ -134,21 +122,19 @@ pub fn setup_perf_scenario_runtime_if_enabled(params: PerfWorkloadSetupParams) {
 }
 
 #[cfg(feature = "profiling")]
-fn configure_perf_workload(
-    config: &PerfScenarioConfig,
-    commands: &mut Commands,
-    q_familiars: &mut Query<(Entity, &mut ActiveCommand, &mut FamiliarOperation)>,
-    q_souls: &mut Query<(
-        Entity,
-        &mut Transform,
-        &mut Destination,
-        &mut Path,
-        &mut AssignedTask,
-    )>,
-    q_trees: &Query<Entity, With<Tree>>,
-    q_rocks: &Query<Entity, With<Rock>>,
-    world_map: &mut WorldMapWrite,
-) -> bool {
+fn configure_perf_workload(params: &mut PerfWorkloadSetupParams) -> bool {
+    let PerfWorkloadSetupParams {
+        config,
+        commands,
+        q_familiars,
+        q_souls,
+        q_trees,
+        q_rocks,
+        q_yards,
+        world_map,
+        ..
+    } = params;
+
     match config.workload {
         PerfWorkload::Gather => {
             configure_gather_baseline(commands, q_familiars, q_trees, q_rocks);
@@ -163,6 +149,25 @@ fn configure_perf_workload(
         PerfWorkload::UiGpu => {
             configure_ui_gpu_fixture(commands, q_familiars, world_map, config.size)
         }
+        PerfWorkload::Logistics => {
+            if !spawn_logistics_fixture(commands, q_yards, world_map, config.size) {
+                return false;
+            }
+            configure_gather_baseline(commands, q_familiars, q_trees, q_rocks);
+            true
+        }
+        PerfWorkload::SaveLoad => {
+            configure_gather_baseline(commands, q_familiars, q_trees, q_rocks);
+            commands.insert_resource(SavePath::new(perf_save_load_path()));
+            true
+        }
+        PerfWorkload::Mapgen => {
+            for (_, mut command, mut operation) in q_familiars.iter_mut() {
+                command.command = FamiliarCommand::Idle;
+                operation.max_controlled_soul = 0;
+            }
+            true
+        }
     }
 }
 
@@ -404,6 +409,237 @@ fn configure_ui_gpu_fixture(
     true
 }
 
+/// logistics workload の施設と在庫。
+///
+/// 最初の Yard に方針付き Stockpile セル、手押し車置き場、MudMixer を敷き詰め、Yard の
+/// 外に散らばった地面アイテムを運搬させる。3 セルに 1 つは一部だけ埋めておき、統合運搬の
+/// 候補も常に存在するようにする。
+#[cfg(feature = "profiling")]
+fn spawn_logistics_fixture(
+    commands: &mut Commands,
+    q_yards: &Query<(Entity, &Yard)>,
+    world_map: &mut WorldMapWrite,
+    size: PerfScenarioSize,
+) -> bool {
+    let (stockpile_count, parking_count, mixer_count, loose_item_count) = match size {
+        PerfScenarioSize::Small => (24, 1, 1, 40),
+        PerfScenarioSize::Medium => (48, 2, 1, 120),
+        PerfScenarioSize::Large => (96, 4, 2, 240),
+    };
+    let Some((_, yard)) = q_yards.iter().min_by_key(|(_, yard)| {
+        let grid = WorldMap::world_to_grid(yard.min);
+        (grid.1, grid.0)
+    }) else {
+        error!("PERF_CAPTURE: logistics fixture requires a Yard");
+        return false;
+    };
+    let (min_x, min_y) = WorldMap::world_to_grid(yard.min);
+    let (max_x, max_y) = WorldMap::world_to_grid(yard.max);
+    let in_yard = |grid: (i32, i32)| yard.contains(WorldMap::grid_to_world(grid.0, grid.1));
+
+    let facilities = std::iter::repeat_n(BuildingType::WheelbarrowParking, parking_count)
+        .chain(std::iter::repeat_n(BuildingType::MudMixer, mixer_count))
+        .collect::<Vec<_>>();
+    let mut bases = (min_y..=max_y)
+        .step_by(3)
+        .flat_map(|y| (min_x..=max_x).step_by(3).map(move |x| (x, y)));
+    for (ordinal, kind) in facilities.into_iter().enumerate() {
+        let Some(geometry) = bases.find_map(|base| {
+            let geometry = building_geometry(kind, base, RIVER_Y_MIN);
+            geometry
+                .occupied_grids
+                .iter()
+                .all(|&grid| {
+                    in_yard(grid)
+                        && fixture_grid_is_free(world_map.as_ref(), grid)
+                        && !world_map.has_stockpile(grid)
+                })
+                .then_some(geometry)
+        }) else {
+            error!("PERF_CAPTURE: logistics fixture could not place {kind:?} inside the Yard");
+            return false;
+        };
+        spawn_logistics_facility(commands, world_map.as_mut(), kind, geometry, ordinal as u32);
+    }
+
+    let policies = [
+        StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY),
+        StockpilePolicy {
+            acceptance: StockpileAcceptance::Only(ResourceType::Wood),
+            inbound_priority: TransportPriority::High,
+            ..StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY)
+        },
+        StockpilePolicy {
+            acceptance: StockpileAcceptance::Only(ResourceType::Rock),
+            ..StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY)
+        },
+        StockpilePolicy {
+            acceptance: StockpileAcceptance::Only(ResourceType::Sand),
+            target_amount: 5,
+            allow_export: false,
+            ..StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY)
+        },
+    ];
+    let mut spawned = 0;
+    for grid in (min_y..=max_y).flat_map(|y| (min_x..=max_x).map(move |x| (x, y))) {
+        if spawned == stockpile_count {
+            break;
+        }
+        let policy = policies[spawned % policies.len()];
+        let Ok(stockpile) =
+            try_spawn_stockpile_cell(commands, world_map.as_mut(), grid, policy, q_yards)
+        else {
+            continue;
+        };
+        if spawned % 3 == 0 {
+            let resource = match policy.acceptance {
+                StockpileAcceptance::Only(resource) => resource,
+                _ => ResourceType::Wood,
+            };
+            commands.entity(stockpile).insert(Stockpile {
+                capacity: STOCKPILE_CELL_CAPACITY,
+                resource_type: Some(resource),
+            });
+            for _ in 0..3 {
+                spawn_logistics_item(commands, resource, grid, Some(stockpile));
+            }
+        }
+        spawned += 1;
+    }
+    if spawned != stockpile_count {
+        error!(
+            "PERF_CAPTURE: logistics fixture placed only {spawned} of {stockpile_count} stockpiles"
+        );
+        return false;
+    }
+
+    let loose_grids = fixture_free_grids(world_map.as_ref(), MAP_WIDTH as usize * 4)
+        .into_iter()
+        .filter(|&grid| !in_yard(grid))
+        .take(loose_item_count)
+        .collect::<Vec<_>>();
+    if loose_grids.len() != loose_item_count {
+        error!(
+            "PERF_CAPTURE: logistics fixture found only {} of {loose_item_count} loose item tiles",
+            loose_grids.len()
+        );
+        return false;
+    }
+    let loose_resources = [ResourceType::Wood, ResourceType::Rock, ResourceType::Sand];
+    for (ordinal, grid) in loose_grids.into_iter().enumerate() {
+        let resource = loose_resources[ordinal % loose_resources.len()];
+        spawn_logistics_item(commands, resource, grid, None);
+    }
+    true
+}
+
+/// 完成済みの手押し車置き場（手押し車付き）または MudMixer。
+///
+/// 建築完了時の post-process と初期配置が付ける component だけを付与する。
+#[cfg(feature = "profiling")]
+fn spawn_logistics_facility(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    kind: BuildingType,
+    geometry: PlacementGeometry,
+    ordinal: u32,
+) {
+    let building_entity = commands
+        .spawn((
+            Building {
+                kind,
+                is_provisional: false,
+            },
+            Sprite {
+                custom_size: Some(geometry.size),
+                ..default()
+            },
+            Transform::from_translation(geometry.draw_pos.extend(Z_ITEM_OBSTACLE)),
+            PerfFixtureMarker {
+                kind: PerfFixtureKind::LogisticsFacility,
+                ordinal,
+            },
+            Name::new("PerfLogisticsFacilityFixture"),
+        ))
+        .id();
+    commands.entity(building_entity).with_children(|parent| {
+        for &(gx, gy) in &geometry.occupied_grids {
+            parent.spawn((
+                ObstaclePosition(gx, gy),
+                ObstacleSourceKind::BuildingFootprint,
+                Name::new("Building Obstacle"),
+            ));
+        }
+    });
+    world_map.register_completed_building_footprint(kind, building_entity, geometry.occupied_grids);
+
+    if kind == BuildingType::MudMixer {
+        commands.entity(building_entity).insert((
+            MudMixerStorage::default(),
+            Stockpile {
+                capacity: MUD_MIXER_CAPACITY as usize,
+                resource_type: Some(ResourceType::Water),
+            },
+        ));
+        return;
+    }
+
+    const PARKING_CAPACITY: usize = 2;
+    commands.entity(building_entity).insert(WheelbarrowParking {
+        capacity: PARKING_CAPACITY,
+    });
+    for offset in [Vec2::new(-8.0, -8.0), Vec2::new(8.0, 8.0)] {
+        let position = geometry.draw_pos + offset;
+        commands.spawn((
+            ResourceItem(ResourceType::Wheelbarrow),
+            Wheelbarrow {
+                capacity: WHEELBARROW_CAPACITY,
+            },
+            BelongsTo(building_entity),
+            ParkedAt(building_entity),
+            LoadedItems::default(),
+            TaskSlots::new(1),
+            Sprite {
+                custom_size: Some(Vec2::splat(TILE_SIZE * 0.6)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(Z_ITEM_PICKUP)),
+            Visibility::Visible,
+            Name::new("PerfLogisticsWheelbarrowFixture"),
+        ));
+    }
+}
+
+#[cfg(feature = "profiling")]
+fn spawn_logistics_item(
+    commands: &mut Commands,
+    resource: ResourceType,
+    grid: (i32, i32),
+    stored_in: Option<Entity>,
+) {
+    let position = WorldMap::grid_to_world(grid.0, grid.1);
+    let mut item = commands.spawn((
+        ResourceItem(resource),
+        Sprite {
+            custom_size: Some(Vec2::splat(TILE_SIZE * 0.5)),
+            ..default()
+        },
+        Transform::from_translation(position.extend(Z_ITEM_PICKUP)),
+    ));
+    if let Some(stockpile) = stored_in {
+        item.insert(StoredIn(stockpile));
+    }
+}
+
+/// save-load workload が書き込むセーブ先。プロセスごとに分け、capture 終了時に消す。
+#[cfg(feature = "profiling")]
+pub(super) fn perf_save_load_path() -> PathBuf {
+    env::temp_dir().join(format!(
+        "hell-workers-perf-save-load-{}.sav",
+        std::process::id()
+    ))
+}
+
 #[cfg(feature = "profiling")]
 type PerfGridPosition = (i32, i32);
 #[cfg(feature = "profiling")]
@@ -21,6 +21,9 @@ pub(super) struct PerfCaptureWriteInput<'a> {
     pub(super) energy_metrics: &'a EnergyPerfMetrics,
     pub(super) runtime_path_metrics: &'a RuntimePathSearchMetrics,
     pub(super) runtime_path_defer_metrics: &'a RuntimePathDeferMetrics,
+    pub(super) invariant_report: Option<&'a InvariantReport>,
+    pub(super) save_load_metrics: &'a SaveLoadPerfMetrics,
+    pub(super) mapgen_metrics: &'a PerfMapgenMetrics,
 }
 
 #[cfg(feature = "profiling")]
@@ -45,6 +48,9 @@ pub(super) fn write_perf_capture(input: PerfCaptureWriteInput<'_>) -> std::io::R
         energy_metrics,
         runtime_path_metrics,
         runtime_path_defer_metrics,
+        invariant_report,
+        save_load_metrics,
+        mapgen_metrics,
     } = input;
 
     if config.uses_fixed_timesteps() {
@@ -129,7 +135,12 @@ pub(super) fn write_perf_capture(input: PerfCaptureWriteInput<'_>) -> std::io::R
         "slow_simulation_steps,slow_simulation_souls_updated,slow_simulation_idle_decisions,",
         "slow_simulation_idle_spatial_target_lookups,slow_simulation_state_sanity_audits,",
         "energy_power_output_runs,energy_grid_recalc_runs,energy_lamp_steps,",
-        "energy_lamp_candidates_scanned\n"
+        "energy_lamp_candidates_scanned,invariant_checks,invariant_violations,",
+        "save_load_saves,save_load_save_elapsed_micros,save_load_save_max_elapsed_micros,",
+        "save_load_save_bytes,save_load_loads,save_load_load_elapsed_micros,",
+        "save_load_load_max_elapsed_micros,save_load_rehydrate_elapsed_micros,",
+        "mapgen_layouts,mapgen_elapsed_micros,mapgen_max_elapsed_micros,mapgen_retries,",
+        "mapgen_fallbacks,peak_rss_kib\n"
     );
     let summary_fields = vec![
         PERF_SUMMARY_SCHEMA_VERSION.to_string(),
@@ -237,6 +248,26 @@ pub(super) fn write_perf_capture(input: PerfCaptureWriteInput<'_>) -> std::io::R
         energy_metrics.grid_recalc_runs.to_string(),
         energy_metrics.lamp_steps.to_string(),
         energy_metrics.lamp_candidates_scanned.to_string(),
+        invariant_report
+            .map_or(0, |report| report.checks)
+            .to_string(),
+        invariant_report
+            .map_or(0, |report| report.total_violations)
+            .to_string(),
+        save_load_metrics.saves.to_string(),
+        save_load_metrics.save_elapsed_micros.to_string(),
+        save_load_metrics.save_max_elapsed_micros.to_string(),
+        save_load_metrics.save_bytes.to_string(),
+        save_load_metrics.loads.to_string(),
+        save_load_metrics.load_elapsed_micros.to_string(),
+        save_load_metrics.load_max_elapsed_micros.to_string(),
+        save_load_metrics.rehydrate_elapsed_micros.to_string(),
+        mapgen_metrics.layouts.to_string(),
+        mapgen_metrics.elapsed_micros.to_string(),
+        mapgen_metrics.max_elapsed_micros.to_string(),
+        mapgen_metrics.retries.to_string(),
+        mapgen_metrics.fallbacks.to_string(),
+        peak_rss_kib().to_string(),
     ];
     let summary = format!("{summary_header}{}\n", summary_fields.join(","));
     std::fs::write(&summary_path, summary)?;
@@ -250,6 +281,24 @@ pub(super) fn write_perf_capture(input: PerfCaptureWriteInput<'_>) -> std::io::R
     Ok(())
 }
 
+/// Process high-water RSS (`VmHWM`) in KiB. Platforms without procfs report 0.
+#[cfg(feature = "profiling")]
+fn peak_rss_kib() -> u64 {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status.lines().find_map(|line| {
+                line.strip_prefix("VmHWM:")?
+                    .trim()
+                    .trim_end_matches("kB")
+                    .trim()
+                    .parse()
+                    .ok()
+            })
+        })
+        .unwrap_or(0)
+}
+
 #[cfg(feature = "profiling")]
 fn perf_output_directory(config: &PerfScenarioConfig) -> PathBuf {
     config.output_dir.clone().unwrap_or_else(|| {
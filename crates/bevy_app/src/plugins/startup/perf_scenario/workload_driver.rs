@@ -42,3 +42,102 @@ pub(crate) fn drive_perf_workload_system(
         );
     }
 }
+
+/// Save/load round-trip timing comes from `SaveLoadPerfMetrics`; this resource
+/// holds the equivalent counters for the mapgen workload.
+#[cfg(feature = "profiling")]
+#[derive(Resource, Debug, Default)]
+pub struct PerfMapgenMetrics {
+    pub layouts: u64,
+    pub elapsed_micros: u64,
+    pub max_elapsed_micros: u64,
+    /// Sum of the 0-indexed WFC attempt that produced each layout.
+    pub retries: u64,
+    pub fallbacks: u64,
+}
+
+/// Alternates save and load requests every five seconds of virtual time. Odd
+/// slots save and even slots load the file written by the previous slot.
+///
+/// `ActiveCommand` and `FamiliarOperation` are not persisted, so familiars are
+/// put back on the gather baseline after each successful load.
+#[cfg(feature = "profiling")]
+pub(crate) fn drive_save_load_workload_system(
+    config: Res<PerfScenarioConfig>,
+    applied: Res<PerfScenarioApplied>,
+    virtual_time: Res<Time<Virtual>>,
+    mut state: ResMut<PerfScenarioDriverState>,
+    mut save_load_state: ResMut<SaveLoadState>,
+    mut outcomes: MessageReader<SaveLoadOutcome>,
+    mut q_familiars: Query<(&mut ActiveCommand, &mut FamiliarOperation)>,
+) {
+    if !applied.0 || !config.enabled() || config.workload != PerfWorkload::SaveLoad {
+        outcomes.clear();
+        return;
+    }
+
+    for outcome in outcomes.read() {
+        match outcome.result {
+            SaveLoadResult::Failed(failure) => {
+                error!(
+                    "PERF_CAPTURE: save-load workload {} failed: {}",
+                    outcome.operation.key_part(),
+                    failure.key_part()
+                );
+            }
+            _ if outcome.operation == SaveLoadOperation::Load => {
+                for (mut command, mut operation) in q_familiars.iter_mut() {
+                    command.command = FamiliarCommand::GatherResources;
+                    operation.max_controlled_soul = 20;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let slot = (virtual_time.elapsed_secs_f64() / 5.0).floor() as u64;
+    if slot == 0
+        || state.last_save_load_slot == Some(slot)
+        || *save_load_state != SaveLoadState::Idle
+    {
+        return;
+    }
+    state.last_save_load_slot = Some(slot);
+    *save_load_state = if slot.is_multiple_of(2) {
+        SaveLoadState::LoadRequested
+    } else {
+        SaveLoadState::SaveRequested
+    };
+}
+
+/// Generates one world layout per frame from consecutive seeds starting at the
+/// scenario master seed. The live world is never touched.
+#[cfg(feature = "profiling")]
+pub(crate) fn drive_mapgen_workload_system(
+    config: Res<PerfScenarioConfig>,
+    applied: Res<PerfScenarioApplied>,
+    mut state: ResMut<PerfScenarioDriverState>,
+    mut metrics: ResMut<PerfMapgenMetrics>,
+) {
+    if !applied.0 || !config.enabled() || config.workload != PerfWorkload::Mapgen {
+        return;
+    }
+
+    let seed = config
+        .master_seed
+        .wrapping_add(state.next_mapgen_seed_offset);
+    state.next_mapgen_seed_offset = state.next_mapgen_seed_offset.wrapping_add(1);
+    let started = std::time::Instant::now();
+    let layout = generate_world_layout(seed);
+    let micros = u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX);
+
+    metrics.layouts = metrics.layouts.saturating_add(1);
+    metrics.elapsed_micros = metrics.elapsed_micros.saturating_add(micros);
+    metrics.max_elapsed_micros = metrics.max_elapsed_micros.max(micros);
+    metrics.retries = metrics
+        .retries
+        .saturating_add(u64::from(layout.generation_attempt));
+    if layout.used_fallback {
+        metrics.fallbacks = metrics.fallbacks.saturating_add(1);
+    }
+}
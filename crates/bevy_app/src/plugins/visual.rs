@@ -38,6 +38,7 @@ use crate::systems::visual::soul_animation::{
     sync_soul_face_expression_system,
 };
 use crate::systems::visual::soul_shadow_projector::sync_soul_shadow_projectors_system;
+use crate::systems::visual::supply_route_visual::supply_route_arrow_system;
 use crate::systems::visual::task_area_visual::update_task_area_material_system;
 use crate::systems::visual::terrain_lod::{
     TerrainLodMetrics, TerrainLodState, terrain_lod_switch_system,
@@ -134,6 +135,7 @@ impl Plugin for VisualPlugin {
                 crate::systems::command::designation_visual_system,
                 crate::systems::command::familiar_command_visual_system,
                 crate::systems::visual::placement_ghost::placement_ghost_system,
+                crate::systems::visual::layout_ghost::layout_ghost_system,
             )
                 .in_set(GameSystemSet::Visual)
                 .run_if(|state: Res<State<hw_core::game_state::PlayMode>>| {
@@ -182,6 +184,12 @@ impl Plugin for VisualPlugin {
                 .in_set(GameSystemSet::Visual),
         );
 
+        // 補給ルートの矢印（供給元 → 供給先）
+        app.add_systems(
+            Update,
+            supply_route_arrow_system.in_set(GameSystemSet::Visual),
+        );
+
         // task area visual（root 残留型に依存）
         app.add_systems(
             Update,
@@ -418,6 +426,7 @@ mod tests {
         let handle = world
             .spawn(AreaEditHandleVisual {
                 owner: stale,
+                rect_index: 0,
                 kind: AreaEditHandleKind::Center,
             })
             .id();
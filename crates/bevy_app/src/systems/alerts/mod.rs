@@ -0,0 +1,380 @@
+//! プレイヤー設定のアラート規則の評価
+//!
+//! `ALERT_EVALUATION_INTERVAL_SECS` ごとに有効な規則を評価し、条件が成立した瞬間だけ
+//! `ColonyAlert` を送る。成立し続けている間は再送せず、いったん解消すると次の成立で再び送る。
+//! 通知への変換は `interface::ui::notifications::adapt_colony_alerts` が行う。
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::alerts::{ALERT_EVALUATION_INTERVAL_SECS, AlertCondition, AlertRule, AlertRules};
+use hw_core::familiar::Familiar;
+use hw_core::logistics::ResourceType;
+use hw_core::relationships::{Commanding, RestAreaOccupants, RestAreaReservations, StoredIn};
+use hw_core::soul::DamnedSoul;
+use hw_energy::{GridConsumers, PowerGrid, Unpowered};
+use hw_jobs::RestArea;
+use hw_logistics::{ResourceItem, Stockpile};
+
+use crate::systems::GameSystemSet;
+
+/// 成立した条件の内容
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertFinding {
+    ResourceLow {
+        resource: ResourceType,
+        stocked: u32,
+        threshold: u32,
+    },
+    NoRestCapacity {
+        souls: u32,
+        capacity: u32,
+    },
+    GridBlackout {
+        generation: f32,
+        consumption: f32,
+    },
+    SoulStress {
+        count: u32,
+        peak: f32,
+        threshold: f32,
+    },
+    FamiliarWithoutWorkers {
+        name: String,
+        seconds: f32,
+    },
+}
+
+/// アラート規則の成立を通知 adapter へ渡す Message
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ColonyAlert {
+    pub rule_id: u32,
+    /// 規則が対象ごとに成立する場合の対象（電力網・使い魔）
+    pub scope: Option<Entity>,
+    /// 通知履歴のクリックでカメラを移動する先
+    pub focus: Option<Entity>,
+    pub finding: AlertFinding,
+}
+
+/// 評価タイミングと、成立中の条件・部下なし使い魔の経過時間
+#[derive(Resource, Debug, Default)]
+pub struct AlertMonitor {
+    next_evaluation_at: f32,
+    firing: HashSet<(u32, Option<Entity>)>,
+    /// 使役中の Soul がいなくなったゲーム内時刻
+    unstaffed_since: HashMap<Entity, f32>,
+}
+
+type RestAreaQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static RestArea,
+        Option<&'static RestAreaOccupants>,
+        Option<&'static RestAreaReservations>,
+    ),
+>;
+
+#[derive(SystemParam)]
+pub struct AlertSources<'w, 's> {
+    game_time: Res<'w, GameTime>,
+    q_stored: Query<'w, 's, &'static ResourceItem, With<StoredIn>>,
+    q_stockpiles: Query<'w, 's, Entity, With<Stockpile>>,
+    q_rest_areas: RestAreaQuery<'w, 's>,
+    q_souls: Query<'w, 's, (Entity, &'static DamnedSoul)>,
+    q_grids: Query<'w, 's, (Entity, &'static PowerGrid, Option<&'static GridConsumers>)>,
+    q_unpowered: Query<'w, 's, (), With<Unpowered>>,
+    q_familiars: Query<'w, 's, (Entity, &'static Familiar, Option<&'static Commanding>)>,
+}
+
+impl AlertSources<'_, '_> {
+    fn track_unstaffed_familiars(&self, unstaffed_since: &mut HashMap<Entity, f32>) {
+        let now = self.game_time.seconds;
+        let mut present = HashSet::new();
+        for (entity, _, commanding) in &self.q_familiars {
+            present.insert(entity);
+            if commanding.is_some_and(|souls| souls.iter().next().is_some()) {
+                unstaffed_since.remove(&entity);
+            } else {
+                unstaffed_since.entry(entity).or_insert(now);
+            }
+        }
+        unstaffed_since.retain(|entity, _| present.contains(entity));
+    }
+
+    fn evaluate(
+        &self,
+        rule: &AlertRule,
+        unstaffed_since: &HashMap<Entity, f32>,
+    ) -> Vec<ColonyAlert> {
+        let alert = |scope: Option<Entity>, focus: Option<Entity>, finding| ColonyAlert {
+            rule_id: rule.id,
+            scope,
+            focus,
+            finding,
+        };
+        match rule.condition {
+            AlertCondition::ResourceBelow {
+                resource,
+                threshold,
+            } => {
+                let stocked = self
+                    .q_stored
+                    .iter()
+                    .filter(|item| item.0 == resource)
+                    .count() as u32;
+                if stocked >= threshold {
+                    return Vec::new();
+                }
+                let focus = self.q_stockpiles.iter().next();
+                vec![alert(
+                    None,
+                    focus,
+                    AlertFinding::ResourceLow {
+                        resource,
+                        stocked,
+                        threshold,
+                    },
+                )]
+            }
+            AlertCondition::NoRestCapacity => {
+                let souls = self.q_souls.iter().count() as u32;
+                let mut capacity = 0;
+                let mut free = 0;
+                for (_, area, occupants, reservations) in &self.q_rest_areas {
+                    let used = occupants.map_or(0, RestAreaOccupants::len)
+                        + reservations.map_or(0, RestAreaReservations::len);
+                    capacity += area.capacity;
+                    free += area.capacity.saturating_sub(used);
+                }
+                if souls == 0 || free > 0 {
+                    return Vec::new();
+                }
+                let focus = self.q_rest_areas.iter().next().map(|(entity, ..)| entity);
+                vec![alert(
+                    None,
+                    focus,
+                    AlertFinding::NoRestCapacity {
+                        souls,
+                        capacity: capacity as u32,
+                    },
+                )]
+            }
+            AlertCondition::GridBlackout => self
+                .q_grids
+                .iter()
+                .filter(|(_, grid, _)| !grid.powered)
+                .map(|(entity, grid, consumers)| {
+                    let focus = consumers.and_then(|consumers| {
+                        consumers
+                            .iter()
+                            .copied()
+                            .find(|consumer| self.q_unpowered.contains(*consumer))
+                    });
+                    alert(
+                        Some(entity),
+                        focus,
+                        AlertFinding::GridBlackout {
+                            generation: grid.generation,
+                            consumption: grid.consumption,
+                        },
+                    )
+                })
+                .collect(),
+            AlertCondition::SoulStressAbove { threshold } => {
+                let mut count = 0;
+                let mut peak: Option<(Entity, f32)> = None;
+                for (entity, soul) in &self.q_souls {
+                    if soul.stress <= threshold {
+                        continue;
+                    }
+                    count += 1;
+                    if peak.is_none_or(|(_, stress)| soul.stress > stress) {
+                        peak = Some((entity, soul.stress));
+                    }
+                }
+                let Some((soul, stress)) = peak else {
+                    return Vec::new();
+                };
+                vec![alert(
+                    None,
+                    Some(soul),
+                    AlertFinding::SoulStress {
+                        count,
+                        peak: stress,
+                        threshold,
+                    },
+                )]
+            }
+            AlertCondition::FamiliarWithoutWorkers { seconds } => {
+                let now = self.game_time.seconds;
+                self.q_familiars
+                    .iter()
+                    .filter_map(|(entity, familiar, _)| {
+                        let since = *unstaffed_since.get(&entity)?;
+                        (now - since >= seconds).then(|| {
+                            alert(
+                                Some(entity),
+                                Some(entity),
+                                AlertFinding::FamiliarWithoutWorkers {
+                                    name: familiar.name.clone(),
+                                    seconds: now - since,
+                                },
+                            )
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// 有効な規則を定期評価し、新たに成立した条件だけ `ColonyAlert` を送る。
+pub fn alert_evaluation_system(
+    rules: Res<AlertRules>,
+    sources: AlertSources,
+    mut monitor: ResMut<AlertMonitor>,
+    mut alerts: MessageWriter<ColonyAlert>,
+) {
+    let now = sources.game_time.seconds;
+    // ゲーム内時間が巻き戻った場合は待たずに評価し直す
+    let rewound = monitor.next_evaluation_at - now > ALERT_EVALUATION_INTERVAL_SECS;
+    if now < monitor.next_evaluation_at && !rewound {
+        return;
+    }
+    monitor.next_evaluation_at = now + ALERT_EVALUATION_INTERVAL_SECS;
+    sources.track_unstaffed_familiars(&mut monitor.unstaffed_since);
+
+    let findings: Vec<ColonyAlert> = rules
+        .rules()
+        .iter()
+        .filter(|rule| rule.enabled)
+        .flat_map(|rule| sources.evaluate(rule, &monitor.unstaffed_since))
+        .collect();
+    let firing: HashSet<_> = findings
+        .iter()
+        .map(|alert| (alert.rule_id, alert.scope))
+        .collect();
+    for alert in findings {
+        if !monitor.firing.contains(&(alert.rule_id, alert.scope)) {
+            alerts.write(alert);
+        }
+    }
+    monitor.firing = firing;
+}
+
+fn reset_alert_monitor_for_world_replace(world: &mut World) {
+    world.insert_resource(AlertMonitor::default());
+}
+
+pub struct AlertsPlugin;
+
+impl Plugin for AlertsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AlertRules>()
+            .init_resource::<AlertMonitor>()
+            .add_message::<ColonyAlert>()
+            .add_systems(Update, alert_evaluation_system.in_set(GameSystemSet::Logic));
+        crate::systems::save::register_load_reset_hook(
+            app,
+            "alert-monitor",
+            reset_alert_monitor_for_world_replace,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+
+    fn drain_alerts(app: &mut App) -> Vec<ColonyAlert> {
+        app.world_mut()
+            .resource_mut::<Messages<ColonyAlert>>()
+            .drain()
+            .collect()
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        app.world_mut().resource_mut::<GameTime>().seconds += seconds;
+        app.update();
+    }
+
+    #[test]
+    fn alerts_fire_on_the_rising_edge_and_again_after_clearing() {
+        let mut app = App::new();
+        app.init_resource::<GameTime>()
+            .init_resource::<AlertRules>()
+            .init_resource::<AlertMonitor>()
+            .add_message::<ColonyAlert>()
+            .add_systems(Update, alert_evaluation_system);
+        let soul = app
+            .world_mut()
+            .spawn(DamnedSoul {
+                stress: 0.95,
+                ..default()
+            })
+            .id();
+
+        app.update();
+        let alerts = drain_alerts(&mut app);
+        let rule_ids: Vec<_> = alerts.iter().map(|alert| alert.rule_id).collect();
+        // Wood 0 本・休憩所なし・ストレス 0.95 が成立する
+        assert_eq!(rule_ids, [1, 2, 4]);
+        assert_eq!(alerts[2].focus, Some(soul));
+
+        advance(&mut app, ALERT_EVALUATION_INTERVAL_SECS);
+        assert!(drain_alerts(&mut app).is_empty());
+
+        app.world_mut().get_mut::<DamnedSoul>(soul).unwrap().stress = 0.1;
+        advance(&mut app, ALERT_EVALUATION_INTERVAL_SECS);
+        app.world_mut().get_mut::<DamnedSoul>(soul).unwrap().stress = 0.95;
+        advance(&mut app, ALERT_EVALUATION_INTERVAL_SECS);
+        let again = drain_alerts(&mut app);
+        assert_eq!(again.len(), 1);
+        assert!(matches!(
+            again[0].finding,
+            AlertFinding::SoulStress { count: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn familiar_alert_waits_for_the_configured_duration() {
+        let mut app = App::new();
+        let mut rules = AlertRules::default();
+        for id in 1..=4 {
+            rules.get_mut(id).unwrap().enabled = false;
+        }
+        app.init_resource::<GameTime>()
+            .insert_resource(rules)
+            .init_resource::<AlertMonitor>()
+            .add_message::<ColonyAlert>()
+            .add_systems(Update, alert_evaluation_system);
+        let familiar = app
+            .world_mut()
+            .spawn(Familiar {
+                name: "Skrix".to_string(),
+                ..default()
+            })
+            .id();
+
+        app.update();
+        advance(&mut app, 30.0);
+        assert!(drain_alerts(&mut app).is_empty());
+
+        advance(&mut app, 30.0);
+        let alerts = drain_alerts(&mut app);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].scope, Some(familiar));
+        assert_eq!(
+            alerts[0].finding,
+            AlertFinding::FamiliarWithoutWorkers {
+                name: "Skrix".to_string(),
+                seconds: 60.0,
+            }
+        );
+    }
+}
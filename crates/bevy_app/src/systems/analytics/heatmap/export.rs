@@ -0,0 +1,48 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::model::HeatmapTotals;
+
+/// ヒートマップ CSV の既定出力先（作業ディレクトリ相対）
+pub const HEATMAP_EXPORT_DIR: &str = "analytics";
+pub const HEATMAP_TILES_FILE: &str = "heatmap_tiles.csv";
+pub const HEATMAP_FLOWS_FILE: &str = "heatmap_flows.csv";
+
+/// タイル別集計と運搬フローを `dir` 配下の 2 つの CSV へ書き出す。
+///
+/// 既存ファイルは上書きする。戻り値は (タイル CSV, フロー CSV) のパス。
+pub fn write_heatmap_csv(dir: &Path, totals: &HeatmapTotals) -> io::Result<(PathBuf, PathBuf)> {
+    fs::create_dir_all(dir)?;
+    let tiles = dir.join(HEATMAP_TILES_FILE);
+    let flows = dir.join(HEATMAP_FLOWS_FILE);
+    fs::write(&tiles, totals.tile_csv())?;
+    fs::write(&flows, totals.flow_csv())?;
+    Ok((tiles, flows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::analytics::heatmap::model::HeatmapAccumulator;
+
+    #[test]
+    fn export_writes_tile_and_flow_csv_files() {
+        let directory =
+            std::env::temp_dir().join(format!("hell-workers-heatmap-test-{}", std::process::id()));
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.record_traffic((0, 0));
+        heatmap.record_haul_flow((0, 0), (2, 0));
+
+        let (tiles, flows) =
+            write_heatmap_csv(&directory, &heatmap.totals()).expect("export should succeed");
+
+        let tiles = fs::read_to_string(tiles).unwrap();
+        assert!(tiles.starts_with("x,y,traffic,"));
+        assert_eq!(tiles.lines().count(), 4);
+        let flows = fs::read_to_string(flows).unwrap();
+        assert_eq!(flows.lines().nth(1), Some("0,0,2,0,1"));
+
+        fs::remove_dir_all(directory).expect("test directory should be removable");
+    }
+}
@@ -0,0 +1,19 @@
+//! 分析ヒートマップ
+//!
+//! Soul の足跡・ストレス・待機、経路探索の拒否、運搬フローをタイル単位で
+//! ローリングウィンドウに蓄積し、ワールド上のオーバーレイと CSV として出力する。
+
+mod export;
+mod model;
+mod overlay;
+mod sampling;
+
+pub use export::{HEATMAP_EXPORT_DIR, HEATMAP_FLOWS_FILE, HEATMAP_TILES_FILE, write_heatmap_csv};
+pub use model::{
+    HEATMAP_BUCKET_COUNT, HEATMAP_BUCKET_SECS, HEATMAP_SAMPLE_INTERVAL_SECS, HeatmapAccumulator,
+    HeatmapLayer, HeatmapTotals,
+};
+pub use overlay::{
+    HeatmapOverlay, HeatmapOverlaySprite, heatmap_rgba, sync_heatmap_overlay_system,
+};
+pub use sampling::{HeatmapSamplingParams, heatmap_sampling_system};
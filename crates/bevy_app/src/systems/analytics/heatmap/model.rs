@@ -0,0 +1,433 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write as _;
+
+use bevy::prelude::*;
+use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
+use hw_core::world::GridPos;
+
+/// 1 バケットが受け持つゲーム内秒数
+pub const HEATMAP_BUCKET_SECS: f32 = 10.0;
+/// ローリングウィンドウのバケット数（既定で直近 2 分）
+pub const HEATMAP_BUCKET_COUNT: usize = 12;
+/// ストレス・待機のサンプリング間隔（ゲーム内秒）
+pub const HEATMAP_SAMPLE_INTERVAL_SECS: f32 = 0.5;
+
+const TILE_COUNT: usize = (MAP_WIDTH * MAP_HEIGHT) as usize;
+
+/// 表示・出力できる統計レイヤー
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeatmapLayer {
+    /// Soul がタイルへ入った回数
+    Traffic,
+    /// タイル上にいた Soul の平均ストレス
+    Stress,
+    /// タスクを持たずにタイル上で過ごしたサンプル数
+    Idle,
+    /// 予算切れ・到達不能で拒否された経路探索の目的地
+    PathDenial,
+    /// 運搬の搬出元→搬入先の直線が通過した回数
+    HaulFlow,
+}
+
+impl HeatmapLayer {
+    pub const ALL: [Self; 5] = [
+        Self::Traffic,
+        Self::Stress,
+        Self::Idle,
+        Self::PathDenial,
+        Self::HaulFlow,
+    ];
+
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Traffic => "ui.dev.heatmap.traffic",
+            Self::Stress => "ui.dev.heatmap.stress",
+            Self::Idle => "ui.dev.heatmap.idle",
+            Self::PathDenial => "ui.dev.heatmap.path_denial",
+            Self::HaulFlow => "ui.dev.heatmap.haul_flow",
+        }
+    }
+
+    /// DevPanel の切り替え順。最後のレイヤーの次は非表示。
+    pub fn next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::ALL[0]),
+            Some(layer) => {
+                let index = Self::ALL.iter().position(|&candidate| candidate == layer)?;
+                Self::ALL.get(index + 1).copied()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HeatmapBucket {
+    started_at: f32,
+    traffic: Vec<u32>,
+    stress_sum: Vec<f32>,
+    stress_samples: Vec<u32>,
+    idle: Vec<u32>,
+    path_denials: Vec<u32>,
+    haul_flow: Vec<u32>,
+    flows: HashMap<(GridPos, GridPos), u32>,
+}
+
+impl HeatmapBucket {
+    fn new(started_at: f32) -> Self {
+        Self {
+            started_at,
+            traffic: vec![0; TILE_COUNT],
+            stress_sum: vec![0.0; TILE_COUNT],
+            stress_samples: vec![0; TILE_COUNT],
+            idle: vec![0; TILE_COUNT],
+            path_denials: vec![0; TILE_COUNT],
+            haul_flow: vec![0; TILE_COUNT],
+            flows: HashMap::new(),
+        }
+    }
+}
+
+/// ローリングウィンドウ全体を合算したタイル統計
+#[derive(Clone, Debug, Default)]
+pub struct HeatmapTotals {
+    pub traffic: Vec<u32>,
+    pub stress_sum: Vec<f32>,
+    pub stress_samples: Vec<u32>,
+    pub idle: Vec<u32>,
+    pub path_denials: Vec<u32>,
+    pub haul_flow: Vec<u32>,
+    pub flows: HashMap<(GridPos, GridPos), u32>,
+}
+
+impl HeatmapTotals {
+    /// レイヤーのタイル値。ストレスはサンプル平均、その他は回数。
+    pub fn value(&self, layer: HeatmapLayer, idx: usize) -> f32 {
+        match layer {
+            HeatmapLayer::Traffic => self.traffic[idx] as f32,
+            HeatmapLayer::Stress => self.stress_average(idx).unwrap_or(0.0),
+            HeatmapLayer::Idle => self.idle[idx] as f32,
+            HeatmapLayer::PathDenial => self.path_denials[idx] as f32,
+            HeatmapLayer::HaulFlow => self.haul_flow[idx] as f32,
+        }
+    }
+
+    pub fn stress_average(&self, idx: usize) -> Option<f32> {
+        let samples = self.stress_samples[idx];
+        (samples > 0).then(|| self.stress_sum[idx] / samples as f32)
+    }
+
+    /// 1 タイル 1 行の CSV。どの統計も 0 のタイルは省く。
+    pub fn tile_csv(&self) -> String {
+        let mut csv =
+            String::from("x,y,traffic,stress_avg,stress_samples,idle,path_denials,haul_flow\n");
+        for idx in 0..TILE_COUNT {
+            let counts = [
+                self.traffic[idx],
+                self.stress_samples[idx],
+                self.idle[idx],
+                self.path_denials[idx],
+                self.haul_flow[idx],
+            ];
+            if counts.iter().all(|&count| count == 0) {
+                continue;
+            }
+            let (x, y) = tile_grid(idx);
+            let stress = self
+                .stress_average(idx)
+                .map(|average| format!("{average:.3}"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                csv,
+                "{x},{y},{},{stress},{},{},{},{}",
+                self.traffic[idx],
+                self.stress_samples[idx],
+                self.idle[idx],
+                self.path_denials[idx],
+                self.haul_flow[idx],
+            );
+        }
+        csv
+    }
+
+    /// 運搬経路ごとの CSV。件数の多い順、同数なら座標順。
+    pub fn flow_csv(&self) -> String {
+        let mut flows: Vec<_> = self
+            .flows
+            .iter()
+            .map(|(&key, &count)| (key, count))
+            .collect();
+        flows.sort_by(|(a_key, a_count), (b_key, b_count)| {
+            b_count.cmp(a_count).then(a_key.cmp(b_key))
+        });
+        let mut csv = String::from("source_x,source_y,destination_x,destination_y,count\n");
+        for (((sx, sy), (dx, dy)), count) in flows {
+            let _ = writeln!(csv, "{sx},{sy},{dx},{dy},{count}");
+        }
+        csv
+    }
+}
+
+/// タイル単位の統計をローリングウィンドウで蓄積する
+///
+/// 記録は常に最新バケットへ入り、`advance` で `HEATMAP_BUCKET_SECS` ごとに
+/// 新しいバケットへ切り替える。`HEATMAP_BUCKET_COUNT` を超えた古いバケットは捨てる。
+#[derive(Resource, Debug)]
+pub struct HeatmapAccumulator {
+    buckets: VecDeque<HeatmapBucket>,
+    revision: u64,
+}
+
+impl Default for HeatmapAccumulator {
+    fn default() -> Self {
+        Self {
+            buckets: VecDeque::from([HeatmapBucket::new(0.0)]),
+            revision: 0,
+        }
+    }
+}
+
+impl HeatmapAccumulator {
+    /// ゲーム時刻に合わせて現在バケットを進める。
+    ///
+    /// 時刻が巻き戻った場合（ロード直後など）はウィンドウを作り直す。
+    pub fn advance(&mut self, now: f32) {
+        let current_start = self.current().started_at;
+        if now < current_start {
+            *self = Self {
+                buckets: VecDeque::from([HeatmapBucket::new(now)]),
+                revision: self.revision + 1,
+            };
+            return;
+        }
+        let elapsed_buckets = ((now - current_start) / HEATMAP_BUCKET_SECS).floor() as usize;
+        if elapsed_buckets == 0 {
+            return;
+        }
+        if elapsed_buckets >= HEATMAP_BUCKET_COUNT {
+            self.buckets.clear();
+        }
+        let steps = elapsed_buckets.min(HEATMAP_BUCKET_COUNT);
+        let first_start = current_start + (elapsed_buckets - steps) as f32 * HEATMAP_BUCKET_SECS;
+        for step in 1..=steps {
+            self.buckets.push_back(HeatmapBucket::new(
+                first_start + step as f32 * HEATMAP_BUCKET_SECS,
+            ));
+        }
+        while self.buckets.len() > HEATMAP_BUCKET_COUNT {
+            self.buckets.pop_front();
+        }
+        self.revision += 1;
+    }
+
+    /// 記録内容が変わるたびに増える世代番号。オーバーレイの再描画判定に使う。
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn bucket_count(&self) -> usize {
+        self.buckets.len()
+    }
+
+    pub fn record_traffic(&mut self, grid: GridPos) {
+        self.bump(grid, |bucket, idx| bucket.traffic[idx] += 1);
+    }
+
+    pub fn record_stress(&mut self, grid: GridPos, stress: f32) {
+        self.bump(grid, |bucket, idx| {
+            bucket.stress_sum[idx] += stress;
+            bucket.stress_samples[idx] += 1;
+        });
+    }
+
+    pub fn record_idle(&mut self, grid: GridPos) {
+        self.bump(grid, |bucket, idx| bucket.idle[idx] += 1);
+    }
+
+    pub fn record_path_denial(&mut self, grid: GridPos) {
+        self.bump(grid, |bucket, idx| bucket.path_denials[idx] += 1);
+    }
+
+    /// 運搬 1 件を記録する。経路は搬出元から搬入先への直線で近似する。
+    pub fn record_haul_flow(&mut self, source: GridPos, destination: GridPos) {
+        if tile_index(source).is_none() || tile_index(destination).is_none() {
+            return;
+        }
+        for grid in grid_line(source, destination) {
+            self.bump(grid, |bucket, idx| bucket.haul_flow[idx] += 1);
+        }
+        *self
+            .current_mut()
+            .flows
+            .entry((source, destination))
+            .or_insert(0) += 1;
+    }
+
+    /// ウィンドウ内の全バケットを合算する。
+    pub fn totals(&self) -> HeatmapTotals {
+        let mut totals = HeatmapTotals {
+            traffic: vec![0; TILE_COUNT],
+            stress_sum: vec![0.0; TILE_COUNT],
+            stress_samples: vec![0; TILE_COUNT],
+            idle: vec![0; TILE_COUNT],
+            path_denials: vec![0; TILE_COUNT],
+            haul_flow: vec![0; TILE_COUNT],
+            flows: HashMap::new(),
+        };
+        for bucket in &self.buckets {
+            for idx in 0..TILE_COUNT {
+                totals.traffic[idx] += bucket.traffic[idx];
+                totals.stress_sum[idx] += bucket.stress_sum[idx];
+                totals.stress_samples[idx] += bucket.stress_samples[idx];
+                totals.idle[idx] += bucket.idle[idx];
+                totals.path_denials[idx] += bucket.path_denials[idx];
+                totals.haul_flow[idx] += bucket.haul_flow[idx];
+            }
+            for (&route, &count) in &bucket.flows {
+                *totals.flows.entry(route).or_insert(0) += count;
+            }
+        }
+        totals
+    }
+
+    fn current(&self) -> &HeatmapBucket {
+        self.buckets
+            .back()
+            .expect("heatmap window keeps one bucket")
+    }
+
+    fn current_mut(&mut self) -> &mut HeatmapBucket {
+        self.buckets
+            .back_mut()
+            .expect("heatmap window keeps one bucket")
+    }
+
+    fn bump(&mut self, grid: GridPos, apply: impl FnOnce(&mut HeatmapBucket, usize)) {
+        let Some(idx) = tile_index(grid) else {
+            return;
+        };
+        apply(self.current_mut(), idx);
+        self.revision += 1;
+    }
+}
+
+pub(super) fn tile_index((x, y): GridPos) -> Option<usize> {
+    ((0..MAP_WIDTH).contains(&x) && (0..MAP_HEIGHT).contains(&y))
+        .then(|| (y * MAP_WIDTH + x) as usize)
+}
+
+pub(super) fn tile_grid(idx: usize) -> GridPos {
+    (idx as i32 % MAP_WIDTH, idx as i32 / MAP_WIDTH)
+}
+
+/// 2 点を結ぶ Bresenham 直線上のタイル（両端を含む）
+fn grid_line(from: GridPos, to: GridPos) -> impl Iterator<Item = GridPos> {
+    let (mut x, mut y) = from;
+    let dx = (to.0 - from.0).abs();
+    let dy = -(to.1 - from.1).abs();
+    let sx = (to.0 - from.0).signum();
+    let sy = (to.1 - from.1).signum();
+    let mut error = dx + dy;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let current = (x, y);
+        if current == to {
+            done = true;
+            return Some(current);
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += sy;
+        }
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn old_buckets_fall_out_of_the_rolling_window() {
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.record_traffic((5, 5));
+        heatmap.advance(HEATMAP_BUCKET_SECS);
+        heatmap.record_traffic((5, 5));
+        let idx = tile_index((5, 5)).unwrap();
+        assert_eq!(heatmap.totals().traffic[idx], 2);
+
+        heatmap.advance(HEATMAP_BUCKET_SECS * HEATMAP_BUCKET_COUNT as f32);
+        assert_eq!(heatmap.bucket_count(), HEATMAP_BUCKET_COUNT);
+        assert_eq!(heatmap.totals().traffic[idx], 1);
+
+        heatmap.advance(HEATMAP_BUCKET_SECS * 100.0);
+        assert_eq!(heatmap.totals().traffic[idx], 0);
+    }
+
+    #[test]
+    fn stress_is_averaged_and_csv_skips_empty_tiles() {
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.record_stress((1, 2), 0.2);
+        heatmap.record_stress((1, 2), 0.6);
+        heatmap.record_path_denial((3, 4));
+
+        let totals = heatmap.totals();
+        let idx = tile_index((1, 2)).unwrap();
+        assert!((totals.value(HeatmapLayer::Stress, idx) - 0.4).abs() < 1e-6);
+        assert_eq!(
+            totals.tile_csv(),
+            "x,y,traffic,stress_avg,stress_samples,idle,path_denials,haul_flow\n\
+             1,2,0,0.400,2,0,0,0\n\
+             3,4,0,,0,0,1,0\n"
+        );
+    }
+
+    #[test]
+    fn haul_flow_marks_the_straight_route_and_counts_routes() {
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.record_haul_flow((0, 0), (3, 0));
+        heatmap.record_haul_flow((0, 0), (3, 0));
+        heatmap.record_haul_flow((2, 2), (2, 2));
+
+        let totals = heatmap.totals();
+        for x in 0..=3 {
+            assert_eq!(totals.haul_flow[tile_index((x, 0)).unwrap()], 2);
+        }
+        assert_eq!(totals.haul_flow[tile_index((0, 1)).unwrap()], 0);
+        assert_eq!(
+            totals.flow_csv(),
+            "source_x,source_y,destination_x,destination_y,count\n0,0,3,0,2\n2,2,2,2,1\n"
+        );
+    }
+
+    #[test]
+    fn time_going_backwards_restarts_the_window() {
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.advance(100.0);
+        heatmap.record_idle((7, 7));
+        heatmap.advance(5.0);
+
+        assert_eq!(heatmap.bucket_count(), 1);
+        assert_eq!(heatmap.totals().idle[tile_index((7, 7)).unwrap()], 0);
+    }
+
+    #[test]
+    fn layer_cycle_ends_with_hidden() {
+        let mut layer = None;
+        let mut seen = Vec::new();
+        for _ in 0..=HeatmapLayer::ALL.len() {
+            layer = HeatmapLayer::next(layer);
+            seen.push(layer);
+        }
+        assert_eq!(seen.first(), Some(&Some(HeatmapLayer::Traffic)));
+        assert_eq!(seen.last(), Some(&None));
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::time::Real;
+use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH, TILE_SIZE, Z_HEATMAP_OVERLAY};
+
+use super::model::{HeatmapAccumulator, HeatmapLayer, HeatmapTotals};
+
+/// 表示中レイヤーの再描画間隔（実時間）
+const HEATMAP_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 表示中のヒートマップレイヤー。`None` なら非表示。
+#[derive(Resource, Debug, Default)]
+pub struct HeatmapOverlay {
+    pub active: Option<HeatmapLayer>,
+}
+
+impl HeatmapOverlay {
+    /// 非表示 → 各レイヤー → 非表示 の順に切り替える。
+    pub fn cycle(&mut self) {
+        self.active = HeatmapLayer::next(self.active);
+    }
+}
+
+/// ワールド全体を覆うヒートマップ Sprite
+#[derive(Component)]
+pub struct HeatmapOverlaySprite;
+
+#[derive(Default)]
+pub struct HeatmapOverlayCache {
+    image: Option<Handle<Image>>,
+    drawn: Option<(HeatmapLayer, u64)>,
+    next_redraw: Duration,
+}
+
+/// 値 0..1 を黄→赤のグラデーションへ写す。0 は透明。
+fn heat_color(t: f32) -> [u8; 4] {
+    if t <= 0.0 {
+        return [0, 0, 0, 0];
+    }
+    let t = t.min(1.0);
+    let low = Vec3::new(1.0, 0.9, 0.2);
+    let high = Vec3::new(0.9, 0.1, 0.1);
+    let rgb = low.lerp(high, t) * 255.0;
+    let alpha = (0.25 + 0.45 * t) * 255.0;
+    [
+        rgb.x.round() as u8,
+        rgb.y.round() as u8,
+        rgb.z.round() as u8,
+        alpha.round() as u8,
+    ]
+}
+
+/// レイヤーを RGBA8（上端の行が最大 y）へ描く。
+///
+/// ストレスは 0..1 の絶対値、その他はウィンドウ内の最大値で正規化する。
+pub fn heatmap_rgba(totals: &HeatmapTotals, layer: HeatmapLayer) -> Vec<u8> {
+    let width = MAP_WIDTH as usize;
+    let tile_count = width * MAP_HEIGHT as usize;
+    let values: Vec<f32> = (0..tile_count)
+        .map(|idx| totals.value(layer, idx))
+        .collect();
+    let scale = match layer {
+        HeatmapLayer::Stress => 1.0,
+        _ => values.iter().copied().fold(0.0, f32::max),
+    };
+    let mut rgba = Vec::with_capacity(tile_count * 4);
+    for row in values.chunks_exact(width).rev() {
+        for &value in row {
+            let t = if scale > 0.0 { value / scale } else { 0.0 };
+            rgba.extend(heat_color(t));
+        }
+    }
+    rgba
+}
+
+fn new_heatmap_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: MAP_WIDTH as u32,
+            height: MAP_HEIGHT as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// 選択中レイヤーをワールド上の 1 枚の Sprite として描く。
+///
+/// レイヤー切り替え時は即座に、それ以外は蓄積に変化があれば
+/// `HEATMAP_REDRAW_INTERVAL` ごとに描き直す。
+pub fn sync_heatmap_overlay_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    overlay: Res<HeatmapOverlay>,
+    heatmap: Res<HeatmapAccumulator>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut q_sprite: Query<&mut Visibility, With<HeatmapOverlaySprite>>,
+    mut cache: Local<HeatmapOverlayCache>,
+) {
+    let Some(layer) = overlay.active else {
+        cache.drawn = None;
+        for mut visibility in &mut q_sprite {
+            *visibility = Visibility::Hidden;
+        }
+        return;
+    };
+    let Some(mut images) = images else {
+        return;
+    };
+
+    let now = time.elapsed();
+    let layer_changed = cache.drawn.map(|(drawn, _)| drawn) != Some(layer);
+    let stale = cache.drawn.map(|(_, revision)| revision) != Some(heatmap.revision());
+    let redraw_due = stale && now >= cache.next_redraw;
+    if !layer_changed && !redraw_due {
+        return;
+    }
+    cache.next_redraw = now + HEATMAP_REDRAW_INTERVAL;
+    cache.drawn = Some((layer, heatmap.revision()));
+
+    let handle = cache
+        .image
+        .get_or_insert_with(|| images.add(new_heatmap_image()))
+        .clone();
+    if let Some(mut image) = images.get_mut(&handle) {
+        image.data = Some(heatmap_rgba(&heatmap.totals(), layer));
+    }
+
+    if q_sprite.is_empty() {
+        commands.spawn((
+            HeatmapOverlaySprite,
+            Sprite {
+                image: handle,
+                custom_size: Some(Vec2::new(MAP_WIDTH as f32, MAP_HEIGHT as f32) * TILE_SIZE),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, Z_HEATMAP_OVERLAY),
+            Name::new("HeatmapOverlay"),
+        ));
+        return;
+    }
+    for mut visibility in &mut q_sprite {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_are_normalized_and_rows_start_at_the_top() {
+        let mut heatmap = HeatmapAccumulator::default();
+        heatmap.record_traffic((0, 0));
+        heatmap.record_traffic((0, 0));
+        heatmap.record_traffic((1, 0));
+
+        let rgba = heatmap_rgba(&heatmap.totals(), HeatmapLayer::Traffic);
+        let bottom_row = (MAP_HEIGHT as usize - 1) * MAP_WIDTH as usize * 4;
+        assert_eq!(&rgba[bottom_row..bottom_row + 4], &heat_color(1.0));
+        assert_eq!(&rgba[bottom_row + 4..bottom_row + 8], &heat_color(0.5));
+        assert_eq!(&rgba[0..4], &[0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::logistics::WheelbarrowDestination;
+use hw_core::soul::{DamnedSoul, IdleBehavior, IdleState};
+use hw_core::world::GridPos;
+use hw_core::{EpochLocal, GameTime, WorldEpoch};
+use hw_jobs::AssignedTask;
+use hw_world::{RuntimePathSearchBudget, world_to_grid};
+
+use super::model::{HEATMAP_SAMPLE_INTERVAL_SECS, HeatmapAccumulator};
+
+/// 運搬 1 件の識別キー（搬出物または手押し車、搬入先）
+type HaulKey = (Entity, Entity);
+
+#[derive(Default)]
+pub struct HeatmapSamplerState {
+    next_sample_at: f32,
+    last_tiles: HashMap<Entity, GridPos>,
+    recorded_hauls: HashMap<Entity, HaulKey>,
+}
+
+enum HaulSource {
+    Item(Entity),
+    Position(Vec2),
+}
+
+/// 運搬系タスクの搬出元と搬入先。搬出元が Entity の場合は割り当て直後の位置を使う。
+fn haul_route(task: &AssignedTask) -> Option<(HaulKey, HaulSource)> {
+    match task {
+        AssignedTask::Haul(data) => {
+            Some(((data.item, data.stockpile), HaulSource::Item(data.item)))
+        }
+        AssignedTask::HaulToBlueprint(data) => {
+            Some(((data.item, data.blueprint), HaulSource::Item(data.item)))
+        }
+        AssignedTask::HaulToMixer(data) => {
+            Some(((data.item, data.mixer), HaulSource::Item(data.item)))
+        }
+        AssignedTask::HaulWithWheelbarrow(data) => {
+            let destination = match data.destination {
+                WheelbarrowDestination::Stockpile(entity)
+                | WheelbarrowDestination::Blueprint(entity)
+                | WheelbarrowDestination::Mixer { entity, .. } => entity,
+            };
+            Some((
+                (data.wheelbarrow, destination),
+                HaulSource::Position(data.source_pos),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// タスクを持たず、回復や脱走以外で時間を潰している状態
+fn is_idle(task: &AssignedTask, idle: &IdleState) -> bool {
+    matches!(task, AssignedTask::None)
+        && matches!(
+            idle.behavior,
+            IdleBehavior::Wandering
+                | IdleBehavior::Sitting
+                | IdleBehavior::Gathering
+                | IdleBehavior::ExhaustedGathering
+        )
+}
+
+#[derive(SystemParam)]
+pub struct HeatmapSamplingParams<'w, 's> {
+    game_time: Res<'w, GameTime>,
+    world_epoch: Option<Res<'w, WorldEpoch>>,
+    budget: ResMut<'w, RuntimePathSearchBudget>,
+    heatmap: ResMut<'w, HeatmapAccumulator>,
+    q_souls: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Transform,
+            &'static DamnedSoul,
+            &'static AssignedTask,
+            &'static IdleState,
+        ),
+    >,
+    q_positions: Query<'w, 's, &'static GlobalTransform>,
+    state: Local<'s, EpochLocal<HeatmapSamplerState>>,
+}
+
+/// Soul の移動・ストレス・待機、経路探索の拒否、運搬をヒートマップへ記録する。
+///
+/// 足跡と探索拒否は毎フレーム、ストレス・待機・運搬は `HEATMAP_SAMPLE_INTERVAL_SECS` ごとに記録する。
+pub fn heatmap_sampling_system(params: HeatmapSamplingParams) {
+    let HeatmapSamplingParams {
+        game_time,
+        world_epoch,
+        mut budget,
+        mut heatmap,
+        q_souls,
+        q_positions,
+        mut state,
+    } = params;
+    let world_epoch = world_epoch.map_or_else(WorldEpoch::default, |epoch| *epoch);
+    let state = state.get_mut(world_epoch);
+
+    for goal in budget.drain_denied_goals() {
+        heatmap.record_path_denial(goal);
+    }
+
+    let mut present = HashSet::new();
+    for (entity, transform, _, _, _) in &q_souls {
+        let grid = world_to_grid(transform.translation.truncate());
+        present.insert(entity);
+        if state.last_tiles.insert(entity, grid) != Some(grid) {
+            heatmap.record_traffic(grid);
+        }
+    }
+    state
+        .last_tiles
+        .retain(|entity, _| present.contains(entity));
+
+    let now = game_time.seconds;
+    if now < state.next_sample_at {
+        return;
+    }
+    state.next_sample_at = now + HEATMAP_SAMPLE_INTERVAL_SECS;
+    heatmap.advance(now);
+
+    for (entity, transform, soul, task, idle) in &q_souls {
+        let grid = world_to_grid(transform.translation.truncate());
+        heatmap.record_stress(grid, soul.stress);
+        if is_idle(task, idle) {
+            heatmap.record_idle(grid);
+        }
+
+        let Some((key, source)) = haul_route(task) else {
+            state.recorded_hauls.remove(&entity);
+            continue;
+        };
+        if state.recorded_hauls.get(&entity) == Some(&key) {
+            continue;
+        }
+        let source = match source {
+            HaulSource::Item(item) => q_positions
+                .get(item)
+                .map(|position| position.translation().truncate()),
+            HaulSource::Position(position) => Ok(position),
+        };
+        let (Ok(source), Ok(destination)) = (source, q_positions.get(key.1)) else {
+            continue;
+        };
+        state.recorded_hauls.insert(entity, key);
+        heatmap.record_haul_flow(
+            world_to_grid(source),
+            world_to_grid(destination.translation().truncate()),
+        );
+    }
+    state
+        .recorded_hauls
+        .retain(|entity, _| present.contains(entity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::analytics::heatmap::model::{HeatmapLayer, tile_index};
+    use crate::test_support::minimal_app;
+    use hw_jobs::{HaulData, HaulPhase};
+
+    fn sampling_app() -> App {
+        let mut app = minimal_app();
+        app.init_resource::<GameTime>()
+            .init_resource::<RuntimePathSearchBudget>()
+            .init_resource::<HeatmapAccumulator>()
+            .add_systems(Update, heatmap_sampling_system);
+        app
+    }
+
+    fn value_at(app: &App, layer: HeatmapLayer, world: Vec2) -> f32 {
+        let idx = tile_index(world_to_grid(world)).unwrap();
+        app.world()
+            .resource::<HeatmapAccumulator>()
+            .totals()
+            .value(layer, idx)
+    }
+
+    #[test]
+    fn soul_footsteps_stress_and_idle_are_sampled_per_tile() {
+        let mut app = sampling_app();
+        let soul = app
+            .world_mut()
+            .spawn((
+                DamnedSoul {
+                    stress: 0.5,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                AssignedTask::None,
+                IdleState::default(),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        assert_eq!(value_at(&app, HeatmapLayer::Traffic, Vec2::ZERO), 1.0);
+        assert_eq!(value_at(&app, HeatmapLayer::Idle, Vec2::ZERO), 1.0);
+        assert!((value_at(&app, HeatmapLayer::Stress, Vec2::ZERO) - 0.5).abs() < 1e-6);
+
+        let moved = Vec2::new(64.0, 0.0);
+        app.world_mut()
+            .get_mut::<Transform>(soul)
+            .unwrap()
+            .translation = moved.extend(0.0);
+        app.update();
+        assert_eq!(value_at(&app, HeatmapLayer::Traffic, moved), 1.0);
+    }
+
+    #[test]
+    fn denied_path_goals_and_hauls_are_recorded_once() {
+        let mut app = sampling_app();
+        app.world_mut()
+            .resource_mut::<RuntimePathSearchBudget>()
+            .record_denied_goal((10, 10));
+        let item_pos = Vec2::new(-96.0, 0.0);
+        let stockpile_pos = Vec2::new(96.0, 0.0);
+        let item = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(item_pos.extend(0.0)))
+            .id();
+        let stockpile = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(stockpile_pos.extend(0.0)))
+            .id();
+        app.world_mut().spawn((
+            DamnedSoul::default(),
+            Transform::default(),
+            AssignedTask::Haul(HaulData {
+                item,
+                stockpile,
+                phase: HaulPhase::GoingToItem,
+            }),
+            IdleState::default(),
+        ));
+
+        app.update();
+        app.world_mut().resource_mut::<GameTime>().seconds = HEATMAP_SAMPLE_INTERVAL_SECS;
+        app.update();
+
+        let totals = app.world().resource::<HeatmapAccumulator>().totals();
+        assert_eq!(totals.path_denials[tile_index((10, 10)).unwrap()], 1);
+        assert_eq!(
+            totals
+                .flows
+                .get(&(world_to_grid(item_pos), world_to_grid(stockpile_pos))),
+            Some(&1)
+        );
+        assert_eq!(value_at(&app, HeatmapLayer::Idle, Vec2::ZERO), 0.0);
+    }
+}
@@ -0,0 +1,36 @@
+//! プレイ内容の分析機能
+//!
+//! 現在はタイル単位のヒートマップのみを提供する。蓄積はセーブに含めず、ロード時に破棄する。
+
+pub mod heatmap;
+
+use bevy::prelude::*;
+
+use crate::systems::GameSystemSet;
+use heatmap::{HeatmapAccumulator, HeatmapOverlay};
+
+fn reset_analytics_for_world_replace(world: &mut World) {
+    world.insert_resource(HeatmapAccumulator::default());
+}
+
+pub struct AnalyticsPlugin;
+
+impl Plugin for AnalyticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HeatmapAccumulator>()
+            .init_resource::<HeatmapOverlay>()
+            .add_systems(
+                Update,
+                heatmap::heatmap_sampling_system.in_set(GameSystemSet::Logic),
+            )
+            .add_systems(
+                Update,
+                heatmap::sync_heatmap_overlay_system.in_set(GameSystemSet::Visual),
+            );
+        crate::systems::save::register_load_reset_hook(
+            app,
+            "analytics-heatmap",
+            reset_analytics_for_world_replace,
+        );
+    }
+}
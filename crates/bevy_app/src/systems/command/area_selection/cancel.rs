@@ -142,6 +142,7 @@ mod tests {
                 icon_stasis_mud_small: default(),
             })
             .init_resource::<RuntimePathSearchBudget>()
+            .init_resource::<hw_core::edicts::DreamEdicts>()
             .init_resource::<SharedResourceCache>()
             .init_resource::<Receipts>();
         #[cfg(feature = "profiling")]
@@ -44,7 +44,7 @@ pub fn task_area_edit_cursor_system(
         world_cursor_pos(&state.q_window, &state.q_camera),
     ) {
         if let Ok(area) = state.q_task_areas.get(fam_entity) {
-            if let Some(operation) = detect_area_edit_operation(area, world_pos) {
+            if let Some((_, operation)) = detect_area_edit_operation(area, world_pos) {
                 cursor_icon_for_operation(operation, false)
             } else {
                 CursorIcon::System(SystemCursorIcon::Default)
@@ -1,5 +1,6 @@
 use crate::systems::command::{TaskArea, TaskMode};
 use bevy::prelude::*;
+use hw_core::area::AreaBounds;
 use hw_world::zones::Site;
 
 const AREA_CONTAINS_MARGIN: f32 = 0.1;
@@ -24,22 +25,29 @@ pub fn get_indicator_color(mode: TaskMode, is_valid: bool) -> LinearRgba {
         TaskMode::WallPlace(_) => LinearRgba::from(Color::srgba(1.0, 1.0, 1.0, 0.4)),
         TaskMode::DreamPlanting(_) => LinearRgba::from(Color::srgba(0.5, 0.5, 1.0, 0.5)), // Dream は青紫
         TaskMode::StockpilePolicyEdit(_) => LinearRgba::from(Color::srgba(0.25, 0.85, 1.0, 0.5)),
+        TaskMode::LayoutExport(_) => LinearRgba::from(Color::srgba(0.9, 0.75, 0.3, 0.5)),
         _ => LinearRgba::from(Color::srgba(0.2, 1.0, 0.2, 0.5)),
     }
 }
 
+/// 部分矩形ごとに、その中心を含む Site の範囲へ切り詰める
 pub(super) fn clamp_area_to_site(area: &TaskArea, q_sites: &Query<&Site>) -> TaskArea {
-    let Some(site) = q_sites.iter().find(|site| site.contains(area.center())) else {
-        return area.clone();
+    TaskArea::from_rects(area.rects().map(|rect| clamp_rect_to_site(rect, q_sites)))
+        .unwrap_or_else(|| area.clone())
+}
+
+fn clamp_rect_to_site(rect: &AreaBounds, q_sites: &Query<&Site>) -> AreaBounds {
+    let Some(site) = q_sites.iter().find(|site| site.contains(rect.center())) else {
+        return rect.clone();
     };
 
-    let min = Vec2::new(area.min().x.max(site.min.x), area.min().y.max(site.min.y));
-    let max = Vec2::new(area.max().x.min(site.max.x), area.max().y.min(site.max.y));
+    let min = Vec2::new(rect.min.x.max(site.min.x), rect.min.y.max(site.min.y));
+    let max = Vec2::new(rect.max.x.min(site.max.x), rect.max.y.min(site.max.y));
     if min.x > max.x || min.y > max.y {
-        return area.clone();
+        return rect.clone();
     }
 
-    TaskArea::from_points(min, max)
+    AreaBounds::new(min, max)
 }
 
 pub(super) fn in_selection_area(area: &TaskArea, pos: Vec2) -> bool {
@@ -89,6 +89,14 @@ pub fn task_area_selection_system(
         return;
     }
 
+    // Layout blueprint modes own their pointer gestures in interface::selection.
+    if matches!(
+        state.task_context.0,
+        TaskMode::LayoutExport(_) | TaskMode::LayoutImport
+    ) {
+        return;
+    }
+
     if state.task_context.0 == TaskMode::None {
         state.area_edit_session.active_drag = None;
         return;
@@ -113,10 +121,12 @@ pub fn task_area_selection_system(
         return;
     }
 
+    // Ctrl は部分矩形の追加・削除ジェスチャなので、既存矩形のドラッグ編集を始めない
+    let sub_rect_gesture = input.resolved_frame.modifiers.ctrl;
     if input.buttons.just_pressed(MouseButton::Left)
         && handle_left_just_pressed_input(
             &mut state.task_context,
-            state.selected.0,
+            state.selected.0.filter(|_| !sub_rect_gesture),
             &queries.q_familiar_areas,
             &mut queries.q_familiars,
             &input.q_window,
@@ -141,6 +151,7 @@ pub fn task_area_selection_system(
             selected_entity: state.selected.0,
             world_pos,
             shift_pressed: input.resolved_frame.modifiers.shift,
+            sub_rect_gesture,
             next_play_mode: &mut state.next_play_mode,
             area_edit_session: &mut state.area_edit_session,
             area_edit_history: &mut state.area_edit_history,
@@ -27,7 +27,7 @@ pub(super) fn try_start_direct_edit_drag(
     let Ok(existing_area) = q_familiar_areas.get(fam_entity) else {
         return false;
     };
-    let Some(operation) = detect_area_edit_operation(existing_area, world_pos) else {
+    let Some((rect_index, operation)) = detect_area_edit_operation(existing_area, world_pos) else {
         return false;
     };
     let Ok((active_command, destination)) = q_familiar_state.get_mut(fam_entity) else {
@@ -36,6 +36,7 @@ pub(super) fn try_start_direct_edit_drag(
 
     area_edit_session.active_drag = Some(AreaEditDrag {
         familiar_entity: fam_entity,
+        rect_index,
         operation,
         original_area: existing_area.clone(),
         original_destination: destination.0,
@@ -19,6 +19,7 @@ pub(super) struct AreaReleaseCtx<'a> {
     pub(super) world_pos: Vec2,
     pub(super) start_pos: Vec2,
     pub(super) shift_pressed: bool,
+    pub(super) sub_rect_gesture: bool,
     pub(super) next_play_mode: &'a mut NextState<PlayMode>,
     pub(super) area_edit_history: &'a mut AreaEditHistory,
 }
@@ -35,15 +36,39 @@ pub(super) fn handle_release_area_selection(
     let end_pos = WorldMap::snap_to_grid_edge(ctx.world_pos);
 
     if ctx.start_pos.distance(end_pos) < 0.1 {
+        if ctx.sub_rect_gesture
+            && let Some(fam_entity) = ctx.selected_entity
+            && let Ok(before_area) = q_familiar_areas.get(fam_entity)
+            && let Some(removed) = before_area
+                .rect_index_at(ctx.world_pos)
+                .and_then(|index| before_area.with_rect_removed(index))
+        {
+            apply_area_and_record_history(
+                fam_entity,
+                &removed,
+                Some(before_area.clone()),
+                commands,
+                q_familiars,
+                ctx.area_edit_history,
+                q_sites,
+            );
+            despawn_indicators(indicator_entities, commands);
+            ctx.task_context.0 = TaskMode::AreaSelection(None);
+            return;
+        }
         ctx.task_context.0 = TaskMode::None;
         ctx.next_play_mode.set(PlayMode::Normal);
         despawn_indicators(indicator_entities, commands);
         return;
     }
 
-    let new_area = TaskArea::from_points(ctx.start_pos, end_pos);
+    let dragged = TaskArea::from_points(ctx.start_pos, end_pos);
     if let Some(fam_entity) = ctx.selected_entity {
         let before_area = q_familiar_areas.get(fam_entity).ok().cloned();
+        let new_area = match before_area.as_ref() {
+            Some(existing) if ctx.sub_rect_gesture => existing.with_rect_added(dragged.bounds()),
+            _ => dragged,
+        };
         apply_area_and_record_history(
             fam_entity,
             &new_area,
@@ -58,7 +83,7 @@ pub(super) fn handle_release_area_selection(
 
     despawn_indicators(indicator_entities, commands);
 
-    if should_exit_after_apply(ctx.shift_pressed) {
+    if should_exit_after_apply(ctx.shift_pressed) && !ctx.sub_rect_gesture {
         ctx.task_context.0 = TaskMode::None;
         ctx.next_play_mode.set(PlayMode::Normal);
     } else {
@@ -26,6 +26,8 @@ pub(super) struct ReleaseCtx<'a> {
     pub(super) selected_entity: Option<Entity>,
     pub(super) world_pos: Vec2,
     pub(super) shift_pressed: bool,
+    /// Ctrl 押下中。AreaSelection では部分矩形の追加（ドラッグ）・削除（クリック）になる
+    pub(super) sub_rect_gesture: bool,
     pub(super) next_play_mode: &'a mut NextState<PlayMode>,
     pub(super) area_edit_session: &'a mut AreaEditSession,
     pub(super) area_edit_history: &'a mut AreaEditHistory,
@@ -57,6 +59,7 @@ pub(super) fn handle_left_just_released_input(
                 world_pos: ctx.world_pos,
                 start_pos,
                 shift_pressed: ctx.shift_pressed,
+                sub_rect_gesture: ctx.sub_rect_gesture,
                 next_play_mode: ctx.next_play_mode,
                 area_edit_history: ctx.area_edit_history,
             };
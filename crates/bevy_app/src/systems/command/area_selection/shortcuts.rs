@@ -4,7 +4,7 @@ use super::{AreaEditClipboard, AreaEditHistory, AreaEditPresets};
 use crate::entities::damned_soul::Destination;
 use crate::entities::familiar::{ActiveCommand, Familiar};
 use crate::input_actions::{InputAction, ResolvedInputFrame};
-use crate::interface::selection::SelectedEntity;
+use crate::interface::selection::SelectionWriter;
 use crate::systems::command::TaskArea;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
@@ -13,7 +13,7 @@ use hw_world::zones::Site;
 #[derive(SystemParam)]
 pub struct ShortcutResources<'w> {
     resolved_frame: Res<'w, ResolvedInputFrame>,
-    selected_entity: ResMut<'w, SelectedEntity>,
+    selection: SelectionWriter<'w>,
     area_edit_history: ResMut<'w, AreaEditHistory>,
     area_edit_clipboard: ResMut<'w, AreaEditClipboard>,
     area_edit_presets: ResMut<'w, AreaEditPresets>,
@@ -44,7 +44,7 @@ pub fn task_area_edit_history_shortcuts_system(
     };
 
     if let Some(slot) = load_preset_slot(action) {
-        let Some(selected) = res.selected_entity.0 else {
+        let Some(selected) = res.selection.primary() else {
             return;
         };
         if queries.q_familiar_exists.get(selected).is_err() {
@@ -78,7 +78,7 @@ pub fn task_area_edit_history_shortcuts_system(
     }
 
     if let Some(slot) = save_preset_slot(action) {
-        if let Some(selected) = res.selected_entity.0
+        if let Some(selected) = res.selection.primary()
             && queries.q_familiar_exists.get(selected).is_ok()
             && let Ok(area) = queries.q_task_areas.get(selected)
         {
@@ -88,7 +88,7 @@ pub fn task_area_edit_history_shortcuts_system(
     }
 
     if action == InputAction::AreaCopy {
-        if let Some(selected) = res.selected_entity.0
+        if let Some(selected) = res.selection.primary()
             && queries.q_familiar_exists.get(selected).is_ok()
         {
             res.area_edit_clipboard.area = queries.q_task_areas.get(selected).ok().cloned();
@@ -97,7 +97,7 @@ pub fn task_area_edit_history_shortcuts_system(
     }
 
     if action == InputAction::AreaPaste {
-        let Some(selected) = res.selected_entity.0 else {
+        let Some(selected) = res.selection.primary() else {
             return;
         };
         if queries.q_familiar_exists.get(selected).is_err() {
@@ -130,7 +130,7 @@ pub fn task_area_edit_history_shortcuts_system(
                 &mut commands,
                 &mut queries.q_familiars,
             );
-            res.selected_entity.0 = Some(familiar_entity);
+            res.selection.select(Some(familiar_entity));
             res.area_edit_history.undo_stack.push(entry);
         }
         return;
@@ -146,7 +146,7 @@ pub fn task_area_edit_history_shortcuts_system(
             &mut commands,
             &mut queries.q_familiars,
         );
-        res.selected_entity.0 = Some(familiar_entity);
+        res.selection.select(Some(familiar_entity));
         res.area_edit_history.redo_stack.push(entry);
     }
 }
@@ -189,11 +189,13 @@ fn save_preset_slot(action: InputAction) -> Option<usize> {
 mod tests {
     use super::*;
     use crate::input_actions::{InputModifiers, ResolvedInputFrame};
+    use crate::interface::selection::{SelectedEntities, SelectedEntity};
     use crate::test_support::minimal_app;
 
     fn shortcut_app(action: InputAction) -> (App, Entity) {
         let mut app = minimal_app();
         app.init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
             .init_resource::<ResolvedInputFrame>()
             .init_resource::<AreaEditHistory>()
             .init_resource::<AreaEditClipboard>()
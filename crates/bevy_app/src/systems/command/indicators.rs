@@ -8,6 +8,7 @@ use crate::interface::selection::SelectedEntity;
 use crate::systems::jobs::Designation;
 use crate::systems::visual::task_area_visual::{TaskAreaMaterial, TaskAreaVisual};
 use bevy::prelude::*;
+use hw_core::area::AreaBounds;
 use hw_core::constants::TILE_SIZE;
 use std::collections::HashSet;
 
@@ -17,6 +18,7 @@ type TaskAreaIndicatorQuery<'w, 's> = Query<
     (
         Entity,
         &'static TaskAreaIndicator,
+        &'static TaskAreaVisual,
         &'static mut Transform,
         &'static mut Visibility,
         &'static MeshMaterial2d<TaskAreaMaterial>,
@@ -24,6 +26,18 @@ type TaskAreaIndicatorQuery<'w, 's> = Query<
     (Without<Familiar>, With<TaskAreaVisual>),
 >;
 
+/// 使い魔の色インデックスに対応する枠色
+fn task_area_color(familiar: &Familiar) -> LinearRgba {
+    let palette = [
+        LinearRgba::from(Color::srgba(0.7, 0.3, 1.0, 1.0)), // Purple (鮮明化)
+        LinearRgba::from(Color::srgba(1.0, 0.7, 0.0, 1.0)), // Yellow-Orange (赤と区別)
+        LinearRgba::from(Color::srgba(0.1, 1.0, 0.4, 1.0)), // Toxic Green
+        LinearRgba::from(Color::srgba(1.0, 0.0, 0.1, 1.0)), // Red
+    ];
+    palette[familiar.color_index as usize % palette.len()]
+}
+
+/// 部分矩形ごとに 1 枚の枠メッシュを同期する。部分矩形が減ったら余りを消す。
 pub fn task_area_indicator_system(
     q_familiars: Query<(Entity, Ref<TaskArea>, &Familiar), With<Familiar>>,
     mut q_indicators: TaskAreaIndicatorQuery,
@@ -31,69 +45,89 @@ pub fn task_area_indicator_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<TaskAreaMaterial>>,
 ) {
-    let mut indexed_owners = HashSet::new();
-    for (indicator_entity, indicator, mut transform, mut visibility, material_handle) in
+    let mut indexed_rects = HashSet::new();
+    for (indicator_entity, indicator, visual, mut transform, mut visibility, material_handle) in
         q_indicators.iter_mut()
     {
-        if let Ok((_, task_area, _)) = q_familiars.get(indicator.0) {
-            indexed_owners.insert(indicator.0);
-            if task_area.is_changed() {
-                let area = task_area.as_ref();
-                let translation = area.center().extend(0.2);
-                let scale = area.size().extend(1.0);
-                if transform.translation != translation {
-                    transform.translation = translation;
-                }
-                if transform.scale != scale {
-                    transform.scale = scale;
-                }
-                if *visibility != Visibility::Visible {
-                    *visibility = Visibility::Visible;
-                }
-                if let Some(mut material) = materials.get_mut(&material_handle.0)
-                    && material.size != area.size()
-                {
-                    material.size = area.size();
-                }
-            }
-        } else {
+        let Ok((_, task_area, _)) = q_familiars.get(indicator.0) else {
             commands.entity(indicator_entity).try_despawn();
+            continue;
+        };
+        let Some(rect) = task_area.rect(visual.rect_index) else {
+            commands.entity(indicator_entity).try_despawn();
+            continue;
+        };
+        indexed_rects.insert((indicator.0, visual.rect_index));
+        if !task_area.is_changed() {
+            continue;
+        }
+        let translation = rect.center().extend(0.2);
+        let scale = rect.size().extend(1.0);
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+        if *visibility != Visibility::Visible {
+            *visibility = Visibility::Visible;
+        }
+        if let Some(mut material) = materials.get_mut(&material_handle.0)
+            && material.size != rect.size()
+        {
+            material.size = rect.size();
         }
     }
 
-    // The first frame of a TaskArea is the only time a missing indicator must
-    // be created. Existing indicators are owner-linked above, so this avoids
-    // the former Familiar × indicator scan every visual frame.
+    // Missing indicators can only appear on a frame where the TaskArea was
+    // added or gained a sub-rect. Existing indicators are owner-linked above,
+    // so this avoids the former Familiar × indicator scan every visual frame.
     for (fam_entity, task_area, familiar_comp) in q_familiars.iter() {
-        if task_area.is_added() && !indexed_owners.contains(&fam_entity) {
-            // 使い魔のコンポーネントに保持されている色インデックスを使用
-            let palette = [
-                LinearRgba::from(Color::srgba(0.7, 0.3, 1.0, 1.0)), // Purple (鮮明化)
-                LinearRgba::from(Color::srgba(1.0, 0.7, 0.0, 1.0)), // Yellow-Orange (赤と区別)
-                LinearRgba::from(Color::srgba(0.1, 1.0, 0.4, 1.0)), // Toxic Green
-                LinearRgba::from(Color::srgba(1.0, 0.0, 0.1, 1.0)), // Red
-            ];
-            let color = palette[familiar_comp.color_index as usize % palette.len()];
-
+        if !task_area.is_changed() {
+            continue;
+        }
+        for (rect_index, rect) in task_area.rects().enumerate() {
+            if indexed_rects.contains(&(fam_entity, rect_index)) {
+                continue;
+            }
             commands.spawn((
                 TaskAreaIndicator(fam_entity),
                 TaskAreaVisual {
                     familiar: fam_entity,
+                    rect_index,
                 },
                 Mesh2d(meshes.add(Rectangle::default().mesh())),
                 MeshMaterial2d(materials.add(TaskAreaMaterial {
-                    color,
-                    size: task_area.as_ref().size(),
+                    color: task_area_color(familiar_comp),
+                    size: rect.size(),
                     state: 0,
                 })),
-                Transform::from_translation(task_area.as_ref().center().extend(0.2))
-                    .with_scale(task_area.as_ref().size().extend(1.0)),
+                Transform::from_translation(rect.center().extend(0.2))
+                    .with_scale(rect.size().extend(1.0)),
                 Visibility::Visible,
             ));
         }
     }
 }
 
+fn area_edit_handle_positions(rect: &AreaBounds) -> [(AreaEditHandleKind, Vec2); 9] {
+    let min = rect.min;
+    let max = rect.max;
+    let mid_x = (min.x + max.x) * 0.5;
+    let mid_y = (min.y + max.y) * 0.5;
+    [
+        (AreaEditHandleKind::TopLeft, Vec2::new(min.x, max.y)),
+        (AreaEditHandleKind::Top, Vec2::new(mid_x, max.y)),
+        (AreaEditHandleKind::TopRight, Vec2::new(max.x, max.y)),
+        (AreaEditHandleKind::Right, Vec2::new(max.x, mid_y)),
+        (AreaEditHandleKind::BottomRight, Vec2::new(max.x, min.y)),
+        (AreaEditHandleKind::Bottom, Vec2::new(mid_x, min.y)),
+        (AreaEditHandleKind::BottomLeft, Vec2::new(min.x, min.y)),
+        (AreaEditHandleKind::Left, Vec2::new(min.x, mid_y)),
+        (AreaEditHandleKind::Center, Vec2::new(mid_x, mid_y)),
+    ]
+}
+
 pub fn area_edit_handles_visual_system(
     task_context: Res<TaskContext>,
     selected: Res<SelectedEntity>,
@@ -121,48 +155,46 @@ pub fn area_edit_handles_visual_system(
         return;
     };
 
-    let min = area.min();
-    let max = area.max();
-    let mid_x = (min.x + max.x) * 0.5;
-    let mid_y = (min.y + max.y) * 0.5;
     let handle_size = (TILE_SIZE * 0.22).max(5.0);
+    let handles: Vec<(usize, AreaEditHandleKind, Vec2)> = area
+        .rects()
+        .enumerate()
+        .flat_map(|(rect_index, rect)| {
+            area_edit_handle_positions(rect)
+                .into_iter()
+                .map(move |(kind, pos)| (rect_index, kind, pos))
+        })
+        .collect();
 
-    let handles = [
-        (AreaEditHandleKind::TopLeft, Vec2::new(min.x, max.y)),
-        (AreaEditHandleKind::Top, Vec2::new(mid_x, max.y)),
-        (AreaEditHandleKind::TopRight, Vec2::new(max.x, max.y)),
-        (AreaEditHandleKind::Right, Vec2::new(max.x, mid_y)),
-        (AreaEditHandleKind::BottomRight, Vec2::new(max.x, min.y)),
-        (AreaEditHandleKind::Bottom, Vec2::new(mid_x, min.y)),
-        (AreaEditHandleKind::BottomLeft, Vec2::new(min.x, min.y)),
-        (AreaEditHandleKind::Left, Vec2::new(min.x, mid_y)),
-        (AreaEditHandleKind::Center, Vec2::new(mid_x, mid_y)),
-    ];
-
-    let mut existing_kinds = Vec::with_capacity(handles.len());
+    let mut existing = Vec::with_capacity(handles.len());
     for (handle_entity, handle, mut transform) in q_handles.iter_mut() {
-        let Some((_, position)) = handles.iter().find(|(kind, _)| *kind == handle.kind) else {
+        let Some((_, _, position)) = handles
+            .iter()
+            .find(|(rect_index, kind, _)| *rect_index == handle.rect_index && *kind == handle.kind)
+        else {
             commands.entity(handle_entity).try_despawn();
             continue;
         };
-        if handle.owner != fam_entity || existing_kinds.contains(&handle.kind) {
+        let key = (handle.rect_index, handle.kind);
+        if handle.owner != fam_entity || existing.contains(&key) {
             commands.entity(handle_entity).try_despawn();
             continue;
         }
-        existing_kinds.push(handle.kind);
+        existing.push(key);
         let translation = position.extend(0.36);
         if transform.translation != translation {
             transform.translation = translation;
         }
     }
 
-    for (kind, pos) in handles {
-        if existing_kinds.contains(&kind) {
+    for (rect_index, kind, pos) in handles {
+        if existing.contains(&(rect_index, kind)) {
             continue;
         }
         commands.spawn((
             AreaEditHandleVisual {
                 owner: fam_entity,
+                rect_index,
                 kind,
             },
             Sprite {
@@ -7,6 +7,7 @@ pub mod assign_task;
 pub mod indicators;
 pub mod input;
 pub mod stockpile_policy;
+pub mod supply_route;
 pub mod visualization;
 pub mod zone_placement;
 
@@ -17,6 +18,7 @@ pub fn to_task_mode_zone_type(zone_type: ZoneType) -> TaskModeZoneType {
     match zone_type {
         ZoneType::Stockpile => TaskModeZoneType::Stockpile,
         ZoneType::Yard => TaskModeZoneType::Yard,
+        ZoneType::Forbidden => TaskModeZoneType::Forbidden,
     }
 }
 
@@ -36,6 +38,8 @@ pub struct DreamTreePreviewIndicator;
 #[derive(Component, Clone, Copy, Debug)]
 pub struct AreaEditHandleVisual {
     pub owner: Entity,
+    /// ハンドルが属する部分矩形の index
+    pub rect_index: usize,
     pub kind: AreaEditHandleKind,
 }
 
@@ -76,6 +80,8 @@ pub use stockpile_policy::{
     StockpilePolicyRangeEditState, resolve_stockpile_policy_targets,
     stockpile_policy_range_selection_system,
 };
+/// Supply route: inspector draft state.
+pub use supply_route::SupplyRouteDraft;
 /// 視覚フィードバック: designation / command visual
 pub use visualization::{designation_visual_system, familiar_command_visual_system};
 /// ゾーン操作: ECS apply（バリデーション helper は `hw_world::zone_ops` 所有）
@@ -11,6 +11,7 @@ use hw_ui::UiIntent;
 use hw_ui::camera::{MainCamera, world_cursor_pos};
 use hw_ui::components::UiInputState;
 use hw_ui::intents::StockpilePolicyEditTarget;
+use hw_ui::selection::SelectedEntities;
 
 use crate::app_contexts::TaskContext;
 use crate::world::map::WorldMap;
@@ -27,13 +28,23 @@ pub struct StockpilePolicyRangeEditState {
 ///
 /// Area resolution deliberately keeps special storages in the request. The domain handler owns
 /// the positive `Stockpile + StockpilePolicy` boundary and reports mixed-selection skips.
+/// Selection resolution keeps only stockpile cells, so souls or buildings picked up by a box
+/// selection are not reported as skipped storages.
 #[must_use]
 pub fn resolve_stockpile_policy_targets(
     target: StockpilePolicyEditTarget,
     stockpile_grid: &StockpileSpatialGrid,
+    selection: &SelectedEntities,
 ) -> Vec<Entity> {
     match target {
         StockpilePolicyEditTarget::Single(entity) => vec![entity],
+        StockpilePolicyEditTarget::Selection => {
+            let positions = &stockpile_grid.data().positions;
+            selection
+                .iter()
+                .filter(|entity| positions.contains_key(entity))
+                .collect()
+        }
         StockpilePolicyEditTarget::Area { min, max } => {
             let area_min = min.min(max);
             let area_max = min.max(max);
@@ -149,6 +160,7 @@ mod tests {
                 max: Vec2::splat(-1.0),
             },
             &grid,
+            &SelectedEntities::default(),
         );
 
         assert_eq!(targets, vec![lower_left, lower_right, upper_right]);
@@ -161,8 +173,31 @@ mod tests {
             resolve_stockpile_policy_targets(
                 StockpilePolicyEditTarget::Single(entity),
                 &StockpileSpatialGrid::default(),
+                &SelectedEntities::default(),
             ),
             vec![entity]
         );
     }
+
+    #[test]
+    fn stockpile_policy_selection_target_keeps_only_selected_stockpile_cells() {
+        let mut world = World::new();
+        let cell = world.spawn_empty().id();
+        let other_cell = world.spawn_empty().id();
+        let soul = world.spawn_empty().id();
+        let mut grid = StockpileSpatialGrid::default();
+        grid.insert(cell, Vec2::ZERO);
+        grid.insert(other_cell, Vec2::new(16.0, 0.0));
+        let mut selection = SelectedEntities::default();
+        selection.extend([soul, cell]);
+
+        assert_eq!(
+            resolve_stockpile_policy_targets(
+                StockpilePolicyEditTarget::Selection,
+                &grid,
+                &selection
+            ),
+            vec![cell]
+        );
+    }
 }
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Root-owned session state for the two-step supply route gesture.
+///
+/// The stockpile inspector marks a source cell first; opening another cell then offers to
+/// supply it from the marked source. Validation stays with the domain request handler.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplyRouteDraft {
+    pub source: Option<Entity>,
+}
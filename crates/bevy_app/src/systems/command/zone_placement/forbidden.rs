@@ -0,0 +1,67 @@
+//! 禁止区域（Forbidden）の塗り・解除と、Stockpile 分断の警告
+
+use crate::systems::jobs::Designation;
+use crate::world::map::WorldMap;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_ui::notifications::{NotificationRetention, NotificationSeverity, UserFacingNotification};
+use hw_world::targets_cut_off_by_forbidden;
+use hw_world::zones::AreaBounds;
+
+/// 禁止区域を塗った直後の到達性チェックに使う入力。
+#[derive(SystemParam)]
+pub struct ForbiddenZoneValidation<'w, 's> {
+    q_designations: Query<'w, 's, &'static Transform, With<Designation>>,
+    notifications: MessageWriter<'w, UserFacingNotification>,
+    localization: Res<'w, Localization>,
+}
+
+fn area_grids(area: &AreaBounds) -> impl Iterator<Item = (i32, i32)> {
+    let min_grid = WorldMap::world_to_grid(area.min + Vec2::splat(0.1));
+    let max_grid = WorldMap::world_to_grid(area.max - Vec2::splat(0.1));
+    (min_grid.1..=max_grid.1).flat_map(move |gy| (min_grid.0..=max_grid.0).map(move |gx| (gx, gy)))
+}
+
+pub(super) fn paint_forbidden_zone(world_map: &mut WorldMap, area: &AreaBounds) -> usize {
+    world_map.add_forbidden_tiles(area_grids(area))
+}
+
+pub(super) fn clear_forbidden_zone(world_map: &mut WorldMap, area: &AreaBounds) -> usize {
+    world_map.remove_forbidden_tiles(area_grids(area))
+}
+
+/// 禁止区域のせいで、どの Stockpile からも辿り着けなくなった作業があれば警告する。
+pub(super) fn warn_if_forbidden_zone_disconnects(
+    world_map: &WorldMap,
+    validation: &mut ForbiddenZoneValidation,
+) {
+    let stockpiles: Vec<_> = world_map
+        .stockpile_entries()
+        .map(|(grid, _)| *grid)
+        .collect();
+    let targets: Vec<_> = validation
+        .q_designations
+        .iter()
+        .map(|transform| WorldMap::world_to_grid(transform.translation.truncate()))
+        .collect();
+
+    let cut_off = targets_cut_off_by_forbidden(world_map, &stockpiles, &targets);
+    if cut_off.is_empty() {
+        return;
+    }
+
+    validation.notifications.write(UserFacingNotification::new(
+        "forbidden-zone:disconnects",
+        NotificationSeverity::Warning,
+        validation
+            .localization
+            .text("notify.forbidden_zone.disconnects.title")
+            .to_string(),
+        validation.localization.format(
+            "notify.forbidden_zone.disconnects.body",
+            &[("count", &cut_off.len())],
+        ),
+        NotificationRetention::ToastOnly,
+    ));
+}
@@ -1,3 +1,6 @@
+use super::forbidden::{
+    ForbiddenZoneValidation, paint_forbidden_zone, warn_if_forbidden_zone_disconnects,
+};
 use crate::app_contexts::TaskContext;
 use crate::interface::ui::UiInputState;
 use crate::systems::command::TaskMode;
@@ -11,11 +14,12 @@ use hw_core::constants::*;
 use hw_core::game_state::PlayMode;
 use hw_logistics::StockpilePolicy;
 use hw_ui::camera::MainCamera;
+use hw_ui::selection::PlacementRejectReason;
 use hw_world::zones::Site;
 use hw_world::zones::{AreaBounds, Yard};
 use hw_world::{area_tile_size, expand_yard_area, rectangles_overlap, rectangles_overlap_site};
 
-const STOCKPILE_CELL_CAPACITY: usize = 10;
+pub(crate) const STOCKPILE_CELL_CAPACITY: usize = 10;
 
 #[derive(SystemParam)]
 pub struct ZonePlacementInput<'w, 's> {
@@ -31,8 +35,8 @@ pub fn zone_placement_system(
     mut next_play_mode: ResMut<NextState<PlayMode>>,
     mut world_map: WorldMapWrite,
     mut commands: Commands,
-    q_yards: Query<(Entity, &Yard)>,
-    q_sites: Query<&Site>,
+    (q_yards, q_sites): (Query<(Entity, &Yard)>, Query<&Site>),
+    mut forbidden_validation: ForbiddenZoneValidation,
 ) {
     if input.ui_input_state.world_input_blocked() {
         return;
@@ -67,7 +71,11 @@ pub fn zone_placement_system(
             {
                 return;
             }
-            if matches!(zone_type, TaskModeZoneType::Yard) {
+            if matches!(zone_type, TaskModeZoneType::Forbidden) {
+                if paint_forbidden_zone(&mut world_map, &area) > 0 {
+                    warn_if_forbidden_zone_disconnects(&world_map, &mut forbidden_validation);
+                }
+            } else if matches!(zone_type, TaskModeZoneType::Yard) {
                 apply_yard_expansion(&mut commands, start_pos, &area, &q_sites, &q_yards);
             } else {
                 apply_zone_placement(&mut commands, &mut world_map, zone_type, &area, &q_yards);
@@ -94,51 +102,67 @@ fn apply_zone_placement(
     area: &AreaBounds,
     q_yards: &Query<(Entity, &Yard)>,
 ) {
+    match zone_type {
+        TaskModeZoneType::Stockpile => {}
+        TaskModeZoneType::Yard | TaskModeZoneType::Forbidden => return,
+    }
+
     let min_grid = WorldMap::world_to_grid(area.min + Vec2::splat(0.1));
     let max_grid = WorldMap::world_to_grid(area.max - Vec2::splat(0.1));
+    let policy = StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY);
 
     for gy in min_grid.1..=max_grid.1 {
         for gx in min_grid.0..=max_grid.0 {
-            let grid = (gx, gy);
-            let grid_pos = WorldMap::grid_to_world(gx, gy);
-            let Some(yard_entity) = pick_stockpile_owner_yard(grid_pos, q_yards) else {
-                continue;
-            };
+            let _ = try_spawn_stockpile_cell(commands, world_map, (gx, gy), policy, q_yards);
+        }
+    }
+}
 
-            // 既に存在するか、建築物がある場合はスキップ
-            if world_map.has_stockpile(grid) || world_map.has_building(grid) {
-                continue;
-            }
-            // 通行不能な場所もスキップ
-            if !world_map.is_walkable(gx, gy) {
-                continue;
-            }
+/// Stockpile セルを 1 つ作成する。
+/// Yard 外・既存の Stockpile / 建築物・通行不能なセルは理由付きでスキップする。
+pub(crate) fn try_spawn_stockpile_cell(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    grid: (i32, i32),
+    policy: StockpilePolicy,
+    q_yards: &Query<(Entity, &Yard)>,
+) -> Result<Entity, PlacementRejectReason> {
+    let grid_pos = WorldMap::grid_to_world(grid.0, grid.1);
+    let Some(yard_entity) = pick_stockpile_owner_yard(grid_pos, q_yards) else {
+        return Err(PlacementRejectReason::NotInYard);
+    };
 
-            match zone_type {
-                TaskModeZoneType::Stockpile => {
-                    let entity = commands
-                        .spawn((
-                            Stockpile {
-                                capacity: STOCKPILE_CELL_CAPACITY,
-                                resource_type: None,
-                            },
-                            StockpilePolicy::for_capacity(STOCKPILE_CELL_CAPACITY),
-                            BelongsTo(yard_entity),
-                            Sprite {
-                                color: Color::srgba(1.0, 1.0, 0.0, 0.2),
-                                custom_size: Some(Vec2::splat(TILE_SIZE)),
-                                ..default()
-                            },
-                            Transform::from_xyz(grid_pos.x, grid_pos.y, Z_MAP + 0.01),
-                            Name::new("Stockpile"),
-                        ))
-                        .id();
-                    world_map.register_stockpile_tile(grid, entity);
-                }
-                TaskModeZoneType::Yard => {}
-            }
-        }
+    // 既に存在するか、建築物がある場合はスキップ
+    if world_map.has_stockpile(grid) {
+        return Err(PlacementRejectReason::OccupiedByStockpile);
+    }
+    if world_map.has_building(grid) {
+        return Err(PlacementRejectReason::OccupiedByBuilding);
     }
+    // 通行不能な場所もスキップ
+    if !world_map.is_walkable(grid.0, grid.1) {
+        return Err(PlacementRejectReason::NotWalkable);
+    }
+
+    let entity = commands
+        .spawn((
+            Stockpile {
+                capacity: STOCKPILE_CELL_CAPACITY,
+                resource_type: None,
+            },
+            policy.normalized_for_capacity(STOCKPILE_CELL_CAPACITY),
+            BelongsTo(yard_entity),
+            Sprite {
+                color: Color::srgba(1.0, 1.0, 0.0, 0.2),
+                custom_size: Some(Vec2::splat(TILE_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(grid_pos.x, grid_pos.y, Z_MAP + 0.01),
+            Name::new("Stockpile"),
+        ))
+        .id();
+    world_map.register_stockpile_tile(grid, entity);
+    Ok(entity)
 }
 
 fn apply_yard_expansion(
@@ -1,6 +1,6 @@
 use crate::app_contexts::TaskContext;
 use crate::interface::ui::UiInputState;
-use crate::systems::command::TaskMode;
+use crate::systems::command::{TaskMode, TaskModeZoneType};
 use crate::world::map::{WorldMap, WorldMapWrite};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
@@ -10,6 +10,7 @@ use hw_ui::camera::MainCamera;
 use hw_world::identify_removal_targets;
 use hw_world::zones::AreaBounds;
 
+use super::forbidden::clear_forbidden_zone;
 use super::removal_preview::{
     ZoneRemovalPreviewState, clear_removal_preview, update_removal_preview,
 };
@@ -51,8 +52,10 @@ pub fn zone_removal_system(
         return;
     }
 
-    // プレビュー更新 (ドラッグ中のみ)
-    if let Some(start_pos) = start_pos_opt {
+    // プレビュー更新 (ドラッグ中のみ。禁止区域は Stockpile を消さないので対象外)
+    if let Some(start_pos) = start_pos_opt
+        && !matches!(zone_type, TaskModeZoneType::Forbidden)
+    {
         let area = AreaBounds::from_points(start_pos, snapped_pos);
         update_removal_preview(&world_map, &area, &mut q_sprites, &mut preview_state);
     }
@@ -61,7 +64,11 @@ pub fn zone_removal_system(
     if input.buttons.just_released(MouseButton::Left) {
         if let Some(start_pos) = start_pos_opt {
             let area = AreaBounds::from_points(start_pos, snapped_pos);
-            apply_zone_removal(&mut commands, &mut world_map, &area);
+            if matches!(zone_type, TaskModeZoneType::Forbidden) {
+                clear_forbidden_zone(&mut world_map, &area);
+            } else {
+                apply_zone_removal(&mut commands, &mut world_map, &area);
+            }
 
             // Shift押下で継続、そうでなければ解除
             task_context.0 = TaskMode::ZoneRemoval(zone_type, None);
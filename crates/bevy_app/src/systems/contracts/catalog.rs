@@ -0,0 +1,75 @@
+//! King of Hell から届く契約のカタログ
+//!
+//! 契約はこの順で発行され、末尾まで進むと先頭に戻る。
+
+use hw_core::logistics::ResourceType;
+use hw_jobs::BuildingType;
+
+use super::model::{ContractObjective, ContractReward, GAME_SECONDS_PER_DAY};
+
+pub struct ContractTemplate {
+    pub objective: ContractObjective,
+    pub reward: ContractReward,
+    /// 受注から期限までの日数
+    pub duration_days: f32,
+}
+
+/// 契約報酬で解禁されるまで Architect から選べない建物
+pub const CONTRACT_LOCKED_BUILDINGS: &[BuildingType] = &[BuildingType::RedemptionShrine];
+
+pub const CONTRACT_CATALOG: &[ContractTemplate] = &[
+    ContractTemplate {
+        objective: ContractObjective::DeliverResource {
+            resource: ResourceType::Rock,
+            amount: 200,
+        },
+        reward: ContractReward::Dream(60.0),
+        duration_days: 3.0,
+    },
+    ContractTemplate {
+        objective: ContractObjective::CompleteRooms { count: 6 },
+        reward: ContractReward::UnlockBuilding(BuildingType::RedemptionShrine),
+        duration_days: 5.0,
+    },
+    ContractTemplate {
+        objective: ContractObjective::KeepLampsPowered {
+            lamps: 10,
+            duration_secs: GAME_SECONDS_PER_DAY,
+        },
+        reward: ContractReward::Dream(120.0),
+        duration_days: 6.0,
+    },
+    ContractTemplate {
+        objective: ContractObjective::DeliverResource {
+            resource: ResourceType::Wood,
+            amount: 150,
+        },
+        reward: ContractReward::Dream(50.0),
+        duration_days: 2.0,
+    },
+    ContractTemplate {
+        objective: ContractObjective::DeliverResource {
+            resource: ResourceType::Bone,
+            amount: 80,
+        },
+        reward: ContractReward::Dream(80.0),
+        duration_days: 4.0,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_locked_building_has_an_unlocking_contract() {
+        for kind in CONTRACT_LOCKED_BUILDINGS {
+            assert!(
+                CONTRACT_CATALOG
+                    .iter()
+                    .any(|template| { template.reward == ContractReward::UnlockBuilding(*kind) }),
+                "{kind:?} can never be unlocked"
+            );
+        }
+    }
+}
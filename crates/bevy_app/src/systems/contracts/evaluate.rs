@@ -0,0 +1,248 @@
+//! 契約の発行・評価システム
+//!
+//! ワールド状態（Stockpile / `Room` / 電力網）を集計して `ContractBook` を更新し、
+//! 結果を `ContractOutcome` として通知 adapter へ渡す。
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::relationships::StoredIn;
+use hw_core::soul::DreamPool;
+use hw_energy::{PowerConsumer, Unpowered};
+use hw_jobs::{Building, BuildingType};
+use hw_logistics::{ResourceItem, Stockpile};
+use hw_world::{Room, Site, Yard};
+
+use super::model::{
+    Contract, ContractBook, ContractResolution, ContractReward, ContractWorldSnapshot,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractOutcomeKind {
+    Issued,
+    Completed,
+    Failed,
+}
+
+/// 契約の受注・達成・失敗を通知 adapter へ渡す Message
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ContractOutcome {
+    pub contract: Contract,
+    pub kind: ContractOutcomeKind,
+}
+
+#[derive(SystemParam)]
+pub struct ContractWorldQueries<'w, 's> {
+    q_stored: Query<'w, 's, (&'static ResourceItem, &'static StoredIn)>,
+    q_stockpiles: Query<'w, 's, &'static Transform, With<Stockpile>>,
+    q_sites: Query<'w, 's, &'static Site>,
+    q_yards: Query<'w, 's, &'static Yard>,
+    q_rooms: Query<'w, 's, (), With<Room>>,
+    q_powered: Query<'w, 's, &'static Building, (With<PowerConsumer>, Without<Unpowered>)>,
+}
+
+impl ContractWorldQueries<'_, '_> {
+    fn snapshot(&self) -> ContractWorldSnapshot {
+        let mut snapshot = ContractWorldSnapshot {
+            rooms: self.q_rooms.iter().count() as u32,
+            powered_lamps: self
+                .q_powered
+                .iter()
+                .filter(|building| {
+                    building.kind == BuildingType::OutdoorLamp && !building.is_provisional
+                })
+                .count() as u32,
+            ..default()
+        };
+
+        for (item, stored_in) in &self.q_stored {
+            let Ok(transform) = self.q_stockpiles.get(stored_in.0) else {
+                continue;
+            };
+            let pos = transform.translation.truncate();
+            let in_site = self.q_sites.iter().any(|site| site.contains(pos))
+                || self.q_yards.iter().any(|yard| yard.contains(pos));
+            if in_site {
+                *snapshot.stored_in_site.entry(item.0).or_default() += 1;
+            }
+        }
+        snapshot
+    }
+}
+
+/// 枠が空いていれば次の契約を受注する
+pub fn contract_issue_system(
+    game_time: Res<GameTime>,
+    mut book: ResMut<ContractBook>,
+    mut outcomes: MessageWriter<ContractOutcome>,
+) {
+    if let Some(contract) = book.try_issue(game_time.seconds) {
+        info!("CONTRACT: issued #{} {:?}", contract.id, contract.objective);
+        outcomes.write(ContractOutcome {
+            contract: contract.clone(),
+            kind: ContractOutcomeKind::Issued,
+        });
+    }
+}
+
+/// 一定間隔でワールド状態を集計し、達成・期限切れを解決して報酬を支払う
+pub fn contract_evaluation_system(
+    game_time: Res<GameTime>,
+    mut book: ResMut<ContractBook>,
+    mut dream_pool: ResMut<DreamPool>,
+    world: ContractWorldQueries,
+    mut outcomes: MessageWriter<ContractOutcome>,
+) {
+    if book.active.is_empty() {
+        return;
+    }
+    let now = game_time.seconds;
+    let Some(elapsed) = book.take_evaluation_tick(now) else {
+        return;
+    };
+
+    let snapshot = world.snapshot();
+    for (contract, resolution) in book.evaluate(&snapshot, now, elapsed) {
+        let kind = match resolution {
+            ContractResolution::Completed => {
+                if let ContractReward::Dream(amount) = contract.reward {
+                    dream_pool.points += amount;
+                }
+                ContractOutcomeKind::Completed
+            }
+            ContractResolution::Failed => ContractOutcomeKind::Failed,
+        };
+        info!(
+            "CONTRACT: #{} {:?} -> {:?}",
+            contract.id, contract.objective, kind
+        );
+        outcomes.write(ContractOutcome { contract, kind });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::contracts::model::{
+        CONTRACT_EVALUATION_INTERVAL_SECS, ContractObjective, GAME_SECONDS_PER_DAY,
+    };
+    use hw_core::logistics::ResourceType;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<GameTime>()
+            .init_resource::<ContractBook>()
+            .init_resource::<DreamPool>()
+            .add_message::<ContractOutcome>()
+            .add_systems(
+                Update,
+                (contract_issue_system, contract_evaluation_system).chain(),
+            );
+        app
+    }
+
+    fn stockpile() -> Stockpile {
+        Stockpile {
+            capacity: 10,
+            resource_type: Some(ResourceType::Rock),
+        }
+    }
+
+    #[test]
+    fn rock_stored_in_yard_completes_delivery_contract_and_pays_dream() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<ContractBook>()
+            .active
+            .push(Contract {
+                id: 7,
+                objective: ContractObjective::DeliverResource {
+                    resource: ResourceType::Rock,
+                    amount: 2,
+                },
+                reward: ContractReward::Dream(25.0),
+                issued_at: 0.0,
+                deadline: GAME_SECONDS_PER_DAY,
+                progress: 0.0,
+            });
+        app.world_mut().resource_mut::<ContractBook>().next_issue_at = f32::MAX;
+
+        app.world_mut().spawn(Yard {
+            min: Vec2::splat(-100.0),
+            max: Vec2::splat(100.0),
+        });
+        let inside = app
+            .world_mut()
+            .spawn((stockpile(), Transform::default()))
+            .id();
+        let outside = app
+            .world_mut()
+            .spawn((stockpile(), Transform::from_xyz(500.0, 0.0, 0.0)))
+            .id();
+        for stockpile in [inside, inside, outside] {
+            app.world_mut()
+                .spawn((ResourceItem(ResourceType::Rock), StoredIn(stockpile)));
+        }
+
+        app.world_mut().resource_mut::<GameTime>().seconds = CONTRACT_EVALUATION_INTERVAL_SECS;
+        app.update();
+
+        let book = app.world().resource::<ContractBook>();
+        assert!(book.active.is_empty());
+        assert_eq!(book.completed, 1);
+        assert_eq!(app.world().resource::<DreamPool>().points, 25.0);
+        let kinds: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<ContractOutcome>>()
+            .drain()
+            .map(|outcome| outcome.kind)
+            .collect();
+        assert_eq!(kinds, vec![ContractOutcomeKind::Completed]);
+    }
+
+    #[test]
+    fn lamp_contract_issued_after_idle_gap_only_counts_time_since_issue() {
+        let mut app = app();
+        app.world_mut().resource_mut::<ContractBook>().next_issue_at = f32::MAX;
+        for _ in 0..2 {
+            let lamp = app
+                .world_mut()
+                .spawn((
+                    Building {
+                        kind: BuildingType::OutdoorLamp,
+                        is_provisional: false,
+                    },
+                    PowerConsumer { demand: 1.0 },
+                ))
+                .id();
+            app.world_mut().entity_mut(lamp).remove::<Unpowered>();
+        }
+
+        let issued_at = 3.0 * GAME_SECONDS_PER_DAY;
+        app.world_mut().resource_mut::<GameTime>().seconds = issued_at;
+        app.update();
+        app.world_mut()
+            .resource_mut::<ContractBook>()
+            .active
+            .push(Contract {
+                id: 1,
+                objective: ContractObjective::KeepLampsPowered {
+                    lamps: 2,
+                    duration_secs: GAME_SECONDS_PER_DAY,
+                },
+                reward: ContractReward::Dream(10.0),
+                issued_at,
+                deadline: issued_at + 2.0 * GAME_SECONDS_PER_DAY,
+                progress: 0.0,
+            });
+
+        app.world_mut().resource_mut::<GameTime>().seconds =
+            issued_at + CONTRACT_EVALUATION_INTERVAL_SECS;
+        app.update();
+
+        let book = app.world().resource::<ContractBook>();
+        assert_eq!(book.completed, 0);
+        assert_eq!(book.active.len(), 1);
+        assert_eq!(book.active[0].progress, CONTRACT_EVALUATION_INTERVAL_SECS);
+    }
+}
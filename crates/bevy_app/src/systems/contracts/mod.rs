@@ -0,0 +1,35 @@
+//! King of Hell からの契約（Contracts）
+//!
+//! カタログ順に契約を受注し、`GameTime` 上の期限までにワールド状態が条件を満たせば
+//! DreamPool への報酬または建物の解禁を得る。キューと累計は `ContractBook` に保持し、
+//! セーブ対象とする。
+
+mod catalog;
+mod evaluate;
+mod model;
+
+use bevy::prelude::*;
+
+use crate::systems::GameSystemSet;
+
+pub use catalog::{CONTRACT_CATALOG, CONTRACT_LOCKED_BUILDINGS};
+pub use evaluate::{
+    ContractOutcome, ContractOutcomeKind, contract_evaluation_system, contract_issue_system,
+};
+pub use model::{
+    Contract, ContractBook, ContractObjective, ContractResolution, ContractReward,
+    ContractWorldSnapshot, MAX_ACTIVE_CONTRACTS,
+};
+
+pub struct ContractPlugin;
+
+impl Plugin for ContractPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ContractBook>().add_systems(
+            Update,
+            (contract_issue_system, contract_evaluation_system)
+                .chain()
+                .in_set(GameSystemSet::Logic),
+        );
+    }
+}
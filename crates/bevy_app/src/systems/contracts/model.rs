@@ -0,0 +1,383 @@
+//! 契約の型と、ワールド状態スナップショットに対する純粋な評価ロジック
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_core::logistics::ResourceType;
+use hw_jobs::BuildingType;
+
+use super::catalog::{CONTRACT_CATALOG, CONTRACT_LOCKED_BUILDINGS};
+
+/// 同時に受注できる契約の上限
+pub const MAX_ACTIVE_CONTRACTS: usize = 3;
+/// ゲーム内 1 日の秒数（`GameTime::seconds` 基準）
+pub const GAME_SECONDS_PER_DAY: f32 = 24.0 * 60.0 * 60.0;
+/// 契約の解決後、次の契約が届くまでの待ち時間（ゲーム内秒）
+pub const CONTRACT_ISSUE_COOLDOWN_SECS: f32 = 6.0 * 60.0 * 60.0;
+/// 連続して契約を受注するときの最小間隔（ゲーム内秒）
+pub const CONTRACT_ISSUE_STAGGER_SECS: f32 = 60.0 * 60.0;
+/// ワールド状態を評価する間隔（ゲーム内秒）
+pub const CONTRACT_EVALUATION_INTERVAL_SECS: f32 = 60.0;
+/// 既に解禁済みの建物が報酬だった場合に代わりに支払う Dream
+pub const CONTRACT_UNLOCK_FALLBACK_DREAM: f32 = 40.0;
+
+/// 契約の達成条件
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ContractObjective {
+    /// Site / Yard 内の Stockpile に指定資源を備蓄する
+    DeliverResource { resource: ResourceType, amount: u32 },
+    /// 部屋（`Room`）を指定数完成させる
+    CompleteRooms { count: u32 },
+    /// 通電中の OutdoorLamp を指定数、指定時間維持する
+    KeepLampsPowered { lamps: u32, duration_secs: f32 },
+}
+
+impl ContractObjective {
+    pub fn title(&self, text: &Localization) -> String {
+        match *self {
+            Self::DeliverResource { resource, amount } => text.format(
+                "ui.contract.deliver",
+                &[
+                    ("amount", &amount),
+                    ("resource", &text.text(resource.text_id())),
+                ],
+            ),
+            Self::CompleteRooms { count } => text.format("ui.contract.rooms", &[("count", &count)]),
+            Self::KeepLampsPowered {
+                lamps,
+                duration_secs,
+            } => text.format(
+                "ui.contract.lamps",
+                &[
+                    ("lamps", &lamps),
+                    ("duration", &format_duration(text, duration_secs)),
+                ],
+            ),
+        }
+    }
+
+    /// 達成に必要な進捗値
+    pub fn target(&self) -> f32 {
+        match *self {
+            Self::DeliverResource { amount, .. } => amount as f32,
+            Self::CompleteRooms { count } => count as f32,
+            Self::KeepLampsPowered { duration_secs, .. } => duration_secs,
+        }
+    }
+
+    pub fn progress_label(&self, text: &Localization, progress: f32) -> String {
+        match *self {
+            Self::DeliverResource { amount, .. } => format!("{:.0}/{amount}", progress),
+            Self::CompleteRooms { count } => text.format(
+                "ui.contract.rooms_progress",
+                &[("progress", &format!("{progress:.0}")), ("count", &count)],
+            ),
+            Self::KeepLampsPowered { duration_secs, .. } => format!(
+                "{}/{}",
+                format_duration(text, progress),
+                format_duration(text, duration_secs)
+            ),
+        }
+    }
+}
+
+/// 契約達成時の報酬
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ContractReward {
+    Dream(f32),
+    UnlockBuilding(BuildingType),
+}
+
+impl ContractReward {
+    pub fn label(&self, text: &Localization) -> String {
+        match *self {
+            Self::Dream(amount) => text.format(
+                "ui.contract.reward_dream",
+                &[("amount", &format!("{amount:.0}"))],
+            ),
+            Self::UnlockBuilding(kind) => text.format(
+                "ui.contract.reward_unlock",
+                &[("building", &text.text(kind.text_id()))],
+            ),
+        }
+    }
+}
+
+/// 受注中の契約
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct Contract {
+    pub id: u32,
+    pub objective: ContractObjective,
+    pub reward: ContractReward,
+    /// 受注時刻（`GameTime::seconds`）
+    pub issued_at: f32,
+    /// 期限（`GameTime::seconds`）
+    pub deadline: f32,
+    /// 直近の評価値。`KeepLampsPowered` では条件を連続で満たした秒数
+    pub progress: f32,
+}
+
+impl Contract {
+    pub fn is_fulfilled(&self) -> bool {
+        self.progress >= self.objective.target()
+    }
+}
+
+/// 評価に使うワールド状態の集計
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ContractWorldSnapshot {
+    pub stored_in_site: HashMap<ResourceType, u32>,
+    pub rooms: u32,
+    pub powered_lamps: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractResolution {
+    Completed,
+    Failed,
+}
+
+/// 契約キューと累計。セーブ対象の Resource
+#[derive(Resource, Reflect, Debug, Clone, Default, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct ContractBook {
+    pub active: Vec<Contract>,
+    /// 次に発行するカタログ上の位置
+    pub next_template: u32,
+    pub next_id: u32,
+    /// 次の契約を発行できる時刻（`GameTime::seconds`）
+    pub next_issue_at: f32,
+    pub last_evaluated_at: f32,
+    pub completed: u32,
+    pub failed: u32,
+    pub unlocked_buildings: Vec<BuildingType>,
+}
+
+impl ContractBook {
+    /// 契約報酬で解禁されるまで Architect から選べない建物か
+    pub fn is_building_locked(&self, kind: BuildingType) -> bool {
+        CONTRACT_LOCKED_BUILDINGS.contains(&kind) && !self.unlocked_buildings.contains(&kind)
+    }
+
+    pub fn locked_buildings(&self) -> impl Iterator<Item = BuildingType> + '_ {
+        CONTRACT_LOCKED_BUILDINGS
+            .iter()
+            .copied()
+            .filter(|kind| self.is_building_locked(*kind))
+    }
+
+    /// 枠と待ち時間が許せばカタログの次の契約を受注し、その参照を返す
+    pub fn try_issue(&mut self, now: f32) -> Option<&Contract> {
+        if self.active.len() >= MAX_ACTIVE_CONTRACTS || now < self.next_issue_at {
+            return None;
+        }
+
+        let template = &CONTRACT_CATALOG[self.next_template as usize % CONTRACT_CATALOG.len()];
+        let reward = match template.reward {
+            ContractReward::UnlockBuilding(kind) if !self.is_building_locked(kind) => {
+                ContractReward::Dream(CONTRACT_UNLOCK_FALLBACK_DREAM)
+            }
+            reward => reward,
+        };
+        self.active.push(Contract {
+            id: self.next_id,
+            objective: template.objective,
+            reward,
+            issued_at: now,
+            deadline: now + template.duration_days * GAME_SECONDS_PER_DAY,
+            progress: 0.0,
+        });
+        self.next_id += 1;
+        self.next_issue_at = now + CONTRACT_ISSUE_STAGGER_SECS;
+        self.next_template = self.next_template.wrapping_add(1);
+        self.active.last()
+    }
+
+    /// 評価間隔が経過していれば前回評価からの経過時間を返し、評価時刻を進める
+    pub fn take_evaluation_tick(&mut self, now: f32) -> Option<f32> {
+        let elapsed = now - self.last_evaluated_at;
+        if elapsed < CONTRACT_EVALUATION_INTERVAL_SECS {
+            return None;
+        }
+        self.last_evaluated_at = now;
+        Some(elapsed)
+    }
+
+    /// 進捗を更新し、達成・期限切れになった契約をキューから取り除いて返す
+    ///
+    /// 解禁報酬はここで反映する。Dream 報酬の加算は呼び出し側が担う。
+    pub fn evaluate(
+        &mut self,
+        snapshot: &ContractWorldSnapshot,
+        now: f32,
+        elapsed: f32,
+    ) -> Vec<(Contract, ContractResolution)> {
+        for contract in &mut self.active {
+            contract.progress = match contract.objective {
+                ContractObjective::DeliverResource { resource, .. } => {
+                    snapshot.stored_in_site.get(&resource).copied().unwrap_or(0) as f32
+                }
+                ContractObjective::CompleteRooms { .. } => snapshot.rooms as f32,
+                ContractObjective::KeepLampsPowered { lamps, .. } => {
+                    if snapshot.powered_lamps >= lamps {
+                        // 受注前の時間は計上しない
+                        contract.progress + elapsed.min(now - contract.issued_at).max(0.0)
+                    } else {
+                        0.0
+                    }
+                }
+            };
+        }
+
+        let mut resolved = Vec::new();
+        let mut index = 0;
+        while index < self.active.len() {
+            let resolution = if self.active[index].is_fulfilled() {
+                ContractResolution::Completed
+            } else if now >= self.active[index].deadline {
+                ContractResolution::Failed
+            } else {
+                index += 1;
+                continue;
+            };
+            let contract = self.active.remove(index);
+            match resolution {
+                ContractResolution::Completed => {
+                    self.completed += 1;
+                    if let ContractReward::UnlockBuilding(kind) = contract.reward
+                        && !self.unlocked_buildings.contains(&kind)
+                    {
+                        self.unlocked_buildings.push(kind);
+                    }
+                }
+                ContractResolution::Failed => self.failed += 1,
+            }
+            resolved.push((contract, resolution));
+        }
+
+        if !resolved.is_empty() {
+            self.next_issue_at = self.next_issue_at.max(now + CONTRACT_ISSUE_COOLDOWN_SECS);
+        }
+        resolved
+    }
+}
+
+fn format_duration(text: &Localization, secs: f32) -> String {
+    let hours = (secs / 3600.0).floor() as u32;
+    if hours >= 24 && hours.is_multiple_of(24) {
+        let days = hours / 24;
+        if days == 1 {
+            text.text("ui.contract.one_day").to_string()
+        } else {
+            text.format("ui.contract.days", &[("days", &days)])
+        }
+    } else {
+        text.format("ui.contract.hours", &[("hours", &hours)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book_with(objective: ContractObjective, reward: ContractReward) -> ContractBook {
+        ContractBook {
+            active: vec![Contract {
+                id: 0,
+                objective,
+                reward,
+                issued_at: 0.0,
+                deadline: GAME_SECONDS_PER_DAY,
+                progress: 0.0,
+            }],
+            ..default()
+        }
+    }
+
+    #[test]
+    fn issue_respects_capacity_and_cooldown() {
+        let mut book = ContractBook::default();
+        let mut now = 0.0;
+        for _ in 0..MAX_ACTIVE_CONTRACTS {
+            assert!(book.try_issue(now).is_some());
+            assert!(book.try_issue(now).is_none());
+            now += CONTRACT_ISSUE_STAGGER_SECS;
+        }
+        assert!(book.try_issue(now).is_none());
+        assert_eq!(book.active.len(), MAX_ACTIVE_CONTRACTS);
+
+        book.active.clear();
+        book.next_issue_at = now + 100.0;
+        assert!(book.try_issue(now + 50.0).is_none());
+        assert!(book.try_issue(now + 100.0).is_some());
+    }
+
+    #[test]
+    fn delivery_contract_completes_from_snapshot() {
+        let mut book = book_with(
+            ContractObjective::DeliverResource {
+                resource: ResourceType::Rock,
+                amount: 10,
+            },
+            ContractReward::Dream(5.0),
+        );
+        let mut snapshot = ContractWorldSnapshot::default();
+        snapshot.stored_in_site.insert(ResourceType::Rock, 4);
+        assert!(book.evaluate(&snapshot, 60.0, 60.0).is_empty());
+        assert_eq!(book.active[0].progress, 4.0);
+
+        snapshot.stored_in_site.insert(ResourceType::Rock, 10);
+        let resolved = book.evaluate(&snapshot, 120.0, 60.0);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].1, ContractResolution::Completed);
+        assert_eq!(book.completed, 1);
+        assert_eq!(book.next_issue_at, 120.0 + CONTRACT_ISSUE_COOLDOWN_SECS);
+    }
+
+    #[test]
+    fn lamp_contract_resets_when_power_drops_and_fails_at_deadline() {
+        let mut book = book_with(
+            ContractObjective::KeepLampsPowered {
+                lamps: 2,
+                duration_secs: GAME_SECONDS_PER_DAY,
+            },
+            ContractReward::Dream(5.0),
+        );
+        let mut snapshot = ContractWorldSnapshot {
+            powered_lamps: 2,
+            ..default()
+        };
+        book.evaluate(&snapshot, 600.0, 600.0);
+        assert_eq!(book.active[0].progress, 600.0);
+
+        snapshot.powered_lamps = 1;
+        book.evaluate(&snapshot, 1200.0, 600.0);
+        assert_eq!(book.active[0].progress, 0.0);
+
+        let resolved = book.evaluate(&snapshot, GAME_SECONDS_PER_DAY, 600.0);
+        assert_eq!(resolved[0].1, ContractResolution::Failed);
+        assert_eq!(book.failed, 1);
+    }
+
+    #[test]
+    fn unlock_reward_unlocks_building_once() {
+        let kind = CONTRACT_LOCKED_BUILDINGS[0];
+        let mut book = book_with(
+            ContractObjective::CompleteRooms { count: 1 },
+            ContractReward::UnlockBuilding(kind),
+        );
+        assert!(book.is_building_locked(kind));
+
+        let snapshot = ContractWorldSnapshot {
+            rooms: 1,
+            ..default()
+        };
+        book.evaluate(&snapshot, 60.0, 60.0);
+        assert!(!book.is_building_locked(kind));
+        assert_eq!(
+            book.locked_buildings().count(),
+            CONTRACT_LOCKED_BUILDINGS.len() - 1
+        );
+    }
+}
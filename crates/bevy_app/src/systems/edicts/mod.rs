@@ -0,0 +1,141 @@
+//! Dream Edicts の発令・取り消し・失効
+//!
+//! 勅令の定義と効果の合成は `hw_core::edicts` にあり、ここでは UI からの要求と
+//! `GameTime` による失効を `DreamEdicts` へ反映して `EdictOutcome` を通知する。
+
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::edicts::{DreamEdicts, EdictEnactError, EdictKind};
+use hw_core::soul::DreamPool;
+
+use crate::systems::GameSystemSet;
+
+/// 勅令パネルからの発令／取り消し要求。発令中なら取り消し、未発令なら発令する
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdictToggleRequest(pub EdictKind);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdictChange {
+    Enacted { expires_at: f32 },
+    Revoked,
+    Expired,
+    Rejected(EdictEnactError),
+}
+
+/// 勅令の状態変化を通知 adapter へ渡す Message
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct EdictOutcome {
+    pub kind: EdictKind,
+    pub change: EdictChange,
+}
+
+pub fn edict_toggle_request_system(
+    mut requests: MessageReader<EdictToggleRequest>,
+    game_time: Res<GameTime>,
+    mut edicts: ResMut<DreamEdicts>,
+    mut dream_pool: ResMut<DreamPool>,
+    mut outcomes: MessageWriter<EdictOutcome>,
+) {
+    for EdictToggleRequest(kind) in requests.read().copied() {
+        let change = if edicts.revoke(kind) {
+            EdictChange::Revoked
+        } else {
+            match edicts.enact(kind, game_time.seconds, &mut dream_pool) {
+                Ok(()) => EdictChange::Enacted {
+                    expires_at: game_time.seconds + kind.duration_secs(),
+                },
+                Err(error) => EdictChange::Rejected(error),
+            }
+        };
+        info!("EDICT: {} -> {:?}", kind.label(), change);
+        outcomes.write(EdictOutcome { kind, change });
+    }
+}
+
+pub fn edict_expiry_system(
+    game_time: Res<GameTime>,
+    mut edicts: ResMut<DreamEdicts>,
+    mut outcomes: MessageWriter<EdictOutcome>,
+) {
+    if edicts.active.is_empty() {
+        return;
+    }
+    for kind in edicts.expire(game_time.seconds) {
+        info!("EDICT: {} expired", kind.label());
+        outcomes.write(EdictOutcome {
+            kind,
+            change: EdictChange::Expired,
+        });
+    }
+}
+
+pub struct EdictPlugin;
+
+impl Plugin for EdictPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DreamEdicts>().add_systems(
+            Update,
+            (edict_toggle_request_system, edict_expiry_system)
+                .chain()
+                .in_set(GameSystemSet::Logic),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<GameTime>()
+            .init_resource::<DreamEdicts>()
+            .insert_resource(DreamPool { points: 100.0 })
+            .add_message::<EdictToggleRequest>()
+            .add_message::<EdictOutcome>()
+            .add_systems(
+                Update,
+                (edict_toggle_request_system, edict_expiry_system).chain(),
+            );
+        app
+    }
+
+    fn drain_changes(app: &mut App) -> Vec<EdictChange> {
+        app.world_mut()
+            .resource_mut::<Messages<EdictOutcome>>()
+            .drain()
+            .map(|outcome| outcome.change)
+            .collect()
+    }
+
+    #[test]
+    fn toggle_enacts_then_revokes_and_expiry_is_reported() {
+        let mut app = app();
+        let kind = EdictKind::MandatorySiesta;
+
+        app.world_mut().write_message(EdictToggleRequest(kind));
+        app.update();
+        assert_eq!(
+            drain_changes(&mut app),
+            vec![EdictChange::Enacted {
+                expires_at: kind.duration_secs()
+            }]
+        );
+        assert_eq!(
+            app.world().resource::<DreamPool>().points,
+            100.0 - kind.cost()
+        );
+
+        app.world_mut().write_message(EdictToggleRequest(kind));
+        app.update();
+        assert_eq!(drain_changes(&mut app), vec![EdictChange::Revoked]);
+        assert!(!app.world().resource::<DreamEdicts>().is_active(kind));
+
+        app.world_mut().write_message(EdictToggleRequest(kind));
+        app.update();
+        drain_changes(&mut app);
+        app.world_mut().resource_mut::<GameTime>().seconds = kind.duration_secs();
+        app.update();
+        assert_eq!(drain_changes(&mut app), vec![EdictChange::Expired]);
+    }
+}
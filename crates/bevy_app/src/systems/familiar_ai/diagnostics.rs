@@ -60,6 +60,7 @@ impl SoulEligibilitySnapshot {
                     | IdleBehavior::GoingToRest
                     | IdleBehavior::Escaping
                     | IdleBehavior::Drifting
+                    | IdleBehavior::Redeeming
             ),
             fatigue: soul.fatigue,
             generate_power_ready: soul.dream >= DREAM_GENERATE_ASSIGN_THRESHOLD,
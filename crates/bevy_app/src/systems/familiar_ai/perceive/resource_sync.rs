@@ -182,7 +182,8 @@ pub fn sync_reservations_system(
             | TransportRequestKind::DeliverToWallConstruction
             | TransportRequestKind::DeliverToProvisionalWall
             | TransportRequestKind::GatherWaterToTank
-            | TransportRequestKind::ConsolidateStockpile => {
+            | TransportRequestKind::ConsolidateStockpile
+            | TransportRequestKind::SupplyRoute => {
                 // DeliveringTo リレーションシップを使用するため、ここでは HashMap に積まない
             }
             TransportRequestKind::DeliverToMixerSolid => {
@@ -0,0 +1,423 @@
+//! ゲーム不変条件の実行時検査
+//!
+//! `docs/invariants.md` の規則のうち live ECS state から判定できるものを、毎フレームの
+//! simulation 後（`PostUpdate`）に検査する。既定の App には登録しない opt-in plugin で、
+//! 違反はエンティティ付きで報告し、ポリシーに応じて panic するか `InvariantReport` に数える。
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::events::OnTaskCompleted;
+use hw_core::familiar::Familiar;
+use hw_core::relationships::{CommandedBy, DeliveringTo, LoadedIn, StoredItems, WorkingOn};
+use hw_core::soul::DamnedSoul;
+use hw_jobs::AssignedTask;
+use hw_logistics::Stockpile;
+
+/// 1 フレームの報告に残す違反の上限。件数の集計はこの上限に関係なく行う。
+const MAX_RECENT_VIOLATIONS: usize = 32;
+
+/// 検査する不変条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum InvariantRule {
+    /// I-S1: `AssignedTask::None` の Soul が `WorkingOn` を持っている
+    WorkingOnWithoutTask,
+    /// I-S2: `CommandedBy` の指揮元が Familiar ではない
+    CommandedByNonFamiliar,
+    /// I-S3: 同じ Soul の `OnTaskCompleted` が 1 フレームに複数回発火した
+    DuplicateTaskCompletion,
+    /// I-F1: Familiar 自身が `WorkingOn` を持っている
+    FamiliarWorking,
+    /// I-L1: どの Soul のタスクにも属さないアイテムに `DeliveringTo` が残っている
+    OrphanedDelivery,
+    /// I-L3: `StoredItems` が `Stockpile.capacity` を超えている
+    StockpileOverCapacity,
+}
+
+impl InvariantRule {
+    pub const ALL: [Self; 6] = [
+        Self::WorkingOnWithoutTask,
+        Self::CommandedByNonFamiliar,
+        Self::DuplicateTaskCompletion,
+        Self::FamiliarWorking,
+        Self::OrphanedDelivery,
+        Self::StockpileOverCapacity,
+    ];
+
+    /// `docs/invariants.md` の規則 ID
+    pub const fn doc_id(self) -> &'static str {
+        match self {
+            Self::WorkingOnWithoutTask => "I-S1",
+            Self::CommandedByNonFamiliar => "I-S2",
+            Self::DuplicateTaskCompletion => "I-S3",
+            Self::FamiliarWorking => "I-F1",
+            Self::OrphanedDelivery => "I-L1",
+            Self::StockpileOverCapacity => "I-L3",
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::WorkingOnWithoutTask => "working-on-without-task",
+            Self::CommandedByNonFamiliar => "commanded-by-non-familiar",
+            Self::DuplicateTaskCompletion => "duplicate-task-completion",
+            Self::FamiliarWorking => "familiar-working",
+            Self::OrphanedDelivery => "orphaned-delivery",
+            Self::StockpileOverCapacity => "stockpile-over-capacity",
+        }
+    }
+}
+
+/// 検出した違反 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantViolation {
+    pub rule: InvariantRule,
+    pub entity: Entity,
+    pub detail: String,
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} on {:?}: {}",
+            self.rule.doc_id(),
+            self.rule.as_str(),
+            self.entity,
+            self.detail
+        )
+    }
+}
+
+/// 違反を見つけたときの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvariantViolationPolicy {
+    /// `warn!` で報告して `InvariantReport` に数える（perf / headless 向け）
+    #[default]
+    Count,
+    /// 違反の一覧を添えて panic する（テスト向け）
+    Panic,
+}
+
+/// 検査結果の累計
+#[derive(Resource, Debug, Default)]
+pub struct InvariantReport {
+    pub policy: InvariantViolationPolicy,
+    pub checks: u64,
+    pub total_violations: u64,
+    violations_by_rule: HashMap<InvariantRule, u64>,
+    /// 直近で違反が見つかったフレームの違反（`MAX_RECENT_VIOLATIONS` 件まで）
+    pub recent: Vec<InvariantViolation>,
+}
+
+impl InvariantReport {
+    pub fn violations(&self, rule: InvariantRule) -> u64 {
+        self.violations_by_rule.get(&rule).copied().unwrap_or(0)
+    }
+
+    fn record(&mut self, violations: Vec<InvariantViolation>) {
+        self.checks += 1;
+        if violations.is_empty() {
+            return;
+        }
+        self.total_violations += violations.len() as u64;
+        for violation in &violations {
+            *self.violations_by_rule.entry(violation.rule).or_default() += 1;
+        }
+        self.recent = violations;
+        self.recent.truncate(MAX_RECENT_VIOLATIONS);
+    }
+}
+
+/// `OnTaskCompleted` を前回の検査以降の Soul ごとに数える。
+#[derive(Resource, Default)]
+struct TaskCompletionTally(HashMap<Entity, u32>);
+
+fn on_task_completed_invariant_tally(
+    on: On<OnTaskCompleted>,
+    mut tally: ResMut<TaskCompletionTally>,
+) {
+    *tally.0.entry(on.event().entity).or_default() += 1;
+}
+
+type InvariantSoulQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static AssignedTask,
+        Option<&'static WorkingOn>,
+        Option<&'static CommandedBy>,
+    ),
+    With<DamnedSoul>,
+>;
+
+#[derive(SystemParam)]
+struct InvariantSources<'w, 's> {
+    q_souls: InvariantSoulQuery<'w, 's>,
+    q_familiars: Query<'w, 's, (Entity, Option<&'static WorkingOn>), With<Familiar>>,
+    q_deliveries: Query<'w, 's, (Entity, &'static DeliveringTo, Option<&'static LoadedIn>)>,
+    q_stockpiles: Query<'w, 's, (Entity, &'static Stockpile, &'static StoredItems)>,
+}
+
+impl InvariantSources<'_, '_> {
+    fn collect_violations(&self, completions: &HashMap<Entity, u32>) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new();
+        let mut claimed_payloads = HashSet::new();
+
+        for (soul, task, working_on, commanded_by) in &self.q_souls {
+            if let (AssignedTask::None, Some(working_on)) = (task, working_on) {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::WorkingOnWithoutTask,
+                    entity: soul,
+                    detail: format!("WorkingOn({:?}) with AssignedTask::None", working_on.0),
+                });
+            }
+            if let Some(commanded_by) = commanded_by
+                && !self.q_familiars.contains(commanded_by.0)
+            {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::CommandedByNonFamiliar,
+                    entity: soul,
+                    detail: format!("CommandedBy({:?}) is not a Familiar", commanded_by.0),
+                });
+            }
+            claimed_payloads.extend(task.expected_item());
+            if let AssignedTask::HaulWithWheelbarrow(data) = task {
+                claimed_payloads.extend(data.items.iter().copied());
+            }
+        }
+
+        for (familiar, working_on) in &self.q_familiars {
+            if let Some(working_on) = working_on {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::FamiliarWorking,
+                    entity: familiar,
+                    detail: format!("Familiar has WorkingOn({:?})", working_on.0),
+                });
+            }
+        }
+
+        for (&soul, &count) in completions {
+            if count > 1 {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::DuplicateTaskCompletion,
+                    entity: soul,
+                    detail: format!("OnTaskCompleted fired {count} times in one frame"),
+                });
+            }
+        }
+
+        for (item, delivering_to, loaded_in) in &self.q_deliveries {
+            // 手押し車に積まれたアイテムは手押し車を運ぶタスクが所有する。
+            let owner = loaded_in.map_or(item, |loaded_in| loaded_in.0);
+            if !claimed_payloads.contains(&item) && !claimed_payloads.contains(&owner) {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::OrphanedDelivery,
+                    entity: item,
+                    detail: format!(
+                        "DeliveringTo({:?}) without an owning soul task",
+                        delivering_to.0
+                    ),
+                });
+            }
+        }
+
+        for (stockpile_entity, stockpile, stored) in &self.q_stockpiles {
+            if stored.len() > stockpile.capacity {
+                violations.push(InvariantViolation {
+                    rule: InvariantRule::StockpileOverCapacity,
+                    entity: stockpile_entity,
+                    detail: format!(
+                        "{} stored items exceed capacity {}",
+                        stored.len(),
+                        stockpile.capacity
+                    ),
+                });
+            }
+        }
+
+        violations.sort_by_key(|violation| (violation.rule, violation.entity));
+        violations
+    }
+}
+
+fn invariant_check_system(
+    sources: InvariantSources,
+    mut tally: ResMut<TaskCompletionTally>,
+    mut report: ResMut<InvariantReport>,
+) {
+    let violations = sources.collect_violations(&tally.0);
+    tally.0.clear();
+
+    if !violations.is_empty() {
+        let lines = violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n  ");
+        match report.policy {
+            InvariantViolationPolicy::Panic => {
+                panic!("INVARIANT: {} violation(s)\n  {lines}", violations.len())
+            }
+            InvariantViolationPolicy::Count => {
+                warn!("INVARIANT: {} violation(s)\n  {lines}", violations.len())
+            }
+        }
+    }
+    report.record(violations);
+}
+
+/// 不変条件の実行時検査を登録する opt-in plugin。
+///
+/// 検査は virtual time が進んだフレームだけ、`Update` の simulation set がすべて終わった後に走る。
+pub struct InvariantCheckPlugin {
+    pub policy: InvariantViolationPolicy,
+}
+
+impl InvariantCheckPlugin {
+    pub const fn counting() -> Self {
+        Self {
+            policy: InvariantViolationPolicy::Count,
+        }
+    }
+
+    pub const fn panicking() -> Self {
+        Self {
+            policy: InvariantViolationPolicy::Panic,
+        }
+    }
+}
+
+impl Plugin for InvariantCheckPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InvariantReport {
+            policy: self.policy,
+            ..default()
+        })
+        .init_resource::<TaskCompletionTally>()
+        .add_observer(on_task_completed_invariant_tally)
+        .add_systems(
+            PostUpdate,
+            invariant_check_system.run_if(|time: Res<Time<Virtual>>| !time.is_paused()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::minimal_app;
+    use hw_core::jobs::WorkType;
+    use hw_core::relationships::StoredIn;
+    use hw_jobs::{HaulData, HaulPhase};
+
+    fn counting_app() -> App {
+        let mut app = minimal_app();
+        app.add_plugins(InvariantCheckPlugin::counting());
+        app
+    }
+
+    fn report(app: &App) -> &InvariantReport {
+        app.world().resource::<InvariantReport>()
+    }
+
+    #[test]
+    fn consistent_state_reports_no_violations() {
+        let mut app = counting_app();
+        let world = app.world_mut();
+        let familiar = world.spawn(Familiar::default()).id();
+        let stockpile = world
+            .spawn(Stockpile {
+                capacity: 2,
+                resource_type: None,
+            })
+            .id();
+        let item = world.spawn(DeliveringTo(stockpile)).id();
+        world.spawn(StoredIn(stockpile));
+        world.spawn((
+            DamnedSoul::default(),
+            AssignedTask::Haul(HaulData {
+                item,
+                stockpile,
+                phase: HaulPhase::GoingToItem,
+            }),
+            WorkingOn(item),
+            CommandedBy(familiar),
+        ));
+
+        app.update();
+
+        assert_eq!(report(&app).checks, 1);
+        assert_eq!(report(&app).total_violations, 0);
+    }
+
+    #[test]
+    fn broken_relationships_are_counted_per_rule() {
+        let mut app = counting_app();
+        let world = app.world_mut();
+        let target = world.spawn_empty().id();
+        let familiar = world.spawn((Familiar::default(), WorkingOn(target))).id();
+        world.spawn((DamnedSoul::default(), AssignedTask::None, WorkingOn(target)));
+        world.spawn((
+            DamnedSoul::default(),
+            AssignedTask::None,
+            CommandedBy(target),
+        ));
+        world.spawn(DeliveringTo(target));
+        let stockpile = world
+            .spawn(Stockpile {
+                capacity: 1,
+                resource_type: None,
+            })
+            .id();
+        world.spawn_batch([StoredIn(stockpile), StoredIn(stockpile)]);
+        let soul = world.spawn(DamnedSoul::default()).id();
+        for _ in 0..2 {
+            world.trigger(OnTaskCompleted {
+                entity: soul,
+                assignment_entity: soul,
+                current_target_entity: target,
+                current_work_type: WorkType::Mine,
+            });
+        }
+
+        app.update();
+
+        let report = report(&app);
+        assert_eq!(report.total_violations, 6);
+        for rule in InvariantRule::ALL {
+            assert_eq!(report.violations(rule), 1, "{}", rule.as_str());
+        }
+        assert!(
+            report
+                .recent
+                .iter()
+                .any(|violation| violation.rule == InvariantRule::FamiliarWorking
+                    && violation.entity == familiar)
+        );
+
+        // 発火回数の集計はフレームごとにリセットされる。
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<InvariantReport>()
+                .violations(InvariantRule::DuplicateTaskCompletion),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "I-S1 working-on-without-task")]
+    fn panicking_policy_fails_on_the_first_violation() {
+        let mut app = minimal_app();
+        app.add_plugins(InvariantCheckPlugin::panicking());
+        let target = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .spawn((DamnedSoul::default(), AssignedTask::None, WorkingOn(target)));
+
+        app.update();
+    }
+}
@@ -1,5 +1,6 @@
 use super::super::{
-    Blueprint, BonePile, BridgeMarker, BuildingType, MudMixerStorage, RestArea, SandPile, TaskSlots,
+    Blueprint, BonePile, BridgeMarker, BuildingType, MudMixerStorage, RedemptionShrine, RestArea,
+    SandPile, TaskSlots,
 };
 use crate::assets::GameAssets;
 use crate::world::map::WorldMap;
@@ -68,6 +69,10 @@ pub(super) fn apply_building_specific_post_process(
         setup_outdoor_lamp(commands, building_entity);
     }
 
+    if bp.kind == BuildingType::RedemptionShrine {
+        commands.entity(building_entity).insert(RedemptionShrine);
+    }
+
     spawn_completion_text(commands, transform, game_assets);
 }
 
@@ -104,6 +104,7 @@ pub(crate) fn attach_building_shell(
         ),
         BuildingType::SoulSpa => (game_assets.rest_area.clone(), Vec2::splat(TILE_SIZE * 2.0)),
         BuildingType::OutdoorLamp => (game_assets.bone_pile.clone(), Vec2::splat(TILE_SIZE)),
+        BuildingType::RedemptionShrine => (game_assets.rest_area.clone(), Vec2::splat(TILE_SIZE)),
     };
 
     commands
@@ -209,7 +210,8 @@ fn spawn_building_3d_visual(
         BuildingType::SandPile
         | BuildingType::BonePile
         | BuildingType::WheelbarrowParking
-        | BuildingType::OutdoorLamp => {
+        | BuildingType::OutdoorLamp
+        | BuildingType::RedemptionShrine => {
             let transform_3d = Transform::from_xyz(pos2d.x, TILE_SIZE * 0.3, -pos2d.y);
             commands.spawn((
                 Mesh3d(handles_3d.equipment_1x1_mesh.clone()),
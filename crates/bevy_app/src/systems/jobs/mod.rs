@@ -14,7 +14,8 @@ pub use hw_jobs::model::{
     Blueprint, BlueprintCancelRequested, BonePile, BridgeMarker, Building, BuildingCategory,
     BuildingType, Designation, FlexibleMaterialRequirement, IssuedBy, MovePlanned,
     ObstaclePosition, ObstacleSourceKind, PlayerIssuedDesignation, Priority, ProvisionalWall,
-    RestArea, Rock, SandPile, TargetBlueprint, TaskSlots, Tree, TreeVariant, WorkType,
+    RedemptionShrine, RestArea, Rock, SandPile, TargetBlueprint, TaskSlots, Tree, TreeVariant,
+    WorkType,
 };
 pub use hw_jobs::mud_mixer::{MudMixerStorage, StoredByMixer, TargetMixer};
 pub use hw_jobs::remove_tile_task_components;
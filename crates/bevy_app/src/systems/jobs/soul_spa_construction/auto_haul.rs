@@ -29,7 +29,7 @@ pub fn soul_spa_auto_haul_system(
     let active_familiars: Vec<(Entity, AreaBounds)> = q_familiars
         .iter()
         .filter(|(_, active_cmd, _)| !matches!(active_cmd.command, FamiliarCommand::Idle))
-        .map(|(e, _, area)| (e, area.bounds()))
+        .flat_map(|(e, _, area)| area.rects().map(move |rect| (e, rect.clone())))
         .collect();
 
     let active_yards: Vec<(Entity, Yard)> = q_yards.iter().map(|(e, y)| (e, y.clone())).collect();
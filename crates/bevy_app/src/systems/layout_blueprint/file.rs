@@ -0,0 +1,173 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::model::{LAYOUT_BLUEPRINT_VERSION, LayoutBlueprint};
+
+/// 設計図ファイルの既定保存先（作業ディレクトリ相対）
+pub const LAYOUT_BLUEPRINT_DIR: &str = "blueprints";
+pub const LAYOUT_BLUEPRINT_EXTENSION: &str = "ron";
+
+#[derive(Debug)]
+pub enum LayoutBlueprintFileError {
+    Io(io::Error),
+    Parse(String),
+    UnsupportedVersion(u32),
+    Empty,
+}
+
+impl fmt::Display for LayoutBlueprintFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Parse(err) => write!(f, "invalid layout blueprint: {err}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "layout blueprint version {version} is newer than supported version {LAYOUT_BLUEPRINT_VERSION}"
+            ),
+            Self::Empty => write!(f, "layout blueprint has no pieces"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutBlueprintFileError {}
+
+impl From<io::Error> for LayoutBlueprintFileError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+pub fn layout_blueprint_to_ron(blueprint: &LayoutBlueprint) -> Result<String, String> {
+    ron::ser::to_string_pretty(blueprint, ron::ser::PrettyConfig::default())
+        .map_err(|err| err.to_string())
+}
+
+/// RON 文字列を設計図として読む。ファイル由来の座標は信用せず再正規化する。
+pub fn parse_layout_blueprint(contents: &str) -> Result<LayoutBlueprint, LayoutBlueprintFileError> {
+    let parsed = ron::from_str::<LayoutBlueprint>(contents)
+        .map_err(|err| LayoutBlueprintFileError::Parse(err.to_string()))?;
+    if parsed.version > LAYOUT_BLUEPRINT_VERSION {
+        return Err(LayoutBlueprintFileError::UnsupportedVersion(parsed.version));
+    }
+    let blueprint = LayoutBlueprint::from_absolute(
+        parsed.buildings,
+        parsed.floors,
+        parsed.walls,
+        parsed.stockpiles,
+    );
+    if blueprint.is_empty() {
+        return Err(LayoutBlueprintFileError::Empty);
+    }
+    Ok(blueprint)
+}
+
+/// `dir/<name>.ron` へ書き出す。既存ファイルは上書きする。
+pub fn write_layout_blueprint(
+    dir: &Path,
+    name: &str,
+    blueprint: &LayoutBlueprint,
+) -> Result<PathBuf, LayoutBlueprintFileError> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(name).with_extension(LAYOUT_BLUEPRINT_EXTENSION);
+    let contents = layout_blueprint_to_ron(blueprint).map_err(LayoutBlueprintFileError::Parse)?;
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+pub fn read_layout_blueprint(path: &Path) -> Result<LayoutBlueprint, LayoutBlueprintFileError> {
+    parse_layout_blueprint(&fs::read_to_string(path)?)
+}
+
+/// `dir` 内で最も新しく更新された `.ron` を探す。ディレクトリが無ければ `None`。
+pub fn latest_layout_blueprint_path(dir: &Path) -> io::Result<Option<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let mut latest = None;
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(LAYOUT_BLUEPRINT_EXTENSION) {
+            continue;
+        }
+        let modified = entry.metadata()?.modified()?;
+        if latest
+            .as_ref()
+            .is_none_or(|(latest_modified, latest_path)| {
+                (modified, &path) > (*latest_modified, latest_path)
+            })
+        {
+            latest = Some((modified, path));
+        }
+    }
+    Ok(latest.map(|(_, path)| path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::layout_blueprint::model::{LayoutBuilding, LayoutBuildingKind};
+
+    fn blueprint() -> LayoutBlueprint {
+        LayoutBlueprint::from_absolute(
+            vec![LayoutBuilding {
+                kind: LayoutBuildingKind::MudMixer,
+                offset: (3, 3),
+                bucket_storage: Vec::new(),
+            }],
+            vec![(2, 3)],
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn blueprint_round_trips_through_ron() {
+        let blueprint = blueprint();
+        let text = layout_blueprint_to_ron(&blueprint).expect("serialize blueprint");
+        assert_eq!(
+            parse_layout_blueprint(&text).expect("parse blueprint"),
+            blueprint
+        );
+    }
+
+    #[test]
+    fn hand_edited_files_are_renormalized_and_validated() {
+        let shifted = "(version: 1, width: 9, height: 9, floors: [(5, 7), (6, 7)])";
+        let blueprint = parse_layout_blueprint(shifted).expect("parse shifted blueprint");
+        assert_eq!(blueprint.floors, vec![(0, 0), (1, 0)]);
+        assert_eq!((blueprint.width, blueprint.height), (2, 1));
+
+        assert!(matches!(
+            parse_layout_blueprint("(version: 99, width: 1, height: 1, floors: [(0, 0)])"),
+            Err(LayoutBlueprintFileError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            parse_layout_blueprint("(version: 1, width: 0, height: 0)"),
+            Err(LayoutBlueprintFileError::Empty)
+        ));
+    }
+
+    #[test]
+    fn write_then_find_latest_blueprint() {
+        let directory = std::env::temp_dir().join(format!(
+            "hell-workers-layout-blueprint-test-{}",
+            std::process::id()
+        ));
+        assert_eq!(latest_layout_blueprint_path(&directory).unwrap(), None);
+
+        let path = write_layout_blueprint(&directory, "yard", &blueprint()).expect("write");
+        assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some("ron"));
+        assert_eq!(
+            latest_layout_blueprint_path(&directory).unwrap(),
+            Some(path.clone())
+        );
+        assert_eq!(read_layout_blueprint(&path).expect("read"), blueprint());
+
+        fs::remove_dir_all(directory).expect("test directory should be removable");
+    }
+}
@@ -0,0 +1,39 @@
+//! 建築レイアウトの設計図（共有用 RON ファイル）
+//!
+//! 範囲内の建物・床・壁・Stockpile セルを相対座標で書き出し、別の場所や別のセーブへ
+//! 回転付きで一括配置する。配置は通常の Blueprint / 施工予定 / Stockpile 作成と同じ検証を通り、
+//! 置けない要素だけを飛ばす。
+
+mod file;
+mod model;
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+pub use file::{
+    LAYOUT_BLUEPRINT_DIR, LAYOUT_BLUEPRINT_EXTENSION, LayoutBlueprintFileError,
+    latest_layout_blueprint_path, layout_blueprint_to_ron, parse_layout_blueprint,
+    read_layout_blueprint, write_layout_blueprint,
+};
+pub use model::{
+    LAYOUT_BLUEPRINT_VERSION, LayoutBlueprint, LayoutBuilding, LayoutBuildingKind, LayoutCellKind,
+    LayoutPriority, LayoutResource, LayoutRotation, LayoutStockpile, LayoutStockpilePolicy,
+};
+
+/// `TaskMode::LayoutImport` で配置中の設計図と回転。
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ActiveLayoutBlueprint {
+    pub source: Option<PathBuf>,
+    pub blueprint: Option<LayoutBlueprint>,
+    pub rotation: LayoutRotation,
+}
+
+impl ActiveLayoutBlueprint {
+    /// 現在の回転を適用した設計図
+    pub fn rotated(&self) -> Option<LayoutBlueprint> {
+        self.blueprint
+            .as_ref()
+            .map(|blueprint| blueprint.rotated(self.rotation))
+    }
+}
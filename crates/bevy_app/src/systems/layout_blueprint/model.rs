@@ -0,0 +1,520 @@
+use hw_core::logistics::ResourceType;
+use hw_jobs::BuildingType;
+use hw_logistics::transport_request::TransportPriority;
+use hw_logistics::zone::{StockpileAcceptance, StockpilePolicy};
+use serde::{Deserialize, Serialize};
+
+/// 現在の設計図ファイルの版。これより新しい版は読み込まない。
+pub const LAYOUT_BLUEPRINT_VERSION: u32 = 1;
+
+/// 共有可能な建築レイアウト。座標はすべて占有セルの最小角を (0, 0) とした相対値。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutBlueprint {
+    pub version: u32,
+    pub width: i32,
+    pub height: i32,
+    #[serde(default)]
+    pub buildings: Vec<LayoutBuilding>,
+    #[serde(default)]
+    pub floors: Vec<(i32, i32)>,
+    #[serde(default)]
+    pub walls: Vec<(i32, i32)>,
+    #[serde(default)]
+    pub stockpiles: Vec<LayoutStockpile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutBuilding {
+    pub kind: LayoutBuildingKind,
+    /// 占有範囲の最小角
+    pub offset: (i32, i32),
+    /// Tank の付属 BucketStorage セル。Tank 以外では空
+    #[serde(default)]
+    pub bucket_storage: Vec<(i32, i32)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutStockpile {
+    pub offset: (i32, i32),
+    pub policy: LayoutStockpilePolicy,
+}
+
+/// 設計図に含められる建物。
+///
+/// Wall / Floor はタイル単位の施工予定として `walls` / `floors` に入る。
+/// Bridge は川の位置に、SoulSpa は Yard の電力網に縛られるため対象外。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutBuildingKind {
+    Door,
+    Tank,
+    MudMixer,
+    RestArea,
+    SandPile,
+    BonePile,
+    WheelbarrowParking,
+    OutdoorLamp,
+    RedemptionShrine,
+}
+
+impl LayoutBuildingKind {
+    pub const fn from_building_type(kind: BuildingType) -> Option<Self> {
+        match kind {
+            BuildingType::Door => Some(Self::Door),
+            BuildingType::Tank => Some(Self::Tank),
+            BuildingType::MudMixer => Some(Self::MudMixer),
+            BuildingType::RestArea => Some(Self::RestArea),
+            BuildingType::SandPile => Some(Self::SandPile),
+            BuildingType::BonePile => Some(Self::BonePile),
+            BuildingType::WheelbarrowParking => Some(Self::WheelbarrowParking),
+            BuildingType::OutdoorLamp => Some(Self::OutdoorLamp),
+            BuildingType::RedemptionShrine => Some(Self::RedemptionShrine),
+            BuildingType::Wall
+            | BuildingType::Floor
+            | BuildingType::Bridge
+            | BuildingType::SoulSpa => None,
+        }
+    }
+
+    pub const fn building_type(self) -> BuildingType {
+        match self {
+            Self::Door => BuildingType::Door,
+            Self::Tank => BuildingType::Tank,
+            Self::MudMixer => BuildingType::MudMixer,
+            Self::RestArea => BuildingType::RestArea,
+            Self::SandPile => BuildingType::SandPile,
+            Self::BonePile => BuildingType::BonePile,
+            Self::WheelbarrowParking => BuildingType::WheelbarrowParking,
+            Self::OutdoorLamp => BuildingType::OutdoorLamp,
+            Self::RedemptionShrine => BuildingType::RedemptionShrine,
+        }
+    }
+
+    /// 正方形の占有範囲の一辺（タイル数）
+    pub const fn footprint(self) -> i32 {
+        match self {
+            Self::Tank | Self::MudMixer | Self::RestArea | Self::WheelbarrowParking => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl LayoutBuilding {
+    pub fn occupied_cells(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        let size = self.kind.footprint();
+        (0..size)
+            .flat_map(move |dy| (0..size).map(move |dx| (self.offset.0 + dx, self.offset.1 + dy)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutResource {
+    Wood,
+    Rock,
+    Water,
+    BucketEmpty,
+    BucketWater,
+    Sand,
+    Bone,
+    StasisMud,
+    Wheelbarrow,
+}
+
+impl From<ResourceType> for LayoutResource {
+    fn from(resource: ResourceType) -> Self {
+        match resource {
+            ResourceType::Wood => Self::Wood,
+            ResourceType::Rock => Self::Rock,
+            ResourceType::Water => Self::Water,
+            ResourceType::BucketEmpty => Self::BucketEmpty,
+            ResourceType::BucketWater => Self::BucketWater,
+            ResourceType::Sand => Self::Sand,
+            ResourceType::Bone => Self::Bone,
+            ResourceType::StasisMud => Self::StasisMud,
+            ResourceType::Wheelbarrow => Self::Wheelbarrow,
+        }
+    }
+}
+
+impl From<LayoutResource> for ResourceType {
+    fn from(resource: LayoutResource) -> Self {
+        match resource {
+            LayoutResource::Wood => Self::Wood,
+            LayoutResource::Rock => Self::Rock,
+            LayoutResource::Water => Self::Water,
+            LayoutResource::BucketEmpty => Self::BucketEmpty,
+            LayoutResource::BucketWater => Self::BucketWater,
+            LayoutResource::Sand => Self::Sand,
+            LayoutResource::Bone => Self::Bone,
+            LayoutResource::StasisMud => Self::StasisMud,
+            LayoutResource::Wheelbarrow => Self::Wheelbarrow,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutPriority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+impl From<TransportPriority> for LayoutPriority {
+    fn from(priority: TransportPriority) -> Self {
+        match priority {
+            TransportPriority::Low => Self::Low,
+            TransportPriority::Normal => Self::Normal,
+            TransportPriority::High => Self::High,
+            TransportPriority::Critical => Self::Critical,
+        }
+    }
+}
+
+impl From<LayoutPriority> for TransportPriority {
+    fn from(priority: LayoutPriority) -> Self {
+        match priority {
+            LayoutPriority::Low => Self::Low,
+            LayoutPriority::Normal => Self::Normal,
+            LayoutPriority::High => Self::High,
+            LayoutPriority::Critical => Self::Critical,
+        }
+    }
+}
+
+/// `StockpilePolicy` のファイル表現。受け入れは資源名の一覧で持つ。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutStockpilePolicy {
+    pub accepts: Vec<LayoutResource>,
+    pub inbound_priority: LayoutPriority,
+    pub target_amount: usize,
+    pub allow_export: bool,
+}
+
+impl From<StockpilePolicy> for LayoutStockpilePolicy {
+    fn from(policy: StockpilePolicy) -> Self {
+        Self {
+            accepts: policy
+                .acceptance
+                .accepted_resources()
+                .map(LayoutResource::from)
+                .collect(),
+            inbound_priority: policy.inbound_priority.into(),
+            target_amount: policy.target_amount,
+            allow_export: policy.allow_export,
+        }
+    }
+}
+
+impl From<&LayoutStockpilePolicy> for StockpilePolicy {
+    fn from(policy: &LayoutStockpilePolicy) -> Self {
+        let acceptance = policy
+            .accepts
+            .iter()
+            .fold(StockpileAcceptance::none(), |acceptance, &resource| {
+                acceptance.with_resource(resource.into(), true)
+            });
+        Self {
+            acceptance: acceptance.normalized(),
+            inbound_priority: policy.inbound_priority.into(),
+            target_amount: policy.target_amount,
+            allow_export: policy.allow_export,
+        }
+    }
+}
+
+/// 配置時の回転（時計回り）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutRotation {
+    #[default]
+    R0,
+    R90,
+    R180,
+    R270,
+}
+
+impl LayoutRotation {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::R0 => Self::R90,
+            Self::R90 => Self::R180,
+            Self::R180 => Self::R270,
+            Self::R270 => Self::R0,
+        }
+    }
+
+    pub const fn degrees(self) -> u32 {
+        match self {
+            Self::R0 => 0,
+            Self::R90 => 90,
+            Self::R180 => 180,
+            Self::R270 => 270,
+        }
+    }
+
+    /// グリッドは y 上向きなので、時計回り 90° は (x, y) -> (y, -x)
+    pub const fn rotate_cell(self, (x, y): (i32, i32)) -> (i32, i32) {
+        match self {
+            Self::R0 => (x, y),
+            Self::R90 => (y, -x),
+            Self::R180 => (-x, -y),
+            Self::R270 => (-y, x),
+        }
+    }
+}
+
+/// ゴースト表示で色分けするセル種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutCellKind {
+    Building,
+    BucketStorage,
+    Floor,
+    Wall,
+    Stockpile,
+}
+
+impl LayoutBlueprint {
+    /// 絶対座標で集めた要素から、最小角を原点に正規化した設計図を作る。
+    pub fn from_absolute(
+        buildings: Vec<LayoutBuilding>,
+        floors: Vec<(i32, i32)>,
+        walls: Vec<(i32, i32)>,
+        stockpiles: Vec<LayoutStockpile>,
+    ) -> Self {
+        Self {
+            version: LAYOUT_BLUEPRINT_VERSION,
+            width: 0,
+            height: 0,
+            buildings,
+            floors,
+            walls,
+            stockpiles,
+        }
+        .normalized()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buildings.is_empty()
+            && self.floors.is_empty()
+            && self.walls.is_empty()
+            && self.stockpiles.is_empty()
+    }
+
+    /// 要素数（付属 BucketStorage は親 Tank に含める）
+    pub fn piece_count(&self) -> usize {
+        self.buildings.len() + self.floors.len() + self.walls.len() + self.stockpiles.len()
+    }
+
+    pub fn occupied_cells(&self) -> Vec<(LayoutCellKind, (i32, i32))> {
+        let mut cells = Vec::new();
+        cells.extend(
+            self.floors
+                .iter()
+                .map(|&cell| (LayoutCellKind::Floor, cell)),
+        );
+        cells.extend(self.walls.iter().map(|&cell| (LayoutCellKind::Wall, cell)));
+        cells.extend(
+            self.stockpiles
+                .iter()
+                .map(|stockpile| (LayoutCellKind::Stockpile, stockpile.offset)),
+        );
+        for building in &self.buildings {
+            cells.extend(
+                building
+                    .occupied_cells()
+                    .map(|cell| (LayoutCellKind::Building, cell)),
+            );
+            cells.extend(
+                building
+                    .bucket_storage
+                    .iter()
+                    .map(|&cell| (LayoutCellKind::BucketStorage, cell)),
+            );
+        }
+        cells
+    }
+
+    /// 回転した設計図を返す。結果は再び最小角が (0, 0) になる。
+    pub fn rotated(&self, rotation: LayoutRotation) -> Self {
+        let rotate = |cell| rotation.rotate_cell(cell);
+        let buildings = self
+            .buildings
+            .iter()
+            .map(|building| LayoutBuilding {
+                kind: building.kind,
+                offset: min_cell(building.occupied_cells().map(rotate))
+                    .expect("building footprint is never empty"),
+                bucket_storage: building
+                    .bucket_storage
+                    .iter()
+                    .copied()
+                    .map(rotate)
+                    .collect(),
+            })
+            .collect();
+        let stockpiles = self
+            .stockpiles
+            .iter()
+            .map(|stockpile| LayoutStockpile {
+                offset: rotate(stockpile.offset),
+                policy: stockpile.policy.clone(),
+            })
+            .collect();
+        Self {
+            version: self.version,
+            width: 0,
+            height: 0,
+            buildings,
+            floors: self.floors.iter().copied().map(rotate).collect(),
+            walls: self.walls.iter().copied().map(rotate).collect(),
+            stockpiles,
+        }
+        .normalized()
+    }
+
+    fn normalized(mut self) -> Self {
+        let cells = self.occupied_cells();
+        let Some(min) = min_cell(cells.iter().map(|&(_, cell)| cell)) else {
+            self.width = 0;
+            self.height = 0;
+            return self;
+        };
+        let max = cells
+            .iter()
+            .fold(min, |max, &(_, (x, y))| (max.0.max(x), max.1.max(y)));
+        let shift = |(x, y): (i32, i32)| (x - min.0, y - min.1);
+
+        for building in &mut self.buildings {
+            building.offset = shift(building.offset);
+            for cell in &mut building.bucket_storage {
+                *cell = shift(*cell);
+            }
+            building.bucket_storage.sort_unstable();
+        }
+        for cell in self.floors.iter_mut().chain(self.walls.iter_mut()) {
+            *cell = shift(*cell);
+        }
+        for stockpile in &mut self.stockpiles {
+            stockpile.offset = shift(stockpile.offset);
+        }
+
+        self.buildings.sort_by_key(|building| building.offset);
+        self.floors.sort_unstable();
+        self.walls.sort_unstable();
+        self.stockpiles.sort_by_key(|stockpile| stockpile.offset);
+        self.width = max.0 - min.0 + 1;
+        self.height = max.1 - min.1 + 1;
+        self
+    }
+}
+
+fn min_cell(cells: impl Iterator<Item = (i32, i32)>) -> Option<(i32, i32)> {
+    cells.reduce(|min, (x, y)| (min.0.min(x), min.1.min(y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> LayoutBlueprint {
+        LayoutBlueprint::from_absolute(
+            vec![
+                LayoutBuilding {
+                    kind: LayoutBuildingKind::Tank,
+                    offset: (12, 20),
+                    bucket_storage: vec![(14, 20), (15, 20)],
+                },
+                LayoutBuilding {
+                    kind: LayoutBuildingKind::Door,
+                    offset: (11, 22),
+                    bucket_storage: Vec::new(),
+                },
+            ],
+            vec![(10, 21), (10, 22)],
+            vec![(11, 21), (11, 23)],
+            vec![LayoutStockpile {
+                offset: (13, 23),
+                policy: StockpilePolicy::for_capacity(10).into(),
+            }],
+        )
+    }
+
+    #[test]
+    fn absolute_cells_are_normalized_to_the_minimum_corner() {
+        let blueprint = sample();
+        assert_eq!((blueprint.width, blueprint.height), (6, 4));
+        assert_eq!(blueprint.floors, vec![(0, 1), (0, 2)]);
+        assert_eq!(blueprint.buildings[0].kind, LayoutBuildingKind::Door);
+        assert_eq!(blueprint.buildings[1].offset, (2, 0));
+        assert_eq!(blueprint.buildings[1].bucket_storage, vec![(4, 0), (5, 0)]);
+        assert_eq!(blueprint.stockpiles[0].offset, (3, 3));
+    }
+
+    #[test]
+    fn quarter_turn_swaps_extent_and_keeps_square_footprints_anchored() {
+        let blueprint = sample();
+        let rotated = blueprint.rotated(LayoutRotation::R90);
+
+        assert_eq!((rotated.width, rotated.height), (4, 6));
+        assert_eq!(rotated.piece_count(), blueprint.piece_count());
+        let tank = rotated
+            .buildings
+            .iter()
+            .find(|building| building.kind == LayoutBuildingKind::Tank)
+            .unwrap();
+        let cells: Vec<_> = tank.occupied_cells().collect();
+        assert!(cells.iter().all(|&(x, y)| x >= 0 && y >= 0));
+        assert_eq!(tank.bucket_storage.len(), 2);
+        assert_eq!(tank.bucket_storage[0].0, tank.bucket_storage[1].0);
+    }
+
+    #[test]
+    fn four_quarter_turns_return_the_original_layout() {
+        let blueprint = sample();
+        let mut rotation = LayoutRotation::R0;
+        let mut rotated = blueprint.clone();
+        for _ in 0..4 {
+            rotation = rotation.next();
+            rotated = rotated.rotated(LayoutRotation::R90);
+        }
+        assert_eq!(rotation, LayoutRotation::R0);
+        assert_eq!(rotated, blueprint);
+        assert_eq!(
+            blueprint
+                .rotated(LayoutRotation::R180)
+                .rotated(LayoutRotation::R180),
+            blueprint
+        );
+    }
+
+    #[test]
+    fn stockpile_policy_survives_the_file_representation() {
+        let policy = StockpilePolicy {
+            acceptance: StockpileAcceptance::Only(ResourceType::Wood)
+                .with_resource(ResourceType::Rock, true),
+            inbound_priority: TransportPriority::High,
+            target_amount: 6,
+            allow_export: false,
+        };
+        let file = LayoutStockpilePolicy::from(policy);
+        assert_eq!(
+            file.accepts,
+            vec![LayoutResource::Wood, LayoutResource::Rock]
+        );
+        assert_eq!(StockpilePolicy::from(&file), policy);
+    }
+
+    #[test]
+    fn building_kinds_excluded_from_layouts_have_no_file_kind() {
+        for kind in [
+            BuildingType::Wall,
+            BuildingType::Floor,
+            BuildingType::Bridge,
+            BuildingType::SoulSpa,
+        ] {
+            assert_eq!(LayoutBuildingKind::from_building_type(kind), None);
+        }
+        let kind = LayoutBuildingKind::from_building_type(BuildingType::MudMixer).unwrap();
+        assert_eq!(kind.building_type(), BuildingType::MudMixer);
+        assert_eq!(kind.footprint(), 2);
+    }
+}
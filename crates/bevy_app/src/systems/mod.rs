@@ -1,12 +1,19 @@
+pub mod alerts;
+pub mod analytics;
 pub mod command;
+pub mod contracts;
 pub mod dream_tree_planting;
+pub mod edicts;
 pub mod energy;
 pub mod familiar_ai;
+pub mod invariants;
 pub mod jobs;
+pub mod layout_blueprint;
 pub mod logistics;
 pub mod save;
 pub mod settings;
 pub mod soul_ai;
+pub mod statistics;
 pub mod time;
 pub mod visual;
 
@@ -0,0 +1,44 @@
+//! DynamicWorld body の符号化（`SaveBodyEncoding`）。
+//!
+//! RON は人が読める既定の形式で、postcard + zstd は大きな colony 向けの opt-in。どちらも同じ
+//! `DynamicWorldSerializer` / `WorldDeserializer` を通すため、復元される DynamicWorld は一致する。
+
+use std::io::Read;
+
+use bevy::reflect::TypeRegistry;
+use bevy_world_serialization::DynamicWorld;
+use bevy_world_serialization::serde::DynamicWorldSerializer;
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+use super::format::SaveBodyEncoding;
+
+/// `encoding` で body を直列化する。header は含まない。
+pub(super) fn serialize_body(
+    dynamic_world: &DynamicWorld,
+    registry: &TypeRegistry,
+    encoding: SaveBodyEncoding,
+) -> Result<Vec<u8>, String> {
+    match encoding {
+        SaveBodyEncoding::Ron => dynamic_world
+            .serialize(registry)
+            .map(String::into_bytes)
+            .map_err(|error| error.to_string()),
+        SaveBodyEncoding::PostcardZstd => {
+            let bytes = postcard::to_stdvec(&DynamicWorldSerializer::new(dynamic_world, registry))
+                .map_err(|error| error.to_string())?;
+            Ok(compress_to_vec(bytes.as_slice(), CompressionLevel::Fastest))
+        }
+    }
+}
+
+/// zstd frame を展開し、postcard の byte 列を返す。
+pub(super) fn decompress_binary_body(body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut source = body;
+    let mut decoder = StreamingDecoder::new(&mut source).map_err(|error| error.to_string())?;
+    let mut bytes = Vec::new();
+    decoder
+        .read_to_end(&mut bytes)
+        .map_err(|error| error.to_string())?;
+    Ok(bytes)
+}
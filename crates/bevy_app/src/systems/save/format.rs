@@ -2,15 +2,35 @@
 
 use std::fmt;
 
+use hw_world::MapTemplate;
 use serde::{Deserialize, Serialize};
 
+use super::migration::{LEGACY_V0_BODY_VERSION, OLDEST_MIGRATABLE_VERSION};
+
 pub const SAVE_MAGIC: &str = "HELL_WORKERS_SAVE";
-pub const CURRENT_SAVE_FORMAT_VERSION: u32 = 1;
+pub const CURRENT_SAVE_FORMAT_VERSION: u32 = 6;
+
+/// header 以降の body の符号化。
+///
+/// body の形（`format_version`）とは独立しており、どちらの符号化でも同じ DynamicWorld を表す。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SaveBodyEncoding {
+    /// 人が読める DynamicWorld RON。新規セーブの既定で、`body_encoding` を持たない header はこれとして読む。
+    #[default]
+    Ron,
+    /// DynamicWorld を postcard で直列化し、zstd frame で包んだもの。current version でしか読めない。
+    PostcardZstd,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SaveHeader {
     pub format_version: u32,
     pub worldgen_seed: u64,
+    #[serde(default)]
+    pub body_encoding: SaveBodyEncoding,
+    /// 生成に使ったマップテンプレート。`map_template` を持たない header は `Classic` として読む。
+    #[serde(default)]
+    pub map_template: MapTemplate,
 }
 
 impl SaveHeader {
@@ -18,21 +38,67 @@ impl SaveHeader {
         Self {
             format_version: CURRENT_SAVE_FORMAT_VERSION,
             worldgen_seed,
+            body_encoding: SaveBodyEncoding::Ron,
+            map_template: MapTemplate::Classic,
         }
     }
+
+    pub const fn with_body_encoding(mut self, body_encoding: SaveBodyEncoding) -> Self {
+        self.body_encoding = body_encoding;
+        self
+    }
+
+    pub const fn with_map_template(mut self, map_template: MapTemplate) -> Self {
+        self.map_template = map_template;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SaveFormat {
     /// Header 導入前の DynamicWorld RON。seed は body 内の legacy Resource から読む。
     LegacyV0,
-    V1(SaveHeader),
+    /// 外部 header 付き。旧 version の body は deserialize 前に migration で現在の形へ上げる。
+    Versioned(SaveHeader),
+}
+
+impl SaveFormat {
+    /// ファイルが宣言する format version。legacy v0 は 0。
+    pub const fn version(self) -> u32 {
+        match self {
+            Self::LegacyV0 => 0,
+            Self::Versioned(header) => header.format_version,
+        }
+    }
+
+    /// migration pipeline の入口になる body version。
+    pub const fn body_version(self) -> u32 {
+        match self {
+            Self::LegacyV0 => LEGACY_V0_BODY_VERSION,
+            Self::Versioned(header) => header.format_version,
+        }
+    }
+
+    /// body の符号化。legacy v0 は常に RON。
+    pub const fn body_encoding(self) -> SaveBodyEncoding {
+        match self {
+            Self::LegacyV0 => SaveBodyEncoding::Ron,
+            Self::Versioned(header) => header.body_encoding,
+        }
+    }
+}
+
+/// header が宣言する符号化で切り出した body。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveBody<'a> {
+    Ron(&'a str),
+    PostcardZstd(&'a [u8]),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DecodedSaveFile<'a> {
     pub format: SaveFormat,
-    pub body: &'a str,
+    pub body: SaveBody<'a>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -40,7 +106,15 @@ pub enum SaveFormatError {
     MissingHeaderLineBreak,
     MissingBodySeparator,
     InvalidHeader(String),
-    UnsupportedVersion { found: u32, current: u32 },
+    UnsupportedVersion {
+        found: u32,
+        current: u32,
+    },
+    BodyNotUtf8,
+    /// migration は RON の木の上で動くため、旧 version の binary body は上げられない。
+    UnmigratableBinaryBody {
+        found: u32,
+    },
 }
 
 impl fmt::Display for SaveFormatError {
@@ -55,56 +129,98 @@ impl fmt::Display for SaveFormatError {
                 formatter,
                 "unsupported save format version {found} (current version is {current})"
             ),
+            Self::BodyNotUtf8 => formatter.write_str("RON save body is not valid UTF-8"),
+            Self::UnmigratableBinaryBody { found } => write!(
+                formatter,
+                "binary save body v{found} cannot be migrated; only RON bodies are upgraded"
+            ),
         }
     }
 }
 
 impl std::error::Error for SaveFormatError {}
 
-/// Encodes a v1 file without involving the DynamicWorld type registry.
-pub fn encode_save_file(header: SaveHeader, body: &str) -> String {
-    format!(
-        "{SAVE_MAGIC}\n(format_version: {}, worldgen_seed: {})\n---\n{body}",
+/// Encodes a headered file without involving the DynamicWorld type registry.
+///
+/// `body_encoding` is written only for non-RON bodies and `map_template` only for
+/// non-Classic maps so that the default saves keep the header shape older builds can read.
+pub fn encode_save_file(header: SaveHeader, body: &[u8]) -> Vec<u8> {
+    let encoding = match header.body_encoding {
+        SaveBodyEncoding::Ron => String::new(),
+        encoding => format!(", body_encoding: {encoding:?}"),
+    };
+    let template = match header.map_template {
+        MapTemplate::Classic => String::new(),
+        template => format!(", map_template: {template:?}"),
+    };
+    let mut contents = format!(
+        "{SAVE_MAGIC}\n(format_version: {}, worldgen_seed: {}{encoding}{template})\n---\n",
         header.format_version, header.worldgen_seed
     )
+    .into_bytes();
+    contents.extend_from_slice(body);
+    contents
 }
 
 /// Classifies a save before its DynamicWorld body is deserialized.
 ///
 /// Magic-less files are the only legacy v0 form accepted. A file that declares
-/// a header must use the current version and a valid separator.
-pub fn decode_save_file(contents: &str) -> Result<DecodedSaveFile<'_>, SaveFormatError> {
-    let Some(after_magic) = contents.strip_prefix(SAVE_MAGIC) else {
+/// a header must use a version the migration pipeline can upgrade and a valid
+/// separator. The body is split according to the header's `body_encoding`;
+/// binary bodies are accepted only at the current version.
+pub fn decode_save_file(contents: &[u8]) -> Result<DecodedSaveFile<'_>, SaveFormatError> {
+    let Some(after_magic) = contents.strip_prefix(SAVE_MAGIC.as_bytes()) else {
         return Ok(DecodedSaveFile {
             format: SaveFormat::LegacyV0,
-            body: contents,
+            body: SaveBody::Ron(ron_body(contents)?),
         });
     };
 
     let header_and_body = after_magic
-        .strip_prefix("\r\n")
-        .or_else(|| after_magic.strip_prefix('\n'))
+        .strip_prefix(b"\r\n")
+        .or_else(|| after_magic.strip_prefix(b"\n"))
         .ok_or(SaveFormatError::MissingHeaderLineBreak)?;
-    let (header_text, body) = header_and_body
-        .split_once("\n---\n")
-        .or_else(|| header_and_body.split_once("\r\n---\r\n"))
+    let (header_text, body) = split_once(header_and_body, b"\n---\n")
+        .or_else(|| split_once(header_and_body, b"\r\n---\r\n"))
         .ok_or(SaveFormatError::MissingBodySeparator)?;
+    let header_text = std::str::from_utf8(header_text)
+        .map_err(|error| SaveFormatError::InvalidHeader(error.to_string()))?;
     let header = ron::from_str::<SaveHeader>(header_text)
         .map_err(|error| SaveFormatError::InvalidHeader(error.to_string()))?;
 
-    if header.format_version != CURRENT_SAVE_FORMAT_VERSION {
+    if !(OLDEST_MIGRATABLE_VERSION..=CURRENT_SAVE_FORMAT_VERSION).contains(&header.format_version) {
         return Err(SaveFormatError::UnsupportedVersion {
             found: header.format_version,
             current: CURRENT_SAVE_FORMAT_VERSION,
         });
     }
 
+    let body = match header.body_encoding {
+        SaveBodyEncoding::Ron => SaveBody::Ron(ron_body(body)?),
+        SaveBodyEncoding::PostcardZstd if header.format_version < CURRENT_SAVE_FORMAT_VERSION => {
+            return Err(SaveFormatError::UnmigratableBinaryBody {
+                found: header.format_version,
+            });
+        }
+        SaveBodyEncoding::PostcardZstd => SaveBody::PostcardZstd(body),
+    };
     Ok(DecodedSaveFile {
-        format: SaveFormat::V1(header),
+        format: SaveFormat::Versioned(header),
         body,
     })
 }
 
+fn ron_body(body: &[u8]) -> Result<&str, SaveFormatError> {
+    std::str::from_utf8(body).map_err(|_| SaveFormatError::BodyNotUtf8)
+}
+
+fn split_once<'a>(bytes: &'a [u8], separator: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let index = bytes
+        .windows(separator.len())
+        .position(|window| window == separator)?;
+    Some((&bytes[..index], &bytes[index + separator.len()..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,21 +228,28 @@ mod tests {
     const INVALID_DYNAMIC_WORLD_BODY: &str = "this body is deliberately not DynamicWorld RON";
 
     #[test]
-    fn v1_header_is_decoded_without_reading_the_body() {
-        let encoded = encode_save_file(SaveHeader::current(42), INVALID_DYNAMIC_WORLD_BODY);
+    fn current_header_is_decoded_without_reading_the_body() {
+        let encoded = encode_save_file(
+            SaveHeader::current(42),
+            INVALID_DYNAMIC_WORLD_BODY.as_bytes(),
+        );
 
-        let decoded = decode_save_file(&encoded).expect("v1 header should decode");
+        let decoded = decode_save_file(&encoded).expect("current header should decode");
 
-        assert_eq!(decoded.format, SaveFormat::V1(SaveHeader::current(42)));
-        assert_eq!(decoded.body, INVALID_DYNAMIC_WORLD_BODY);
+        assert_eq!(
+            decoded.format,
+            SaveFormat::Versioned(SaveHeader::current(42))
+        );
+        assert_eq!(decoded.body, SaveBody::Ron(INVALID_DYNAMIC_WORLD_BODY));
     }
 
     #[test]
     fn magic_less_file_is_classified_as_legacy_v0() {
-        let decoded = decode_save_file(INVALID_DYNAMIC_WORLD_BODY).expect("legacy v0 is accepted");
+        let decoded =
+            decode_save_file(INVALID_DYNAMIC_WORLD_BODY.as_bytes()).expect("legacy v0 is accepted");
 
         assert_eq!(decoded.format, SaveFormat::LegacyV0);
-        assert_eq!(decoded.body, INVALID_DYNAMIC_WORLD_BODY);
+        assert_eq!(decoded.body, SaveBody::Ron(INVALID_DYNAMIC_WORLD_BODY));
     }
 
     #[test]
@@ -135,8 +258,10 @@ mod tests {
             SaveHeader {
                 format_version: CURRENT_SAVE_FORMAT_VERSION + 1,
                 worldgen_seed: 42,
+                body_encoding: SaveBodyEncoding::Ron,
+                map_template: MapTemplate::Classic,
             },
-            INVALID_DYNAMIC_WORLD_BODY,
+            INVALID_DYNAMIC_WORLD_BODY.as_bytes(),
         );
 
         assert_eq!(
@@ -148,13 +273,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn older_migratable_header_is_accepted_before_body_deserialization() {
+        let header = SaveHeader {
+            format_version: OLDEST_MIGRATABLE_VERSION,
+            worldgen_seed: 42,
+            body_encoding: SaveBodyEncoding::Ron,
+            map_template: MapTemplate::Classic,
+        };
+        let encoded = encode_save_file(header, INVALID_DYNAMIC_WORLD_BODY.as_bytes());
+
+        let decoded = decode_save_file(&encoded).expect("migratable header should decode");
+
+        assert_eq!(decoded.format, SaveFormat::Versioned(header));
+        assert_eq!(decoded.format.body_version(), OLDEST_MIGRATABLE_VERSION);
+    }
+
+    #[test]
+    fn header_older_than_the_migration_pipeline_is_rejected() {
+        let encoded = encode_save_file(
+            SaveHeader {
+                format_version: OLDEST_MIGRATABLE_VERSION - 1,
+                worldgen_seed: 42,
+                body_encoding: SaveBodyEncoding::Ron,
+                map_template: MapTemplate::Classic,
+            },
+            INVALID_DYNAMIC_WORLD_BODY.as_bytes(),
+        );
+
+        assert!(matches!(
+            decode_save_file(&encoded),
+            Err(SaveFormatError::UnsupportedVersion { .. })
+        ));
+    }
+
     #[test]
     fn malformed_header_is_rejected_before_body_deserialization() {
         let contents = format!("{SAVE_MAGIC}\nnot valid RON\n---\n{INVALID_DYNAMIC_WORLD_BODY}");
 
         assert!(matches!(
-            decode_save_file(&contents),
+            decode_save_file(contents.as_bytes()),
             Err(SaveFormatError::InvalidHeader(_))
         ));
     }
+
+    #[test]
+    fn ron_header_omits_the_encoding_field_and_still_reads_older_headers() {
+        let encoded = encode_save_file(SaveHeader::current(42), b"()");
+
+        assert!(encoded.starts_with(
+            format!(
+                "{SAVE_MAGIC}\n(format_version: {CURRENT_SAVE_FORMAT_VERSION}, worldgen_seed: 42)\n"
+            )
+            .as_bytes()
+        ));
+        assert_eq!(
+            decode_save_file(&encoded).unwrap().format,
+            SaveFormat::Versioned(SaveHeader::current(42))
+        );
+    }
+
+    #[test]
+    fn map_template_is_written_only_for_non_classic_maps() {
+        let header = SaveHeader::current(42).with_map_template(MapTemplate::TwinRivers);
+        let encoded = encode_save_file(header, b"()");
+
+        assert!(encoded.starts_with(
+            format!(
+                "{SAVE_MAGIC}\n(format_version: {CURRENT_SAVE_FORMAT_VERSION}, worldgen_seed: 42, map_template: TwinRivers)\n"
+            )
+            .as_bytes()
+        ));
+        assert_eq!(
+            decode_save_file(&encoded).unwrap().format,
+            SaveFormat::Versioned(header)
+        );
+    }
+
+    #[test]
+    fn binary_body_is_detected_from_the_header_and_kept_as_bytes() {
+        let body = [0x28, 0xb5, 0x2f, 0xfd, 0x00, 0xff, b'\n', b'-'];
+        let header = SaveHeader::current(42).with_body_encoding(SaveBodyEncoding::PostcardZstd);
+        let encoded = encode_save_file(header, &body);
+
+        let decoded = decode_save_file(&encoded).expect("binary header should decode");
+
+        assert_eq!(decoded.format, SaveFormat::Versioned(header));
+        assert_eq!(decoded.body, SaveBody::PostcardZstd(&body));
+    }
+
+    #[test]
+    fn binary_body_from_an_older_version_is_rejected() {
+        let header = SaveHeader {
+            format_version: OLDEST_MIGRATABLE_VERSION,
+            worldgen_seed: 42,
+            body_encoding: SaveBodyEncoding::PostcardZstd,
+            map_template: MapTemplate::Classic,
+        };
+
+        assert_eq!(
+            decode_save_file(&encode_save_file(header, &[0xff])),
+            Err(SaveFormatError::UnmigratableBinaryBody {
+                found: OLDEST_MIGRATABLE_VERSION
+            })
+        );
+        assert_eq!(
+            decode_save_file(&encode_save_file(SaveHeader::current(42), &[0xff])),
+            Err(SaveFormatError::BodyNotUtf8)
+        );
+    }
 }
@@ -0,0 +1,670 @@
+//! `hw_save_tool` 用のオフライン検査・修復（`docs/save_load.md`）。
+//!
+//! ファイルは header を解いて migration で現在の body へ上げ、[`SaveValue`] の木として扱う。
+//! binary body は registry で一度 DynamicWorld に戻し、RON の木へ直してから同じ扱いにする。
+//! 木の操作は type path の文字列だけで行うため、registry に無い型を含む壊れた body も読める。
+//! [`SaveFile::check`] だけはロードと同じ registry deserialize と schema 検証を、
+//! ウィンドウもゲームワールドも持たない headless `App` 上で実行する。
+
+mod json;
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+use bevy::asset::AssetPlugin;
+use bevy::ecs::reflect::AppTypeRegistry;
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use bevy_world_serialization::serde::WorldDeserializer;
+use hw_world::MapTemplate;
+
+use super::body::decompress_binary_body;
+use super::format::{
+    SaveBody, SaveBodyEncoding, SaveFormat, SaveFormatError, SaveHeader, decode_save_file,
+    encode_save_file,
+};
+use super::load::{LoadPreparationError, prepare_dynamic_world};
+use super::migration::{
+    MigrationError, SaveDocument, SaveValue, migrate_body, migration_path, ops,
+};
+use super::schema::{PersistedTypePaths, persisted_type_paths, register_save_types};
+use super::state::SavedWorldgenSeed;
+
+type Entries = [(SaveValue, SaveValue)];
+
+const ROOTLESS: &str = "(rootless)";
+
+#[derive(Debug)]
+pub enum InspectError {
+    Read(std::io::Error),
+    Format(SaveFormatError),
+    Migration(MigrationError),
+    Body(String),
+    NotFound(String),
+}
+
+impl fmt::Display for InspectError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(error) => write!(formatter, "failed to read save file: {error}"),
+            Self::Format(error) => write!(formatter, "invalid save format: {error}"),
+            Self::Migration(error) => write!(formatter, "save migration failed: {error}"),
+            Self::Body(reason) => write!(formatter, "unexpected save body shape: {reason}"),
+            Self::NotFound(query) => write!(formatter, "nothing in the save matches `{query}`"),
+        }
+    }
+}
+
+impl std::error::Error for InspectError {}
+
+/// header を解き、現在の version の body 木まで上げたセーブファイル。
+pub struct SaveFile {
+    contents: Vec<u8>,
+    format: SaveFormat,
+    upgrade_steps: Vec<&'static str>,
+    document: SaveDocument,
+}
+
+impl SaveFile {
+    pub fn read(path: &Path) -> Result<Self, InspectError> {
+        let contents = std::fs::read(path).map_err(InspectError::Read)?;
+        Self::from_contents(contents)
+    }
+
+    pub fn from_contents(contents: impl Into<Vec<u8>>) -> Result<Self, InspectError> {
+        let contents = contents.into();
+        let (format, upgrade_steps, document) = {
+            let decoded = decode_save_file(&contents).map_err(InspectError::Format)?;
+            let version = decoded.format.body_version();
+            let upgrade_steps = migration_path(version)
+                .map_err(InspectError::Migration)?
+                .into_iter()
+                .map(|step| step.summary)
+                .collect();
+            let body = match decoded.body {
+                SaveBody::Ron(body) => {
+                    migrate_body(version, body).map_err(InspectError::Migration)?
+                }
+                SaveBody::PostcardZstd(body) => Cow::Owned(binary_body_to_ron(body)?),
+            };
+            let document = SaveDocument::parse(&body)
+                .map_err(|error| InspectError::Migration(MigrationError::Syntax(error)))?;
+            (decoded.format, upgrade_steps, document)
+        };
+        let file = Self {
+            contents,
+            format,
+            upgrade_steps,
+            document,
+        };
+        file.resources()?;
+        for (_, entity) in file.entities()? {
+            components_of(entity)?;
+        }
+        Ok(file)
+    }
+
+    /// ファイルが宣言する format version。legacy v0 は 0。
+    pub fn format_version(&self) -> u32 {
+        self.format.version()
+    }
+
+    /// header の seed。legacy v0 は body 内の seed resource から読む。
+    pub fn worldgen_seed(&self) -> Option<u64> {
+        match self.format {
+            SaveFormat::Versioned(header) => Some(header.worldgen_seed),
+            SaveFormat::LegacyV0 => {
+                let value = self
+                    .resources()
+                    .ok()?
+                    .iter()
+                    .find(|(key, _)| key.as_str() == Some(SavedWorldgenSeed::type_path()))
+                    .map(|(_, value)| value)?;
+                let atom = match value {
+                    SaveValue::Tuple { name: None, items } => items.first()?,
+                    atom => atom,
+                };
+                let SaveValue::Atom(seed) = atom else {
+                    return None;
+                };
+                seed.replace('_', "").parse().ok()
+            }
+        }
+    }
+
+    /// header のマップテンプレート。legacy v0 はテンプレート導入前なので `Classic`。
+    pub fn map_template(&self) -> MapTemplate {
+        match self.format {
+            SaveFormat::Versioned(header) => header.map_template,
+            SaveFormat::LegacyV0 => MapTemplate::Classic,
+        }
+    }
+
+    fn resources(&self) -> Result<&Entries, InspectError> {
+        self.document
+            .root
+            .field("resources")
+            .and_then(SaveValue::map_entries)
+            .ok_or_else(|| InspectError::Body("body has no `resources` map".to_owned()))
+    }
+
+    fn entities(&self) -> Result<&Entries, InspectError> {
+        self.document
+            .root
+            .field("entities")
+            .and_then(SaveValue::map_entries)
+            .ok_or_else(|| InspectError::Body("body has no `entities` map".to_owned()))
+    }
+
+    /// 件数の要約。root 種別は各 entity が持つ最初の root marker で数える。
+    pub fn summary(&self) -> Result<SaveSummary, InspectError> {
+        let paths = persisted_type_paths();
+        let mut roots = BTreeMap::new();
+        let mut components = BTreeMap::new();
+        let entities = self.entities()?;
+        for (_, entity) in entities {
+            let entity_components = components_of(entity)?;
+            *roots
+                .entry(root_kind(entity_components, &paths).to_owned())
+                .or_insert(0) += 1;
+            for (key, _) in entity_components {
+                *components.entry(type_path_of(key)).or_insert(0) += 1;
+            }
+        }
+        Ok(SaveSummary {
+            format_version: self.format_version(),
+            worldgen_seed: self.worldgen_seed(),
+            map_template: self.map_template(),
+            body_encoding: self.format.body_encoding(),
+            upgrade_steps: self.upgrade_steps.clone(),
+            resources: self
+                .resources()?
+                .iter()
+                .map(|(key, _)| type_path_of(key))
+                .collect(),
+            entity_count: entities.len(),
+            roots,
+            components,
+        })
+    }
+
+    /// ロードと同じ registry deserialize と schema 検証を実行する。seed は照合しない。
+    pub fn check(&self) -> Result<(), CheckFailure> {
+        let app = headless_app();
+        let decoded = decode_save_file(&self.contents)
+            .map_err(|error| CheckFailure::Load(error.to_string()))?;
+        match prepare_dynamic_world(app.world(), decoded.format, decoded.body, |_| Ok(())) {
+            Ok(_) => Ok(()),
+            Err(LoadPreparationError::Schema(error)) => Err(CheckFailure::Schema(error.reasons())),
+            Err(error) => Err(CheckFailure::Load(error.to_string())),
+        }
+    }
+
+    /// `after` との差分を root 種別ごとに entity id で突き合わせる。
+    ///
+    /// entity id はセーブ時点の live id なので、同じセッションから書いたセーブ同士で
+    /// 最も意味を持つ。ロードを挟むと id が振り直され、追加/削除として現れる。
+    pub fn diff(&self, after: &SaveFile) -> Result<SaveDiff, InspectError> {
+        let paths = persisted_type_paths();
+        let resources = keyed_changes(&keyed(self.resources()?), &keyed(after.resources()?));
+
+        let before_entities = indexed_entities(self.entities()?, &paths)?;
+        let after_entities = indexed_entities(after.entities()?, &paths)?;
+        let mut roots: BTreeMap<String, RootDiff> = BTreeMap::new();
+        for (id, (kind, _)) in &before_entities {
+            roots.entry((*kind).to_owned()).or_default().before += 1;
+            match after_entities.get(id) {
+                Some((after_kind, _)) if after_kind == kind => {}
+                _ => roots
+                    .entry((*kind).to_owned())
+                    .or_default()
+                    .removed
+                    .push(id.clone()),
+            }
+        }
+        for (id, (kind, components)) in &after_entities {
+            let root = roots.entry((*kind).to_owned()).or_default();
+            root.after += 1;
+            match before_entities.get(id) {
+                Some((before_kind, before_components)) if before_kind == kind => {
+                    let changes = keyed_changes(before_components, components);
+                    if !changes.is_empty() {
+                        root.changed.push((id.clone(), changes));
+                    }
+                }
+                _ => root.added.push(id.clone()),
+            }
+        }
+
+        Ok(SaveDiff { resources, roots })
+    }
+
+    /// 一致する resource、または一致する component を持つ entity を丸ごと JSON で返す。
+    ///
+    /// 型は完全な type path か、末尾の型名（`Stockpile` など）で指定できる。
+    pub fn extract(&self, target: &ExtractTarget) -> Result<String, InspectError> {
+        let extracted = match target {
+            ExtractTarget::Resource(query) => {
+                let matches: Vec<_> = self
+                    .resources()?
+                    .iter()
+                    .filter(|(key, _)| matches_type(key, query))
+                    .cloned()
+                    .collect();
+                if matches.is_empty() {
+                    return Err(InspectError::NotFound(query.clone()));
+                }
+                SaveValue::Map(matches)
+            }
+            ExtractTarget::Component(query) => {
+                let mut matches = Vec::new();
+                for (id, entity) in self.entities()? {
+                    let components = components_of(entity)?;
+                    if components.iter().any(|(key, _)| matches_type(key, query)) {
+                        matches.push(SaveValue::unnamed_struct([
+                            ("entity", id.clone()),
+                            ("components", SaveValue::Map(components.to_vec())),
+                        ]));
+                    }
+                }
+                if matches.is_empty() {
+                    return Err(InspectError::NotFound(query.clone()));
+                }
+                SaveValue::Seq(matches)
+            }
+        };
+        Ok(json::to_json(&extracted))
+    }
+
+    /// schema の allow-list 外の resource/component と root marker を失った entity を取り除き、
+    /// 現在の format version の RON body のファイルとして書き直す。
+    ///
+    /// 取り除いた entity への参照は書き換えないため、結果は [`SaveFile::check`] で確かめる。
+    /// 欠けている必須 resource は補えない。
+    pub fn salvage(&self) -> Result<SalvageReport, InspectError> {
+        let worldgen_seed = self.worldgen_seed().ok_or_else(|| {
+            InspectError::Body("legacy v0 save has no worldgen seed to write into a header".into())
+        })?;
+        let paths = persisted_type_paths();
+        let mut document = self.document.clone();
+        let root = &mut document.root;
+
+        let removed_resources: Vec<String> = self
+            .resources()?
+            .iter()
+            .map(|(key, _)| type_path_of(key))
+            .filter(|path| !paths.resources.contains(&path.as_str()))
+            .collect();
+        for path in &removed_resources {
+            ops::remove_resource(root, path).map_err(InspectError::Body)?;
+        }
+
+        let mut unsupported = BTreeSet::new();
+        for (_, entity) in self.entities()? {
+            for (key, _) in components_of(entity)? {
+                let path = type_path_of(key);
+                if !paths.components.contains(&path.as_str()) {
+                    unsupported.insert(path);
+                }
+            }
+        }
+        let mut removed_components = BTreeMap::new();
+        for path in unsupported {
+            let removed = ops::remove_component(root, &path).map_err(InspectError::Body)?;
+            removed_components.insert(path, removed);
+        }
+
+        let mut removed_entities = Vec::new();
+        if let Some(entities) = root
+            .field_mut("entities")
+            .and_then(SaveValue::map_entries_mut)
+        {
+            entities.retain(|(id, entity)| {
+                let components = entity
+                    .field("components")
+                    .and_then(SaveValue::map_entries)
+                    .unwrap_or_default();
+                let rooted = root_kind(components, &paths) != ROOTLESS;
+                if !rooted {
+                    removed_entities.push(entity_id(id));
+                }
+                rooted
+            });
+        }
+
+        Ok(SalvageReport {
+            removed_resources,
+            removed_components,
+            removed_entities,
+            contents: encode_save_file(
+                SaveHeader::current(worldgen_seed).with_map_template(self.map_template()),
+                document.to_ron().as_bytes(),
+            ),
+        })
+    }
+}
+
+/// ロードと同じ型登録だけを持つ、ウィンドウもゲームワールドも無い `App`。
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AssetPlugin::default());
+    register_save_types(&mut app);
+    app
+}
+
+/// binary body を RON の body へ直す。postcard は型を自己記述しないため registry を通す。
+///
+/// binary body は現在の version でしか書かれないので migration は要らない。registry に無い型を
+/// 含む binary body はここで読めなくなる。
+fn binary_body_to_ron(body: &[u8]) -> Result<String, InspectError> {
+    use serde::de::DeserializeSeed;
+
+    let app = headless_app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let mut asset_server = app.world().resource::<AssetServer>().clone();
+    let bytes = decompress_binary_body(body).map_err(InspectError::Body)?;
+    let dynamic_world = WorldDeserializer {
+        type_registry: &registry,
+        load_from_path: &mut asset_server,
+    }
+    .deserialize(&mut postcard::Deserializer::from_bytes(&bytes))
+    .map_err(|error| InspectError::Body(error.to_string()))?;
+    dynamic_world
+        .serialize(&registry)
+        .map_err(|error| InspectError::Body(error.to_string()))
+}
+
+/// [`SaveFile::check`] の失敗。`Schema` は違反した規則ごとの説明を持つ。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckFailure {
+    Load(String),
+    Schema(Vec<String>),
+}
+
+impl fmt::Display for CheckFailure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load(reason) => write!(formatter, "load preparation failed: {reason}"),
+            Self::Schema(reasons) => {
+                formatter.write_str("save body violates the persisted schema:")?;
+                for reason in reasons {
+                    write!(formatter, "\n  - {reason}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub enum ExtractTarget {
+    Resource(String),
+    Component(String),
+}
+
+pub struct SaveSummary {
+    pub format_version: u32,
+    pub worldgen_seed: Option<u64>,
+    pub map_template: MapTemplate,
+    pub body_encoding: SaveBodyEncoding,
+    /// 読み込み時に適用した migration step の要約。
+    pub upgrade_steps: Vec<&'static str>,
+    pub resources: Vec<String>,
+    pub entity_count: usize,
+    /// root marker の type path（無ければ `(rootless)`）ごとの entity 数。
+    pub roots: BTreeMap<String, usize>,
+    pub components: BTreeMap<String, usize>,
+}
+
+impl fmt::Display for SaveSummary {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.format_version, self.worldgen_seed) {
+            (0, Some(seed)) => writeln!(formatter, "format: legacy v0 (worldgen seed {seed})")?,
+            (0, None) => writeln!(formatter, "format: legacy v0 (no worldgen seed)")?,
+            (version, seed) => writeln!(
+                formatter,
+                "format: v{version} (worldgen seed {})",
+                seed.unwrap_or_default()
+            )?,
+        }
+        writeln!(formatter, "map template: {}", self.map_template)?;
+        writeln!(formatter, "body: {:?}", self.body_encoding)?;
+        for step in &self.upgrade_steps {
+            writeln!(formatter, "upgraded on read: {step}")?;
+        }
+        writeln!(formatter, "resources: {}", self.resources.len())?;
+        for resource in &self.resources {
+            writeln!(formatter, "  {resource}")?;
+        }
+        writeln!(formatter, "entities: {}", self.entity_count)?;
+        for (root, count) in &self.roots {
+            writeln!(formatter, "  {count:>6}  {root}")?;
+        }
+        writeln!(formatter, "components:")?;
+        for (component, count) in &self.components {
+            writeln!(formatter, "  {count:>6}  {component}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Change {
+    const fn symbol(self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::Changed => '~',
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RootDiff {
+    pub before: usize,
+    pub after: usize,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// entity id と、変化した component の type path。
+    pub changed: Vec<(String, Vec<(Change, String)>)>,
+}
+
+impl RootDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+pub struct SaveDiff {
+    pub resources: Vec<(Change, String)>,
+    pub roots: BTreeMap<String, RootDiff>,
+}
+
+impl SaveDiff {
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty() && self.roots.values().all(RootDiff::is_empty)
+    }
+}
+
+impl fmt::Display for SaveDiff {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(formatter, "no differences");
+        }
+        for (change, path) in &self.resources {
+            writeln!(formatter, "resource {} {path}", change.symbol())?;
+        }
+        for (root, diff) in self.roots.iter().filter(|(_, diff)| !diff.is_empty()) {
+            writeln!(
+                formatter,
+                "{root}: {} -> {} ({} added, {} removed, {} changed)",
+                diff.before,
+                diff.after,
+                diff.added.len(),
+                diff.removed.len(),
+                diff.changed.len()
+            )?;
+            for id in &diff.added {
+                writeln!(formatter, "  + {id}")?;
+            }
+            for id in &diff.removed {
+                writeln!(formatter, "  - {id}")?;
+            }
+            for (id, changes) in &diff.changed {
+                let changes: Vec<String> = changes
+                    .iter()
+                    .map(|(change, path)| format!("{}{path}", change.symbol()))
+                    .collect();
+                writeln!(formatter, "  ~ {id}: {}", changes.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct SalvageReport {
+    pub removed_resources: Vec<String>,
+    /// 取り除いた component の type path と件数。
+    pub removed_components: BTreeMap<String, usize>,
+    pub removed_entities: Vec<String>,
+    /// 現在の format version で書き直したファイル全体。
+    pub contents: Vec<u8>,
+}
+
+impl SalvageReport {
+    pub fn is_unchanged(&self) -> bool {
+        self.removed_resources.is_empty()
+            && self.removed_components.is_empty()
+            && self.removed_entities.is_empty()
+    }
+}
+
+impl fmt::Display for SalvageReport {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_unchanged() {
+            return writeln!(formatter, "nothing outside the persisted schema");
+        }
+        for path in &self.removed_resources {
+            writeln!(formatter, "strip resource {path}")?;
+        }
+        for (path, count) in &self.removed_components {
+            writeln!(formatter, "strip component {path} ({count})")?;
+        }
+        if !self.removed_entities.is_empty() {
+            writeln!(
+                formatter,
+                "drop {} rootless entit{}: {}",
+                self.removed_entities.len(),
+                if self.removed_entities.len() == 1 {
+                    "y"
+                } else {
+                    "ies"
+                },
+                self.removed_entities.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn components_of(entity: &SaveValue) -> Result<&Entries, InspectError> {
+    entity
+        .field("components")
+        .and_then(SaveValue::map_entries)
+        .ok_or_else(|| InspectError::Body("entity has no `components` map".to_owned()))
+}
+
+/// type path の key を文字列で返す。エスケープを含む key も RON の綴りのまま返す。
+fn type_path_of(key: &SaveValue) -> String {
+    key.as_str().map_or_else(|| entity_id(key), str::to_owned)
+}
+
+fn entity_id(key: &SaveValue) -> String {
+    match key {
+        SaveValue::Atom(text) | SaveValue::Ident(text) => text.clone(),
+        other => SaveDocument {
+            attributes: Vec::new(),
+            root: other.clone(),
+        }
+        .to_ron(),
+    }
+}
+
+fn root_kind(components: &Entries, paths: &PersistedTypePaths) -> &'static str {
+    paths
+        .root_markers
+        .iter()
+        .copied()
+        .find(|marker| {
+            components
+                .iter()
+                .any(|(key, _)| key.as_str() == Some(marker))
+        })
+        .unwrap_or(ROOTLESS)
+}
+
+fn matches_type(key: &SaveValue, query: &str) -> bool {
+    key.as_str().is_some_and(|path| {
+        path == query
+            || path
+                .strip_suffix(query)
+                .is_some_and(|prefix| prefix.ends_with("::"))
+    })
+}
+
+fn keyed(entries: &Entries) -> BTreeMap<String, &SaveValue> {
+    entries
+        .iter()
+        .map(|(key, value)| (type_path_of(key), value))
+        .collect()
+}
+
+fn keyed_changes(
+    before: &BTreeMap<String, &SaveValue>,
+    after: &BTreeMap<String, &SaveValue>,
+) -> Vec<(Change, String)> {
+    let mut changes = Vec::new();
+    for (path, value) in before {
+        match after.get(path) {
+            None => changes.push((Change::Removed, path.clone())),
+            Some(other) if other != value => changes.push((Change::Changed, path.clone())),
+            Some(_) => {}
+        }
+    }
+    for path in after.keys() {
+        if !before.contains_key(path) {
+            changes.push((Change::Added, path.clone()));
+        }
+    }
+    changes
+}
+
+type IndexedEntities<'a> = BTreeMap<String, (&'static str, BTreeMap<String, &'a SaveValue>)>;
+
+fn indexed_entities<'a>(
+    entities: &'a Entries,
+    paths: &PersistedTypePaths,
+) -> Result<IndexedEntities<'a>, InspectError> {
+    entities
+        .iter()
+        .map(|(id, entity)| {
+            let components = components_of(entity)?;
+            Ok((
+                entity_id(id),
+                (root_kind(components, paths), keyed(components)),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "inspect/tests.rs"]
+mod tests;
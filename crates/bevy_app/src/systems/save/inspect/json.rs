@@ -0,0 +1,190 @@
+//! [`SaveValue`] 木の JSON 書き出し（`hw_save_tool extract` 用）。
+//!
+//! RON の enum variant 名は失わずに残す: unit variant は文字列、newtype/tuple variant は
+//! `{"Variant": value}`、struct variant は `{"Variant": {...}}` になる。`Some(x)` は `x`、
+//! `None` は `null`。文字列 key だけの map は object、それ以外は `[key, value]` の配列になる。
+
+use std::fmt::Write as _;
+
+use super::super::migration::SaveValue;
+
+pub(super) fn to_json(value: &SaveValue) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+fn write_value(value: &SaveValue, out: &mut String) {
+    match value {
+        SaveValue::Atom(text) => write_atom(text, out),
+        SaveValue::Ident(ident) => match ident.as_str() {
+            "true" | "false" => out.push_str(ident),
+            "None" => out.push_str("null"),
+            _ => write_string(ident, out),
+        },
+        SaveValue::Seq(items) => write_array(items, out),
+        SaveValue::Map(entries) => {
+            if entries.iter().all(|(key, _)| key.as_str().is_some()) {
+                write_object(
+                    entries
+                        .iter()
+                        .map(|(key, value)| (key.as_str().unwrap_or_default(), value)),
+                    out,
+                );
+            } else {
+                out.push('[');
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    out.push('[');
+                    write_value(key, out);
+                    out.push(',');
+                    write_value(value, out);
+                    out.push(']');
+                }
+                out.push(']');
+            }
+        }
+        SaveValue::Tuple { name: None, items } => write_array(items, out),
+        SaveValue::Tuple {
+            name: Some(name),
+            items,
+        } => match (name.as_str(), items.as_slice()) {
+            ("Some", [inner]) => write_value(inner, out),
+            (_, [inner]) => write_object([(name.as_str(), inner)], out),
+            _ => {
+                out.push('{');
+                write_string(name, out);
+                out.push(':');
+                write_array(items, out);
+                out.push('}');
+            }
+        },
+        SaveValue::Struct { name, fields } => {
+            let fields = fields.iter().map(|(field, value)| (field.as_str(), value));
+            match name {
+                None => write_object(fields, out),
+                Some(name) => {
+                    out.push('{');
+                    write_string(name, out);
+                    out.push(':');
+                    write_object(fields, out);
+                    out.push('}');
+                }
+            }
+        }
+    }
+}
+
+fn write_array(items: &[SaveValue], out: &mut String) {
+    out.push('[');
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_value(item, out);
+    }
+    out.push(']');
+}
+
+fn write_object<'a>(entries: impl IntoIterator<Item = (&'a str, &'a SaveValue)>, out: &mut String) {
+    out.push('{');
+    for (index, (key, value)) in entries.into_iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_string(key, out);
+        out.push(':');
+        write_value(value, out);
+    }
+    out.push('}');
+}
+
+/// 数値・文字列・文字 literal を JSON へ写す。JSON で表せない数値は元の綴りの文字列にする。
+fn write_atom(text: &str, out: &mut String) {
+    if let Some(decoded) = decode_ron_string(text) {
+        write_string(&decoded, out);
+        return;
+    }
+    let digits = text.replace('_', "");
+    let unsigned = digits.trim_start_matches(['+', '-']);
+    let negative = digits.starts_with('-');
+    let radix = [("0x", 16), ("0o", 8), ("0b", 2)]
+        .into_iter()
+        .find_map(|(prefix, radix)| unsigned.strip_prefix(prefix).map(|rest| (rest, radix)));
+    if let Some((rest, radix)) = radix
+        && let Ok(number) = u128::from_str_radix(rest, radix)
+    {
+        let _ = write!(out, "{}{number}", if negative { "-" } else { "" });
+    } else if let Ok(number) = unsigned.parse::<u128>() {
+        let _ = write!(out, "{}{number}", if negative { "-" } else { "" });
+    } else if let Ok(number) = digits.parse::<f64>()
+        && number.is_finite()
+    {
+        let _ = write!(out, "{number:?}");
+    } else {
+        write_string(text, out);
+    }
+}
+
+/// `"..."`・`'c'`・`r#"..."#`・`b"..."` を中身の文字列へ戻す。文字列でなければ `None`。
+fn decode_ron_string(text: &str) -> Option<String> {
+    let text = text.strip_prefix('b').unwrap_or(text);
+    if let Some(raw) = text.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let inner = raw.get(hashes + 1..raw.len().checked_sub(hashes + 1)?)?;
+        return Some(inner.to_owned());
+    }
+    let quote = text
+        .chars()
+        .next()
+        .filter(|quote| matches!(quote, '"' | '\''))?;
+    let inner = text.strip_prefix(quote)?.strip_suffix(quote)?;
+    let mut decoded = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            decoded.push(character);
+            continue;
+        }
+        match chars.next()? {
+            'n' => decoded.push('\n'),
+            'r' => decoded.push('\r'),
+            't' => decoded.push('\t'),
+            '0' => decoded.push('\0'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                decoded.push(char::from(u8::from_str_radix(&hex, 16).ok()?));
+            }
+            'u' => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip_while(|character| *character == '{')
+                    .take_while(|character| *character != '}')
+                    .collect();
+                decoded.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            other => decoded.push(other),
+        }
+    }
+    Some(decoded)
+}
+
+fn write_string(text: &str, out: &mut String) {
+    out.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            control if control < ' ' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(control));
+            }
+            other => out.push(other),
+        }
+    }
+    out.push('"');
+}
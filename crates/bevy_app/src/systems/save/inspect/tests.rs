@@ -0,0 +1,298 @@
+use hw_core::GameTime;
+use hw_core::population::PopulationManager;
+use hw_core::soul::DreamPool;
+use hw_logistics::Stockpile;
+use hw_world::WorldMap;
+
+use super::super::body::serialize_body;
+use super::super::format::CURRENT_SAVE_FORMAT_VERSION;
+use super::super::schema::build_persisted_world;
+use super::*;
+
+/// Frozen v1 save shared with the migration tests.
+const V1_COLONY_FIXTURE: &str = include_str!("../migration/fixtures/v1_colony.scn.ron");
+
+const FAMILIAR: &str = "hw_core::familiar::Familiar";
+const STOCKPILE: &str = "hw_logistics::zone::Stockpile";
+
+fn fixture() -> SaveFile {
+    SaveFile::from_contents(V1_COLONY_FIXTURE.to_owned()).unwrap()
+}
+
+fn fixture_with(from: &str, to: &str) -> SaveFile {
+    assert!(
+        V1_COLONY_FIXTURE.contains(from),
+        "fixture must contain {from:?}"
+    );
+    SaveFile::from_contents(V1_COLONY_FIXTURE.replacen(from, to, 1)).unwrap()
+}
+
+fn document_json(source: &str) -> String {
+    json::to_json(&SaveDocument::parse(source).unwrap().root)
+}
+
+#[test]
+fn summary_reports_header_upgrade_and_counts_by_root() {
+    let summary = fixture().summary().unwrap();
+
+    assert_eq!(summary.format_version, 1);
+    assert_eq!(summary.worldgen_seed, Some(42));
+    assert_eq!(summary.map_template, hw_world::MapTemplate::Classic);
+    assert_eq!(
+        summary.upgrade_steps.len(),
+        super::super::migration::SAVE_MIGRATIONS.len()
+    );
+    assert!(
+        summary
+            .resources
+            .iter()
+            .any(|path| path == "hw_core::alerts::AlertRules"),
+        "counts describe the migrated body"
+    );
+    assert_eq!(summary.entity_count, 2);
+    assert_eq!(
+        summary.roots,
+        BTreeMap::from([(FAMILIAR.to_owned(), 1), (STOCKPILE.to_owned(), 1)])
+    );
+    assert_eq!(summary.components.get("hw_core::area::TaskArea"), Some(&1));
+}
+
+#[test]
+fn malformed_files_are_reported_before_inspection() {
+    assert!(matches!(
+        SaveFile::from_contents(
+            "HELL_WORKERS_SAVE\n(format_version: 99, worldgen_seed: 1)\n---\n()".to_owned()
+        ),
+        Err(InspectError::Format(SaveFormatError::UnsupportedVersion {
+            found: 99,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        SaveFile::from_contents("(resources: {".to_owned()),
+        Err(InspectError::Migration(MigrationError::Syntax(_)))
+    ));
+}
+
+#[test]
+fn extract_by_short_type_name_returns_whole_entities_as_json() {
+    let file = fixture();
+
+    let stockpiles = file
+        .extract(&ExtractTarget::Component("Stockpile".to_owned()))
+        .unwrap();
+    let game_time = file
+        .extract(&ExtractTarget::Resource(
+            "hw_core::time::GameTime".to_owned(),
+        ))
+        .unwrap();
+
+    assert!(stockpiles.starts_with(r#"[{"entity":4294967289,"components":{"#));
+    assert!(
+        stockpiles
+            .contains(r#""hw_logistics::zone::Stockpile":{"capacity":10,"resource_type":"Wood"}"#)
+    );
+    assert!(stockpiles.contains("bevy_transform::components::transform::Transform"));
+    assert!(!stockpiles.contains(FAMILIAR));
+    assert_eq!(
+        game_time,
+        r#"{"hw_core::time::GameTime":{"seconds":412.5,"day":2,"hour":6,"minute":52}}"#
+    );
+    assert!(matches!(
+        file.extract(&ExtractTarget::Component("Pile".to_owned())),
+        Err(InspectError::NotFound(_))
+    ));
+}
+
+#[test]
+fn json_keeps_variant_names_and_decodes_ron_literals() {
+    assert_eq!(
+        document_json("(kind: ResourceBelow(resource: Wood, threshold: 20), unit: GridBlackout)"),
+        r#"{"kind":{"ResourceBelow":{"resource":"Wood","threshold":20}},"unit":"GridBlackout"}"#
+    );
+    assert_eq!(
+        document_json("[Some(3), None, Pair(1, 2), Wrapped(true), (), 0x1F, -1_000, inf]"),
+        r#"[3,null,{"Pair":[1,2]},{"Wrapped":true},[],31,-1000,"inf"]"#
+    );
+    assert_eq!(
+        document_json(r##"{"a\tb \u{e9}": 'q', (0, 1): r#"raw "x""#}"##),
+        r#"[["a\tb é","q"],[[0,1],"raw \"x\""]]"#
+    );
+    assert_eq!(
+        document_json(r#"{"name": "Imp \"Gnash\""}"#),
+        r#"{"name":"Imp \"Gnash\""}"#
+    );
+}
+
+#[test]
+fn diff_matches_entities_by_id_within_each_root() {
+    let before = fixture();
+    let after = fixture_with("capacity: 10", "capacity: 12");
+    let renumbered = fixture_with("4294967293: (", "4294967000: (");
+
+    let diff = before.diff(&after).unwrap();
+    assert!(diff.resources.is_empty());
+    assert_eq!(
+        diff.roots[STOCKPILE].changed,
+        vec![(
+            "4294967289".to_owned(),
+            vec![(Change::Changed, STOCKPILE.to_owned())]
+        )]
+    );
+    assert!(diff.roots[FAMILIAR].is_empty());
+    assert!(before.diff(&fixture()).unwrap().is_empty());
+
+    let familiars = &before.diff(&renumbered).unwrap().roots[FAMILIAR];
+    assert_eq!((familiars.before, familiars.after), (1, 1));
+    assert_eq!(familiars.added, vec!["4294967000".to_owned()]);
+    assert_eq!(familiars.removed, vec!["4294967293".to_owned()]);
+}
+
+#[test]
+fn salvage_strips_types_outside_the_schema_and_rootless_entities() {
+    let broken = V1_COLONY_FIXTURE
+        .replacen(
+            "  resources: {\n",
+            "  resources: {\n    \"mod_x::Weather\": (rain: true),\n",
+            1,
+        )
+        .replacen(
+            "  entities: {\n",
+            "  entities: {\n    7: (components: {\"mod_x::Tag\": (level: 1)}),\n",
+            1,
+        )
+        .replacen(
+            "        \"hw_logistics::zone::Stockpile\": (",
+            "        \"mod_x::Tag\": (level: 2),\n        \"hw_logistics::zone::Stockpile\": (",
+            1,
+        );
+    let broken = SaveFile::from_contents(broken).unwrap();
+
+    let report = broken.salvage().unwrap();
+
+    assert_eq!(report.removed_resources, vec!["mod_x::Weather".to_owned()]);
+    assert_eq!(
+        report.removed_components,
+        BTreeMap::from([("mod_x::Tag".to_owned(), 2)])
+    );
+    assert_eq!(report.removed_entities, vec!["7".to_owned()]);
+
+    let salvaged = SaveFile::from_contents(report.contents).unwrap();
+    assert_eq!(salvaged.format_version(), CURRENT_SAVE_FORMAT_VERSION);
+    assert_eq!(salvaged.worldgen_seed(), Some(42));
+    let summary = salvaged.summary().unwrap();
+    assert_eq!(summary.entity_count, 2);
+    assert!(
+        !summary
+            .resources
+            .iter()
+            .any(|path| path.starts_with("mod_x"))
+    );
+    assert!(
+        !summary
+            .components
+            .keys()
+            .any(|path| path.starts_with("mod_x"))
+    );
+    assert!(fixture().salvage().unwrap().is_unchanged());
+}
+
+#[test]
+fn check_runs_the_loader_schema_validation_headlessly() {
+    let mut app = App::new();
+    app.add_plugins(AssetPlugin::default());
+    register_save_types(&mut app);
+    let world = app.world_mut();
+    world.insert_resource(GameTime::default());
+    world.insert_resource(DreamPool::default());
+    world.insert_resource(PopulationManager::default());
+    world.insert_resource(crate::systems::contracts::ContractBook::default());
+    world.insert_resource(hw_core::edicts::DreamEdicts::default());
+    world.insert_resource(hw_core::camera::CameraBookmarks::default());
+    world.insert_resource(hw_core::statistics::ColonyStatistics::default());
+    world.insert_resource(hw_core::alerts::AlertRules::default());
+    world.insert_resource(WorldMap::default());
+    let stockpile = world
+        .spawn(Stockpile {
+            capacity: 4,
+            resource_type: None,
+        })
+        .id();
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let body = build_persisted_world(app.world(), &registry, std::iter::once(stockpile))
+        .serialize(&registry)
+        .unwrap();
+    let encode = |edit: fn(&mut SaveValue)| {
+        let mut document = SaveDocument::parse(&body).unwrap();
+        edit(&mut document.root);
+        SaveFile::from_contents(encode_save_file(
+            SaveHeader::current(42),
+            document.to_ron().as_bytes(),
+        ))
+        .unwrap()
+    };
+
+    assert_eq!(encode(|_| {}).check(), Ok(()));
+
+    let Err(CheckFailure::Schema(reasons)) = encode(|root| {
+        ops::remove_resource(root, GameTime::type_path()).unwrap();
+    })
+    .check() else {
+        panic!("a missing resource must fail schema validation");
+    };
+    assert!(reasons[0].starts_with("missing required persisted resource(s)"));
+
+    let unregistered = encode(|root| {
+        ops::insert_resource_if_missing(
+            root,
+            "mod_x::Weather",
+            SaveValue::unnamed_struct([("rain", SaveValue::Ident("true".to_owned()))]),
+        )
+        .unwrap();
+    });
+    assert!(matches!(unregistered.check(), Err(CheckFailure::Load(_))));
+}
+
+#[test]
+fn binary_bodies_are_inspected_through_the_registry() {
+    let mut app = headless_app();
+    let world = app.world_mut();
+    world.insert_resource(GameTime::default());
+    world.insert_resource(DreamPool::default());
+    world.insert_resource(PopulationManager::default());
+    world.insert_resource(crate::systems::contracts::ContractBook::default());
+    world.insert_resource(hw_core::edicts::DreamEdicts::default());
+    world.insert_resource(hw_core::camera::CameraBookmarks::default());
+    world.insert_resource(hw_core::statistics::ColonyStatistics::default());
+    world.insert_resource(hw_core::alerts::AlertRules::default());
+    world.insert_resource(WorldMap::default());
+    let stockpile = world
+        .spawn(Stockpile {
+            capacity: 4,
+            resource_type: None,
+        })
+        .id();
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let dynamic_world = build_persisted_world(app.world(), &registry, std::iter::once(stockpile));
+    let [ron, binary] = [SaveBodyEncoding::Ron, SaveBodyEncoding::PostcardZstd].map(|encoding| {
+        SaveFile::from_contents(encode_save_file(
+            SaveHeader::current(42).with_body_encoding(encoding),
+            &serialize_body(&dynamic_world, &registry, encoding).unwrap(),
+        ))
+        .unwrap()
+    });
+
+    let summary = binary.summary().unwrap();
+    assert_eq!(summary.body_encoding, SaveBodyEncoding::PostcardZstd);
+    assert_eq!(summary.roots, BTreeMap::from([(STOCKPILE.to_owned(), 1)]));
+    assert_eq!(binary.check(), Ok(()));
+    assert!(ron.diff(&binary).unwrap().is_empty());
+
+    let salvaged = SaveFile::from_contents(binary.salvage().unwrap().contents).unwrap();
+    assert_eq!(
+        salvaged.summary().unwrap().body_encoding,
+        SaveBodyEncoding::Ron
+    );
+}
@@ -1,6 +1,7 @@
 //! ワールドのロード（exclusive system）。
 //!
-//! 1. 外部header、worldgen seed、DynamicWorld schemaを検証して`PreparedLoad`を作る。
+//! 1. 外部header、worldgen seed・マップテンプレートを検証し、旧versionのbodyを`migration`で現在の形へ上げてから
+//!    DynamicWorld schemaを検証して`PreparedLoad`を作る。
 //! 2. staging Worldへ適用して、Reflect registryの静的contractをpreflightする。
 //! 3. rehydrate前提を検証後、rollback snapshotを取り、旧persisted entityを置換する。
 //! 4. 成功時とrollback復旧時の両方でcache reset、`AssignedTask`復元、rehydrateを実行する。
@@ -21,19 +22,28 @@ use bevy::prelude::*;
 
 use hw_core::soul::DamnedSoul;
 use hw_jobs::AssignedTask;
+use hw_world::MapTemplate;
 
 use bevy_world_serialization::DynamicWorld;
 use bevy_world_serialization::serde::WorldDeserializer;
 
 use crate::world::map::GeneratedWorldLayoutResource;
 
-use super::format::{SaveFormat, SaveFormatError, decode_save_file};
+use super::body::decompress_binary_body;
+use super::format::{
+    CURRENT_SAVE_FORMAT_VERSION, SaveBody, SaveFormat, SaveFormatError, decode_save_file,
+};
+use super::migration::{MigrationError, migrate_body};
 use super::rehydrate::{rehydrate_after_load, validate_rehydrate_prerequisites};
 use super::reset::reset_runtime_caches;
 use super::schema::{
     DynamicWorldSchemaError, discard_legacy_reserved_for_task, discard_runtime_derived_components,
     validate_persisted_world,
 };
+#[cfg(test)]
+use super::schema::{build_persisted_world, collect_persisted_entities};
+#[cfg(feature = "profiling")]
+use super::state::SaveLoadPerfMetrics;
 use super::state::{SaveLoadFailureKind, SaveLoadResult, SavePath, SavedWorldgenSeed};
 use super::transaction::{CommitError, preflight_dynamic_world, replace_persisted_world};
 
@@ -42,13 +52,28 @@ struct PreparedLoad {
     dynamic_world: DynamicWorld,
 }
 
+impl PreparedLoad {
+    /// 現在の version より古いファイルなら、その version を返す。
+    fn upgraded_from(&self) -> Option<u32> {
+        (self.format.version() < CURRENT_SAVE_FORMAT_VERSION).then_some(self.format.version())
+    }
+}
+
 #[derive(Debug)]
-enum LoadPreparationError {
+pub(super) enum LoadPreparationError {
     Format(SaveFormatError),
+    Migration(MigrationError),
     MissingPrerequisite(&'static str),
     BodySyntax(String),
     Deserialize(String),
-    SeedMismatch { saved: u64, current: u64 },
+    SeedMismatch {
+        saved: u64,
+        current: u64,
+    },
+    MapTemplateMismatch {
+        saved: MapTemplate,
+        current: MapTemplate,
+    },
     Schema(DynamicWorldSchemaError),
 }
 
@@ -56,6 +81,7 @@ impl fmt::Display for LoadPreparationError {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Format(error) => write!(formatter, "invalid save format: {error}"),
+            Self::Migration(error) => write!(formatter, "save migration failed: {error}"),
             Self::MissingPrerequisite(resource) => {
                 write!(
                     formatter,
@@ -70,6 +96,10 @@ impl fmt::Display for LoadPreparationError {
                 formatter,
                 "worldgen seed mismatch (save={saved}, session={current}); restart with HELL_WORKERS_WORLDGEN_SEED={saved} before loading"
             ),
+            Self::MapTemplateMismatch { saved, current } => write!(
+                formatter,
+                "map template mismatch (save={saved}, session={current}); restart with HELL_WORKERS_MAP_TEMPLATE={saved} before loading"
+            ),
             Self::Schema(error) => write!(formatter, "invalid save schema: {error}"),
         }
     }
@@ -81,6 +111,17 @@ impl From<SaveFormatError> for LoadPreparationError {
     }
 }
 
+impl From<MigrationError> for LoadPreparationError {
+    fn from(error: MigrationError) -> Self {
+        match error {
+            // The migration parser is the first reader of an older body; its
+            // syntax errors mean the same thing as a DynamicWorld parse failure.
+            MigrationError::Syntax(error) => Self::BodySyntax(error.to_string()),
+            error => Self::Migration(error),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum LoadExecutionError {
     Read(std::io::Error),
@@ -99,16 +140,19 @@ impl LoadExecutionError {
             }
             Self::Read(_) => SaveLoadFailureKind::LoadRead,
             Self::Preparation(LoadPreparationError::Format(
-                SaveFormatError::UnsupportedVersion { .. },
+                SaveFormatError::UnsupportedVersion { .. }
+                | SaveFormatError::UnmigratableBinaryBody { .. },
             )) => SaveLoadFailureKind::UnsupportedFormat,
             Self::Preparation(LoadPreparationError::MissingPrerequisite(_))
             | Self::MissingPrerequisite(_)
             | Self::RehydratePrerequisite(_) => SaveLoadFailureKind::MissingPrerequisite,
-            Self::Preparation(LoadPreparationError::SeedMismatch { .. }) => {
-                SaveLoadFailureKind::SeedMismatch
-            }
+            Self::Preparation(
+                LoadPreparationError::SeedMismatch { .. }
+                | LoadPreparationError::MapTemplateMismatch { .. },
+            ) => SaveLoadFailureKind::SeedMismatch,
             Self::Preparation(
                 LoadPreparationError::Format(_)
+                | LoadPreparationError::Migration(_)
                 | LoadPreparationError::BodySyntax(_)
                 | LoadPreparationError::Deserialize(_)
                 | LoadPreparationError::Schema(_),
@@ -148,14 +192,35 @@ impl fmt::Display for LoadExecutionError {
 
 pub(super) fn load_world_system(world: &mut World) -> SaveLoadResult {
     let save_path = world.resource::<SavePath>().as_path().to_path_buf();
+    #[cfg(feature = "profiling")]
+    let started = std::time::Instant::now();
     match execute_load(world, &save_path) {
-        Ok(format) => {
+        Ok(LoadedSave {
+            format,
+            upgraded_from,
+        }) => {
+            #[cfg(feature = "profiling")]
+            if let Some(mut metrics) = world.get_resource_mut::<SaveLoadPerfMetrics>() {
+                metrics.record_load(started.elapsed());
+            }
             let format = match format {
-                SaveFormat::LegacyV0 => "legacy v0",
-                SaveFormat::V1(_) => "v1",
+                SaveFormat::LegacyV0 => "legacy v0".to_owned(),
+                SaveFormat::Versioned(header) => format!("v{}", header.format_version),
             };
             info!("World loaded from {} ({format})", save_path.display());
-            SaveLoadResult::Succeeded
+            match upgraded_from {
+                Some(from_version) => {
+                    info!(
+                        "Save {} upgraded from format v{from_version} to v{CURRENT_SAVE_FORMAT_VERSION}",
+                        save_path.display()
+                    );
+                    SaveLoadResult::Upgraded {
+                        from_version,
+                        to_version: CURRENT_SAVE_FORMAT_VERSION,
+                    }
+                }
+                None => SaveLoadResult::Succeeded,
+            }
         }
         Err(error) if error.failure_kind() == SaveLoadFailureKind::ApplyRecovered => {
             warn!(
@@ -171,10 +236,15 @@ pub(super) fn load_world_system(world: &mut World) -> SaveLoadResult {
     }
 }
 
-fn execute_load(world: &mut World, save_path: &Path) -> Result<SaveFormat, LoadExecutionError> {
+struct LoadedSave {
+    format: SaveFormat,
+    upgraded_from: Option<u32>,
+}
+
+fn execute_load(world: &mut World, save_path: &Path) -> Result<LoadedSave, LoadExecutionError> {
     let contents = read_save_file(save_path).map_err(LoadExecutionError::Read)?;
     let prepared =
-        prepare_load_from_str(world, &contents).map_err(LoadExecutionError::Preparation)?;
+        prepare_load_from_bytes(world, &contents).map_err(LoadExecutionError::Preparation)?;
 
     let type_registry = world.get_resource::<AppTypeRegistry>().cloned().ok_or(
         LoadExecutionError::MissingPrerequisite(std::any::type_name::<AppTypeRegistry>()),
@@ -197,62 +267,115 @@ fn execute_load(world: &mut World, save_path: &Path) -> Result<SaveFormat, LoadE
     drop(registry);
     commit_result.map_err(LoadExecutionError::Commit)?;
 
-    Ok(prepared.format)
+    Ok(LoadedSave {
+        format: prepared.format,
+        upgraded_from: prepared.upgraded_from(),
+    })
 }
 
-fn read_save_file(path: &Path) -> std::io::Result<String> {
-    std::fs::read_to_string(path)
+fn read_save_file(path: &Path) -> std::io::Result<Vec<u8>> {
+    std::fs::read(path)
 }
 
-/// Validates the external format and seed guard before DynamicWorld parsing.
-fn prepare_load_from_str(
+/// Validates the external format and seed guard, then migrates older bodies
+/// to the current shape before DynamicWorld parsing.
+fn prepare_load_from_bytes(
     world: &World,
-    contents: &str,
+    contents: &[u8],
 ) -> Result<PreparedLoad, LoadPreparationError> {
     let decoded = decode_save_file(contents)?;
     let format = decoded.format;
-    if let SaveFormat::V1(header) = format {
+    if let SaveFormat::Versioned(header) = format {
         validate_worldgen_seed(world, header.worldgen_seed)?;
+        validate_map_template(world, header.map_template)?;
     }
+    let dynamic_world = prepare_dynamic_world(world, format, decoded.body, |saved_seed| {
+        match saved_seed {
+            Some(saved_seed) => validate_worldgen_seed(world, saved_seed),
+            None => {
+                warn!(
+                    "Save file has no worldgen seed (legacy v0); terrain visuals may not match the loaded WorldMap"
+                );
+                Ok(())
+            }
+        }
+    })?;
+
+    Ok(PreparedLoad {
+        format,
+        dynamic_world,
+    })
+}
+
+/// 構文を確かめ終え、`WorldDeserializer` に渡すだけになった body。
+enum ParsedBody<'a> {
+    Ron(ron::de::Deserializer<'a>),
+    Postcard(postcard::Deserializer<'a, postcard::de_flavors::Slice<'a>>),
+}
+
+/// セッションに依存しない load 準備。migrate → deserialize → shim → schema 検証を行う。
+///
+/// RON body は migration を通してから、binary body は zstd を展開してから同じ
+/// `WorldDeserializer` で読む。
+///
+/// legacy v0 の body に埋め込まれた seed は `check_legacy_seed` に渡す。`hw_save_tool` は
+/// 実行中のワールドを持たないため、この関数を seed 検証なしで呼ぶ。
+pub(super) fn prepare_dynamic_world(
+    world: &World,
+    format: SaveFormat,
+    body: SaveBody<'_>,
+    check_legacy_seed: impl FnOnce(Option<u64>) -> Result<(), LoadPreparationError>,
+) -> Result<DynamicWorld, LoadPreparationError> {
+    // body の構文は前提 Resource より先に確かめる。壊れたファイルは `BodySyntax` として報告する。
+    let migrated;
+    let decompressed;
+    let mut parsed = match body {
+        SaveBody::Ron(body) => {
+            migrated = migrate_body(format.body_version(), body)?;
+            ParsedBody::Ron(
+                ron::de::Deserializer::from_str(&migrated)
+                    .map_err(|error| LoadPreparationError::BodySyntax(error.to_string()))?,
+            )
+        }
+        SaveBody::PostcardZstd(body) => {
+            decompressed =
+                decompress_binary_body(body).map_err(LoadPreparationError::BodySyntax)?;
+            ParsedBody::Postcard(postcard::Deserializer::from_bytes(&decompressed))
+        }
+    };
 
     let type_registry = world.get_resource::<AppTypeRegistry>().cloned().ok_or(
         LoadPreparationError::MissingPrerequisite(std::any::type_name::<AppTypeRegistry>()),
     )?;
     let registry = type_registry.read();
-    let mut ron_deserializer = ron::de::Deserializer::from_str(decoded.body)
-        .map_err(|error| LoadPreparationError::BodySyntax(error.to_string()))?;
     let mut asset_server = world.get_resource::<AssetServer>().cloned().ok_or(
         LoadPreparationError::MissingPrerequisite(std::any::type_name::<AssetServer>()),
     )?;
+    let deserializer = WorldDeserializer {
+        type_registry: &registry,
+        load_from_path: &mut asset_server,
+    };
     let mut dynamic_world = {
         use serde::de::DeserializeSeed;
-        let deserializer = WorldDeserializer {
-            type_registry: &registry,
-            load_from_path: &mut asset_server,
-        };
-        deserializer
-            .deserialize(&mut ron_deserializer)
-            .map_err(|error| LoadPreparationError::Deserialize(error.to_string()))?
+        match &mut parsed {
+            ParsedBody::Ron(ron_deserializer) => deserializer
+                .deserialize(ron_deserializer)
+                .map_err(|error| LoadPreparationError::Deserialize(error.to_string()))?,
+            ParsedBody::Postcard(postcard_deserializer) => deserializer
+                .deserialize(postcard_deserializer)
+                .map_err(|error| LoadPreparationError::Deserialize(error.to_string()))?,
+        }
     };
 
     if format == SaveFormat::LegacyV0 {
-        match extract_saved_worldgen_seed(&dynamic_world) {
-            Some(saved_seed) => validate_worldgen_seed(world, saved_seed)?,
-            None => warn!(
-                "Save file has no worldgen seed (legacy v0); terrain visuals may not match the loaded WorldMap"
-            ),
-        }
+        check_legacy_seed(extract_saved_worldgen_seed(&dynamic_world))?;
         remove_legacy_saved_worldgen_seed(&mut dynamic_world);
         discard_legacy_reserved_for_task(&mut dynamic_world);
     }
 
     discard_runtime_derived_components(&mut dynamic_world);
     validate_persisted_world(&dynamic_world).map_err(LoadPreparationError::Schema)?;
-
-    Ok(PreparedLoad {
-        format,
-        dynamic_world,
-    })
+    Ok(dynamic_world)
 }
 
 fn validate_worldgen_seed(world: &World, saved_seed: u64) -> Result<(), LoadPreparationError> {
@@ -272,6 +395,29 @@ fn validate_worldgen_seed(world: &World, saved_seed: u64) -> Result<(), LoadPrep
     }
 }
 
+/// The template changes the terrain generated from the same seed, so it is
+/// checked alongside the seed. Legacy v0 saves predate templates and are Classic.
+fn validate_map_template(
+    world: &World,
+    saved_template: MapTemplate,
+) -> Result<(), LoadPreparationError> {
+    let current_template = world
+        .get_resource::<GeneratedWorldLayoutResource>()
+        .ok_or(LoadPreparationError::MissingPrerequisite(
+            std::any::type_name::<GeneratedWorldLayoutResource>(),
+        ))?
+        .layout
+        .template;
+    if saved_template == current_template {
+        Ok(())
+    } else {
+        Err(LoadPreparationError::MapTemplateMismatch {
+            saved: saved_template,
+            current: current_template,
+        })
+    }
+}
+
 /// `SavedWorldgenSeed` is only an input to legacy v0 validation. Never apply
 /// it to the live v1-era world after its value has been checked.
 fn remove_legacy_saved_worldgen_seed(dynamic_world: &mut DynamicWorld) {
@@ -322,7 +468,34 @@ fn restore_default_assigned_task(world: &mut World) {
 fn finalize_loaded_world(world: &mut World) -> Result<(), String> {
     reset_runtime_caches(world);
     restore_default_assigned_task(world);
-    rehydrate_after_load(world).map_err(|error| error.to_string())
+    #[cfg(feature = "profiling")]
+    let started = std::time::Instant::now();
+    let rehydrated = rehydrate_after_load(world).map_err(|error| error.to_string());
+    #[cfg(feature = "profiling")]
+    if let Some(mut metrics) = world.get_resource_mut::<SaveLoadPerfMetrics>() {
+        metrics.record_rehydrate(started.elapsed());
+    }
+    rehydrated
+}
+
+/// durable component だけを持つ `source` world で live world を置き換える。
+///
+/// ファイル読み込み・header / seed 検査・migration を除き、ロードと同じ preflight、
+/// 置換 transaction、cache reset、rehydrate を通る。シナリオテストが宣言した初期状態を
+/// 本番の load 経路で適用するために使う。
+#[cfg(test)]
+pub(crate) fn replace_world_from_durable_source(
+    world: &mut World,
+    source: &mut World,
+) -> Result<(), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let entities = collect_persisted_entities(source);
+    let dynamic_world = build_persisted_world(source, &registry, entities.into_iter());
+    preflight_dynamic_world(&dynamic_world, &registry).map_err(|error| error.to_string())?;
+    validate_rehydrate_prerequisites(world).map_err(|error| error.to_string())?;
+    replace_persisted_world(world, &dynamic_world, &registry, finalize_loaded_world)
+        .map_err(|error| error.to_string())
 }
 
 #[cfg(test)]
@@ -330,13 +503,26 @@ mod tests {
     use std::any::TypeId;
 
     use bevy::asset::AssetPlugin;
+    use bevy::ecs::entity::EntityHashMap;
 
     use super::*;
+    use crate::entities::damned_soul::{Destination, SoulIdentity};
+    use crate::systems::contracts::ContractBook;
+    use crate::test_support::scenario::Scenario;
     use crate::world::map::GeneratedWorldLayoutResource;
     use hw_core::GameTime;
+    use hw_core::alerts::AlertRules;
+    use hw_core::area::TaskArea;
+    use hw_core::camera::CameraBookmarks;
+    use hw_core::edicts::DreamEdicts;
+    use hw_core::familiar::Familiar;
+    use hw_core::life_log::{SoulLifeEventKind, SoulLifeLog};
     use hw_core::logistics::ResourceType;
     use hw_core::population::PopulationManager;
+    use hw_core::relationships::{CommandedBy, Commanding, StoredIn};
+    use hw_core::social::SoulRelationships;
     use hw_core::soul::DreamPool;
+    use hw_core::statistics::ColonyStatistics;
     use hw_jobs::Building;
     use hw_jobs::mud_mixer::MudMixerStorage;
     use hw_logistics::types::{
@@ -347,7 +533,9 @@ mod tests {
     use hw_world::WorldMap;
     use hw_world::Yard;
 
-    use super::super::format::{SaveHeader, encode_save_file};
+    use super::super::body::serialize_body;
+    use super::super::format::{SaveBodyEncoding, SaveHeader, encode_save_file};
+    use super::super::migration::{SaveDocument, ops};
     use super::super::rehydrate::rehydrate_stockpile_policies;
     use super::super::schema::{
         build_persisted_world, collect_persisted_entities, register_save_types,
@@ -370,6 +558,11 @@ mod tests {
         world.insert_resource(GameTime::default());
         world.insert_resource(DreamPool::default());
         world.insert_resource(PopulationManager::default());
+        world.insert_resource(ContractBook::default());
+        world.insert_resource(DreamEdicts::default());
+        world.insert_resource(CameraBookmarks::default());
+        world.insert_resource(ColonyStatistics::default());
+        world.insert_resource(AlertRules::default());
         world.insert_resource(WorldMap::default());
         app
     }
@@ -406,11 +599,11 @@ mod tests {
         });
         let contents = encode_save_file(
             SaveHeader::current(8),
-            "this is deliberately not DynamicWorld RON",
+            b"this is deliberately not DynamicWorld RON",
         );
 
         assert!(matches!(
-            prepare_load_from_str(&world, &contents),
+            prepare_load_from_bytes(&world, &contents),
             Err(LoadPreparationError::SeedMismatch {
                 saved: 8,
                 current: 7,
@@ -418,6 +611,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn map_template_mismatch_is_rejected_before_dynamic_world_deserialization() {
+        let mut world = World::new();
+        world.insert_resource(GeneratedWorldLayoutResource {
+            master_seed: 7,
+            layout: GeneratedWorldLayout::stub(7),
+        });
+        let contents = encode_save_file(
+            SaveHeader::current(7).with_map_template(MapTemplate::IslandSite),
+            b"this is deliberately not DynamicWorld RON",
+        );
+
+        assert!(matches!(
+            prepare_load_from_bytes(&world, &contents),
+            Err(LoadPreparationError::MapTemplateMismatch {
+                saved: MapTemplate::IslandSite,
+                current: MapTemplate::Classic,
+            })
+        ));
+    }
+
     #[test]
     fn execution_errors_map_exhaustively_to_display_safe_failure_kinds() {
         assert_eq!(
@@ -442,6 +656,12 @@ mod tests {
             )),
             SaveLoadFailureKind::UnsupportedFormat
         );
+        assert_eq!(
+            classified(LoadExecutionError::Preparation(
+                LoadPreparationError::Format(SaveFormatError::UnmigratableBinaryBody { found: 1 })
+            )),
+            SaveLoadFailureKind::UnsupportedFormat
+        );
         assert_eq!(
             classified(LoadExecutionError::Preparation(
                 LoadPreparationError::BodySyntax("raw parser details".to_owned())
@@ -462,6 +682,12 @@ mod tests {
             )),
             SaveLoadFailureKind::InvalidData
         );
+        assert_eq!(
+            classified(LoadExecutionError::Preparation(
+                LoadPreparationError::Migration(MigrationError::MissingStep { from_version: 1 })
+            )),
+            SaveLoadFailureKind::InvalidData
+        );
         assert_eq!(
             classified(LoadExecutionError::Preflight(
                 "raw preflight details".to_owned()
@@ -477,6 +703,15 @@ mod tests {
             )),
             SaveLoadFailureKind::SeedMismatch
         );
+        assert_eq!(
+            classified(LoadExecutionError::Preparation(
+                LoadPreparationError::MapTemplateMismatch {
+                    saved: MapTemplate::Badlands,
+                    current: MapTemplate::Classic,
+                }
+            )),
+            SaveLoadFailureKind::SeedMismatch
+        );
         assert_eq!(
             classified(LoadExecutionError::MissingPrerequisite("registry")),
             SaveLoadFailureKind::MissingPrerequisite
@@ -514,7 +749,7 @@ mod tests {
         world.init_resource::<AppTypeRegistry>();
 
         assert!(matches!(
-            prepare_load_from_str(&world, "#![enable(not_a_real_ron_extension)]"),
+            prepare_load_from_bytes(&world, b"#![enable(not_a_real_ron_extension)]"),
             Err(LoadPreparationError::BodySyntax(_))
         ));
     }
@@ -542,7 +777,7 @@ mod tests {
         let mut app = legacy_loader_test_app();
         let legacy_body = legacy_body_with_reserved_for_task(&mut app);
 
-        let prepared = prepare_load_from_str(app.world(), &legacy_body)
+        let prepared = prepare_load_from_bytes(app.world(), legacy_body.as_bytes())
             .expect("headerless v0 body with the legacy marker must remain loadable");
         assert_eq!(prepared.format, SaveFormat::LegacyV0);
         assert!(prepared.dynamic_world.entities.iter().all(|entity| {
@@ -561,8 +796,6 @@ mod tests {
 
     #[test]
     fn missing_stockpile_policy_migrates_only_yard_owned_cells_for_v0_and_v1() {
-        use bevy::ecs::entity::EntityHashMap;
-
         let mut source = legacy_loader_test_app();
         let (ordinary, tank, legacy_companion, mixer, pending_companion) = {
             let world = source.world_mut();
@@ -598,12 +831,12 @@ mod tests {
         drop(registry);
 
         let fixtures = [
-            body.clone(),
-            encode_save_file(SaveHeader::current(42), &body),
+            body.clone().into_bytes(),
+            encode_save_file(SaveHeader::current(42), body.as_bytes()),
         ];
         for contents in fixtures {
             let loader = legacy_loader_test_app();
-            let prepared = prepare_load_from_str(loader.world(), &contents).unwrap();
+            let prepared = prepare_load_from_bytes(loader.world(), &contents).unwrap();
             let type_registry = loader.world().resource::<AppTypeRegistry>().clone();
             let registry = type_registry.read();
             let mut loaded = World::new();
@@ -635,15 +868,249 @@ mod tests {
         }
     }
 
+    /// ロード結果を Entity id に依存しない行へ正規化する。Soul / Familiar は名前、
+    /// Stockpile と地面アイテムは grid 座標で参照する。
+    fn simulation_state(world: &mut World) -> Vec<String> {
+        let mut names = EntityHashMap::<String>::default();
+        for (entity, identity) in world.query::<(Entity, &SoulIdentity)>().iter(world) {
+            names.insert(entity, identity.name.clone());
+        }
+        for (entity, familiar) in world.query::<(Entity, &Familiar)>().iter(world) {
+            names.insert(entity, familiar.name.clone());
+        }
+        let name = |entity: Entity| names.get(&entity).cloned().unwrap_or_default();
+        let grid =
+            |transform: &Transform| WorldMap::world_to_grid(transform.translation.truncate());
+
+        let mut lines = vec![
+            format!("time {}", world.resource::<GameTime>().seconds),
+            format!("dream pool {}", world.resource::<DreamPool>().points),
+            format!(
+                "population spawned {}",
+                world.resource::<PopulationManager>().total_spawned
+            ),
+        ];
+        let mut souls = world.query::<(
+            Entity,
+            &DamnedSoul,
+            &Transform,
+            Option<&CommandedBy>,
+            &SoulRelationships,
+            &SoulLifeLog,
+            Has<Destination>,
+            Has<AssignedTask>,
+        )>();
+        for (entity, soul, transform, commanded_by, relationships, life_log, shell, task) in
+            souls.iter(world)
+        {
+            let bonds: Vec<_> = relationships
+                .bonds()
+                .iter()
+                .map(|bond| (name(bond.other), bond.affinity))
+                .collect();
+            let log: Vec<_> = life_log.iter_newest_first().collect();
+            lines.push(format!(
+                "soul {} vitals=({}, {}, {}, {}, {}) at {} commanded_by={:?} bonds={bonds:?} log={log:?} shell={shell} task={task}",
+                name(entity),
+                soul.laziness,
+                soul.motivation,
+                soul.fatigue,
+                soul.stress,
+                soul.dream,
+                transform.translation,
+                commanded_by.map(|commanded_by| name(commanded_by.0)),
+            ));
+        }
+        let mut familiars = world.query::<(Entity, &Familiar, Option<&Commanding>, &TaskArea)>();
+        for (entity, familiar, commanding, task_area) in familiars.iter(world) {
+            let mut commanded: Vec<_> = commanding
+                .map(|commanding| commanding.iter().copied().map(name).collect())
+                .unwrap_or_default();
+            commanded.sort();
+            lines.push(format!(
+                "familiar {} {:?} commanding={commanded:?} area={task_area:?}",
+                name(entity),
+                familiar.familiar_type,
+            ));
+        }
+        let mut stockpiles =
+            world.query::<(Entity, &Stockpile, &Transform, Option<&StockpilePolicy>)>();
+        let stockpiles: Vec<_> = stockpiles
+            .iter(world)
+            .map(|(entity, stockpile, transform, policy)| {
+                (entity, stockpile.capacity, grid(transform), policy.copied())
+            })
+            .collect();
+        let mut stored = world.query::<(&ResourceItem, &StoredIn)>();
+        for (entity, capacity, at, policy) in stockpiles {
+            let mut items: Vec<_> = stored
+                .iter(world)
+                .filter(|(_, stored_in)| stored_in.0 == entity)
+                .map(|(item, _)| item.0)
+                .collect();
+            items.sort_by_key(|resource| format!("{resource:?}"));
+            lines.push(format!(
+                "stockpile {at:?} capacity={capacity} policy={policy:?} items={items:?}"
+            ));
+        }
+        let mut ground = world.query_filtered::<(&ResourceItem, &Transform), Without<StoredIn>>();
+        for (item, transform) in ground.iter(world) {
+            lines.push(format!("ground {:?} at {:?}", item.0, grid(transform)));
+        }
+        lines.sort();
+        lines
+    }
+
+    #[test]
+    fn ron_and_binary_saves_rehydrate_to_the_same_simulation_state() {
+        let mut run = Scenario::new()
+            .yard((40, 40), (46, 46))
+            .stockpile_with((41, 41), ResourceType::Wood, 4)
+            .stockpile_with((42, 41), ResourceType::Rock, 2)
+            .items((44, 44), ResourceType::Wood, 3)
+            .familiar("Imp", (43, 43), ((40, 40), (46, 46)))
+            .soul(
+                "Alice",
+                (42, 43),
+                DamnedSoul {
+                    fatigue: 0.4,
+                    dream: 12.5,
+                    ..default()
+                },
+            )
+            .soul(
+                "Bram",
+                (44, 42),
+                DamnedSoul {
+                    stress: 0.3,
+                    ..default()
+                },
+            )
+            .soul("Cora", (45, 45), DamnedSoul::default())
+            .start();
+        let alice = run.soul("Alice");
+        let bram = run.soul("Bram");
+        let imp = run.familiar("Imp");
+        let world = run.world_mut();
+        world.resource_mut::<GameTime>().seconds = 4_321.0;
+        world.resource_mut::<DreamPool>().points = 37.5;
+        for soul in [alice, bram] {
+            world.entity_mut(soul).insert(CommandedBy(imp));
+        }
+        let game_time = GameTime {
+            seconds: 4_321.0,
+            day: 1,
+            hour: 12,
+            minute: 5,
+        };
+        world
+            .get_mut::<SoulRelationships>(alice)
+            .unwrap()
+            .adjust(bram, 0.6);
+        world
+            .get_mut::<SoulRelationships>(bram)
+            .unwrap()
+            .adjust(alice, -0.2);
+        world
+            .get_mut::<SoulLifeLog>(alice)
+            .unwrap()
+            .record(&game_time, SoulLifeEventKind::Recruited);
+        let expected = simulation_state(world);
+
+        let directory =
+            std::env::temp_dir().join(format!("hell-workers-load-test-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let (seed, template) = {
+            let layout = world.resource::<GeneratedWorldLayoutResource>();
+            (layout.master_seed, layout.layout.template)
+        };
+        let mut sizes = Vec::new();
+        for encoding in [SaveBodyEncoding::Ron, SaveBodyEncoding::PostcardZstd] {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let registry = type_registry.read();
+            let roots = collect_persisted_entities(world);
+            let dynamic_world = build_persisted_world(world, &registry, roots.into_iter());
+            let body = serialize_body(&dynamic_world, &registry, encoding).unwrap();
+            drop(registry);
+            let contents = encode_save_file(
+                SaveHeader::current(seed)
+                    .with_body_encoding(encoding)
+                    .with_map_template(template),
+                &body,
+            );
+            sizes.push(contents.len());
+            let path = directory.join(format!("{encoding:?}.scn.ron"));
+            std::fs::write(&path, contents).unwrap();
+
+            let loaded = execute_load(world, &path)
+                .unwrap_or_else(|error| panic!("{encoding:?} save must load: {error}"));
+            assert_eq!(loaded.upgraded_from, None);
+            assert_eq!(
+                simulation_state(world),
+                expected,
+                "{encoding:?} body must rehydrate to the saved simulation state"
+            );
+        }
+        std::fs::remove_dir_all(directory).unwrap();
+        assert!(sizes[1] < sizes[0]);
+    }
+
     #[test]
     fn v1_body_with_legacy_reserved_marker_is_rejected() {
         let mut app = legacy_loader_test_app();
         let legacy_body = legacy_body_with_reserved_for_task(&mut app);
-        let v1_contents = encode_save_file(SaveHeader::current(42), &legacy_body);
+        let v1_contents = encode_save_file(SaveHeader::current(42), legacy_body.as_bytes());
 
         assert!(matches!(
-            prepare_load_from_str(app.world(), &v1_contents),
+            prepare_load_from_bytes(app.world(), &v1_contents),
             Err(LoadPreparationError::Schema(_))
         ));
     }
+
+    #[test]
+    fn v1_body_without_post_v1_resources_is_upgraded_before_schema_validation() {
+        let mut source = legacy_loader_test_app();
+        let type_registry = source.world().resource::<AppTypeRegistry>().clone();
+        let registry = type_registry.read();
+        let roots = collect_persisted_entities(source.world_mut());
+        let current_body = build_persisted_world(source.world(), &registry, roots.into_iter())
+            .serialize(&registry)
+            .unwrap();
+        drop(registry);
+
+        let mut document = SaveDocument::parse(&current_body).unwrap();
+        for type_path in [
+            "bevy_app::systems::contracts::model::ContractBook",
+            "hw_core::edicts::DreamEdicts",
+            "hw_core::camera::CameraBookmarks",
+            "hw_core::statistics::ColonyStatistics",
+            "hw_core::alerts::AlertRules",
+        ] {
+            assert!(
+                ops::remove_resource(&mut document.root, type_path)
+                    .unwrap()
+                    .is_some(),
+                "{type_path} must be part of the current body"
+            );
+        }
+        let v1_contents = encode_save_file(
+            SaveHeader {
+                format_version: 1,
+                ..SaveHeader::current(42)
+            },
+            document.to_ron().as_bytes(),
+        );
+
+        let loader = legacy_loader_test_app();
+        let prepared = prepare_load_from_bytes(loader.world(), &v1_contents)
+            .expect("v1 save must load after migration");
+        assert_eq!(prepared.upgraded_from(), Some(1));
+
+        let current = prepare_load_from_bytes(
+            loader.world(),
+            &encode_save_file(SaveHeader::current(42), current_body.as_bytes()),
+        )
+        .unwrap();
+        assert_eq!(current.upgraded_from(), None);
+    }
 }
@@ -0,0 +1,132 @@
+//! 旧 version のセーブ body を現在の形へ書き換える migration pipeline。
+//!
+//! 各 step は `vN` の body を `vN+1` の形へ書き換える。step は registry deserialize より前に
+//! [`SaveValue`] の木の上で動くため、改名・削除済みの型を registry に残す必要がない。
+//! body の形を変えるときは `CURRENT_SAVE_FORMAT_VERSION` を上げ、[`SAVE_MIGRATIONS`] の末尾に
+//! step を 1 つ足し、`migration/fixtures/` に旧 version の固定セーブを置く
+//! （`docs/save_load.md`）。
+
+pub mod ops;
+mod v1_to_v2;
+mod v2_to_v3;
+mod v3_to_v4;
+mod v4_to_v5;
+mod v5_to_v6;
+mod value;
+
+use std::borrow::Cow;
+use std::fmt;
+
+pub use value::{SaveDocument, SaveValue, SaveValueError};
+
+use super::format::CURRENT_SAVE_FORMAT_VERSION;
+
+/// migration で読める最古の body version。
+pub const OLDEST_MIGRATABLE_VERSION: u32 = 1;
+
+/// header 無し legacy v0 の body version。
+///
+/// v0 body は v1 と同じ形で、seed resource と `ReservedForTask` の違いだけを
+/// `load.rs` が DynamicWorld 上の shim で処理する。
+pub const LEGACY_V0_BODY_VERSION: u32 = 1;
+
+/// `from_version` の body を `from_version + 1` の形へ書き換える 1 step。
+pub struct SaveMigration {
+    pub from_version: u32,
+    pub summary: &'static str,
+    pub apply: fn(&mut SaveValue) -> Result<(), String>,
+}
+
+/// `from_version` の昇順に並ぶ migration step。
+pub const SAVE_MIGRATIONS: &[SaveMigration] = &[
+    SaveMigration {
+        from_version: 1,
+        summary: "add contract book and explicit redeemed count",
+        apply: v1_to_v2::migrate,
+    },
+    SaveMigration {
+        from_version: 2,
+        summary: "add dream edicts",
+        apply: v2_to_v3::migrate,
+    },
+    SaveMigration {
+        from_version: 3,
+        summary: "add camera bookmarks and explicit task area sub-rects",
+        apply: v3_to_v4::migrate,
+    },
+    SaveMigration {
+        from_version: 4,
+        summary: "add colony statistics",
+        apply: v4_to_v5::migrate,
+    },
+    SaveMigration {
+        from_version: 5,
+        summary: "add alert rules",
+        apply: v5_to_v6::migrate,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    Syntax(SaveValueError),
+    MissingStep { from_version: u32 },
+    Step { from_version: u32, reason: String },
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(error) => write!(formatter, "save body is not valid RON: {error}"),
+            Self::MissingStep { from_version } => write!(
+                formatter,
+                "no migration step upgrades save body v{from_version}"
+            ),
+            Self::Step {
+                from_version,
+                reason,
+            } => write!(
+                formatter,
+                "migration v{from_version} -> v{} failed: {reason}",
+                from_version + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+/// `from_version` から現在の version までの step を順に返す。
+pub fn migration_path(from_version: u32) -> Result<Vec<&'static SaveMigration>, MigrationError> {
+    (from_version..CURRENT_SAVE_FORMAT_VERSION)
+        .map(|version| {
+            SAVE_MIGRATIONS
+                .iter()
+                .find(|step| step.from_version == version)
+                .ok_or(MigrationError::MissingStep {
+                    from_version: version,
+                })
+        })
+        .collect()
+}
+
+/// `from_version` の body を現在の version の body へ書き換える。
+///
+/// 既に現在の version なら body を解析せずそのまま返す。
+pub fn migrate_body(from_version: u32, body: &str) -> Result<Cow<'_, str>, MigrationError> {
+    if from_version >= CURRENT_SAVE_FORMAT_VERSION {
+        return Ok(Cow::Borrowed(body));
+    }
+    let steps = migration_path(from_version)?;
+    let mut document = SaveDocument::parse(body).map_err(MigrationError::Syntax)?;
+    for step in steps {
+        (step.apply)(&mut document.root).map_err(|reason| MigrationError::Step {
+            from_version: step.from_version,
+            reason,
+        })?;
+    }
+    Ok(Cow::Owned(document.to_ron()))
+}
+
+#[cfg(test)]
+#[path = "migration/tests.rs"]
+mod tests;
@@ -0,0 +1,164 @@
+//! DynamicWorld body の木に対する migration 用の編集操作。
+//!
+//! body の root は `(resources: {"type::Path": value}, entities: {id: (components:
+//! {"type::Path": value})})` の形をとる。各操作は type path の文字列だけで対象を選び、
+//! registry を参照しない。
+
+use super::value::{SaveDocument, SaveValue};
+
+fn resources_mut(root: &mut SaveValue) -> Result<&mut Vec<(SaveValue, SaveValue)>, String> {
+    root.field_mut("resources")
+        .and_then(SaveValue::map_entries_mut)
+        .ok_or_else(|| "body has no `resources` map".to_owned())
+}
+
+fn component_maps_mut(
+    root: &mut SaveValue,
+) -> Result<Vec<&mut Vec<(SaveValue, SaveValue)>>, String> {
+    let entities = root
+        .field_mut("entities")
+        .and_then(SaveValue::map_entries_mut)
+        .ok_or_else(|| "body has no `entities` map".to_owned())?;
+    entities
+        .iter_mut()
+        .map(|(_, entity)| {
+            entity
+                .field_mut("components")
+                .and_then(SaveValue::map_entries_mut)
+                .ok_or_else(|| "entity has no `components` map".to_owned())
+        })
+        .collect()
+}
+
+fn is_type_path(key: &SaveValue, type_path: &str) -> bool {
+    key.as_str() == Some(type_path)
+}
+
+/// `type_path` の resource が無ければ `value` で追加する。追加したら true。
+pub fn insert_resource_if_missing(
+    root: &mut SaveValue,
+    type_path: &str,
+    value: SaveValue,
+) -> Result<bool, String> {
+    let resources = resources_mut(root)?;
+    if resources
+        .iter()
+        .any(|(key, _)| is_type_path(key, type_path))
+    {
+        return Ok(false);
+    }
+    resources.push((SaveValue::string(type_path), value));
+    Ok(true)
+}
+
+/// `type_path` の resource が無ければ、RON で固定した既定値 `default` を解析して追加する。
+pub fn insert_frozen_resource_if_missing(
+    root: &mut SaveValue,
+    type_path: &str,
+    default: &str,
+) -> Result<bool, String> {
+    let value = SaveDocument::parse(default)
+        .map_err(|error| format!("frozen default for `{type_path}` is invalid: {error}"))?
+        .root;
+    insert_resource_if_missing(root, type_path, value)
+}
+
+/// `type_path` の resource を取り除いて返す。
+pub fn remove_resource(root: &mut SaveValue, type_path: &str) -> Result<Option<SaveValue>, String> {
+    let resources = resources_mut(root)?;
+    let index = resources
+        .iter()
+        .position(|(key, _)| is_type_path(key, type_path));
+    Ok(index.map(|index| resources.remove(index).1))
+}
+
+/// 1 つの resource を取り除き、`split` が返す resource 群へ置き換える。
+///
+/// 既に同じ type path を持つ resource は上書きしない。元の resource が無ければ何もしない。
+pub fn split_resource(
+    root: &mut SaveValue,
+    type_path: &str,
+    split: impl FnOnce(SaveValue) -> Result<Vec<(String, SaveValue)>, String>,
+) -> Result<bool, String> {
+    let Some(original) = remove_resource(root, type_path)? else {
+        return Ok(false);
+    };
+    for (split_path, value) in split(original)? {
+        insert_resource_if_missing(root, &split_path, value)?;
+    }
+    Ok(true)
+}
+
+/// 全 entity の `from` component を `to` へ改名する。改名した数を返す。
+pub fn rename_component(root: &mut SaveValue, from: &str, to: &str) -> Result<usize, String> {
+    let mut renamed = 0;
+    for components in component_maps_mut(root)? {
+        for (key, _) in components.iter_mut() {
+            if is_type_path(key, from) {
+                *key = SaveValue::string(to);
+                renamed += 1;
+            }
+        }
+    }
+    Ok(renamed)
+}
+
+/// 全 entity から `type_path` component を取り除く。取り除いた数を返す。
+pub fn remove_component(root: &mut SaveValue, type_path: &str) -> Result<usize, String> {
+    let mut removed = 0;
+    for components in component_maps_mut(root)? {
+        let before = components.len();
+        components.retain(|(key, _)| !is_type_path(key, type_path));
+        removed += before - components.len();
+    }
+    Ok(removed)
+}
+
+/// struct 形の component に `field` が無ければ `default` で追加する。追加した数を返す。
+pub fn add_default_field(
+    root: &mut SaveValue,
+    type_path: &str,
+    field: &str,
+    default: &SaveValue,
+) -> Result<usize, String> {
+    let mut added = 0;
+    for components in component_maps_mut(root)? {
+        for (key, value) in components.iter_mut() {
+            if !is_type_path(key, type_path) {
+                continue;
+            }
+            let SaveValue::Struct { fields, .. } = value else {
+                return Err(format!("component `{type_path}` is not struct-shaped"));
+            };
+            if !fields.iter().any(|(name, _)| name == field) {
+                fields.push((field.to_owned(), default.clone()));
+                added += 1;
+            }
+        }
+    }
+    Ok(added)
+}
+
+/// struct 形の resource に `field` が無ければ `default` で追加する。追加したら true。
+pub fn add_default_resource_field(
+    root: &mut SaveValue,
+    type_path: &str,
+    field: &str,
+    default: SaveValue,
+) -> Result<bool, String> {
+    let resources = resources_mut(root)?;
+    let Some((_, value)) = resources
+        .iter_mut()
+        .find(|(key, _)| is_type_path(key, type_path))
+    else {
+        return Ok(false);
+    };
+    let SaveValue::Struct { fields, .. } = value else {
+        return Err(format!("resource `{type_path}` is not struct-shaped"));
+    };
+    if fields.iter().any(|(name, _)| name == field) {
+        return Ok(false);
+    }
+    fields.push((field.to_owned(), default));
+    Ok(true)
+}
@@ -0,0 +1,286 @@
+use super::super::format::{SaveBody, SaveFormat, decode_save_file};
+use super::*;
+
+/// Frozen v1 save written before the post-v1 colony resources existed.
+const V1_COLONY_FIXTURE: &str = include_str!("fixtures/v1_colony.scn.ron");
+
+fn resource<'a>(root: &'a SaveValue, type_path: &str) -> Option<&'a SaveValue> {
+    root.field("resources")?
+        .map_entries()?
+        .iter()
+        .find(|(key, _)| key.as_str() == Some(type_path))
+        .map(|(_, value)| value)
+}
+
+fn component<'a>(root: &'a SaveValue, entity: &str, type_path: &str) -> Option<&'a SaveValue> {
+    root.field("entities")?
+        .map_entries()?
+        .iter()
+        .find(|(key, _)| *key == SaveValue::Atom(entity.to_owned()))?
+        .1
+        .field("components")?
+        .map_entries()?
+        .iter()
+        .find(|(key, _)| key.as_str() == Some(type_path))
+        .map(|(_, value)| value)
+}
+
+fn fixture_body(contents: &str) -> (u32, &str) {
+    let decoded = decode_save_file(contents.as_bytes()).expect("fixture header must decode");
+    let SaveFormat::Versioned(header) = decoded.format else {
+        panic!("fixture must carry a header");
+    };
+    let SaveBody::Ron(body) = decoded.body else {
+        panic!("fixture must carry a RON body");
+    };
+    (header.format_version, body)
+}
+
+#[test]
+fn registry_steps_are_contiguous_up_to_the_current_version() {
+    for (index, step) in SAVE_MIGRATIONS.iter().enumerate() {
+        assert_eq!(step.from_version, OLDEST_MIGRATABLE_VERSION + index as u32);
+        assert!(!step.summary.is_empty());
+    }
+    assert_eq!(
+        OLDEST_MIGRATABLE_VERSION + SAVE_MIGRATIONS.len() as u32,
+        CURRENT_SAVE_FORMAT_VERSION
+    );
+    for version in OLDEST_MIGRATABLE_VERSION..=CURRENT_SAVE_FORMAT_VERSION {
+        assert_eq!(
+            migration_path(version).unwrap().len() as u32,
+            CURRENT_SAVE_FORMAT_VERSION - version
+        );
+    }
+}
+
+#[test]
+fn version_without_a_step_is_reported() {
+    assert!(matches!(
+        migration_path(OLDEST_MIGRATABLE_VERSION - 1),
+        Err(MigrationError::MissingStep { from_version: 0 })
+    ));
+}
+
+#[test]
+fn current_body_is_returned_without_parsing() {
+    let body = "this body is deliberately not RON";
+
+    let migrated = migrate_body(CURRENT_SAVE_FORMAT_VERSION, body).unwrap();
+
+    assert!(matches!(migrated, Cow::Borrowed(unchanged) if unchanged == body));
+}
+
+#[test]
+fn value_tree_keeps_variant_names_and_literal_spelling() {
+    let source = r##"#![enable(implicit_some)]
+(
+  // comments are dropped
+  kind: ResourceBelow(resource: Wood, threshold: 20),
+  unit: GridBlackout,
+  maybe: Some(Water),
+  none: None,
+  tuple: (-1.5, 2e-3, 0x1F),
+  keys: {(0, -1): 42, "name": 'x'},
+  text: "quote \" and \\ slash",
+  raw: r#"raw "text""#,
+  bytes: b"abc",
+  nested: [(), (a: [])],
+)"##;
+
+    let document = SaveDocument::parse(source).unwrap();
+    let written = document.to_ron();
+
+    assert_eq!(document.attributes, vec!["#![enable(implicit_some)]"]);
+    assert_eq!(SaveDocument::parse(&written).unwrap(), document);
+    assert!(written.contains("kind: ResourceBelow(resource: Wood, threshold: 20)"));
+    assert!(written.contains("maybe: Some(Water)"));
+    assert!(written.contains("tuple: (-1.5, 2e-3, 0x1F)"));
+    assert!(written.contains(r#""quote \" and \\ slash""#));
+    assert!(written.contains(r##"r#"raw "text""#"##));
+    assert!(!written.contains("comments"));
+}
+
+#[test]
+fn malformed_body_reports_a_syntax_error() {
+    for body in [
+        "(resources: {",
+        "(a: \"unterminated)",
+        "(a: 1) trailing",
+        "",
+    ] {
+        assert!(
+            matches!(
+                migrate_body(OLDEST_MIGRATABLE_VERSION, body),
+                Err(MigrationError::Syntax(_))
+            ),
+            "{body:?} must be rejected"
+        );
+    }
+}
+
+#[test]
+fn v1_fixture_gains_post_v1_resources_and_default_fields() {
+    let (version, body) = fixture_body(V1_COLONY_FIXTURE);
+    assert_eq!(version, 1);
+    let original = SaveDocument::parse(body).unwrap().root;
+
+    let migrated = migrate_body(version, body).unwrap();
+    let root = SaveDocument::parse(&migrated).unwrap().root;
+
+    for type_path in [
+        "bevy_app::systems::contracts::model::ContractBook",
+        "hw_core::edicts::DreamEdicts",
+        "hw_core::camera::CameraBookmarks",
+        "hw_core::statistics::ColonyStatistics",
+        "hw_core::alerts::AlertRules",
+    ] {
+        assert!(resource(&root, type_path).is_some(), "{type_path} missing");
+    }
+    assert_eq!(
+        resource(&root, "hw_core::alerts::AlertRules").and_then(|rules| rules.field("next_id")),
+        Some(&SaveValue::Atom("6".to_owned()))
+    );
+    assert_eq!(
+        resource(&root, "hw_core::population::PopulationManager")
+            .and_then(|population| population.field("total_redeemed")),
+        Some(&SaveValue::Atom("0".to_owned()))
+    );
+    assert_eq!(
+        component(&root, "4294967293", "hw_core::area::TaskArea")
+            .and_then(|area| area.field("extra_rects")),
+        Some(&SaveValue::Seq(Vec::new()))
+    );
+
+    // Pre-existing state is carried over untouched.
+    for type_path in ["hw_core::time::GameTime", "hw_world::map::WorldMap"] {
+        assert_eq!(resource(&root, type_path), resource(&original, type_path));
+    }
+    assert_eq!(
+        component(&root, "4294967289", "hw_logistics::zone::Stockpile"),
+        component(&original, "4294967289", "hw_logistics::zone::Stockpile")
+    );
+    assert_eq!(
+        component(&root, "4294967293", "hw_core::familiar::Familiar")
+            .and_then(|familiar| familiar.field("name")),
+        Some(&SaveValue::Atom(r#""Imp \"Gnash\"""#.to_owned()))
+    );
+}
+
+#[test]
+fn steps_are_idempotent_and_keep_existing_resources() {
+    let (_, body) = fixture_body(V1_COLONY_FIXTURE);
+    let mut root = SaveDocument::parse(body).unwrap().root;
+    let custom_edicts = SaveDocument::parse("(active: [(kind: Overtime, expires_at: 90.0)])")
+        .unwrap()
+        .root;
+    ops::insert_resource_if_missing(
+        &mut root,
+        "hw_core::edicts::DreamEdicts",
+        custom_edicts.clone(),
+    )
+    .unwrap();
+    let apply_all = |root: &mut SaveValue| {
+        for step in SAVE_MIGRATIONS {
+            (step.apply)(root).unwrap();
+        }
+    };
+
+    apply_all(&mut root);
+    let once = root.clone();
+    apply_all(&mut root);
+
+    assert_eq!(root, once);
+    assert_eq!(
+        resource(&root, "hw_core::edicts::DreamEdicts"),
+        Some(&custom_edicts)
+    );
+}
+
+#[test]
+fn intermediate_version_only_runs_later_steps() {
+    let (_, body) = fixture_body(V1_COLONY_FIXTURE);
+
+    let migrated = migrate_body(3, body).unwrap();
+    let root = SaveDocument::parse(&migrated).unwrap().root;
+
+    assert!(resource(&root, "bevy_app::systems::contracts::model::ContractBook").is_none());
+    assert!(resource(&root, "hw_core::edicts::DreamEdicts").is_none());
+    for type_path in [
+        "hw_core::camera::CameraBookmarks",
+        "hw_core::statistics::ColonyStatistics",
+        "hw_core::alerts::AlertRules",
+    ] {
+        assert!(resource(&root, type_path).is_some(), "{type_path} missing");
+    }
+}
+
+#[test]
+fn step_rejects_a_body_without_the_dynamic_world_maps() {
+    assert!(matches!(
+        migrate_body(1, "(entities: {})"),
+        Err(MigrationError::Step {
+            from_version: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn component_and_resource_operations_edit_every_matching_entry() {
+    let (_, body) = fixture_body(V1_COLONY_FIXTURE);
+    let mut root = SaveDocument::parse(body).unwrap().root;
+
+    assert_eq!(
+        ops::rename_component(
+            &mut root,
+            "hw_logistics::zone::Stockpile",
+            "hw_logistics::zone::StockpileCell",
+        ),
+        Ok(1)
+    );
+    assert!(component(&root, "4294967289", "hw_logistics::zone::StockpileCell").is_some());
+    assert!(component(&root, "4294967289", "hw_logistics::zone::Stockpile").is_none());
+
+    assert_eq!(
+        ops::remove_component(
+            &mut root,
+            "bevy_transform::components::transform::Transform"
+        ),
+        Ok(1)
+    );
+    assert_eq!(
+        ops::add_default_field(
+            &mut root,
+            "hw_core::familiar::Familiar",
+            "command_radius",
+            &SaveValue::Atom("1.0".to_owned()),
+        ),
+        Ok(0),
+        "existing fields are never overwritten"
+    );
+
+    let split = ops::split_resource(&mut root, "hw_core::time::GameTime", |game_time| {
+        let seconds = game_time
+            .field("seconds")
+            .cloned()
+            .ok_or_else(|| "GameTime has no seconds".to_owned())?;
+        Ok(vec![
+            (
+                "hw_core::time::GameClock".to_owned(),
+                SaveValue::unnamed_struct([("seconds", seconds)]),
+            ),
+            (
+                "hw_core::time::GameCalendar".to_owned(),
+                SaveValue::unnamed_struct([("day", SaveValue::Atom("2".to_owned()))]),
+            ),
+        ])
+    });
+    assert_eq!(split, Ok(true));
+    assert!(resource(&root, "hw_core::time::GameTime").is_none());
+    assert_eq!(
+        resource(&root, "hw_core::time::GameClock").and_then(|clock| clock.field("seconds")),
+        Some(&SaveValue::Atom("412.5".to_owned()))
+    );
+    assert!(resource(&root, "hw_core::time::GameCalendar").is_some());
+}
@@ -0,0 +1,28 @@
+//! v1 → v2: 契約の Resource を既定値で補う。
+//!
+//! v2 では `ContractBook` が必須になった。既定値は v2 時点の `Default` 実装を RON で
+//! 固定したもので、後から型の既定値が変わってもこの step の出力は変えない
+//! （変更は次の step で行う）。
+
+use super::ops::{add_default_resource_field, insert_frozen_resource_if_missing};
+use super::value::SaveValue;
+
+const CONTRACT_BOOK_DEFAULT: &str = "(active: [], next_template: 0, next_id: 0, next_issue_at: 0.0, \
+     last_evaluated_at: 0.0, completed: 0, failed: 0, unlocked_buildings: [])";
+
+pub(super) fn migrate(root: &mut SaveValue) -> Result<(), String> {
+    insert_frozen_resource_if_missing(
+        root,
+        "bevy_app::systems::contracts::model::ContractBook",
+        CONTRACT_BOOK_DEFAULT,
+    )?;
+
+    // v1 で `#[reflect(default)]` により読めていた field を明示的に持たせる。
+    add_default_resource_field(
+        root,
+        "hw_core::population::PopulationManager",
+        "total_redeemed",
+        SaveValue::Atom("0".to_owned()),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! v2 → v3: Dream 勅令の Resource を既定値で補う。
+
+use super::ops::insert_frozen_resource_if_missing;
+use super::value::SaveValue;
+
+pub(super) fn migrate(root: &mut SaveValue) -> Result<(), String> {
+    insert_frozen_resource_if_missing(root, "hw_core::edicts::DreamEdicts", "(active: [])")?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+//! v3 → v4: カメラブックマークの Resource を既定値で補う。
+
+use super::ops::{add_default_field, insert_frozen_resource_if_missing};
+use super::value::SaveValue;
+
+pub(super) fn migrate(root: &mut SaveValue) -> Result<(), String> {
+    insert_frozen_resource_if_missing(
+        root,
+        "hw_core::camera::CameraBookmarks",
+        "(slots: (None, None, None, None))",
+    )?;
+
+    // v3 以前で `#[reflect(default)]` により読めていた field を明示的に持たせる。
+    add_default_field(
+        root,
+        "hw_core::area::TaskArea",
+        "extra_rects",
+        &SaveValue::Seq(Vec::new()),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! v4 → v5: コロニー統計の Resource を既定値で補う。
+
+use super::ops::insert_frozen_resource_if_missing;
+use super::value::SaveValue;
+
+pub(super) fn migrate(root: &mut SaveValue) -> Result<(), String> {
+    insert_frozen_resource_if_missing(
+        root,
+        "hw_core::statistics::ColonyStatistics",
+        "(samples: [], next_sample_at: 0.0, pending_completions: [])",
+    )?;
+    Ok(())
+}
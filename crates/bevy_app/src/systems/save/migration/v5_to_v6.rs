@@ -0,0 +1,17 @@
+//! v5 → v6: アラート規則の Resource を既定の規則で補う。
+
+use super::ops::insert_frozen_resource_if_missing;
+use super::value::SaveValue;
+
+const ALERT_RULES_DEFAULT: &str = "(rules: [\
+     (id: 1, enabled: true, condition: ResourceBelow(resource: Wood, threshold: 20)), \
+     (id: 2, enabled: true, condition: NoRestCapacity), \
+     (id: 3, enabled: true, condition: GridBlackout), \
+     (id: 4, enabled: true, condition: SoulStressAbove(threshold: 0.9)), \
+     (id: 5, enabled: true, condition: FamiliarWithoutWorkers(seconds: 60.0))\
+     ], next_id: 6)";
+
+pub(super) fn migrate(root: &mut SaveValue) -> Result<(), String> {
+    insert_frozen_resource_if_missing(root, "hw_core::alerts::AlertRules", ALERT_RULES_DEFAULT)?;
+    Ok(())
+}
@@ -0,0 +1,430 @@
+//! Migration 用の可逆な RON 値ツリー。
+//!
+//! `ron::Value` は enum variant 名と struct 名を捨てるため、書き戻した body は
+//! reflect deserialize できない。ここでは識別子・数値・文字列をソース上の綴りのまま
+//! 保持し、構造（struct field、map entry、seq 要素）だけを編集できるようにする。
+//! コメントと空白は保持しない。
+
+use std::fmt;
+
+/// DynamicWorld RON body の 1 ノード。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveValue {
+    /// 数値・文字列・文字・byte string。ソース上の綴りをそのまま保持する。
+    Atom(String),
+    /// 括弧を伴わない識別子（unit variant、`None`、`true` など）。
+    Ident(String),
+    /// `[a, b]`
+    Seq(Vec<SaveValue>),
+    /// `{key: value}`。entry の順序を保持する。
+    Map(Vec<(SaveValue, SaveValue)>),
+    /// `(a, b)` / `Name(a, b)`。`()` は要素なしの tuple として表す。
+    Tuple {
+        name: Option<String>,
+        items: Vec<SaveValue>,
+    },
+    /// `(field: value)` / `Name(field: value)`
+    Struct {
+        name: Option<String>,
+        fields: Vec<(String, SaveValue)>,
+    },
+}
+
+impl SaveValue {
+    /// 引用符付きの文字列 atom を作る。
+    pub fn string(text: &str) -> Self {
+        let mut quoted = String::with_capacity(text.len() + 2);
+        quoted.push('"');
+        for character in text.chars() {
+            if matches!(character, '"' | '\\') {
+                quoted.push('\\');
+            }
+            quoted.push(character);
+        }
+        quoted.push('"');
+        Self::Atom(quoted)
+    }
+
+    /// 名前のない struct を作る。
+    pub fn unnamed_struct(fields: impl IntoIterator<Item = (&'static str, SaveValue)>) -> Self {
+        Self::Struct {
+            name: None,
+            fields: fields
+                .into_iter()
+                .map(|(field, value)| (field.to_owned(), value))
+                .collect(),
+        }
+    }
+
+    /// エスケープを含まない文字列 atom の中身を返す。
+    ///
+    /// type path や field 名の照合専用で、エスケープを含む文字列は `None` とする。
+    pub fn as_str(&self) -> Option<&str> {
+        let Self::Atom(atom) = self else {
+            return None;
+        };
+        atom.strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .filter(|inner| !inner.contains('\\'))
+    }
+
+    pub fn field(&self, name: &str) -> Option<&SaveValue> {
+        let Self::Struct { fields, .. } = self else {
+            return None;
+        };
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut SaveValue> {
+        let Self::Struct { fields, .. } = self else {
+            return None;
+        };
+        fields
+            .iter_mut()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn map_entries(&self) -> Option<&[(SaveValue, SaveValue)]> {
+        match self {
+            Self::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn map_entries_mut(&mut self) -> Option<&mut Vec<(SaveValue, SaveValue)>> {
+        match self {
+            Self::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Self::Atom(text) | Self::Ident(text) => out.push_str(text),
+            Self::Seq(items) => {
+                out.push('[');
+                write_separated(out, items, |out, item| item.write(out));
+                out.push(']');
+            }
+            Self::Map(entries) => {
+                out.push('{');
+                write_separated(out, entries, |out, (key, value)| {
+                    key.write(out);
+                    out.push_str(": ");
+                    value.write(out);
+                });
+                out.push('}');
+            }
+            Self::Tuple { name, items } => {
+                out.push_str(name.as_deref().unwrap_or_default());
+                out.push('(');
+                write_separated(out, items, |out, item| item.write(out));
+                out.push(')');
+            }
+            Self::Struct { name, fields } => {
+                out.push_str(name.as_deref().unwrap_or_default());
+                out.push('(');
+                write_separated(out, fields, |out, (field, value)| {
+                    out.push_str(field);
+                    out.push_str(": ");
+                    value.write(out);
+                });
+                out.push(')');
+            }
+        }
+    }
+}
+
+fn write_separated<T>(out: &mut String, items: &[T], mut write: impl FnMut(&mut String, &T)) {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        write(out, item);
+    }
+}
+
+/// `#![enable(...)]` 属性と root 値からなる RON 文書。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDocument {
+    /// 先頭の `#![...]` 属性。ソース上の綴りのまま保持する。
+    pub attributes: Vec<String>,
+    pub root: SaveValue,
+}
+
+impl SaveDocument {
+    pub fn parse(text: &str) -> Result<Self, SaveValueError> {
+        let mut parser = Parser { text, position: 0 };
+        let mut attributes = Vec::new();
+        parser.skip_trivia()?;
+        while parser.rest().starts_with("#!") {
+            attributes.push(parser.attribute()?);
+            parser.skip_trivia()?;
+        }
+        let root = parser.value()?;
+        parser.skip_trivia()?;
+        if parser.position != text.len() {
+            return Err(parser.error("trailing characters after the root value"));
+        }
+        Ok(Self { attributes, root })
+    }
+
+    pub fn to_ron(&self) -> String {
+        let mut out = String::new();
+        for attribute in &self.attributes {
+            out.push_str(attribute);
+            out.push('\n');
+        }
+        self.root.write(&mut out);
+        out
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveValueError {
+    pub position: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for SaveValueError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} at byte {}", self.reason, self.position)
+    }
+}
+
+impl std::error::Error for SaveValueError {}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn error(&self, reason: &'static str) -> SaveValueError {
+        SaveValueError {
+            position: self.position,
+            reason,
+        }
+    }
+
+    fn bump(&mut self, character: char) {
+        self.position += character.len_utf8();
+    }
+
+    fn expect(&mut self, expected: char, reason: &'static str) -> Result<(), SaveValueError> {
+        self.skip_trivia()?;
+        if self.peek() == Some(expected) {
+            self.bump(expected);
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), SaveValueError> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.position += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                let end = trimmed
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated block comment"))?;
+                self.position += end + 2;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn attribute(&mut self) -> Result<String, SaveValueError> {
+        let start = self.position;
+        let end = self
+            .rest()
+            .find(']')
+            .ok_or_else(|| self.error("unterminated attribute"))?;
+        self.position += end + 1;
+        Ok(self.text[start..self.position].to_owned())
+    }
+
+    fn value(&mut self) -> Result<SaveValue, SaveValueError> {
+        self.skip_trivia()?;
+        let rest = self.rest();
+        match self.peek() {
+            Some('[') => self.seq(),
+            Some('{') => self.map(),
+            Some('(') => self.parenthesized(None),
+            Some('"') => self.quoted('"'),
+            Some('\'') => self.quoted('\''),
+            Some('b') if rest.starts_with("b\"") || rest.starts_with("b'") => {
+                let quote = rest[1..].chars().next().unwrap_or('"');
+                self.position += 1;
+                let SaveValue::Atom(text) = self.quoted(quote)? else {
+                    unreachable!("quoted literals are atoms");
+                };
+                Ok(SaveValue::Atom(format!("b{text}")))
+            }
+            Some('r') if rest.starts_with("r\"") || rest.starts_with("r#\"") => self.raw_string(),
+            Some(character) if character.is_ascii_digit() || "+-.".contains(character) => {
+                Ok(self.number())
+            }
+            Some(character) if character.is_alphabetic() || character == '_' => {
+                let name = self.identifier();
+                self.skip_trivia()?;
+                if self.peek() == Some('(') {
+                    self.parenthesized(Some(name))
+                } else {
+                    Ok(SaveValue::Ident(name))
+                }
+            }
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.position;
+        if self.rest().starts_with("r#") {
+            self.position += 2;
+        }
+        let length = self
+            .rest()
+            .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+            .unwrap_or(self.rest().len());
+        self.position += length;
+        self.text[start..self.position].to_owned()
+    }
+
+    fn number(&mut self) -> SaveValue {
+        let start = self.position;
+        let length = self
+            .rest()
+            .find(|character: char| {
+                !(character.is_ascii_alphanumeric() || "._+-".contains(character))
+            })
+            .unwrap_or(self.rest().len());
+        self.position += length;
+        SaveValue::Atom(self.text[start..self.position].to_owned())
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<SaveValue, SaveValueError> {
+        let start = self.position;
+        self.bump(quote);
+        let mut escaped = false;
+        while let Some(character) = self.peek() {
+            self.bump(character);
+            if escaped {
+                escaped = false;
+            } else if character == '\\' {
+                escaped = true;
+            } else if character == quote {
+                return Ok(SaveValue::Atom(self.text[start..self.position].to_owned()));
+            }
+        }
+        Err(SaveValueError {
+            position: start,
+            reason: "unterminated string or char literal",
+        })
+    }
+
+    fn raw_string(&mut self) -> Result<SaveValue, SaveValueError> {
+        let start = self.position;
+        self.position += 1;
+        let hashes = self.rest().chars().take_while(|&c| c == '#').count();
+        self.position += hashes + 1;
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let end = self.rest().find(&terminator).ok_or(SaveValueError {
+            position: start,
+            reason: "unterminated raw string",
+        })?;
+        self.position += end + terminator.len();
+        Ok(SaveValue::Atom(self.text[start..self.position].to_owned()))
+    }
+
+    /// `close` までの `,` 区切り要素を読む。末尾の `,` を許す。
+    fn separated<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, SaveValueError>,
+    ) -> Result<Vec<T>, SaveValueError> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.peek() == Some(close) {
+                self.bump(close);
+                return Ok(items);
+            }
+            items.push(item(self)?);
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(',') => self.bump(','),
+                Some(character) if character == close => {}
+                _ => return Err(self.error("expected `,` or a closing delimiter")),
+            }
+        }
+    }
+
+    fn seq(&mut self) -> Result<SaveValue, SaveValueError> {
+        self.bump('[');
+        self.separated(']', Self::value).map(SaveValue::Seq)
+    }
+
+    fn map(&mut self) -> Result<SaveValue, SaveValueError> {
+        self.bump('{');
+        self.separated('}', |parser| {
+            let key = parser.value()?;
+            parser.expect(':', "expected `:` after a map key")?;
+            Ok((key, parser.value()?))
+        })
+        .map(SaveValue::Map)
+    }
+
+    fn parenthesized(&mut self, name: Option<String>) -> Result<SaveValue, SaveValueError> {
+        self.bump('(');
+        if self.starts_struct_field()? {
+            let fields = self.separated(')', |parser| {
+                parser.skip_trivia()?;
+                let field = parser.identifier();
+                if field.is_empty() {
+                    return Err(parser.error("expected a struct field name"));
+                }
+                parser.expect(':', "expected `:` after a struct field name")?;
+                Ok((field, parser.value()?))
+            })?;
+            Ok(SaveValue::Struct { name, fields })
+        } else {
+            let items = self.separated(')', Self::value)?;
+            Ok(SaveValue::Tuple { name, items })
+        }
+    }
+
+    /// `(` の直後が `field:` なら struct とみなす。位置は進めない。
+    fn starts_struct_field(&mut self) -> Result<bool, SaveValueError> {
+        self.skip_trivia()?;
+        let start = self.position;
+        let is_field = match self.peek() {
+            Some(character) if character.is_alphabetic() || character == '_' => {
+                self.identifier();
+                self.skip_trivia()?;
+                self.rest().starts_with(':') && !self.rest().starts_with("::")
+            }
+            _ => false,
+        };
+        self.position = start;
+        Ok(is_field)
+    }
+}
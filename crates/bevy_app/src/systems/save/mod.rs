@@ -6,8 +6,11 @@
 //! （plan が想定していた複数フレームにまたがる `Time<Virtual>` 一時停止パイプラインは
 //! 採用していない。1フレーム内で完結させることで実装・検証を単純化した）。
 
+mod body;
 mod format;
+pub mod inspect;
 mod load;
+pub mod migration;
 mod rehydrate;
 mod reset;
 mod saving;
@@ -19,12 +22,17 @@ use bevy::prelude::*;
 
 use crate::systems::settings::SettingsPersistenceSet;
 
+pub use format::SaveBodyEncoding;
+#[cfg(feature = "profiling")]
+pub use state::SaveLoadPerfMetrics;
 pub use state::{
-    SAVE_FILE_PATH, SaveLoadFailureKind, SaveLoadOperation, SaveLoadOutcome, SaveLoadResult,
-    SaveLoadState, SavePath,
+    SAVE_ENCODING_ENV, SAVE_FILE_PATH, SaveEncoding, SaveLoadFailureKind, SaveLoadOperation,
+    SaveLoadOutcome, SaveLoadResult, SaveLoadState, SavePath,
 };
 
 use load::load_world_system;
+#[cfg(test)]
+pub(crate) use load::replace_world_from_durable_source;
 pub(crate) use reset::{
     register_load_reset_hook, reset_root_interaction_state, reset_runtime_caches,
 };
@@ -44,7 +52,10 @@ impl Plugin for SavePlugin {
 
         app.init_resource::<SaveLoadState>();
         app.init_resource::<SavePath>();
+        app.insert_resource(SaveEncoding::from_env());
         app.init_resource::<hw_core::WorldEpoch>();
+        #[cfg(feature = "profiling")]
+        app.init_resource::<SaveLoadPerfMetrics>();
         app.add_message::<SaveLoadOutcome>();
 
         // These root-owned hooks are registered here because they have no leaf
@@ -101,6 +112,7 @@ fn clear_save_load_outcomes(world: &mut World) {
 mod tests {
     use super::*;
     use crate::test_support::minimal_app;
+    use hw_core::locale::{Locale, Localization};
     use hw_ui::HwUiPlugin;
     use hw_ui::notifications::{
         NotificationCenter, NotificationRetention, NotificationSeverity, UserFacingNotification,
@@ -265,6 +277,7 @@ mod tests {
             let mut app = minimal_app();
             app.add_plugins(HwUiPlugin)
                 .add_message::<SaveLoadOutcome>()
+                .insert_resource(Localization::shipped(Locale::En))
                 .insert_resource(SaveLoadState::LoadRequested)
                 .insert_resource(SavePath::new("slot-a.ron"))
                 .add_systems(
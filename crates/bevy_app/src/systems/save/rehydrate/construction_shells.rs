@@ -43,7 +43,7 @@ impl BlueprintSpriteHandles {
             BuildingType::Floor => self.mud_floor.clone(),
             BuildingType::Tank => self.tank_empty.clone(),
             BuildingType::MudMixer => self.mud_mixer.clone(),
-            BuildingType::RestArea => self.rest_area.clone(),
+            BuildingType::RestArea | BuildingType::RedemptionShrine => self.rest_area.clone(),
             BuildingType::Bridge => self.bridge.clone(),
             BuildingType::SandPile => self.sand_pile.clone(),
             BuildingType::BonePile | BuildingType::SoulSpa | BuildingType::OutdoorLamp => {
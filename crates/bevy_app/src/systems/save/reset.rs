@@ -7,8 +7,9 @@
 use bevy::prelude::*;
 
 use hw_core::WorldEpoch;
+use hw_core::camera::CameraFollow;
 use hw_core::game_state::PlayMode;
-use hw_core::selection::{HoveredEntity, SelectedEntity};
+use hw_core::selection::{HoveredEntity, SelectedEntities, SelectedEntity, SelectionBoxDrag};
 use hw_logistics::resource_cache::SharedResourceCache;
 use hw_logistics::tile_index::TileSiteIndex;
 use hw_logistics::transport_request::TransportRequestMetrics;
@@ -34,7 +35,7 @@ use crate::app_contexts::{
     BuildContext, CompanionPlacementState, MoveContext, MovePlacementState, TaskContext,
     ZoneContext,
 };
-use crate::systems::command::StockpilePolicyRangeEditState;
+use crate::systems::command::{StockpilePolicyRangeEditState, SupplyRouteDraft};
 use crate::systems::energy::grid_recalc::EnergyUpdateDirty;
 use crate::systems::familiar_ai::perceive::resource_sync::{
     ReservationSignatureCache, ReservationSyncTimer,
@@ -98,6 +99,9 @@ pub(super) fn run_load_resets(world: &mut World) {
 /// Resets root-owned selection and input contexts.
 pub(crate) fn reset_root_interaction_state(world: &mut World) {
     reset_existing_resource::<SelectedEntity>(world);
+    reset_existing_resource::<SelectedEntities>(world);
+    reset_existing_resource::<SelectionBoxDrag>(world);
+    reset_existing_resource::<CameraFollow>(world);
     reset_existing_resource::<HoveredEntity>(world);
     reset_existing_resource::<BuildContext>(world);
     reset_existing_resource::<MoveContext>(world);
@@ -106,6 +110,7 @@ pub(crate) fn reset_root_interaction_state(world: &mut World) {
     reset_existing_resource::<TaskContext>(world);
     reset_existing_resource::<CompanionPlacementState>(world);
     reset_existing_resource::<StockpilePolicyRangeEditState>(world);
+    reset_existing_resource::<SupplyRouteDraft>(world);
 
     if let Some(mut next_play_mode) = world.get_resource_mut::<NextState<PlayMode>>() {
         next_play_mode.set(PlayMode::Normal);
@@ -292,6 +297,12 @@ mod tests {
         let mut world = World::new();
         let stale = world.spawn_empty().id();
         world.insert_resource(SelectedEntity(Some(stale)));
+        let mut selected_entities = SelectedEntities::default();
+        selected_entities.set_single(Some(stale));
+        world.insert_resource(selected_entities);
+        let mut camera_follow = CameraFollow::default();
+        camera_follow.start(stale);
+        world.insert_resource(camera_follow);
         world.insert_resource(HoveredEntity(Some(stale)));
         world.insert_resource(MoveContext(Some(stale)));
         world.insert_resource(MovePlacementState(Some(PendingMovePlacement {
@@ -304,12 +315,17 @@ mod tests {
                 ..default()
             }),
         });
+        world.insert_resource(SupplyRouteDraft {
+            source: Some(stale),
+        });
         world.insert_resource(TaskContext::default());
         world.insert_resource(NextState::<PlayMode>::Pending(PlayMode::BuildingMove));
 
         reset_root_interaction_state(&mut world);
 
         assert!(world.resource::<SelectedEntity>().0.is_none());
+        assert!(world.resource::<SelectedEntities>().is_empty());
+        assert!(world.resource::<CameraFollow>().target.is_none());
         assert!(world.resource::<HoveredEntity>().0.is_none());
         assert!(world.resource::<MoveContext>().0.is_none());
         assert!(world.resource::<MovePlacementState>().0.is_none());
@@ -319,6 +335,7 @@ mod tests {
                 .patch
                 .is_none()
         );
+        assert!(world.resource::<SupplyRouteDraft>().source.is_none());
         assert!(matches!(
             world.resource::<NextState<PlayMode>>(),
             NextState::Pending(PlayMode::Normal)
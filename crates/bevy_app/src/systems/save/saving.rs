@@ -1,7 +1,7 @@
 //! ワールドのセーブ（exclusive system）。
 //!
-//! `schema.rs` が構成する DynamicWorld allow-listで構築し、RON にシリアライズして
-//! ファイルへ書き込む。
+//! `schema.rs` が構成する DynamicWorld allow-listで構築し、`SaveEncoding` が選ぶ符号化
+//! （既定は postcard + zstd、debug 用に RON）でシリアライズしてファイルへ書き込む。
 //!
 //! # 設計上の逸脱（plan からの変更点）
 //! plan の Phase A は「セーブ前にライブワールドを正規化する」（例: `AssignedTask` を
@@ -19,9 +19,12 @@ use std::time::Instant;
 
 use bevy::prelude::*;
 
-use super::format::{SaveHeader, encode_save_file};
+use super::body::serialize_body;
+use super::format::{SaveBodyEncoding, SaveHeader, encode_save_file};
 use super::schema::{build_persisted_world, collect_persisted_entities};
-use super::state::{SaveLoadFailureKind, SaveLoadResult, SavePath};
+#[cfg(feature = "profiling")]
+use super::state::SaveLoadPerfMetrics;
+use super::state::{SaveEncoding, SaveLoadFailureKind, SaveLoadResult, SavePath};
 
 static NEXT_TEMP_SAVE_FILE_ID: AtomicU64 = AtomicU64::new(0);
 const TEMP_FILE_ATTEMPTS: usize = 16;
@@ -54,17 +57,31 @@ impl fmt::Display for SaveExecutionError {
 
 pub(super) fn save_world_system(world: &mut World) -> SaveLoadResult {
     let started = Instant::now();
-    let master_seed = world
-        .resource::<crate::world::map::GeneratedWorldLayoutResource>()
-        .master_seed;
+    let generated_layout = world.resource::<crate::world::map::GeneratedWorldLayoutResource>();
+    let master_seed = generated_layout.master_seed;
+    let map_template = generated_layout.layout.template;
     let save_path = world.resource::<SavePath>().as_path().to_path_buf();
+    let encoding = world
+        .get_resource::<SaveEncoding>()
+        .copied()
+        .unwrap_or_default()
+        .0;
 
+    let mut written_bytes = 0;
     let execution = execute_save_with(
         || {
-            let body = serialize_world_body(world)?;
-            Ok(encode_save_file(SaveHeader::current(master_seed), &body))
+            let body = serialize_world_body(world, encoding)?;
+            Ok(encode_save_file(
+                SaveHeader::current(master_seed)
+                    .with_body_encoding(encoding)
+                    .with_map_template(map_template),
+                &body,
+            ))
+        },
+        |contents| {
+            written_bytes = contents.len();
+            write_save_file(&save_path, contents)
         },
-        |contents| write_save_file(&save_path, contents),
     );
 
     if let Err(error) = execution {
@@ -73,10 +90,17 @@ pub(super) fn save_world_system(world: &mut World) -> SaveLoadResult {
     }
 
     let elapsed = started.elapsed();
+    #[cfg(feature = "profiling")]
+    if let Some(mut metrics) = world.get_resource_mut::<SaveLoadPerfMetrics>() {
+        metrics.record_save(elapsed, written_bytes);
+    }
     if elapsed.as_millis() > 100 {
-        warn!("Save took {elapsed:?} (>100ms)");
+        warn!("Save took {elapsed:?} (>100ms, {written_bytes} bytes)");
     } else {
-        info!("World saved to {} in {elapsed:?}", save_path.display());
+        info!(
+            "World saved to {} ({written_bytes} bytes) in {elapsed:?}",
+            save_path.display()
+        );
     }
     SaveLoadResult::Succeeded
 }
@@ -84,15 +108,18 @@ pub(super) fn save_world_system(world: &mut World) -> SaveLoadResult {
 /// Runs the production encode/write branch behind a small injectable seam so
 /// both failure paths use the same classification in tests and at runtime.
 fn execute_save_with(
-    encode: impl FnOnce() -> Result<String, SaveExecutionError>,
-    write: impl FnOnce(&str) -> io::Result<()>,
+    encode: impl FnOnce() -> Result<Vec<u8>, SaveExecutionError>,
+    write: impl FnOnce(&[u8]) -> io::Result<()>,
 ) -> Result<(), SaveExecutionError> {
     let contents = encode()?;
     write(&contents).map_err(SaveExecutionError::Write)
 }
 
 /// Serializes the persisted simulation state without doing filesystem I/O.
-fn serialize_world_body(world: &mut World) -> Result<String, SaveExecutionError> {
+fn serialize_world_body(
+    world: &mut World,
+    encoding: SaveBodyEncoding,
+) -> Result<Vec<u8>, SaveExecutionError> {
     let type_registry = world
         .resource::<bevy::ecs::reflect::AppTypeRegistry>()
         .clone();
@@ -102,17 +129,15 @@ fn serialize_world_body(world: &mut World) -> Result<String, SaveExecutionError>
 
     let dynamic_world = build_persisted_world(world, &registry, target_entities.into_iter());
 
-    dynamic_world
-        .serialize(&registry)
-        .map_err(|error| SaveExecutionError::Serialize(error.to_string()))
+    serialize_body(&dynamic_world, &registry, encoding).map_err(SaveExecutionError::Serialize)
 }
 
 /// 同じディレクトリ内の一意な一時ファイルへ書き込んでから rename する。
 /// 途中でクラッシュしても既存のセーブファイルは破損しない。
-fn write_save_file(path: &Path, contents: &str) -> io::Result<()> {
+fn write_save_file(path: &Path, contents: &[u8]) -> io::Result<()> {
     let (temporary_path, mut file) = create_temporary_save_file(path)?;
     let write_result = (|| -> io::Result<()> {
-        file.write_all(contents.as_bytes())?;
+        file.write_all(contents)?;
         file.sync_all()
     })();
     drop(file);
@@ -199,7 +224,7 @@ mod tests {
     #[test]
     fn injectable_execution_classifies_success_and_both_failure_stages() {
         assert_eq!(
-            outcome(execute_save_with(|| Ok("encoded".to_owned()), |_| Ok(()))),
+            outcome(execute_save_with(|| Ok(b"encoded".to_vec()), |_| Ok(()))),
             SaveLoadResult::Succeeded
         );
         assert_eq!(
@@ -211,7 +236,7 @@ mod tests {
         );
         assert_eq!(
             outcome(execute_save_with(
-                || Ok("encoded".to_owned()),
+                || Ok(b"encoded".to_vec()),
                 |_| Err(io::Error::other("details"))
             )),
             SaveLoadResult::Failed(SaveLoadFailureKind::SaveWrite)
@@ -223,8 +248,8 @@ mod tests {
         let directory = unique_test_directory();
         let path = directory.join("world.scn.ron");
 
-        write_save_file(&path, "first save").expect("first write should succeed");
-        write_save_file(&path, "second save").expect("replacement write should succeed");
+        write_save_file(&path, b"first save").expect("first write should succeed");
+        write_save_file(&path, b"second save").expect("replacement write should succeed");
 
         assert_eq!(std::fs::read_to_string(&path).unwrap(), "second save");
         let remaining_temp_files = std::fs::read_dir(&directory)
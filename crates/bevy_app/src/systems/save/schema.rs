@@ -14,20 +14,29 @@ use crate::entities::damned_soul::{Gender, SoulIdentity};
 use crate::world::map::Tile;
 
 use hw_core::GameTime;
+use hw_core::alerts::{AlertCondition, AlertRule, AlertRules};
 use hw_core::area::{AreaBounds, TaskArea};
+use hw_core::camera::{CameraBookmark, CameraBookmarks};
+use hw_core::edicts::{ActiveEdict, DreamEdicts, EdictKind};
 use hw_core::familiar::{Familiar, FamiliarType};
+use hw_core::life_log::{SoulLifeEventKind, SoulLifeLog, SoulLifeLogEntry};
 use hw_core::logistics::ResourceType;
 use hw_core::population::PopulationManager;
+use hw_core::redemption::{RedemptionCooldown, RedemptionPilgrimage, SoulPenance};
 use hw_core::relationships::{
     CommandedBy, Commanding, DeliveringTo, GatheringParticipants, IncomingDeliveries, LoadedIn,
     LoadedItems, ManagedBy, ManagedTasks, ParkedAt, ParkedWheelbarrows, ParticipatingIn, PushedBy,
     PushingWheelbarrow, RestAreaOccupants, RestAreaReservations, RestAreaReservedFor, RestingIn,
     StoredIn, StoredItems, TaskWorkers, WorkingOn,
 };
+use hw_core::social::{SoulBond, SoulRelationships};
 use hw_core::soul::{
     DamnedSoul, DreamPool, DreamQuality, DreamState, DriftEdge, DriftPhase, DriftingState,
     GatheringBehavior, IdleBehavior, IdleState, RestAreaCooldown, StressBreakdown,
 };
+use hw_core::statistics::{
+    ColonySample, ColonyStatistics, ResourceStock, VitalAverages, WorkCompletions,
+};
 use hw_core::world::DoorState;
 
 use hw_energy::{
@@ -43,8 +52,8 @@ use hw_jobs::mud_mixer::{MudMixerStorage, StoredByMixer, TargetMixer};
 use hw_jobs::{
     Blueprint, BonePile, BridgeMarker, Building, BuildingType, Designation,
     FlexibleMaterialRequirement, ObstaclePosition, PlayerIssuedDesignation, Priority,
-    ProvisionalWall, RestArea, Rock, SandPile, TargetBlueprint, TargetSoulSpaSite, TaskSlots, Tree,
-    TreeVariant, WorkType,
+    ProvisionalWall, RedemptionShrine, RestArea, Rock, SandPile, TargetBlueprint,
+    TargetSoulSpaSite, TaskSlots, Tree, TreeVariant, WorkType,
 };
 
 use hw_logistics::transport_request::{
@@ -53,17 +62,25 @@ use hw_logistics::transport_request::{
 };
 use hw_logistics::types::{ReservedForTask, WheelbarrowParking};
 use hw_logistics::zone::{Stockpile, StockpileAcceptance, StockpilePolicy, StockpileResourceSet};
-use hw_logistics::{BelongsTo, Inventory, PendingBelongsToBlueprint, ResourceItem, Wheelbarrow};
+use hw_logistics::{
+    BelongsTo, Inventory, PendingBelongsToBlueprint, ResourceItem, SupplyRoute, Wheelbarrow,
+};
 
 use hw_world::{TerrainType, WorldMap};
 
 use super::state::SavedWorldgenSeed;
+use crate::systems::contracts::{Contract, ContractBook, ContractObjective, ContractReward};
 
 macro_rules! for_each_persisted_resource {
     ($callback:ident) => {
         $callback!(GameTime);
         $callback!(DreamPool);
         $callback!(PopulationManager);
+        $callback!(ContractBook);
+        $callback!(DreamEdicts);
+        $callback!(CameraBookmarks);
+        $callback!(ColonyStatistics);
+        $callback!(AlertRules);
         $callback!(WorldMap);
     };
 }
@@ -76,6 +93,11 @@ macro_rules! for_each_persisted_component {
         $callback!(StressBreakdown);
         $callback!(RestAreaCooldown);
         $callback!(DriftingState);
+        $callback!(SoulRelationships);
+        $callback!(SoulLifeLog);
+        $callback!(SoulPenance);
+        $callback!(RedemptionPilgrimage);
+        $callback!(RedemptionCooldown);
         $callback!(Familiar);
         $callback!(CommandedBy);
         $callback!(Commanding);
@@ -110,6 +132,7 @@ macro_rules! for_each_persisted_component {
         $callback!(BridgeMarker);
         $callback!(SandPile);
         $callback!(BonePile);
+        $callback!(RedemptionShrine);
         $callback!(TargetBlueprint);
         $callback!(TargetSoulSpaSite);
         $callback!(FloorConstructionSite);
@@ -124,6 +147,7 @@ macro_rules! for_each_persisted_component {
         $callback!(WheelbarrowParking);
         $callback!(Stockpile);
         $callback!(StockpilePolicy);
+        $callback!(SupplyRoute);
         $callback!(TransportRequest);
         $callback!(TransportRequestFixedSource);
         $callback!(ManualTransportRequest);
@@ -191,6 +215,9 @@ macro_rules! for_each_reflect_dependency {
         $callback!(DreamQuality);
         $callback!(DriftPhase);
         $callback!(DriftEdge);
+        $callback!(SoulBond);
+        $callback!(SoulLifeLogEntry);
+        $callback!(SoulLifeEventKind);
         $callback!(FamiliarType);
         $callback!(WorkType);
         $callback!(AreaBounds);
@@ -210,6 +237,18 @@ macro_rules! for_each_reflect_dependency {
         $callback!(TerrainType);
         $callback!(Gender);
         $callback!(SavedWorldgenSeed);
+        $callback!(Contract);
+        $callback!(ContractObjective);
+        $callback!(ContractReward);
+        $callback!(ActiveEdict);
+        $callback!(EdictKind);
+        $callback!(CameraBookmark);
+        $callback!(ColonySample);
+        $callback!(ResourceStock);
+        $callback!(WorkCompletions);
+        $callback!(VitalAverages);
+        $callback!(AlertRule);
+        $callback!(AlertCondition);
     };
 }
 
@@ -231,6 +270,7 @@ macro_rules! for_each_root_marker {
         $callback!(Wheelbarrow);
         $callback!(WheelbarrowParking);
         $callback!(Stockpile);
+        $callback!(SupplyRoute);
         $callback!(TransportRequest);
         $callback!(PowerGrid);
         $callback!(PowerGenerator);
@@ -337,6 +377,52 @@ pub(super) fn discard_legacy_reserved_for_task(dynamic_world: &mut DynamicWorld)
     }
 }
 
+/// Type paths of the schema classifications, for tools that read or edit a
+/// save body as a [`super::migration::SaveValue`] tree without a type registry.
+pub(super) struct PersistedTypePaths {
+    pub(super) resources: Vec<&'static str>,
+    /// Allow-listed components, including externally registered ones.
+    pub(super) components: Vec<&'static str>,
+    /// Root markers in classification order.
+    pub(super) root_markers: Vec<&'static str>,
+}
+
+pub(super) fn persisted_type_paths() -> PersistedTypePaths {
+    let mut paths = PersistedTypePaths {
+        resources: Vec::new(),
+        components: Vec::new(),
+        root_markers: Vec::new(),
+    };
+
+    macro_rules! push_resource {
+        ($type:ty) => {
+            paths
+                .resources
+                .push(<$type as bevy::reflect::TypePath>::type_path());
+        };
+    }
+    macro_rules! push_component {
+        ($type:ty) => {
+            paths
+                .components
+                .push(<$type as bevy::reflect::TypePath>::type_path());
+        };
+    }
+    macro_rules! push_root_marker {
+        ($type:ty) => {
+            paths
+                .root_markers
+                .push(<$type as bevy::reflect::TypePath>::type_path());
+        };
+    }
+
+    for_each_persisted_resource!(push_resource);
+    for_each_persisted_component!(push_component);
+    for_each_external_registered_component!(push_component);
+    for_each_root_marker!(push_root_marker);
+    paths
+}
+
 mod validation;
 
 pub(super) use validation::{DynamicWorldSchemaError, validate_persisted_world};
@@ -177,6 +177,14 @@ fn root_marker_matrix_collects_extracts_and_round_trips_durable_entities() {
                     resource_type: None,
                 })
                 .id(),
+            world
+                .spawn(hw_logistics::SupplyRoute {
+                    source_cells: Vec::new(),
+                    destination_cells: Vec::new(),
+                    resources: hw_logistics::zone::StockpileResourceSet::empty(),
+                    target_amount: 0,
+                })
+                .id(),
             world
                 .spawn(TransportRequest {
                     kind: TransportRequestKind::DepositToStockpile,
@@ -415,6 +423,79 @@ fn stockpile_policy_round_trips_with_yard_owner_entity_remap() {
     );
 }
 
+#[test]
+fn supply_route_round_trips_cell_references_and_resets_stats() {
+    use hw_logistics::zone::StockpileResourceSet;
+    use hw_logistics::{SupplyRoute, SupplyRouteStats};
+
+    let mut app = App::new();
+    register_save_types(&mut app);
+    let [source, destination] = [0.0, 32.0].map(|x| {
+        app.world_mut()
+            .spawn((
+                Stockpile {
+                    capacity: 10,
+                    resource_type: None,
+                },
+                Transform::from_xyz(x, 0.0, 0.0),
+            ))
+            .id()
+    });
+    let resources = StockpileResourceSet::empty().with(ResourceType::Sand, true);
+    let route = app
+        .world_mut()
+        .spawn(SupplyRoute {
+            source_cells: vec![source],
+            destination_cells: vec![destination],
+            resources,
+            target_amount: 7,
+        })
+        .id();
+    app.world_mut()
+        .get_mut::<SupplyRouteStats>(route)
+        .unwrap()
+        .record_delivery(1.0);
+    app.world_mut().flush();
+
+    let roots = collect_persisted_entities(app.world_mut());
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let dynamic_world = build_persisted_world(app.world(), &registry, roots.into_iter());
+    let body = dynamic_world.serialize(&registry).unwrap();
+    let mut ron_deserializer = ron::de::Deserializer::from_str(&body).unwrap();
+    let round_tripped = WorldDeserializer {
+        type_registry: &registry,
+        load_from_path: &mut NoAssetLoad,
+    }
+    .deserialize(&mut ron_deserializer)
+    .unwrap();
+    drop(registry);
+
+    let mut destination_world = World::new();
+    let mut entity_map = EntityHashMap::default();
+    let registry = type_registry.read();
+    round_tripped
+        .write_to_world_with(&mut destination_world, &mut entity_map, &registry)
+        .unwrap();
+
+    let mapped_route = entity_map[&route];
+    assert_eq!(
+        destination_world.get::<SupplyRoute>(mapped_route),
+        Some(&SupplyRoute {
+            source_cells: vec![entity_map[&source]],
+            destination_cells: vec![entity_map[&destination]],
+            resources,
+            target_amount: 7,
+        })
+    );
+    assert_eq!(
+        destination_world
+            .get::<SupplyRouteStats>(mapped_route)
+            .map(|stats| stats.delivered_total),
+        Some(0)
+    );
+}
+
 #[test]
 fn pre_checklist_stockpile_acceptance_ron_body_deserializes() {
     use hw_logistics::transport_request::TransportPriority;
@@ -493,6 +574,67 @@ fn pre_checklist_stockpile_acceptance_ron_body_deserializes() {
     );
 }
 
+#[test]
+fn multi_rect_task_area_round_trips_and_single_rect_body_still_loads() {
+    // Fixed from the single-rectangle representation that predates sub-rects.
+    const SINGLE_RECT_BODY: &str = r#"(
+  resources: {},
+  entities: {
+    1: (
+      components: {
+        "hw_core::area::TaskArea": (
+          bounds: (
+            min: (0.0, 0.0),
+            max: (32.0, 16.0),
+          ),
+        ),
+      },
+    ),
+  },
+)"#;
+
+    let mut app = App::new();
+    register_save_types(&mut app);
+    let l_shape = TaskArea::from_points(Vec2::ZERO, Vec2::new(64.0, 16.0))
+        .with_rect_added(AreaBounds::new(Vec2::ZERO, Vec2::new(16.0, 64.0)));
+    app.world_mut().spawn(l_shape.clone());
+    app.world_mut().flush();
+
+    let roots = collect_persisted_entities(app.world_mut());
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = type_registry.read();
+    let dynamic_world = build_persisted_world(app.world(), &registry, roots.into_iter());
+    let body = dynamic_world.serialize(&registry).unwrap();
+
+    let mut destination = World::new();
+    for source in [body.as_str(), SINGLE_RECT_BODY] {
+        let mut ron_deserializer = ron::de::Deserializer::from_str(source).unwrap();
+        let loaded = WorldDeserializer {
+            type_registry: &registry,
+            load_from_path: &mut NoAssetLoad,
+        }
+        .deserialize(&mut ron_deserializer)
+        .unwrap();
+        loaded
+            .write_to_world_with(&mut destination, &mut EntityHashMap::default(), &registry)
+            .unwrap();
+    }
+
+    let mut areas = destination
+        .query::<&TaskArea>()
+        .iter(&destination)
+        .cloned()
+        .collect::<Vec<_>>();
+    areas.sort_by_key(TaskArea::rect_count);
+    assert_eq!(
+        areas,
+        vec![
+            TaskArea::from_points(Vec2::ZERO, Vec2::new(32.0, 16.0)),
+            l_shape
+        ]
+    );
+}
+
 #[test]
 fn gathering_relationships_are_excluded_from_new_saves_and_stripped_from_legacy_bodies() {
     let mut app = App::new();
@@ -602,6 +744,14 @@ fn persisted_world_requires_every_schema_resource() {
     app.world_mut().insert_resource(DreamPool::default());
     app.world_mut()
         .insert_resource(PopulationManager::default());
+    app.world_mut().insert_resource(ContractBook::default());
+    app.world_mut().insert_resource(DreamEdicts::default());
+    app.world_mut()
+        .insert_resource(hw_core::camera::CameraBookmarks::default());
+    app.world_mut()
+        .insert_resource(hw_core::statistics::ColonyStatistics::default());
+    app.world_mut()
+        .insert_resource(hw_core::alerts::AlertRules::default());
     app.world_mut().insert_resource(WorldMap::default());
 
     let type_registry = app.world().resource::<AppTypeRegistry>().clone();
@@ -617,6 +767,11 @@ fn persisted_world_requires_every_schema_resource() {
             std::any::type_name::<GameTime>(),
             std::any::type_name::<DreamPool>(),
             std::any::type_name::<PopulationManager>(),
+            std::any::type_name::<ContractBook>(),
+            std::any::type_name::<DreamEdicts>(),
+            std::any::type_name::<hw_core::camera::CameraBookmarks>(),
+            std::any::type_name::<hw_core::statistics::ColonyStatistics>(),
+            std::any::type_name::<hw_core::alerts::AlertRules>(),
             std::any::type_name::<WorldMap>(),
         ]
     );
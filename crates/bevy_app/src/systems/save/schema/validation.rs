@@ -12,8 +12,9 @@ pub(crate) struct DynamicWorldSchemaError {
     pub(super) rootless_entities: Vec<Entity>,
 }
 
-impl fmt::Display for DynamicWorldSchemaError {
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl DynamicWorldSchemaError {
+    /// One human-readable line per violated rule, in a stable order.
+    pub(crate) fn reasons(&self) -> Vec<String> {
         let mut reasons = Vec::new();
         if !self.missing_resources.is_empty() {
             reasons.push(format!(
@@ -39,10 +40,16 @@ impl fmt::Display for DynamicWorldSchemaError {
                 self.rootless_entities.len()
             ));
         }
+        reasons
+    }
+}
+
+impl fmt::Display for DynamicWorldSchemaError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             formatter,
             "save body violates the persisted schema: {}",
-            reasons.join("; ")
+            self.reasons().join("; ")
         )
     }
 }
@@ -8,6 +8,8 @@
 use bevy::prelude::*;
 use std::path::{Path, PathBuf};
 
+use super::format::SaveBodyEncoding;
+
 /// セーブファイルの保存先（ワークスペースルートからの相対パス）
 pub const SAVE_FILE_PATH: &str = "saves/world.scn.ron";
 
@@ -31,6 +33,38 @@ impl Default for SavePath {
     }
 }
 
+/// 新しいセーブの body 符号化を選ぶ環境変数。既定は migration で引き継げる RON で、
+/// `binary` を指定したときだけ postcard + zstd を書く。
+pub const SAVE_ENCODING_ENV: &str = "HELL_WORKERS_SAVE_ENCODING";
+
+/// 新しいセーブの body 符号化。
+///
+/// ロードは header の `body_encoding` で判別するため、この設定はセーブにだけ効く。
+/// binary body は format version を上げると読めなくなるため opt-in にとどめる。
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaveEncoding(pub SaveBodyEncoding);
+
+impl SaveEncoding {
+    /// `SAVE_ENCODING_ENV` を読む。未設定や不正値は既定の RON にする。
+    pub fn from_env() -> Self {
+        match std::env::var(SAVE_ENCODING_ENV) {
+            Ok(raw) => Self::parse(&raw).unwrap_or_else(|| {
+                warn!("Invalid {SAVE_ENCODING_ENV}='{raw}' (expected ron or binary); using ron");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "ron" => Some(Self(SaveBodyEncoding::Ron)),
+            "binary" => Some(Self(SaveBodyEncoding::PostcardZstd)),
+            _ => None,
+        }
+    }
+}
+
 /// header 無し v0 セーブの worldgen seed。
 ///
 /// v1 以降は外部 header が seed を保持する。この型は magic 無しの既存セーブを
@@ -100,6 +134,11 @@ impl SaveLoadFailureKind {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SaveLoadResult {
     Succeeded,
+    /// ロードに成功し、旧 version のセーブを migration で現在の形へ上げた。
+    Upgraded {
+        from_version: u32,
+        to_version: u32,
+    },
     Failed(SaveLoadFailureKind),
 }
 
@@ -107,6 +146,7 @@ impl SaveLoadResult {
     pub(crate) const fn key_part(self) -> &'static str {
         match self {
             Self::Succeeded => "succeeded",
+            Self::Upgraded { .. } => "upgraded",
             Self::Failed(failure) => failure.key_part(),
         }
     }
@@ -121,6 +161,53 @@ pub struct SaveLoadOutcome {
     pub result: SaveLoadResult,
 }
 
+/// perf の save-load workload が読む、成功したセーブ/ロードの所要時間。
+///
+/// ロードの時間はファイル読み込みから rehydrate 完了までを含み、`rehydrate_elapsed_micros`
+/// はその内訳（rollback 復旧時の rehydrate も含む）。
+#[cfg(feature = "profiling")]
+#[derive(Resource, Debug, Default)]
+pub struct SaveLoadPerfMetrics {
+    pub saves: u64,
+    pub save_elapsed_micros: u64,
+    pub save_max_elapsed_micros: u64,
+    /// 最後に書いたセーブファイルのバイト数。
+    pub save_bytes: u64,
+    pub loads: u64,
+    pub load_elapsed_micros: u64,
+    pub load_max_elapsed_micros: u64,
+    pub rehydrate_elapsed_micros: u64,
+}
+
+#[cfg(feature = "profiling")]
+impl SaveLoadPerfMetrics {
+    pub(super) fn record_save(&mut self, elapsed: std::time::Duration, bytes: usize) {
+        let micros = duration_micros(elapsed);
+        self.saves = self.saves.saturating_add(1);
+        self.save_elapsed_micros = self.save_elapsed_micros.saturating_add(micros);
+        self.save_max_elapsed_micros = self.save_max_elapsed_micros.max(micros);
+        self.save_bytes = bytes as u64;
+    }
+
+    pub(super) fn record_load(&mut self, elapsed: std::time::Duration) {
+        let micros = duration_micros(elapsed);
+        self.loads = self.loads.saturating_add(1);
+        self.load_elapsed_micros = self.load_elapsed_micros.saturating_add(micros);
+        self.load_max_elapsed_micros = self.load_max_elapsed_micros.max(micros);
+    }
+
+    pub(super) fn record_rehydrate(&mut self, elapsed: std::time::Duration) {
+        self.rehydrate_elapsed_micros = self
+            .rehydrate_elapsed_micros
+            .saturating_add(duration_micros(elapsed));
+    }
+}
+
+#[cfg(feature = "profiling")]
+fn duration_micros(elapsed: std::time::Duration) -> u64 {
+    u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX)
+}
+
 pub(super) fn save_target_label(path: &Path) -> String {
     path.file_name()
         .and_then(|name| name.to_str())
@@ -133,6 +220,20 @@ pub(super) fn save_target_label(path: &Path) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn save_encoding_names_parse_case_insensitively() {
+        assert_eq!(
+            SaveEncoding::parse(" RON "),
+            Some(SaveEncoding(SaveBodyEncoding::Ron))
+        );
+        assert_eq!(
+            SaveEncoding::parse("binary"),
+            Some(SaveEncoding(SaveBodyEncoding::PostcardZstd))
+        );
+        assert_eq!(SaveEncoding::parse("json"), None);
+        assert_eq!(SaveEncoding::default().0, SaveBodyEncoding::Ron);
+    }
+
     #[test]
     fn target_label_exposes_only_a_safe_file_name() {
         assert_eq!(
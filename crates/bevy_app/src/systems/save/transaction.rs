@@ -236,6 +236,11 @@ mod tests {
         });
         world.insert_resource(DreamPool::default());
         world.insert_resource(PopulationManager::default());
+        world.insert_resource(crate::systems::contracts::ContractBook::default());
+        world.insert_resource(hw_core::edicts::DreamEdicts::default());
+        world.insert_resource(hw_core::camera::CameraBookmarks::default());
+        world.insert_resource(hw_core::statistics::ColonyStatistics::default());
+        world.insert_resource(hw_core::alerts::AlertRules::default());
         world.insert_resource(WorldMap::default());
     }
 
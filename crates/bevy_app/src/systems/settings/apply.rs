@@ -2,6 +2,7 @@ use bevy::camera_controller::pan_camera::PanCamera;
 use bevy::prelude::*;
 use hw_core::GameSettings;
 use hw_core::game_state::TimeSpeed;
+use hw_core::locale::Localization;
 use hw_core::ui_nodes::{UiNodeRegistry, UiSlot};
 use hw_ui::camera::MainCamera;
 
@@ -81,3 +82,13 @@ pub fn apply_settings_system(
 
     apply_fps_display_visibility(&ui_nodes, &mut q_visibility, settings.fps_display_enabled);
 }
+
+/// 設定の表示言語を `Localization` へ反映する（同じロケールなら変更検知を起こさない）。
+pub fn apply_locale_setting_system(
+    settings: Res<GameSettings>,
+    mut localization: ResMut<Localization>,
+) {
+    if settings.is_changed() && localization.locale() != settings.locale {
+        localization.set_locale(settings.locale);
+    }
+}
@@ -5,9 +5,16 @@ use bevy::prelude::*;
 use bevy::ui_widgets::{ValueChange, checkbox_self_update, slider_self_update};
 use hw_core::GameSettings;
 use hw_core::game_state::TimeSpeed;
+use hw_core::locale::Localization;
 use hw_ui::UiIntent;
-use hw_ui::components::{SettingsCheckboxMarker, SettingsField, SettingsSliderMarker};
+use hw_ui::components::{
+    SettingsCheckboxMarker, SettingsField, SettingsKeyBindingLabel, SettingsKeyBindingStatusText,
+    SettingsKeyBindingText, SettingsLocaleButton, SettingsSliderMarker,
+};
 
+use crate::input_actions::{
+    InputBindingTable, KeyRebindCapture, binding_row_label, format_input_chord,
+};
 use crate::plugins::startup::PerfScenarioConfig;
 use apply::apply_default_time_speed;
 use persistence::{load_settings_from_disk, save_settings_to_disk};
@@ -32,7 +39,10 @@ impl Plugin for SettingsPlugin {
                 Update,
                 (
                     apply::apply_settings_system,
+                    apply::apply_locale_setting_system,
                     update_settings_default_speed_highlight,
+                    update_settings_locale_highlight,
+                    update_settings_key_binding_rows,
                 ),
             )
             .configure_sets(Last, SettingsPersistenceSet)
@@ -138,3 +148,73 @@ fn update_settings_default_speed_highlight(
         };
     }
 }
+
+fn update_settings_locale_highlight(
+    settings: Res<GameSettings>,
+    theme: Res<hw_ui::theme::UiTheme>,
+    mut q_buttons: Query<(&SettingsLocaleButton, &mut BackgroundColor)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (button, mut color) in q_buttons.iter_mut() {
+        *color = if button.0 == settings.locale {
+            BackgroundColor(theme.colors.speed_button_active)
+        } else {
+            BackgroundColor(theme.colors.button_default)
+        };
+    }
+}
+
+fn update_settings_key_binding_rows(
+    table: Res<InputBindingTable>,
+    capture: Res<KeyRebindCapture>,
+    localization: Res<Localization>,
+    mut q_rows: Query<(&SettingsKeyBindingText, &mut Text), Without<SettingsKeyBindingStatusText>>,
+    mut q_status: Query<
+        &mut Text,
+        (
+            With<SettingsKeyBindingStatusText>,
+            Without<SettingsKeyBindingLabel>,
+        ),
+    >,
+    mut q_labels: Query<(&SettingsKeyBindingLabel, &mut Text), Without<SettingsKeyBindingText>>,
+) {
+    if !table.is_changed() && !capture.is_changed() && !localization.is_changed() {
+        return;
+    }
+
+    if localization.is_changed() {
+        for (row, mut text) in q_labels.iter_mut() {
+            text.0 = table
+                .bindings()
+                .get(row.0)
+                .map(|binding| binding_row_label(binding, &localization))
+                .unwrap_or_default();
+        }
+    }
+
+    for (row, mut text) in q_rows.iter_mut() {
+        text.0 = if capture.target() == Some(row.0) {
+            localization.text("ui.settings.press_key").to_string()
+        } else {
+            table
+                .bindings()
+                .get(row.0)
+                .and_then(|binding| format_input_chord(binding.chord).ok())
+                .unwrap_or_default()
+        };
+    }
+
+    for mut text in q_status.iter_mut() {
+        text.0 = if capture.is_capturing() {
+            localization.text("ui.settings.cancel_hint").to_string()
+        } else {
+            capture
+                .status()
+                .map(|error| error.message(&localization))
+                .unwrap_or_default()
+        };
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
-use hw_core::GameSettings;
 use hw_core::game_state::TimeSpeed;
+use hw_core::{GameSettings, KeyBindingOverride, Locale};
 use serde::{Deserialize, Serialize};
 
 const SETTINGS_DIR: &str = "settings";
@@ -14,6 +14,20 @@ pub struct GameSettingsFile {
     pub default_time_speed: TimeSpeedFile,
     pub debug_gizmos_enabled: bool,
     pub fps_display_enabled: bool,
+    /// ロケールコード（`ja` / `en`）。省略時・未知のコードは既定ロケール
+    #[serde(default)]
+    pub locale: String,
+    /// 旧バージョンの settings.ron には存在しないため省略時は空
+    #[serde(default)]
+    pub key_bindings: Vec<KeyBindingOverrideFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KeyBindingOverrideFile {
+    pub action: String,
+    pub context: String,
+    pub default: String,
+    pub chord: String,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -33,6 +47,12 @@ impl From<GameSettings> for GameSettingsFile {
             default_time_speed: settings.default_time_speed.into(),
             debug_gizmos_enabled: settings.debug_gizmos_enabled,
             fps_display_enabled: settings.fps_display_enabled,
+            locale: settings.locale.code().to_string(),
+            key_bindings: settings
+                .key_binding_overrides
+                .into_iter()
+                .map(KeyBindingOverrideFile::from)
+                .collect(),
         }
     }
 }
@@ -46,6 +66,34 @@ impl From<GameSettingsFile> for GameSettings {
             default_time_speed: file.default_time_speed.into(),
             debug_gizmos_enabled: file.debug_gizmos_enabled,
             fps_display_enabled: file.fps_display_enabled,
+            locale: Locale::from_code(&file.locale).unwrap_or_default(),
+            key_binding_overrides: file
+                .key_bindings
+                .into_iter()
+                .map(KeyBindingOverride::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<KeyBindingOverride> for KeyBindingOverrideFile {
+    fn from(binding: KeyBindingOverride) -> Self {
+        Self {
+            action: binding.action,
+            context: binding.context,
+            default: binding.default_chord,
+            chord: binding.chord,
+        }
+    }
+}
+
+impl From<KeyBindingOverrideFile> for KeyBindingOverride {
+    fn from(file: KeyBindingOverrideFile) -> Self {
+        Self {
+            action: file.action,
+            context: file.context,
+            default_chord: file.default,
+            chord: file.chord,
         }
     }
 }
@@ -102,3 +150,57 @@ pub fn save_settings_to_disk(settings: &GameSettings) -> Result<(), String> {
         .map_err(|err| err.to_string())?;
     std::fs::write(SETTINGS_FILE, contents).map_err(|err| err.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_without_key_bindings_still_parse() {
+        let legacy = r#"(
+            ui_scale: 1.0,
+            camera_pan_speed: 500.0,
+            camera_mouse_pan_enabled: true,
+            default_time_speed: Normal,
+            debug_gizmos_enabled: false,
+            fps_display_enabled: true,
+        )"#;
+        let file = ron::from_str::<GameSettingsFile>(legacy).expect("legacy settings");
+        assert_eq!(GameSettings::from(file), GameSettings::default());
+    }
+
+    #[test]
+    fn key_binding_overrides_round_trip_through_ron() {
+        let settings = GameSettings {
+            key_binding_overrides: vec![KeyBindingOverride {
+                action: "toggle-architect".to_string(),
+                context: "world-normal".to_string(),
+                default_chord: "B".to_string(),
+                chord: "Ctrl+B".to_string(),
+            }],
+            ..GameSettings::default()
+        };
+        let file: GameSettingsFile = settings.clone().into();
+        let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+            .expect("serialize settings");
+        let parsed = ron::from_str::<GameSettingsFile>(&text).expect("parse settings");
+        assert_eq!(GameSettings::from(parsed), settings);
+    }
+
+    #[test]
+    fn locale_round_trips_and_unknown_codes_fall_back() {
+        let settings = GameSettings {
+            locale: Locale::En,
+            ..GameSettings::default()
+        };
+        let file: GameSettingsFile = settings.clone().into();
+        assert_eq!(file.locale, "en");
+        assert_eq!(GameSettings::from(file.clone()), settings);
+
+        let unknown = GameSettingsFile {
+            locale: "xx".to_string(),
+            ..file
+        };
+        assert_eq!(GameSettings::from(unknown).locale, Locale::default());
+    }
+}
@@ -1,12 +1,26 @@
 //! PopulationManager への書き込み adapter
 //!
-//! hw_ai 側の drifting システムが発行する `DriftingEscapeStarted` / `SoulEscaped` イベントを
-//! 受信し、root-only リソース `PopulationManager` を更新する。
+//! hw_ai 側の drifting / redemption システムが発行する `DriftingEscapeStarted` /
+//! `SoulEscaped` / `SoulRedeemed` イベントを受信し、root-only リソース
+//! `PopulationManager` を更新する。
 
 use bevy::prelude::*;
-use hw_core::events::{DriftingEscapeStarted, SoulEscaped};
+use hw_core::events::{DriftingEscapeStarted, SoulEscaped, SoulRedeemed};
+use hw_core::redemption::REDEMPTION_DREAM_REWARD;
 
 use crate::entities::damned_soul::spawn::PopulationManager;
+use crate::entities::damned_soul::{DreamPool, SoulIdentity};
+
+/// 贖罪の結果（通知 adapter 向け）
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct SoulRedemptionOutcome {
+    pub soul: Entity,
+    /// 贖罪が行われた Shrine（Soul 本体は despawn 済み）
+    pub shrine: Entity,
+    pub name: String,
+    pub dream_reward: f32,
+    pub total_redeemed: u32,
+}
 
 /// `DriftingEscapeStarted` を受信して脱走クールダウンを開始する
 pub fn on_drifting_escape_started(
@@ -26,3 +40,32 @@ pub fn on_soul_escaped(trigger: On<SoulEscaped>, mut population: ResMut<Populati
         population.total_escaped
     );
 }
+
+/// `SoulRedeemed` を受信して贖罪カウンタと DreamPool の報酬を反映する
+pub fn on_soul_redeemed(
+    trigger: On<SoulRedeemed>,
+    mut population: ResMut<PopulationManager>,
+    mut dream_pool: ResMut<DreamPool>,
+    q_identity: Query<&SoulIdentity>,
+    mut outcomes: MessageWriter<SoulRedemptionOutcome>,
+) {
+    let event = trigger.event();
+    population.total_redeemed += 1;
+    dream_pool.points += REDEMPTION_DREAM_REWARD;
+
+    let name = q_identity
+        .get(event.entity)
+        .map(|identity| identity.name.clone())
+        .unwrap_or_else(|_| "Damned Soul".to_string());
+    info!(
+        "SOUL_REDEMPTION: {:?} ({}) redeemed at {:?} (total_redeemed={})",
+        event.entity, name, event.shrine, population.total_redeemed
+    );
+    outcomes.write(SoulRedemptionOutcome {
+        soul: event.entity,
+        shrine: event.shrine,
+        name,
+        dream_reward: REDEMPTION_DREAM_REWARD,
+        total_redeemed: population.total_redeemed,
+    });
+}
@@ -20,9 +20,10 @@ impl Plugin for SoulAiPlugin {
         // hw_ai の SoulAiCorePlugin でコアシステムを登録
         app.add_plugins(hw_soul_ai::SoulAiCorePlugin);
 
-        // drifting 書き込み adapter (hw_ai → root PopulationManager ブリッジ)
+        // drifting / redemption 書き込み adapter (hw_ai → root PopulationManager ブリッジ)
         app.add_observer(adapters::on_drifting_escape_started)
-            .add_observer(adapters::on_soul_escaped);
+            .add_observer(adapters::on_soul_escaped)
+            .add_observer(adapters::on_soul_redeemed);
 
         // Soul AI は Familiar AI の後に実行される
         // FamiliarAiSystemSet::Execute → ApplyDeferred → SoulAiSystemSet::Perceive
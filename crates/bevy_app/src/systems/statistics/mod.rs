@@ -0,0 +1,169 @@
+//! コロニー統計のサンプリング
+//!
+//! `STATISTICS_SAMPLE_INTERVAL_SECS` ごとにゲーム状態を集計して `ColonyStatistics` へ追加する。
+//! 作業完了数は `OnTaskCompleted` を受けて次のサンプルまで積み上げる。
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::events::OnTaskCompleted;
+use hw_core::logistics::ResourceType;
+use hw_core::population::PopulationManager;
+use hw_core::relationships::StoredIn;
+use hw_core::soul::{DamnedSoul, DreamPool};
+use hw_core::statistics::{ColonySample, ColonyStatistics, ResourceStock, VitalAverages};
+use hw_energy::PowerGrid;
+use hw_logistics::ResourceItem;
+
+use crate::systems::GameSystemSet;
+
+#[derive(SystemParam)]
+pub struct ColonySampleSources<'w, 's> {
+    game_time: Res<'w, GameTime>,
+    population: Res<'w, PopulationManager>,
+    dream_pool: Res<'w, DreamPool>,
+    q_stored: Query<'w, 's, &'static ResourceItem, With<StoredIn>>,
+    q_grids: Query<'w, 's, &'static PowerGrid>,
+    q_souls: Query<'w, 's, &'static DamnedSoul>,
+}
+
+fn vital_averages<'a>(souls: impl Iterator<Item = &'a DamnedSoul>) -> VitalAverages {
+    let mut sum = VitalAverages::default();
+    let mut count = 0.0;
+    for soul in souls {
+        sum.stress += soul.stress;
+        sum.fatigue += soul.fatigue;
+        sum.motivation += soul.motivation;
+        sum.laziness += soul.laziness;
+        count += 1.0;
+    }
+    if count == 0.0 {
+        return sum;
+    }
+    VitalAverages {
+        stress: sum.stress / count,
+        fatigue: sum.fatigue / count,
+        motivation: sum.motivation / count,
+        laziness: sum.laziness / count,
+    }
+}
+
+impl ColonySampleSources<'_, '_> {
+    fn sample(&self) -> ColonySample {
+        let mut counts = [0u32; ResourceType::ALL.len()];
+        for item in &self.q_stored {
+            if let Some(slot) = ResourceType::ALL.iter().position(|kind| *kind == item.0) {
+                counts[slot] += 1;
+            }
+        }
+        let stocked = ResourceType::ALL
+            .into_iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(resource, count)| ResourceStock { resource, count })
+            .collect();
+
+        ColonySample {
+            time: self.game_time.seconds,
+            population: self.population.current_count,
+            population_cap: self.population.population_cap,
+            escaped: self.population.total_escaped,
+            dream_points: self.dream_pool.points,
+            stocked,
+            completed: Vec::new(),
+            power_generation: self.q_grids.iter().map(|grid| grid.generation).sum(),
+            power_consumption: self.q_grids.iter().map(|grid| grid.consumption).sum(),
+            vitals: vital_averages(self.q_souls.iter()),
+        }
+    }
+}
+
+pub fn colony_statistics_sampling_system(
+    sources: ColonySampleSources,
+    mut statistics: ResMut<ColonyStatistics>,
+) {
+    if !statistics.is_sample_due(sources.game_time.seconds) {
+        return;
+    }
+    statistics.push(sources.sample());
+}
+
+pub fn on_task_completed_statistics(
+    on: On<OnTaskCompleted>,
+    mut statistics: ResMut<ColonyStatistics>,
+) {
+    statistics.record_completion(on.event().current_work_type);
+}
+
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColonyStatistics>()
+            .add_observer(on_task_completed_statistics)
+            .add_systems(
+                Update,
+                colony_statistics_sampling_system.in_set(GameSystemSet::Logic),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hw_core::jobs::WorkType;
+    use hw_core::statistics::STATISTICS_SAMPLE_INTERVAL_SECS;
+
+    #[test]
+    fn samples_collect_stock_power_vitals_and_completed_work() {
+        let mut app = App::new();
+        app.init_resource::<GameTime>()
+            .init_resource::<PopulationManager>()
+            .insert_resource(DreamPool { points: 12.0 })
+            .init_resource::<ColonyStatistics>()
+            .add_observer(on_task_completed_statistics)
+            .add_systems(Update, colony_statistics_sampling_system);
+        let stockpile = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .spawn((ResourceItem(ResourceType::Wood), StoredIn(stockpile)));
+        app.world_mut()
+            .spawn((ResourceItem(ResourceType::Wood), StoredIn(stockpile)));
+        app.world_mut().spawn(ResourceItem(ResourceType::Rock));
+        app.world_mut().spawn(PowerGrid {
+            generation: 5.0,
+            consumption: 3.0,
+            powered: true,
+        });
+        app.world_mut().spawn(DamnedSoul {
+            stress: 0.2,
+            ..default()
+        });
+        app.world_mut().spawn(DamnedSoul {
+            stress: 0.6,
+            ..default()
+        });
+
+        app.update();
+        let soul = app.world_mut().spawn_empty().id();
+        app.world_mut().trigger(OnTaskCompleted {
+            entity: soul,
+            assignment_entity: soul,
+            current_target_entity: soul,
+            current_work_type: WorkType::Mine,
+        });
+        app.world_mut().resource_mut::<GameTime>().seconds = STATISTICS_SAMPLE_INTERVAL_SECS;
+        app.update();
+
+        let statistics = app.world().resource::<ColonyStatistics>();
+        assert_eq!(statistics.samples().len(), 2);
+        let first = &statistics.samples()[0];
+        assert_eq!(first.stocked(ResourceType::Wood), 2);
+        assert_eq!(first.stocked(ResourceType::Rock), 0);
+        assert_eq!(first.dream_points, 12.0);
+        assert_eq!(first.power_generation, 5.0);
+        assert_eq!(first.power_consumption, 3.0);
+        assert!((first.vitals.stress - 0.4).abs() < 1e-6);
+        assert_eq!(first.completed_total(), 0);
+        assert_eq!(statistics.latest().unwrap().completed(WorkType::Mine), 1);
+    }
+}
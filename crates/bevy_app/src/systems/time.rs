@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 use hw_core::GameTime;
+use hw_core::locale::Localization;
 use hw_ui::components::ClockText;
 
 pub fn game_time_system(
     time: Res<Time<Virtual>>,
     mut game_time: ResMut<GameTime>,
+    localization: Option<Res<Localization>>,
     mut q_clock: Query<&mut Text, With<ClockText>>,
 ) {
     // 1秒(実時間) = 1分(ゲーム中) に調整 (60倍速)
@@ -18,10 +20,18 @@ pub fn game_time_system(
 
     game_time.day = (total_hours / 24) + 1;
 
-    if let Ok(mut text) = q_clock.single_mut() {
-        text.0 = format!(
-            "Day {}, {:02}:{:02}",
-            game_time.day, game_time.hour, game_time.minute
+    if let Ok(mut text) = q_clock.single_mut()
+        && let Some(localization) = localization
+    {
+        text.0 = localization.format(
+            "ui.time.clock",
+            &[
+                ("day", &game_time.day),
+                (
+                    "time",
+                    &format!("{:02}:{:02}", game_time.hour, game_time.minute),
+                ),
+            ],
         );
     }
 }
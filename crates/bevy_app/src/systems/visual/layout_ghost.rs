@@ -0,0 +1,95 @@
+//! レイアウト設計図の配置ゴースト
+//!
+//! `TaskMode::LayoutImport` 中、回転済みの設計図をカーソル位置にセル単位で表示する。
+//! 色は占有・通行可否だけの簡易判定で、実際の配置可否は各要素の検証で決まる。
+
+use crate::app_contexts::TaskContext;
+use crate::systems::layout_blueprint::{ActiveLayoutBlueprint, LayoutCellKind};
+use crate::world::map::{WorldMap, WorldMapRead};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use hw_core::constants::{TILE_SIZE, Z_SELECTION};
+use hw_core::game_state::TaskMode;
+use hw_ui::camera::MainCamera;
+use hw_ui::components::UiInputState;
+
+#[derive(Component)]
+pub struct LayoutGhostCell;
+
+fn cell_color(kind: LayoutCellKind, blocked: bool) -> Color {
+    if blocked {
+        return Color::srgba(1.0, 0.2, 0.2, 0.5);
+    }
+    match kind {
+        LayoutCellKind::Building => Color::srgba(0.5, 1.0, 0.5, 0.5),
+        LayoutCellKind::BucketStorage => Color::srgba(0.8, 0.9, 1.0, 0.35),
+        LayoutCellKind::Floor => Color::srgba(0.5, 0.5, 0.8, 0.35),
+        LayoutCellKind::Wall => Color::srgba(0.8, 0.55, 0.3, 0.45),
+        LayoutCellKind::Stockpile => Color::srgba(1.0, 1.0, 0.0, 0.3),
+    }
+}
+
+#[derive(SystemParam)]
+pub struct LayoutGhostCursor<'w, 's> {
+    pub q_window: Query<'w, 's, &'static Window, With<bevy::window::PrimaryWindow>>,
+    pub q_camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+}
+
+type LayoutGhostQuery<'w, 's> =
+    Query<'w, 's, (Entity, &'static mut Transform, &'static mut Sprite), With<LayoutGhostCell>>;
+
+pub fn layout_ghost_system(
+    mut commands: Commands,
+    task_context: Res<TaskContext>,
+    active_layout: Res<ActiveLayoutBlueprint>,
+    ui_input_state: Res<UiInputState>,
+    world_map: WorldMapRead,
+    cursor: LayoutGhostCursor,
+    mut q_ghosts: LayoutGhostQuery,
+) {
+    let cursor_grid = hw_ui::camera::world_cursor_pos(&cursor.q_window, &cursor.q_camera)
+        .map(WorldMap::world_to_grid)
+        .filter(|_| {
+            matches!(task_context.0, TaskMode::LayoutImport)
+                && !ui_input_state.world_input_blocked()
+        });
+    let cells = cursor_grid
+        .zip(active_layout.rotated())
+        .map(|(origin, blueprint)| {
+            blueprint
+                .occupied_cells()
+                .into_iter()
+                .map(|(kind, (x, y))| {
+                    let grid = (origin.0 + x, origin.1 + y);
+                    let blocked = world_map.has_building(grid)
+                        || world_map.has_stockpile(grid)
+                        || !world_map.is_walkable(grid.0, grid.1);
+                    (grid, cell_color(kind, blocked))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut ghosts = q_ghosts.iter_mut();
+    for &((gx, gy), color) in &cells {
+        let translation = WorldMap::grid_to_world(gx, gy).extend(Z_SELECTION);
+        if let Some((_, mut transform, mut sprite)) = ghosts.next() {
+            transform.translation = translation;
+            sprite.color = color;
+        } else {
+            commands.spawn((
+                LayoutGhostCell,
+                Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(TILE_SIZE)),
+                    ..default()
+                },
+                Transform::from_translation(translation),
+                Name::new("LayoutGhostCell"),
+            ));
+        }
+    }
+    for (entity, _, _) in ghosts {
+        commands.entity(entity).despawn();
+    }
+}
@@ -9,10 +9,12 @@ pub mod building3d_cleanup;
 pub mod camera_sync;
 pub mod character_proxy_3d;
 pub mod elevation_view;
+pub mod layout_ghost;
 pub mod placement_ghost;
 pub mod section_cut;
 pub mod soul_animation;
 pub mod soul_shadow_projector;
+pub mod supply_route_visual;
 pub mod task_area_visual;
 pub mod terrain_lod;
 pub mod terrain_material;
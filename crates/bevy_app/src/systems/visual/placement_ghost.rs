@@ -323,6 +323,7 @@ pub fn placement_ghost_system(
             BuildingType::WheelbarrowParking => game_assets.wheelbarrow_parking.clone(),
             BuildingType::SoulSpa => game_assets.rest_area.clone(),
             BuildingType::OutdoorLamp => game_assets.bone_pile.clone(),
+            BuildingType::RedemptionShrine => game_assets.rest_area.clone(),
         }
     };
 
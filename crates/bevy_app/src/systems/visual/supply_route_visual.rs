@@ -0,0 +1,70 @@
+//! 補給ルートの矢印表示
+//!
+//! 供給元グループの中心から供給先グループの中心へ矢印を描く。
+//! 目標量を満たしているルートは淡く、補給中のルートは濃く表示する。
+
+use bevy::prelude::*;
+use hw_core::constants::TILE_SIZE;
+use hw_core::relationships::StoredItems;
+use hw_logistics::{Stockpile, SupplyRoute};
+
+const ROUTE_ACTIVE_COLOR: Color = Color::srgba(0.35, 0.85, 1.0, 0.85);
+const ROUTE_SATISFIED_COLOR: Color = Color::srgba(0.35, 0.85, 1.0, 0.3);
+
+type RouteCellQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        &'static Stockpile,
+        Option<&'static StoredItems>,
+    ),
+>;
+
+fn cells_centroid(cells: &[Entity], q_cells: &RouteCellQuery) -> Option<Vec2> {
+    let (sum, count) = cells
+        .iter()
+        .filter_map(|cell| q_cells.get(*cell).ok())
+        .fold((Vec2::ZERO, 0usize), |(sum, count), (transform, _, _)| {
+            (sum + transform.translation.truncate(), count + 1)
+        });
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// 各 `SupplyRoute` について供給元 → 供給先の矢印を描画する。
+pub fn supply_route_arrow_system(
+    q_routes: Query<&SupplyRoute>,
+    q_cells: RouteCellQuery,
+    mut gizmos: Gizmos,
+) {
+    for route in q_routes.iter() {
+        let (Some(start), Some(end)) = (
+            cells_centroid(&route.source_cells, &q_cells),
+            cells_centroid(&route.destination_cells, &q_cells),
+        ) else {
+            continue;
+        };
+        if start.distance_squared(end) < f32::EPSILON {
+            continue;
+        }
+
+        let level: usize = q_cells
+            .iter_many(&route.destination_cells)
+            .filter(|(_, stockpile, _)| {
+                stockpile
+                    .resource_type
+                    .is_some_and(|resource_type| route.resources.contains(resource_type))
+            })
+            .map(|(_, _, stored_items)| stored_items.map_or(0, StoredItems::len))
+            .sum();
+        let color = if level >= route.target_amount {
+            ROUTE_SATISFIED_COLOR
+        } else {
+            ROUTE_ACTIVE_COLOR
+        };
+
+        gizmos
+            .arrow_2d(start, end, color)
+            .with_tip_length(TILE_SIZE * 0.6);
+    }
+}
@@ -38,7 +38,10 @@ pub fn update_task_area_material_system(
         let Ok((fam_entity, area)) = q_familiars.get(visual.familiar) else {
             continue;
         };
-        let new_size = area.size();
+        let Some(rect) = area.rect(visual.rect_index) else {
+            continue;
+        };
+        let new_size = rect.size();
 
         let is_selected = ctx.selected.0 == Some(fam_entity);
         let is_border_hovered = cursor_pos.is_some_and(|pos| area.contains_border(pos, 6.0));
@@ -1,7 +1,25 @@
 //! Minimal Bevy App construction for focused library unit tests.
+//!
+//! `minimal_app` registers only the systems under test. `headless_game_app`
+//! builds the production `HellWorkersGamePlugin` without window, renderer or
+//! GPU; `scenario` drives it for scripted gameplay assertions.
+
+pub(crate) mod scenario;
 
 use bevy::app::ScheduleRunnerPlugin;
+use bevy::asset::AssetApp;
+use bevy::core_pipeline::CorePipelinePlugin;
+use bevy::gilrs::GilrsPlugin;
+use bevy::gizmos_render::GizmoRenderPlugin;
+use bevy::image::ImagePlugin;
+use bevy::log::LogPlugin;
+use bevy::pbr::PbrPlugin;
 use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::shader::{Shader, ShaderLoader};
+use bevy::winit::WinitPlugin;
+
+use crate::HellWorkersGamePlugin;
 
 pub(crate) fn minimal_app() -> App {
     let mut app = App::new();
@@ -9,6 +27,41 @@ pub(crate) fn minimal_app() -> App {
     app
 }
 
+/// Registers the asset types that `RenderPlugin` / `PbrPlugin` normally provide.
+///
+/// Material plugins load shader libraries during build, and startup creates
+/// `StandardMaterial` handles, so both must exist before `ImagePlugin`.
+#[derive(Default)]
+struct HeadlessRenderAssetsPlugin;
+
+impl Plugin for HeadlessRenderAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Shader>()
+            .init_asset_loader::<ShaderLoader>()
+            .init_asset::<StandardMaterial>();
+    }
+}
+
+/// The production game plugin on top of `DefaultPlugins` without window,
+/// renderer, gamepad backend or global logger.
+pub(crate) fn headless_game_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .build()
+            .disable::<WinitPlugin>()
+            .disable::<RenderPlugin>()
+            .disable::<GilrsPlugin>()
+            .disable::<CorePipelinePlugin>()
+            .disable::<GizmoRenderPlugin>()
+            .disable::<PbrPlugin>()
+            .disable::<LogPlugin>()
+            .add_before::<ImagePlugin>(HeadlessRenderAssetsPlugin),
+    )
+    .add_plugins(HellWorkersGamePlugin::default());
+    app
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
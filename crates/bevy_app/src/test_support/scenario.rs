@@ -0,0 +1,389 @@
+//! 本番 plugin 構成を headless で回す gameplay シナリオテスト。
+//!
+//! `Scenario` で小さなマップ（地形・Yard・Stockpile・建物・設計図・地面アイテム・
+//! Soul・Familiar）を durable component だけで宣言し、セーブのロードと同じ置換・
+//! rehydrate 経路で起動済みの `headless_game_app` へ適用する。以降は固定 delta の
+//! `App::update` を 1 tick として回し、`ScenarioRun` の helper で結果を検査する。
+//!
+//! - ロードで Entity が振り直されるため、参照は Soul / Familiar の名前と grid 座標で行う。
+//! - 宣言しなかったタイルは `Grass`。`River` を置くと通常の定期スポーンが有効になる。
+//! - `InvariantCheckPlugin::panicking()` を常に有効にし、不変条件違反はテスト失敗にする。
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::entities::damned_soul::{Gender, SoulIdentity};
+use crate::systems::invariants::{InvariantCheckPlugin, InvariantReport};
+use crate::systems::save::replace_world_from_durable_source;
+use crate::world::map::RIVER_Y_MIN;
+
+use hw_core::GameTime;
+use hw_core::area::TaskArea;
+use hw_core::constants::{TILE_SIZE, Z_AURA, Z_CHARACTER, Z_ITEM_PICKUP, Z_MAP};
+use hw_core::familiar::{ActiveCommand, Familiar, FamiliarCommand};
+use hw_core::logistics::ResourceType;
+use hw_core::population::PopulationManager;
+use hw_core::relationships::{Commanding, ManagedTasks, StoredIn};
+use hw_core::soul::{DamnedSoul, DreamState, IdleState};
+use hw_core::world::DoorState;
+use hw_jobs::{Blueprint, Building, BuildingType, Designation, Door, TaskSlots, WorkType};
+use hw_logistics::zone::Stockpile;
+use hw_logistics::{BelongsTo, Inventory, ResourceItem};
+use hw_ui::selection::building_geometry;
+use hw_world::{TerrainType, WorldMap, Yard};
+
+use super::headless_game_app;
+
+/// 1 tick あたりに進める仮想時間（60 Hz）。
+const SCENARIO_TICK: Duration = Duration::from_micros(16_667);
+
+/// 手動配置の Stockpile と同じ 1 セルあたりの容量。
+const SCENARIO_STOCKPILE_CAPACITY: usize = 10;
+
+type GridPos = (i32, i32);
+
+/// durable component だけで組み立てる初期ワールドの宣言。
+pub(crate) struct Scenario {
+    source: World,
+    yards: Vec<(Entity, Yard)>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        let mut source = World::new();
+        source.insert_resource(WorldMap::default());
+        source.insert_resource(GameTime::default());
+        source.insert_resource(PopulationManager::default());
+        Self {
+            source,
+            yards: Vec::new(),
+        }
+    }
+}
+
+impl Scenario {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// `min..=max` の矩形を `terrain` で塗る。
+    pub(crate) fn terrain(mut self, min: GridPos, max: GridPos, terrain: TerrainType) -> Self {
+        let mut world_map = self.source.resource_mut::<WorldMap>();
+        for (x, y) in cells(min, max) {
+            let idx = world_map
+                .pos_to_idx(x, y)
+                .unwrap_or_else(|| panic!("terrain cell ({x}, {y}) is outside the map"));
+            world_map.set_terrain_at_idx(idx, terrain);
+        }
+        self
+    }
+
+    /// `min..=max` を覆う Yard。Stockpile は後から含まれる Yard に所属する。
+    pub(crate) fn yard(mut self, min: GridPos, max: GridPos) -> Self {
+        let bounds = grid_rect_bounds(min, max);
+        let yard = Yard {
+            min: bounds.0,
+            max: bounds.1,
+        };
+        let entity = self.source.spawn(yard.clone()).id();
+        self.yards.push((entity, yard));
+        self
+    }
+
+    /// 空の Stockpile セル。
+    pub(crate) fn stockpile(self, grid: GridPos) -> Self {
+        self.stockpile_with(grid, ResourceType::Wood, 0)
+    }
+
+    /// `count` 個の `resource` を格納済みの Stockpile セル。
+    pub(crate) fn stockpile_with(
+        mut self,
+        grid: GridPos,
+        resource: ResourceType,
+        count: usize,
+    ) -> Self {
+        assert!(
+            count <= SCENARIO_STOCKPILE_CAPACITY,
+            "stockpile {grid:?} holds at most {SCENARIO_STOCKPILE_CAPACITY} items"
+        );
+        let pos = WorldMap::grid_to_world(grid.0, grid.1);
+        let yard = self
+            .yards
+            .iter()
+            .find(|(_, yard)| yard.contains(pos))
+            .map(|(entity, _)| *entity)
+            .unwrap_or_else(|| panic!("stockpile {grid:?} must be declared inside a yard"));
+        let stockpile = self
+            .source
+            .spawn((
+                Stockpile {
+                    capacity: SCENARIO_STOCKPILE_CAPACITY,
+                    resource_type: (count > 0).then_some(resource),
+                },
+                BelongsTo(yard),
+                Transform::from_xyz(pos.x, pos.y, Z_MAP + 0.01),
+            ))
+            .id();
+        self.source
+            .resource_mut::<WorldMap>()
+            .register_stockpile_tile(grid, stockpile);
+        for _ in 0..count {
+            self.source.spawn((
+                ResourceItem(resource),
+                StoredIn(stockpile),
+                Transform::from_xyz(pos.x, pos.y, Z_ITEM_PICKUP),
+            ));
+        }
+        self
+    }
+
+    /// 地面に置いた `count` 個の `resource`。
+    pub(crate) fn items(mut self, grid: GridPos, resource: ResourceType, count: usize) -> Self {
+        let pos = WorldMap::grid_to_world(grid.0, grid.1);
+        for _ in 0..count {
+            self.source.spawn((
+                ResourceItem(resource),
+                Transform::from_xyz(pos.x, pos.y, Z_ITEM_PICKUP),
+            ));
+        }
+        self
+    }
+
+    /// 完成済みの建物。`grid` は配置時と同じ左下の基準セル。
+    ///
+    /// 占有セルと Door の開閉状態だけを登録する。RestArea の定員や Tank の BucketStorage
+    /// など種類固有の付属物は付与しないため、壁・扉などの地形的な建物に使う。
+    pub(crate) fn building(mut self, kind: BuildingType, grid: GridPos) -> Self {
+        let geometry = building_geometry(kind, grid, RIVER_Y_MIN);
+        let mut entity = self.source.spawn((
+            Building {
+                kind,
+                is_provisional: false,
+            },
+            Transform::from_xyz(geometry.draw_pos.x, geometry.draw_pos.y, Z_MAP + 0.01),
+        ));
+        if kind == BuildingType::Door {
+            entity.insert(Door {
+                state: DoorState::Closed,
+            });
+        }
+        let entity = entity.id();
+        self.source
+            .resource_mut::<WorldMap>()
+            .register_completed_building_footprint(kind, entity, geometry.occupied_grids);
+        self
+    }
+
+    /// 建築待ちの設計図。配置 UI と同じ `Build` 指定と 1 枠の TaskSlots を持つ。
+    pub(crate) fn blueprint(mut self, kind: BuildingType, grid: GridPos) -> Self {
+        let geometry = building_geometry(kind, grid, RIVER_Y_MIN);
+        let entity = self
+            .source
+            .spawn((
+                Blueprint::new(kind, geometry.occupied_grids.clone()),
+                Designation {
+                    work_type: WorkType::Build,
+                },
+                TaskSlots::new(1),
+                Transform::from_xyz(geometry.draw_pos.x, geometry.draw_pos.y, Z_AURA),
+            ))
+            .id();
+        self.source
+            .resource_mut::<WorldMap>()
+            .reserve_building_footprint(kind, entity, geometry.occupied_grids);
+        self
+    }
+
+    /// 名前付きの Soul。`vitals` の laziness / motivation / fatigue / stress / dream を使う。
+    pub(crate) fn soul(mut self, name: &str, grid: GridPos, vitals: DamnedSoul) -> Self {
+        let pos = WorldMap::grid_to_world(grid.0, grid.1);
+        self.source.spawn((
+            vitals,
+            DreamState::default(),
+            SoulIdentity {
+                name: name.to_string(),
+                gender: Gender::default(),
+            },
+            IdleState::default(),
+            Inventory::default(),
+            Transform::from_xyz(pos.x, pos.y, Z_CHARACTER),
+        ));
+        self
+    }
+
+    /// 名前付きの Familiar。`task_area` は `min..=max` の矩形で、ロード後に Patrol 指示へ入る。
+    pub(crate) fn familiar(
+        mut self,
+        name: &str,
+        grid: GridPos,
+        task_area: (GridPos, GridPos),
+    ) -> Self {
+        let pos = WorldMap::grid_to_world(grid.0, grid.1);
+        let (min, max) = grid_rect_bounds(task_area.0, task_area.1);
+        self.source.spawn((
+            Familiar {
+                name: name.to_string(),
+                ..default()
+            },
+            Commanding::default(),
+            ManagedTasks::default(),
+            TaskArea::from_points(min, max),
+            Transform::from_xyz(pos.x, pos.y, Z_CHARACTER + 0.5),
+        ));
+        self
+    }
+
+    /// 本番 App を起動し、宣言したワールドをロード経路で適用する。
+    pub(crate) fn start(mut self) -> ScenarioRun {
+        let mut app = headless_game_app();
+        if !app.is_plugin_added::<InvariantCheckPlugin>() {
+            app.add_plugins(InvariantCheckPlugin::panicking());
+        }
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(SCENARIO_TICK));
+        app.finish();
+        app.cleanup();
+        // Startup / PostStartup が asset handle と生成マップを用意し、初期 spawn も消化する。
+        app.update();
+
+        let world = app.world_mut();
+        if let Err(error) = replace_world_from_durable_source(world, &mut self.source) {
+            panic!("scenario world could not be applied: {error}");
+        }
+        start_task_area_patrols(world);
+
+        ScenarioRun { app, ticks: 0 }
+    }
+}
+
+/// TaskArea 付き Familiar を、エリア指定 UI と同じく Patrol 指示にする。
+/// `ActiveCommand` は save schema の外にあり、ロード直後は `Idle` に戻るため。
+fn start_task_area_patrols(world: &mut World) {
+    let mut query = world.query_filtered::<&mut ActiveCommand, (With<Familiar>, With<TaskArea>)>();
+    for mut active_command in query.iter_mut(world) {
+        active_command.command = FamiliarCommand::Patrol;
+    }
+}
+
+fn cells(min: GridPos, max: GridPos) -> impl Iterator<Item = GridPos> {
+    (min.0..=max.0).flat_map(move |x| (min.1..=max.1).map(move |y| (x, y)))
+}
+
+/// grid 矩形 `min..=max` の外周に合わせた world 座標の境界。
+fn grid_rect_bounds(min: GridPos, max: GridPos) -> (Vec2, Vec2) {
+    let half = Vec2::splat(TILE_SIZE * 0.5);
+    (
+        WorldMap::grid_to_world(min.0, min.1) - half,
+        WorldMap::grid_to_world(max.0, max.1) + half,
+    )
+}
+
+/// 適用済みシナリオの実行と検査。
+pub(crate) struct ScenarioRun {
+    app: App,
+    ticks: u32,
+}
+
+impl ScenarioRun {
+    /// これまでに進めた tick 数。
+    pub(crate) fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    pub(crate) fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// `count` tick 進める。
+    pub(crate) fn tick(&mut self, count: u32) {
+        for _ in 0..count {
+            self.app.update();
+            self.ticks += 1;
+        }
+    }
+
+    /// `done` が成立するまで最大 `max_ticks` tick 進め、成立した時点の通算 tick を返す。
+    pub(crate) fn run_until(
+        &mut self,
+        max_ticks: u32,
+        mut done: impl FnMut(&mut ScenarioRun) -> bool,
+    ) -> Option<u32> {
+        for _ in 0..max_ticks {
+            if done(self) {
+                return Some(self.ticks);
+            }
+            self.tick(1);
+        }
+        done(self).then_some(self.ticks)
+    }
+
+    pub(crate) fn soul(&mut self, name: &str) -> Entity {
+        let world = self.app.world_mut();
+        let mut query = world.query_filtered::<(Entity, &SoulIdentity), With<DamnedSoul>>();
+        query
+            .iter(world)
+            .find(|(_, identity)| identity.name == name)
+            .map(|(entity, _)| entity)
+            .unwrap_or_else(|| panic!("no soul named {name}"))
+    }
+
+    pub(crate) fn familiar(&mut self, name: &str) -> Entity {
+        let world = self.app.world_mut();
+        let mut query = world.query::<(Entity, &Familiar)>();
+        query
+            .iter(world)
+            .find(|(_, familiar)| familiar.name == name)
+            .map(|(entity, _)| entity)
+            .unwrap_or_else(|| panic!("no familiar named {name}"))
+    }
+
+    pub(crate) fn soul_count(&mut self) -> usize {
+        let world = self.app.world_mut();
+        world.query::<&DamnedSoul>().iter(world).count()
+    }
+
+    /// シナリオ開始後に逃走した Soul の数。
+    pub(crate) fn escaped_souls(&self) -> u32 {
+        self.app
+            .world()
+            .resource::<PopulationManager>()
+            .total_escaped
+    }
+
+    /// `grid` の Stockpile に格納された `resource` の数。
+    pub(crate) fn stored_at(&mut self, grid: GridPos, resource: ResourceType) -> usize {
+        let world = self.app.world_mut();
+        let Some(stockpile) = world.resource::<WorldMap>().stockpile_entity(grid) else {
+            panic!("no stockpile at {grid:?}");
+        };
+        world
+            .query::<(&ResourceItem, &StoredIn)>()
+            .iter(world)
+            .filter(|(item, stored_in)| item.0 == resource && stored_in.0 == stockpile)
+            .count()
+    }
+
+    /// どの Stockpile にも格納されていない `resource` の数（運搬中を含む）。
+    pub(crate) fn unstored_items(&mut self, resource: ResourceType) -> usize {
+        let world = self.app.world_mut();
+        world
+            .query_filtered::<&ResourceItem, Without<StoredIn>>()
+            .iter(world)
+            .filter(|item| item.0 == resource)
+            .count()
+    }
+
+    /// `grid` を占有する完成済み建物の種類。設計図や空きセルなら `None`。
+    pub(crate) fn building_at(&self, grid: GridPos) -> Option<BuildingType> {
+        let world = self.app.world();
+        let entity = world.resource::<WorldMap>().building_entity(grid)?;
+        world.get::<Building>(entity).map(|building| building.kind)
+    }
+
+    pub(crate) fn invariant_report(&self) -> &InvariantReport {
+        self.app.world().resource::<InvariantReport>()
+    }
+}
+
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+fn diligent() -> DamnedSoul {
+    DamnedSoul {
+        laziness: 0.1,
+        motivation: 0.9,
+        ..default()
+    }
+}
+
+/// 40..=55 四方の Yard と、同じ範囲を担当する Familiar 1 体・Soul 2 体。
+fn yard_crew() -> Scenario {
+    Scenario::new()
+        .yard((40, 40), (55, 55))
+        .familiar("Imp", (47, 47), ((40, 40), (55, 55)))
+        .soul("Alice", (45, 45), diligent())
+        .soul("Bram", (46, 45), diligent())
+}
+
+#[test]
+fn declared_world_replaces_the_generated_startup_world() {
+    let mut run = Scenario::new()
+        .terrain((30, 30), (32, 32), TerrainType::Dirt)
+        .yard((40, 40), (44, 44))
+        .stockpile_with((41, 41), ResourceType::Wood, 3)
+        .items((43, 43), ResourceType::Wood, 5)
+        .building(BuildingType::Wall, (35, 35))
+        .soul("Alice", (42, 42), DamnedSoul::default())
+        .familiar("Imp", (42, 43), ((40, 40), (44, 44)))
+        .start();
+
+    assert_eq!(run.ticks(), 0);
+    assert_eq!(run.soul_count(), 1);
+    run.soul("Alice");
+    assert_eq!(run.stored_at((41, 41), ResourceType::Wood), 3);
+    assert_eq!(run.unstored_items(ResourceType::Wood), 5);
+    assert_eq!(run.building_at((35, 35)), Some(BuildingType::Wall));
+
+    let imp = run.familiar("Imp");
+    let world = run.world_mut();
+    assert_eq!(
+        world.get::<ActiveCommand>(imp).map(|active| active.command),
+        Some(FamiliarCommand::Patrol)
+    );
+    let world_map = world.resource::<WorldMap>();
+    let dirt = world_map.pos_to_idx(31, 31).unwrap();
+    assert_eq!(world_map.terrain_at_idx(dirt), Some(TerrainType::Dirt));
+    assert!(!world_map.is_walkable(35, 35));
+}
+
+#[test]
+fn ground_wood_is_hauled_into_the_yard_stockpiles() {
+    let mut run = yard_crew()
+        .stockpile((50, 50))
+        .stockpile((51, 50))
+        .items((43, 43), ResourceType::Wood, 20)
+        .start();
+
+    let stored = |run: &mut ScenarioRun| {
+        run.stored_at((50, 50), ResourceType::Wood) + run.stored_at((51, 50), ResourceType::Wood)
+    };
+    let finished = run.run_until(6_000, |run| stored(run) == 20);
+
+    assert!(
+        finished.is_some(),
+        "only {} of 20 wood stored after {} ticks",
+        stored(&mut run),
+        run.ticks()
+    );
+    assert_eq!(run.unstored_items(ResourceType::Wood), 0);
+    assert_eq!(run.escaped_souls(), 0);
+    assert!(run.invariant_report().checks > 0);
+}
+
+#[test]
+fn wall_blueprint_completes_from_stocked_materials() {
+    let mut run = yard_crew()
+        .stockpile_with((50, 50), ResourceType::Wood, 2)
+        .stockpile_with((51, 50), ResourceType::StasisMud, 2)
+        .blueprint(BuildingType::Wall, (44, 48))
+        .start();
+
+    assert_eq!(run.building_at((44, 48)), None);
+    let finished = run.run_until(6_000, |run| {
+        run.building_at((44, 48)) == Some(BuildingType::Wall)
+    });
+
+    assert!(
+        finished.is_some(),
+        "wall at (44, 48) not completed after {} ticks",
+        run.ticks()
+    );
+    assert_eq!(run.soul_count(), 2);
+    assert_eq!(run.escaped_souls(), 0);
+}
@@ -6,11 +6,16 @@ use bevy::light::NotShadowCaster;
 use bevy::prelude::*;
 use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH, TILE_SIZE, building_3d_render_layers};
 use hw_visual::TerrainSurfaceMaterial;
-use hw_world::{GeneratedWorldLayout, generate_world_layout, grid_to_world};
+use hw_world::{
+    GeneratedWorldLayout, MapTemplate, generate_world_layout_with_template, grid_to_world,
+};
 
 use super::{Tile, WorldMapWrite};
 
 const WORLDGEN_SEED_ENV: &str = "HELL_WORKERS_WORLDGEN_SEED";
+const MAP_TEMPLATE_ENV: &str = "HELL_WORKERS_MAP_TEMPLATE";
+/// `HELL_WORKERS_MAP_TEMPLATE` に指定すると seed からテンプレートを選ぶ値
+const MAP_TEMPLATE_FROM_SEED: &str = "seed";
 
 /// 地形描画 chunk のサイズ（タイル数/辺）
 const CHUNK_TILES: i32 = 16;
@@ -51,11 +56,39 @@ pub fn resolve_worldgen_seed(perf_config: &PerfScenarioConfig) -> u64 {
     }
 }
 
+/// 新規ゲームのマップテンプレートを決める。
+///
+/// `HELL_WORKERS_MAP_TEMPLATE` にテンプレート名（`classic` / `twin-rivers` / `north-lake` /
+/// `island-site` / `badlands`）か `seed` を指定する。未指定・不正値は `Classic`。
+/// perf シナリオはベースラインを保つため常に `Classic`。
+pub fn resolve_map_template(perf_config: &PerfScenarioConfig, master_seed: u64) -> MapTemplate {
+    if perf_config.enabled() {
+        return MapTemplate::Classic;
+    }
+
+    match std::env::var(MAP_TEMPLATE_ENV) {
+        Ok(raw) if raw.trim().eq_ignore_ascii_case(MAP_TEMPLATE_FROM_SEED) => {
+            MapTemplate::from_seed(master_seed)
+        }
+        Ok(raw) => raw.parse().unwrap_or_else(|err| {
+            warn!(
+                "BEVY_STARTUP: invalid {}='{}' ({err}); falling back to {}",
+                MAP_TEMPLATE_ENV,
+                raw,
+                MapTemplate::Classic
+            );
+            MapTemplate::Classic
+        }),
+        Err(_) => MapTemplate::Classic,
+    }
+}
+
 pub fn prepare_generated_world_layout_resource(
     perf_config: &PerfScenarioConfig,
 ) -> GeneratedWorldLayoutResource {
     let master_seed = resolve_worldgen_seed(perf_config);
-    let layout = generate_world_layout(master_seed);
+    let template = resolve_map_template(perf_config, master_seed);
+    let layout = generate_world_layout_with_template(master_seed, template);
     GeneratedWorldLayoutResource {
         master_seed,
         layout,
@@ -95,10 +128,11 @@ pub fn spawn_map(
     }
 
     info!(
-        "BEVY_STARTUP: Map tile anchors spawned ({}x{} tiles, worldgen seed={}, attempt={}, fallback={})",
+        "BEVY_STARTUP: Map tile anchors spawned ({}x{} tiles, worldgen seed={}, template={}, attempt={}, fallback={})",
         MAP_WIDTH,
         MAP_HEIGHT,
         generated_layout.master_seed,
+        generated_layout.layout.template,
         generated_layout.layout.generation_attempt,
         generated_layout.layout.used_fallback
     );
@@ -0,0 +1,232 @@
+//! プレイヤーが設定するアラート規則
+//!
+//! 規則はセーブ単位で保持する。条件の評価と通知は root の `alerts` システム、
+//! 規則の編集は `hw_ui` のアラートパネルが担当する。
+
+use bevy::prelude::*;
+
+use crate::locale::Localization;
+use crate::logistics::ResourceType;
+
+/// 規則を評価する間隔（ゲーム内秒）
+pub const ALERT_EVALUATION_INTERVAL_SECS: f32 = 2.0;
+/// 保持できる規則数の上限（アラートパネルの行数）
+pub const MAX_ALERT_RULES: usize = 8;
+
+const RESOURCE_THRESHOLD_STEP: u32 = 5;
+const RESOURCE_THRESHOLD_MAX: u32 = 500;
+const STRESS_THRESHOLD_STEP: f32 = 0.05;
+const IDLE_SECONDS_STEP: f32 = 15.0;
+const IDLE_SECONDS_MAX: f32 = 600.0;
+
+/// アラートの発火条件
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum AlertCondition {
+    /// Stockpile の備蓄が `threshold` 未満
+    ResourceBelow {
+        resource: ResourceType,
+        threshold: u32,
+    },
+    /// Soul がいるのに休憩所の空きがない
+    NoRestCapacity,
+    /// 需要が発電を上回っている電力網がある
+    GridBlackout,
+    /// ストレスが `threshold` を超えた Soul がいる
+    SoulStressAbove { threshold: f32 },
+    /// 使役中の Soul がいない状態が `seconds` 続いた使い魔がいる
+    FamiliarWithoutWorkers { seconds: f32 },
+}
+
+impl AlertCondition {
+    /// パネルに表示する条件文
+    pub fn label(&self, text: &Localization) -> String {
+        match self {
+            Self::ResourceBelow {
+                resource,
+                threshold,
+            } => text.format(
+                "alert.resource_below",
+                &[
+                    ("resource", &text.text(resource.text_id())),
+                    ("threshold", threshold),
+                ],
+            ),
+            Self::NoRestCapacity => text.text("alert.no_rest_capacity").to_string(),
+            Self::GridBlackout => text.text("alert.grid_blackout").to_string(),
+            Self::SoulStressAbove { threshold } => text.format(
+                "alert.soul_stress_above",
+                &[("threshold", &format!("{threshold:.2}"))],
+            ),
+            Self::FamiliarWithoutWorkers { seconds } => text.format(
+                "alert.familiar_without_workers",
+                &[("seconds", &format!("{seconds:.0}"))],
+            ),
+        }
+    }
+
+    /// しきい値を持つ条件なら true
+    pub fn is_adjustable(&self) -> bool {
+        matches!(
+            self,
+            Self::ResourceBelow { .. }
+                | Self::SoulStressAbove { .. }
+                | Self::FamiliarWithoutWorkers { .. }
+        )
+    }
+
+    /// しきい値を `steps` 段階だけ増減する（範囲外は丸める）。
+    pub fn adjust(&mut self, steps: i32) {
+        match self {
+            Self::ResourceBelow { threshold, .. } => {
+                let next = *threshold as i64 + steps as i64 * RESOURCE_THRESHOLD_STEP as i64;
+                *threshold = next.clamp(0, RESOURCE_THRESHOLD_MAX as i64) as u32;
+            }
+            Self::SoulStressAbove { threshold } => {
+                let next = *threshold + steps as f32 * STRESS_THRESHOLD_STEP;
+                *threshold = ((next / STRESS_THRESHOLD_STEP).round() * STRESS_THRESHOLD_STEP)
+                    .clamp(STRESS_THRESHOLD_STEP, 1.0);
+            }
+            Self::FamiliarWithoutWorkers { seconds } => {
+                *seconds = (*seconds + steps as f32 * IDLE_SECONDS_STEP)
+                    .clamp(IDLE_SECONDS_STEP, IDLE_SECONDS_MAX);
+            }
+            Self::NoRestCapacity | Self::GridBlackout => {}
+        }
+    }
+
+    /// 備蓄条件の資源を `ResourceType::ALL` の次の種別へ切り替える。
+    pub fn cycle_resource(&mut self) {
+        if let Self::ResourceBelow { resource, .. } = self {
+            let index = ResourceType::ALL
+                .iter()
+                .position(|kind| kind == resource)
+                .unwrap_or(0);
+            *resource = ResourceType::ALL[(index + 1) % ResourceType::ALL.len()];
+        }
+    }
+}
+
+/// 1 つのアラート規則
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct AlertRule {
+    /// 通知の重複排除キーに使う安定 ID
+    pub id: u32,
+    pub enabled: bool,
+    pub condition: AlertCondition,
+}
+
+/// アラート規則の一覧（セーブ単位で永続化）
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct AlertRules {
+    rules: Vec<AlertRule>,
+    next_id: u32,
+}
+
+impl Default for AlertRules {
+    fn default() -> Self {
+        let mut rules = Self {
+            rules: Vec::new(),
+            next_id: 1,
+        };
+        for condition in [
+            AlertCondition::ResourceBelow {
+                resource: ResourceType::Wood,
+                threshold: 20,
+            },
+            AlertCondition::NoRestCapacity,
+            AlertCondition::GridBlackout,
+            AlertCondition::SoulStressAbove { threshold: 0.9 },
+            AlertCondition::FamiliarWithoutWorkers { seconds: 60.0 },
+        ] {
+            rules.push(condition);
+        }
+        rules
+    }
+}
+
+impl AlertRules {
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<&mut AlertRule> {
+        self.rules.iter_mut().find(|rule| rule.id == id)
+    }
+
+    /// 有効な規則として追加する。上限に達していれば `None`。
+    pub fn push(&mut self, condition: AlertCondition) -> Option<u32> {
+        if self.rules.len() >= MAX_ALERT_RULES {
+            return None;
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1).max(1);
+        self.rules.push(AlertRule {
+            id,
+            enabled: true,
+            condition,
+        });
+        Some(id)
+    }
+
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.id != id);
+        self.rules.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_cover_the_stock_rules_and_ids_stay_unique_after_removal() {
+        let mut rules = AlertRules::default();
+        assert_eq!(rules.rules().len(), 5);
+        assert!(rules.rules().iter().all(|rule| rule.enabled));
+
+        assert!(rules.remove(1));
+        let id = rules
+            .push(AlertCondition::ResourceBelow {
+                resource: ResourceType::Rock,
+                threshold: 10,
+            })
+            .unwrap();
+        assert_eq!(id, 6);
+        while rules.rules().len() < MAX_ALERT_RULES {
+            rules.push(AlertCondition::GridBlackout).unwrap();
+        }
+        assert_eq!(rules.push(AlertCondition::GridBlackout), None);
+    }
+
+    #[test]
+    fn thresholds_clamp_and_resources_cycle() {
+        let mut stock = AlertCondition::ResourceBelow {
+            resource: ResourceType::Wood,
+            threshold: 5,
+        };
+        stock.adjust(-3);
+        stock.cycle_resource();
+        assert_eq!(
+            stock,
+            AlertCondition::ResourceBelow {
+                resource: ResourceType::Rock,
+                threshold: 0,
+            }
+        );
+
+        let mut stress = AlertCondition::SoulStressAbove { threshold: 0.9 };
+        stress.adjust(4);
+        assert_eq!(stress, AlertCondition::SoulStressAbove { threshold: 1.0 });
+        stress.adjust(-1);
+        let AlertCondition::SoulStressAbove { threshold } = stress else {
+            unreachable!();
+        };
+        assert!((threshold - 0.95).abs() < 1e-6);
+        assert_eq!(
+            stress.label(&Localization::shipped(crate::locale::Locale::Ja)),
+            "ストレス 0.95 超の Soul"
+        );
+    }
+}
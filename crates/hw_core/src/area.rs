@@ -43,70 +43,201 @@ impl AreaBounds {
             && pos.y >= self.min.y - m
             && pos.y <= self.max.y + m
     }
+
+    /// 2 つの矩形が重なる面積。重ならなければ 0。
+    pub fn intersection_area(&self, other: &AreaBounds) -> f32 {
+        let w = (self.max.x.min(other.max.x) - self.min.x.max(other.min.x)).max(0.0);
+        let h = (self.max.y.min(other.max.y) - self.min.y.max(other.min.y)).max(0.0);
+        w * h
+    }
+
+    /// 矩形の外側にある点から矩形までの距離の二乗。内側なら 0。
+    pub fn distance_sq_outside(&self, pos: Vec2) -> f32 {
+        let clamped = pos.clamp(self.min, self.max);
+        pos.distance_squared(clamped)
+    }
 }
 
 /// タスクエリア - 使い魔が担当するエリア
+///
+/// 1 つ以上の矩形の和集合。`bounds` が主矩形で、`extra_rects` が追加の部分矩形。
+/// 判定系（`contains` など）は和集合に対して働き、`min` / `max` / `size` / `bounds()` は
+/// 全矩形を囲む外接矩形を返す。空間グリッドの絞り込みには外接矩形ではなく
+/// [`TaskArea::rects`] を使う。
 #[derive(Component, Clone, Debug, PartialEq, Reflect)]
 #[reflect(Component)]
 pub struct TaskArea {
-    pub bounds: AreaBounds,
+    bounds: AreaBounds,
+    /// 旧セーブには存在しないため既定値（空）で読み込む
+    #[reflect(default)]
+    extra_rects: Vec<AreaBounds>,
 }
 
 impl TaskArea {
     pub fn from_points(a: Vec2, b: Vec2) -> Self {
-        Self {
-            bounds: AreaBounds::from_points(a, b),
+        AreaBounds::from_points(a, b).into()
+    }
+
+    /// 矩形列から和集合エリアを作る。空なら `None`。
+    pub fn from_rects(rects: impl IntoIterator<Item = AreaBounds>) -> Option<Self> {
+        let mut rects = rects.into_iter();
+        let bounds = rects.next()?;
+        Some(Self {
+            bounds,
+            extra_rects: rects.collect(),
+        })
+    }
+
+    /// 主矩形を先頭に、全ての部分矩形を返す
+    pub fn rects(&self) -> impl Iterator<Item = &AreaBounds> + '_ {
+        std::iter::once(&self.bounds).chain(self.extra_rects.iter())
+    }
+
+    pub fn rect_count(&self) -> usize {
+        1 + self.extra_rects.len()
+    }
+
+    pub fn rect(&self, index: usize) -> Option<&AreaBounds> {
+        match index {
+            0 => Some(&self.bounds),
+            _ => self.extra_rects.get(index - 1),
+        }
+    }
+
+    /// `pos` を含む部分矩形のうち、後から追加されたもの（描画で上に来るもの）の index
+    pub fn rect_index_at(&self, pos: Vec2) -> Option<usize> {
+        (0..self.rect_count())
+            .rev()
+            .find(|&index| self.rect(index).is_some_and(|rect| rect.contains(pos)))
+    }
+
+    /// 部分矩形を 1 つ追加したエリアを返す
+    pub fn with_rect_added(&self, rect: AreaBounds) -> Self {
+        let mut area = self.clone();
+        area.extra_rects.push(rect);
+        area
+    }
+
+    /// `index` の部分矩形を差し替えたエリアを返す。範囲外なら変更しない。
+    pub fn with_rect_replaced(&self, index: usize, rect: AreaBounds) -> Self {
+        let mut area = self.clone();
+        match index {
+            0 => area.bounds = rect,
+            _ => {
+                if let Some(slot) = area.extra_rects.get_mut(index - 1) {
+                    *slot = rect;
+                }
+            }
+        }
+        area
+    }
+
+    /// `index` の部分矩形を取り除いたエリアを返す。最後の 1 つは取り除けない。
+    pub fn with_rect_removed(&self, index: usize) -> Option<Self> {
+        if index >= self.rect_count() || self.rect_count() == 1 {
+            return None;
         }
+        Self::from_rects(
+            self.rects()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, rect)| rect.clone()),
+        )
     }
 
+    /// 外接矩形の中心。和集合の外に出る場合（L 字など）は主矩形の中心を返すため、
+    /// 使い魔の巡回先として常にエリア内の点になる。
     pub fn center(&self) -> Vec2 {
-        self.bounds.center()
+        let center = self.bounds().center();
+        if self.contains(center) {
+            center
+        } else {
+            self.bounds.center()
+        }
     }
 
     pub fn size(&self) -> Vec2 {
-        self.bounds.size()
+        self.bounds().size()
     }
 
     pub fn contains(&self, pos: Vec2) -> bool {
-        self.bounds.contains(pos)
+        self.rects().any(|rect| rect.contains(pos))
     }
 
     pub fn contains_with_margin(&self, pos: Vec2, margin: f32) -> bool {
-        self.bounds.contains_with_margin(pos, margin)
+        self.rects()
+            .any(|rect| rect.contains_with_margin(pos, margin))
     }
 
+    /// 和集合の輪郭から `thickness` 以内にある点か。部分矩形同士の接合部は輪郭に含めない。
     pub fn contains_border(&self, pos: Vec2, thickness: f32) -> bool {
-        let in_outer = self.bounds.contains_with_margin(pos, thickness);
-        let inner = AreaBounds::new(
-            self.bounds.min + Vec2::splat(thickness),
-            self.bounds.max - Vec2::splat(thickness),
-        );
-        let in_inner = inner.contains(pos);
-        in_outer && !in_inner
+        if !self.contains_with_margin(pos, thickness) {
+            return false;
+        }
+        let t = thickness.abs();
+        let deep_inside = [
+            Vec2::new(-t, -t),
+            Vec2::new(t, -t),
+            Vec2::new(-t, t),
+            Vec2::new(t, t),
+        ]
+        .into_iter()
+        .all(|offset| self.contains(pos + offset));
+        !deep_inside
+    }
+
+    /// 部分矩形面積の合計。部分矩形同士の重なりは重複して数える。
+    pub fn total_area(&self) -> f32 {
+        self.rects()
+            .map(|rect| {
+                let size = rect.size();
+                size.x * size.y
+            })
+            .sum()
+    }
+
+    /// 他エリアと重なる面積（部分矩形の組ごとの重なりの合計）
+    pub fn overlap_area(&self, other: &TaskArea) -> f32 {
+        self.rects()
+            .flat_map(|a| other.rects().map(move |b| a.intersection_area(b)))
+            .sum()
+    }
+
+    /// 最も近い部分矩形までの距離の二乗。エリア内なら 0。
+    pub fn distance_sq_outside(&self, pos: Vec2) -> f32 {
+        self.rects()
+            .map(|rect| rect.distance_sq_outside(pos))
+            .fold(f32::INFINITY, f32::min)
     }
 
+    /// 全矩形を囲む外接矩形
     pub fn bounds(&self) -> AreaBounds {
-        self.bounds.clone()
+        AreaBounds::new(self.min(), self.max())
     }
 
     pub fn min(&self) -> Vec2 {
-        self.bounds.min
+        self.rects()
+            .fold(self.bounds.min, |acc, rect| acc.min(rect.min))
     }
 
     pub fn max(&self) -> Vec2 {
-        self.bounds.max
+        self.rects()
+            .fold(self.bounds.max, |acc, rect| acc.max(rect.max))
     }
 }
 
 impl From<&TaskArea> for AreaBounds {
     fn from(area: &TaskArea) -> Self {
-        area.bounds.clone()
+        area.bounds()
     }
 }
 
 impl From<AreaBounds> for TaskArea {
     fn from(bounds: AreaBounds) -> Self {
-        TaskArea { bounds }
+        TaskArea {
+            bounds,
+            extra_rects: Vec::new(),
+        }
     }
 }
 
@@ -129,6 +260,7 @@ pub fn get_drag_start(mode: TaskMode) -> Option<Vec2> {
         TaskMode::DreamPlanting(s) => s,
         TaskMode::StockpilePolicyEdit(s) => s,
         TaskMode::SoulSpaPlace(s) => s,
+        TaskMode::LayoutExport(s) => s,
         _ => None,
     }
 }
@@ -176,8 +308,7 @@ pub fn overlap_summary_from_areas(
     selected_area: &TaskArea,
     areas: impl Iterator<Item = (Entity, TaskArea)>,
 ) -> Option<(usize, f32)> {
-    let selected_size = selected_area.size();
-    let selected_area_value = selected_size.x.abs() * selected_size.y.abs();
+    let selected_area_value = selected_area.total_area();
     if selected_area_value <= f32::EPSILON {
         return None;
     }
@@ -190,13 +321,7 @@ pub fn overlap_summary_from_areas(
             continue;
         }
 
-        let overlap_w = (selected_area.max().x.min(area.max().x)
-            - selected_area.min().x.max(area.min().x))
-        .max(0.0);
-        let overlap_h = (selected_area.max().y.min(area.max().y)
-            - selected_area.min().y.max(area.min().y))
-        .max(0.0);
-        let overlap_area = overlap_w * overlap_h;
+        let overlap_area = selected_area.overlap_area(&area);
         if overlap_area <= f32::EPSILON {
             continue;
         }
@@ -210,3 +335,48 @@ pub fn overlap_summary_from_areas(
 
     Some((overlap_count, max_ratio))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn l_shape() -> TaskArea {
+        TaskArea::from_points(Vec2::ZERO, Vec2::new(30.0, 10.0))
+            .with_rect_added(AreaBounds::new(Vec2::ZERO, Vec2::new(10.0, 30.0)))
+    }
+
+    #[test]
+    fn multi_rect_area_contains_only_the_union() {
+        let area = l_shape();
+
+        assert!(area.contains(Vec2::new(25.0, 5.0)));
+        assert!(area.contains(Vec2::new(5.0, 25.0)));
+        assert!(!area.contains(Vec2::new(25.0, 25.0)));
+        assert_eq!(
+            area.bounds(),
+            AreaBounds::new(Vec2::ZERO, Vec2::splat(30.0))
+        );
+        assert!(area.contains(area.center()));
+    }
+
+    #[test]
+    fn border_skips_seams_between_sub_rects() {
+        let area = TaskArea::from_points(Vec2::ZERO, Vec2::new(10.0, 10.0))
+            .with_rect_added(AreaBounds::new(Vec2::new(10.0, 0.0), Vec2::new(20.0, 10.0)));
+
+        assert!(area.contains_border(Vec2::new(0.5, 5.0), 1.0));
+        assert!(!area.contains_border(Vec2::new(10.0, 5.0), 1.0));
+    }
+
+    #[test]
+    fn sub_rect_edits_keep_at_least_one_rect() {
+        let area = l_shape();
+
+        assert_eq!(area.rect_index_at(Vec2::new(5.0, 5.0)), Some(1));
+        assert_eq!(area.rect_index_at(Vec2::new(25.0, 5.0)), Some(0));
+        let removed = area.with_rect_removed(0).expect("two rects");
+        assert_eq!(removed.rect_count(), 1);
+        assert_eq!(removed.rect(0), area.rect(1));
+        assert!(removed.with_rect_removed(0).is_none());
+    }
+}
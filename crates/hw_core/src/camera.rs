@@ -3,3 +3,76 @@ use bevy::prelude::*;
 /// メインレンダーカメラのマーカー
 #[derive(Component)]
 pub struct MainCamera;
+
+/// カメラブックマークのスロット数（Ctrl/Alt + 1..4）
+pub const CAMERA_BOOKMARK_SLOTS: usize = 4;
+
+/// 保存済みのカメラ位置と拡大率
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct CameraBookmark {
+    pub position: Vec2,
+    /// `PanCamera::zoom_factor`
+    pub zoom: f32,
+}
+
+/// 番号付きカメラブックマーク（セーブ単位で永続化）
+#[derive(Resource, Reflect, Default, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct CameraBookmarks {
+    slots: [Option<CameraBookmark>; CAMERA_BOOKMARK_SLOTS],
+}
+
+impl CameraBookmarks {
+    pub fn get(&self, slot: usize) -> Option<CameraBookmark> {
+        self.slots.get(slot).copied().flatten()
+    }
+
+    /// `slot` が範囲外なら何もしない。
+    pub fn store(&mut self, slot: usize, bookmark: CameraBookmark) {
+        if let Some(entry) = self.slots.get_mut(slot) {
+            *entry = Some(bookmark);
+        }
+    }
+}
+
+/// 選択エンティティへのカメラ追従状態
+///
+/// `last_position` は追従システムが前フレームに書いたカメラ位置。
+/// カメラがそこから動いていればプレイヤーがパンしたとみなして追従を解除する。
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq)]
+pub struct CameraFollow {
+    pub target: Option<Entity>,
+    pub last_position: Option<Vec2>,
+}
+
+impl CameraFollow {
+    pub fn start(&mut self, target: Entity) {
+        self.target = Some(target);
+        self.last_position = None;
+    }
+
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmarks_store_per_slot_and_ignore_out_of_range_slots() {
+        let mut bookmarks = CameraBookmarks::default();
+        let bookmark = CameraBookmark {
+            position: Vec2::new(12.0, -4.0),
+            zoom: 1.5,
+        };
+
+        bookmarks.store(1, bookmark);
+        bookmarks.store(CAMERA_BOOKMARK_SLOTS, bookmark);
+
+        assert_eq!(bookmarks.get(0), None);
+        assert_eq!(bookmarks.get(1), Some(bookmark));
+        assert_eq!(bookmarks.get(CAMERA_BOOKMARK_SLOTS), None);
+    }
+}
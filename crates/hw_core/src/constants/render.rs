@@ -30,6 +30,10 @@ pub const Z_MAP_DIRT: f32 = 0.02;
 pub const Z_MAP_GRASS: f32 = 0.03;
 /// Roomオーバーレイ（床より上、拾得アイテムより下）
 pub const Z_ROOM_OVERLAY: f32 = 0.08;
+/// 禁止区域オーバーレイ（Roomオーバーレイの上、拾得アイテムより下）
+pub const Z_FORBIDDEN_ZONE_OVERLAY: f32 = 0.09;
+/// 分析ヒートマップオーバーレイ（禁止区域オーバーレイの上、拾得アイテムより下）
+pub const Z_HEATMAP_OVERLAY: f32 = 0.095;
 /// 地面にあるアイテム（資材など）のベースレイヤー
 pub const Z_ITEM: f32 = 0.1;
 /// 建築物: 床・地面面（Z_ITEM より下）
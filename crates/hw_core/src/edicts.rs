@@ -0,0 +1,268 @@
+//! Dream Edicts（DreamPool を消費して発令する全体方針）
+//!
+//! 発令中の勅令は `DreamEdicts` に期限付きで保持され、バイタル更新・作業速度・
+//! Soul の定期スポーンが `EdictModifiers` を読んで効果を反映する。
+
+use bevy::prelude::*;
+
+use crate::soul::DreamPool;
+
+// ============================================================
+// 定数
+// ============================================================
+
+/// Mandatory Siesta: 疲労回復速度の倍率
+pub const SIESTA_FATIGUE_RECOVERY_MULTIPLIER: f32 = 1.5;
+/// Mandatory Siesta: 作業速度の倍率
+pub const SIESTA_WORK_SPEED_MULTIPLIER: f32 = 0.75;
+/// Ledger of Shame: やる気の上昇量（/秒）
+pub const LEDGER_MOTIVATION_BONUS_RATE: f32 = 0.01;
+/// Ledger of Shame: ストレス増加速度の倍率
+pub const LEDGER_STRESS_GAIN_MULTIPLIER: f32 = 1.3;
+/// Open Gates: 定期スポーン1回あたりの追加数
+pub const OPEN_GATES_SPAWN_COUNT_BONUS: u32 = 1;
+
+// ============================================================
+// 勅令の定義
+// ============================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum EdictKind {
+    /// 疲労回復が速くなる代わりに作業が遅くなる
+    MandatorySiesta,
+    /// やる気が上がる代わりにストレスが溜まりやすくなる
+    LedgerOfShame,
+    /// 定期スポーンで流入する Soul が増える
+    OpenGates,
+}
+
+impl EdictKind {
+    pub const ALL: [Self; 3] = [Self::MandatorySiesta, Self::LedgerOfShame, Self::OpenGates];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::MandatorySiesta => "Mandatory Siesta",
+            Self::LedgerOfShame => "Ledger of Shame",
+            Self::OpenGates => "Open Gates",
+        }
+    }
+
+    /// UI 表示名の文言 ID（`label` はログ用の固定英語名）
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::MandatorySiesta => "ui.edict.mandatory_siesta",
+            Self::LedgerOfShame => "ui.edict.ledger_of_shame",
+            Self::OpenGates => "ui.edict.open_gates",
+        }
+    }
+
+    /// 効果説明の文言 ID
+    pub const fn effect_text_id(self) -> &'static str {
+        match self {
+            Self::MandatorySiesta => "ui.edict.mandatory_siesta.effect",
+            Self::LedgerOfShame => "ui.edict.ledger_of_shame.effect",
+            Self::OpenGates => "ui.edict.open_gates.effect",
+        }
+    }
+
+    /// 発令に必要な Dream
+    pub const fn cost(self) -> f32 {
+        match self {
+            Self::MandatorySiesta => 30.0,
+            Self::LedgerOfShame => 40.0,
+            Self::OpenGates => 60.0,
+        }
+    }
+
+    /// 効果時間（`GameTime::seconds` 基準）
+    pub const fn duration_secs(self) -> f32 {
+        match self {
+            Self::MandatorySiesta => 12.0 * 60.0 * 60.0,
+            Self::LedgerOfShame => 24.0 * 60.0 * 60.0,
+            Self::OpenGates => 24.0 * 60.0 * 60.0,
+        }
+    }
+
+    fn apply(self, modifiers: &mut EdictModifiers) {
+        match self {
+            Self::MandatorySiesta => {
+                modifiers.fatigue_recovery_multiplier *= SIESTA_FATIGUE_RECOVERY_MULTIPLIER;
+                modifiers.work_speed_multiplier *= SIESTA_WORK_SPEED_MULTIPLIER;
+            }
+            Self::LedgerOfShame => {
+                modifiers.motivation_bonus_rate += LEDGER_MOTIVATION_BONUS_RATE;
+                modifiers.stress_gain_multiplier *= LEDGER_STRESS_GAIN_MULTIPLIER;
+            }
+            Self::OpenGates => {
+                modifiers.spawn_count_bonus += OPEN_GATES_SPAWN_COUNT_BONUS;
+            }
+        }
+    }
+}
+
+/// 発令中の勅令を合成した補正値。勅令が無いときは効果なし
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdictModifiers {
+    pub fatigue_recovery_multiplier: f32,
+    pub work_speed_multiplier: f32,
+    pub stress_gain_multiplier: f32,
+    pub motivation_bonus_rate: f32,
+    pub spawn_count_bonus: u32,
+}
+
+impl Default for EdictModifiers {
+    fn default() -> Self {
+        Self {
+            fatigue_recovery_multiplier: 1.0,
+            work_speed_multiplier: 1.0,
+            stress_gain_multiplier: 1.0,
+            motivation_bonus_rate: 0.0,
+            spawn_count_bonus: 0,
+        }
+    }
+}
+
+// ============================================================
+// リソース
+// ============================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ActiveEdict {
+    pub kind: EdictKind,
+    /// 失効時刻（`GameTime::seconds`）
+    pub expires_at: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdictEnactError {
+    AlreadyActive,
+    InsufficientDream { cost: f32, available: f32 },
+}
+
+/// 発令中の勅令。セーブ対象の Resource
+#[derive(Resource, Reflect, Debug, Clone, Default, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct DreamEdicts {
+    pub active: Vec<ActiveEdict>,
+}
+
+impl DreamEdicts {
+    pub fn is_active(&self, kind: EdictKind) -> bool {
+        self.active.iter().any(|edict| edict.kind == kind)
+    }
+
+    pub fn remaining_secs(&self, kind: EdictKind, now: f32) -> Option<f32> {
+        self.active
+            .iter()
+            .find(|edict| edict.kind == kind)
+            .map(|edict| (edict.expires_at - now).max(0.0))
+    }
+
+    pub fn modifiers(&self) -> EdictModifiers {
+        let mut modifiers = EdictModifiers::default();
+        for edict in &self.active {
+            edict.kind.apply(&mut modifiers);
+        }
+        modifiers
+    }
+
+    /// Dream を支払って勅令を発令する
+    pub fn enact(
+        &mut self,
+        kind: EdictKind,
+        now: f32,
+        dream_pool: &mut DreamPool,
+    ) -> Result<(), EdictEnactError> {
+        if self.is_active(kind) {
+            return Err(EdictEnactError::AlreadyActive);
+        }
+        let cost = kind.cost();
+        if dream_pool.points < cost {
+            return Err(EdictEnactError::InsufficientDream {
+                cost,
+                available: dream_pool.points,
+            });
+        }
+        dream_pool.points -= cost;
+        self.active.push(ActiveEdict {
+            kind,
+            expires_at: now + kind.duration_secs(),
+        });
+        Ok(())
+    }
+
+    /// 期限前に勅令を取り消す。支払った Dream は返らない
+    pub fn revoke(&mut self, kind: EdictKind) -> bool {
+        let before = self.active.len();
+        self.active.retain(|edict| edict.kind != kind);
+        self.active.len() != before
+    }
+
+    /// 期限切れの勅令を取り除き、その種類を返す
+    pub fn expire(&mut self, now: f32) -> Vec<EdictKind> {
+        let mut expired = Vec::new();
+        self.active.retain(|edict| {
+            let keep = edict.expires_at > now;
+            if !keep {
+                expired.push(edict.kind);
+            }
+            keep
+        });
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enact_spends_dream_and_rejects_duplicates_or_shortfall() {
+        let mut edicts = DreamEdicts::default();
+        let mut pool = DreamPool { points: 50.0 };
+
+        assert_eq!(
+            edicts.enact(EdictKind::MandatorySiesta, 0.0, &mut pool),
+            Ok(())
+        );
+        assert_eq!(pool.points, 20.0);
+        assert_eq!(
+            edicts.enact(EdictKind::MandatorySiesta, 0.0, &mut pool),
+            Err(EdictEnactError::AlreadyActive)
+        );
+        assert_eq!(
+            edicts.enact(EdictKind::OpenGates, 0.0, &mut pool),
+            Err(EdictEnactError::InsufficientDream {
+                cost: 60.0,
+                available: 20.0,
+            })
+        );
+        assert_eq!(pool.points, 20.0);
+    }
+
+    #[test]
+    fn modifiers_combine_active_edicts_and_reset_after_expiry() {
+        let mut edicts = DreamEdicts::default();
+        let mut pool = DreamPool { points: 200.0 };
+        edicts
+            .enact(EdictKind::MandatorySiesta, 0.0, &mut pool)
+            .unwrap();
+        edicts.enact(EdictKind::OpenGates, 0.0, &mut pool).unwrap();
+
+        let modifiers = edicts.modifiers();
+        assert_eq!(
+            modifiers.fatigue_recovery_multiplier,
+            SIESTA_FATIGUE_RECOVERY_MULTIPLIER
+        );
+        assert_eq!(
+            modifiers.work_speed_multiplier,
+            SIESTA_WORK_SPEED_MULTIPLIER
+        );
+        assert_eq!(modifiers.spawn_count_bonus, OPEN_GATES_SPAWN_COUNT_BONUS);
+
+        let expired = edicts.expire(EdictKind::MandatorySiesta.duration_secs());
+        assert_eq!(expired, vec![EdictKind::MandatorySiesta]);
+        assert_eq!(edicts.modifiers().work_speed_multiplier, 1.0);
+        assert!(edicts.is_active(EdictKind::OpenGates));
+    }
+}
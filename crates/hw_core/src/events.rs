@@ -30,6 +30,17 @@ pub struct DreamTransferredVisualMessage {
     pub is_final: bool,
 }
 
+/// `DamnedSoul.dream` が `DreamPool` へ加算された際の domain 通知。
+///
+/// slow simulation の 1 回の実行で Pool に加算した量を Soul ごとに合算して発行する。
+/// `is_final` はその Soul の放出が終わったと producer が確定できた場合だけ立てる。
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct OnDreamContributed {
+    pub soul: Entity,
+    pub amount: f32,
+    pub is_final: bool,
+}
+
 /// 魂が使い魔に勧誘（使役開始）された際の domain 通知。
 #[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OnSoulRecruited {
@@ -409,6 +420,15 @@ pub struct SoulEscaped {
     pub grid: GridPos,
 }
 
+/// Soul が Redemption Shrine で贖罪を終えたシグナル (execute/redemption → root adapter へのブリッジ)
+/// Soul の despawn より先に発火する。root adapter が `PopulationManager::total_redeemed` と
+/// DreamPool の報酬を反映し、通知を出す。
+#[derive(Event, Debug)]
+pub struct SoulRedeemed {
+    pub entity: Entity,
+    pub shrine: Entity,
+}
+
 /// 魂のタスク解除要求（Familiar AI → Soul AI Pub/Sub ブリッジ）
 ///
 /// `hw_familiar_ai` が送信し、`hw_soul_ai` の Perceive フェーズで処理される。
@@ -56,6 +56,15 @@ pub enum FamiliarType {
     Imp,
 }
 
+impl FamiliarType {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Imp => "ui.familiar_type.imp",
+        }
+    }
+}
+
 /// 使い魔への指示
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
 pub enum FamiliarCommand {
@@ -20,6 +20,18 @@ pub enum PlayMode {
 pub enum TaskModeZoneType {
     Stockpile,
     Yard,
+    Forbidden,
+}
+
+impl TaskModeZoneType {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Stockpile => "ui.zone.stockpile",
+            Self::Yard => "ui.zone.yard",
+            Self::Forbidden => "ui.zone.forbidden",
+        }
+    }
 }
 
 #[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
@@ -40,6 +52,10 @@ pub enum TaskMode {
     DreamPlanting(Option<Vec2>),
     StockpilePolicyEdit(Option<Vec2>),
     SoulSpaPlace(Option<Vec2>),
+    /// 範囲をドラッグして建築レイアウトを設計図ファイルへ書き出す
+    LayoutExport(Option<Vec2>),
+    /// 読み込んだ設計図をカーソル位置へ配置する
+    LayoutImport,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
@@ -20,3 +20,46 @@ pub enum WorkType {
     CoatWall,
     GeneratePower,
 }
+
+impl WorkType {
+    pub const ALL: [Self; 16] = [
+        Self::Chop,
+        Self::Mine,
+        Self::Build,
+        Self::Move,
+        Self::Haul,
+        Self::HaulToMixer,
+        Self::GatherWater,
+        Self::CollectBone,
+        Self::Refine,
+        Self::HaulWaterToMixer,
+        Self::WheelbarrowHaul,
+        Self::ReinforceFloorTile,
+        Self::PourFloorTile,
+        Self::FrameWallTile,
+        Self::CoatWall,
+        Self::GeneratePower,
+    ];
+
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Chop => "ui.work_type.chop",
+            Self::Mine => "ui.work_type.mine",
+            Self::Build => "ui.work_type.build",
+            Self::Move => "ui.work_type.move",
+            Self::Haul => "ui.work_type.haul",
+            Self::HaulToMixer => "ui.work_type.haul_to_mixer",
+            Self::GatherWater => "ui.work_type.gather_water",
+            Self::CollectBone => "ui.work_type.collect_bone",
+            Self::Refine => "ui.work_type.refine",
+            Self::HaulWaterToMixer => "ui.work_type.haul_water_to_mixer",
+            Self::WheelbarrowHaul => "ui.work_type.wheelbarrow_haul",
+            Self::ReinforceFloorTile => "ui.work_type.reinforce_floor_tile",
+            Self::PourFloorTile => "ui.work_type.pour_floor_tile",
+            Self::FrameWallTile => "ui.work_type.frame_wall_tile",
+            Self::CoatWall => "ui.work_type.coat_wall",
+            Self::GeneratePower => "ui.work_type.generate_power",
+        }
+    }
+}
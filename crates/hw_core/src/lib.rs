@@ -1,21 +1,28 @@
+pub mod alerts;
 pub mod area;
 pub mod camera;
 pub mod constants;
 pub mod ecs;
+pub mod edicts;
 pub mod events;
 pub mod familiar;
 pub mod game_state;
 pub mod gathering;
 pub mod jobs;
+pub mod life_log;
+pub mod locale;
 pub mod logistics;
 pub mod population;
 pub mod quality;
+pub mod redemption;
 pub mod relationships;
 pub mod selection;
 pub mod settings;
 #[cfg(any(feature = "profiling", test))]
 pub mod simulation_rng;
+pub mod social;
 pub mod soul;
+pub mod statistics;
 pub mod system_sets;
 pub mod time;
 pub mod ui_nodes;
@@ -24,7 +31,8 @@ pub mod visual_mirror;
 pub mod world;
 pub mod world_epoch;
 
-pub use settings::GameSettings;
+pub use locale::{Locale, Localization};
+pub use settings::{GameSettings, KeyBindingOverride};
 pub use time::GameTime;
 pub use world::GridPos;
 pub use world_epoch::{EpochLocal, WorldEpoch};
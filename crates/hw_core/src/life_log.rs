@@ -0,0 +1,104 @@
+//! Soul ごとの生涯ログ
+//!
+//! 勧誘・解放・タスク完了/中断・疲労限界・ストレス崩壊・休憩・Dream 放出・集会参加を
+//! `GameTime` の時刻付きで記録する有界リング。古いエントリから捨てる。
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::jobs::WorkType;
+use crate::time::GameTime;
+
+/// 1 Soul が保持するログの上限件数
+pub const SOUL_LIFE_LOG_CAPACITY: usize = 32;
+
+/// ログに記録する出来事
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum SoulLifeEventKind {
+    Recruited,
+    Released,
+    TaskCompleted(WorkType),
+    TaskAbandoned,
+    Exhausted,
+    StressBreakdown,
+    RestStarted,
+    DreamReleased { amount: f32 },
+    JoinedGathering,
+}
+
+/// 時刻付きのログ1件
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SoulLifeLogEntry {
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub kind: SoulLifeEventKind,
+}
+
+impl SoulLifeLogEntry {
+    pub fn at(time: &GameTime, kind: SoulLifeEventKind) -> Self {
+        Self {
+            day: time.day,
+            hour: time.hour,
+            minute: time.minute,
+            kind,
+        }
+    }
+}
+
+/// Soul の生涯ログ（古い順）
+///
+/// `DamnedSoul` の required component として自動付与される。
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct SoulLifeLog {
+    entries: VecDeque<SoulLifeLogEntry>,
+}
+
+impl SoulLifeLog {
+    pub fn push(&mut self, entry: SoulLifeLogEntry) {
+        if self.entries.len() >= SOUL_LIFE_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn record(&mut self, time: &GameTime, kind: SoulLifeEventKind) {
+        self.push(SoulLifeLogEntry::at(time, kind));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 新しい順に走査する
+    pub fn iter_newest_first(&self) -> impl Iterator<Item = &SoulLifeLogEntry> {
+        self.entries.iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_is_bounded_and_drops_oldest() {
+        let mut log = SoulLifeLog::default();
+        let mut time = GameTime::default();
+        for minute in 0..(SOUL_LIFE_LOG_CAPACITY as u32 + 3) {
+            time.minute = minute;
+            log.record(&time, SoulLifeEventKind::JoinedGathering);
+        }
+
+        assert_eq!(log.len(), SOUL_LIFE_LOG_CAPACITY);
+        let newest = log.iter_newest_first().next().unwrap();
+        assert_eq!(newest.minute, SOUL_LIFE_LOG_CAPACITY as u32 + 2);
+        let oldest = log.iter_newest_first().last().unwrap();
+        assert_eq!(oldest.minute, 3);
+    }
+}
@@ -0,0 +1,278 @@
+//! UI 文言のローカライズ
+//!
+//! 文言は `assets/locales/<code>.ron` の「ID → 文字列」テーブルで管理し、ビルド時に埋め込む。
+//! 表示側は ID を持ち、現在のロケールで [`Localization::text`] / [`Localization::format`] を
+//! 引いて文字列にする。日本語テーブルが原文で、他ロケールに無い ID は日本語へフォールバックする。
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use bevy::prelude::*;
+
+/// 出荷するロケール
+#[derive(Reflect, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Locale {
+    #[default]
+    Ja,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [Self::Ja, Self::En];
+    /// 原文ロケール（欠けた ID のフォールバック先）
+    pub const SOURCE: Self = Self::Ja;
+
+    /// 設定ファイルとテーブルファイル名に使うコード
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Ja => "ja",
+            Self::En => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    /// 言語選択に表示する自言語での名前（ロケールに依らず固定）
+    pub const fn native_name(self) -> &'static str {
+        match self {
+            Self::Ja => "日本語",
+            Self::En => "English",
+        }
+    }
+
+    const fn source(self) -> &'static str {
+        match self {
+            Self::Ja => include_str!("../../../assets/locales/ja.ron"),
+            Self::En => include_str!("../../../assets/locales/en.ron"),
+        }
+    }
+}
+
+/// 1 ロケール分の「ID → 文字列」テーブル
+pub type LocaleTable = BTreeMap<String, String>;
+
+/// RON テーブルを読み込む。
+pub fn parse_locale_table(source: &str) -> Result<LocaleTable, ron::error::SpannedError> {
+    ron::from_str(source)
+}
+
+/// 現在のロケールと出荷テーブル一式
+#[derive(Resource, Debug, Clone)]
+pub struct Localization {
+    locale: Locale,
+    tables: BTreeMap<Locale, LocaleTable>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::shipped(Locale::default())
+    }
+}
+
+impl Localization {
+    /// 埋め込みテーブルを読み込む。壊れたテーブルは空として扱い、文言は ID のまま表示される
+    /// （テーブルの妥当性は `locale` のテストで保証する）。
+    pub fn shipped(locale: Locale) -> Self {
+        let tables = Locale::ALL
+            .into_iter()
+            .map(|candidate| {
+                let table = parse_locale_table(candidate.source()).unwrap_or_else(|error| {
+                    warn!("Failed to parse locale table {}: {error}", candidate.code());
+                    LocaleTable::new()
+                });
+                (candidate, table)
+            })
+            .collect();
+        Self { locale, tables }
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    pub fn table(&self, locale: Locale) -> Option<&LocaleTable> {
+        self.tables.get(&locale)
+    }
+
+    /// 現在のロケールだけを引く（フォールバックしない）。
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.table(self.locale)
+            .and_then(|table| table.get(id))
+            .map(String::as_str)
+    }
+
+    /// 現在のロケール → 原文ロケール → ID の順で文言を引く。
+    pub fn text<'a>(&'a self, id: &'a str) -> &'a str {
+        self.get(id)
+            .or_else(|| {
+                self.table(Locale::SOURCE)
+                    .and_then(|table| table.get(id))
+                    .map(String::as_str)
+            })
+            .unwrap_or(id)
+    }
+
+    /// `{name}` 形式のプレースホルダを `args` で置き換える。
+    pub fn format(&self, id: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = Cow::Borrowed(self.text(id));
+        for (name, value) in args {
+            let placeholder = format!("{{{name}}}");
+            if text.contains(&placeholder) {
+                text = Cow::Owned(text.replace(&placeholder, &value.to_string()));
+            }
+        }
+        text.into_owned()
+    }
+}
+
+/// 文言中の `{name}` プレースホルダ名を集める。
+pub fn placeholders(text: &str) -> BTreeSet<&str> {
+    let mut names = BTreeSet::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        names.insert(&after[..end]);
+        rest = &after[end + 1..];
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ソース中で ID として扱う文字列リテラルの名前空間
+    const ID_NAMESPACES: [&str; 6] = ["ui", "help", "notify", "resource", "building", "alert"];
+
+    fn looks_like_id(literal: &str) -> bool {
+        let Some((namespace, rest)) = literal.split_once('.') else {
+            return false;
+        };
+        ID_NAMESPACES.contains(&namespace)
+            && !rest.is_empty()
+            && rest.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.')
+            })
+            && !rest.ends_with(".rs")
+    }
+
+    fn string_literals(line: &str) -> impl Iterator<Item = &str> {
+        line.split('"').skip(1).step_by(2)
+    }
+
+    fn rust_sources(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                rust_sources(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn shipped_tables_share_ids_placeholders_and_line_counts() {
+        let localization = Localization::default();
+        let source = localization.table(Locale::SOURCE).unwrap();
+        assert!(!source.is_empty());
+        for locale in Locale::ALL {
+            parse_locale_table(locale.source())
+                .unwrap_or_else(|error| panic!("{} table must parse: {error}", locale.code()));
+            let table = localization.table(locale).unwrap();
+            let source_ids: BTreeSet<_> = source.keys().collect();
+            let ids: BTreeSet<_> = table.keys().collect();
+            assert_eq!(
+                source_ids.symmetric_difference(&ids).collect::<Vec<_>>(),
+                Vec::<&&String>::new(),
+                "{} table ids differ from {}",
+                locale.code(),
+                Locale::SOURCE.code()
+            );
+            for (id, text) in table {
+                let original = &source[id];
+                assert!(!text.trim().is_empty(), "{}: {id} is empty", locale.code());
+                assert_eq!(
+                    placeholders(text),
+                    placeholders(original),
+                    "{}: {id} placeholders differ",
+                    locale.code()
+                );
+                assert_eq!(
+                    text.lines().count(),
+                    original.lines().count(),
+                    "{}: {id} paragraph count differs",
+                    locale.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_id_used_in_source_exists_in_every_locale() {
+        let crates = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut files = Vec::new();
+        rust_sources(&crates, &mut files);
+        assert!(!files.is_empty());
+
+        let localization = Localization::default();
+        let mut missing = Vec::new();
+        for file in files {
+            let Ok(contents) = std::fs::read_to_string(&file) else {
+                continue;
+            };
+            for literal in contents.lines().flat_map(string_literals) {
+                if !looks_like_id(literal) {
+                    continue;
+                }
+                for locale in Locale::ALL {
+                    if !localization.table(locale).unwrap().contains_key(literal) {
+                        missing.push(format!("{} ({}): {literal}", file.display(), locale.code()));
+                    }
+                }
+            }
+        }
+        assert!(
+            missing.is_empty(),
+            "missing locale ids:\n{}",
+            missing.join("\n")
+        );
+    }
+
+    #[test]
+    fn lookups_fall_back_to_the_source_locale_and_fill_placeholders() {
+        let mut localization = Localization::shipped(Locale::En);
+        localization
+            .tables
+            .get_mut(&Locale::Ja)
+            .unwrap()
+            .insert("test.only_ja".into(), "{count} 個".into());
+
+        assert_eq!(localization.text("test.only_ja"), "{count} 個");
+        assert_eq!(localization.get("test.only_ja"), None);
+        assert_eq!(localization.text("test.missing"), "test.missing");
+        assert_eq!(
+            localization.format("test.only_ja", &[("count", &3)]),
+            "3 個"
+        );
+        assert_eq!(
+            localization.format("resource.wood", &[]),
+            localization.table(Locale::En).unwrap()["resource.wood"]
+        );
+        assert_eq!(Locale::from_code("en"), Some(Locale::En));
+        assert_eq!(Locale::from_code("fr"), None);
+    }
+}
@@ -14,17 +14,30 @@ pub enum ResourceType {
 }
 
 impl ResourceType {
-    pub const fn display_name(self) -> &'static str {
+    pub const ALL: [Self; 9] = [
+        Self::Wood,
+        Self::Rock,
+        Self::Water,
+        Self::BucketEmpty,
+        Self::BucketWater,
+        Self::Sand,
+        Self::Bone,
+        Self::StasisMud,
+        Self::Wheelbarrow,
+    ];
+
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
         match self {
-            Self::Wood => "Wood",
-            Self::Rock => "Rock",
-            Self::Water => "Water",
-            Self::BucketEmpty => "Empty Bucket",
-            Self::BucketWater => "Water Bucket",
-            Self::Sand => "Sand",
-            Self::Bone => "Bone",
-            Self::StasisMud => "Stasis Mud",
-            Self::Wheelbarrow => "Wheelbarrow",
+            Self::Wood => "resource.wood",
+            Self::Rock => "resource.rock",
+            Self::Water => "resource.water",
+            Self::BucketEmpty => "resource.bucket_empty",
+            Self::BucketWater => "resource.bucket_water",
+            Self::Sand => "resource.sand",
+            Self::Bone => "resource.bone",
+            Self::StasisMud => "resource.stasis_mud",
+            Self::Wheelbarrow => "resource.wheelbarrow",
         }
     }
 
@@ -1,6 +1,6 @@
 //! Soul 人口管理リソース。
 //!
-//! スポーン・脱走クールダウンの状態を保持する。
+//! スポーン・脱走クールダウンの状態と、脱走・贖罪の累計を保持する。
 //! `bevy_app` 側の spawn システムが更新し、AI 意思決定システムが参照する。
 
 use crate::constants::{
@@ -16,6 +16,9 @@ pub struct PopulationManager {
     pub population_cap: u32,
     pub total_spawned: u32,
     pub total_escaped: u32,
+    /// 贖罪によって去った Soul の累計
+    #[reflect(default)]
+    pub total_redeemed: u32,
     pub escape_cooldown_remaining: f32,
     pub spawn_timer: Timer,
 }
@@ -27,6 +30,7 @@ impl Default for PopulationManager {
             population_cap: SOUL_POPULATION_BASE_CAP,
             total_spawned: 0,
             total_escaped: 0,
+            total_redeemed: 0,
             escape_cooldown_remaining: 0.0,
             spawn_timer: Timer::from_seconds(SOUL_SPAWN_INTERVAL, TimerMode::Repeating),
         }
@@ -0,0 +1,115 @@
+//! Soul の贖罪（Redemption）
+//!
+//! 作業の完了と Dream の放出で各 Soul に贖罪ポイント（penance）が貯まる。
+//! 閾値に達した Soul は Redemption Shrine へ巡礼し、儀式を終えるとワールドから去る。
+//! 贖罪は恒久的なボーナス（DreamPool と人口上限）をもたらす。
+
+use bevy::prelude::*;
+
+use crate::constants::TILE_SIZE;
+
+// ============================================================
+// 定数
+// ============================================================
+
+/// 贖罪可能になる penance の閾値
+pub const REDEMPTION_PENANCE_THRESHOLD: f32 = 100.0;
+/// タスク完了1回あたりの penance
+pub const PENANCE_PER_TASK_COMPLETED: f32 = 2.0;
+/// Dream を 1 ポイント放出するごとの penance
+pub const PENANCE_PER_DREAM_RELEASED: f32 = 0.25;
+
+/// 巡礼開始を判定する間隔（秒）
+pub const REDEMPTION_CHECK_INTERVAL: f32 = 5.0;
+/// Shrine に到着したとみなす距離
+pub const REDEMPTION_ARRIVAL_RADIUS: f32 = TILE_SIZE * 0.75;
+/// Shrine で儀式を行う時間（秒）
+pub const REDEMPTION_RITE_DURATION: f32 = 4.0;
+/// 到着できないまま巡礼を諦めるまでの時間（秒）
+pub const REDEMPTION_PILGRIMAGE_TIMEOUT: f32 = 90.0;
+/// 巡礼を諦めた Soul が次の巡礼を始められるようになるまでの時間（秒）
+pub const REDEMPTION_RETRY_COOLDOWN: f32 = 180.0;
+
+/// 贖罪1件ごとに DreamPool へ加算される報酬
+pub const REDEMPTION_DREAM_REWARD: f32 = 30.0;
+/// 贖罪1件ごとに恒久的に増える人口上限
+pub const SOUL_POPULATION_PER_REDEMPTION: u32 = 1;
+
+// ============================================================
+// コンポーネント
+// ============================================================
+
+/// Soul ごとの贖罪ポイント
+///
+/// `DamnedSoul` の required component として自動付与される。
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default)]
+pub struct SoulPenance {
+    pub score: f32,
+}
+
+impl SoulPenance {
+    pub fn add(&mut self, amount: f32) {
+        if amount > 0.0 {
+            self.score += amount;
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.score >= REDEMPTION_PENANCE_THRESHOLD
+    }
+
+    /// 閾値に対する進捗（0.0〜1.0）
+    pub fn progress(&self) -> f32 {
+        (self.score / REDEMPTION_PENANCE_THRESHOLD).clamp(0.0, 1.0)
+    }
+}
+
+/// Redemption Shrine へ巡礼中の Soul に付与される
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct RedemptionPilgrimage {
+    #[entities]
+    pub shrine: Entity,
+    /// 巡礼開始からの経過時間
+    pub elapsed: f32,
+    /// Shrine 到着後の儀式の経過時間
+    pub rite_timer: f32,
+}
+
+impl RedemptionPilgrimage {
+    pub fn new(shrine: Entity) -> Self {
+        Self {
+            shrine,
+            elapsed: 0.0,
+            rite_timer: 0.0,
+        }
+    }
+}
+
+/// 巡礼を諦めた Soul に付与され、残り時間が尽きるまで新しい巡礼を始めさせない
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default)]
+pub struct RedemptionCooldown {
+    pub remaining_secs: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn penance_reaches_threshold_and_clamps_progress() {
+        let mut penance = SoulPenance::default();
+        penance.add(-5.0);
+        assert_eq!(penance.score, 0.0);
+
+        penance.add(REDEMPTION_PENANCE_THRESHOLD * 0.5);
+        assert!(!penance.is_ready());
+        assert_eq!(penance.progress(), 0.5);
+
+        penance.add(REDEMPTION_PENANCE_THRESHOLD);
+        assert!(penance.is_ready());
+        assert_eq!(penance.progress(), 1.0);
+    }
+}
@@ -1,9 +1,122 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 /// 現在選択中のエンティティ
 #[derive(Resource, Default)]
 pub struct SelectedEntity(pub Option<Entity>);
 
+/// 複数選択中のエンティティ集合（選択順）
+///
+/// `SelectedEntity` は常にこの集合の主選択（最後に加えたもの）を指す。
+/// 単一選択の間は要素数 1、未選択なら空。
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SelectedEntities(Vec<Entity>);
+
+impl SelectedEntities {
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 2 体以上を選択しているか
+    pub fn is_multi(&self) -> bool {
+        self.0.len() > 1
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+
+    /// 主選択（最後に加えたエンティティ）
+    pub fn primary(&self) -> Option<Entity> {
+        self.0.last().copied()
+    }
+
+    /// 集合を `entity` 1 体（または空）に置き換える。
+    pub fn set_single(&mut self, entity: Option<Entity>) {
+        self.0.clear();
+        self.0.extend(entity);
+    }
+
+    /// 未選択なら加え、選択済みなら外す。加えた場合は `true`。
+    pub fn toggle(&mut self, entity: Entity) -> bool {
+        if let Some(index) = self.0.iter().position(|&selected| selected == entity) {
+            self.0.remove(index);
+            false
+        } else {
+            self.0.push(entity);
+            true
+        }
+    }
+
+    /// 重複を除いて末尾に加える。
+    pub fn extend(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        for entity in entities {
+            if !self.0.contains(&entity) {
+                self.0.push(entity);
+            }
+        }
+    }
+
+    pub fn retain(&mut self, keep: impl FnMut(&Entity) -> bool) {
+        self.0.retain(keep);
+    }
+}
+
+/// 選択を書き換える窓口
+///
+/// `SelectedEntity` と `SelectedEntities` を必ず一緒に更新し、主選択と複数選択の集合を
+/// ずらさない。選択を変えるシステムはどちらの resource も直接書き換えず、これを使う。
+#[derive(SystemParam)]
+pub struct SelectionWriter<'w> {
+    selected_entity: ResMut<'w, SelectedEntity>,
+    selected_entities: ResMut<'w, SelectedEntities>,
+}
+
+impl SelectionWriter<'_> {
+    /// 主選択
+    pub fn primary(&self) -> Option<Entity> {
+        self.selected_entity.0
+    }
+
+    pub fn entities(&self) -> &SelectedEntities {
+        &self.selected_entities
+    }
+
+    /// `entity` 1 体だけを選択する。`None` なら選択を解除する。
+    pub fn select(&mut self, entity: Option<Entity>) {
+        self.selected_entity.0 = entity;
+        self.selected_entities.set_single(entity);
+    }
+
+    /// 複数選択に `entity` を出し入れする。集合が空なら現在の主選択から始める。
+    pub fn toggle(&mut self, entity: Entity) {
+        self.seed_from_primary();
+        self.selected_entities.toggle(entity);
+        self.selected_entity.0 = self.selected_entities.primary();
+    }
+
+    /// 複数選択に `entities` を加える。集合が空なら現在の主選択から始める。
+    pub fn extend(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        self.seed_from_primary();
+        self.selected_entities.extend(entities);
+        self.selected_entity.0 = self.selected_entities.primary();
+    }
+
+    fn seed_from_primary(&mut self) {
+        if self.selected_entities.is_empty() {
+            self.selected_entities.set_single(self.selected_entity.0);
+        }
+    }
+}
+
 /// 現在ホバー中のエンティティ
 #[derive(Resource, Default)]
 pub struct HoveredEntity(pub Option<Entity>);
@@ -11,3 +124,84 @@ pub struct HoveredEntity(pub Option<Entity>);
 /// 選択ハイライト表示エンティティのマーカー
 #[derive(Component)]
 pub struct SelectionIndicator;
+
+/// 範囲選択ドラッグ中の矩形（ワールド座標）
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+pub struct SelectionBoxDrag {
+    pub origin: Option<Vec2>,
+    pub current: Vec2,
+}
+
+impl SelectionBoxDrag {
+    /// ドラッグ中なら正規化済みの `(min, max)` を返す。
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.origin
+            .map(|origin| (origin.min(self.current), origin.max(self.current)))
+    }
+}
+
+/// 範囲選択ドラッグの矩形表示マーカー
+#[derive(Component)]
+pub struct SelectionBoxVisual;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn toggling_and_extending_keeps_selection_order_without_duplicates() {
+        let a = Entity::from_raw_u32(1).unwrap();
+        let b = Entity::from_raw_u32(2).unwrap();
+        let c = Entity::from_raw_u32(3).unwrap();
+        let mut selection = SelectedEntities::default();
+
+        selection.set_single(Some(a));
+        assert!(selection.toggle(b));
+        selection.extend([a, c, b]);
+        assert_eq!(selection.iter().collect::<Vec<_>>(), vec![a, b, c]);
+        assert_eq!(selection.primary(), Some(c));
+
+        assert!(!selection.toggle(c));
+        assert_eq!(selection.primary(), Some(b));
+        assert!(selection.is_multi());
+
+        selection.set_single(None);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn selection_writer_keeps_primary_and_set_in_step() {
+        let a = Entity::from_raw_u32(1).unwrap();
+        let b = Entity::from_raw_u32(2).unwrap();
+        let mut world = World::new();
+        world.init_resource::<SelectedEntity>();
+        world.init_resource::<SelectedEntities>();
+        world.resource_mut::<SelectedEntities>().extend([a, b]);
+        world.resource_mut::<SelectedEntity>().0 = Some(b);
+
+        world
+            .run_system_once(move |mut selection: SelectionWriter| selection.select(Some(a)))
+            .unwrap();
+        assert_eq!(world.resource::<SelectedEntity>().0, Some(a));
+        assert_eq!(
+            world
+                .resource::<SelectedEntities>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![a]
+        );
+
+        world
+            .run_system_once(move |mut selection: SelectionWriter| selection.toggle(b))
+            .unwrap();
+        assert_eq!(world.resource::<SelectedEntity>().0, Some(b));
+        assert!(world.resource::<SelectedEntities>().is_multi());
+
+        world
+            .run_system_once(|mut selection: SelectionWriter| selection.select(None))
+            .unwrap();
+        assert_eq!(world.resource::<SelectedEntity>().0, None);
+        assert!(world.resource::<SelectedEntities>().is_empty());
+    }
+}
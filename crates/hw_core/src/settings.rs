@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::game_state::TimeSpeed;
+use crate::locale::Locale;
 
 /// 永続化対象のゲーム設定（型定義のみ。ロード/保存は bevy_app）
 #[derive(Resource, Reflect, Debug, Clone, PartialEq)]
@@ -18,6 +19,25 @@ pub struct GameSettings {
     pub debug_gizmos_enabled: bool,
     /// DevPanel 内 FPS テキスト表示
     pub fps_display_enabled: bool,
+    /// UI 文言のロケール（Localization へ反映）
+    pub locale: Locale,
+    /// 既定キーバインドに対するプレイヤーの上書き（空なら既定のまま）
+    pub key_binding_overrides: Vec<KeyBindingOverride>,
+}
+
+/// 1 つの既定バインドを別のキーへ差し替える上書き。
+///
+/// 識別子とキー表記は bevy_app の入力テーブルが解釈する（hw_core は文字列として保持するだけ）。
+#[derive(Reflect, Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindingOverride {
+    /// `InputAction` の安定 ID（例: `toggle-architect`）
+    pub action: String,
+    /// `InputBindingContext` の安定 ID（例: `world-normal`）
+    pub context: String,
+    /// 差し替え元となる既定キー（例: `B`）
+    pub default_chord: String,
+    /// 割り当てるキー（例: `Ctrl+B`）
+    pub chord: String,
 }
 
 impl Default for GameSettings {
@@ -29,6 +49,8 @@ impl Default for GameSettings {
             default_time_speed: TimeSpeed::Normal,
             debug_gizmos_enabled: false,
             fps_display_enabled: true,
+            locale: Locale::default(),
+            key_binding_overrides: Vec::new(),
         }
     }
 }
@@ -0,0 +1,250 @@
+//! Soul 同士の関係性（友好度グラフ）
+//!
+//! 各 Soul が相手ごとの友好度 (`affinity`, -1.0〜1.0) を保持する有向グラフ。
+//! 集会・同一分隊で上昇し、ストレスを撒き散らす近隣 Soul に対して下降する。
+//! 一定値を超えた相手を友人、下回った相手をライバルとして扱う。
+
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::prelude::*;
+
+use crate::constants::TILE_SIZE;
+
+// ============================================================
+// 定数
+// ============================================================
+
+/// 1 Soul が保持する関係の上限。超過時は |affinity| が最小のものから捨てる
+pub const SOUL_RELATIONSHIP_CAPACITY: usize = 12;
+/// 友人とみなす友好度の下限
+pub const SOCIAL_FRIEND_THRESHOLD: f32 = 0.5;
+/// ライバルとみなす友好度の上限
+pub const SOCIAL_RIVAL_THRESHOLD: f32 = -0.5;
+
+/// 同じ集会に参加したときの友好度上昇（参加1回あたり）
+pub const SOCIAL_AFFINITY_SHARED_GATHERING: f32 = 0.08;
+/// 同じ使い魔の分隊で近くにいるときの友好度上昇 (/秒)
+pub const SOCIAL_AFFINITY_SQUAD_RATE: f32 = 0.004;
+/// 高ストレスの近隣 Soul に対する友好度低下 (/秒)
+pub const SOCIAL_AFFINITY_STRESS_DECAY_RATE: f32 = 0.006;
+/// 近隣 Soul を「ストレスを撒いている」とみなすストレス閾値
+pub const SOCIAL_STRESS_SPREAD_THRESHOLD: f32 = 0.7;
+
+/// 友好度・ストレス相互作用を判定する近傍半径
+pub const SOCIAL_INFLUENCE_RADIUS: f32 = TILE_SIZE * 3.0;
+/// 作業中に近くの友人1人あたりのストレス軽減 (/秒)
+pub const SOCIAL_FRIEND_STRESS_RELIEF_RATE: f32 = 0.002;
+/// 作業中に近くのライバル1人あたりのストレス増加 (/秒)
+pub const SOCIAL_RIVAL_STRESS_RATE: f32 = 0.003;
+/// 集会先選択時、参加中の友人1人あたりの距離割引率
+pub const SOCIAL_GATHERING_FRIEND_PULL: f32 = 0.5;
+
+/// 情報パネルに表示する友人/ライバルの最大人数
+pub const SOCIAL_PANEL_MAX_ENTRIES: usize = 3;
+
+// ============================================================
+// コンポーネント
+// ============================================================
+
+/// 特定の相手に対する友好度
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SoulBond {
+    pub other: Entity,
+    pub affinity: f32,
+}
+
+impl MapEntities for SoulBond {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        self.other = entity_mapper.get_mapped(self.other);
+    }
+}
+
+/// 関係の分類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocialStanding {
+    Friend,
+    Neutral,
+    Rival,
+}
+
+impl SocialStanding {
+    pub fn from_affinity(affinity: f32) -> Self {
+        if affinity >= SOCIAL_FRIEND_THRESHOLD {
+            Self::Friend
+        } else if affinity <= SOCIAL_RIVAL_THRESHOLD {
+            Self::Rival
+        } else {
+            Self::Neutral
+        }
+    }
+}
+
+/// Soul ごとの関係グラフ（自分 → 相手の有向エッジ）
+///
+/// `DamnedSoul` の required component として自動付与される。
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct SoulRelationships {
+    #[entities]
+    bonds: Vec<SoulBond>,
+}
+
+impl SoulRelationships {
+    pub fn bonds(&self) -> &[SoulBond] {
+        &self.bonds
+    }
+
+    pub fn affinity(&self, other: Entity) -> f32 {
+        self.bonds
+            .iter()
+            .find(|bond| bond.other == other)
+            .map_or(0.0, |bond| bond.affinity)
+    }
+
+    pub fn standing(&self, other: Entity) -> SocialStanding {
+        SocialStanding::from_affinity(self.affinity(other))
+    }
+
+    pub fn is_friend(&self, other: Entity) -> bool {
+        self.standing(other) == SocialStanding::Friend
+    }
+
+    /// 友好度を `delta` だけ変化させる（-1.0〜1.0 にクランプ）。
+    ///
+    /// 未知の相手は新規エッジとして追加する。容量が埋まっている場合は、新しい関係より
+    /// 弱い既存の関係があるときだけそれを捨てて置き換え、無ければ新しい関係を記録しない。
+    pub fn adjust(&mut self, other: Entity, delta: f32) {
+        if delta == 0.0 {
+            return;
+        }
+        if let Some(bond) = self.bonds.iter_mut().find(|bond| bond.other == other) {
+            bond.affinity = (bond.affinity + delta).clamp(-1.0, 1.0);
+            return;
+        }
+
+        let incoming = SoulBond {
+            other,
+            affinity: delta.clamp(-1.0, 1.0),
+        };
+        if self.bonds.len() < SOUL_RELATIONSHIP_CAPACITY {
+            self.bonds.push(incoming);
+            return;
+        }
+        if let Some(weakest) = self
+            .bonds
+            .iter_mut()
+            .min_by(|a, b| a.affinity.abs().total_cmp(&b.affinity.abs()))
+            .filter(|weakest| weakest.affinity.abs() < incoming.affinity.abs())
+        {
+            *weakest = incoming;
+        }
+    }
+
+    pub fn forget(&mut self, other: Entity) {
+        self.bonds.retain(|bond| bond.other != other);
+    }
+
+    /// 友好度の高い順に友人を最大 `limit` 件返す
+    pub fn top_friends(&self, limit: usize) -> Vec<SoulBond> {
+        self.top_by(limit, SocialStanding::Friend, |a, b| {
+            b.affinity.total_cmp(&a.affinity)
+        })
+    }
+
+    /// 友好度の低い順にライバルを最大 `limit` 件返す
+    pub fn top_rivals(&self, limit: usize) -> Vec<SoulBond> {
+        self.top_by(limit, SocialStanding::Rival, |a, b| {
+            a.affinity.total_cmp(&b.affinity)
+        })
+    }
+
+    fn top_by(
+        &self,
+        limit: usize,
+        standing: SocialStanding,
+        compare: impl Fn(&SoulBond, &SoulBond) -> std::cmp::Ordering,
+    ) -> Vec<SoulBond> {
+        let mut bonds: Vec<SoulBond> = self
+            .bonds
+            .iter()
+            .copied()
+            .filter(|bond| SocialStanding::from_affinity(bond.affinity) == standing)
+            .collect();
+        bonds.sort_by(|a, b| compare(a, b).then(a.other.cmp(&b.other)));
+        bonds.truncate(limit);
+        bonds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    #[test]
+    fn adjust_clamps_and_classifies() {
+        let mut relationships = SoulRelationships::default();
+        let other = entity(1);
+
+        relationships.adjust(other, 0.4);
+        assert_eq!(relationships.standing(other), SocialStanding::Neutral);
+        relationships.adjust(other, 0.9);
+        assert_eq!(relationships.affinity(other), 1.0);
+        assert!(relationships.is_friend(other));
+
+        relationships.adjust(other, -1.8);
+        assert_eq!(relationships.standing(other), SocialStanding::Rival);
+    }
+
+    #[test]
+    fn capacity_drops_weakest_bond() {
+        let mut relationships = SoulRelationships::default();
+        for index in 0..SOUL_RELATIONSHIP_CAPACITY as u32 {
+            relationships.adjust(entity(index + 1), 0.6);
+        }
+        relationships.adjust(entity(1), -0.55);
+        relationships.adjust(entity(100), 0.9);
+
+        assert_eq!(relationships.bonds().len(), SOUL_RELATIONSHIP_CAPACITY);
+        assert_eq!(relationships.affinity(entity(1)), 0.0);
+        assert_eq!(relationships.affinity(entity(100)), 0.9);
+    }
+
+    #[test]
+    fn capacity_keeps_existing_bonds_stronger_than_the_newcomer() {
+        let mut relationships = SoulRelationships::default();
+        for index in 0..SOUL_RELATIONSHIP_CAPACITY as u32 {
+            relationships.adjust(entity(index + 1), 0.6);
+        }
+        relationships.adjust(entity(1), -0.55);
+
+        relationships.adjust(entity(100), 0.02);
+        assert_eq!(relationships.affinity(entity(100)), 0.0);
+        assert!(relationships.affinity(entity(1)) > 0.0);
+
+        relationships.adjust(entity(101), -0.3);
+        assert_eq!(relationships.bonds().len(), SOUL_RELATIONSHIP_CAPACITY);
+        assert_eq!(relationships.affinity(entity(1)), 0.0);
+        assert_eq!(relationships.affinity(entity(101)), -0.3);
+    }
+
+    #[test]
+    fn top_lists_are_sorted_and_limited() {
+        let mut relationships = SoulRelationships::default();
+        relationships.adjust(entity(1), 0.6);
+        relationships.adjust(entity(2), 0.9);
+        relationships.adjust(entity(3), 0.7);
+        relationships.adjust(entity(4), -0.8);
+        relationships.adjust(entity(5), 0.1);
+
+        let friends: Vec<Entity> = relationships
+            .top_friends(2)
+            .iter()
+            .map(|bond| bond.other)
+            .collect();
+        assert_eq!(friends, vec![entity(2), entity(3)]);
+        assert_eq!(relationships.top_rivals(3).len(), 1);
+    }
+}
@@ -4,9 +4,14 @@
 
 use bevy::prelude::*;
 
+use crate::life_log::SoulLifeLog;
+use crate::redemption::SoulPenance;
+use crate::social::SoulRelationships;
+
 /// 地獄に堕ちた人間（怠惰な魂）
 #[derive(Component, Reflect)]
 #[reflect(Component)]
+#[require(SoulRelationships, SoulLifeLog, SoulPenance)]
 pub struct DamnedSoul {
     pub laziness: f32,   // 怠惰レベル (0.0-1.0)
     pub motivation: f32, // やる気 (0.0-1.0)
@@ -93,6 +98,26 @@ pub enum IdleBehavior {
     GoingToRest,
     Escaping,
     Drifting,
+    /// Redemption Shrine への巡礼と儀式
+    Redeeming,
+}
+
+impl IdleBehavior {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Wandering => "ui.idle.wandering",
+            Self::Sitting => "ui.idle.sitting",
+            Self::Sleeping => "ui.idle.sleeping",
+            Self::Gathering => "ui.idle.gathering",
+            Self::ExhaustedGathering => "ui.idle.exhausted_gathering",
+            Self::Resting => "ui.idle.resting",
+            Self::GoingToRest => "ui.idle.going_to_rest",
+            Self::Escaping => "ui.idle.escaping",
+            Self::Drifting => "ui.idle.drifting",
+            Self::Redeeming => "ui.idle.redeeming",
+        }
+    }
 }
 
 /// 集会中のサブ行動
@@ -0,0 +1,194 @@
+//! コロニー統計の時系列
+//!
+//! 一定のゲーム内時間ごとに人口・DreamPool・備蓄・作業完了数・電力・平均バイタルを
+//! `ColonySample` として記録し、上限付きの履歴をセーブに含める。
+//! 値の収集は root の統計システム、グラフ表示は `hw_ui` が担当する。
+
+use bevy::prelude::*;
+
+use crate::jobs::WorkType;
+use crate::logistics::ResourceType;
+
+/// サンプリング間隔（ゲーム内秒）
+pub const STATISTICS_SAMPLE_INTERVAL_SECS: f32 = 30.0;
+/// 保持するサンプル数の上限（30 秒間隔で 4 時間分）
+pub const STATISTICS_HISTORY_CAPACITY: usize = 480;
+
+/// 資源種別ごとの備蓄数
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct ResourceStock {
+    pub resource: ResourceType,
+    pub count: u32,
+}
+
+/// 作業種別ごとの完了数
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct WorkCompletions {
+    pub work_type: WorkType,
+    pub count: u32,
+}
+
+/// Soul のバイタル平均（Soul がいなければすべて 0）
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub struct VitalAverages {
+    pub stress: f32,
+    pub fatigue: f32,
+    pub motivation: f32,
+    pub laziness: f32,
+}
+
+/// 1 回分の統計サンプル
+#[derive(Reflect, Clone, Debug, Default, PartialEq)]
+pub struct ColonySample {
+    /// 記録時の `GameTime::seconds`
+    pub time: f32,
+    pub population: u32,
+    pub population_cap: u32,
+    /// 脱走した Soul の累計
+    pub escaped: u32,
+    pub dream_points: f32,
+    /// Stockpile に格納されている資源（0 の種別は省く）
+    pub stocked: Vec<ResourceStock>,
+    /// 前回のサンプル以降に完了した作業（0 の種別は省く）
+    pub completed: Vec<WorkCompletions>,
+    pub power_generation: f32,
+    pub power_consumption: f32,
+    pub vitals: VitalAverages,
+}
+
+impl ColonySample {
+    pub fn stocked(&self, resource: ResourceType) -> u32 {
+        self.stocked
+            .iter()
+            .find(|stock| stock.resource == resource)
+            .map_or(0, |stock| stock.count)
+    }
+
+    pub fn completed(&self, work_type: WorkType) -> u32 {
+        self.completed
+            .iter()
+            .find(|entry| entry.work_type == work_type)
+            .map_or(0, |entry| entry.count)
+    }
+
+    pub fn completed_total(&self) -> u32 {
+        self.completed.iter().map(|entry| entry.count).sum()
+    }
+}
+
+/// コロニー統計の履歴（セーブ単位で永続化）
+#[derive(Resource, Reflect, Clone, Debug, Default, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct ColonyStatistics {
+    samples: Vec<ColonySample>,
+    /// 次のサンプルを取るゲーム内時刻
+    next_sample_at: f32,
+    /// 次のサンプルへ計上する作業完了数
+    pending_completions: Vec<WorkCompletions>,
+}
+
+impl ColonyStatistics {
+    /// 古い順のサンプル
+    pub fn samples(&self) -> &[ColonySample] {
+        &self.samples
+    }
+
+    pub fn latest(&self) -> Option<&ColonySample> {
+        self.samples.last()
+    }
+
+    /// `since` 以降（その時刻を含む）のサンプル
+    pub fn samples_since(&self, since: f32) -> &[ColonySample] {
+        let start = self.samples.partition_point(|sample| sample.time < since);
+        &self.samples[start..]
+    }
+
+    pub fn record_completion(&mut self, work_type: WorkType) {
+        match self
+            .pending_completions
+            .iter_mut()
+            .find(|entry| entry.work_type == work_type)
+        {
+            Some(entry) => entry.count += 1,
+            None => self.pending_completions.push(WorkCompletions {
+                work_type,
+                count: 1,
+            }),
+        }
+    }
+
+    /// `now` がサンプリング時刻に達していれば true。
+    ///
+    /// 最後のサンプルより時刻が戻っている場合（ゲーム内時間のリセット）も true を返す。
+    pub fn is_sample_due(&self, now: f32) -> bool {
+        now >= self.next_sample_at || self.latest().is_some_and(|latest| now < latest.time)
+    }
+
+    /// サンプルを追加し、保留中の作業完了数を移す。
+    ///
+    /// 時刻が最後のサンプルより前なら履歴を破棄してから追加する。上限を超えた分は古い順に捨てる。
+    pub fn push(&mut self, mut sample: ColonySample) {
+        if self
+            .latest()
+            .is_some_and(|latest| sample.time < latest.time)
+        {
+            self.samples.clear();
+        }
+        sample.completed = std::mem::take(&mut self.pending_completions);
+        self.next_sample_at = sample.time + STATISTICS_SAMPLE_INTERVAL_SECS;
+        self.samples.push(sample);
+        if self.samples.len() > STATISTICS_HISTORY_CAPACITY {
+            let overflow = self.samples.len() - STATISTICS_HISTORY_CAPACITY;
+            self.samples.drain(..overflow);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_at(time: f32) -> ColonySample {
+        ColonySample { time, ..default() }
+    }
+
+    #[test]
+    fn completions_move_into_the_next_sample_and_history_is_bounded() {
+        let mut statistics = ColonyStatistics::default();
+        assert!(statistics.is_sample_due(0.0));
+        statistics.record_completion(WorkType::Chop);
+        statistics.record_completion(WorkType::Chop);
+        statistics.record_completion(WorkType::Haul);
+        statistics.push(sample_at(0.0));
+
+        let latest = statistics.latest().unwrap();
+        assert_eq!(latest.completed(WorkType::Chop), 2);
+        assert_eq!(latest.completed_total(), 3);
+        assert!(!statistics.is_sample_due(STATISTICS_SAMPLE_INTERVAL_SECS - 1.0));
+        assert!(statistics.is_sample_due(STATISTICS_SAMPLE_INTERVAL_SECS));
+
+        for step in 1..=STATISTICS_HISTORY_CAPACITY {
+            statistics.push(sample_at(step as f32 * STATISTICS_SAMPLE_INTERVAL_SECS));
+        }
+        assert_eq!(statistics.samples().len(), STATISTICS_HISTORY_CAPACITY);
+        assert_eq!(
+            statistics.samples()[0].time,
+            STATISTICS_SAMPLE_INTERVAL_SECS
+        );
+        assert_eq!(statistics.latest().unwrap().completed_total(), 0);
+    }
+
+    #[test]
+    fn range_queries_and_time_reset() {
+        let mut statistics = ColonyStatistics::default();
+        for time in [0.0, 30.0, 60.0, 90.0] {
+            statistics.push(sample_at(time));
+        }
+        assert_eq!(statistics.samples_since(45.0).len(), 2);
+        assert_eq!(statistics.samples_since(60.0)[0].time, 60.0);
+
+        assert!(statistics.is_sample_due(10.0));
+        statistics.push(sample_at(10.0));
+        assert_eq!(statistics.samples().len(), 1);
+    }
+}
@@ -40,6 +40,11 @@ pub enum UiSlot {
     DialogMaxSoulText,
     ModeText,
     TaskSummaryText,
+    PopulationText,
+    ContractPanel,
+    ContractHeaderText,
+    ContractListText,
+    EdictStatusText,
     AreaEditPreview,
     TooltipAnchor,
     FpsText,
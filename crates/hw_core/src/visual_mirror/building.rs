@@ -18,6 +18,7 @@ pub enum BuildingTypeVisual {
     WheelbarrowParking,
     SoulSpa,
     OutdoorLamp,
+    RedemptionShrine,
 }
 
 /// Mirror of `hw_jobs::Building` carrying only the data `hw_visual` needs.
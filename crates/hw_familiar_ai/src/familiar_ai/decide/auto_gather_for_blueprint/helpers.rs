@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 
 use bevy::prelude::*;
-use hw_core::area::AreaBounds;
+use hw_core::area::{AreaBounds, TaskArea};
 use hw_core::constants::{
     BLUEPRINT_AUTO_GATHER_STAGE1_RADIUS_TILES, BLUEPRINT_AUTO_GATHER_STAGE2_RADIUS_TILES,
     BLUEPRINT_AUTO_GATHER_STAGE3_RADIUS_TILES, ROCK_DROP_AMOUNT, TILE_SIZE, WOOD_DROP_AMOUNT,
@@ -14,7 +14,7 @@ pub const STAGE_COUNT: usize = 5;
 
 #[derive(Clone)]
 pub struct OwnerInfo {
-    pub area: AreaBounds,
+    pub area: TaskArea,
     pub center: Vec2,
     pub path_start: (i32, i32),
     pub yard: Option<Yard>,
@@ -83,7 +83,7 @@ pub fn stage_for_pos(pos: Vec2, owner: &OwnerInfo) -> usize {
         return 0;
     }
 
-    let dist_sq = distance_sq_to_area_outside(pos, &owner.area);
+    let dist_sq = owner.area.distance_sq_outside(pos);
     let stage1_radius = BLUEPRINT_AUTO_GATHER_STAGE1_RADIUS_TILES * TILE_SIZE;
     let stage2_radius = BLUEPRINT_AUTO_GATHER_STAGE2_RADIUS_TILES * TILE_SIZE;
     let stage3_radius = BLUEPRINT_AUTO_GATHER_STAGE3_RADIUS_TILES * TILE_SIZE;
@@ -183,35 +183,27 @@ fn resolve_owner_matching(
         .map(|(owner, _)| *owner)
 }
 
-fn distance_sq_to_task_area_perimeter(pos: Vec2, area: &AreaBounds) -> f32 {
-    let inside_x = pos.x >= area.min.x && pos.x <= area.max.x;
-    let inside_y = pos.y >= area.min.y && pos.y <= area.max.y;
-
-    if inside_x && inside_y {
-        let dist_to_left = pos.x - area.min.x;
-        let dist_to_right = area.max.x - pos.x;
-        let dist_to_bottom = pos.y - area.min.y;
-        let dist_to_top = area.max.y - pos.y;
-        let min_dist = dist_to_left
-            .min(dist_to_right)
-            .min(dist_to_bottom)
-            .min(dist_to_top);
-        min_dist * min_dist
-    } else {
-        let clamped_x = pos.x.clamp(area.min.x, area.max.x);
-        let clamped_y = pos.y.clamp(area.min.y, area.max.y);
-        let dx = pos.x - clamped_x;
-        let dy = pos.y - clamped_y;
-        dx * dx + dy * dy
+/// 内側の点は含む部分矩形の輪郭までの最短距離、外側の点は最寄り部分矩形までの距離
+fn distance_sq_to_task_area_perimeter(pos: Vec2, area: &TaskArea) -> f32 {
+    if !area.contains(pos) {
+        return area.distance_sq_outside(pos);
     }
+    area.rects()
+        .filter(|rect| rect.contains(pos))
+        .map(|rect| distance_sq_to_rect_perimeter(pos, rect))
+        .fold(f32::INFINITY, f32::min)
 }
 
-fn distance_sq_to_area_outside(pos: Vec2, area: &AreaBounds) -> f32 {
-    let clamped_x = pos.x.clamp(area.min.x, area.max.x);
-    let clamped_y = pos.y.clamp(area.min.y, area.max.y);
-    let dx = pos.x - clamped_x;
-    let dy = pos.y - clamped_y;
-    dx * dx + dy * dy
+fn distance_sq_to_rect_perimeter(pos: Vec2, area: &AreaBounds) -> f32 {
+    let dist_to_left = pos.x - area.min.x;
+    let dist_to_right = area.max.x - pos.x;
+    let dist_to_bottom = pos.y - area.min.y;
+    let dist_to_top = area.max.y - pos.y;
+    let min_dist = dist_to_left
+        .min(dist_to_right)
+        .min(dist_to_bottom)
+        .min(dist_to_top);
+    min_dist * min_dist
 }
 
 fn distance_sq_to_yard_perimeter(pos: Vec2, yard: &Yard) -> f32 {
@@ -285,7 +277,7 @@ mod tests {
 
     fn owner() -> OwnerInfo {
         OwnerInfo {
-            area: AreaBounds::new(Vec2::ZERO, Vec2::splat(TILE_SIZE * 2.0)),
+            area: AreaBounds::new(Vec2::ZERO, Vec2::splat(TILE_SIZE * 2.0)).into(),
             center: Vec2::splat(TILE_SIZE),
             path_start: (1, 1),
             yard: None,
@@ -295,7 +287,7 @@ mod tests {
     #[test]
     fn auto_gather_stage_uses_documented_distance_bands() {
         let owner = owner();
-        let area_edge = owner.area.max.x;
+        let area_edge = owner.area.max().x;
 
         assert_eq!(stage_for_pos(owner.center, &owner), 0);
         assert_eq!(
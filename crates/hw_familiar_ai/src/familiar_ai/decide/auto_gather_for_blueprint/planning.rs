@@ -274,7 +274,7 @@ mod tests {
 
     fn owner_info() -> OwnerInfo {
         OwnerInfo {
-            area: AreaBounds::new(Vec2::ZERO, WorldMap::grid_to_world(30, 30)),
+            area: AreaBounds::new(Vec2::ZERO, WorldMap::grid_to_world(30, 30)).into(),
             center: WorldMap::grid_to_world(15, 15),
             path_start: (15, 15),
             yard: None,
@@ -161,7 +161,7 @@ pub fn blueprint_auto_gather_system(
         owner_infos.insert(
             fam_entity,
             OwnerInfo {
-                area: area.bounds(),
+                area: area.clone(),
                 center: area.center(),
                 path_start,
                 yard: owner_yard,
@@ -177,7 +177,7 @@ pub fn blueprint_auto_gather_system(
         owner_infos.insert(
             *yard_entity,
             OwnerInfo {
-                area: yard.bounds(),
+                area: yard.bounds().into(),
                 center: yard_center,
                 path_start,
                 yard: Some(yard.clone()),
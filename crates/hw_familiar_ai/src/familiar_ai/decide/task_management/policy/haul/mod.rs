@@ -11,6 +11,7 @@ mod selector_metrics;
 mod soul_spa;
 mod source_selector;
 mod stockpile;
+mod supply_route;
 mod wall;
 mod wheelbarrow;
 
@@ -99,6 +100,16 @@ pub fn assign_haul(
         return TaskAssignmentAttempt::Submitted;
     }
 
+    if supply_route::assign_supply_route_to_stockpile(
+        task_pos,
+        already_commanded,
+        ctx,
+        queries,
+        shadow,
+    ) {
+        return TaskAssignmentAttempt::Submitted;
+    }
+
     debug!(
         "ASSIGN: Haul task {:?} is not a valid transport request candidate",
         ctx.task_entity
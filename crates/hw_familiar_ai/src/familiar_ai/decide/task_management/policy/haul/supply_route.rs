@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use hw_core::constants::*;
+use hw_logistics::transport_request::can_complete_pick_drop_to_point;
+
+use super::super::super::builders::issue_haul_to_stockpile_with_source;
+use super::super::super::validator::resolve_supply_route_inputs;
+use super::lease_validation;
+use super::source_selector;
+use crate::familiar_ai::decide::task_management::{
+    AssignTaskContext, FamiliarTaskAssignmentQueries, ReservationShadow,
+};
+
+/// 補給ルート request を、手押し車 lease か搬出元セルからの単品運搬として割り当てる。
+pub fn assign_supply_route_to_stockpile(
+    task_pos: Vec2,
+    already_commanded: bool,
+    ctx: &AssignTaskContext<'_>,
+    queries: &mut FamiliarTaskAssignmentQueries,
+    shadow: &mut ReservationShadow,
+) -> bool {
+    let Some(resolved) =
+        resolve_supply_route_inputs(ctx.task_entity, queries, shadow, ctx.incoming_snapshot)
+    else {
+        return false;
+    };
+    let resource_type = resolved.resource_type;
+
+    let min_valid_items = if resource_type.requires_wheelbarrow() {
+        1
+    } else {
+        WHEELBARROW_MIN_BATCH_SIZE
+    };
+    if lease_validation::try_issue_haul_from_lease(
+        lease_validation::HaulFromLeaseSpec {
+            task_entity: ctx.task_entity,
+            task_pos,
+            already_commanded,
+            min_valid_items,
+            max_items: resolved.available_amount.max(1),
+        },
+        |_| true,
+        ctx,
+        queries,
+        shadow,
+    ) {
+        return true;
+    }
+
+    let Some((source_item, source_pos)) = source_selector::find_consolidation_source_item(
+        resource_type,
+        &resolved.source_cells,
+        resolved.destination_owner,
+        queries,
+        shadow,
+    ) else {
+        debug!(
+            "ASSIGN: Supply route request {:?} has no available {:?} source",
+            ctx.task_entity, resource_type
+        );
+        return false;
+    };
+
+    // 手押し車必須の資源は、隣接セル間を除いて仲裁の lease を待つ。
+    if resource_type.requires_wheelbarrow() {
+        let Ok((_, destination_transform, _, _)) =
+            queries.storage.stockpiles.get(resolved.destination)
+        else {
+            return false;
+        };
+        if !can_complete_pick_drop_to_point(
+            source_pos,
+            destination_transform.translation.truncate(),
+        ) {
+            return false;
+        }
+    }
+
+    issue_haul_to_stockpile_with_source(
+        source_item,
+        resolved.destination,
+        source_pos,
+        already_commanded,
+        ctx,
+        queries,
+        shadow,
+    );
+    true
+}
@@ -8,11 +8,12 @@ mod wheelbarrow;
 pub use finder::find_bucket_return_assignment;
 pub use reservation::{can_reserve_source, source_not_reserved};
 pub use resolver::{
-    ResolvedConsolidationInputs, ResolvedStockpileInputs, resolve_consolidation_inputs,
-    resolve_gather_water_inputs, resolve_haul_to_blueprint_inputs,
+    ResolvedConsolidationInputs, ResolvedStockpileInputs, ResolvedSupplyRouteInputs,
+    resolve_consolidation_inputs, resolve_gather_water_inputs, resolve_haul_to_blueprint_inputs,
     resolve_haul_to_floor_construction_inputs, resolve_haul_to_mixer_inputs,
     resolve_haul_to_provisional_wall_inputs, resolve_haul_to_soul_spa_inputs,
     resolve_haul_to_stockpile_inputs, resolve_haul_to_wall_construction_inputs,
     resolve_haul_water_to_mixer_inputs, resolve_return_bucket_tank, resolve_return_wheelbarrow,
+    resolve_supply_route_inputs,
 };
 pub use wheelbarrow::find_nearest_wheelbarrow;
@@ -50,6 +50,46 @@ pub struct ResolvedConsolidationInputs {
     pub receiver_owner: Option<Entity>,
 }
 
+/// 搬出元セルが受け取り側と同じ所有者で、`resource_type` を 1 個以上搬出できるかを判定する。
+fn donor_can_export(
+    donor: Entity,
+    resource_type: ResourceType,
+    receiver_owner: Option<Entity>,
+    queries: &FamiliarTaskAssignmentQueries,
+) -> bool {
+    let donor_owner = queries
+        .designation
+        .belongs
+        .get(donor)
+        .ok()
+        .map(|belongs| belongs.0);
+    if donor_owner != receiver_owner {
+        return false;
+    }
+    let Ok((_, _, stockpile, stored)) = queries.storage.stockpiles.get(donor) else {
+        return false;
+    };
+    let Ok(policy) = queries.storage.stockpile_policies.get(donor) else {
+        return false;
+    };
+    let stored_amount = stored.map(|items| items.len()).unwrap_or(0);
+    evaluate_stockpile_policy(StockpilePolicyInput {
+        phase: StockpileTransferPhase::NewOutbound,
+        policy: *policy,
+        capacity: stockpile.capacity,
+        stored_amount,
+        stored_resource: stockpile.resource_type,
+        transfer_resource: resource_type,
+        requested_amount: 1,
+        incoming_reserved: 0,
+        incoming_reserved_other_resource: 0,
+        cycle_reserved: 0,
+        cycle_reserved_other_resource: 0,
+    })
+    .allowed_amount
+        == 1
+}
+
 pub fn resolve_consolidation_inputs(
     task_entity: Entity,
     queries: &FamiliarTaskAssignmentQueries,
@@ -83,39 +123,7 @@ pub fn resolve_consolidation_inputs(
         .iter()
         .copied()
         .filter(|donor| *donor != receiver)
-        .filter(|donor| {
-            let donor_owner = queries
-                .designation
-                .belongs
-                .get(*donor)
-                .ok()
-                .map(|belongs| belongs.0);
-            if donor_owner != receiver_owner {
-                return false;
-            }
-            let Ok((_, _, stockpile, stored)) = queries.storage.stockpiles.get(*donor) else {
-                return false;
-            };
-            let Ok(policy) = queries.storage.stockpile_policies.get(*donor) else {
-                return false;
-            };
-            let stored_amount = stored.map(|items| items.len()).unwrap_or(0);
-            evaluate_stockpile_policy(StockpilePolicyInput {
-                phase: StockpileTransferPhase::NewOutbound,
-                policy: *policy,
-                capacity: stockpile.capacity,
-                stored_amount,
-                stored_resource: stockpile.resource_type,
-                transfer_resource: resource_type,
-                requested_amount: 1,
-                incoming_reserved: 0,
-                incoming_reserved_other_resource: 0,
-                cycle_reserved: 0,
-                cycle_reserved_other_resource: 0,
-            })
-            .allowed_amount
-                == 1
-        })
+        .filter(|donor| donor_can_export(*donor, resource_type, receiver_owner, queries))
         .collect();
     if donor_cells.is_empty() {
         return None;
@@ -129,6 +137,62 @@ pub fn resolve_consolidation_inputs(
     })
 }
 
+pub struct ResolvedSupplyRouteInputs {
+    pub destination: Entity,
+    pub resource_type: ResourceType,
+    pub source_cells: Vec<Entity>,
+    pub destination_owner: Option<Entity>,
+    pub available_amount: usize,
+}
+
+pub fn resolve_supply_route_inputs(
+    task_entity: Entity,
+    queries: &FamiliarTaskAssignmentQueries,
+    shadow: &ReservationShadow,
+    incoming_snapshot: &IncomingDeliverySnapshot,
+) -> Option<ResolvedSupplyRouteInputs> {
+    let req = queries.transport_requests.get(task_entity).ok()?;
+    if req.kind != TransportRequestKind::SupplyRoute {
+        return None;
+    }
+
+    let destination = req.anchor;
+    let resource_type = req.resource_type;
+    let expected_priority = queries.receiver_policy_tiers.get(task_entity).ok()?.0;
+    let available_amount = check_stockpile_capacity(
+        destination,
+        resource_type,
+        queries,
+        shadow,
+        incoming_snapshot,
+        Some(expected_priority),
+    )?;
+    let destination_owner = queries
+        .designation
+        .belongs
+        .get(destination)
+        .ok()
+        .map(|belongs| belongs.0);
+    let source_cells: Vec<Entity> = req
+        .stockpile_group
+        .iter()
+        .copied()
+        .filter(|source| *source != destination)
+        .filter(|source| donor_can_export(*source, resource_type, destination_owner, queries))
+        .collect();
+    if source_cells.is_empty() {
+        return None;
+    }
+
+    Some(ResolvedSupplyRouteInputs {
+        destination,
+        resource_type,
+        source_cells,
+        destination_owner,
+        available_amount,
+    })
+}
+
 pub fn resolve_haul_to_stockpile_inputs(
     task_entity: Entity,
     queries: &FamiliarTaskAssignmentQueries,
@@ -23,6 +23,7 @@ pub enum BuildingType {
     WheelbarrowParking,
     SoulSpa,
     OutdoorLamp,
+    RedemptionShrine,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,12 +35,13 @@ pub enum BuildingCategory {
 }
 
 impl BuildingCategory {
-    pub fn label(&self) -> &'static str {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
         match self {
-            Self::Structure => "Structure",
-            Self::Architecture => "Architecture",
-            Self::Plant => "Plant",
-            Self::Temporary => "Temporary",
+            Self::Structure => "building.category.structure",
+            Self::Architecture => "building.category.architecture",
+            Self::Plant => "building.category.plant",
+            Self::Temporary => "building.category.temporary",
         }
     }
 }
@@ -63,12 +65,31 @@ impl BuildingType {
         )
     }
 
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Wall => "building.wall",
+            Self::Door => "building.door",
+            Self::Floor => "building.floor",
+            Self::Tank => "building.tank",
+            Self::MudMixer => "building.mud_mixer",
+            Self::RestArea => "building.rest_area",
+            Self::Bridge => "building.bridge",
+            Self::SandPile => "building.sand_pile",
+            Self::BonePile => "building.bone_pile",
+            Self::WheelbarrowParking => "building.wheelbarrow_parking",
+            Self::SoulSpa => "building.soul_spa",
+            Self::OutdoorLamp => "building.outdoor_lamp",
+            Self::RedemptionShrine => "building.redemption_shrine",
+        }
+    }
+
     pub fn category(&self) -> BuildingCategory {
         match self {
             BuildingType::Wall | BuildingType::Floor | BuildingType::Bridge => {
                 BuildingCategory::Structure
             }
-            BuildingType::Door => BuildingCategory::Architecture,
+            BuildingType::Door | BuildingType::RedemptionShrine => BuildingCategory::Architecture,
             BuildingType::Tank | BuildingType::MudMixer | BuildingType::SoulSpa => {
                 BuildingCategory::Plant
             }
@@ -118,6 +139,10 @@ impl BuildingType {
             BuildingType::OutdoorLamp => {
                 materials.insert(ResourceType::Bone, 2);
             }
+            BuildingType::RedemptionShrine => {
+                materials.insert(ResourceType::Wood, 3);
+                materials.insert(ResourceType::Bone, 5);
+            }
         }
         materials
     }
@@ -169,6 +194,11 @@ pub struct SandPile;
 #[reflect(Component, Default)]
 pub struct BonePile;
 
+/// 贖罪可能な Soul の巡礼先
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
+pub struct RedemptionShrine;
+
 #[derive(Component, Reflect, Debug, Clone, Copy)]
 #[reflect(Component)]
 pub struct RestArea {
@@ -22,5 +22,6 @@ fn building_type_to_visual(kind: BuildingType) -> BuildingTypeVisual {
         BuildingType::WheelbarrowParking => BuildingTypeVisual::WheelbarrowParking,
         BuildingType::SoulSpa => BuildingTypeVisual::SoulSpa,
         BuildingType::OutdoorLamp => BuildingTypeVisual::OutdoorLamp,
+        BuildingType::RedemptionShrine => BuildingTypeVisual::RedemptionShrine,
     }
 }
@@ -10,6 +10,7 @@ pub mod resource_cache;
 pub mod spatial_sync;
 pub mod stockpile_policy;
 pub mod stockpile_policy_change;
+pub mod supply_route;
 pub mod tile_index;
 pub mod transport_request;
 pub mod types;
@@ -45,6 +46,10 @@ pub use stockpile_policy_change::{
     StockpilePolicyChangeOutcome, StockpilePolicyChangeRequest,
     apply_stockpile_policy_change_requests_system,
 };
+pub use supply_route::{
+    SupplyRoute, SupplyRouteChangeOutcome, SupplyRouteChangeRequest, SupplyRouteRejection,
+    SupplyRouteStats, apply_supply_route_change_requests_system,
+};
 pub use types::{
     BelongsTo, BucketStorage, Inventory, PendingBelongsToBlueprint, ResourceItem, Wheelbarrow,
 };
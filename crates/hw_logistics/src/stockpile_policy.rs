@@ -32,6 +32,18 @@ pub enum StockpilePolicyState {
     Disabled,
 }
 
+impl StockpilePolicyState {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Accepting => "ui.stockpile_state.accepting",
+            Self::TargetReached => "ui.stockpile_state.target_reached",
+            Self::Draining => "ui.stockpile_state.draining",
+            Self::Disabled => "ui.stockpile_state.disabled",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StockpilePolicyRejection {
     ResourceNotAccepted,
@@ -0,0 +1,636 @@
+//! Player-defined supply routes between stockpile clusters.
+//!
+//! A route keeps a destination cluster topped up from a source cluster inside the same Yard.
+//! The route itself only stores durable intent; the `SupplyRoute` transport producer turns the
+//! current deficit into requests every frame.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use bevy::prelude::*;
+use hw_core::constants::TILE_SIZE;
+use hw_core::relationships::StoredIn;
+
+use crate::types::{BelongsTo, ResourceItem};
+use crate::zone::{Stockpile, StockpilePolicy, StockpileResourceSet};
+
+/// Length of the sliding window used by [`SupplyRouteStats::deliveries_per_minute`].
+pub const SUPPLY_ROUTE_THROUGHPUT_WINDOW_SECS: f64 = 60.0;
+
+/// Durable standing order: keep `destination_cells` stocked from `source_cells`.
+///
+/// `target_amount` is counted per filtered resource across the whole destination cluster,
+/// including deliveries already on their way.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+#[require(SupplyRouteStats)]
+pub struct SupplyRoute {
+    #[entities]
+    pub source_cells: Vec<Entity>,
+    #[entities]
+    pub destination_cells: Vec<Entity>,
+    pub resources: StockpileResourceSet,
+    pub target_amount: usize,
+}
+
+impl SupplyRoute {
+    pub fn supplies(&self, cell: Entity) -> bool {
+        self.destination_cells.contains(&cell)
+    }
+
+    pub fn draws_from(&self, cell: Entity) -> bool {
+        self.source_cells.contains(&cell)
+    }
+}
+
+/// Runtime throughput counters. Rebuilt empty after load.
+#[derive(Component, Debug, Clone, Default)]
+pub struct SupplyRouteStats {
+    pub delivered_total: u32,
+    recent_deliveries: VecDeque<f64>,
+}
+
+impl SupplyRouteStats {
+    pub fn record_delivery(&mut self, now: f64) {
+        self.delivered_total = self.delivered_total.saturating_add(1);
+        self.recent_deliveries.push_back(now);
+        while self
+            .recent_deliveries
+            .front()
+            .is_some_and(|at| now - *at > SUPPLY_ROUTE_THROUGHPUT_WINDOW_SECS)
+        {
+            self.recent_deliveries.pop_front();
+        }
+    }
+
+    /// Deliveries completed within the last [`SUPPLY_ROUTE_THROUGHPUT_WINDOW_SECS`].
+    pub fn deliveries_per_minute(&self, now: f64) -> usize {
+        self.recent_deliveries
+            .iter()
+            .filter(|at| now - **at <= SUPPLY_ROUTE_THROUGHPUT_WINDOW_SECS)
+            .count()
+    }
+}
+
+/// Runtime marker on an item picked up from a route source cell.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplyRouteCargo(pub Entity);
+
+/// Player edit for supply routes, emitted by the stockpile inspector.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupplyRouteChangeRequest {
+    /// Creates a route between the clusters containing the two cells.
+    Create {
+        source_cell: Entity,
+        destination_cell: Entity,
+    },
+    SetTargetAmount {
+        route: Entity,
+        target_amount: usize,
+    },
+    SetResources {
+        route: Entity,
+        resources: StockpileResourceSet,
+    },
+    Remove {
+        route: Entity,
+    },
+}
+
+/// Why a [`SupplyRouteChangeRequest`] was not applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupplyRouteRejection {
+    /// A referenced cell no longer exists.
+    StaleCell,
+    /// A referenced cell is not a player-managed stockpile cell.
+    UnmanagedCell,
+    /// The two cells belong to different Yards.
+    DifferentOwner,
+    /// Source and destination resolve to the same cluster.
+    SameCluster,
+    /// Another route already supplies part of the destination cluster.
+    DestinationAlreadySupplied,
+    /// The destination cluster accepts none of the routable resources.
+    NoAcceptedResources,
+    /// The route no longer exists.
+    StaleRoute,
+}
+
+impl fmt::Display for SupplyRouteRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StaleCell => write!(f, "stockpile cell no longer exists"),
+            Self::UnmanagedCell => write!(f, "target is not a managed stockpile cell"),
+            Self::DifferentOwner => write!(f, "source and destination belong to different yards"),
+            Self::SameCluster => write!(f, "source and destination are the same stockpile"),
+            Self::DestinationAlreadySupplied => {
+                write!(f, "destination is already supplied by another route")
+            }
+            Self::NoAcceptedResources => write!(f, "destination accepts no resources"),
+            Self::StaleRoute => write!(f, "supply route no longer exists"),
+        }
+    }
+}
+
+/// Terminal result for one [`SupplyRouteChangeRequest`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupplyRouteChangeOutcome {
+    Created {
+        route: Entity,
+        source_cells: usize,
+        destination_cells: usize,
+    },
+    Updated {
+        route: Entity,
+    },
+    Removed {
+        route: Entity,
+    },
+    Rejected(SupplyRouteRejection),
+}
+
+type ManagedCellQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Stockpile,
+        &'static StockpilePolicy,
+        Option<&'static BelongsTo>,
+    ),
+>;
+
+fn grid_coord(pos: Vec2) -> IVec2 {
+    (pos / TILE_SIZE).floor().as_ivec2()
+}
+
+/// Expands `start` to its 4-connected managed cells owned by the same Yard.
+fn connected_cluster(start: Entity, owner: Entity, q_cells: &ManagedCellQuery) -> Vec<Entity> {
+    let by_coord: HashMap<IVec2, Entity> = q_cells
+        .iter()
+        .filter(|(.., belongs_to)| belongs_to.is_some_and(|belongs_to| belongs_to.0 == owner))
+        .map(|(entity, transform, ..)| (grid_coord(transform.translation.truncate()), entity))
+        .collect();
+    let Ok((_, start_transform, ..)) = q_cells.get(start) else {
+        return Vec::new();
+    };
+
+    let start_coord = grid_coord(start_transform.translation.truncate());
+    let mut visited = HashSet::from([start_coord]);
+    let mut frontier = vec![start_coord];
+    let mut cluster = Vec::new();
+    while let Some(coord) = frontier.pop() {
+        let Some(entity) = by_coord.get(&coord) else {
+            continue;
+        };
+        cluster.push(*entity);
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let next = coord + offset;
+            if by_coord.contains_key(&next) && visited.insert(next) {
+                frontier.push(next);
+            }
+        }
+    }
+    cluster.sort_unstable_by_key(|entity| (entity.index_u32(), entity.generation().to_bits()));
+    cluster
+}
+
+fn managed_owner(
+    cell: Entity,
+    q_existing: &Query<()>,
+    q_cells: &ManagedCellQuery,
+) -> Result<Entity, SupplyRouteRejection> {
+    if !q_existing.contains(cell) {
+        return Err(SupplyRouteRejection::StaleCell);
+    }
+    q_cells
+        .get(cell)
+        .ok()
+        .and_then(|(.., belongs_to)| belongs_to.map(|belongs_to| belongs_to.0))
+        .ok_or(SupplyRouteRejection::UnmanagedCell)
+}
+
+fn create_route(
+    commands: &mut Commands,
+    source_cell: Entity,
+    destination_cell: Entity,
+    q_existing: &Query<()>,
+    q_cells: &ManagedCellQuery,
+    q_routes: &Query<(Entity, &mut SupplyRoute)>,
+) -> Result<SupplyRouteChangeOutcome, SupplyRouteRejection> {
+    let source_owner = managed_owner(source_cell, q_existing, q_cells)?;
+    let destination_owner = managed_owner(destination_cell, q_existing, q_cells)?;
+    if source_owner != destination_owner {
+        return Err(SupplyRouteRejection::DifferentOwner);
+    }
+
+    let destination_cells = connected_cluster(destination_cell, destination_owner, q_cells);
+    if destination_cells.contains(&source_cell) {
+        return Err(SupplyRouteRejection::SameCluster);
+    }
+    if q_routes.iter().any(|(_, route)| {
+        route
+            .destination_cells
+            .iter()
+            .any(|cell| destination_cells.contains(cell))
+    }) {
+        return Err(SupplyRouteRejection::DestinationAlreadySupplied);
+    }
+    let source_cells = connected_cluster(source_cell, source_owner, q_cells);
+
+    let mut resources = StockpileResourceSet::empty();
+    let mut target_amount = 0usize;
+    for (_, _, _, policy, _) in q_cells.iter_many(&destination_cells) {
+        for resource_type in policy.acceptance.accepted_resources() {
+            resources = resources.with(resource_type, true);
+        }
+        target_amount = target_amount.saturating_add(policy.target_amount);
+    }
+    if resources.is_empty() {
+        return Err(SupplyRouteRejection::NoAcceptedResources);
+    }
+
+    let outcome_counts = (source_cells.len(), destination_cells.len());
+    let route = commands
+        .spawn((
+            Name::new("SupplyRoute"),
+            SupplyRoute {
+                source_cells,
+                destination_cells,
+                resources,
+                target_amount,
+            },
+        ))
+        .id();
+    Ok(SupplyRouteChangeOutcome::Created {
+        route,
+        source_cells: outcome_counts.0,
+        destination_cells: outcome_counts.1,
+    })
+}
+
+/// Applies supply route edits. Route removal leaves in-flight deliveries to finish normally.
+pub fn apply_supply_route_change_requests_system(
+    mut commands: Commands,
+    mut requests: MessageReader<SupplyRouteChangeRequest>,
+    q_existing: Query<()>,
+    q_cells: ManagedCellQuery,
+    mut q_routes: Query<(Entity, &mut SupplyRoute)>,
+    mut outcomes: MessageWriter<SupplyRouteChangeOutcome>,
+) {
+    for request in requests.read().copied() {
+        let result = match request {
+            SupplyRouteChangeRequest::Create {
+                source_cell,
+                destination_cell,
+            } => create_route(
+                &mut commands,
+                source_cell,
+                destination_cell,
+                &q_existing,
+                &q_cells,
+                &q_routes,
+            ),
+            SupplyRouteChangeRequest::SetTargetAmount {
+                route,
+                target_amount,
+            } => q_routes
+                .get_mut(route)
+                .map(|(_, mut current)| {
+                    let capacity = q_cells
+                        .iter_many(&current.destination_cells)
+                        .map(|(_, _, stockpile, ..)| stockpile.capacity)
+                        .sum::<usize>();
+                    let target_amount = target_amount.min(capacity);
+                    if current.target_amount != target_amount {
+                        current.target_amount = target_amount;
+                    }
+                    SupplyRouteChangeOutcome::Updated { route }
+                })
+                .map_err(|_| SupplyRouteRejection::StaleRoute),
+            SupplyRouteChangeRequest::SetResources { route, resources } => q_routes
+                .get_mut(route)
+                .map(|(_, mut current)| {
+                    if current.resources != resources {
+                        current.resources = resources;
+                    }
+                    SupplyRouteChangeOutcome::Updated { route }
+                })
+                .map_err(|_| SupplyRouteRejection::StaleRoute),
+            SupplyRouteChangeRequest::Remove { route } => {
+                if q_routes.contains(route) {
+                    commands.entity(route).try_despawn();
+                    Ok(SupplyRouteChangeOutcome::Removed { route })
+                } else {
+                    Err(SupplyRouteRejection::StaleRoute)
+                }
+            }
+        };
+        outcomes.write(result.unwrap_or_else(SupplyRouteChangeOutcome::Rejected));
+    }
+}
+
+/// Drops cells that were removed from the world and deletes routes left without an endpoint.
+pub fn prune_supply_routes_system(
+    mut commands: Commands,
+    mut q_routes: Query<(Entity, &mut SupplyRoute)>,
+    q_cells: Query<(), (With<Stockpile>, With<StockpilePolicy>)>,
+) {
+    for (entity, mut route) in q_routes.iter_mut() {
+        let is_stale = |cell: &Entity| !q_cells.contains(*cell);
+        if !route.source_cells.iter().any(is_stale) && !route.destination_cells.iter().any(is_stale)
+        {
+            continue;
+        }
+        route.source_cells.retain(|cell| q_cells.contains(*cell));
+        route
+            .destination_cells
+            .retain(|cell| q_cells.contains(*cell));
+        if route.source_cells.is_empty() || route.destination_cells.is_empty() {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// Tags an item leaving a route source cell so its arrival can be counted.
+pub fn on_supply_route_item_unstored(
+    on: On<Discard, StoredIn>,
+    mut commands: Commands,
+    q_items: Query<(&StoredIn, &ResourceItem)>,
+    q_routes: Query<(Entity, &SupplyRoute)>,
+) {
+    let Ok((stored_in, item)) = q_items.get(on.entity) else {
+        return;
+    };
+    if let Some((route, _)) = q_routes
+        .iter()
+        .find(|(_, route)| route.draws_from(stored_in.0) && route.resources.contains(item.0))
+    {
+        commands
+            .entity(on.entity)
+            .try_insert(SupplyRouteCargo(route));
+    }
+}
+
+/// Counts a tagged item once it is stored in its route's destination cluster.
+pub fn on_supply_route_item_stored(
+    on: On<Insert, StoredIn>,
+    mut commands: Commands,
+    time: Res<Time>,
+    q_items: Query<(&StoredIn, &SupplyRouteCargo)>,
+    mut q_routes: Query<(&SupplyRoute, &mut SupplyRouteStats)>,
+) {
+    let Ok((stored_in, cargo)) = q_items.get(on.entity) else {
+        return;
+    };
+    if let Ok((route, mut stats)) = q_routes.get_mut(cargo.0)
+        && route.supplies(stored_in.0)
+    {
+        stats.record_delivery(time.elapsed_secs_f64());
+    }
+    commands.entity(on.entity).try_remove::<SupplyRouteCargo>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StockpileAcceptance;
+    use crate::types::ResourceType;
+
+    #[derive(Resource, Default)]
+    struct Receipts(Vec<SupplyRouteChangeOutcome>);
+
+    fn collect_outcomes(
+        mut outcomes: MessageReader<SupplyRouteChangeOutcome>,
+        mut receipts: ResMut<Receipts>,
+    ) {
+        receipts.0.extend(outcomes.read().copied());
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SupplyRouteChangeRequest>()
+            .add_message::<SupplyRouteChangeOutcome>()
+            .init_resource::<Receipts>()
+            .add_systems(
+                Update,
+                (apply_supply_route_change_requests_system, collect_outcomes).chain(),
+            )
+            .add_observer(on_supply_route_item_unstored)
+            .add_observer(on_supply_route_item_stored);
+        app
+    }
+
+    fn cell(app: &mut App, owner: Entity, x: i32, acceptance: StockpileAcceptance) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz((x as f32 + 0.5) * TILE_SIZE, 0.5 * TILE_SIZE, 0.0),
+                Stockpile {
+                    capacity: 10,
+                    resource_type: None,
+                },
+                StockpilePolicy {
+                    acceptance,
+                    ..StockpilePolicy::for_capacity(10)
+                },
+                BelongsTo(owner),
+            ))
+            .id()
+    }
+
+    fn routes(app: &mut App) -> Vec<(Entity, SupplyRoute)> {
+        let mut query = app.world_mut().query::<(Entity, &SupplyRoute)>();
+        query
+            .iter(app.world())
+            .map(|(entity, route)| (entity, route.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn create_expands_both_ends_to_adjacent_cells_and_defaults_to_destination_acceptance() {
+        let mut app = app();
+        let owner = app.world_mut().spawn_empty().id();
+        let source_a = cell(&mut app, owner, 0, StockpileAcceptance::Any);
+        let source_b = cell(&mut app, owner, 1, StockpileAcceptance::Any);
+        let destination_a = cell(
+            &mut app,
+            owner,
+            3,
+            StockpileAcceptance::Only(ResourceType::Sand),
+        );
+        let destination_b = cell(
+            &mut app,
+            owner,
+            4,
+            StockpileAcceptance::Only(ResourceType::Rock),
+        );
+
+        app.world_mut()
+            .write_message(SupplyRouteChangeRequest::Create {
+                source_cell: source_b,
+                destination_cell: destination_a,
+            });
+        app.update();
+
+        let routes = routes(&mut app);
+        assert_eq!(routes.len(), 1);
+        let (route_entity, route) = &routes[0];
+        assert_eq!(route.source_cells.len(), 2);
+        assert!(route.draws_from(source_a) && route.draws_from(source_b));
+        assert!(route.supplies(destination_a) && route.supplies(destination_b));
+        assert_eq!(
+            route.resources,
+            StockpileResourceSet::empty()
+                .with(ResourceType::Sand, true)
+                .with(ResourceType::Rock, true)
+        );
+        assert_eq!(route.target_amount, 20);
+        assert_eq!(
+            app.world().resource::<Receipts>().0,
+            vec![SupplyRouteChangeOutcome::Created {
+                route: *route_entity,
+                source_cells: 2,
+                destination_cells: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn create_rejects_same_cluster_other_yards_and_double_supplied_destinations() {
+        let mut app = app();
+        let owner = app.world_mut().spawn_empty().id();
+        let other_owner = app.world_mut().spawn_empty().id();
+        let source = cell(&mut app, owner, 0, StockpileAcceptance::Any);
+        let neighbour = cell(&mut app, owner, 1, StockpileAcceptance::Any);
+        let destination = cell(&mut app, owner, 3, StockpileAcceptance::Any);
+        let second_source = cell(&mut app, owner, 6, StockpileAcceptance::Any);
+        let foreign = cell(&mut app, other_owner, 9, StockpileAcceptance::Any);
+
+        for (source_cell, destination_cell) in [
+            (source, neighbour),
+            (source, foreign),
+            (source, destination),
+            (second_source, destination),
+        ] {
+            app.world_mut()
+                .write_message(SupplyRouteChangeRequest::Create {
+                    source_cell,
+                    destination_cell,
+                });
+            app.update();
+        }
+
+        let receipts = &app.world().resource::<Receipts>().0;
+        assert_eq!(
+            receipts[0],
+            SupplyRouteChangeOutcome::Rejected(SupplyRouteRejection::SameCluster)
+        );
+        assert_eq!(
+            receipts[1],
+            SupplyRouteChangeOutcome::Rejected(SupplyRouteRejection::DifferentOwner)
+        );
+        assert!(matches!(
+            receipts[2],
+            SupplyRouteChangeOutcome::Created { .. }
+        ));
+        assert_eq!(
+            receipts[3],
+            SupplyRouteChangeOutcome::Rejected(SupplyRouteRejection::DestinationAlreadySupplied)
+        );
+    }
+
+    #[test]
+    fn items_moved_from_source_to_destination_count_as_route_throughput() {
+        let mut app = app();
+        let owner = app.world_mut().spawn_empty().id();
+        let source = cell(&mut app, owner, 0, StockpileAcceptance::Any);
+        let destination = cell(&mut app, owner, 3, StockpileAcceptance::Any);
+        let elsewhere = cell(&mut app, owner, 6, StockpileAcceptance::Any);
+        app.world_mut()
+            .write_message(SupplyRouteChangeRequest::Create {
+                source_cell: source,
+                destination_cell: destination,
+            });
+        app.update();
+        let (route, _) = routes(&mut app)[0].clone();
+
+        let delivered = app
+            .world_mut()
+            .spawn((ResourceItem(ResourceType::Wood), StoredIn(source)))
+            .id();
+        let diverted = app
+            .world_mut()
+            .spawn((ResourceItem(ResourceType::Wood), StoredIn(source)))
+            .id();
+        for item in [delivered, diverted] {
+            app.world_mut().entity_mut(item).remove::<StoredIn>();
+        }
+        app.world_mut()
+            .entity_mut(delivered)
+            .insert(StoredIn(destination));
+        app.world_mut()
+            .entity_mut(diverted)
+            .insert(StoredIn(elsewhere));
+
+        let stats = app
+            .world()
+            .get::<SupplyRouteStats>(route)
+            .expect("route stats");
+        assert_eq!(stats.delivered_total, 1);
+        assert!(app.world().get::<SupplyRouteCargo>(diverted).is_none());
+    }
+
+    #[test]
+    fn unstore_observer_tags_cargo_and_store_observer_counts_it_through_commands() {
+        let mut app = app();
+        let owner = app.world_mut().spawn_empty().id();
+        let source = cell(&mut app, owner, 0, StockpileAcceptance::Any);
+        let destination = cell(&mut app, owner, 3, StockpileAcceptance::Any);
+        app.world_mut()
+            .write_message(SupplyRouteChangeRequest::Create {
+                source_cell: source,
+                destination_cell: destination,
+            });
+        app.update();
+        let (route, _) = routes(&mut app)[0].clone();
+        let item = app
+            .world_mut()
+            .spawn((ResourceItem(ResourceType::Wood), StoredIn(source)))
+            .id();
+
+        app.world_mut().commands().entity(item).remove::<StoredIn>();
+        app.update();
+        assert_eq!(
+            app.world().get::<SupplyRouteCargo>(item),
+            Some(&SupplyRouteCargo(route))
+        );
+
+        app.world_mut()
+            .commands()
+            .entity(item)
+            .insert(StoredIn(destination));
+        app.update();
+        assert!(app.world().get::<SupplyRouteCargo>(item).is_none());
+        assert_eq!(
+            app.world()
+                .get::<SupplyRouteStats>(route)
+                .expect("route stats")
+                .delivered_total,
+            1
+        );
+    }
+
+    #[test]
+    fn throughput_window_forgets_old_deliveries() {
+        let mut stats = SupplyRouteStats::default();
+        stats.record_delivery(10.0);
+        stats.record_delivery(50.0);
+        stats.record_delivery(65.0);
+
+        assert_eq!(stats.deliveries_per_minute(65.0), 3);
+        assert_eq!(stats.deliveries_per_minute(80.0), 2);
+        assert_eq!(stats.delivered_total, 3);
+    }
+}
@@ -61,7 +61,7 @@ pub fn build_free_item_buckets(
         }
 
         let owner = q_belongs.get(entity).ok().map(|belongs| belongs.0);
-        let is_ground = q_stored_in.get(entity).is_err();
+        let stored_in = q_stored_in.get(entity).ok().map(|stored_in| stored_in.0);
 
         let snapshot_idx = snapshots.len();
         let snapshot = FreeItemSnapshot {
@@ -69,7 +69,7 @@ pub fn build_free_item_buckets(
             pos: transform.translation.truncate(),
             resource_type: resource_item.0,
             owner,
-            is_ground,
+            stored_in,
         };
         snapshots.push(snapshot);
 
@@ -77,7 +77,7 @@ pub fn build_free_item_buckets(
             .entry(snapshot.resource_type)
             .or_insert_with(Vec::new)
             .push(snapshot_idx);
-        if snapshot.is_ground {
+        if snapshot.stored_in.is_none() {
             by_resource_owner_ground
                 .entry((snapshot.resource_type, snapshot.owner))
                 .or_insert_with(Vec::new)
@@ -299,13 +299,44 @@ pub fn build_request_eval_context(
             (demand.remaining() as usize).min(WHEELBARROW_CAPACITY),
             ItemBucketKey::Resource(req.resource_type),
         ),
+        TransportRequestKind::SupplyRoute => {
+            let receiver_priority = receiver_policy_tier.map_or(req.priority, |tier| tier.0);
+            let Some(evaluation) = evaluate_stockpile_cell(
+                req.anchor,
+                req.resource_type,
+                receiver_priority,
+                q_stockpiles,
+                q_incoming,
+                q_resource_items,
+                None,
+            ) else {
+                return Err(WheelbarrowArbitrationOutcome::StaleInput);
+            };
+            if evaluation.available == 0 {
+                return Err(if evaluation.blocked_by_reservation {
+                    WheelbarrowArbitrationOutcome::CapacityReserved
+                } else {
+                    WheelbarrowArbitrationOutcome::NoDestinationCapacity
+                });
+            }
+            (
+                WheelbarrowDestination::Stockpile(req.anchor),
+                evaluation
+                    .available
+                    .min(demand.remaining() as usize)
+                    .min(WHEELBARROW_CAPACITY),
+                ItemBucketKey::StoredInCells(req.resource_type),
+            )
+        }
         _ => return Err(WheelbarrowArbitrationOutcome::NotApplicable),
     };
 
     let hard_min = if req.resource_type.requires_wheelbarrow()
         && matches!(
             req.kind,
-            TransportRequestKind::DeliverToBlueprint | TransportRequestKind::DeliverToMixerSolid
+            TransportRequestKind::DeliverToBlueprint
+                | TransportRequestKind::DeliverToMixerSolid
+                | TransportRequestKind::SupplyRoute
         ) {
         1
     } else {
@@ -464,21 +495,21 @@ mod tests {
                 pos: Vec2::new(1.0, 0.0),
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
             FreeItemSnapshot {
                 entity: available,
                 pos: Vec2::new(2.0, 0.0),
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
             FreeItemSnapshot {
                 entity: far_reserved,
                 pos: Vec2::new(100.0, 0.0),
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
         ];
         let mut cache = SharedResourceCache::default();
@@ -501,7 +532,7 @@ mod tests {
             pos: Vec2::new(100.0, 0.0),
             resource_type: ResourceType::Wood,
             owner: None,
-            is_ground: true,
+            stored_in: None,
         }];
         let mut cache = SharedResourceCache::default();
         cache.reserve_source(far_reserved, 1);
@@ -521,14 +552,14 @@ mod tests {
                 pos: Vec2::X,
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
             FreeItemSnapshot {
                 entity: entity(2),
                 pos: -Vec2::X,
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
         ];
 
@@ -88,16 +88,17 @@ struct SourceCandidateBuckets<'a> {
 }
 
 #[derive(Clone, Copy)]
-struct SourceCandidateSearch {
+struct SourceCandidateSearch<'a> {
     request_pos: Vec2,
     search_radius_sq: f32,
     top_k: usize,
+    source_cells: &'a [Entity],
 }
 
 fn collect_request_source_candidates(
     bucket_key: ItemBucketKey,
     buckets: SourceCandidateBuckets<'_>,
-    search: SourceCandidateSearch,
+    search: SourceCandidateSearch<'_>,
 ) -> (Vec<NearbyItem>, usize, usize) {
     match bucket_key {
         ItemBucketKey::Resource(resource_type) => {
@@ -158,6 +159,31 @@ fn collect_request_source_candidates(
                 exact_bucket.len().saturating_add(unowned_bucket.len()),
             )
         }
+        ItemBucketKey::StoredInCells(resource_type) => {
+            let bucket: Vec<usize> = buckets
+                .by_resource
+                .get(&resource_type)
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
+                .iter()
+                .copied()
+                .filter(|idx| {
+                    buckets.free_items[*idx]
+                        .stored_in
+                        .is_some_and(|cell| search.source_cells.contains(&cell))
+                })
+                .collect();
+            // 搬出元は request 位置から離れた別クラスタなので距離制限は掛けない。
+            let (items, reserved) = collect_top_k_unreserved_nearest(
+                &bucket,
+                buckets.free_items,
+                search.request_pos,
+                f32::INFINITY,
+                search.top_k,
+                buckets.cache,
+            );
+            (items, reserved, bucket.len())
+        }
     }
 }
 
@@ -272,6 +298,7 @@ pub(super) fn collect_candidates(
                     request_pos: eval.request_pos,
                     search_radius_sq,
                     top_k: WHEELBARROW_ARBITRATION_TOP_K,
+                    source_cells: &req.stockpile_group,
                 },
             );
         bucket_items_total = bucket_items_total.saturating_add(inspected_bucket_items as u32);
@@ -371,7 +398,12 @@ pub(super) fn collect_candidates(
                 items,
                 source_pos,
                 destination: eval.destination,
-                group_cells: req.stockpile_group,
+                // 補給ルートの stockpile_group は搬出元なので、搬入先は anchor 1 セルに固定する。
+                group_cells: if req.kind == TransportRequestKind::SupplyRoute {
+                    Vec::new()
+                } else {
+                    req.stockpile_group
+                },
                 resource_type: eval.resource_type,
                 receiver_priority: eval.receiver_priority,
                 hard_min: eval.hard_min,
@@ -437,14 +469,14 @@ mod tests {
                 pos: Vec2::ZERO,
                 resource_type: ResourceType::Wood,
                 owner: None,
-                is_ground: true,
+                stored_in: None,
             },
             FreeItemSnapshot {
                 entity: entity(2),
                 pos: Vec2::ZERO,
                 resource_type: ResourceType::Wood,
                 owner: Some(other_owner),
-                is_ground: true,
+                stored_in: None,
             },
         ];
         let by_resource = HashMap::from([(ResourceType::Wood, vec![0, 1])]);
@@ -468,9 +500,60 @@ mod tests {
                 request_pos: Vec2::ZERO,
                 search_radius_sq: f32::INFINITY,
                 top_k: 4,
+                source_cells: &[],
+            },
+        );
+
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].entity, entity(1));
+    }
+
+    #[test]
+    fn supply_route_bucket_only_takes_items_stored_in_route_sources() {
+        let source_cell = entity(50);
+        let other_cell = entity(51);
+        let free_items = vec![
+            FreeItemSnapshot {
+                entity: entity(1),
+                pos: Vec2::splat(1_000.0),
+                resource_type: ResourceType::Sand,
+                owner: None,
+                stored_in: Some(source_cell),
+            },
+            FreeItemSnapshot {
+                entity: entity(2),
+                pos: Vec2::ZERO,
+                resource_type: ResourceType::Sand,
+                owner: None,
+                stored_in: Some(other_cell),
+            },
+            FreeItemSnapshot {
+                entity: entity(3),
+                pos: Vec2::ZERO,
+                resource_type: ResourceType::Sand,
+                owner: None,
+                stored_in: None,
+            },
+        ];
+        let by_resource = HashMap::from([(ResourceType::Sand, vec![0, 1, 2])]);
+
+        let (selected, _, inspected) = collect_request_source_candidates(
+            ItemBucketKey::StoredInCells(ResourceType::Sand),
+            SourceCandidateBuckets {
+                free_items: &free_items,
+                by_resource: &by_resource,
+                by_resource_owner_ground: &HashMap::new(),
+                cache: &SharedResourceCache::default(),
+            },
+            SourceCandidateSearch {
+                request_pos: Vec2::ZERO,
+                search_radius_sq: 25.0,
+                top_k: 4,
+                source_cells: &[source_cell],
             },
         );
 
+        assert_eq!(inspected, 1);
         assert_eq!(selected.len(), 1);
         assert_eq!(selected[0].entity, entity(1));
     }
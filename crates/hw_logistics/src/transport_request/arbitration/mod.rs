@@ -47,6 +47,7 @@ pub fn is_wheelbarrow_arbitration_applicable(request: &TransportRequest) -> bool
         crate::transport_request::TransportRequestKind::DeliverToMixerSolid => {
             request.resource_type.requires_wheelbarrow()
         }
+        crate::transport_request::TransportRequestKind::SupplyRoute => true,
         _ => false,
     }
 }
@@ -27,7 +27,7 @@ pub(super) struct FreeItemSnapshot {
     pub pos: Vec2,
     pub resource_type: ResourceType,
     pub owner: Option<Entity>,
-    pub is_ground: bool,
+    pub stored_in: Option<Entity>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -37,6 +37,8 @@ pub(super) enum ItemBucketKey {
         resource_type: ResourceType,
         owner: Option<Entity>,
     },
+    /// request の `stockpile_group` に格納済みのアイテムのみ（補給ルート）
+    StoredInCells(ResourceType),
 }
 
 pub(super) struct RequestEvalContext {
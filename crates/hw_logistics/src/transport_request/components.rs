@@ -14,6 +14,18 @@ pub enum TransportPriority {
     Critical = 30,
 }
 
+impl TransportPriority {
+    /// UI 表示名の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Low => "ui.priority.low",
+            Self::Normal => "ui.priority.normal",
+            Self::High => "ui.priority.high",
+            Self::Critical => "ui.priority.critical",
+        }
+    }
+}
+
 /// Runtime carrier for the receiver policy tier that contributes to worker ranking.
 ///
 /// This is intentionally separate from `TransportRequest::priority`: request priority also
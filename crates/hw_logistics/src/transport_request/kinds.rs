@@ -15,4 +15,5 @@ pub enum TransportRequestKind {
     BatchWheelbarrow,
     ConsolidateStockpile,
     DeliverToSoulSpa,
+    SupplyRoute,
 }
@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use hw_core::system_sets::{FamiliarAiSystemSet, GameSystemSet, SoulAiSystemSet};
 
+use crate::supply_route::prune_supply_routes_system;
+
 use super::arbitration::{WheelbarrowArbitrationDiagnostics, WheelbarrowArbitrationRuntime};
 use super::producer::{
     active_unit_cache::{
@@ -22,6 +24,7 @@ use super::producer::{
         provisional_wall_auto_haul_system, provisional_wall_designation_system,
         provisional_wall_material_delivery_sync_system,
     },
+    supply_route::supply_route_producer_system,
     tank_water_request::tank_water_request_system,
     task_area::task_area_auto_haul_system,
     tile_wait_cache::{
@@ -127,6 +130,7 @@ impl Plugin for TransportRequestPlugin {
                 update_cached_stockpile_groups_system
                     .after(update_cached_active_yards_system)
                     .in_set(TransportRequestSet::Perceive),
+                prune_supply_routes_system.in_set(TransportRequestSet::Perceive),
                 (
                     blueprint_auto_haul_system,
                     bucket_auto_haul_system,
@@ -146,6 +150,7 @@ impl Plugin for TransportRequestPlugin {
                     wall_tile_designation_system.after(wall_material_delivery_sync_system),
                     wheelbarrow_auto_haul_system,
                     stockpile_consolidation_producer_system.after(task_area_auto_haul_system),
+                    supply_route_producer_system.after(stockpile_consolidation_producer_system),
                 )
                     .in_set(TransportRequestSet::Decide),
                 wheelbarrow_arbitration_system.in_set(TransportRequestSet::Arbitrate),
@@ -39,6 +39,9 @@ type ActiveYardDirtyQuery<'w, 's> = Query<'w, 's, (), Or<(Added<Yard>, Changed<Y
 
 /// アクティブな Familiar（Idle 以外）のリスト。
 ///
+/// 複数矩形の TaskArea は部分矩形ごとに 1 要素へ展開するため、同じ Entity が
+/// 複数回現れる。`find_owner` などの包含判定はそのまま和集合として働く。
+///
 /// Entity を保持するため load reset で既定値へ戻す。通常時は source component
 /// の Added / Changed / Removed がない限り再構築しない。
 #[derive(Resource, Default)]
@@ -98,7 +101,7 @@ pub fn update_cached_active_familiars_system(
         q_familiars
             .iter()
             .filter(|(_, ac, _)| !matches!(ac.command, FamiliarCommand::Idle))
-            .map(|(entity, _, area)| (entity, area.bounds())),
+            .flat_map(|(entity, _, area)| area.rects().map(move |rect| (entity, rect.clone()))),
     );
     cache.initialized = true;
 }
@@ -10,28 +10,31 @@ use crate::SharedResourceCache;
 use crate::stockpile_policy::{
     StockpilePolicyInput, StockpileTransferPhase, evaluate_stockpile_policy,
 };
+use crate::supply_route::SupplyRoute;
 use crate::transport_request::producer::active_unit_cache::CachedStockpileGroups;
 use crate::transport_request::{
     ManualTransportRequest, ReceiverPolicyTier, TransportDemand, TransportPolicy,
     TransportPriority, TransportRequest, TransportRequestKind, TransportRequestState,
 };
 use crate::types::{BelongsTo, ResourceItem, ResourceType};
-use crate::zone::{Stockpile, StockpilePolicy};
+use crate::zone::{Stockpile, StockpilePolicy, StockpileResourceSet};
 
 #[derive(Clone)]
-struct CellInfo {
-    entity: Entity,
-    pos: Vec2,
-    stockpile: Stockpile,
-    policy: StockpilePolicy,
-    stored: usize,
-    available_sources: usize,
-    incoming_reserved: usize,
-    incoming_by_resource: HashMap<ResourceType, usize>,
+pub(super) struct CellInfo {
+    pub(super) entity: Entity,
+    pub(super) pos: Vec2,
+    pub(super) stockpile: Stockpile,
+    pub(super) policy: StockpilePolicy,
+    pub(super) stored: usize,
+    pub(super) available_sources: usize,
+    pub(super) incoming_reserved: usize,
+    pub(super) incoming_by_resource: HashMap<ResourceType, usize>,
+    /// 補給ルートがこのセルに保持する資源。統合の搬出元にはしない。
+    pub(super) route_protected: StockpileResourceSet,
 }
 
 impl CellInfo {
-    fn incoming_matching(&self, resource_type: ResourceType) -> usize {
+    pub(super) fn incoming_matching(&self, resource_type: ResourceType) -> usize {
         self.incoming_by_resource
             .get(&resource_type)
             .copied()
@@ -39,13 +42,13 @@ impl CellInfo {
     }
 }
 
+/// 自動管理されるセル間搬送 request の目標状態（anchor と資源でキー付け）。
 #[derive(Clone)]
-struct DesiredConsolidationRequest {
-    issued_by: Entity,
-    donor_cells: Vec<Entity>,
-    new_assignable: usize,
-    pos: Vec2,
-    receiver_priority: TransportPriority,
+pub(super) struct DesiredStockpileRequest {
+    pub(super) request: TransportRequest,
+    pub(super) new_assignable: usize,
+    pub(super) pos: Vec2,
+    pub(super) receiver_priority: TransportPriority,
 }
 
 struct ConsolidationTransfer {
@@ -56,7 +59,7 @@ struct ConsolidationTransfer {
     receiver_priority: TransportPriority,
 }
 
-type ConsolidationStockpileQuery<'w, 's> = Query<
+pub(super) type StockpileCellQuery<'w, 's> = Query<
     'w,
     's,
     (
@@ -70,7 +73,7 @@ type ConsolidationStockpileQuery<'w, 's> = Query<
     ),
 >;
 
-type ConsolidationResourceQuery<'w, 's> =
+pub(super) type StoredResourceQuery<'w, 's> =
     Query<'w, 's, (Entity, &'static ResourceItem, Option<&'static StoredIn>)>;
 
 type ExistingRequestRuntime<'a> = (
@@ -86,7 +89,7 @@ type ExistingRequestRuntime<'a> = (
     Option<&'a TransportPolicy>,
 );
 
-type ExistingConsolidationRequestQuery<'w, 's> = Query<
+pub(super) type ExistingStockpileRequestQuery<'w, 's> = Query<
     'w,
     's,
     (
@@ -98,11 +101,11 @@ type ExistingConsolidationRequestQuery<'w, 's> = Query<
     Without<ManualTransportRequest>,
 >;
 
-fn entity_sort_key(entity: Entity) -> (u32, u32) {
+pub(super) fn entity_sort_key(entity: Entity) -> (u32, u32) {
     (entity.index_u32(), entity.generation().to_bits())
 }
 
-const fn resource_sort_key(resource_type: ResourceType) -> u8 {
+pub(super) const fn resource_sort_key(resource_type: ResourceType) -> u8 {
     match resource_type {
         ResourceType::Wood => 0,
         ResourceType::Rock => 1,
@@ -116,7 +119,7 @@ const fn resource_sort_key(resource_type: ResourceType) -> u8 {
     }
 }
 
-fn receiver_available(
+pub(super) fn receiver_available(
     cell: &CellInfo,
     resource_type: ResourceType,
     cycle_reservations: &HashMap<Entity, HashMap<ResourceType, usize>>,
@@ -145,7 +148,7 @@ fn receiver_available(
     .available_amount
 }
 
-fn donor_available(cell: &CellInfo, resource_type: ResourceType) -> usize {
+pub(super) fn donor_available(cell: &CellInfo, resource_type: ResourceType) -> usize {
     evaluate_stockpile_policy(StockpilePolicyInput {
         phase: StockpileTransferPhase::NewOutbound,
         policy: cell.policy,
@@ -188,6 +191,7 @@ fn choose_transfer(
             .iter()
             .copied()
             .filter(|cell| cell.entity != receiver.entity)
+            .filter(|cell| !cell.route_protected.contains(resource_type))
             .filter(|cell| {
                 let available = donor_available(cell, resource_type);
                 available > 0 && available == cell.stored
@@ -255,8 +259,7 @@ fn upsert_active_request(
     entity: Entity,
     current_request: &TransportRequest,
     current: ExistingRequestRuntime<'_>,
-    key: (Entity, ResourceType),
-    desired: &DesiredConsolidationRequest,
+    desired: &DesiredStockpileRequest,
     workers: usize,
 ) {
     let (
@@ -274,14 +277,7 @@ fn upsert_active_request(
     let desired_slots = super::to_u32_saturating(workers.saturating_add(desired.new_assignable));
     let inflight = super::to_u32_saturating(workers);
     let desired_transform = Transform::from_xyz(desired.pos.x, desired.pos.y, 0.0);
-    let desired_request = TransportRequest {
-        kind: TransportRequestKind::ConsolidateStockpile,
-        anchor: key.0,
-        resource_type: key.1,
-        issued_by: desired.issued_by,
-        priority: TransportPriority::Low,
-        stockpile_group: desired.donor_cells.clone(),
-    };
+    let desired_request = &desired.request;
     let desired_state = super::upsert::request_state_for_workers(workers);
     let mut entity_commands = commands.entity(entity);
 
@@ -300,8 +296,8 @@ fn upsert_active_request(
             work_type: WorkType::Haul,
         });
     }
-    if managed_by.is_none_or(|current| current.0 != desired.issued_by) {
-        entity_commands.try_insert(ManagedBy(desired.issued_by));
+    if managed_by.is_none_or(|current| current.0 != desired_request.issued_by) {
+        entity_commands.try_insert(ManagedBy(desired_request.issued_by));
     }
     if slots.is_none_or(|current| current.max != desired_slots) {
         entity_commands.try_insert(TaskSlots::new(desired_slots));
@@ -309,8 +305,8 @@ fn upsert_active_request(
     if priority.is_none_or(|current| current.0 != 0) {
         entity_commands.try_insert(Priority(0));
     }
-    if !request_matches(current_request, &desired_request) {
-        entity_commands.try_insert(desired_request);
+    if !request_matches(current_request, desired_request) {
+        entity_commands.try_insert(desired_request.clone());
     }
     if receiver_tier.is_none_or(|current| current.0 != desired.receiver_priority) {
         entity_commands.try_insert(ReceiverPolicyTier(desired.receiver_priority));
@@ -382,14 +378,11 @@ fn disable_workerless_request(
     }
 }
 
-pub fn stockpile_consolidation_producer_system(
-    mut commands: Commands,
-    stockpile_groups_cache: Res<CachedStockpileGroups>,
-    resource_cache: Res<SharedResourceCache>,
-    q_stockpiles: ConsolidationStockpileQuery,
-    q_resource_items: ConsolidationResourceQuery,
-    q_existing_requests: ExistingConsolidationRequestQuery,
-) {
+/// 予約されていない格納済みアイテム数を (セル, 資源) ごとに数える。
+pub(super) fn count_available_sources(
+    resource_cache: &SharedResourceCache,
+    q_resource_items: &StoredResourceQuery,
+) -> HashMap<(Entity, ResourceType), usize> {
     let mut available_sources = HashMap::<(Entity, ResourceType), usize>::new();
     for (entity, resource, stored_in) in q_resource_items.iter() {
         let Some(stored_in) = stored_in else {
@@ -401,6 +394,150 @@ pub fn stockpile_consolidation_producer_system(
                 .or_insert(0) += 1;
         }
     }
+    available_sources
+}
+
+/// 管理セルの現在量・搬入予定を集約する。所有 Yard が `owner` と異なるセルは除外する。
+pub(super) fn read_cell_info(
+    cell: Entity,
+    owner: Entity,
+    q_stockpiles: &StockpileCellQuery,
+    q_resource_items: &StoredResourceQuery,
+    available_sources: &HashMap<(Entity, ResourceType), usize>,
+) -> Option<CellInfo> {
+    let (entity, transform, stockpile, policy, stored, incoming, belongs_to) =
+        q_stockpiles.get(cell).ok()?;
+    if belongs_to.map(|belongs_to| belongs_to.0) != Some(owner) {
+        return None;
+    }
+
+    let mut incoming_by_resource = HashMap::new();
+    if let Some(incoming) = incoming {
+        for item in incoming.iter() {
+            let Ok((_, resource, _)) = q_resource_items.get(*item) else {
+                continue;
+            };
+            *incoming_by_resource.entry(resource.0).or_insert(0) += 1;
+        }
+    }
+    let cell_resource = stockpile.resource_type;
+    Some(CellInfo {
+        entity,
+        pos: transform.translation.truncate(),
+        stockpile: *stockpile,
+        policy: *policy,
+        stored: stored.map_or(0, StoredItems::len),
+        available_sources: cell_resource
+            .and_then(|resource| available_sources.get(&(entity, resource)).copied())
+            .unwrap_or(0),
+        incoming_reserved: incoming.map_or(0, IncomingDeliveries::len),
+        incoming_by_resource,
+        route_protected: StockpileResourceSet::empty(),
+    })
+}
+
+/// `kind` の既存 request を desired 集合へ収束させ、不足分を spawn する。
+///
+/// 同一キーの重複は 1 件へ正規化し、不要になった request は作業中なら committed 分へ縮退、
+/// 作業者がいなければ無効化する。
+pub(super) fn reconcile_stockpile_requests(
+    commands: &mut Commands,
+    kind: TransportRequestKind,
+    name: &'static str,
+    desired_requests: HashMap<(Entity, ResourceType), DesiredStockpileRequest>,
+    q_existing_requests: &ExistingStockpileRequestQuery,
+) {
+    let mut canonical = HashMap::<(Entity, ResourceType), (Entity, usize)>::new();
+    for (entity, request, workers, _) in q_existing_requests.iter() {
+        if request.kind != kind {
+            continue;
+        }
+        let workers = workers.map_or(0, TaskWorkers::len);
+        canonical
+            .entry((request.anchor, request.resource_type))
+            .and_modify(|current| *current = prefer_canonical((entity, workers), *current))
+            .or_insert((entity, workers));
+    }
+
+    for (entity, request, workers, current) in q_existing_requests.iter() {
+        if request.kind != kind {
+            continue;
+        }
+        let key = (request.anchor, request.resource_type);
+        let workers = workers.map_or(0, TaskWorkers::len);
+        let is_canonical = canonical.get(&key).is_some_and(|(kept, _)| *kept == entity);
+        if !is_canonical {
+            if workers == 0 {
+                commands.entity(entity).try_despawn();
+            } else {
+                cap_committed_request(commands, entity, workers, current);
+            }
+            continue;
+        }
+
+        if let Some(desired) = desired_requests.get(&key) {
+            upsert_active_request(commands, entity, request, current, desired, workers);
+        } else if workers == 0 {
+            disable_workerless_request(commands, entity, current);
+        } else {
+            cap_committed_request(commands, entity, workers, current);
+        }
+    }
+
+    let mut missing_requests: Vec<_> = desired_requests
+        .into_iter()
+        .filter(|(key, _)| !canonical.contains_key(key))
+        .collect();
+    missing_requests.sort_unstable_by(|(left_key, left), (right_key, right)| {
+        left.pos
+            .x
+            .total_cmp(&right.pos.x)
+            .then_with(|| left.pos.y.total_cmp(&right.pos.y))
+            .then_with(|| resource_sort_key(left_key.1).cmp(&resource_sort_key(right_key.1)))
+            .then_with(|| entity_sort_key(left_key.0).cmp(&entity_sort_key(right_key.0)))
+    });
+    for (_, desired) in missing_requests {
+        let desired_slots = super::to_u32_saturating(desired.new_assignable);
+        commands.spawn((
+            Name::new(name),
+            Transform::from_xyz(desired.pos.x, desired.pos.y, 0.0),
+            Visibility::Hidden,
+            Designation {
+                work_type: WorkType::Haul,
+            },
+            ManagedBy(desired.request.issued_by),
+            TaskSlots::new(desired_slots),
+            Priority(0),
+            desired.request,
+            ReceiverPolicyTier(desired.receiver_priority),
+            TransportDemand {
+                desired_slots,
+                inflight: 0,
+            },
+            TransportRequestState::Pending,
+            TransportPolicy::default(),
+        ));
+    }
+}
+
+pub fn stockpile_consolidation_producer_system(
+    mut commands: Commands,
+    stockpile_groups_cache: Res<CachedStockpileGroups>,
+    resource_cache: Res<SharedResourceCache>,
+    q_stockpiles: StockpileCellQuery,
+    q_resource_items: StoredResourceQuery,
+    q_routes: Query<&SupplyRoute>,
+    q_existing_requests: ExistingStockpileRequestQuery,
+) {
+    let available_sources = count_available_sources(&resource_cache, &q_resource_items);
+
+    // 補給ルートの搬入先は、そのルートが扱う資源について統合の搬出元にしない。
+    let mut route_protected = HashMap::<Entity, StockpileResourceSet>::new();
+    for route in q_routes.iter() {
+        for cell in &route.destination_cells {
+            route_protected.insert(*cell, route.resources);
+        }
+    }
 
     let mut group_indices: Vec<usize> = (0..stockpile_groups_cache.groups.len()).collect();
     group_indices.sort_unstable_by(|left, right| {
@@ -423,46 +560,30 @@ pub fn stockpile_consolidation_producer_system(
             })
     });
 
-    let mut desired_requests =
-        HashMap::<(Entity, ResourceType), DesiredConsolidationRequest>::new();
+    let mut desired_requests = HashMap::<(Entity, ResourceType), DesiredStockpileRequest>::new();
     let mut receiver_cycle_reservations = HashMap::<Entity, HashMap<ResourceType, usize>>::new();
 
     for group_idx in group_indices {
         let group = &stockpile_groups_cache.groups[group_idx];
-        let mut cells = Vec::<CellInfo>::new();
-        for &cell in &group.cells {
-            let Ok((entity, transform, stockpile, policy, stored, incoming, owner)) =
-                q_stockpiles.get(cell)
-            else {
-                continue;
-            };
-            if owner.map(|owner| owner.0) != Some(group.owner_yard) {
-                continue;
-            }
-
-            let mut incoming_by_resource = HashMap::new();
-            if let Some(incoming) = incoming {
-                for item in incoming.iter() {
-                    let Ok((_, resource, _)) = q_resource_items.get(*item) else {
-                        continue;
-                    };
-                    *incoming_by_resource.entry(resource.0).or_insert(0) += 1;
+        let cells: Vec<CellInfo> = group
+            .cells
+            .iter()
+            .filter_map(|cell| {
+                read_cell_info(
+                    *cell,
+                    group.owner_yard,
+                    &q_stockpiles,
+                    &q_resource_items,
+                    &available_sources,
+                )
+            })
+            .map(|mut cell| {
+                if let Some(protected) = route_protected.get(&cell.entity) {
+                    cell.route_protected = *protected;
                 }
-            }
-            let cell_resource = stockpile.resource_type;
-            cells.push(CellInfo {
-                entity,
-                pos: transform.translation.truncate(),
-                stockpile: *stockpile,
-                policy: *policy,
-                stored: stored.map_or(0, StoredItems::len),
-                available_sources: cell_resource
-                    .and_then(|resource| available_sources.get(&(entity, resource)).copied())
-                    .unwrap_or(0),
-                incoming_reserved: incoming.map_or(0, IncomingDeliveries::len),
-                incoming_by_resource,
-            });
-        }
+                cell
+            })
+            .collect();
 
         let mut by_type = HashMap::<ResourceType, Vec<&CellInfo>>::new();
         for cell in &cells {
@@ -500,9 +621,15 @@ pub fn stockpile_consolidation_producer_system(
                 .or_insert(transfer.amount);
             desired_requests.insert(
                 key,
-                DesiredConsolidationRequest {
-                    issued_by: group.owner_yard,
-                    donor_cells: transfer.donor_cells,
+                DesiredStockpileRequest {
+                    request: TransportRequest {
+                        kind: TransportRequestKind::ConsolidateStockpile,
+                        anchor: transfer.receiver,
+                        resource_type,
+                        issued_by: group.owner_yard,
+                        priority: TransportPriority::Low,
+                        stockpile_group: transfer.donor_cells,
+                    },
                     new_assignable: transfer.amount,
                     pos: transfer.pos,
                     receiver_priority: transfer.receiver_priority,
@@ -511,92 +638,13 @@ pub fn stockpile_consolidation_producer_system(
         }
     }
 
-    let mut canonical = HashMap::<(Entity, ResourceType), (Entity, usize)>::new();
-    for (entity, request, workers, _) in q_existing_requests.iter() {
-        if request.kind != TransportRequestKind::ConsolidateStockpile {
-            continue;
-        }
-        let workers = workers.map_or(0, TaskWorkers::len);
-        canonical
-            .entry((request.anchor, request.resource_type))
-            .and_modify(|current| *current = prefer_canonical((entity, workers), *current))
-            .or_insert((entity, workers));
-    }
-
-    for (entity, request, workers, current) in q_existing_requests.iter() {
-        if request.kind != TransportRequestKind::ConsolidateStockpile {
-            continue;
-        }
-        let key = (request.anchor, request.resource_type);
-        let workers = workers.map_or(0, TaskWorkers::len);
-        let is_canonical = canonical.get(&key).is_some_and(|(kept, _)| *kept == entity);
-        if !is_canonical {
-            if workers == 0 {
-                commands.entity(entity).try_despawn();
-            } else {
-                cap_committed_request(&mut commands, entity, workers, current);
-            }
-            continue;
-        }
-
-        if let Some(desired) = desired_requests.get(&key) {
-            upsert_active_request(
-                &mut commands,
-                entity,
-                request,
-                current,
-                key,
-                desired,
-                workers,
-            );
-        } else if workers == 0 {
-            disable_workerless_request(&mut commands, entity, current);
-        } else {
-            cap_committed_request(&mut commands, entity, workers, current);
-        }
-    }
-
-    let mut missing_requests: Vec<_> = desired_requests
-        .into_iter()
-        .filter(|(key, _)| !canonical.contains_key(key))
-        .collect();
-    missing_requests.sort_unstable_by(|(left_key, left), (right_key, right)| {
-        left.pos
-            .x
-            .total_cmp(&right.pos.x)
-            .then_with(|| left.pos.y.total_cmp(&right.pos.y))
-            .then_with(|| resource_sort_key(left_key.1).cmp(&resource_sort_key(right_key.1)))
-            .then_with(|| entity_sort_key(left_key.0).cmp(&entity_sort_key(right_key.0)))
-    });
-    for (key, desired) in missing_requests {
-        let desired_slots = super::to_u32_saturating(desired.new_assignable);
-        commands.spawn((
-            Name::new("TransportRequest::ConsolidateStockpile"),
-            Transform::from_xyz(desired.pos.x, desired.pos.y, 0.0),
-            Visibility::Hidden,
-            Designation {
-                work_type: WorkType::Haul,
-            },
-            ManagedBy(desired.issued_by),
-            TaskSlots::new(desired_slots),
-            Priority(0),
-            TransportRequest {
-                kind: TransportRequestKind::ConsolidateStockpile,
-                anchor: key.0,
-                resource_type: key.1,
-                issued_by: desired.issued_by,
-                priority: TransportPriority::Low,
-                stockpile_group: desired.donor_cells,
-            },
-            ReceiverPolicyTier(desired.receiver_priority),
-            TransportDemand {
-                desired_slots,
-                inflight: 0,
-            },
-            TransportRequestState::Pending,
-            TransportPolicy::default(),
-        ));
-    }
+    reconcile_stockpile_requests(
+        &mut commands,
+        TransportRequestKind::ConsolidateStockpile,
+        "TransportRequest::ConsolidateStockpile",
+        desired_requests,
+        &q_existing_requests,
+    );
 }
 
 #[cfg(test)]
@@ -623,6 +671,7 @@ mod tests {
             available_sources: stored,
             incoming_reserved: incoming,
             incoming_by_resource: HashMap::from([(ResourceType::Wood, incoming)]),
+            route_protected: StockpileResourceSet::empty(),
         }
     }
 
@@ -7,6 +7,7 @@ pub mod mixer;
 pub mod mixer_helpers;
 pub mod provisional_wall;
 pub mod stockpile_group;
+pub mod supply_route;
 pub mod tank_water_request;
 pub mod task_area;
 pub mod tile_wait_cache;
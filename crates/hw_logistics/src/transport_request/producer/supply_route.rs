@@ -0,0 +1,276 @@
+//! Supply route producer.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::SharedResourceCache;
+use crate::supply_route::SupplyRoute;
+use crate::transport_request::{TransportPriority, TransportRequest, TransportRequestKind};
+use crate::types::ResourceType;
+use crate::zone::STOCKPILE_ACCEPTANCE_RESOURCES;
+
+use super::consolidation::{
+    CellInfo, DesiredStockpileRequest, ExistingStockpileRequestQuery, StockpileCellQuery,
+    StoredResourceQuery, count_available_sources, donor_available, entity_sort_key, read_cell_info,
+    receiver_available, reconcile_stockpile_requests,
+};
+
+/// 搬入先クラスタ内で `resource_type` の現在量＋搬入予定＋今サイクルの割当を数える。
+fn destination_level(
+    destinations: &[CellInfo],
+    resource_type: ResourceType,
+    cycle_reservations: &HashMap<Entity, HashMap<ResourceType, usize>>,
+) -> usize {
+    destinations
+        .iter()
+        .map(|cell| {
+            let stored = if cell.stockpile.resource_type == Some(resource_type) {
+                cell.stored
+            } else {
+                0
+            };
+            let cycle = cycle_reservations
+                .get(&cell.entity)
+                .and_then(|counts| counts.get(&resource_type))
+                .copied()
+                .unwrap_or(0);
+            stored + cell.incoming_matching(resource_type) + cycle
+        })
+        .sum()
+}
+
+/// 同じ資源を既に保持しているセルを優先し、次に空きの多いセルを選ぶ。
+fn choose_anchor<'a>(
+    destinations: &'a [CellInfo],
+    resource_type: ResourceType,
+    cycle_reservations: &HashMap<Entity, HashMap<ResourceType, usize>>,
+) -> Option<(&'a CellInfo, usize)> {
+    destinations
+        .iter()
+        .filter_map(|cell| {
+            let available = receiver_available(cell, resource_type, cycle_reservations);
+            (available > 0).then_some((cell, available))
+        })
+        .min_by(|(left, left_available), (right, right_available)| {
+            let left_holds = left.stockpile.resource_type == Some(resource_type);
+            let right_holds = right.stockpile.resource_type == Some(resource_type);
+            right_holds
+                .cmp(&left_holds)
+                .then_with(|| right.stored.cmp(&left.stored))
+                .then_with(|| right_available.cmp(left_available))
+                .then_with(|| left.pos.x.total_cmp(&right.pos.x))
+                .then_with(|| left.pos.y.total_cmp(&right.pos.y))
+                .then_with(|| entity_sort_key(left.entity).cmp(&entity_sort_key(right.entity)))
+        })
+}
+
+/// ルートの目標量と現在量の差分を `SupplyRoute` request として発行する。
+///
+/// anchor は搬入先セル、`stockpile_group` は搬出可能な搬出元セル。
+/// 搬出元は `allow_export` と draining 判定に従い、部分的な搬出も許す。
+pub fn supply_route_producer_system(
+    mut commands: Commands,
+    resource_cache: Res<SharedResourceCache>,
+    q_routes: Query<(Entity, &SupplyRoute)>,
+    q_stockpiles: StockpileCellQuery,
+    q_resource_items: StoredResourceQuery,
+    q_existing_requests: ExistingStockpileRequestQuery,
+) {
+    let available_sources = count_available_sources(&resource_cache, &q_resource_items);
+    let mut routes: Vec<(Entity, &SupplyRoute)> = q_routes.iter().collect();
+    routes.sort_unstable_by_key(|(entity, _)| entity_sort_key(*entity));
+
+    let mut desired_requests = HashMap::<(Entity, ResourceType), DesiredStockpileRequest>::new();
+    let mut receiver_cycle_reservations = HashMap::<Entity, HashMap<ResourceType, usize>>::new();
+
+    for (_, route) in routes {
+        let Some(owner) = route
+            .destination_cells
+            .iter()
+            .find_map(|cell| q_stockpiles.get(*cell).ok().and_then(|(.., owner)| owner))
+            .map(|belongs_to| belongs_to.0)
+        else {
+            continue;
+        };
+        let read = |cell: &Entity| {
+            read_cell_info(
+                *cell,
+                owner,
+                &q_stockpiles,
+                &q_resource_items,
+                &available_sources,
+            )
+        };
+        let destinations: Vec<CellInfo> = route.destination_cells.iter().filter_map(read).collect();
+        let sources: Vec<CellInfo> = route
+            .source_cells
+            .iter()
+            .filter(|cell| !route.destination_cells.contains(*cell))
+            .filter_map(read)
+            .collect();
+        if destinations.is_empty() || sources.is_empty() {
+            continue;
+        }
+
+        for resource_type in STOCKPILE_ACCEPTANCE_RESOURCES
+            .into_iter()
+            .filter(|resource_type| route.resources.contains(*resource_type))
+        {
+            let level =
+                destination_level(&destinations, resource_type, &receiver_cycle_reservations);
+            let deficit = route.target_amount.saturating_sub(level);
+            if deficit == 0 {
+                continue;
+            }
+
+            let donors: Vec<(Entity, usize)> = sources
+                .iter()
+                .filter(|cell| cell.stockpile.resource_type == Some(resource_type))
+                .map(|cell| (cell.entity, donor_available(cell, resource_type)))
+                .filter(|(_, available)| *available > 0)
+                .collect();
+            let donor_total = donors
+                .iter()
+                .map(|(_, available)| *available)
+                .sum::<usize>();
+            if donor_total == 0 {
+                continue;
+            }
+
+            let Some((anchor, anchor_available)) =
+                choose_anchor(&destinations, resource_type, &receiver_cycle_reservations)
+            else {
+                continue;
+            };
+            let key = (anchor.entity, resource_type);
+            if desired_requests.contains_key(&key) {
+                continue;
+            }
+            let amount = deficit.min(anchor_available).min(donor_total);
+
+            receiver_cycle_reservations
+                .entry(anchor.entity)
+                .or_default()
+                .entry(resource_type)
+                .and_modify(|reserved| *reserved = reserved.saturating_add(amount))
+                .or_insert(amount);
+            desired_requests.insert(
+                key,
+                DesiredStockpileRequest {
+                    request: TransportRequest {
+                        kind: TransportRequestKind::SupplyRoute,
+                        anchor: anchor.entity,
+                        resource_type,
+                        issued_by: owner,
+                        priority: TransportPriority::Normal,
+                        stockpile_group: donors.iter().map(|(cell, _)| *cell).collect(),
+                    },
+                    new_assignable: amount,
+                    pos: anchor.pos,
+                    receiver_priority: anchor.policy.inbound_priority,
+                },
+            );
+        }
+    }
+
+    reconcile_stockpile_requests(
+        &mut commands,
+        TransportRequestKind::SupplyRoute,
+        "TransportRequest::SupplyRoute",
+        desired_requests,
+        &q_existing_requests,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BelongsTo, ResourceItem};
+    use crate::zone::{Stockpile, StockpilePolicy, StockpileResourceSet};
+    use hw_core::relationships::StoredIn;
+
+    fn cell(app: &mut App, owner: Entity, x: f32, policy: StockpilePolicy) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Stockpile {
+                    capacity: 10,
+                    resource_type: None,
+                },
+                policy,
+                BelongsTo(owner),
+            ))
+            .id()
+    }
+
+    fn store(app: &mut App, cell: Entity, resource_type: ResourceType, amount: usize) {
+        app.world_mut()
+            .get_mut::<Stockpile>(cell)
+            .expect("stockpile")
+            .resource_type = Some(resource_type);
+        for _ in 0..amount {
+            app.world_mut()
+                .spawn((ResourceItem(resource_type), StoredIn(cell)));
+        }
+    }
+
+    fn route_requests(world: &mut World) -> Vec<TransportRequest> {
+        let mut query = world.query::<&TransportRequest>();
+        query
+            .iter(world)
+            .filter(|request| request.kind == TransportRequestKind::SupplyRoute)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn deficit_is_clamped_by_target_and_exportable_source_stock() {
+        let mut app = App::new();
+        app.init_resource::<SharedResourceCache>()
+            .add_systems(Update, supply_route_producer_system);
+        let owner = app.world_mut().spawn_empty().id();
+        let source = cell(&mut app, owner, 0.0, StockpilePolicy::for_capacity(10));
+        let locked_source = cell(
+            &mut app,
+            owner,
+            1.0,
+            StockpilePolicy {
+                allow_export: false,
+                ..StockpilePolicy::for_capacity(10)
+            },
+        );
+        let destination = cell(&mut app, owner, 5.0, StockpilePolicy::for_capacity(10));
+        store(&mut app, source, ResourceType::Sand, 8);
+        store(&mut app, locked_source, ResourceType::Sand, 8);
+        store(&mut app, destination, ResourceType::Sand, 2);
+        app.world_mut().spawn(SupplyRoute {
+            source_cells: vec![source, locked_source],
+            destination_cells: vec![destination],
+            resources: StockpileResourceSet::empty()
+                .with(ResourceType::Sand, true)
+                .with(ResourceType::Rock, true),
+            target_amount: 6,
+        });
+
+        app.update();
+
+        let requests = route_requests(app.world_mut());
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.anchor, destination);
+        assert_eq!(request.resource_type, ResourceType::Sand);
+        assert_eq!(request.issued_by, owner);
+        assert_eq!(request.stockpile_group, vec![source]);
+
+        let mut demand = app.world_mut().query::<(
+            &TransportRequest,
+            &crate::transport_request::TransportDemand,
+        )>();
+        let (_, demand) = demand
+            .iter(app.world())
+            .find(|(request, _)| request.kind == TransportRequestKind::SupplyRoute)
+            .expect("route request demand");
+        assert_eq!(demand.desired_slots, 4);
+    }
+}
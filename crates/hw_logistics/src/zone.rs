@@ -7,6 +7,8 @@ use crate::types::ResourceType;
 pub enum ZoneType {
     Stockpile,
     Yard,
+    /// Soul の立ち入りを禁じる区域（割り当て作業の目的地を除く）
+    Forbidden,
 }
 
 #[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
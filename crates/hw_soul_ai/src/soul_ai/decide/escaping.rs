@@ -117,6 +117,7 @@ pub(crate) fn escaping_decision_system(
                 idle_state.behavior,
                 IdleBehavior::Escaping
                     | IdleBehavior::Drifting
+                    | IdleBehavior::Redeeming
                     | IdleBehavior::Resting
                     | IdleBehavior::GoingToRest
             ) {
@@ -220,8 +220,10 @@ pub(crate) fn gathering_recruitment_decision(params: GatheringRecruitmentParams)
                     if dist_to_spot > ESCAPE_GATHERING_JOIN_RADIUS || !spot_is_safe_for_escape {
                         continue;
                     }
-                } else if idle.behavior == IdleBehavior::Drifting
-                    || dist_to_spot > GATHERING_DETECTION_RADIUS
+                } else if matches!(
+                    idle.behavior,
+                    IdleBehavior::Drifting | IdleBehavior::Redeeming
+                ) || dist_to_spot > GATHERING_DETECTION_RADIUS
                 {
                     continue;
                 }
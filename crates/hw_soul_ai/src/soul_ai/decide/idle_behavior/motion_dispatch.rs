@@ -13,7 +13,7 @@ use hw_core::simulation_rng::{FixedAuditSeed, SimulationRandomState, SimulationR
 use hw_core::soul::{Destination, GatheringBehavior, IdleBehavior, IdleState, Path};
 use hw_world::coords::grid_to_world;
 use hw_world::coords::world_to_grid;
-use hw_world::{SpatialGridOps, WorldMap};
+use hw_world::{PathWorld, SpatialGridOps, WorldMap};
 
 /// `update_motion_destinations` に渡すエンティティ位置情報。
 pub struct SoulPos {
@@ -71,6 +71,8 @@ pub fn update_motion_destinations(
 ) {
     let entity = soul_pos.entity;
     let current_pos = soul_pos.pos;
+    // 怠惰行動の目的地は禁止区域に置かない
+    let world_map = &world_map.avoiding_forbidden();
     #[cfg(feature = "profiling")]
     let MotionExtras {
         dt,
@@ -340,14 +342,15 @@ pub fn update_motion_destinations(
         | IdleBehavior::Resting
         | IdleBehavior::GoingToRest
         | IdleBehavior::Escaping
-        | IdleBehavior::Drifting => {}
+        | IdleBehavior::Drifting
+        | IdleBehavior::Redeeming => {}
     }
 }
 
 fn set_wandering_destination(
     current_pos: Vec2,
     destination: &mut Destination,
-    world_map: &WorldMap,
+    world_map: &impl PathWorld,
     rng: &mut impl Rng,
 ) {
     let current_grid = world_to_grid(current_pos);
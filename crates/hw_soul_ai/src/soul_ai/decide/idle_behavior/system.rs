@@ -9,6 +9,7 @@ use hw_core::gathering::GatheringSpot;
 use hw_core::relationships::GatheringParticipants;
 #[cfg(feature = "profiling")]
 use hw_core::simulation_rng::{FixedAuditSeed, SimulationRng};
+use hw_core::social::SoulRelationships;
 use hw_core::soul::IdleBehavior;
 use hw_spatial::{GatheringSpotSpatialGrid, SpatialGrid};
 use hw_world::WorldMap;
@@ -58,6 +59,7 @@ pub(crate) struct IdleGatheringQueries<'w, 's> {
     q_rest_areas: RestAreasQuery<'w, 's>,
     spot_grid: Res<'w, GatheringSpotSpatialGrid>,
     soul_grid: Res<'w, SpatialGrid>,
+    q_relationships: Query<'w, 's, &'static SoulRelationships>,
 }
 
 #[cfg(feature = "profiling")]
@@ -144,6 +146,7 @@ pub(crate) fn idle_behavior_decision_system(
             &gq.q_spots,
             &gq.spot_grid,
             transform,
+            gq.q_relationships.get(entity).ok(),
             &mut local.nearby_buf,
         );
 
@@ -256,7 +259,7 @@ pub(crate) fn idle_behavior_decision_system(
 
         if matches!(
             idle.behavior,
-            IdleBehavior::Escaping | IdleBehavior::Drifting
+            IdleBehavior::Escaping | IdleBehavior::Drifting | IdleBehavior::Redeeming
         ) {
             continue;
         }
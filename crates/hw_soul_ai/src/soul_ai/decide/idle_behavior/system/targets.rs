@@ -4,6 +4,7 @@ use bevy::prelude::*;
 
 use hw_core::gathering::{GATHERING_LEAVE_RADIUS, GatheringSpot};
 use hw_core::relationships::GatheringParticipants;
+use hw_core::social::{SOCIAL_GATHERING_FRIEND_PULL, SoulRelationships};
 use hw_spatial::{GatheringSpotSpatialGrid, SpatialGridOps};
 
 use super::super::rest_area::{RestAreasQuery, find_nearest_available_rest_area};
@@ -13,6 +14,7 @@ pub(super) fn resolve_gathering_target(
     q_spots: &Query<(Entity, &GatheringSpot, &GatheringParticipants)>,
     spot_grid: &GatheringSpotSpatialGrid,
     transform: &Transform,
+    relationships: Option<&SoulRelationships>,
     scratch: &mut Vec<Entity>,
 ) -> (Option<Vec2>, Option<Entity>) {
     if let Some(p) = participating_in {
@@ -26,9 +28,8 @@ pub(super) fn resolve_gathering_target(
             .filter_map(|&e| q_spots.get(e).ok())
             .filter(|item| item.2.len() < item.1.max_capacity)
             .min_by(|a, b| {
-                a.1.center
-                    .distance_squared(pos)
-                    .partial_cmp(&b.1.center.distance_squared(pos))
+                gathering_spot_score(pos, a.1, a.2, relationships)
+                    .partial_cmp(&gathering_spot_score(pos, b.1, b.2, relationships))
                     .unwrap_or(std::cmp::Ordering::Equal)
             });
         match nearest {
@@ -38,6 +39,25 @@ pub(super) fn resolve_gathering_target(
     }
 }
 
+/// 集会スポットの選択スコア（小さいほど優先）。
+///
+/// 距離の二乗を、参加中の友人1人ごとに割り引く。
+fn gathering_spot_score(
+    pos: Vec2,
+    spot: &GatheringSpot,
+    participants: &GatheringParticipants,
+    relationships: Option<&SoulRelationships>,
+) -> f32 {
+    let distance_sq = spot.center.distance_squared(pos);
+    let friends = relationships.map_or(0, |relationships| {
+        participants
+            .iter()
+            .filter(|&&participant| relationships.is_friend(participant))
+            .count()
+    });
+    distance_sq / (1.0 + friends as f32 * SOCIAL_GATHERING_FRIEND_PULL)
+}
+
 pub(super) fn resolve_rest_area_target(
     reserved_rest_area: Option<Entity>,
     pos_a: Vec2,
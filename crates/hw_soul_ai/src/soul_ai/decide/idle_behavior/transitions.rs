@@ -3,6 +3,7 @@
 use rand::Rng;
 
 use hw_core::constants::*;
+use hw_core::redemption::REDEMPTION_RITE_DURATION;
 use hw_core::soul::{GatheringBehavior, IdleBehavior};
 
 /// ランダムな集会中のサブ行動を選択
@@ -63,6 +64,7 @@ pub fn behavior_duration_for_with_rng(behavior: IdleBehavior, rng: &mut impl Rng
         IdleBehavior::Drifting => {
             rng.gen_range(DRIFT_WANDER_DURATION_MIN..DRIFT_WANDER_DURATION_MAX)
         }
+        IdleBehavior::Redeeming => REDEMPTION_RITE_DURATION,
     }
 }
 
@@ -2,6 +2,7 @@ pub mod drifting;
 pub mod escaping;
 pub mod gathering_mgmt;
 pub mod idle_behavior;
+pub mod redemption;
 pub mod separation;
 pub mod work;
 
@@ -0,0 +1,215 @@
+//! 贖罪可能な Soul を Redemption Shrine への巡礼へ遷移させる意思決定システム。
+
+use bevy::prelude::*;
+use hw_core::redemption::{
+    REDEMPTION_CHECK_INTERVAL, RedemptionCooldown, RedemptionPilgrimage, SoulPenance,
+};
+use hw_core::relationships::{CommandedBy, ParticipatingIn, RestingIn};
+use hw_core::soul::{DamnedSoul, Destination, IdleBehavior, IdleState, Path, StressBreakdown};
+use hw_jobs::{Building, RedemptionShrine};
+
+use crate::soul_ai::execute::task_execution::AssignedTask;
+
+#[derive(Resource)]
+pub struct RedemptionDecisionTimer {
+    pub timer: Timer,
+}
+
+impl Default for RedemptionDecisionTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(REDEMPTION_CHECK_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+type RedemptionDecisionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static SoulPenance,
+        &'static mut IdleState,
+        &'static mut Destination,
+        &'static mut Path,
+        &'static AssignedTask,
+        Option<&'static CommandedBy>,
+        Option<&'static RestingIn>,
+        Option<&'static ParticipatingIn>,
+        Option<&'static StressBreakdown>,
+    ),
+    (
+        With<DamnedSoul>,
+        Without<RedemptionPilgrimage>,
+        Without<RedemptionCooldown>,
+    ),
+>;
+
+type ShrineQuery<'w, 's> =
+    Query<'w, 's, (Entity, &'static Transform, &'static Building), With<RedemptionShrine>>;
+
+/// penance が閾値に達した未管理の Soul を、最寄りの完成済み Shrine へ巡礼させる
+///
+/// 巡礼を諦めた直後の Soul は `RedemptionCooldown` が尽きるまで対象にしない。
+pub fn redemption_decision_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut timer: ResMut<RedemptionDecisionTimer>,
+    q_shrines: ShrineQuery,
+    mut q_cooldowns: Query<(Entity, &mut RedemptionCooldown)>,
+    mut q_souls: RedemptionDecisionQuery,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut cooldown) in q_cooldowns.iter_mut() {
+        cooldown.remaining_secs -= dt;
+        if cooldown.remaining_secs <= 0.0 {
+            commands.entity(entity).remove::<RedemptionCooldown>();
+        }
+    }
+
+    if !timer.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    if q_shrines.is_empty() {
+        return;
+    }
+
+    for (
+        entity,
+        transform,
+        penance,
+        mut idle,
+        mut destination,
+        mut path,
+        task,
+        under_command,
+        resting_in,
+        participating_in,
+        breakdown,
+    ) in q_souls.iter_mut()
+    {
+        if !penance.is_ready() {
+            continue;
+        }
+        if under_command.is_some() || !matches!(*task, AssignedTask::None) || resting_in.is_some() {
+            continue;
+        }
+        if breakdown.is_some_and(|breakdown| breakdown.is_frozen) {
+            continue;
+        }
+        if matches!(
+            idle.behavior,
+            IdleBehavior::Resting
+                | IdleBehavior::GoingToRest
+                | IdleBehavior::ExhaustedGathering
+                | IdleBehavior::Escaping
+                | IdleBehavior::Drifting
+        ) {
+            continue;
+        }
+
+        let current_pos = transform.translation.truncate();
+        let Some((shrine, shrine_pos)) = nearest_shrine(current_pos, &q_shrines) else {
+            continue;
+        };
+
+        if participating_in.is_some() {
+            commands.entity(entity).remove::<ParticipatingIn>();
+        }
+
+        idle.behavior = IdleBehavior::Redeeming;
+        idle.idle_timer = 0.0;
+        destination.0 = shrine_pos;
+        path.waypoints.clear();
+        path.current_index = 0;
+        commands
+            .entity(entity)
+            .insert(RedemptionPilgrimage::new(shrine));
+
+        info!(
+            "SOUL_REDEMPTION: {:?} started pilgrimage to shrine {:?} (penance={:.1})",
+            entity, shrine, penance.score
+        );
+    }
+}
+
+/// 完成済み（仮設でない）Shrine のうち最も近いものを返す
+fn nearest_shrine(from: Vec2, q_shrines: &ShrineQuery) -> Option<(Entity, Vec2)> {
+    q_shrines
+        .iter()
+        .filter(|(_, _, building)| !building.is_provisional)
+        .map(|(entity, transform, _)| (entity, transform.translation.truncate()))
+        .min_by(|(_, a), (_, b)| {
+            from.distance_squared(*a)
+                .total_cmp(&from.distance_squared(*b))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use hw_core::redemption::REDEMPTION_PENANCE_THRESHOLD;
+    use hw_jobs::BuildingType;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<RedemptionDecisionTimer>()
+            .add_systems(Update, redemption_decision_system);
+        app.world_mut().spawn((
+            RedemptionShrine,
+            Building {
+                kind: BuildingType::RedemptionShrine,
+                is_provisional: false,
+            },
+            Transform::from_xyz(64.0, 0.0, 0.0),
+        ));
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    #[test]
+    fn retry_cooldown_defers_the_next_pilgrimage() {
+        let mut app = app();
+        let soul = app
+            .world_mut()
+            .spawn((
+                DamnedSoul::default(),
+                Transform::default(),
+                SoulPenance {
+                    score: REDEMPTION_PENANCE_THRESHOLD,
+                },
+                IdleState::default(),
+                Destination(Vec2::ZERO),
+                Path::default(),
+                AssignedTask::None,
+                RedemptionCooldown {
+                    remaining_secs: REDEMPTION_CHECK_INTERVAL * 1.5,
+                },
+            ))
+            .id();
+
+        advance(&mut app, REDEMPTION_CHECK_INTERVAL);
+        assert!(app.world().get::<RedemptionPilgrimage>(soul).is_none());
+        assert!(app.world().get::<RedemptionCooldown>(soul).is_some());
+
+        advance(&mut app, REDEMPTION_CHECK_INTERVAL);
+        assert!(app.world().get::<RedemptionCooldown>(soul).is_none());
+
+        advance(&mut app, REDEMPTION_CHECK_INTERVAL);
+        assert!(app.world().get::<RedemptionPilgrimage>(soul).is_some());
+        assert_eq!(
+            app.world().get::<IdleState>(soul).unwrap().behavior,
+            IdleBehavior::Redeeming
+        );
+    }
+}
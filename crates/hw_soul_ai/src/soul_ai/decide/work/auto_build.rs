@@ -122,9 +122,14 @@ pub(crate) fn blueprint_auto_build_system(
         let mut already_requested_workers = std::collections::HashSet::new();
 
         // 最適化: タスクエリア内のブループリントのみを取得
-        let blueprints_in_area = params
-            .blueprint_grid
-            .get_in_area(task_area.min(), task_area.max());
+        let mut blueprints_in_area = Vec::new();
+        for rect in task_area.rects() {
+            for bp_entity in params.blueprint_grid.get_in_area(rect.min, rect.max) {
+                if !blueprints_in_area.contains(&bp_entity) {
+                    blueprints_in_area.push(bp_entity);
+                }
+            }
+        }
 
         for bp_entity in blueprints_in_area {
             // クエリで詳細データを取得
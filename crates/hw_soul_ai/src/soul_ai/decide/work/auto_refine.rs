@@ -52,7 +52,7 @@ pub fn mud_mixer_auto_refine_system(
     let active_familiars: Vec<_> = q_familiars
         .iter()
         .filter(|(_, ac, _)| !matches!(ac.command, FamiliarCommand::Idle))
-        .map(|(e, _, area)| (e, area.bounds()))
+        .flat_map(|(e, _, area)| area.rects().map(move |rect| (e, rect.clone())))
         .collect();
     let active_yards: Vec<_> = q_yards.iter().map(|(e, y)| (e, y.clone())).collect();
     let all_owners = collect_all_area_owners(&active_familiars, &active_yards);
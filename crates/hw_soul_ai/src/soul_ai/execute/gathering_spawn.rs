@@ -9,6 +9,7 @@ use hw_core::simulation_rng::{FixedAuditSeed, SimulationRandomState, SimulationR
 use hw_core::soul::{DamnedSoul, IdleBehavior, IdleState};
 use hw_jobs::AssignedTask;
 use hw_spatial::{GatheringSpotSpatialGrid, SpatialGrid, SpatialGridOps};
+use hw_world::WorldMap;
 
 #[cfg(feature = "profiling")]
 const GATHERING_OBJECT_TYPE_STREAM: u64 = 0x6761_7468_5f6f_626a;
@@ -35,6 +36,7 @@ pub struct GatheringSpawnResources<'w> {
     pub spot_grid: Res<'w, GatheringSpotSpatialGrid>,
     pub soul_grid: Res<'w, SpatialGrid>,
     pub update_timer: Res<'w, GatheringUpdateTimer>,
+    pub world_map: Res<'w, WorldMap>,
 }
 
 #[cfg(feature = "profiling")]
@@ -82,6 +84,10 @@ pub fn gathering_spawn_logic_system(
         }
 
         let pos = transform.translation.truncate();
+        // 禁止区域の中では集会を始めない
+        if res.world_map.is_forbidden(WorldMap::world_to_grid(pos)) {
+            continue;
+        }
 
         res.spot_grid
             .get_nearby_in_radius_into(pos, GATHERING_DETECTION_RADIUS, &mut nearby_buf);
@@ -5,6 +5,7 @@ pub mod escaping_apply;
 pub mod gathering_apply;
 pub mod gathering_spawn;
 pub mod idle_behavior_apply;
+pub mod redemption;
 pub mod task_assignment_apply;
 pub mod task_execution;
 pub mod task_execution_system;
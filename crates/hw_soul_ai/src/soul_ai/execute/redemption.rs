@@ -0,0 +1,222 @@
+//! 贖罪巡礼（Redeeming）実行システム
+//!
+//! 巡礼中の Soul を Shrine へ歩かせ、到着後に儀式を行って贖罪させる。
+//! 報酬と人口カウンタの反映は `SoulRedeemed` を受ける root adapter が担う。
+
+use bevy::prelude::*;
+use hw_core::events::SoulRedeemed;
+use hw_core::redemption::{
+    REDEMPTION_ARRIVAL_RADIUS, REDEMPTION_PILGRIMAGE_TIMEOUT, REDEMPTION_RETRY_COOLDOWN,
+    REDEMPTION_RITE_DURATION, RedemptionCooldown, RedemptionPilgrimage,
+};
+use hw_core::relationships::CommandedBy;
+use hw_core::soul::{DamnedSoul, Destination, IdleBehavior, IdleState, Path};
+use hw_jobs::{AssignedTask, RedemptionShrine};
+
+type RedemptionBehaviorQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static mut IdleState,
+        &'static mut Destination,
+        &'static mut Path,
+        &'static AssignedTask,
+        Option<&'static CommandedBy>,
+        &'static mut RedemptionPilgrimage,
+    ),
+    With<DamnedSoul>,
+>;
+
+/// 巡礼を中断して通常の待機行動へ戻す
+fn abandon_pilgrimage(
+    commands: &mut Commands,
+    entity: Entity,
+    idle: &mut IdleState,
+    path: &mut Path,
+) {
+    if idle.behavior == IdleBehavior::Redeeming {
+        idle.behavior = IdleBehavior::Wandering;
+        idle.idle_timer = 0.0;
+    }
+    path.waypoints.clear();
+    path.current_index = 0;
+    commands.entity(entity).remove::<RedemptionPilgrimage>();
+}
+
+/// 巡礼中 Soul の移動・儀式・贖罪を更新する
+pub fn redemption_pilgrimage_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    q_shrines: Query<&Transform, With<RedemptionShrine>>,
+    mut q_souls: RedemptionBehaviorQuery,
+) {
+    let dt = time.delta_secs();
+
+    for (
+        entity,
+        transform,
+        mut idle,
+        mut destination,
+        mut path,
+        task,
+        under_command,
+        mut pilgrimage,
+    ) in q_souls.iter_mut()
+    {
+        if idle.behavior != IdleBehavior::Redeeming
+            || under_command.is_some()
+            || !matches!(*task, AssignedTask::None)
+        {
+            abandon_pilgrimage(&mut commands, entity, &mut idle, &mut path);
+            continue;
+        }
+
+        let Ok(shrine_transform) = q_shrines.get(pilgrimage.shrine) else {
+            abandon_pilgrimage(&mut commands, entity, &mut idle, &mut path);
+            continue;
+        };
+        let shrine_pos = shrine_transform.translation.truncate();
+        let current_pos = transform.translation.truncate();
+
+        if current_pos.distance(shrine_pos) > REDEMPTION_ARRIVAL_RADIUS {
+            pilgrimage.elapsed += dt;
+            pilgrimage.rite_timer = 0.0;
+            if pilgrimage.elapsed >= REDEMPTION_PILGRIMAGE_TIMEOUT {
+                info!(
+                    "SOUL_REDEMPTION: {:?} gave up pilgrimage to {:?}",
+                    entity, pilgrimage.shrine
+                );
+                abandon_pilgrimage(&mut commands, entity, &mut idle, &mut path);
+                // 到達できない Shrine へすぐ再出発しないよう、次の巡礼まで間を置く
+                commands.entity(entity).insert(RedemptionCooldown {
+                    remaining_secs: REDEMPTION_RETRY_COOLDOWN,
+                });
+                continue;
+            }
+            if destination.0 != shrine_pos {
+                destination.0 = shrine_pos;
+                path.waypoints.clear();
+                path.current_index = 0;
+            }
+            continue;
+        }
+
+        pilgrimage.rite_timer += dt;
+        if pilgrimage.rite_timer < REDEMPTION_RITE_DURATION {
+            continue;
+        }
+
+        // 報酬側の adapter が Soul の名前を参照できるよう、despawn より先に発火する
+        commands.trigger(SoulRedeemed {
+            entity,
+            shrine: pilgrimage.shrine,
+        });
+        commands.entity(entity).try_despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Redeemed(Vec<Entity>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<Redeemed>()
+            .add_observer(|on: On<SoulRedeemed>, mut redeemed: ResMut<Redeemed>| {
+                redeemed.0.push(on.event().entity);
+            })
+            .add_systems(Update, redemption_pilgrimage_system);
+        app
+    }
+
+    fn spawn_pilgrim(app: &mut App, shrine: Entity, pos: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                DamnedSoul::default(),
+                Transform::from_translation(pos.extend(0.0)),
+                IdleState {
+                    behavior: IdleBehavior::Redeeming,
+                    ..default()
+                },
+                Destination(pos),
+                Path::default(),
+                AssignedTask::None,
+                RedemptionPilgrimage::new(shrine),
+            ))
+            .id()
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    #[test]
+    fn soul_at_shrine_is_redeemed_after_rite() {
+        let mut app = app();
+        let shrine = app
+            .world_mut()
+            .spawn((RedemptionShrine, Transform::default()))
+            .id();
+        let soul = spawn_pilgrim(&mut app, shrine, Vec2::ZERO);
+
+        advance(&mut app, REDEMPTION_RITE_DURATION * 0.5);
+        assert!(app.world().get_entity(soul).is_ok());
+
+        advance(&mut app, REDEMPTION_RITE_DURATION);
+        assert!(app.world().get_entity(soul).is_err());
+        assert_eq!(app.world().resource::<Redeemed>().0, vec![soul]);
+    }
+
+    #[test]
+    fn missing_shrine_abandons_pilgrimage() {
+        let mut app = app();
+        let shrine = app.world_mut().spawn_empty().id();
+        let soul = spawn_pilgrim(&mut app, shrine, Vec2::ZERO);
+        app.world_mut().despawn(shrine);
+
+        advance(&mut app, 0.1);
+
+        assert!(app.world().get::<RedemptionPilgrimage>(soul).is_none());
+        assert_eq!(
+            app.world().get::<IdleState>(soul).unwrap().behavior,
+            IdleBehavior::Wandering
+        );
+        assert!(app.world().resource::<Redeemed>().0.is_empty());
+    }
+
+    #[test]
+    fn timed_out_pilgrimage_starts_retry_cooldown() {
+        let mut app = app();
+        let shrine = app
+            .world_mut()
+            .spawn((RedemptionShrine, Transform::default()))
+            .id();
+        let soul = spawn_pilgrim(&mut app, shrine, Vec2::splat(1000.0));
+        app.world_mut()
+            .get_mut::<RedemptionPilgrimage>(soul)
+            .unwrap()
+            .elapsed = REDEMPTION_PILGRIMAGE_TIMEOUT - 0.05;
+
+        advance(&mut app, 0.1);
+
+        assert!(app.world().get::<RedemptionPilgrimage>(soul).is_none());
+        assert_eq!(
+            app.world()
+                .get::<RedemptionCooldown>(soul)
+                .map(|cooldown| cooldown.remaining_secs),
+            Some(REDEMPTION_RETRY_COOLDOWN)
+        );
+        assert!(app.world().resource::<Redeemed>().0.is_empty());
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use hw_core::constants::REST_AREA_RECRUIT_COOLDOWN_SECS;
 use hw_core::events::{OnTaskAssigned, publish_soul_recruited};
 use hw_core::logistics::WheelbarrowDestination;
+use hw_core::redemption::RedemptionPilgrimage;
 use hw_core::relationships::{
     CommandedBy, DeliveringTo, ParticipatingIn, RestAreaReservedFor, RestingIn, WorkingOn,
 };
@@ -77,7 +78,10 @@ fn normalize_worker_idle_state(
         }
     }
 
-    if idle.behavior == IdleBehavior::Drifting {
+    if matches!(
+        idle.behavior,
+        IdleBehavior::Drifting | IdleBehavior::Redeeming
+    ) {
         idle.behavior = IdleBehavior::Wandering;
         idle.idle_timer = 0.0;
         idle.behavior_duration = 3.0;
@@ -90,7 +94,9 @@ fn normalize_worker_idle_state(
         idle.needs_separation = false;
     }
     idle.total_idle_time = 0.0;
-    commands.entity(worker_entity).try_remove::<DriftingState>();
+    commands
+        .entity(worker_entity)
+        .try_remove::<(DriftingState, RedemptionPilgrimage)>();
 }
 
 fn apply_assignment_state(
@@ -34,7 +34,7 @@ pub fn handle(
     match data.source {
         BucketTransportSource::River => {
             // 時間経過で水を汲む
-            let new_progress = progress + ctx.env.time.delta_secs() * 0.5;
+            let new_progress = progress + ctx.env.work_delta_secs() * 0.5;
 
             if new_progress >= 1.0 {
                 let tank_entity = match data.destination {
@@ -34,12 +34,13 @@ pub fn set_path_to_river(
     else {
         return PathSearchResult::Unreachable;
     };
+    let start_grid = WorldMap::world_to_grid(ctx.soul_transform.translation.truncate());
     let path = match hw_world::find_path_to_adjacent_with_budget(
-        world_map,
+        &world_map.path_world_for(start_grid, river_grid, true),
         ctx.pf_context,
         ctx.path_budget,
         hw_world::PathSearchCaller::BucketTransport,
-        WorldMap::world_to_grid(ctx.soul_transform.translation.truncate()),
+        start_grid,
         river_grid,
         true,
     ) {
@@ -71,12 +72,13 @@ pub fn set_path_to_grid_boundary(
     target_grid: (i32, i32),
     fallback_pos: Vec2,
 ) -> PathSearchResult<()> {
+    let start_grid = WorldMap::world_to_grid(ctx.soul_transform.translation.truncate());
     let path = match hw_world::find_path_to_boundary_with_budget(
-        world_map,
+        &world_map.path_world_for(start_grid, target_grid, true),
         ctx.pf_context,
         ctx.path_budget,
         hw_world::PathSearchCaller::BucketTransport,
-        WorldMap::world_to_grid(ctx.soul_transform.translation.truncate()),
+        start_grid,
         &[target_grid],
     ) {
         PathSearchResult::Found(path) => path,
@@ -109,12 +111,13 @@ pub fn set_path_to_tank_boundary(
     let (cx, cy) = WorldMap::world_to_grid(tank_pos);
     let tank_grids = vec![(cx - 1, cy - 1), (cx, cy - 1), (cx - 1, cy), (cx, cy)];
 
+    let start_grid = WorldMap::world_to_grid(ctx.soul_transform.translation.truncate());
     let path = match hw_world::find_path_to_boundary_with_budget(
-        world_map,
+        &world_map.path_world_for(start_grid, (cx, cy), true),
         ctx.pf_context,
         ctx.path_budget,
         hw_world::PathSearchCaller::BucketTransport,
-        WorldMap::world_to_grid(ctx.soul_transform.translation.truncate()),
+        start_grid,
         &tank_grids,
     ) {
         PathSearchResult::Found(path) => path,
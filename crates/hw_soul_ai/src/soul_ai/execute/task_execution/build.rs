@@ -84,7 +84,7 @@ pub fn handle_build_task(
                     return TaskHandlerControl::Continue;
                 }
 
-                progress += ctx.env.time.delta_secs() * 0.33;
+                progress += ctx.env.work_delta_secs() * 0.33;
                 bp.progress = progress;
 
                 if progress >= 1.0 {
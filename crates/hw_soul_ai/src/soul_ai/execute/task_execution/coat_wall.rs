@@ -100,7 +100,7 @@ fn handle_legacy_coat_wall_task(
             }
 
             const MAX_PROGRESS_BP: u16 = 10_000;
-            let delta_bp = ((ctx.env.time.delta_secs() / WALL_COAT_DURATION_SECS
+            let delta_bp = ((ctx.env.work_delta_secs() / WALL_COAT_DURATION_SECS
                 * MAX_PROGRESS_BP as f32)
                 .round()
                 .max(1.0)) as u16;
@@ -278,7 +278,7 @@ pub fn handle_coat_wall_task(
             }
 
             const MAX_PROGRESS_BP: u16 = 10_000;
-            let delta_bp = ((ctx.env.time.delta_secs() / WALL_COAT_DURATION_SECS
+            let delta_bp = ((ctx.env.work_delta_secs() / WALL_COAT_DURATION_SECS
                 * MAX_PROGRESS_BP as f32)
                 .round()
                 .max(1.0)) as u16;
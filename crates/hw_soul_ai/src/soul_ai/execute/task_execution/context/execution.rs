@@ -63,6 +63,15 @@ pub struct TaskExecEnv<'a> {
     pub time: &'a Time,
     pub world_map: &'a WorldMap,
     pub breakdown: Option<&'a StressBreakdown>,
+    /// 勅令による作業速度の倍率
+    pub work_speed: f32,
+}
+
+impl TaskExecEnv<'_> {
+    /// 作業進捗に使う経過時間（勅令の作業速度補正込み）
+    pub fn work_delta_secs(&self) -> f32 {
+        self.time.delta_secs() * self.work_speed
+    }
 }
 
 /// タスク実行の基本コンテキスト
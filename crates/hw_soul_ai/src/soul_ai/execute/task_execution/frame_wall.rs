@@ -97,7 +97,7 @@ pub fn handle_frame_wall_task(
             };
 
             const MAX_PROGRESS_BP: u16 = 10_000;
-            let delta_bp = ((ctx.env.time.delta_secs() / WALL_FRAME_DURATION_SECS
+            let delta_bp = ((ctx.env.work_delta_secs() / WALL_FRAME_DURATION_SECS
                 * MAX_PROGRESS_BP as f32)
                 .round()
                 .max(1.0)) as u16;
@@ -85,7 +85,7 @@ pub fn handle_gather_task(
                 } else {
                     GATHER_SPEED_BASE
                 };
-                progress += ctx.env.time.delta_secs() * speed;
+                progress += ctx.env.work_delta_secs() * speed;
 
                 if progress >= 1.0 {
                     if tree.is_some() {
@@ -46,7 +46,8 @@ fn has_pending_wheelbarrow_task(ctx: &TaskExecutionContext) -> bool {
                 | TransportRequestKind::DeliverToFloorConstruction
                 | TransportRequestKind::DeliverToWallConstruction
                 | TransportRequestKind::DeliverToMixerSolid
-                | TransportRequestKind::DeliverToSoulSpa => true,
+                | TransportRequestKind::DeliverToSoulSpa
+                | TransportRequestKind::SupplyRoute => true,
                 TransportRequestKind::DeliverToBlueprint => {
                     request.resource_type.requires_wheelbarrow()
                 }
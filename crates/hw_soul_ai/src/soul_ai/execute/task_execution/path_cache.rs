@@ -184,10 +184,11 @@ fn update_destination_to_adjacent_resumable(
         obstacle_version: world_map.obstacle_version,
     };
     let stage = progress.stage_for(entity, caller, fingerprint);
+    let path_world = world_map.path_world_for(start_grid, target_grid, true);
 
     if stage == TaskPathStage::Direct {
         match find_path_with_budget(
-            world_map,
+            &path_world,
             pf_context,
             budget,
             caller,
@@ -206,7 +207,7 @@ fn update_destination_to_adjacent_resumable(
     }
 
     let grid_path = find_path_to_adjacent_with_budget(
-        world_map,
+        &path_world,
         pf_context,
         budget,
         caller,
@@ -275,8 +276,10 @@ pub fn update_destination_to_blueprint(
     }
 
     // ターゲットの中心地点を軸に「境界」までのパスを計算
+    let goal_grid = occupied_grids.first().copied().unwrap_or(start_grid);
+    let path_world = world_map.path_world_for(start_grid, goal_grid, true);
     match find_path_to_boundary_with_budget(
-        world_map,
+        &path_world,
         pf_context,
         budget,
         PathSearchCaller::TaskExecution,
@@ -79,7 +79,7 @@ pub fn handle_refine_task(
                     return ctx.abort_retryable(commands, "refine materials unavailable");
                 }
 
-                progress += ctx.env.time.delta_secs() * GATHER_SPEED_BASE;
+                progress += ctx.env.work_delta_secs() * GATHER_SPEED_BASE;
 
                 if progress >= 1.0 {
                     storage.consume_materials_for_refining(water_count);
@@ -152,7 +152,7 @@ pub fn handle_reinforce_floor_task(
 
             // Update progress (basis points) to avoid truncation at 1x speed.
             const MAX_PROGRESS_BP: u16 = 10_000;
-            let delta_bp = ((ctx.env.time.delta_secs() / FLOOR_REINFORCE_DURATION_SECS
+            let delta_bp = ((ctx.env.work_delta_secs() / FLOOR_REINFORCE_DURATION_SECS
                 * MAX_PROGRESS_BP as f32)
                 .round()
                 .max(1.0)) as u16;
@@ -1,5 +1,6 @@
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use hw_core::edicts::DreamEdicts;
 use hw_core::events::publish_task_completed;
 use hw_core::relationships::WorkingOn;
 use hw_core::visual::SoulTaskHandles;
@@ -24,6 +25,7 @@ use crate::soul_ai::pathfinding::TASK_EXECUTION_PATHFINDS_PHASE_LIMIT;
 pub struct TaskExecResources<'w, 's> {
     soul_handles: Res<'w, SoulTaskHandles>,
     time: Res<'w, Time>,
+    edicts: Res<'w, DreamEdicts>,
     world_map: WorldMapRead<'w>,
     pf_context: Local<'s, PathfindingContext>,
     path_budget: ResMut<'w, RuntimePathSearchBudget>,
@@ -57,6 +59,7 @@ pub fn task_execution_system(
     // slots for Actor-side replans later in the frame, plus the idle reserve.
     res.path_budget
         .begin_phase(TASK_EXECUTION_PATHFINDS_PHASE_LIMIT);
+    let work_speed = res.edicts.modifiers().work_speed_multiplier;
     let world_epoch = res
         .world_epoch
         .map_or_else(WorldEpoch::default, |epoch| *epoch);
@@ -194,6 +197,7 @@ pub fn task_execution_system(
                     time: res.time.as_ref(),
                     world_map: res.world_map.as_ref(),
                     breakdown: breakdown_opt,
+                    work_speed,
                 },
                 end_state: default(),
             };
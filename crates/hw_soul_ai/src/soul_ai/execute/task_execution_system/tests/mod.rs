@@ -61,6 +61,7 @@ fn task_execution_test_app() -> App {
         .insert_resource(WorldMap::default())
         .insert_resource(empty_soul_task_handles())
         .init_resource::<RuntimePathSearchBudget>()
+        .init_resource::<hw_core::edicts::DreamEdicts>()
         .init_resource::<SharedResourceCache>()
         .init_resource::<TaskNotificationReceipts>()
         .add_message::<ResourceReservationRequest>()
@@ -47,13 +47,13 @@ pub fn is_near_map_edge(grid: (i32, i32)) -> bool {
         || grid.1 >= MAP_HEIGHT - 1 - SOUL_DESPAWN_EDGE_MARGIN_TILES
 }
 
-/// 現在グリッド周辺のランダムな歩行可能点を返す（Wandering フェーズ用）。
+/// 現在グリッド周辺のランダムな歩行可能点（禁止区域を除く）を返す（Wandering フェーズ用）。
 pub fn random_wander_target(grid: (i32, i32), world_map: &WorldMap, rng: &mut impl Rng) -> Vec2 {
     for _ in 0..24 {
         let dx = rng.gen_range(-4..=4);
         let dy = rng.gen_range(-4..=4);
         let target = (grid.0 + dx, grid.1 + dy);
-        if world_map.is_walkable(target.0, target.1) {
+        if world_map.is_walkable(target.0, target.1) && !world_map.is_forbidden(target) {
             return WorldMap::grid_to_world(target.0, target.1);
         }
     }
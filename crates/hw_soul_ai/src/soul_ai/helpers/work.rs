@@ -29,6 +29,7 @@ pub fn is_soul_available_for_work(
             | IdleBehavior::GoingToRest
             | IdleBehavior::Escaping
             | IdleBehavior::Drifting
+            | IdleBehavior::Redeeming
     ) {
         return false;
     }
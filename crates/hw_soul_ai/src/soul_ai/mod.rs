@@ -24,6 +24,7 @@ impl Plugin for SoulAiCorePlugin {
             .init_resource::<perceive::escaping::EscapeDetectionTimer>()
             .init_resource::<perceive::escaping::EscapeBehaviorTimer>()
             .init_resource::<decide::drifting::DriftingDecisionTimer>()
+            .init_resource::<decide::redemption::RedemptionDecisionTimer>()
             .init_resource::<decide::work::auto_build::BlueprintAutoBuildTimer>()
             .init_resource::<decide::work::auto_build_diagnostics::BlueprintAutoBuildDiagnostics>()
             .init_resource::<update::slow_simulation::SlowSimulationClock>()
@@ -82,6 +83,19 @@ impl Plugin for SoulAiCorePlugin {
                     .chain()
                     .in_set(SoulAiSystemSet::Update),
             )
+            .add_systems(
+                Update,
+                (
+                    update::social::social_gathering_affinity_system,
+                    update::social::social_proximity_system
+                        .after(update::slow_simulation::slow_simulation_driver_system),
+                    update::life_log::life_log_message_system
+                        .after(update::slow_simulation::slow_simulation_driver_system),
+                    update::penance::penance_dream_accrual_system
+                        .after(update::slow_simulation::slow_simulation_driver_system),
+                )
+                    .in_set(SoulAiSystemSet::Update),
+            )
             .add_systems(
                 Update,
                 decide::idle_behavior::mark_needs_idle_decision_system
@@ -101,6 +115,9 @@ impl Plugin for SoulAiCorePlugin {
                     ),
                     execute::drifting::despawn_at_edge_system
                         .after(execute::drifting::drifting_behavior_system),
+                    execute::redemption::redemption_pilgrimage_system.after(
+                        execute::task_assignment_apply::apply_task_assignment_requests_system,
+                    ),
                     // Assignment uses Commands for WorkingOn and ActiveTaskIdentity. Apply them
                     // before task execution so an accepted assignment has one coherent identity.
                     ApplyDeferred
@@ -136,6 +153,8 @@ impl Plugin for SoulAiCorePlugin {
                         .after(decide::idle_behavior::idle_behavior_decision_system),
                     decide::drifting::drifting_decision_system
                         .after(decide::escaping::escaping_decision_system),
+                    decide::redemption::redemption_decision_system
+                        .after(decide::drifting::drifting_decision_system),
                     decide::gathering_mgmt::gathering_maintenance_decision,
                     decide::gathering_mgmt::gathering_merge_decision,
                     decide::gathering_mgmt::gathering_recruitment_decision,
@@ -146,6 +165,13 @@ impl Plugin for SoulAiCorePlugin {
             .add_observer(update::vitals::on_task_completed_motivation_bonus)
             .add_observer(update::vitals::on_encouraged_effect)
             .add_observer(update::vitals::on_soul_recruited_effect)
+            .add_observer(update::social::on_soul_removed_forget_relationships)
+            .add_observer(update::life_log::on_soul_recruited_life_log)
+            .add_observer(update::life_log::on_task_completed_life_log)
+            .add_observer(update::life_log::on_exhausted_life_log)
+            .add_observer(update::life_log::on_stress_breakdown_life_log)
+            .add_observer(update::life_log::on_rest_started_life_log)
+            .add_observer(update::penance::on_task_completed_penance)
             .add_observer(building_completed::on_building_completed)
             .add_systems(
                 Update,
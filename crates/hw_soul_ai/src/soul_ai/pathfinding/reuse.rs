@@ -3,8 +3,8 @@
 use bevy::prelude::*;
 use hw_core::soul::Path;
 use hw_world::{
-    PathSearchCaller, PathSearchResult, PathfindingContext, RuntimePathSearchBudget, WorldMap,
-    find_path_world_waypoints_with_budget,
+    ForbiddenAwarePathWorld, PathSearchCaller, PathSearchResult, PathWorld, PathfindingContext,
+    RuntimePathSearchBudget, WorldMap, find_path_world_waypoints_with_budget,
 };
 
 use super::PathCooldown;
@@ -21,6 +21,8 @@ pub(super) struct ReusePfState<'a> {
     pub entity: Entity,
     pub budget: &'a mut RuntimePathSearchBudget,
     pub world_map: &'a WorldMap,
+    /// 禁止区域の通行可否を反映した探索ビュー
+    pub path_world: ForbiddenAwarePathWorld<'a>,
     pub pf_context: &'a mut PathfindingContext,
 }
 
@@ -38,6 +40,7 @@ pub(super) fn try_reuse_existing_path(
         entity,
         budget,
         world_map,
+        path_world,
         pf_context,
     } = pf;
     // すでに有効なパスがあり、目的地も変わっていないならスキップ
@@ -54,7 +57,7 @@ pub(super) fn try_reuse_existing_path(
     let Some(last) = path.waypoints.last() else {
         return ReusePathResult::NotReused;
     };
-    let goal_is_walkable = world_map.is_walkable(goal_grid.0, goal_grid.1);
+    let goal_is_walkable = path_world.is_walkable(goal_grid.0, goal_grid.1);
     let goal_reached_by_path = if goal_is_walkable {
         last.distance_squared(destination) < 1.0
     } else {
@@ -74,7 +77,7 @@ pub(super) fn try_reuse_existing_path(
 
     let blocked_relative = path.waypoints[path.current_index..].iter().position(|wp| {
         let grid = WorldMap::world_to_grid(*wp);
-        !world_map.is_walkable(grid.0, grid.1)
+        !path_world.is_walkable(grid.0, grid.1)
     });
 
     let Some(rel_idx) = blocked_relative else {
@@ -88,7 +91,7 @@ pub(super) fn try_reuse_existing_path(
         let resume_grid = WorldMap::world_to_grid(resume_wp);
 
         match find_path_world_waypoints_with_budget(
-            world_map,
+            &path_world,
             pf_context,
             budget,
             PathSearchCaller::ActorReuse,
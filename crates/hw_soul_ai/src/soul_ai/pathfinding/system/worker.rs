@@ -24,6 +24,8 @@ pub(super) fn process_worker_pathfinding(
     let start_grid = WorldMap::world_to_grid(current_pos);
     let goal_grid = WorldMap::world_to_grid(soul.destination.0);
     let obstacle_version = world_map.obstacle_version;
+    // 禁止区域は割り当て作業の目的地が区域内にある場合だけ通行できる
+    let path_world = world_map.path_world_for(start_grid, goal_grid, has_task);
 
     // --- 再利用フェーズ: 既存パスが有効なら A* コストなしで続行 ---
     match reuse::try_reuse_existing_path(
@@ -32,6 +34,7 @@ pub(super) fn process_worker_pathfinding(
             entity,
             budget,
             world_map,
+            path_world,
             pf_context,
         },
         soul.path,
@@ -71,7 +74,7 @@ pub(super) fn process_worker_pathfinding(
 
     if stage == ActorPathStage::Direct {
         match find_path_with_budget(
-            world_map,
+            &path_world,
             pf_context,
             budget,
             PathSearchCaller::ActorNew,
@@ -101,7 +104,7 @@ pub(super) fn process_worker_pathfinding(
     let mut run_rest_fallback = stage == ActorPathStage::RestFallback;
     if !run_rest_fallback {
         match find_path_to_adjacent_with_budget(
-            world_map,
+            &path_world,
             pf_context,
             budget,
             PathSearchCaller::ActorNew,
@@ -0,0 +1,307 @@
+//! Soul の生涯ログ記録
+//!
+//! domain イベント（Observer）と Message を購読し、`SoulLifeLog` へ
+//! `GameTime` の時刻付きで追記する。ログの内容はシミュレーションに影響しない。
+
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use hw_core::events::{
+    OnDreamContributed, OnExhausted, OnGatheringJoined, OnReleasedFromService, OnSoulRecruited,
+    OnStressBreakdown, OnTaskAbandoned, OnTaskCompleted,
+};
+use hw_core::life_log::{SoulLifeEventKind, SoulLifeLog};
+use hw_core::relationships::RestingIn;
+use hw_core::{EpochLocal, GameTime, WorldEpoch};
+
+fn record(
+    q_logs: &mut Query<&mut SoulLifeLog>,
+    game_time: &GameTime,
+    entity: Entity,
+    kind: SoulLifeEventKind,
+) {
+    if let Ok(mut log) = q_logs.get_mut(entity) {
+        log.record(game_time, kind);
+    }
+}
+
+pub fn on_soul_recruited_life_log(
+    on: On<OnSoulRecruited>,
+    game_time: Res<GameTime>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    record(
+        &mut q_logs,
+        &game_time,
+        on.event().entity,
+        SoulLifeEventKind::Recruited,
+    );
+}
+
+pub fn on_task_completed_life_log(
+    on: On<OnTaskCompleted>,
+    game_time: Res<GameTime>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    let event = on.event();
+    record(
+        &mut q_logs,
+        &game_time,
+        event.entity,
+        SoulLifeEventKind::TaskCompleted(event.current_work_type),
+    );
+}
+
+pub fn on_exhausted_life_log(
+    on: On<OnExhausted>,
+    game_time: Res<GameTime>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    record(
+        &mut q_logs,
+        &game_time,
+        on.event().entity,
+        SoulLifeEventKind::Exhausted,
+    );
+}
+
+pub fn on_stress_breakdown_life_log(
+    on: On<OnStressBreakdown>,
+    game_time: Res<GameTime>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    record(
+        &mut q_logs,
+        &game_time,
+        on.event().entity,
+        SoulLifeEventKind::StressBreakdown,
+    );
+}
+
+pub fn on_rest_started_life_log(
+    on: On<Add, RestingIn>,
+    game_time: Res<GameTime>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    record(
+        &mut q_logs,
+        &game_time,
+        on.entity,
+        SoulLifeEventKind::RestStarted,
+    );
+}
+
+#[derive(SystemParam)]
+pub struct LifeLogMessages<'w, 's> {
+    released: MessageReader<'w, 's, OnReleasedFromService>,
+    abandoned: MessageReader<'w, 's, OnTaskAbandoned>,
+    joined: MessageReader<'w, 's, OnGatheringJoined>,
+    dream_contributions: MessageReader<'w, 's, OnDreamContributed>,
+}
+
+/// Message 経由の出来事（解放・中断・集会参加・Dream 放出）を記録する。
+///
+/// Dream 放出は `OnDreamContributed` の加算量を Soul 単位で合算し、producer が
+/// `is_final` を立てた時点で1件として記録する。合算途中の値はワールド置換で破棄する。
+pub fn life_log_message_system(
+    game_time: Res<GameTime>,
+    world_epoch: Res<WorldEpoch>,
+    mut messages: LifeLogMessages,
+    mut pending_dream: Local<EpochLocal<HashMap<Entity, f32>>>,
+    mut q_logs: Query<&mut SoulLifeLog>,
+) {
+    let pending_dream = pending_dream.get_mut(*world_epoch);
+    for event in messages.released.read() {
+        record(
+            &mut q_logs,
+            &game_time,
+            event.entity,
+            SoulLifeEventKind::Released,
+        );
+    }
+    for event in messages.abandoned.read() {
+        record(
+            &mut q_logs,
+            &game_time,
+            event.entity,
+            SoulLifeEventKind::TaskAbandoned,
+        );
+    }
+    for event in messages.joined.read() {
+        record(
+            &mut q_logs,
+            &game_time,
+            event.entity,
+            SoulLifeEventKind::JoinedGathering,
+        );
+    }
+    for contribution in messages.dream_contributions.read() {
+        let total = pending_dream.entry(contribution.soul).or_default();
+        *total += contribution.amount;
+        if contribution.is_final {
+            let amount = pending_dream.remove(&contribution.soul).unwrap_or_default();
+            record(
+                &mut q_logs,
+                &game_time,
+                contribution.soul,
+                SoulLifeEventKind::DreamReleased { amount },
+            );
+        }
+    }
+    pending_dream.retain(|&soul, _| q_logs.contains(soul));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::ScheduleRunnerPlugin;
+    use hw_core::jobs::WorkType;
+    use hw_core::life_log::SoulLifeLogEntry;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_once()))
+            .insert_resource(GameTime {
+                seconds: 0.0,
+                day: 2,
+                hour: 13,
+                minute: 45,
+            })
+            .init_resource::<WorldEpoch>()
+            .add_message::<OnReleasedFromService>()
+            .add_message::<OnTaskAbandoned>()
+            .add_message::<OnGatheringJoined>()
+            .add_message::<OnDreamContributed>()
+            .add_systems(Update, life_log_message_system)
+            .add_observer(on_soul_recruited_life_log)
+            .add_observer(on_task_completed_life_log)
+            .add_observer(on_exhausted_life_log)
+            .add_observer(on_stress_breakdown_life_log)
+            .add_observer(on_rest_started_life_log);
+        app
+    }
+
+    fn kinds(app: &App, soul: Entity) -> Vec<SoulLifeEventKind> {
+        let mut kinds: Vec<_> = app
+            .world()
+            .get::<SoulLifeLog>(soul)
+            .expect("life log")
+            .iter_newest_first()
+            .map(|entry| entry.kind)
+            .collect();
+        kinds.reverse();
+        kinds
+    }
+
+    fn dream(soul: Entity, amount: f32, is_final: bool) -> OnDreamContributed {
+        OnDreamContributed {
+            soul,
+            amount,
+            is_final,
+        }
+    }
+
+    #[test]
+    fn domain_events_are_recorded_with_game_time() {
+        let mut app = app();
+        let soul = app.world_mut().spawn(SoulLifeLog::default()).id();
+        let familiar = app.world_mut().spawn_empty().id();
+        let rest_area = app.world_mut().spawn_empty().id();
+
+        let world = app.world_mut();
+        world.trigger(OnSoulRecruited {
+            entity: soul,
+            familiar_entity: familiar,
+        });
+        world.trigger(OnTaskCompleted {
+            entity: soul,
+            assignment_entity: familiar,
+            current_target_entity: rest_area,
+            current_work_type: WorkType::Chop,
+        });
+        world.trigger(OnExhausted { entity: soul });
+        world.trigger(OnStressBreakdown { entity: soul });
+        world.entity_mut(soul).insert(RestingIn(rest_area));
+
+        assert_eq!(
+            kinds(&app, soul),
+            vec![
+                SoulLifeEventKind::Recruited,
+                SoulLifeEventKind::TaskCompleted(WorkType::Chop),
+                SoulLifeEventKind::Exhausted,
+                SoulLifeEventKind::StressBreakdown,
+                SoulLifeEventKind::RestStarted,
+            ]
+        );
+        let newest = app
+            .world()
+            .get::<SoulLifeLog>(soul)
+            .unwrap()
+            .iter_newest_first()
+            .next()
+            .copied();
+        assert_eq!(
+            newest,
+            Some(SoulLifeLogEntry {
+                day: 2,
+                hour: 13,
+                minute: 45,
+                kind: SoulLifeEventKind::RestStarted,
+            })
+        );
+    }
+
+    #[test]
+    fn messages_are_recorded_and_dream_is_summed_until_final() {
+        let mut app = app();
+        let soul = app.world_mut().spawn(SoulLifeLog::default()).id();
+
+        app.world_mut()
+            .write_message(OnReleasedFromService { entity: soul });
+        app.world_mut()
+            .write_message(OnTaskAbandoned { entity: soul });
+        app.world_mut()
+            .write_message(OnGatheringJoined { entity: soul });
+        app.world_mut().write_message(dream(soul, 1.5, false));
+        app.update();
+
+        assert_eq!(
+            kinds(&app, soul),
+            vec![
+                SoulLifeEventKind::Released,
+                SoulLifeEventKind::TaskAbandoned,
+                SoulLifeEventKind::JoinedGathering,
+            ]
+        );
+
+        app.world_mut().write_message(dream(soul, 2.0, false));
+        app.update();
+        app.world_mut().write_message(dream(soul, 0.5, true));
+        app.update();
+
+        assert_eq!(
+            kinds(&app, soul).last(),
+            Some(&SoulLifeEventKind::DreamReleased { amount: 4.0 })
+        );
+        assert_eq!(kinds(&app, soul).len(), 4);
+    }
+
+    #[test]
+    fn pending_dream_is_dropped_on_world_replacement() {
+        let mut app = app();
+        let soul = app.world_mut().spawn(SoulLifeLog::default()).id();
+
+        app.world_mut().write_message(dream(soul, 3.0, false));
+        app.update();
+        app.world_mut().resource_mut::<WorldEpoch>().advance();
+        app.world_mut().write_message(dream(soul, 1.0, true));
+        app.update();
+
+        assert_eq!(
+            kinds(&app, soul),
+            vec![SoulLifeEventKind::DreamReleased { amount: 1.0 }]
+        );
+    }
+}
@@ -1,7 +1,10 @@
 pub mod dream_update;
 pub mod gathering_tick;
+pub mod life_log;
+pub mod penance;
 pub mod rest_area_update;
 pub mod slow_simulation;
+pub mod social;
 pub mod state_sanity;
 pub mod vitals;
 pub mod vitals_influence;
@@ -0,0 +1,55 @@
+//! Soul の贖罪ポイント（penance）の蓄積
+//!
+//! タスク完了（Observer）と DreamPool への Dream 加算（`OnDreamContributed`）から加算する。
+
+use bevy::prelude::*;
+
+use hw_core::events::{OnDreamContributed, OnTaskCompleted};
+use hw_core::redemption::{PENANCE_PER_DREAM_RELEASED, PENANCE_PER_TASK_COMPLETED, SoulPenance};
+
+pub fn on_task_completed_penance(on: On<OnTaskCompleted>, mut q_penance: Query<&mut SoulPenance>) {
+    if let Ok(mut penance) = q_penance.get_mut(on.event().entity) {
+        penance.add(PENANCE_PER_TASK_COMPLETED);
+    }
+}
+
+/// DreamPool へ実際に加算された Dream 量に比例して penance を加算する
+pub fn penance_dream_accrual_system(
+    mut contributions: MessageReader<OnDreamContributed>,
+    mut q_penance: Query<&mut SoulPenance>,
+) {
+    for contribution in contributions.read() {
+        if let Ok(mut penance) = q_penance.get_mut(contribution.soul) {
+            penance.add(contribution.amount * PENANCE_PER_DREAM_RELEASED);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::ScheduleRunnerPlugin;
+    use hw_core::soul::DamnedSoul;
+
+    #[test]
+    fn dream_contributions_accumulate_penance() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_once()))
+            .add_message::<OnDreamContributed>()
+            .add_systems(Update, penance_dream_accrual_system);
+
+        let soul = app.world_mut().spawn(DamnedSoul::default()).id();
+        for is_final in [false, true] {
+            app.world_mut().write_message(OnDreamContributed {
+                soul,
+                amount: 4.0,
+                is_final,
+            });
+        }
+
+        app.update();
+
+        let penance = app.world().get::<SoulPenance>(soul).unwrap();
+        assert_eq!(penance.score, 8.0 * PENANCE_PER_DREAM_RELEASED);
+    }
+}
@@ -8,8 +8,10 @@
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::time::Virtual;
+use hw_core::edicts::DreamEdicts;
 use hw_core::events::{
     DreamTransferVisualSource, DreamTransferredVisualMessage, IdleBehaviorRequest,
+    OnDreamContributed,
 };
 use hw_core::familiar::{ActiveCommand, Familiar};
 use hw_core::soul::{DreamPool, DreamQuality};
@@ -31,7 +33,7 @@ struct PendingDreamTransfer {
 }
 
 /// Aggregates all slow steps in one driver invocation into at most one visual
-/// message per Soul. The first positive transfer fixes the visual snapshot;
+/// message and one `OnDreamContributed` per Soul. The first positive transfer fixes the visual snapshot;
 /// later steps must describe the same source and quality.
 #[derive(Default)]
 pub(crate) struct DreamTransferAccumulator {
@@ -98,9 +100,18 @@ impl DreamTransferAccumulator {
             });
     }
 
-    fn publish(&mut self, writer: &mut MessageWriter<DreamTransferredVisualMessage>) {
+    fn publish(
+        &mut self,
+        visual_writer: &mut MessageWriter<DreamTransferredVisualMessage>,
+        contribution_writer: &mut MessageWriter<OnDreamContributed>,
+    ) {
         for (soul, pending) in self.transfers.drain() {
-            writer.write(DreamTransferredVisualMessage {
+            contribution_writer.write(OnDreamContributed {
+                soul,
+                amount: pending.amount,
+                is_final: pending.is_final,
+            });
+            visual_writer.write(DreamTransferredVisualMessage {
                 soul,
                 amount: pending.amount,
                 quality: pending.quality,
@@ -171,8 +182,10 @@ pub fn advance_slow_simulation_clock_system(
 pub(crate) struct SlowSimulationDriverParams<'w, 's> {
     commands: Commands<'w, 's>,
     dream_pool: ResMut<'w, DreamPool>,
+    edicts: Res<'w, DreamEdicts>,
     request_writer: MessageWriter<'w, IdleBehaviorRequest>,
     dream_transfer_writer: MessageWriter<'w, DreamTransferredVisualMessage>,
+    dream_contribution_writer: MessageWriter<'w, OnDreamContributed>,
     familiar_grid: Res<'w, FamiliarSpatialGrid>,
     q_familiars: Query<
         'w,
@@ -217,6 +230,7 @@ pub(crate) fn slow_simulation_driver_system(
     params.breakdown_notifications.clear();
     params.exhausted_notifications.clear();
     params.dream_transfers.clear();
+    let modifiers = params.edicts.modifiers();
 
     for _ in 0..clock.steps_this_frame() {
         let dt = clock.step_secs();
@@ -228,6 +242,7 @@ pub(crate) fn slow_simulation_driver_system(
             let mut q_souls = params.queries.p0();
             let _souls_updated = vitals_update::fatigue_update_step(
                 dt,
+                &modifiers,
                 &mut params.commands,
                 &mut params.exhausted_notifications,
                 &mut q_souls,
@@ -271,7 +286,7 @@ pub(crate) fn slow_simulation_driver_system(
         {
             let mut q_souls = params.queries.p4();
             vitals_influence::familiar_influence_step(
-                dt,
+                vitals_influence::VitalsStep { dt, modifiers },
                 &mut params.commands,
                 &params.familiar_grid,
                 &params.q_familiars,
@@ -282,9 +297,10 @@ pub(crate) fn slow_simulation_driver_system(
         }
     }
 
-    params
-        .dream_transfers
-        .publish(&mut params.dream_transfer_writer);
+    params.dream_transfers.publish(
+        &mut params.dream_transfer_writer,
+        &mut params.dream_contribution_writer,
+    );
 }
 
 #[cfg(test)]
@@ -298,11 +314,17 @@ mod tests {
     #[derive(Resource, Default)]
     struct TransferProbe(Vec<DreamTransferredVisualMessage>);
 
+    #[derive(Resource, Default)]
+    struct ContributionProbe(Vec<OnDreamContributed>);
+
     fn collect_transfers(
         mut messages: MessageReader<DreamTransferredVisualMessage>,
+        mut contributions: MessageReader<OnDreamContributed>,
         mut probe: ResMut<TransferProbe>,
+        mut contribution_probe: ResMut<ContributionProbe>,
     ) {
         probe.0.extend(messages.read().copied());
+        contribution_probe.0.extend(contributions.read().copied());
     }
 
     fn driver_test_app(steps: u8) -> App {
@@ -313,10 +335,13 @@ mod tests {
         };
         app.insert_resource(clock)
             .init_resource::<DreamPool>()
+            .init_resource::<DreamEdicts>()
             .init_resource::<FamiliarSpatialGrid>()
             .init_resource::<TransferProbe>()
+            .init_resource::<ContributionProbe>()
             .add_message::<IdleBehaviorRequest>()
             .add_message::<DreamTransferredVisualMessage>()
+            .add_message::<OnDreamContributed>()
             .add_systems(
                 Update,
                 (slow_simulation_driver_system, collect_transfers).chain(),
@@ -457,6 +482,14 @@ mod tests {
         assert!((probe.0[0].amount - pool.points).abs() <= 1e-5);
         assert_eq!(probe.0[0].quality, DreamQuality::NormalDream);
         assert!(probe.0[0].is_final);
+        assert_eq!(
+            app.world().resource::<ContributionProbe>().0,
+            vec![OnDreamContributed {
+                soul,
+                amount: probe.0[0].amount,
+                is_final: true,
+            }]
+        );
         assert_eq!(
             probe.0[0].source,
             DreamTransferVisualSource::Sleeping {
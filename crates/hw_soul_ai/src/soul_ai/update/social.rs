@@ -0,0 +1,200 @@
+//! Soul 同士の関係性（友好度）の更新
+//!
+//! - 同じ集会への参加で双方向に友好度を上げる
+//! - 同じ使い魔の分隊で近くにいる間、友好度が少しずつ上がる
+//! - 高ストレスの近隣 Soul への友好度が下がる
+//! - 作業中、近くの友人はストレスを和らげ、ライバルは増やす
+
+use bevy::prelude::*;
+
+use hw_core::events::OnGatheringParticipated;
+use hw_core::relationships::{CommandedBy, GatheringParticipants};
+use hw_core::social::*;
+use hw_core::soul::DamnedSoul;
+use hw_jobs::AssignedTask;
+use hw_spatial::{SpatialGrid, SpatialGridOps};
+
+use super::slow_simulation::SlowSimulationClock;
+
+/// 集会参加メッセージから、同じ集会の参加者同士の友好度を上げる
+pub fn social_gathering_affinity_system(
+    mut reader: MessageReader<OnGatheringParticipated>,
+    q_participants: Query<&GatheringParticipants>,
+    mut q_relationships: Query<&mut SoulRelationships>,
+) {
+    for event in reader.read() {
+        let Ok(participants) = q_participants.get(event.spot_entity) else {
+            continue;
+        };
+        for &other in participants.iter() {
+            if other == event.entity {
+                continue;
+            }
+            let gain = SOCIAL_AFFINITY_SHARED_GATHERING;
+            if let Ok(mut relationships) = q_relationships.get_mut(event.entity) {
+                relationships.adjust(other, gain);
+            }
+            if let Ok(mut relationships) = q_relationships.get_mut(other) {
+                relationships.adjust(event.entity, gain);
+            }
+        }
+    }
+}
+
+/// `social_proximity_system` が近接判定の前に取る Soul ごとのスナップショット
+#[derive(Clone, Copy)]
+struct SocialSnapshot {
+    entity: Entity,
+    stress: f32,
+    commander: Option<Entity>,
+}
+
+type SocialSoulQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static mut DamnedSoul,
+        &'static mut SoulRelationships,
+        &'static AssignedTask,
+        Option<&'static CommandedBy>,
+    ),
+>;
+
+/// 近接 Soul 間の友好度とストレスを slow simulation の step 数ぶん更新する
+pub fn social_proximity_system(
+    clock: Res<SlowSimulationClock>,
+    soul_grid: Res<SpatialGrid>,
+    mut nearby_buf: Local<Vec<Entity>>,
+    mut souls: Local<Vec<(Entity, Vec2, bool)>>,
+    mut q_souls: SocialSoulQuery,
+) {
+    let steps = clock.steps_this_frame();
+    if steps == 0 {
+        return;
+    }
+    let dt = clock.step_secs() * f32::from(steps);
+
+    souls.clear();
+    souls.extend(q_souls.iter().map(|(entity, transform, _, _, task, _)| {
+        (
+            entity,
+            transform.translation.truncate(),
+            !matches!(*task, AssignedTask::None),
+        )
+    }));
+
+    let mut snapshots = Vec::new();
+    for &(entity, pos, working) in souls.iter() {
+        soul_grid.get_nearby_in_radius_into(pos, SOCIAL_INFLUENCE_RADIUS, &mut nearby_buf);
+        snapshots.clear();
+        for &other in nearby_buf.iter() {
+            if other == entity {
+                continue;
+            }
+            let Ok((_, _, other_soul, _, _, other_commander)) = q_souls.get(other) else {
+                continue;
+            };
+            snapshots.push(SocialSnapshot {
+                entity: other,
+                stress: other_soul.stress,
+                commander: other_commander.map(|commanded_by| commanded_by.0),
+            });
+        }
+        if snapshots.is_empty() {
+            continue;
+        }
+
+        let Ok((_, _, mut soul, mut relationships, _, commander)) = q_souls.get_mut(entity) else {
+            continue;
+        };
+        let commander = commander.map(|commanded_by| commanded_by.0);
+
+        let mut friends_nearby = 0_u32;
+        let mut rivals_nearby = 0_u32;
+        for snapshot in snapshots.iter() {
+            if commander.is_some() && snapshot.commander == commander {
+                relationships.adjust(snapshot.entity, SOCIAL_AFFINITY_SQUAD_RATE * dt);
+            }
+            if snapshot.stress >= SOCIAL_STRESS_SPREAD_THRESHOLD {
+                relationships.adjust(snapshot.entity, -SOCIAL_AFFINITY_STRESS_DECAY_RATE * dt);
+            }
+            match relationships.standing(snapshot.entity) {
+                SocialStanding::Friend => friends_nearby += 1,
+                SocialStanding::Rival => rivals_nearby += 1,
+                SocialStanding::Neutral => {}
+            }
+        }
+
+        if working {
+            let stress_delta = rivals_nearby as f32 * SOCIAL_RIVAL_STRESS_RATE
+                - friends_nearby as f32 * SOCIAL_FRIEND_STRESS_RELIEF_RATE;
+            soul.stress = (soul.stress + stress_delta * dt).clamp(0.0, 1.0);
+        }
+    }
+}
+
+/// 消滅した Soul への関係を全 Soul から取り除く
+pub fn on_soul_removed_forget_relationships(
+    on: On<Remove, DamnedSoul>,
+    mut q_relationships: Query<&mut SoulRelationships>,
+) {
+    let removed = on.entity;
+    for mut relationships in q_relationships.iter_mut() {
+        if relationships
+            .bonds()
+            .iter()
+            .any(|bond| bond.other == removed)
+        {
+            relationships.forget(removed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::ScheduleRunnerPlugin;
+    use hw_core::relationships::ParticipatingIn;
+
+    #[test]
+    fn shared_gathering_raises_affinity_both_ways() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_once()))
+            .add_message::<OnGatheringParticipated>()
+            .add_systems(Update, social_gathering_affinity_system);
+
+        let spot = app.world_mut().spawn_empty().id();
+        let veteran = app
+            .world_mut()
+            .spawn((DamnedSoul::default(), ParticipatingIn(spot)))
+            .id();
+        let newcomer = app
+            .world_mut()
+            .spawn((DamnedSoul::default(), ParticipatingIn(spot)))
+            .id();
+        app.world_mut().write_message(OnGatheringParticipated {
+            entity: newcomer,
+            spot_entity: spot,
+        });
+
+        app.update();
+
+        let affinity = |soul: Entity, other: Entity| {
+            app.world()
+                .get::<SoulRelationships>(soul)
+                .unwrap()
+                .affinity(other)
+        };
+        assert_eq!(
+            affinity(newcomer, veteran),
+            SOCIAL_AFFINITY_SHARED_GATHERING
+        );
+        assert_eq!(
+            affinity(veteran, newcomer),
+            SOCIAL_AFFINITY_SHARED_GATHERING
+        );
+        assert_eq!(affinity(newcomer, newcomer), 0.0);
+    }
+}
@@ -169,7 +169,10 @@ pub fn reconcile_rest_state_system(
             && (resting_in.is_some() || reserved_for.is_some())
             && matches!(
                 idle.behavior,
-                IdleBehavior::Escaping | IdleBehavior::Drifting | IdleBehavior::ExhaustedGathering
+                IdleBehavior::Escaping
+                    | IdleBehavior::Drifting
+                    | IdleBehavior::Redeeming
+                    | IdleBehavior::ExhaustedGathering
             )
         {
             commands
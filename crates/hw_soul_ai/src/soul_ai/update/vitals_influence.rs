@@ -1,6 +1,8 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 use hw_core::constants::*;
+use hw_core::edicts::{DreamEdicts, EdictModifiers};
 use hw_core::events::publish_stress_breakdown;
 use hw_core::familiar::{ActiveCommand, Familiar, FamiliarCommand};
 use hw_core::relationships::CommandedBy;
@@ -11,6 +13,20 @@ use hw_world::SpatialGridOps;
 
 use super::slow_simulation::SlowSimulationClock;
 
+/// 1ステップ分の経過時間と、そのステップに適用する勅令補正
+#[derive(Clone, Copy)]
+pub(crate) struct VitalsStep {
+    pub dt: f32,
+    pub modifiers: EdictModifiers,
+}
+
+/// slow simulation の step 数と、その間に適用する勅令
+#[derive(SystemParam)]
+pub struct VitalsStepClock<'w> {
+    clock: Res<'w, SlowSimulationClock>,
+    edicts: Res<'w, DreamEdicts>,
+}
+
 pub(crate) type SoulVitalsQuery<'w, 's> = Query<
     'w,
     's,
@@ -28,7 +44,7 @@ pub(crate) type SoulVitalsQuery<'w, 's> = Query<
 /// Familiar影響関連の更新を1パスで処理する統合システム
 pub fn familiar_influence_unified_system(
     mut commands: Commands,
-    clock: Res<SlowSimulationClock>,
+    step_clock: VitalsStepClock,
     familiar_grid: Res<FamiliarSpatialGrid>,
     mut nearby_buf: Local<Vec<Entity>>,
     mut breakdown_notifications: Local<std::collections::HashSet<Entity>>,
@@ -36,9 +52,14 @@ pub fn familiar_influence_unified_system(
     mut q_souls: SoulVitalsQuery<'_, '_>,
 ) {
     breakdown_notifications.clear();
+    let VitalsStepClock { clock, edicts } = step_clock;
+    let modifiers = edicts.modifiers();
     for _ in 0..clock.steps_this_frame() {
         familiar_influence_step(
-            clock.step_secs(),
+            VitalsStep {
+                dt: clock.step_secs(),
+                modifiers,
+            },
             &mut commands,
             &familiar_grid,
             &q_familiars,
@@ -50,7 +71,7 @@ pub fn familiar_influence_unified_system(
 }
 
 pub(crate) fn familiar_influence_step(
-    dt: f32,
+    step: VitalsStep,
     commands: &mut Commands,
     familiar_grid: &FamiliarSpatialGrid,
     q_familiars: &Query<(&Transform, &Familiar, &ActiveCommand)>,
@@ -58,6 +79,7 @@ pub(crate) fn familiar_influence_step(
     breakdown_notifications: &mut std::collections::HashSet<Entity>,
     q_souls: &mut SoulVitalsQuery,
 ) {
+    let VitalsStep { dt, modifiers } = step;
     let familiar_search_radius = TILE_SIZE * 15.0;
     let supervision_eval_radius_sq = (TILE_SIZE * 10.0).powi(2);
     for (entity, soul_transform, mut soul, task, idle, under_command, breakdown_opt) in
@@ -104,7 +126,8 @@ pub(crate) fn familiar_influence_step(
             best_influence = best_influence.max(influence);
         }
 
-        let dream_stress_factor = 1.0 + soul.dream * DREAM_STRESS_MULTIPLIER;
+        let dream_stress_factor =
+            (1.0 + soul.dream * DREAM_STRESS_MULTIPLIER) * modifiers.stress_gain_multiplier;
         if has_task {
             soul.stress = (soul.stress + dt * STRESS_WORK_RATE * dream_stress_factor).min(1.0);
         } else if under_command.is_some() {
@@ -137,6 +160,10 @@ pub(crate) fn familiar_influence_step(
             soul.laziness = (soul.laziness + dt * LAZINESS_GAIN_RATE_IDLE).min(1.0);
         }
 
+        if modifiers.motivation_bonus_rate > 0.0 {
+            soul.motivation = (soul.motivation + dt * modifiers.motivation_bonus_rate).min(1.0);
+        }
+
         if soul.stress >= 1.0 && breakdown_opt.is_none() && breakdown_notifications.insert(entity) {
             publish_stress_breakdown(commands, entity);
         }
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use std::collections::HashSet;
 
 use hw_core::constants::*;
+use hw_core::edicts::{DreamEdicts, EdictModifiers};
 use hw_core::events::publish_soul_exhausted;
 use hw_core::relationships::CommandedBy;
 use hw_core::soul::{DamnedSoul, IdleBehavior, IdleState};
@@ -26,14 +27,17 @@ pub(crate) type FatiguePenaltyQuery<'w, 's> = Query<'w, 's, &'static mut DamnedS
 /// 疲労の増減を管理するシステム
 pub fn fatigue_update_system(
     clock: Res<SlowSimulationClock>,
+    edicts: Res<DreamEdicts>,
     mut commands: Commands,
     mut exhausted_notifications: Local<HashSet<Entity>>,
     mut q_souls: FatigueUpdateQuery,
 ) {
     exhausted_notifications.clear();
+    let modifiers = edicts.modifiers();
     for _ in 0..clock.steps_this_frame() {
         fatigue_update_step(
             clock.step_secs(),
+            &modifiers,
             &mut commands,
             &mut exhausted_notifications,
             &mut q_souls,
@@ -43,11 +47,13 @@ pub fn fatigue_update_system(
 
 pub(crate) fn fatigue_update_step(
     dt: f32,
+    modifiers: &EdictModifiers,
     commands: &mut Commands,
     exhausted_notifications: &mut HashSet<Entity>,
     q_souls: &mut FatigueUpdateQuery,
 ) -> u64 {
     let mut souls_updated = 0_u64;
+    let recovery = modifiers.fatigue_recovery_multiplier;
     for (entity, mut soul, task, idle, under_command) in q_souls.iter_mut() {
         souls_updated = souls_updated.saturating_add(1);
         let has_task = !matches!(task, AssignedTask::None);
@@ -58,10 +64,11 @@ pub(crate) fn fatigue_update_step(
             soul.fatigue = (soul.fatigue + dt * FATIGUE_WORK_RATE).min(1.0);
         } else if under_command.is_some() {
             // 使役中の待機: 疲労減少（遅い）
-            soul.fatigue = (soul.fatigue - dt * FATIGUE_RECOVERY_RATE_COMMANDED).max(0.0);
+            soul.fatigue =
+                (soul.fatigue - dt * FATIGUE_RECOVERY_RATE_COMMANDED * recovery).max(0.0);
         } else {
             // 通常の待機: 疲労減少（速い）
-            soul.fatigue = (soul.fatigue - dt * FATIGUE_RECOVERY_RATE_IDLE).max(0.0);
+            soul.fatigue = (soul.fatigue - dt * FATIGUE_RECOVERY_RATE_IDLE * recovery).max(0.0);
         }
 
         let crossed_exhausted_threshold = prev_fatigue <= FATIGUE_GATHERING_THRESHOLD
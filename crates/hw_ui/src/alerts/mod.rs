@@ -0,0 +1,13 @@
+//! プレイヤー設定のアラート規則を編集するパネル
+//!
+//! `hw_core::alerts::AlertRules` の各規則を 1 行ずつ表示し、有効化・しきい値・資源種別を
+//! その場で書き換える。規則の評価と通知は root の `alerts` システムが行う。
+
+mod ui;
+
+pub(crate) use ui::spawn_alert_panel;
+pub use ui::{
+    AlertAddButton, AlertPanelRoot, AlertPanelState, AlertRuleAction, AlertRuleButton,
+    AlertRuleConditionText, AlertRuleRow, AlertRuleToggleText, alert_panel_intent_system,
+    alert_panel_interaction_system, sync_alert_panel_system,
+};
@@ -0,0 +1,394 @@
+use crate::UiIntent;
+use crate::components::UiInputBlocker;
+use crate::interaction::update_interaction_color;
+use crate::locale::LocalizedText;
+use crate::theme::{UiTheme, font_size_rem};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use hw_core::alerts::{AlertCondition, AlertRules, MAX_ALERT_RULES};
+use hw_core::locale::Localization;
+use hw_core::logistics::ResourceType;
+
+/// 条件文の表示幅（論理ピクセル）
+const ALERT_CONDITION_WIDTH: f32 = 180.0;
+/// 追加ボタンで作る備蓄アラートの既定しきい値
+const DEFAULT_RESOURCE_THRESHOLD: u32 = 20;
+
+/// アラートパネルの表示状態
+#[derive(Resource, Debug, Default)]
+pub struct AlertPanelState {
+    pub open: bool,
+}
+
+#[derive(Component)]
+pub struct AlertPanelRoot;
+
+/// 規則 1 行（`AlertRules::rules()` の並び順）
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AlertRuleRow(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlertRuleAction {
+    Toggle,
+    CycleResource,
+    Decrease,
+    Increase,
+    Remove,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AlertRuleButton {
+    pub slot: usize,
+    pub action: AlertRuleAction,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AlertRuleToggleText(pub usize);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AlertRuleConditionText(pub usize);
+
+#[derive(Component)]
+pub struct AlertAddButton;
+
+fn spawn_text_button(
+    commands: &mut Commands,
+    text_font: &TextFont,
+    theme: &UiTheme,
+    width: Option<f32>,
+    label: impl Bundle,
+    marker: impl Bundle,
+) -> Entity {
+    let button = commands
+        .spawn((
+            Button,
+            Node {
+                width: width.map_or(Val::Auto, Val::Px),
+                height: Val::Px(22.0),
+                padding: UiRect::horizontal(Val::Px(4.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(theme.colors.button_default),
+            BorderColor::all(theme.colors.border_default),
+            marker,
+        ))
+        .id();
+    let text = commands
+        .spawn((
+            text_font.clone(),
+            TextColor(theme.colors.text_primary_semantic),
+            label,
+        ))
+        .id();
+    commands.entity(button).add_child(text);
+    button
+}
+
+pub(crate) fn spawn_alert_panel(
+    commands: &mut Commands,
+    font: Handle<Font>,
+    theme: &UiTheme,
+    parent: Entity,
+) {
+    let text_font = TextFont {
+        font: font.into(),
+        font_size: font_size_rem(theme.typography.font_size_xs),
+        ..default()
+    };
+    let root = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                left: Val::Px(theme.spacing.panel_margin_x),
+                bottom: Val::Px(theme.spacing.bottom_bar_height + theme.spacing.panel_padding),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(theme.sizes.panel_border_width)),
+                border_radius: BorderRadius::all(Val::Px(theme.sizes.panel_corner_radius)),
+                ..default()
+            },
+            BackgroundColor(theme.colors.bg_overlay),
+            BorderColor::all(theme.colors.border_default),
+            UiInputBlocker,
+            FocusPolicy::Block,
+            AlertPanelRoot,
+            Name::new("Alert Panel"),
+        ))
+        .id();
+    let title = commands
+        .spawn((
+            LocalizedText("ui.alerts.title"),
+            text_font.clone(),
+            TextColor(theme.colors.text_primary_semantic),
+        ))
+        .id();
+    commands.entity(root).add_child(title);
+
+    for slot in 0..MAX_ALERT_RULES {
+        let row = commands
+            .spawn((
+                Node {
+                    display: Display::None,
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(4.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                AlertRuleRow(slot),
+                Name::new(format!("Alert Rule {slot}")),
+            ))
+            .id();
+        let button = |action| AlertRuleButton { slot, action };
+        let buttons = [
+            spawn_text_button(
+                commands,
+                &text_font,
+                theme,
+                Some(36.0),
+                (Text::default(), AlertRuleToggleText(slot)),
+                button(AlertRuleAction::Toggle),
+            ),
+            spawn_text_button(
+                commands,
+                &text_font,
+                theme,
+                Some(ALERT_CONDITION_WIDTH),
+                (Text::default(), AlertRuleConditionText(slot)),
+                button(AlertRuleAction::CycleResource),
+            ),
+            spawn_text_button(
+                commands,
+                &text_font,
+                theme,
+                Some(22.0),
+                Text::new("-"),
+                button(AlertRuleAction::Decrease),
+            ),
+            spawn_text_button(
+                commands,
+                &text_font,
+                theme,
+                Some(22.0),
+                Text::new("+"),
+                button(AlertRuleAction::Increase),
+            ),
+            spawn_text_button(
+                commands,
+                &text_font,
+                theme,
+                Some(22.0),
+                Text::new("×"),
+                button(AlertRuleAction::Remove),
+            ),
+        ];
+        commands.entity(row).add_children(&buttons);
+        commands.entity(root).add_child(row);
+    }
+
+    let add = spawn_text_button(
+        commands,
+        &text_font,
+        theme,
+        None,
+        LocalizedText("ui.alerts.add_stock"),
+        AlertAddButton,
+    );
+    commands.entity(root).add_child(add);
+    commands.entity(parent).add_child(root);
+}
+
+type AlertRuleButtonQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Interaction,
+        &'static AlertRuleButton,
+        &'static mut BackgroundColor,
+    ),
+    (Changed<Interaction>, Without<AlertAddButton>),
+>;
+
+type AlertAddButtonQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static mut BackgroundColor),
+    (Changed<Interaction>, With<AlertAddButton>),
+>;
+
+/// `UiIntent::ToggleAlerts` でパネルを開閉する。
+pub fn alert_panel_intent_system(
+    mut intents: MessageReader<UiIntent>,
+    mut state: ResMut<AlertPanelState>,
+    mut q_root: Query<&mut Node, With<AlertPanelRoot>>,
+) {
+    for intent in intents.read() {
+        if matches!(intent, UiIntent::ToggleAlerts) {
+            state.open = !state.open;
+        }
+    }
+    let display = if state.open {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut q_root {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// 行のボタンで規則を編集し、追加ボタンで備蓄アラートを足す。
+pub fn alert_panel_interaction_system(
+    rules: Option<ResMut<AlertRules>>,
+    theme: Res<UiTheme>,
+    mut q_rule_buttons: AlertRuleButtonQuery,
+    mut q_add_button: AlertAddButtonQuery,
+) {
+    let Some(mut rules) = rules else {
+        return;
+    };
+    for (interaction, button, mut color) in &mut q_rule_buttons {
+        update_interaction_color(*interaction, &mut color, &theme);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(id) = rules.rules().get(button.slot).map(|rule| rule.id) else {
+            continue;
+        };
+        if button.action == AlertRuleAction::Remove {
+            rules.remove(id);
+            continue;
+        }
+        let Some(rule) = rules.get_mut(id) else {
+            continue;
+        };
+        match button.action {
+            AlertRuleAction::Toggle => rule.enabled = !rule.enabled,
+            AlertRuleAction::CycleResource => rule.condition.cycle_resource(),
+            AlertRuleAction::Decrease => rule.condition.adjust(-1),
+            AlertRuleAction::Increase => rule.condition.adjust(1),
+            AlertRuleAction::Remove => {}
+        }
+    }
+    for (interaction, mut color) in &mut q_add_button {
+        update_interaction_color(*interaction, &mut color, &theme);
+        if *interaction == Interaction::Pressed {
+            rules.push(AlertCondition::ResourceBelow {
+                resource: ResourceType::Wood,
+                threshold: DEFAULT_RESOURCE_THRESHOLD,
+            });
+        }
+    }
+}
+
+/// 規則の変更やパネルを開いたときに、各行の表示を規則一覧へ合わせる。
+pub fn sync_alert_panel_system(
+    state: Res<AlertPanelState>,
+    rules: Option<Res<AlertRules>>,
+    localization: Res<Localization>,
+    mut q_rows: Query<(&AlertRuleRow, &mut Node), Without<AlertRuleButton>>,
+    mut q_buttons: Query<(&AlertRuleButton, &mut Node), Without<AlertRuleRow>>,
+    mut q_toggle_text: Query<(&AlertRuleToggleText, &mut Text), Without<AlertRuleConditionText>>,
+    mut q_condition_text: Query<(&AlertRuleConditionText, &mut Text), Without<AlertRuleToggleText>>,
+) {
+    let Some(rules) = rules else {
+        return;
+    };
+    let changed = state.is_changed() || rules.is_changed() || localization.is_changed();
+    if !state.open || !changed {
+        return;
+    }
+    let rules = rules.rules();
+    for (row, mut node) in &mut q_rows {
+        node.display = if row.0 < rules.len() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (button, mut node) in &mut q_buttons {
+        let Some(rule) = rules.get(button.slot) else {
+            continue;
+        };
+        let visible = match button.action {
+            AlertRuleAction::Decrease | AlertRuleAction::Increase => rule.condition.is_adjustable(),
+            _ => true,
+        };
+        node.display = if visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (slot, mut text) in &mut q_toggle_text {
+        if let Some(rule) = rules.get(slot.0) {
+            let id = if rule.enabled {
+                "ui.alerts.on"
+            } else {
+                "ui.alerts.off"
+            };
+            text.0 = localization.text(id).to_string();
+        }
+    }
+    for (slot, mut text) in &mut q_condition_text {
+        if let Some(rule) = rules.get(slot.0) {
+            text.0 = rule.condition.label(&localization);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_buttons_edit_rules_and_the_add_button_appends_a_stock_rule() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<UiTheme>()
+            .init_resource::<AlertRules>()
+            .add_systems(Update, alert_panel_interaction_system);
+        for action in [AlertRuleAction::Toggle, AlertRuleAction::Increase] {
+            app.world_mut().spawn((
+                Interaction::Pressed,
+                BackgroundColor::default(),
+                AlertRuleButton { slot: 0, action },
+            ));
+        }
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            BackgroundColor::default(),
+            AlertRuleButton {
+                slot: 1,
+                action: AlertRuleAction::Remove,
+            },
+        ));
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            BackgroundColor::default(),
+            AlertAddButton,
+        ));
+
+        app.update();
+
+        let rules = app.world().resource::<AlertRules>().rules();
+        assert_eq!(rules.len(), 5);
+        assert!(!rules[0].enabled);
+        assert_eq!(
+            rules[0].condition,
+            AlertCondition::ResourceBelow {
+                resource: ResourceType::Wood,
+                threshold: 25,
+            }
+        );
+        assert_eq!(rules[1].condition, AlertCondition::GridBlackout);
+        assert_eq!(rules[4].id, 6);
+    }
+}
@@ -1,15 +1,37 @@
 use super::{AreaEditDrag, AreaEditHandleKind, AreaEditOperation};
 use bevy::prelude::*;
 use bevy::window::{CursorIcon, SystemCursorIcon};
-use hw_core::area::TaskArea;
+use hw_core::area::{AreaBounds, TaskArea};
 use hw_core::constants::TILE_SIZE;
 
-pub fn detect_area_edit_operation(area: &TaskArea, world_pos: Vec2) -> Option<AreaEditOperation> {
+/// カーソル位置の編集操作と、その対象の部分矩形 index を返す。
+///
+/// 全部分矩形のハンドルを内部（Move）より優先し、重なる場合は後から追加した矩形を優先する。
+pub fn detect_area_edit_operation(
+    area: &TaskArea,
+    world_pos: Vec2,
+) -> Option<(usize, AreaEditOperation)> {
+    let rects: Vec<(usize, &AreaBounds)> = area.rects().enumerate().collect();
+    rects
+        .iter()
+        .rev()
+        .find_map(|&(index, rect)| {
+            detect_rect_resize_handle(rect, world_pos)
+                .map(|kind| (index, AreaEditOperation::Resize(kind)))
+        })
+        .or_else(|| {
+            rects.iter().rev().find_map(|&(index, rect)| {
+                let threshold = TILE_SIZE * 0.55;
+                (rect.center().distance(world_pos) <= threshold || rect.contains(world_pos))
+                    .then_some((index, AreaEditOperation::Move))
+            })
+        })
+}
+
+fn detect_rect_resize_handle(rect: &AreaBounds, world_pos: Vec2) -> Option<AreaEditHandleKind> {
     let threshold = TILE_SIZE * 0.55;
-    let min = area.min();
-    let max = area.max();
-    let mid_x = (min.x + max.x) * 0.5;
-    let mid_y = (min.y + max.y) * 0.5;
+    let min = rect.min;
+    let max = rect.max;
 
     let corners = [
         (AreaEditHandleKind::TopLeft, Vec2::new(min.x, max.y)),
@@ -19,34 +41,34 @@ pub fn detect_area_edit_operation(area: &TaskArea, world_pos: Vec2) -> Option<Ar
     ];
     for (kind, point) in corners {
         if point.distance(world_pos) <= threshold {
-            return Some(AreaEditOperation::Resize(kind));
+            return Some(kind);
         }
     }
 
     if (world_pos.y - max.y).abs() <= threshold && world_pos.x >= min.x && world_pos.x <= max.x {
-        return Some(AreaEditOperation::Resize(AreaEditHandleKind::Top));
+        return Some(AreaEditHandleKind::Top);
     }
     if (world_pos.x - max.x).abs() <= threshold && world_pos.y >= min.y && world_pos.y <= max.y {
-        return Some(AreaEditOperation::Resize(AreaEditHandleKind::Right));
+        return Some(AreaEditHandleKind::Right);
     }
     if (world_pos.y - min.y).abs() <= threshold && world_pos.x >= min.x && world_pos.x <= max.x {
-        return Some(AreaEditOperation::Resize(AreaEditHandleKind::Bottom));
+        return Some(AreaEditHandleKind::Bottom);
     }
     if (world_pos.x - min.x).abs() <= threshold && world_pos.y >= min.y && world_pos.y <= max.y {
-        return Some(AreaEditOperation::Resize(AreaEditHandleKind::Left));
-    }
-
-    if Vec2::new(mid_x, mid_y).distance(world_pos) <= threshold || area.contains(world_pos) {
-        return Some(AreaEditOperation::Move);
+        return Some(AreaEditHandleKind::Left);
     }
 
     None
 }
 
+/// ドラッグ対象の部分矩形だけを動かした／伸縮したエリアを返す
 pub fn apply_area_edit_drag(active_drag: &AreaEditDrag, current_snapped: Vec2) -> TaskArea {
     let min_size = TILE_SIZE.max(1.0);
-    let mut min = active_drag.original_area.min();
-    let mut max = active_drag.original_area.max();
+    let Some(original_rect) = active_drag.original_area.rect(active_drag.rect_index) else {
+        return active_drag.original_area.clone();
+    };
+    let mut min = original_rect.min;
+    let mut max = original_rect.max;
 
     match active_drag.operation {
         AreaEditOperation::Move => {
@@ -91,7 +113,9 @@ pub fn apply_area_edit_drag(active_drag: &AreaEditDrag, current_snapped: Vec2) -
         },
     }
 
-    TaskArea::from_points(min, max)
+    active_drag
+        .original_area
+        .with_rect_replaced(active_drag.rect_index, AreaBounds::from_points(min, max))
 }
 
 pub fn cursor_icon_for_operation(operation: AreaEditOperation, dragging: bool) -> CursorIcon {
@@ -119,3 +143,39 @@ pub fn cursor_icon_for_operation(operation: AreaEditOperation, dragging: bool) -
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hw_core::familiar::FamiliarCommand;
+
+    #[test]
+    fn drag_edits_only_the_sub_rect_under_the_cursor() {
+        let area = TaskArea::from_points(Vec2::ZERO, Vec2::splat(TILE_SIZE * 4.0)).with_rect_added(
+            AreaBounds::new(
+                Vec2::new(TILE_SIZE * 10.0, 0.0),
+                Vec2::new(TILE_SIZE * 14.0, TILE_SIZE * 4.0),
+            ),
+        );
+        let grab = Vec2::new(TILE_SIZE * 12.0, TILE_SIZE * 2.0);
+        let (rect_index, operation) = detect_area_edit_operation(&area, grab).expect("inside");
+        assert_eq!(rect_index, 1);
+        assert!(matches!(operation, AreaEditOperation::Move));
+
+        let drag = AreaEditDrag {
+            familiar_entity: Entity::PLACEHOLDER,
+            rect_index,
+            operation,
+            original_area: area.clone(),
+            original_destination: Vec2::ZERO,
+            original_command: FamiliarCommand::Patrol,
+            drag_start: grab,
+        };
+        let moved = apply_area_edit_drag(&drag, grab + Vec2::new(0.0, TILE_SIZE * 2.0));
+        assert_eq!(moved.rect(0), area.rect(0));
+        assert_eq!(
+            moved.rect(1).map(|rect| rect.min),
+            Some(Vec2::new(TILE_SIZE * 10.0, TILE_SIZE * 2.0))
+        );
+    }
+}
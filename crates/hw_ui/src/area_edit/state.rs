@@ -25,6 +25,8 @@ pub enum AreaEditOperation {
 #[derive(Clone)]
 pub struct AreaEditDrag {
     pub familiar_entity: Entity,
+    /// 編集中の部分矩形（`TaskArea::rect` の index）
+    pub rect_index: usize,
     pub operation: AreaEditOperation,
     pub original_area: TaskArea,
     pub original_destination: Vec2,
@@ -46,19 +48,24 @@ impl AreaEditSession {
         self.active_drag.is_some()
     }
 
-    pub fn operation_label(&self) -> Option<&'static str> {
+    /// ドラッグ中の操作名の文言 ID
+    pub fn operation_text_id(&self) -> Option<&'static str> {
         let drag = self.active_drag.as_ref()?;
         Some(match drag.operation {
-            AreaEditOperation::Move => "Move",
-            AreaEditOperation::Resize(AreaEditHandleKind::TopLeft) => "Resize TL",
-            AreaEditOperation::Resize(AreaEditHandleKind::Top) => "Resize T",
-            AreaEditOperation::Resize(AreaEditHandleKind::TopRight) => "Resize TR",
-            AreaEditOperation::Resize(AreaEditHandleKind::Right) => "Resize R",
-            AreaEditOperation::Resize(AreaEditHandleKind::BottomRight) => "Resize BR",
-            AreaEditOperation::Resize(AreaEditHandleKind::Bottom) => "Resize B",
-            AreaEditOperation::Resize(AreaEditHandleKind::BottomLeft) => "Resize BL",
-            AreaEditOperation::Resize(AreaEditHandleKind::Left) => "Resize L",
-            AreaEditOperation::Resize(AreaEditHandleKind::Center) => "Move",
+            AreaEditOperation::Move => "ui.area_edit.op.move",
+            AreaEditOperation::Resize(AreaEditHandleKind::TopLeft) => "ui.area_edit.op.resize_tl",
+            AreaEditOperation::Resize(AreaEditHandleKind::Top) => "ui.area_edit.op.resize_t",
+            AreaEditOperation::Resize(AreaEditHandleKind::TopRight) => "ui.area_edit.op.resize_tr",
+            AreaEditOperation::Resize(AreaEditHandleKind::Right) => "ui.area_edit.op.resize_r",
+            AreaEditOperation::Resize(AreaEditHandleKind::BottomRight) => {
+                "ui.area_edit.op.resize_br"
+            }
+            AreaEditOperation::Resize(AreaEditHandleKind::Bottom) => "ui.area_edit.op.resize_b",
+            AreaEditOperation::Resize(AreaEditHandleKind::BottomLeft) => {
+                "ui.area_edit.op.resize_bl"
+            }
+            AreaEditOperation::Resize(AreaEditHandleKind::Left) => "ui.area_edit.op.resize_l",
+            AreaEditOperation::Resize(AreaEditHandleKind::Center) => "ui.area_edit.op.move",
         })
     }
 }
@@ -83,8 +90,7 @@ impl AreaEditHistory {
         before: Option<TaskArea>,
         after: Option<TaskArea>,
     ) {
-        if before.as_ref().map(|a| (a.min(), a.max())) == after.as_ref().map(|a| (a.min(), a.max()))
-        {
+        if before == after {
             return;
         }
 
@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use hw_core::game_state::TimeSpeed;
+use hw_core::locale::Locale;
 use hw_jobs::BuildingCategory;
 use hw_logistics::ResourceType;
 use std::borrow::Cow;
@@ -79,8 +80,13 @@ pub struct InfoPanelNodes {
     pub stress: Option<Entity>,
     pub fatigue: Option<Entity>,
     pub dream: Option<Entity>,
+    pub penance: Option<Entity>,
     pub task: Option<Entity>,
     pub inventory: Option<Entity>,
+    pub relationships: Option<Entity>,
+    pub status_body: Option<Entity>,
+    pub life_log_body: Option<Entity>,
+    pub life_log_text: Option<Entity>,
     pub stockpile_group: Option<Entity>,
     pub stockpile_state: Option<Entity>,
     pub stockpile_current: Option<Entity>,
@@ -96,11 +102,37 @@ pub struct InfoPanelNodes {
     pub stockpile_export_button: Option<Entity>,
     pub stockpile_export_text: Option<Entity>,
     pub stockpile_area_button: Option<Entity>,
+    pub stockpile_route_text: Option<Entity>,
+    pub stockpile_route_source_button: Option<Entity>,
+    pub stockpile_route_source_text: Option<Entity>,
+    pub stockpile_route_create_button: Option<Entity>,
+    pub stockpile_route_controls: Option<Entity>,
+    pub stockpile_route_target_decrease_button: Option<Entity>,
+    pub stockpile_route_target_text: Option<Entity>,
+    pub stockpile_route_target_increase_button: Option<Entity>,
+    pub stockpile_route_filter_button: Option<Entity>,
+    pub stockpile_route_remove_button: Option<Entity>,
+    pub group_section: Option<Entity>,
+    pub group_familiar_row: Option<Entity>,
+    pub group_door_row: Option<Entity>,
+    pub group_designation_row: Option<Entity>,
+    pub group_stockpile_row: Option<Entity>,
     pub common: Option<Entity>,
     pub rename_button: Option<Entity>,
     pub rename_field_container: Option<Entity>,
 }
 
+/// Soul 情報パネルのタブ
+#[derive(Resource, Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum InfoPanelTab {
+    #[default]
+    Status,
+    LifeLog,
+}
+
+#[derive(Component)]
+pub struct InfoPanelTabButton(pub InfoPanelTab);
+
 #[derive(Clone, Copy)]
 pub struct StockpileAcceptanceRowNodes {
     pub resource_type: ResourceType,
@@ -198,7 +230,7 @@ pub struct TooltipBody;
 #[derive(Component)]
 pub struct TooltipProgressBar(pub f32);
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Default)]
 pub struct UiTooltip {
     pub text: Cow<'static, str>,
     pub shortcut: Option<Cow<'static, str>>,
@@ -287,6 +319,22 @@ pub struct SettingsCheckmarkMarker(pub SettingsField);
 #[derive(Component, Clone, Copy)]
 pub struct SettingsDefaultSpeedButton(pub TimeSpeed);
 
+/// 設定パネルの言語選択ボタン
+#[derive(Component, Clone, Copy)]
+pub struct SettingsLocaleButton(pub Locale);
+
+/// キーバインド行の現在キー表示。値は root 側バインドテーブルの行番号
+#[derive(Component, Clone, Copy)]
+pub struct SettingsKeyBindingText(pub usize);
+
+/// キーバインド行の操作名。表示言語に合わせて root 側が書き込む
+#[derive(Component, Clone, Copy)]
+pub struct SettingsKeyBindingLabel(pub usize);
+
+/// キーバインド編集の案内・衝突メッセージ
+#[derive(Component, Default)]
+pub struct SettingsKeyBindingStatusText;
+
 #[derive(Component)]
 pub struct LoadConfirmDialog;
 
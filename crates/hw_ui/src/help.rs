@@ -195,6 +195,30 @@ impl HelpPanelContent {
         };
         topics.get(next).copied()
     }
+
+    /// Help パネル上の 1 テキストに対応する現在の文言
+    pub fn catalog_text(&self, slot: HelpCatalogText) -> Option<&str> {
+        let entry = |id: HelpEntryId| {
+            self.topics()
+                .flat_map(HelpTopic::entries)
+                .find(|entry| entry.id() == id)
+        };
+        match slot {
+            HelpCatalogText::SectionTitle(id) => self
+                .sections()
+                .iter()
+                .find(|section| section.id() == id)
+                .map(HelpSection::title),
+            HelpCatalogText::TopicTitle(id) => self
+                .topics()
+                .find(|topic| topic.id() == id)
+                .map(HelpTopic::title),
+            HelpCatalogText::EntryTitle(id) => entry(id).map(HelpEntry::title),
+            HelpCatalogText::EntryParagraph(id, index) => entry(id)
+                .and_then(|entry| entry.paragraphs().get(index))
+                .map(String::as_str),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -261,15 +285,15 @@ impl HelpChromeSlot {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct HelpPanelCopySpec {
-    pub launcher_label: &'static str,
-    pub launcher_tooltip: &'static str,
-    pub panel_title: &'static str,
-    pub close_label: &'static str,
-    pub topic_navigation_label: &'static str,
-    pub page_navigation_label: &'static str,
-    pub document_bounds_label: &'static str,
-    pub shortcut_label: &'static str,
+pub struct HelpPanelCopySpec<'a> {
+    pub launcher_label: &'a str,
+    pub launcher_tooltip: &'a str,
+    pub panel_title: &'a str,
+    pub close_label: &'a str,
+    pub topic_navigation_label: &'a str,
+    pub page_navigation_label: &'a str,
+    pub document_bounds_label: &'a str,
+    pub shortcut_label: &'a str,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -285,7 +309,7 @@ pub struct HelpPanelCopy {
 }
 
 impl HelpPanelCopy {
-    pub fn new(spec: HelpPanelCopySpec) -> Self {
+    pub fn new(spec: HelpPanelCopySpec<'_>) -> Self {
         Self {
             launcher_label: spec.launcher_label.to_string(),
             launcher_tooltip: spec.launcher_tooltip.to_string(),
@@ -331,7 +355,7 @@ impl HelpPanelCopy {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
 pub struct HelpPanelChrome {
     copy: HelpPanelCopy,
     launcher_shortcut: String,
@@ -469,6 +493,28 @@ pub struct HelpTopicBody(pub HelpTopicId);
 #[derive(Component, Default)]
 pub struct HelpScrollArea;
 
+/// キーバインド変更時に書き換える Help entry のショートカット表示
+#[derive(Component, Clone, Copy)]
+pub struct HelpEntryShortcutText(pub HelpEntryId);
+
+/// キーバインド・ロケール変更時に書き換える Help chrome のテキスト
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpChromeText {
+    CloseButton,
+    Footer,
+    PanelTitle,
+    LauncherLabel,
+}
+
+/// ロケール変更時に書き換える Help 本文のテキスト
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpCatalogText {
+    SectionTitle(HelpSectionId),
+    TopicTitle(HelpTopicId),
+    EntryTitle(HelpEntryId),
+    EntryParagraph(HelpEntryId, usize),
+}
+
 #[derive(Component, Default)]
 pub struct HelpNavigationScrollArea;
 
@@ -1,8 +1,10 @@
 use bevy::prelude::{Entity, Message, Vec2};
+use hw_core::edicts::EdictKind;
 use hw_core::game_state::{TaskMode, TimeSpeed};
 use hw_core::jobs::WorkType;
+use hw_core::locale::Locale;
 use hw_jobs::{BuildingCategory, BuildingType};
-use hw_logistics::{StockpilePolicyPatch, zone::ZoneType};
+use hw_logistics::{StockpilePolicyPatch, SupplyRouteChangeRequest, zone::ZoneType};
 
 use crate::help::{HelpScrollCommand, HelpTopicId, HelpTopicStep};
 use crate::panels::task_list::{TaskCancelKind, TaskPriorityAdjustment};
@@ -11,7 +13,12 @@ use crate::panels::task_list::{TaskCancelKind, TaskPriorityAdjustment};
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum StockpilePolicyEditTarget {
     Single(Entity),
-    Area { min: Vec2, max: Vec2 },
+    Area {
+        min: Vec2,
+        max: Vec2,
+    },
+    /// 現在の `SelectedEntities` に含まれる Stockpile セル
+    Selection,
 }
 
 #[derive(Message, Copy, Clone, Debug)]
@@ -28,6 +35,10 @@ pub enum UiIntent {
     ToggleOrders,
     ToggleDream,
     ToggleSettings,
+    /// 統計グラフパネルの開閉
+    ToggleStatistics,
+    /// アラート規則パネルの開閉
+    ToggleAlerts,
     CloseSettings,
     SetUiScale(f32),
     SetCameraPanSpeed(f32),
@@ -35,6 +46,11 @@ pub enum UiIntent {
     SetDefaultTimeSpeed(TimeSpeed),
     SetDebugGizmosEnabled(bool),
     SetFpsDisplayEnabled(bool),
+    /// UI 文言のロケールを切り替える
+    SetLocale(Locale),
+    /// 設定パネルのキーバインド行（root 側テーブルの行番号）をキー入力待ちにする
+    BeginKeyRebind(usize),
+    ResetKeyBindings,
     InspectEntity(Entity),
     ClearInspectPin,
     SelectBuild(BuildingType),
@@ -44,11 +60,18 @@ pub enum UiIntent {
     SelectTaskMode(TaskMode),
     SelectAreaTask,
     SelectDreamPlanting,
+    ToggleEdict(EdictKind),
     ToggleDoorLock(Entity),
     OpenOperationDialog,
     AdjustFatigueThreshold(f32),
     AdjustMaxControlledSoul(isize),
     AdjustMaxControlledSoulFor(Entity, isize),
+    /// 複数選択中の使い魔すべての疲労閾値を増減する
+    AdjustSelectedFatigueThreshold(f32),
+    /// 複数選択中の使い魔すべての最大指揮数を増減する
+    AdjustSelectedMaxControlledSoul(isize),
+    /// 複数選択中のドアをまとめて施錠（`true`）/ 解錠（`false`）する
+    SetSelectedDoorsLocked(bool),
     CloseDialog,
     SetTimeSpeed(TimeSpeed),
     TogglePause,
@@ -65,6 +88,10 @@ pub enum UiIntent {
     BeginStockpilePolicyRangeEdit {
         patch: StockpilePolicyPatch,
     },
+    /// 補給ルートの搬出元としてセルを仮指定する（`None` で解除）
+    SetSupplyRouteSource(Option<Entity>),
+    /// 補給ルートの作成・目標量・フィルタ・削除
+    EditSupplyRoute(SupplyRouteChangeRequest),
     AdjustTaskPriority {
         entity: Entity,
         expected_work_type: WorkType,
@@ -75,13 +102,20 @@ pub enum UiIntent {
         expected_work_type: WorkType,
         expected_kind: TaskCancelKind,
     },
+    /// 複数選択中の指定（Designation）の優先度をまとめて変更する
+    AdjustSelectedTaskPriority(TaskPriorityAdjustment),
+    /// 複数選択中の指定（Designation）をまとめて取り消す
+    CancelSelectedTasks,
 }
 
 impl UiIntent {
     pub const fn is_specialized(&self) -> bool {
         matches!(
             self,
-            Self::AdjustTaskPriority { .. } | Self::CancelTask { .. }
+            Self::AdjustTaskPriority { .. }
+                | Self::CancelTask { .. }
+                | Self::AdjustSelectedTaskPriority(_)
+                | Self::CancelSelectedTasks
         )
     }
 }
@@ -108,7 +142,12 @@ mod tests {
             }
             .is_specialized()
         );
+        assert!(
+            UiIntent::AdjustSelectedTaskPriority(TaskPriorityAdjustment::Increase).is_specialized()
+        );
+        assert!(UiIntent::CancelSelectedTasks.is_specialized());
         assert!(!UiIntent::ToggleDoorLock(Entity::PLACEHOLDER).is_specialized());
+        assert!(!UiIntent::SetSelectedDoorsLocked(true).is_specialized());
         assert!(!UiIntent::SelectArchitectCategory(Some(BuildingCategory::Plant)).is_specialized());
         assert!(!UiIntent::MovePlantBuilding(Entity::PLACEHOLDER).is_specialized());
         assert!(
@@ -124,5 +163,12 @@ mod tests {
             }
             .is_specialized()
         );
+        assert!(!UiIntent::SetSupplyRouteSource(None).is_specialized());
+        assert!(
+            !UiIntent::EditSupplyRoute(SupplyRouteChangeRequest::Remove {
+                route: Entity::PLACEHOLDER,
+            })
+            .is_specialized()
+        );
     }
 }
@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 
 use crate::UiIntent;
+use crate::components::{MenuButton, UiTooltip};
 use crate::help::{
-    HelpPanel, HelpPanelContent, HelpPanelState, HelpScrollArea, HelpScrollCommand, HelpTopicBody,
+    HelpCatalogText, HelpChromeText, HelpEntryShortcutText, HelpPanel, HelpPanelChrome,
+    HelpPanelContent, HelpPanelState, HelpScrollArea, HelpScrollCommand, HelpTopicBody,
     HelpTopicButton,
 };
 use crate::theme::UiTheme;
@@ -107,6 +109,76 @@ pub fn update_help_topic_presentation_system(
     }
 }
 
+type HelpEntryTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static HelpEntryShortcutText, &'static mut Text),
+    (Without<HelpChromeText>, Without<HelpCatalogText>),
+>;
+
+type HelpChromeTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static HelpChromeText, &'static mut Text),
+    (Without<HelpEntryShortcutText>, Without<HelpCatalogText>),
+>;
+
+type HelpCatalogTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static HelpCatalogText, &'static mut Text),
+    (Without<HelpEntryShortcutText>, Without<HelpChromeText>),
+>;
+
+/// キーバインド・ロケール変更で作り直された Help 本文・chrome の文言とショートカット表記を反映する
+pub fn sync_help_shortcut_text_system(
+    content: Res<HelpPanelContent>,
+    chrome: Res<HelpPanelChrome>,
+    mut entry_texts: HelpEntryTextQuery,
+    mut chrome_texts: HelpChromeTextQuery,
+    mut catalog_texts: HelpCatalogTextQuery,
+    mut launchers: Query<(&MenuButton, &mut UiTooltip)>,
+) {
+    if !content.is_changed() && !chrome.is_changed() {
+        return;
+    }
+
+    for (entry_text, mut text) in &mut entry_texts {
+        let shortcut = content
+            .topics()
+            .flat_map(|topic| topic.entries())
+            .find(|entry| entry.id() == entry_text.0)
+            .and_then(|entry| entry.shortcut());
+        if let Some(shortcut) = shortcut {
+            text.0 = chrome.entry_shortcut_text(shortcut);
+        }
+    }
+
+    for (slot, mut text) in &mut chrome_texts {
+        text.0 = match slot {
+            HelpChromeText::CloseButton => chrome.close_button_text(),
+            HelpChromeText::Footer => chrome.footer_text(),
+            HelpChromeText::PanelTitle => chrome.copy().panel_title().to_owned(),
+            HelpChromeText::LauncherLabel => chrome.copy().launcher_label().to_owned(),
+        };
+    }
+
+    for (slot, mut text) in &mut catalog_texts {
+        if let Some(value) = content.catalog_text(*slot)
+            && text.0 != value
+        {
+            text.0 = value.to_owned();
+        }
+    }
+
+    for (button, mut tooltip) in &mut launchers {
+        if matches!(button.0, UiIntent::OpenHelp { .. }) && tooltip.shortcut.is_some() {
+            tooltip.text = chrome.copy().launcher_tooltip().to_owned().into();
+            tooltip.shortcut = Some(chrome.launcher_shortcut().to_owned().into());
+        }
+    }
+}
+
 fn help_topic_button_color(selected: bool, interaction: Interaction, theme: &UiTheme) -> Color {
     if selected {
         match interaction {
@@ -15,8 +15,8 @@ pub use dialog::{
     open_load_confirm_dialog, open_operation_dialog,
 };
 pub use help::{
-    handle_help_navigation_system, update_help_panel_visibility_system,
-    update_help_topic_presentation_system,
+    handle_help_navigation_system, sync_help_shortcut_text_system,
+    update_help_panel_visibility_system, update_help_topic_presentation_system,
 };
 pub use hover_action::{HoverActionTarget, hover_action_button_system};
 pub use pause_menu::update_pause_menu_visibility as update_pause_menu_visibility_system;
@@ -0,0 +1,48 @@
+use crate::components::{UiNodeRegistry, UiSlot};
+use bevy::prelude::*;
+
+#[derive(Debug)]
+pub struct ContractPanelPayload {
+    pub visible: bool,
+    pub header: String,
+    pub body: String,
+}
+
+/// 契約パネルの表示切替とテキスト更新。変化がない場合は書き込まない
+pub fn update_contract_panel_system(
+    payload: Option<ContractPanelPayload>,
+    ui_nodes: Res<UiNodeRegistry>,
+    mut q_node: Query<&mut Node>,
+    mut q_text: Query<&mut Text>,
+) {
+    let Some(payload) = payload else {
+        return;
+    };
+
+    if let Some(panel) = ui_nodes.get_slot(UiSlot::ContractPanel)
+        && let Ok(mut node) = q_node.get_mut(panel)
+    {
+        let display = if payload.visible {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    for (slot, next) in [
+        (UiSlot::ContractHeaderText, payload.header),
+        (UiSlot::ContractListText, payload.body),
+    ] {
+        let Some(entity) = ui_nodes.get_slot(slot) else {
+            continue;
+        };
+        if let Ok(mut text) = q_text.get_mut(entity)
+            && text.0 != next
+        {
+            text.0 = next;
+        }
+    }
+}
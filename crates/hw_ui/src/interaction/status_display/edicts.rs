@@ -0,0 +1,52 @@
+use crate::components::{UiNodeRegistry, UiSlot};
+use bevy::prelude::*;
+use hw_core::GameTime;
+use hw_core::edicts::{DreamEdicts, EdictKind};
+use hw_core::locale::Localization;
+
+fn format_remaining(secs: f32) -> String {
+    let total_mins = (secs / 60.0).ceil() as u32;
+    format!("{}h {:02}m", total_mins / 60, total_mins % 60)
+}
+
+fn edict_status_text(text: &Localization, edicts: &DreamEdicts, now: f32) -> String {
+    EdictKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let state = match edicts.remaining_secs(kind, now) {
+                Some(remaining) => text.format(
+                    "ui.edict.active",
+                    &[("remaining", &format_remaining(remaining))],
+                ),
+                None => text.format("ui.edict.cost", &[("cost", &format!("{:.0}", kind.cost()))]),
+            };
+            format!(
+                "{}: {state}\n  {}",
+                text.text(kind.text_id()),
+                text.text(kind.effect_text_id())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Dream サブメニューの勅令パネルに発令状況（残り時間・費用・効果）を表示する
+pub fn update_edict_status_system(
+    edicts: Res<DreamEdicts>,
+    game_time: Res<GameTime>,
+    localization: Res<Localization>,
+    ui_nodes: Res<UiNodeRegistry>,
+    mut q_text: Query<&mut Text>,
+) {
+    let Some(entity) = ui_nodes.get_slot(UiSlot::EdictStatusText) else {
+        return;
+    };
+    let Ok(mut text) = q_text.get_mut(entity) else {
+        return;
+    };
+
+    let next = edict_status_text(&localization, &edicts, game_time.seconds);
+    if text.0 != next {
+        text.0 = next;
+    }
+}
@@ -1,5 +1,6 @@
 use crate::components::{UiNodeRegistry, UiSlot};
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 
 #[derive(Debug)]
 pub struct ModeTextPayload {
@@ -40,6 +41,7 @@ pub fn update_mode_text_system(
 
 pub fn task_summary_ui_system(
     payload: Option<TaskSummaryPayload>,
+    localization: &Localization,
     task_high_color: Color,
     normal_color: Color,
     ui_nodes: Res<UiNodeRegistry>,
@@ -53,7 +55,10 @@ pub fn task_summary_ui_system(
         return;
     };
     if let Ok((mut text, mut color)) = q_text.get_mut(entity) {
-        text.0 = format!("Tasks: {} ({} High)", payload.total, payload.high);
+        text.0 = localization.format(
+            "ui.time.tasks",
+            &[("total", &payload.total), ("high", &payload.high)],
+        );
         if payload.high > 0 {
             color.0 = task_high_color;
         } else {
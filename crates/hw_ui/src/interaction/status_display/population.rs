@@ -0,0 +1,32 @@
+use crate::components::{UiNodeRegistry, UiSlot};
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+use hw_core::population::PopulationManager;
+
+/// 人口統計（現在数/上限・贖罪累計・脱走累計）を時間操作パネルに表示する
+pub fn update_population_display_system(
+    population: Res<PopulationManager>,
+    localization: Res<Localization>,
+    ui_nodes: Res<UiNodeRegistry>,
+    mut q_text: Query<&mut Text>,
+) {
+    let Some(entity) = ui_nodes.get_slot(UiSlot::PopulationText) else {
+        return;
+    };
+    let Ok(mut text) = q_text.get_mut(entity) else {
+        return;
+    };
+
+    let next = localization.format(
+        "ui.time.population",
+        &[
+            ("current", &population.current_count),
+            ("cap", &population.population_cap),
+            ("redeemed", &population.total_redeemed),
+            ("escaped", &population.total_escaped),
+        ],
+    );
+    if text.0 != next {
+        text.0 = next;
+    }
+}
@@ -7,6 +7,7 @@ use crate::theme::UiTheme;
 use bevy::prelude::*;
 use bevy::time::Real;
 use bevy::ui_widgets::popover::Popover;
+use hw_core::locale::Localization;
 
 use super::target::TooltipTarget;
 use super::{
@@ -48,6 +49,7 @@ pub struct TooltipQuerySet<'w, 's> {
 pub struct TooltipHandlers<'a, I: TooltipInspectionSource, R: TooltipContentRenderer> {
     pub game_assets: &'a R::GameAssets,
     pub theme: &'a UiTheme,
+    pub text: &'a Localization,
     pub inspection: &'a I,
     pub tooltip_renderer: &'a R,
 }
@@ -125,17 +127,15 @@ pub fn hover_tooltip_system<'w, 's, I, R>(
             }
             crate::selection::PlacementFeedbackStatus::Partial => TooltipTemplate::PlacementPartial,
         };
-        let body = feedback.body();
+        let header = handlers.text.text(feedback.header_text_id());
+        let body = feedback.body(handlers.text);
         payload = format!(
             "placement_feedback:{:?}:{}:{}:{}",
-            feedback.status,
-            feedback.header(),
-            body,
-            feedback.rejected_tile_count
+            feedback.status, header, body, feedback.rejected_tile_count
         );
         model = Some(EntityInspectionModel {
             entity: Entity::PLACEHOLDER,
-            header: feedback.header().to_string(),
+            header: header.to_string(),
             common_text: String::new(),
             tooltip_lines: vec![body],
             soul: None,
@@ -259,6 +259,7 @@ pub fn hover_tooltip_system<'w, 's, I, R>(
             handlers.theme,
             TooltipBuildPayload {
                 template,
+                text: handlers.text,
                 model: model.as_ref(),
                 ui_tooltip: ui_tooltip.as_ref(),
             },
@@ -2,6 +2,7 @@ use bevy::input_focus::InputFocus;
 use bevy::prelude::*;
 use std::collections::HashSet;
 
+pub mod alerts;
 pub mod area_edit;
 /// Game-agnostic Help content schema and presentation state.
 pub mod help;
@@ -10,12 +11,15 @@ pub use intents::{StockpilePolicyEditTarget, UiIntent};
 pub mod components;
 pub mod interaction;
 pub mod list;
+pub mod locale;
+pub mod minimap;
 pub mod models;
 pub mod notifications;
 pub mod overlay;
 pub mod panels;
 pub mod plugins;
 pub mod setup;
+pub mod statistics;
 pub mod text_input_intents;
 pub use text_input_intents::TextInputIntent;
 pub mod theme;
@@ -28,8 +32,12 @@ impl Plugin for HwUiPlugin {
         app.add_message::<UiIntent>()
             .add_message::<TextInputIntent>()
             .add_message::<notifications::UserFacingNotification>()
+            .init_resource::<hw_core::locale::Localization>()
             .init_resource::<notifications::NotificationCenter>()
             .init_resource::<notifications::NotificationUiRuntime>()
+            .init_resource::<minimap::MinimapState>()
+            .init_resource::<statistics::StatisticsPanelState>()
+            .init_resource::<alerts::AlertPanelState>()
             .init_resource::<panels::task_list::TaskDashboardViewState>()
             .init_resource::<panels::task_list::TaskDashboardActionState>()
             .init_resource::<panels::task_list::TaskListDirty>()
@@ -68,7 +76,7 @@ pub fn reset_for_world_replace(world: &mut World) {
     }
 
     clear_hover_action_targets(world);
-    clear_stockpile_policy_button_targets(world);
+    clear_stockpile_editor_button_targets(world);
     reset_existing_resource::<components::UiInputState>(world);
     reset_existing_resource::<help::HelpPanelState>(world);
     reset_existing_resource::<components::SoulRenameState>(world);
@@ -87,6 +95,12 @@ pub fn reset_for_world_replace(world: &mut World) {
     reset_existing_resource::<panels::task_list::TaskDashboardViewState>(world);
     reset_existing_resource::<panels::task_list::TaskDashboardActionState>(world);
     notifications::reset_for_world_replace(world);
+    if let Some(mut minimap) = world.get_resource_mut::<minimap::MinimapState>() {
+        minimap.clear_world_data();
+    }
+    if let Some(mut statistics) = world.get_resource_mut::<statistics::StatisticsPanelState>() {
+        statistics.invalidate();
+    }
     reset_info_panel_presentation(world);
     reset_help_presentation(world);
     mark_entity_list_dirty(world);
@@ -120,17 +134,21 @@ fn reset_help_presentation(world: &mut World) {
     }
 }
 
-fn clear_stockpile_policy_button_targets(world: &mut World) {
+fn clear_stockpile_editor_button_targets(world: &mut World) {
     let mut query = world.query::<&mut components::MenuButton>();
     for mut button in query.iter_mut(world) {
-        if matches!(
-            button.0,
-            UiIntent::ApplyStockpilePolicy { .. } | UiIntent::BeginStockpilePolicyRangeEdit { .. }
-        ) {
-            button.0 = UiIntent::ApplyStockpilePolicy {
-                target: StockpilePolicyEditTarget::Single(Entity::PLACEHOLDER),
-                patch: hw_logistics::StockpilePolicyPatch::default(),
-            };
+        match button.0 {
+            UiIntent::ApplyStockpilePolicy { .. }
+            | UiIntent::BeginStockpilePolicyRangeEdit { .. } => {
+                button.0 = UiIntent::ApplyStockpilePolicy {
+                    target: StockpilePolicyEditTarget::Single(Entity::PLACEHOLDER),
+                    patch: hw_logistics::StockpilePolicyPatch::default(),
+                };
+            }
+            UiIntent::SetSupplyRouteSource(_) | UiIntent::EditSupplyRoute(_) => {
+                button.0 = UiIntent::SetSupplyRouteSource(None);
+            }
+            _ => {}
         }
     }
 }
@@ -315,6 +333,14 @@ mod tests {
                 },
             ))
             .id();
+        let supply_route_button = world
+            .spawn(components::MenuButton(UiIntent::EditSupplyRoute(
+                hw_logistics::SupplyRouteChangeRequest::Create {
+                    source_cell: stale_simulation_entity,
+                    destination_cell: stale_simulation_entity,
+                },
+            )))
+            .id();
 
         world.insert_resource(components::SoulRenameState {
             active: Some(components::SoulRenameActive {
@@ -470,6 +496,13 @@ mod tests {
             );
             assert_eq!(patch, hw_logistics::StockpilePolicyPatch::default());
         }
+        assert!(matches!(
+            world
+                .get::<components::MenuButton>(supply_route_button)
+                .unwrap()
+                .0,
+            UiIntent::SetSupplyRouteSource(None)
+        ));
         assert!(
             world
                 .resource::<selection::PlacementFeedbackState>()
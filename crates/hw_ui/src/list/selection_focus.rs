@@ -22,10 +22,10 @@ pub fn focus_camera_on_entity<F>(
 pub fn select_entity_and_focus_camera(
     target: Entity,
     _label: &str,
-    selected_entity: &mut ResMut<crate::selection::SelectedEntity>,
+    selection: &mut crate::selection::SelectionWriter,
     q_camera: &mut Query<&mut Transform, With<crate::camera::MainCamera>>,
     q_transforms: &Query<&GlobalTransform>,
 ) {
-    selected_entity.0 = Some(target);
+    selection.select(Some(target));
     focus_camera_on_entity(target, q_camera, q_transforms);
 }
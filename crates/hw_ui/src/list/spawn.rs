@@ -8,6 +8,7 @@ use crate::components::{
     EntityListSectionType, FamiliarListItem, FamiliarMaxSoulAdjustButton, SectionToggle,
     SoulListItem,
 };
+use crate::locale::LocalizedText;
 use crate::setup::UiAssets;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
@@ -276,7 +277,7 @@ pub fn spawn_empty_squad_hint_entity(
         .with_children(|members_parent| {
             result = members_parent
                 .spawn((
-                    Text::new("  (empty)"),
+                    LocalizedText("ui.entity_list.empty"),
                     TextFont {
                         font: assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_item),
@@ -0,0 +1,92 @@
+//! 文言 ID を持つ UI ノード
+//!
+//! 固定ラベルは [`LocalizedText`]、ツールチップは [`LocalizedTooltip`] に ID を持たせて
+//! スポーンし、[`sync_localized_text_system`] が現在のロケールの文言を書き込む。
+//! 値を埋め込む文言は各パネルの更新システムが `Localization::format` で組み立てる。
+
+use crate::components::UiTooltip;
+use bevy::prelude::*;
+use hw_core::locale::Localization;
+
+/// `Text` をロケールの文言で置き換える
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[require(Text)]
+pub struct LocalizedText(pub &'static str);
+
+/// `UiTooltip::text` をロケールの文言で置き換える（ショートカット表記は保持する）
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[require(UiTooltip)]
+pub struct LocalizedTooltip(pub &'static str);
+
+/// 追加されたノードとロケール変更時の全ノードへ文言を反映する。
+pub fn sync_localized_text_system(
+    localization: Res<Localization>,
+    mut texts: Query<(Ref<LocalizedText>, &mut Text)>,
+    mut tooltips: Query<(Ref<LocalizedTooltip>, &mut UiTooltip)>,
+) {
+    let all = localization.is_changed();
+    for (id, mut text) in &mut texts {
+        if !all && !id.is_changed() {
+            continue;
+        }
+        let value = localization.text(id.0);
+        if text.0 != value {
+            text.0 = value.to_string();
+        }
+    }
+    for (id, mut tooltip) in &mut tooltips {
+        if !all && !id.is_changed() {
+            continue;
+        }
+        let value = localization.text(id.0);
+        if tooltip.text != value {
+            tooltip.text = value.to_string().into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hw_core::locale::Locale;
+
+    #[test]
+    fn labels_follow_the_active_locale() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Localization::shipped(Locale::Ja))
+            .add_systems(Update, sync_localized_text_system);
+        let label = app
+            .world_mut()
+            .spawn(LocalizedText("ui.settings.title"))
+            .id();
+        let tooltip = app
+            .world_mut()
+            .spawn((
+                LocalizedTooltip("ui.bottom_bar.settings.tooltip"),
+                UiTooltip::with_shortcut(String::new(), "F10"),
+            ))
+            .id();
+
+        app.update();
+        let ja = app.world().get::<Text>(label).unwrap().0.clone();
+
+        app.world_mut()
+            .resource_mut::<Localization>()
+            .set_locale(Locale::En);
+        app.update();
+
+        let localization = app.world().resource::<Localization>();
+        assert_ne!(ja, app.world().get::<Text>(label).unwrap().0);
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().0,
+            localization.text("ui.settings.title")
+        );
+        let tooltip = app.world().get::<UiTooltip>(tooltip).unwrap();
+        assert_eq!(
+            tooltip.text,
+            localization.text("ui.bottom_bar.settings.tooltip")
+        );
+        assert_eq!(tooltip.shortcut.as_deref(), Some("F10"));
+    }
+}
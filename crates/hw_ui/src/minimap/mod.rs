@@ -0,0 +1,18 @@
+//! ワールド全体の俯瞰ミニマップ
+//!
+//! 地形・建物・Stockpile・タスクエリアを CPU でテクスチャへ描き、Soul / 使い魔のドットと
+//! カメラ表示範囲を重ねる。ゲーム ECS からの値の収集は root adapter が行う。
+
+mod model;
+mod ui;
+
+pub use model::{
+    MINIMAP_REFRESH_INTERVAL, MINIMAP_TEXTURE_HEIGHT, MINIMAP_TEXTURE_WIDTH, MinimapDot,
+    MinimapDotKind, MinimapLayer, MinimapPowerArea, MinimapState, MinimapTile,
+    minimap_normalized_to_world, world_rect_to_minimap_fraction,
+};
+pub(crate) use ui::spawn_minimap_ui;
+pub use ui::{
+    MinimapImage, MinimapLayerButton, MinimapRoot, MinimapViewportFrame,
+    minimap_interaction_system, sync_minimap_viewport_frame_system, upload_minimap_texture_system,
+};
@@ -0,0 +1,448 @@
+use bevy::prelude::*;
+use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH, TILE_SIZE};
+use std::time::Duration;
+
+/// ミニマップ画像の一辺（1 タイル = 1 ピクセル）
+pub const MINIMAP_TEXTURE_WIDTH: u32 = MAP_WIDTH as u32;
+pub const MINIMAP_TEXTURE_HEIGHT: u32 = MAP_HEIGHT as u32;
+/// Soul / 使い魔のドットとオーバーレイを描き直す間隔（実時間）
+pub const MINIMAP_REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+const TILE_COUNT: usize = (MAP_WIDTH * MAP_HEIGHT) as usize;
+
+/// 地形・建物・Stockpile をまとめたベースレイヤーのタイル種別
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MinimapTile {
+    #[default]
+    Grass,
+    Dirt,
+    Sand,
+    River,
+    Obstacle,
+    Stockpile,
+    Building,
+}
+
+impl MinimapTile {
+    const fn rgba(self) -> [u8; 4] {
+        match self {
+            Self::Grass => [58, 74, 44, 255],
+            Self::Dirt => [92, 70, 52, 255],
+            Self::Sand => [150, 130, 92, 255],
+            Self::River => [44, 78, 128, 255],
+            Self::Obstacle => [40, 34, 34, 255],
+            Self::Stockpile => [168, 132, 60, 255],
+            Self::Building => [196, 188, 176, 255],
+        }
+    }
+}
+
+/// 任意表示のオーバーレイ
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MinimapLayer {
+    PowerCoverage,
+    ForbiddenZones,
+    Designations,
+}
+
+impl MinimapLayer {
+    pub const ALL: [Self; 3] = [
+        Self::PowerCoverage,
+        Self::ForbiddenZones,
+        Self::Designations,
+    ];
+
+    /// ボタンに表示する文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::PowerCoverage => "ui.minimap.layer.power",
+            Self::ForbiddenZones => "ui.minimap.layer.forbidden",
+            Self::Designations => "ui.minimap.layer.designations",
+        }
+    }
+
+    const fn index(self) -> usize {
+        match self {
+            Self::PowerCoverage => 0,
+            Self::ForbiddenZones => 1,
+            Self::Designations => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinimapDotKind {
+    Soul,
+    Familiar,
+}
+
+impl MinimapDotKind {
+    const fn rgba(self) -> [u8; 4] {
+        match self {
+            Self::Soul => [236, 224, 255, 255],
+            Self::Familiar => [255, 96, 64, 255],
+        }
+    }
+}
+
+/// ワールド座標上の点。描画時にタイルへ量子化する。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinimapDot {
+    pub position: Vec2,
+    pub kind: MinimapDotKind,
+}
+
+/// Yard の給電状況（ワールド座標の矩形）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinimapPowerArea {
+    pub rect: Rect,
+    pub powered: bool,
+}
+
+/// ミニマップの表示状態
+///
+/// root adapter が WorldMap / ECS から値を流し込み、`hw_ui` がテクスチャと UI ノードへ反映する。
+/// ベースレイヤーは変化したタイルだけを書き換え、オーバーレイとドットは
+/// `MINIMAP_REFRESH_INTERVAL` ごとに差し替える。
+#[derive(Resource, Debug)]
+pub struct MinimapState {
+    base: Vec<MinimapTile>,
+    base_synced: bool,
+    task_areas: Vec<Rect>,
+    power_areas: Vec<MinimapPowerArea>,
+    forbidden_tiles: Vec<usize>,
+    designation_tiles: Vec<usize>,
+    dots: Vec<MinimapDot>,
+    viewport: Option<Rect>,
+    layers: [bool; 3],
+    next_refresh: Duration,
+    dirty: bool,
+    pub(super) texture: Option<Handle<Image>>,
+}
+
+impl Default for MinimapState {
+    fn default() -> Self {
+        Self {
+            base: vec![MinimapTile::default(); TILE_COUNT],
+            base_synced: false,
+            task_areas: Vec::new(),
+            power_areas: Vec::new(),
+            forbidden_tiles: Vec::new(),
+            designation_tiles: Vec::new(),
+            dots: Vec::new(),
+            viewport: None,
+            layers: [false; 3],
+            next_refresh: Duration::ZERO,
+            dirty: true,
+            texture: None,
+        }
+    }
+}
+
+impl MinimapState {
+    /// 初回同期前、またはワールド差し替え後なら true。
+    pub fn needs_full_sync(&self) -> bool {
+        !self.base_synced
+    }
+
+    /// ベースレイヤーを走査結果で更新し、実際に変化したタイル数を返す。
+    pub fn sync_base_tiles(&mut self, tiles: impl IntoIterator<Item = MinimapTile>) -> usize {
+        let mut changed = 0;
+        for (slot, tile) in self.base.iter_mut().zip(tiles) {
+            if *slot != tile {
+                *slot = tile;
+                changed += 1;
+            }
+        }
+        self.base_synced = true;
+        if changed > 0 {
+            self.dirty = true;
+        }
+        changed
+    }
+
+    pub fn base_tile(&self, grid: (i32, i32)) -> Option<MinimapTile> {
+        tile_index(grid).map(|idx| self.base[idx])
+    }
+
+    /// オーバーレイとドットを更新する時刻なら、次回時刻を進めて true を返す。
+    pub fn refresh_due(&mut self, now: Duration) -> bool {
+        if now < self.next_refresh {
+            return false;
+        }
+        self.next_refresh = now + MINIMAP_REFRESH_INTERVAL;
+        true
+    }
+
+    pub fn set_task_areas(&mut self, areas: Vec<Rect>) {
+        replace_if_changed(&mut self.task_areas, areas, &mut self.dirty);
+    }
+
+    pub fn set_power_areas(&mut self, areas: Vec<MinimapPowerArea>) {
+        replace_if_changed(&mut self.power_areas, areas, &mut self.dirty);
+    }
+
+    pub fn set_forbidden_tiles(&mut self, grids: impl IntoIterator<Item = (i32, i32)>) {
+        let tiles = sorted_tile_indices(grids);
+        replace_if_changed(&mut self.forbidden_tiles, tiles, &mut self.dirty);
+    }
+
+    pub fn set_designation_positions(&mut self, positions: impl IntoIterator<Item = Vec2>) {
+        let tiles = sorted_tile_indices(positions.into_iter().map(world_to_tile));
+        replace_if_changed(&mut self.designation_tiles, tiles, &mut self.dirty);
+    }
+
+    pub fn set_dots(&mut self, dots: Vec<MinimapDot>) {
+        replace_if_changed(&mut self.dots, dots, &mut self.dirty);
+    }
+
+    pub fn set_viewport(&mut self, viewport: Option<Rect>) {
+        self.viewport = viewport;
+    }
+
+    pub fn viewport(&self) -> Option<Rect> {
+        self.viewport
+    }
+
+    pub fn layer_enabled(&self, layer: MinimapLayer) -> bool {
+        self.layers[layer.index()]
+    }
+
+    pub fn toggle_layer(&mut self, layer: MinimapLayer) {
+        self.layers[layer.index()] = !self.layers[layer.index()];
+        self.dirty = true;
+    }
+
+    /// ワールド差し替え時に世界由来の表示データを捨てる。レイヤー選択とテクスチャは維持する。
+    pub fn clear_world_data(&mut self) {
+        *self = Self {
+            layers: self.layers,
+            texture: self.texture.take(),
+            ..default()
+        };
+    }
+
+    pub(super) fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+
+    /// 全レイヤーを合成した RGBA8 ピクセル列（上端の行から）を返す。
+    pub fn render_rgba(&self) -> Vec<u8> {
+        let mut pixels: Vec<[u8; 4]> = self.base.iter().map(|tile| tile.rgba()).collect();
+
+        if self.layer_enabled(MinimapLayer::PowerCoverage) {
+            for area in &self.power_areas {
+                let color = if area.powered {
+                    [96, 220, 120, 255]
+                } else {
+                    [220, 72, 72, 255]
+                };
+                for idx in rect_tiles(area.rect) {
+                    blend(&mut pixels[idx], color, 0.35);
+                }
+            }
+        }
+        if self.layer_enabled(MinimapLayer::ForbiddenZones) {
+            for &idx in &self.forbidden_tiles {
+                blend(&mut pixels[idx], [200, 40, 40, 255], 0.6);
+            }
+        }
+        if self.layer_enabled(MinimapLayer::Designations) {
+            for &idx in &self.designation_tiles {
+                pixels[idx] = [255, 214, 64, 255];
+            }
+        }
+        for rect in &self.task_areas {
+            for idx in rect_outline_tiles(*rect) {
+                pixels[idx] = [176, 120, 255, 255];
+            }
+        }
+        for dot in &self.dots {
+            if let Some(idx) = tile_index(world_to_tile(dot.position)) {
+                pixels[idx] = dot.kind.rgba();
+            }
+        }
+
+        let width = MAP_WIDTH as usize;
+        let mut rgba = Vec::with_capacity(TILE_COUNT * 4);
+        for row in pixels.chunks_exact(width).rev() {
+            rgba.extend(row.iter().flatten());
+        }
+        rgba
+    }
+}
+
+fn replace_if_changed<T: PartialEq>(slot: &mut Vec<T>, next: Vec<T>, dirty: &mut bool) {
+    if *slot != next {
+        *slot = next;
+        *dirty = true;
+    }
+}
+
+fn sorted_tile_indices(grids: impl IntoIterator<Item = (i32, i32)>) -> Vec<usize> {
+    let mut tiles: Vec<usize> = grids.into_iter().filter_map(tile_index).collect();
+    tiles.sort_unstable();
+    tiles.dedup();
+    tiles
+}
+
+fn blend(pixel: &mut [u8; 4], color: [u8; 4], alpha: f32) {
+    for channel in 0..3 {
+        let mixed = pixel[channel] as f32 * (1.0 - alpha) + color[channel] as f32 * alpha;
+        pixel[channel] = mixed.round() as u8;
+    }
+}
+
+fn tile_index((x, y): (i32, i32)) -> Option<usize> {
+    ((0..MAP_WIDTH).contains(&x) && (0..MAP_HEIGHT).contains(&y))
+        .then(|| (y * MAP_WIDTH + x) as usize)
+}
+
+/// `hw_world::world_to_grid` と同じ量子化（マップ中心がワールド原点）。
+fn world_to_tile(position: Vec2) -> (i32, i32) {
+    let x = (position.x / TILE_SIZE + MAP_WIDTH as f32 / 2.0).floor() as i32;
+    let y = (position.y / TILE_SIZE + MAP_HEIGHT as f32 / 2.0).floor() as i32;
+    (x, y)
+}
+
+fn rect_tile_bounds(rect: Rect) -> ((i32, i32), (i32, i32)) {
+    let min = world_to_tile(rect.min);
+    let max = world_to_tile(rect.max - Vec2::splat(TILE_SIZE * 0.01));
+    (
+        (min.0.max(0), min.1.max(0)),
+        (max.0.min(MAP_WIDTH - 1), max.1.min(MAP_HEIGHT - 1)),
+    )
+}
+
+fn rect_tiles(rect: Rect) -> impl Iterator<Item = usize> {
+    let ((x0, y0), (x1, y1)) = rect_tile_bounds(rect);
+    (y0..=y1).flat_map(move |y| (x0..=x1).filter_map(move |x| tile_index((x, y))))
+}
+
+fn rect_outline_tiles(rect: Rect) -> impl Iterator<Item = usize> {
+    let ((x0, y0), (x1, y1)) = rect_tile_bounds(rect);
+    rect_tiles(rect).filter(move |&idx| {
+        let x = idx as i32 % MAP_WIDTH;
+        let y = idx as i32 / MAP_WIDTH;
+        x == x0 || x == x1 || y == y0 || y == y1
+    })
+}
+
+/// ミニマップ上の正規化座標（中心 0、左上 -0.5）をワールド座標へ変換する。
+pub fn minimap_normalized_to_world(normalized: Vec2) -> Vec2 {
+    Vec2::new(
+        normalized.x * MAP_WIDTH as f32 * TILE_SIZE,
+        -normalized.y * MAP_HEIGHT as f32 * TILE_SIZE,
+    )
+}
+
+/// ワールド矩形をミニマップ上の割合（左上原点、0..1）へ変換する。
+pub fn world_rect_to_minimap_fraction(rect: Rect) -> Rect {
+    let size = Vec2::new(MAP_WIDTH as f32, MAP_HEIGHT as f32) * TILE_SIZE;
+    let to_fraction = |point: Vec2| Vec2::new(point.x / size.x + 0.5, 0.5 - point.y / size.y);
+    Rect::from_corners(to_fraction(rect.min), to_fraction(rect.max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(rgba: &[u8], (x, y): (i32, i32)) -> [u8; 4] {
+        let row = (MAP_HEIGHT - 1 - y) as usize;
+        let start = (row * MAP_WIDTH as usize + x as usize) * 4;
+        rgba[start..start + 4].try_into().unwrap()
+    }
+
+    fn tile_center(x: i32, y: i32) -> Vec2 {
+        Vec2::new(
+            (x as f32 - (MAP_WIDTH as f32 - 1.0) / 2.0) * TILE_SIZE,
+            (y as f32 - (MAP_HEIGHT as f32 - 1.0) / 2.0) * TILE_SIZE,
+        )
+    }
+
+    #[test]
+    fn base_sync_reports_only_changed_tiles() {
+        let mut state = MinimapState::default();
+        assert!(state.needs_full_sync());
+        let mut tiles = vec![MinimapTile::Grass; TILE_COUNT];
+        tiles[5] = MinimapTile::River;
+        assert_eq!(state.sync_base_tiles(tiles.clone()), 1);
+        assert!(state.take_dirty());
+
+        assert_eq!(state.sync_base_tiles(tiles.clone()), 0);
+        assert!(!state.take_dirty());
+        assert!(!state.needs_full_sync());
+
+        tiles[5] = MinimapTile::Building;
+        tiles[6] = MinimapTile::Stockpile;
+        assert_eq!(state.sync_base_tiles(tiles), 2);
+        assert_eq!(state.base_tile((5, 0)), Some(MinimapTile::Building));
+    }
+
+    #[test]
+    fn render_flips_rows_and_draws_dots_over_layers() {
+        let mut state = MinimapState::default();
+        state.set_designation_positions([tile_center(3, 4)]);
+        state.set_dots(vec![MinimapDot {
+            position: tile_center(10, 90),
+            kind: MinimapDotKind::Familiar,
+        }]);
+
+        let rgba = state.render_rgba();
+        assert_eq!(rgba.len(), TILE_COUNT * 4);
+        assert_eq!(pixel(&rgba, (10, 90)), MinimapDotKind::Familiar.rgba());
+        assert_eq!(pixel(&rgba, (3, 4)), MinimapTile::Grass.rgba());
+
+        state.toggle_layer(MinimapLayer::Designations);
+        let rgba = state.render_rgba();
+        assert_eq!(pixel(&rgba, (3, 4)), [255, 214, 64, 255]);
+    }
+
+    #[test]
+    fn task_area_is_outlined_without_filling_the_inside() {
+        let mut state = MinimapState::default();
+        let half = Vec2::splat(TILE_SIZE / 2.0);
+        state.set_task_areas(vec![Rect::from_corners(
+            tile_center(20, 20) - half,
+            tile_center(24, 24) + half,
+        )]);
+
+        let rgba = state.render_rgba();
+        assert_eq!(pixel(&rgba, (20, 22)), [176, 120, 255, 255]);
+        assert_eq!(pixel(&rgba, (24, 24)), [176, 120, 255, 255]);
+        assert_eq!(pixel(&rgba, (22, 22)), MinimapTile::Grass.rgba());
+        assert_eq!(pixel(&rgba, (25, 22)), MinimapTile::Grass.rgba());
+    }
+
+    #[test]
+    fn refresh_is_throttled_and_clear_keeps_layer_choice() {
+        let mut state = MinimapState::default();
+        assert!(state.refresh_due(Duration::ZERO));
+        assert!(!state.refresh_due(Duration::from_millis(100)));
+        assert!(state.refresh_due(MINIMAP_REFRESH_INTERVAL));
+
+        state.toggle_layer(MinimapLayer::ForbiddenZones);
+        state.sync_base_tiles(vec![MinimapTile::Dirt; TILE_COUNT]);
+        state.clear_world_data();
+        assert!(state.layer_enabled(MinimapLayer::ForbiddenZones));
+        assert!(state.needs_full_sync());
+        assert_eq!(state.base_tile((0, 0)), Some(MinimapTile::Grass));
+    }
+
+    #[test]
+    fn normalized_clicks_and_viewport_fractions_share_one_frame() {
+        assert_eq!(minimap_normalized_to_world(Vec2::ZERO), Vec2::ZERO);
+        let top_left = minimap_normalized_to_world(Vec2::new(-0.5, -0.5));
+        assert_eq!(
+            top_left,
+            Vec2::new(
+                -(MAP_WIDTH as f32) * TILE_SIZE / 2.0,
+                MAP_HEIGHT as f32 * TILE_SIZE / 2.0
+            )
+        );
+
+        let fraction = world_rect_to_minimap_fraction(Rect::from_corners(top_left, Vec2::ZERO));
+        assert_eq!(fraction.min, Vec2::ZERO);
+        assert_eq!(fraction.max, Vec2::splat(0.5));
+    }
+}
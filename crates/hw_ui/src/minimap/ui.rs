@@ -0,0 +1,291 @@
+use super::model::{
+    MINIMAP_TEXTURE_HEIGHT, MINIMAP_TEXTURE_WIDTH, MinimapLayer, MinimapState,
+    minimap_normalized_to_world, world_rect_to_minimap_fraction,
+};
+use crate::camera::MainCamera;
+use crate::components::UiInputBlocker;
+use crate::locale::LocalizedText;
+use crate::theme::{UiTheme, font_size_rem};
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::picking::Pickable;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+/// ミニマップの表示サイズ（論理ピクセル）
+const MINIMAP_DISPLAY_SIZE: f32 = 200.0;
+
+#[derive(Component)]
+pub struct MinimapRoot;
+
+/// クリック・ドラッグでカメラを移動するマップ本体
+#[derive(Component)]
+pub struct MinimapImage;
+
+/// カメラの表示範囲を示す枠
+#[derive(Component)]
+pub struct MinimapViewportFrame;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MinimapLayerButton(pub MinimapLayer);
+
+pub(crate) fn spawn_minimap_ui(
+    commands: &mut Commands,
+    font: Handle<Font>,
+    theme: &UiTheme,
+    parent: Entity,
+) {
+    let root = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(theme.spacing.panel_margin_x),
+                bottom: Val::Px(theme.spacing.bottom_bar_height + theme.spacing.panel_padding),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(theme.sizes.panel_border_width)),
+                border_radius: BorderRadius::all(Val::Px(theme.sizes.panel_corner_radius)),
+                ..default()
+            },
+            BackgroundColor(theme.colors.bg_overlay),
+            BorderColor::all(theme.colors.border_default),
+            RelativeCursorPosition::default(),
+            UiInputBlocker,
+            FocusPolicy::Block,
+            MinimapRoot,
+            Name::new("Minimap"),
+        ))
+        .id();
+
+    let map = commands
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(MINIMAP_DISPLAY_SIZE),
+                height: Val::Px(MINIMAP_DISPLAY_SIZE),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            ImageNode::default(),
+            RelativeCursorPosition::default(),
+            MinimapImage,
+            Name::new("Minimap Image"),
+        ))
+        .id();
+    let viewport_frame = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BorderColor::all(theme.colors.text_primary_semantic),
+            Pickable::IGNORE,
+            MinimapViewportFrame,
+            Name::new("Minimap Viewport"),
+        ))
+        .id();
+    commands.entity(map).add_child(viewport_frame);
+
+    let layer_row = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(4.0),
+            ..default()
+        })
+        .id();
+    for layer in MinimapLayer::ALL {
+        let button = commands
+            .spawn((
+                Button,
+                Node {
+                    flex_grow: 1.0,
+                    height: Val::Px(22.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    ..default()
+                },
+                BackgroundColor(theme.colors.button_default),
+                BorderColor::all(theme.colors.border_default),
+                MinimapLayerButton(layer),
+                Name::new(format!("Minimap Layer {layer:?}")),
+            ))
+            .id();
+        commands.entity(button).with_children(|button| {
+            button.spawn((
+                LocalizedText(layer.text_id()),
+                TextFont {
+                    font: font.clone().into(),
+                    font_size: font_size_rem(theme.typography.font_size_xs),
+                    ..default()
+                },
+                TextColor(theme.colors.text_primary_semantic),
+            ));
+        });
+        commands.entity(layer_row).add_child(button);
+    }
+
+    commands.entity(root).add_children(&[map, layer_row]);
+    commands.entity(parent).add_child(root);
+}
+
+fn new_minimap_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: MINIMAP_TEXTURE_WIDTH,
+            height: MINIMAP_TEXTURE_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// 状態が変化したときだけミニマップ画像を描き直す。
+///
+/// 画像アセットは初回実行時に作り、ミニマップ本体の `ImageNode` へ割り当てる。
+pub fn upload_minimap_texture_system(
+    mut state: ResMut<MinimapState>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut q_image: Query<&mut ImageNode, With<MinimapImage>>,
+) {
+    let Some(mut images) = images else {
+        return;
+    };
+    let handle = state
+        .texture
+        .get_or_insert_with(|| images.add(new_minimap_image()))
+        .clone();
+    for mut image_node in &mut q_image {
+        if image_node.image != handle {
+            image_node.image = handle.clone();
+        }
+    }
+    if !state.take_dirty() {
+        return;
+    }
+    if let Some(mut image) = images.get_mut(&handle) {
+        image.data = Some(state.render_rgba());
+    }
+}
+
+/// カメラ表示範囲の枠をミニマップ上へ配置する。
+pub fn sync_minimap_viewport_frame_system(
+    state: Res<MinimapState>,
+    mut q_frame: Query<&mut Node, With<MinimapViewportFrame>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let Ok(mut node) = q_frame.single_mut() else {
+        return;
+    };
+    let Some(viewport) = state.viewport() else {
+        node.display = Display::None;
+        return;
+    };
+    let fraction = world_rect_to_minimap_fraction(viewport);
+    let min = fraction.min.clamp(Vec2::ZERO, Vec2::ONE);
+    let max = fraction.max.clamp(Vec2::ZERO, Vec2::ONE);
+    node.display = Display::Flex;
+    node.left = Val::Percent(min.x * 100.0);
+    node.top = Val::Percent(min.y * 100.0);
+    node.width = Val::Percent((max.x - min.x) * 100.0);
+    node.height = Val::Percent((max.y - min.y) * 100.0);
+}
+
+/// レイヤーボタンの切り替えと、マップ押下中のカメラ移動（クリック・ドラッグ）を処理する。
+pub fn minimap_interaction_system(
+    mut state: ResMut<MinimapState>,
+    theme: Res<UiTheme>,
+    mut q_layer_buttons: Query<
+        (&Interaction, &MinimapLayerButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    q_map: Query<(&Interaction, &RelativeCursorPosition), With<MinimapImage>>,
+    mut q_camera: Query<&mut Transform, With<MainCamera>>,
+) {
+    for (interaction, button, mut color) in &mut q_layer_buttons {
+        if *interaction == Interaction::Pressed {
+            state.toggle_layer(button.0);
+        }
+        color.0 = layer_button_color(*interaction, state.layer_enabled(button.0), &theme);
+    }
+
+    for (interaction, cursor) in &q_map {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        let target =
+            minimap_normalized_to_world(normalized.clamp(Vec2::splat(-0.5), Vec2::splat(0.5)));
+        if let Some(mut camera) = q_camera.iter_mut().next() {
+            camera.translation.x = target.x;
+            camera.translation.y = target.y;
+        }
+    }
+}
+
+fn layer_button_color(interaction: Interaction, enabled: bool, theme: &UiTheme) -> Color {
+    match (interaction, enabled) {
+        (Interaction::Hovered, _) => theme.colors.button_hover,
+        (_, true) => theme.colors.button_pressed,
+        _ => theme.colors.button_default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pressing_the_map_moves_the_camera_and_layer_buttons_toggle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<UiTheme>()
+            .init_resource::<MinimapState>()
+            .add_systems(Update, minimap_interaction_system);
+        let camera = app
+            .world_mut()
+            .spawn((Transform::default(), MainCamera))
+            .id();
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            RelativeCursorPosition {
+                cursor_over: true,
+                normalized: Some(Vec2::new(0.25, -0.25)),
+            },
+            MinimapImage,
+        ));
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            BackgroundColor::default(),
+            MinimapLayerButton(MinimapLayer::PowerCoverage),
+        ));
+
+        app.update();
+
+        let translation = app.world().get::<Transform>(camera).unwrap().translation;
+        assert!(translation.x > 0.0 && translation.y > 0.0);
+        assert_eq!(
+            minimap_normalized_to_world(Vec2::new(0.25, -0.25)),
+            translation.truncate()
+        );
+        assert!(
+            app.world()
+                .resource::<MinimapState>()
+                .layer_enabled(MinimapLayer::PowerCoverage)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use hw_logistics::transport_request::TransportPriority;
-use hw_logistics::{ResourceType, StockpileAcceptance, StockpilePolicyState};
+use hw_logistics::{ResourceType, StockpileAcceptance, StockpilePolicyState, StockpileResourceSet};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InspectionSoulGender {
@@ -15,8 +15,13 @@ pub struct SoulInspectionFields {
     pub stress: String,
     pub fatigue: String,
     pub dream: String,
+    /// 贖罪ポイントの進捗
+    pub penance: String,
     pub task: String,
     pub inventory: String,
+    pub relationships: String,
+    /// 新しい順の Life Log 行
+    pub life_log: Vec<String>,
     pub common: String,
 }
 
@@ -31,6 +36,29 @@ pub struct StockpileInspectionFields {
     pub inbound_priority: TransportPriority,
     pub target_amount: usize,
     pub allow_export: bool,
+    /// このセルを搬入先に含む補給ルート
+    pub inbound_route: Option<InboundSupplyRouteFields>,
+    /// このセルを搬出元に含む補給ルート数
+    pub outbound_routes: usize,
+    /// 補給ルート作成のために仮指定されている搬出元セル
+    pub supply_route_source: Option<Entity>,
+}
+
+/// 搬入先クラスタ全体で集計した補給ルートの状態
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InboundSupplyRouteFields {
+    pub route: Entity,
+    pub resources: StockpileResourceSet,
+    /// 搬入先クラスタの受入資源の和集合
+    pub destination_acceptance: StockpileResourceSet,
+    /// フィルタ対象資源の現在量（搬入先クラスタ合計）
+    pub level: usize,
+    pub target_amount: usize,
+    pub capacity: usize,
+    pub source_cells: usize,
+    pub destination_cells: usize,
+    pub deliveries_per_minute: usize,
+    pub delivered_total: u32,
 }
 
 #[derive(Clone, PartialEq)]
@@ -43,9 +71,32 @@ pub struct EntityInspectionModel {
     pub stockpile: Option<StockpileInspectionFields>,
 }
 
+/// 複数選択時に情報パネルへ出す種類別の集計
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SelectionGroupSummary {
+    pub total: usize,
+    pub souls: usize,
+    pub familiars: usize,
+    pub doors: usize,
+    pub locked_doors: usize,
+    pub designations: usize,
+    pub stockpiles: usize,
+}
+
+impl SelectionGroupSummary {
+    /// 上記の分類に入らない選択（木・岩・アイテム・その他の建物）
+    pub const fn others(&self) -> usize {
+        self.total.saturating_sub(
+            self.souls + self.familiars + self.doors + self.designations + self.stockpiles,
+        )
+    }
+}
+
 #[derive(Resource, Default, Clone, PartialEq)]
 pub struct EntityInspectionViewModel {
     pub model: Option<EntityInspectionModel>,
+    /// 2 体以上を選択し、かつピン留めしていないときの一括操作用集計
+    pub group: Option<SelectionGroupSummary>,
 }
 
 impl EntityInspectionViewModel {
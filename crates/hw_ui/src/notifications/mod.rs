@@ -3,16 +3,20 @@ mod reducer;
 mod ui;
 
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 
 pub use model::{
     MAX_ACTIVE_TOASTS, MAX_NOTIFICATION_HISTORY, NOTIFICATION_DEDUPE_WINDOW,
     NOTIFICATION_TOAST_LIFETIME, NotificationCenter, NotificationEntry, NotificationEntryId,
-    NotificationHistoryButton, NotificationHistoryPanel, NotificationHistoryRow, NotificationKey,
-    NotificationRetention, NotificationSeverity, NotificationToastRoot, NotificationToastRow,
-    NotificationToastSurface, NotificationUiAssets, NotificationUiRuntime, NotificationUnreadText,
-    UserFacingNotification,
+    NotificationFocusTarget, NotificationHistoryButton, NotificationHistoryPanel,
+    NotificationHistoryRow, NotificationKey, NotificationRetention, NotificationSeverity,
+    NotificationToastRoot, NotificationToastRow, NotificationToastSurface, NotificationUiAssets,
+    NotificationUiRuntime, NotificationUnreadText, UserFacingNotification,
+};
+pub use reducer::{
+    apply_notification_ui_state_system, notification_focus_click_system,
+    reduce_notifications_system,
 };
-pub use reducer::{apply_notification_ui_state_system, reduce_notifications_system};
 pub use ui::present_notifications_system;
 pub(crate) use ui::spawn_notification_ui;
 
@@ -48,8 +52,12 @@ pub(crate) fn reset_for_world_replace(world: &mut World) {
     for mut node in history_panels.iter_mut(world) {
         node.display = Display::None;
     }
+    let label = world
+        .get_resource_or_init::<Localization>()
+        .text("ui.notifications.title")
+        .to_string();
     let mut unread_labels = world.query_filtered::<&mut Text, With<NotificationUnreadText>>();
     for mut text in unread_labels.iter_mut(world) {
-        text.0 = "通知".to_string();
+        text.0 = label.clone();
     }
 }
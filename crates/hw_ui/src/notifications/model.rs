@@ -63,6 +63,8 @@ pub struct UserFacingNotification {
     pub title: String,
     pub body: String,
     pub retention: NotificationRetention,
+    /// 履歴行クリックで選択・カメラ移動する対象
+    pub focus: Option<Entity>,
 }
 
 impl UserFacingNotification {
@@ -79,8 +81,14 @@ impl UserFacingNotification {
             title: title.into(),
             body: body.into(),
             retention,
+            focus: None,
         }
     }
+
+    pub fn with_focus(mut self, target: Entity) -> Self {
+        self.focus = Some(target);
+        self
+    }
 }
 
 pub type NotificationEntryId = u64;
@@ -93,6 +101,7 @@ pub struct NotificationEntry {
     pub title: String,
     pub body: String,
     pub retention: NotificationRetention,
+    pub focus: Option<Entity>,
     pub first_seen: Duration,
     pub last_seen: Duration,
     pub repeat_count: u32,
@@ -182,5 +191,9 @@ pub struct NotificationHistoryButton;
 #[derive(Component)]
 pub struct NotificationHistoryPanel;
 
+/// クリックで対象エンティティへジャンプできる履歴行
+#[derive(Component, Clone, Copy, Debug)]
+pub struct NotificationFocusTarget(pub Entity);
+
 #[derive(Component)]
 pub struct NotificationUnreadText;
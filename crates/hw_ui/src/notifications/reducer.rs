@@ -1,10 +1,13 @@
 use super::model::{
     MAX_ACTIVE_TOASTS, MAX_NOTIFICATION_HISTORY, NOTIFICATION_DEDUPE_WINDOW,
     NOTIFICATION_TOAST_LIFETIME, NotificationCenter, NotificationEntry, NotificationEntryId,
-    NotificationHistoryButton, NotificationRetention, UserFacingNotification,
+    NotificationFocusTarget, NotificationHistoryButton, NotificationRetention,
+    UserFacingNotification,
 };
+use crate::camera::MainCamera;
 use crate::components::UiInputState;
 use crate::interaction::update_interaction_color;
+use crate::selection::SelectionWriter;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 use bevy::time::Real;
@@ -93,6 +96,9 @@ impl NotificationCenter {
             entry.title = incoming.title;
             entry.body = incoming.body;
             entry.retention = entry.retention.merge(incoming.retention);
+            if incoming.focus.is_some() {
+                entry.focus = incoming.focus;
+            }
             entry.last_seen = now;
             entry.expires_at = now + NOTIFICATION_TOAST_LIFETIME;
             entry.repeat_count = entry.repeat_count.saturating_add(1);
@@ -122,6 +128,7 @@ impl NotificationCenter {
                 title: incoming.title,
                 body: incoming.body,
                 retention,
+                focus: incoming.focus,
                 first_seen: now,
                 last_seen: now,
                 repeat_count: 1,
@@ -208,10 +215,34 @@ pub fn apply_notification_ui_state_system(
     }
 }
 
+/// 履歴行クリックで通知の対象を選択し、カメラをその位置へ移動する。
+///
+/// 対象がすでに despawn されていれば何もしない。
+pub fn notification_focus_click_system(
+    q_rows: Query<(&Interaction, &NotificationFocusTarget), Changed<Interaction>>,
+    mut selection: SelectionWriter,
+    mut q_camera: Query<&mut Transform, With<MainCamera>>,
+    q_transforms: Query<&GlobalTransform>,
+) {
+    for (interaction, target) in &q_rows {
+        if *interaction != Interaction::Pressed || q_transforms.get(target.0).is_err() {
+            continue;
+        }
+        crate::list::select_entity_and_focus_camera(
+            target.0,
+            "notification",
+            &mut selection,
+            &mut q_camera,
+            &q_transforms,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::notifications::{NotificationRetention, NotificationSeverity};
+    use crate::selection::{SelectedEntities, SelectedEntity};
 
     fn notification(
         key: impl Into<String>,
@@ -368,4 +399,65 @@ mod tests {
             Display::Flex
         );
     }
+
+    #[test]
+    fn coalesced_focus_keeps_the_last_known_target() {
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let mut center = NotificationCenter::default();
+        center.push(
+            notification("focus", NotificationRetention::Important).with_focus(first),
+            Duration::ZERO,
+        );
+        center.push(
+            notification("focus", NotificationRetention::Important),
+            Duration::from_secs(1),
+        );
+        assert_eq!(center.history_entries().next().unwrap().focus, Some(first));
+
+        center.push(
+            notification("focus", NotificationRetention::Important).with_focus(second),
+            Duration::from_secs(2),
+        );
+        assert_eq!(center.history_entries().next().unwrap().focus, Some(second));
+    }
+
+    #[test]
+    fn pressing_a_focus_row_selects_the_target_and_moves_the_camera() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<SelectedEntity>()
+            .init_resource::<SelectedEntities>()
+            .add_systems(Update, notification_focus_click_system);
+        let camera = app
+            .world_mut()
+            .spawn((Transform::default(), MainCamera))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::new(
+                64.0, -32.0, 0.0,
+            )))
+            .id();
+        let missing = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(missing);
+        app.world_mut()
+            .spawn((Interaction::Pressed, NotificationFocusTarget(missing)));
+        app.world_mut()
+            .spawn((Interaction::Pressed, NotificationFocusTarget(target)));
+
+        app.update();
+
+        assert_eq!(app.world().resource::<SelectedEntity>().0, Some(target));
+        assert_eq!(
+            app.world()
+                .resource::<SelectedEntities>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![target]
+        );
+        let translation = app.world().get::<Transform>(camera).unwrap().translation;
+        assert_eq!(translation.truncate(), Vec2::new(64.0, -32.0));
+    }
 }
@@ -1,14 +1,17 @@
 use super::model::{
-    NotificationCenter, NotificationEntry, NotificationHistoryButton, NotificationHistoryPanel,
-    NotificationHistoryRow, NotificationSeverity, NotificationToastRoot, NotificationToastRow,
-    NotificationToastSurface, NotificationUiAssets, NotificationUiRuntime, NotificationUnreadText,
+    NotificationCenter, NotificationEntry, NotificationFocusTarget, NotificationHistoryButton,
+    NotificationHistoryPanel, NotificationHistoryRow, NotificationSeverity, NotificationToastRoot,
+    NotificationToastRow, NotificationToastSurface, NotificationUiAssets, NotificationUiRuntime,
+    NotificationUnreadText,
 };
 use crate::components::UiInputBlocker;
+use crate::locale::LocalizedText;
 use crate::theme::{UiTheme, font_size_rem};
 use bevy::ecs::system::SystemParam;
 use bevy::picking::Pickable;
 use bevy::prelude::*;
 use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+use hw_core::locale::Localization;
 
 type ToastRootQuery<'w, 's> = Query<
     'w,
@@ -96,7 +99,8 @@ pub(crate) fn spawn_notification_ui(
         .id();
     commands.entity(history_button).with_children(|button| {
         button.spawn((
-            Text::new("通知"),
+            // 未読数つきの表示は present_notifications_system が書き込む
+            Text::default(),
             TextFont {
                 font: font.clone().into(),
                 font_size: font_size_rem(theme.typography.font_size_sm),
@@ -138,7 +142,7 @@ pub(crate) fn spawn_notification_ui(
         .id();
     commands.entity(history_panel).with_children(|panel| {
         panel.spawn((
-            Text::new("重要な通知"),
+            LocalizedText("ui.notifications.history_title"),
             TextFont {
                 font: font.into(),
                 font_size: font_size_rem(theme.typography.font_size_md),
@@ -159,10 +163,11 @@ pub fn present_notifications_system(
     center: Res<NotificationCenter>,
     assets: Option<Res<NotificationUiAssets>>,
     theme: Res<UiTheme>,
+    localization: Res<Localization>,
     mut runtime: ResMut<NotificationUiRuntime>,
     mut queries: NotificationUiQueries,
 ) {
-    if runtime.rendered_revision == Some(center.revision()) {
+    if runtime.rendered_revision == Some(center.revision()) && !localization.is_changed() {
         return;
     }
     let Some(assets) = assets else {
@@ -195,9 +200,12 @@ pub fn present_notifications_system(
     };
     if let Ok(mut text) = queries.unread_text.single_mut() {
         text.0 = if center.unread_count() == 0 {
-            "通知".to_string()
+            localization.text("ui.notifications.title").to_string()
         } else {
-            format!("通知 ({})", center.unread_count())
+            localization.format(
+                "ui.notifications.unread",
+                &[("count", &center.unread_count())],
+            )
         };
     }
 
@@ -253,6 +261,11 @@ fn spawn_toast_row(
             Name::new("Notification Toast"),
         ))
         .id();
+    if let Some(target) = entry.focus {
+        commands
+            .entity(row)
+            .insert((Button, NotificationFocusTarget(target)));
+    }
     commands.entity(row).with_children(|row| {
         row.spawn((
             Text::new(entry_title(entry)),
@@ -310,6 +323,11 @@ fn spawn_history_row(
             Name::new("Notification History Row"),
         ))
         .id();
+    if let Some(target) = entry.focus {
+        commands
+            .entity(row)
+            .insert((Button, NotificationFocusTarget(target)));
+    }
     commands.entity(row).with_children(|row| {
         row.spawn((
             Text::new(entry_title(entry)),
@@ -331,6 +349,17 @@ fn spawn_history_row(
                 TextColor(theme.colors.text_secondary_semantic),
             ));
         }
+        if entry.focus.is_some() {
+            row.spawn((
+                LocalizedText("ui.notifications.click_to_focus"),
+                TextFont {
+                    font: font.clone().into(),
+                    font_size: font_size_rem(theme.typography.font_size_xs),
+                    ..default()
+                },
+                TextColor(theme.colors.text_accent_semantic),
+            ));
+        }
     });
     commands.entity(parent).add_child(row);
 }
@@ -343,7 +372,7 @@ fn spawn_history_empty_row(
 ) {
     let row = commands
         .spawn((
-            Text::new("重要な通知はありません"),
+            LocalizedText("ui.notifications.history_empty"),
             TextFont {
                 font: font.clone().into(),
                 font_size: font_size_rem(theme.typography.font_size_sm),
@@ -405,6 +434,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins)
             .init_resource::<UiTheme>()
+            .init_resource::<Localization>()
             .init_resource::<NotificationCenter>()
             .init_resource::<NotificationUiRuntime>()
             .add_systems(Startup, setup_notification_test_ui)
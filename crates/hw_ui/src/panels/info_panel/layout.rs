@@ -1,17 +1,21 @@
 use crate::components::{
-    InfoPanel, InfoPanelNodes, MenuAction, MenuButton, SoulRenameButton, SoulRenameFieldContainer,
-    StockpileAcceptanceRowNodes, UiInputBlocker, UiNodeRegistry, UiSlot,
+    InfoPanel, InfoPanelNodes, InfoPanelTab, InfoPanelTabButton, MenuAction, MenuButton,
+    SoulRenameButton, SoulRenameFieldContainer, StockpileAcceptanceRowNodes, UiInputBlocker,
+    UiNodeRegistry, UiSlot,
 };
+use crate::locale::LocalizedText;
 use crate::setup::UiAssets;
 use crate::theme::UiTheme;
 use bevy::input::mouse::MouseScrollUnit;
 use bevy::prelude::*;
 use bevy::ui::{BackgroundGradient, ColorStop, LinearGradient, RelativeCursorPosition};
-use hw_logistics::{STOCKPILE_ACCEPTANCE_RESOURCES, StockpilePolicyPatch};
+use hw_logistics::{STOCKPILE_ACCEPTANCE_RESOURCES, StockpileAcceptance, StockpilePolicyPatch};
 
 use crate::intents::StockpilePolicyEditTarget;
+use crate::panels::task_list::TaskPriorityAdjustment;
 
 const INFO_PANEL_MAX_HEIGHT_VH: f32 = 58.0;
+const LIFE_LOG_MAX_HEIGHT_PX: f32 = 220.0;
 
 fn scroll_info_panel(
     on_scroll: On<Pointer<Scroll>>,
@@ -28,11 +32,70 @@ fn scroll_info_panel(
     scroll_position.y = (scroll_position.y - delta_y).clamp(0.0, max_offset);
 }
 
+/// Life Log は独自にスクロールし、パネル本体へはスクロールを伝播させない。
+fn scroll_life_log(
+    mut on_scroll: On<Pointer<Scroll>>,
+    mut query: Query<(&mut ScrollPosition, &ComputedNode)>,
+) {
+    on_scroll.propagate(false);
+    let Ok((mut scroll_position, node)) = query.get_mut(on_scroll.entity) else {
+        return;
+    };
+    let delta_y = match on_scroll.unit {
+        MouseScrollUnit::Line => on_scroll.y * 20.0,
+        MouseScrollUnit::Pixel => on_scroll.y,
+    };
+    let max_offset = (node.content_size.y - node.size.y).max(0.0) * node.inverse_scale_factor;
+    scroll_position.y = (scroll_position.y - delta_y).clamp(0.0, max_offset);
+}
+
+fn spawn_info_panel_tab_button(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    label: &'static str,
+    tab: InfoPanelTab,
+) {
+    let is_active = tab == InfoPanelTab::default();
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                border: UiRect::bottom(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderColor::all(if is_active {
+                theme.colors.text_accent_semantic
+            } else {
+                Color::NONE
+            }),
+            InfoPanelTabButton(tab),
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                LocalizedText(label),
+                TextFont {
+                    font: game_assets.font_ui().clone().into(),
+                    font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
+                    weight: FontWeight::SEMIBOLD,
+                    ..default()
+                },
+                TextColor(if is_active {
+                    theme.colors.text_accent_semantic
+                } else {
+                    theme.colors.text_secondary_semantic
+                }),
+            ));
+        });
+}
+
 fn spawn_info_section_divider(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    label: &str,
+    label: &'static str,
 ) {
     parent
         .spawn(Node {
@@ -57,7 +120,7 @@ fn spawn_info_section_divider(
                 BackgroundColor(theme.colors.border_default),
             ));
             row.spawn((
-                Text::new(label),
+                LocalizedText(label),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
@@ -82,7 +145,46 @@ fn spawn_stockpile_editor_button(
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
     width: Val,
-    label: &str,
+    label: impl Bundle,
+) -> (Entity, Entity) {
+    spawn_info_action_button(
+        parent,
+        game_assets,
+        theme,
+        width,
+        label,
+        MenuAction::ApplyStockpilePolicy {
+            target: StockpilePolicyEditTarget::Single(Entity::PLACEHOLDER),
+            patch: StockpilePolicyPatch::default(),
+        },
+    )
+}
+
+fn spawn_supply_route_button(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    width: Val,
+    label: impl Bundle,
+) -> (Entity, Entity) {
+    spawn_info_action_button(
+        parent,
+        game_assets,
+        theme,
+        width,
+        label,
+        MenuAction::SetSupplyRouteSource(None),
+    )
+}
+
+/// `label` は固定文言なら `LocalizedText`、更新システムが書き込むボタンなら空の `Text`。
+fn spawn_info_action_button(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    width: Val,
+    label: impl Bundle,
+    action: MenuAction,
 ) -> (Entity, Entity) {
     let mut text_entity = Entity::PLACEHOLDER;
     let button = parent
@@ -97,15 +199,12 @@ fn spawn_stockpile_editor_button(
                 ..default()
             },
             BackgroundColor(theme.colors.button_default),
-            MenuButton(MenuAction::ApplyStockpilePolicy {
-                target: StockpilePolicyEditTarget::Single(Entity::PLACEHOLDER),
-                patch: StockpilePolicyPatch::default(),
-            }),
+            MenuButton(action),
         ))
         .with_children(|button| {
             text_entity = button
                 .spawn((
-                    Text::new(label),
+                    label,
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
@@ -120,6 +219,36 @@ fn spawn_stockpile_editor_button(
     (button, text_entity)
 }
 
+/// 複数選択の一括操作ボタンを 1 行に並べる。
+fn spawn_group_action_row(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    buttons: &[(&'static str, MenuAction)],
+) -> Entity {
+    let width = Val::Percent(100.0 / buttons.len() as f32);
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(5.0),
+            ..default()
+        })
+        .with_children(|row| {
+            for (label, action) in buttons {
+                spawn_info_action_button(
+                    row,
+                    game_assets,
+                    theme,
+                    width,
+                    LocalizedText(label),
+                    *action,
+                );
+            }
+        })
+        .id()
+}
+
 fn spawn_stockpile_acceptance_row(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
@@ -315,7 +444,7 @@ pub fn spawn_info_panel_ui(
                     ))
                     .with_children(|btn| {
                         btn.spawn((
-                            Text::new("Unpin"),
+                            LocalizedText("ui.info_panel.unpin"),
                             TextFont {
                                 font: game_assets.font_ui().clone().into(),
                                 font_size: crate::theme::font_size_rem(
@@ -353,148 +482,263 @@ pub fn spawn_info_panel_ui(
                 },
                 UiSlot::InfoPanelStatsGroup,
             ))
-            .with_children(|col| {
-                spawn_info_section_divider(col, game_assets, theme, "Status");
+            .with_children(|stats_col| {
+                stats_col
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(4.0),
+                        margin: UiRect::bottom(Val::Px(2.0)),
+                        ..default()
+                    })
+                    .with_children(|tabs| {
+                        spawn_info_panel_tab_button(
+                            tabs,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.status",
+                            InfoPanelTab::Status,
+                        );
+                        spawn_info_panel_tab_button(
+                            tabs,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.life_log",
+                            InfoPanelTab::LifeLog,
+                        );
+                    });
 
-                let motivation = col
-                    .spawn((
-                        Text::new(""),
-                        TextFont {
-                            font: game_assets.font_ui().clone().into(),
-                            font_size: crate::theme::font_size_rem(
-                                theme.typography.font_size_small,
-                            ),
-                            ..default()
-                        },
-                        UiSlot::StatMotivation,
-                    ))
-                    .id();
-                ui_nodes.set_slot(UiSlot::StatMotivation, motivation);
-                info_panel_nodes.motivation = Some(motivation);
+                let status_body = stats_col
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    })
+                    .with_children(|col| {
+                        spawn_info_section_divider(col, game_assets, theme, "ui.info_panel.status");
 
-                col.spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    ..default()
-                })
-                .with_children(|row| {
-                    row.spawn((
-                        ImageNode::new(game_assets.icon_stress().clone()),
-                        Node {
-                            width: Val::Px(14.0),
-                            height: Val::Px(14.0),
-                            margin: UiRect::right(Val::Px(4.0)),
+                        let motivation = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                                UiSlot::StatMotivation,
+                            ))
+                            .id();
+                        ui_nodes.set_slot(UiSlot::StatMotivation, motivation);
+                        info_panel_nodes.motivation = Some(motivation);
+
+                        col.spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
                             ..default()
-                        },
-                    ));
-                    let stress = row
-                        .spawn((
-                            Text::new(""),
-                            TextFont {
-                                font: game_assets.font_ui().clone().into(),
-                                font_size: crate::theme::font_size_rem(
-                                    theme.typography.font_size_small,
-                                ),
-                                ..default()
-                            },
-                            UiSlot::StatStress,
-                        ))
-                        .id();
-                    ui_nodes.set_slot(UiSlot::StatStress, stress);
-                    info_panel_nodes.stress = Some(stress);
-                });
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                ImageNode::new(game_assets.icon_stress().clone()),
+                                Node {
+                                    width: Val::Px(14.0),
+                                    height: Val::Px(14.0),
+                                    margin: UiRect::right(Val::Px(4.0)),
+                                    ..default()
+                                },
+                            ));
+                            let stress = row
+                                .spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: game_assets.font_ui().clone().into(),
+                                        font_size: crate::theme::font_size_rem(
+                                            theme.typography.font_size_small,
+                                        ),
+                                        ..default()
+                                    },
+                                    UiSlot::StatStress,
+                                ))
+                                .id();
+                            ui_nodes.set_slot(UiSlot::StatStress, stress);
+                            info_panel_nodes.stress = Some(stress);
+                        });
 
-                col.spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    ..default()
-                })
-                .with_children(|row| {
-                    row.spawn((
-                        ImageNode::new(game_assets.icon_fatigue().clone()),
-                        Node {
-                            width: Val::Px(14.0),
-                            height: Val::Px(14.0),
-                            margin: UiRect::right(Val::Px(4.0)),
+                        col.spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
                             ..default()
-                        },
-                    ));
-                    let fatigue = row
-                        .spawn((
-                            Text::new(""),
-                            TextFont {
-                                font: game_assets.font_ui().clone().into(),
-                                font_size: crate::theme::font_size_rem(
-                                    theme.typography.font_size_small,
-                                ),
-                                ..default()
-                            },
-                            UiSlot::StatFatigue,
-                        ))
-                        .id();
-                    ui_nodes.set_slot(UiSlot::StatFatigue, fatigue);
-                    info_panel_nodes.fatigue = Some(fatigue);
-                });
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                ImageNode::new(game_assets.icon_fatigue().clone()),
+                                Node {
+                                    width: Val::Px(14.0),
+                                    height: Val::Px(14.0),
+                                    margin: UiRect::right(Val::Px(4.0)),
+                                    ..default()
+                                },
+                            ));
+                            let fatigue = row
+                                .spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: game_assets.font_ui().clone().into(),
+                                        font_size: crate::theme::font_size_rem(
+                                            theme.typography.font_size_small,
+                                        ),
+                                        ..default()
+                                    },
+                                    UiSlot::StatFatigue,
+                                ))
+                                .id();
+                            ui_nodes.set_slot(UiSlot::StatFatigue, fatigue);
+                            info_panel_nodes.fatigue = Some(fatigue);
+                        });
 
-                let dream = col
-                    .spawn((
-                        Text::new(""),
-                        TextFont {
-                            font: game_assets.font_ui().clone().into(),
-                            font_size: crate::theme::font_size_rem(
-                                theme.typography.font_size_small,
-                            ),
+                        let dream = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                                UiSlot::StatDream,
+                            ))
+                            .id();
+                        ui_nodes.set_slot(UiSlot::StatDream, dream);
+                        info_panel_nodes.dream = Some(dream);
+
+                        let penance = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                            ))
+                            .id();
+                        info_panel_nodes.penance = Some(penance);
+
+                        spawn_info_section_divider(
+                            col,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.current_task",
+                        );
+
+                        col.spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::top(Val::Px(5.0)),
                             ..default()
-                        },
-                        UiSlot::StatDream,
-                    ))
-                    .id();
-                ui_nodes.set_slot(UiSlot::StatDream, dream);
-                info_panel_nodes.dream = Some(dream);
+                        })
+                        .with_children(|row| {
+                            let task = row
+                                .spawn((
+                                    Text::new(""),
+                                    TextFont {
+                                        font: game_assets.font_ui().clone().into(),
+                                        font_size: crate::theme::font_size_rem(
+                                            theme.typography.font_size_small,
+                                        ),
+                                        ..default()
+                                    },
+                                    UiSlot::TaskText,
+                                ))
+                                .id();
+                            ui_nodes.set_slot(UiSlot::TaskText, task);
+                            info_panel_nodes.task = Some(task);
+                        });
 
-                spawn_info_section_divider(col, game_assets, theme, "Current Task");
+                        spawn_info_section_divider(
+                            col,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.inventory",
+                        );
 
-                col.spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    margin: UiRect::top(Val::Px(5.0)),
-                    ..default()
-                })
-                .with_children(|row| {
-                    let task = row
-                        .spawn((
-                            Text::new(""),
-                            TextFont {
-                                font: game_assets.font_ui().clone().into(),
-                                font_size: crate::theme::font_size_rem(
-                                    theme.typography.font_size_small,
-                                ),
-                                ..default()
-                            },
-                            UiSlot::TaskText,
-                        ))
-                        .id();
-                    ui_nodes.set_slot(UiSlot::TaskText, task);
-                    info_panel_nodes.task = Some(task);
-                });
+                        let inventory = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                                UiSlot::InventoryText,
+                            ))
+                            .id();
+                        ui_nodes.set_slot(UiSlot::InventoryText, inventory);
+                        info_panel_nodes.inventory = Some(inventory);
 
-                spawn_info_section_divider(col, game_assets, theme, "Inventory");
+                        spawn_info_section_divider(
+                            col,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.relationships",
+                        );
+
+                        let relationships = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                            ))
+                            .id();
+                        info_panel_nodes.relationships = Some(relationships);
+                    })
+                    .id();
+                info_panel_nodes.status_body = Some(status_body);
 
-                let inventory = col
+                let life_log_body = stats_col
                     .spawn((
-                        Text::new(""),
-                        TextFont {
-                            font: game_assets.font_ui().clone().into(),
-                            font_size: crate::theme::font_size_rem(
-                                theme.typography.font_size_small,
-                            ),
+                        Node {
+                            display: Display::None,
+                            flex_direction: FlexDirection::Column,
+                            max_height: Val::Px(LIFE_LOG_MAX_HEIGHT_PX),
+                            overflow: Overflow::scroll_y(),
                             ..default()
                         },
-                        UiSlot::InventoryText,
+                        ScrollPosition::default(),
                     ))
+                    .observe(scroll_life_log)
+                    .with_children(|col| {
+                        spawn_info_section_divider(
+                            col,
+                            game_assets,
+                            theme,
+                            "ui.info_panel.life_log",
+                        );
+
+                        let life_log_text = col
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: game_assets.font_ui().clone().into(),
+                                    font_size: crate::theme::font_size_rem(
+                                        theme.typography.font_size_small,
+                                    ),
+                                    ..default()
+                                },
+                            ))
+                            .id();
+                        info_panel_nodes.life_log_text = Some(life_log_text);
+                    })
                     .id();
-                ui_nodes.set_slot(UiSlot::InventoryText, inventory);
-                info_panel_nodes.inventory = Some(inventory);
+                info_panel_nodes.life_log_body = Some(life_log_body);
             })
             .id();
         ui_nodes.set_slot(UiSlot::InfoPanelStatsGroup, stats);
@@ -509,7 +753,12 @@ pub fn spawn_info_panel_ui(
                 ..default()
             })
             .with_children(|column| {
-                spawn_info_section_divider(column, game_assets, theme, "Stockpile Policy");
+                spawn_info_section_divider(
+                    column,
+                    game_assets,
+                    theme,
+                    "ui.info_panel.stockpile_policy",
+                );
 
                 info_panel_nodes.stockpile_state = Some(
                     column
@@ -543,7 +792,12 @@ pub fn spawn_info_panel_ui(
                         .id(),
                 );
 
-                spawn_info_section_divider(column, game_assets, theme, "Accepted Resources");
+                spawn_info_section_divider(
+                    column,
+                    game_assets,
+                    theme,
+                    "ui.info_panel.accepted_resources",
+                );
 
                 info_panel_nodes.stockpile_acceptance_summary = Some(
                     column
@@ -575,7 +829,7 @@ pub fn spawn_info_panel_ui(
                             game_assets,
                             theme,
                             Val::Percent(50.0),
-                            "Allow All",
+                            LocalizedText("ui.info_panel.allow_all"),
                         );
                         info_panel_nodes.stockpile_acceptance_all_button = Some(all_button);
 
@@ -584,7 +838,7 @@ pub fn spawn_info_panel_ui(
                             game_assets,
                             theme,
                             Val::Percent(50.0),
-                            "Clear All",
+                            LocalizedText("ui.info_panel.clear_all"),
                         );
                         info_panel_nodes.stockpile_acceptance_none_button = Some(none_button);
                     });
@@ -625,13 +879,13 @@ pub fn spawn_info_panel_ui(
                             game_assets,
                             theme,
                             Val::Px(30.0),
-                            "−",
+                            Text::new("−"),
                         );
                         info_panel_nodes.stockpile_target_decrease_button = Some(decrease_button);
 
                         info_panel_nodes.stockpile_target_text = Some(
                             row.spawn((
-                                Text::new("Target"),
+                                Text::default(),
                                 TextFont {
                                     font: game_assets.font_ui().clone().into(),
                                     font_size: crate::theme::font_size_rem(
@@ -655,7 +909,7 @@ pub fn spawn_info_panel_ui(
                             game_assets,
                             theme,
                             Val::Px(30.0),
-                            "+",
+                            Text::new("+"),
                         );
                         info_panel_nodes.stockpile_target_increase_button = Some(increase_button);
                     });
@@ -665,7 +919,7 @@ pub fn spawn_info_panel_ui(
                     game_assets,
                     theme,
                     Val::Percent(100.0),
-                    "Inbound Priority",
+                    Text::default(),
                 );
                 info_panel_nodes.stockpile_priority_button = Some(priority_button);
                 info_panel_nodes.stockpile_priority_text = Some(priority_text);
@@ -675,24 +929,286 @@ pub fn spawn_info_panel_ui(
                     game_assets,
                     theme,
                     Val::Percent(100.0),
-                    "Export",
+                    Text::default(),
                 );
                 info_panel_nodes.stockpile_export_button = Some(export_button);
                 info_panel_nodes.stockpile_export_text = Some(export_text);
 
-                spawn_info_section_divider(column, game_assets, theme, "Batch Edit");
+                spawn_info_section_divider(
+                    column,
+                    game_assets,
+                    theme,
+                    "ui.info_panel.supply_route",
+                );
+                info_panel_nodes.stockpile_route_text = Some(
+                    column
+                        .spawn((
+                            Text::new(""),
+                            TextFont {
+                                font: game_assets.font_ui().clone().into(),
+                                font_size: crate::theme::font_size_rem(
+                                    theme.typography.font_size_xs,
+                                ),
+                                ..default()
+                            },
+                            TextColor(theme.colors.text_secondary_semantic),
+                        ))
+                        .id(),
+                );
+
+                column
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(5.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        let (source_button, source_text) = spawn_supply_route_button(
+                            row,
+                            game_assets,
+                            theme,
+                            Val::Percent(50.0),
+                            Text::default(),
+                        );
+                        info_panel_nodes.stockpile_route_source_button = Some(source_button);
+                        info_panel_nodes.stockpile_route_source_text = Some(source_text);
+
+                        let (create_button, _) = spawn_supply_route_button(
+                            row,
+                            game_assets,
+                            theme,
+                            Val::Percent(50.0),
+                            LocalizedText("ui.info_panel.supply_from_source"),
+                        );
+                        info_panel_nodes.stockpile_route_create_button = Some(create_button);
+                    });
+
+                let route_controls = column
+                    .spawn(Node {
+                        display: Display::None,
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(5.0),
+                        ..default()
+                    })
+                    .with_children(|controls| {
+                        controls
+                            .spawn(Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(5.0),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                let (decrease_button, _) = spawn_supply_route_button(
+                                    row,
+                                    game_assets,
+                                    theme,
+                                    Val::Px(30.0),
+                                    Text::new("−"),
+                                );
+                                info_panel_nodes.stockpile_route_target_decrease_button =
+                                    Some(decrease_button);
+
+                                info_panel_nodes.stockpile_route_target_text = Some(
+                                    row.spawn((
+                                        Text::default(),
+                                        TextFont {
+                                            font: game_assets.font_ui().clone().into(),
+                                            font_size: crate::theme::font_size_rem(
+                                                theme.typography.font_size_small,
+                                            ),
+                                            weight: FontWeight::SEMIBOLD,
+                                            ..default()
+                                        },
+                                        TextColor(theme.colors.text_primary_semantic),
+                                        Node {
+                                            flex_grow: 1.0,
+                                            justify_content: JustifyContent::Center,
+                                            ..default()
+                                        },
+                                    ))
+                                    .id(),
+                                );
+
+                                let (increase_button, _) = spawn_supply_route_button(
+                                    row,
+                                    game_assets,
+                                    theme,
+                                    Val::Px(30.0),
+                                    Text::new("+"),
+                                );
+                                info_panel_nodes.stockpile_route_target_increase_button =
+                                    Some(increase_button);
+                            });
+
+                        controls
+                            .spawn(Node {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(5.0),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                let (filter_button, _) = spawn_supply_route_button(
+                                    row,
+                                    game_assets,
+                                    theme,
+                                    Val::Percent(50.0),
+                                    LocalizedText("ui.info_panel.match_acceptance"),
+                                );
+                                info_panel_nodes.stockpile_route_filter_button =
+                                    Some(filter_button);
+
+                                let (remove_button, _) = spawn_supply_route_button(
+                                    row,
+                                    game_assets,
+                                    theme,
+                                    Val::Percent(50.0),
+                                    LocalizedText("ui.info_panel.remove_route"),
+                                );
+                                info_panel_nodes.stockpile_route_remove_button =
+                                    Some(remove_button);
+                            });
+                    })
+                    .id();
+                info_panel_nodes.stockpile_route_controls = Some(route_controls);
+
+                spawn_info_section_divider(column, game_assets, theme, "ui.info_panel.batch_edit");
                 let (area_button, _) = spawn_stockpile_editor_button(
                     column,
                     game_assets,
                     theme,
                     Val::Percent(100.0),
-                    "Apply Policy to Area",
+                    LocalizedText("ui.info_panel.apply_policy_to_area"),
                 );
                 info_panel_nodes.stockpile_area_button = Some(area_button);
             })
             .id();
         info_panel_nodes.stockpile_group = Some(stockpile_group);
 
+        let group_section = parent
+            .spawn(Node {
+                display: Display::None,
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(5.0),
+                ..default()
+            })
+            .with_children(|column| {
+                spawn_info_section_divider(
+                    column,
+                    game_assets,
+                    theme,
+                    "ui.info_panel.group_actions",
+                );
+
+                info_panel_nodes.group_familiar_row = Some(spawn_group_action_row(
+                    column,
+                    game_assets,
+                    theme,
+                    &[
+                        (
+                            "ui.info_panel.group.souls_down",
+                            MenuAction::AdjustSelectedMaxControlledSoul(-1),
+                        ),
+                        (
+                            "ui.info_panel.group.souls_up",
+                            MenuAction::AdjustSelectedMaxControlledSoul(1),
+                        ),
+                        (
+                            "ui.info_panel.group.fatigue_down",
+                            MenuAction::AdjustSelectedFatigueThreshold(-0.1),
+                        ),
+                        (
+                            "ui.info_panel.group.fatigue_up",
+                            MenuAction::AdjustSelectedFatigueThreshold(0.1),
+                        ),
+                    ],
+                ));
+                info_panel_nodes.group_door_row = Some(spawn_group_action_row(
+                    column,
+                    game_assets,
+                    theme,
+                    &[
+                        (
+                            "ui.info_panel.group.lock_all",
+                            MenuAction::SetSelectedDoorsLocked(true),
+                        ),
+                        (
+                            "ui.info_panel.group.unlock_all",
+                            MenuAction::SetSelectedDoorsLocked(false),
+                        ),
+                    ],
+                ));
+                info_panel_nodes.group_designation_row = Some(spawn_group_action_row(
+                    column,
+                    game_assets,
+                    theme,
+                    &[
+                        (
+                            "ui.info_panel.group.priority_up",
+                            MenuAction::AdjustSelectedTaskPriority(
+                                TaskPriorityAdjustment::Increase,
+                            ),
+                        ),
+                        (
+                            "ui.info_panel.group.priority_down",
+                            MenuAction::AdjustSelectedTaskPriority(
+                                TaskPriorityAdjustment::Decrease,
+                            ),
+                        ),
+                        (
+                            "ui.info_panel.group.cancel_tasks",
+                            MenuAction::CancelSelectedTasks,
+                        ),
+                    ],
+                ));
+                let selection_policy = |patch| MenuAction::ApplyStockpilePolicy {
+                    target: StockpilePolicyEditTarget::Selection,
+                    patch,
+                };
+                info_panel_nodes.group_stockpile_row = Some(spawn_group_action_row(
+                    column,
+                    game_assets,
+                    theme,
+                    &[
+                        (
+                            "ui.info_panel.allow_all",
+                            selection_policy(StockpilePolicyPatch {
+                                acceptance: Some(StockpileAcceptance::Any),
+                                ..default()
+                            }),
+                        ),
+                        (
+                            "ui.info_panel.clear_all",
+                            selection_policy(StockpilePolicyPatch {
+                                acceptance: Some(StockpileAcceptance::none()),
+                                ..default()
+                            }),
+                        ),
+                        (
+                            "ui.info_panel.group.export_on",
+                            selection_policy(StockpilePolicyPatch {
+                                allow_export: Some(true),
+                                ..default()
+                            }),
+                        ),
+                        (
+                            "ui.info_panel.group.export_off",
+                            selection_policy(StockpilePolicyPatch {
+                                allow_export: Some(false),
+                                ..default()
+                            }),
+                        ),
+                    ],
+                ));
+            })
+            .id();
+        info_panel_nodes.group_section = Some(group_section);
+
         let common = parent
             .spawn((
                 Text::new(""),
@@ -715,12 +1231,16 @@ mod tests {
     use super::*;
     use crate::components::SoulRenameState;
     use crate::models::inspection::{
-        EntityInspectionModel, EntityInspectionViewModel, StockpileInspectionFields,
+        EntityInspectionModel, EntityInspectionViewModel, SelectionGroupSummary,
+        StockpileInspectionFields,
     };
     use crate::panels::info_panel::{InfoPanelPinState, InfoPanelState, info_panel_system};
     use crate::selection::SelectedEntity;
+    use hw_core::locale::{Locale, Localization};
     use hw_logistics::transport_request::TransportPriority;
-    use hw_logistics::{ResourceType, StockpileAcceptance, StockpilePolicyState};
+    use hw_logistics::{
+        ResourceType, StockpileAcceptance, StockpilePolicyState, SupplyRouteChangeRequest,
+    };
 
     #[derive(Resource, Default)]
     struct TestAssets {
@@ -859,6 +1379,7 @@ mod tests {
             .init_resource::<InfoPanelPinState>()
             .init_resource::<InfoPanelState>()
             .init_resource::<SoulRenameState>()
+            .insert_resource(Localization::shipped(Locale::En))
             .insert_resource(EntityInspectionViewModel {
                 model: Some(EntityInspectionModel {
                     entity: stockpile,
@@ -876,8 +1397,12 @@ mod tests {
                         inbound_priority: TransportPriority::Normal,
                         target_amount: 5,
                         allow_export: true,
+                        inbound_route: None,
+                        outbound_routes: 0,
+                        supply_route_source: None,
                     }),
                 }),
+                group: None,
             })
             .add_systems(Startup, spawn_panel)
             .add_systems(Update, info_panel_system::<TestAssets>);
@@ -909,4 +1434,119 @@ mod tests {
         assert_eq!(patch.target_amount, None);
         assert_eq!(patch.allow_export, None);
     }
+
+    #[test]
+    fn supply_route_section_binds_create_from_marked_source() {
+        let mut app = App::new();
+        let stockpile = app.world_mut().spawn_empty().id();
+        let source = app.world_mut().spawn_empty().id();
+
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TestAssets::default())
+            .init_resource::<UiTheme>()
+            .init_resource::<UiNodeRegistry>()
+            .init_resource::<InfoPanelNodes>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<InfoPanelPinState>()
+            .init_resource::<InfoPanelState>()
+            .init_resource::<SoulRenameState>()
+            .insert_resource(Localization::shipped(Locale::En))
+            .insert_resource(EntityInspectionViewModel {
+                model: Some(EntityInspectionModel {
+                    entity: stockpile,
+                    header: "Stockpile".to_string(),
+                    common_text: String::new(),
+                    tooltip_lines: Vec::new(),
+                    soul: None,
+                    stockpile: Some(StockpileInspectionFields {
+                        state: StockpilePolicyState::Accepting,
+                        current_amount: 0,
+                        incoming_amount: 0,
+                        capacity: 5,
+                        current_resource: None,
+                        acceptance: StockpileAcceptance::Any,
+                        inbound_priority: TransportPriority::Normal,
+                        target_amount: 5,
+                        allow_export: true,
+                        inbound_route: None,
+                        outbound_routes: 0,
+                        supply_route_source: Some(source),
+                    }),
+                }),
+                group: None,
+            })
+            .add_systems(Startup, spawn_panel)
+            .add_systems(Update, info_panel_system::<TestAssets>);
+
+        app.update();
+
+        let nodes = app.world().resource::<InfoPanelNodes>();
+        let display =
+            |entity: Option<Entity>| app.world().get::<Node>(entity.unwrap()).unwrap().display;
+        assert_eq!(display(nodes.stockpile_route_create_button), Display::Flex);
+        assert_eq!(display(nodes.stockpile_route_controls), Display::None);
+
+        let action = app
+            .world()
+            .get::<MenuButton>(nodes.stockpile_route_create_button.unwrap())
+            .unwrap()
+            .0;
+        let MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::Create {
+            source_cell,
+            destination_cell,
+        }) = action
+        else {
+            panic!("expected supply route create action");
+        };
+        assert_eq!(source_cell, source);
+        assert_eq!(destination_cell, stockpile);
+
+        let action = app
+            .world()
+            .get::<MenuButton>(nodes.stockpile_route_source_button.unwrap())
+            .unwrap()
+            .0;
+        assert!(
+            matches!(action, MenuAction::SetSupplyRouteSource(Some(cell)) if cell == stockpile)
+        );
+    }
+
+    #[test]
+    fn group_selection_shows_only_rows_for_selected_kinds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TestAssets::default())
+            .init_resource::<UiTheme>()
+            .init_resource::<UiNodeRegistry>()
+            .init_resource::<InfoPanelNodes>()
+            .init_resource::<SelectedEntity>()
+            .init_resource::<InfoPanelPinState>()
+            .init_resource::<InfoPanelState>()
+            .init_resource::<SoulRenameState>()
+            .insert_resource(Localization::shipped(Locale::En))
+            .insert_resource(EntityInspectionViewModel {
+                model: None,
+                group: Some(SelectionGroupSummary {
+                    total: 3,
+                    doors: 2,
+                    stockpiles: 1,
+                    ..default()
+                }),
+            })
+            .add_systems(Startup, spawn_panel)
+            .add_systems(Update, info_panel_system::<TestAssets>);
+
+        app.update();
+
+        let nodes = app.world().resource::<InfoPanelNodes>();
+        let display =
+            |entity: Option<Entity>| app.world().get::<Node>(entity.unwrap()).unwrap().display;
+        assert_eq!(display(nodes.root), Display::Flex);
+        assert_eq!(display(nodes.group_section), Display::Flex);
+        assert_eq!(display(nodes.group_door_row), Display::Flex);
+        assert_eq!(display(nodes.group_stockpile_row), Display::Flex);
+        assert_eq!(display(nodes.group_familiar_row), Display::None);
+        assert_eq!(display(nodes.group_designation_row), Display::None);
+        assert_eq!(display(nodes.stockpile_group), Display::None);
+    }
 }
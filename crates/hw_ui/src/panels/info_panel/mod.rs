@@ -1,8 +1,10 @@
 mod layout;
 mod model;
 mod state;
+mod tabs;
 mod update;
 
 pub use layout::spawn_info_panel_ui;
 pub use state::{InfoPanelPinState, InfoPanelState};
+pub use tabs::info_panel_tab_system;
 pub use update::info_panel_system;
@@ -1,10 +1,13 @@
 use crate::models::inspection::{
-    EntityInspectionModel, InspectionSoulGender, StockpileInspectionFields,
+    EntityInspectionModel, InboundSupplyRouteFields, InspectionSoulGender, SelectionGroupSummary,
+    StockpileInspectionFields,
 };
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 use hw_logistics::transport_request::TransportPriority;
 use hw_logistics::{
     ResourceType, STOCKPILE_ACCEPTANCE_RESOURCES, StockpileAcceptance, StockpilePolicyState,
+    StockpileResourceSet,
 };
 
 #[derive(Clone, PartialEq)]
@@ -12,6 +15,7 @@ pub(super) enum InfoPanelViewModel {
     Soul(SoulInfoViewModel),
     Stockpile(StockpileInfoViewModel),
     Simple(SimpleInfoViewModel),
+    Group(SelectionGroupSummary),
 }
 
 #[derive(Clone, PartialEq)]
@@ -23,8 +27,11 @@ pub(super) struct SoulInfoViewModel {
     pub(super) stress: String,
     pub(super) fatigue: String,
     pub(super) dream: String,
+    pub(super) penance: String,
     pub(super) task: String,
     pub(super) inventory: String,
+    pub(super) relationships: String,
+    pub(super) life_log: String,
     pub(super) common: String,
 }
 
@@ -47,43 +54,43 @@ pub(super) struct StockpileInfoViewModel {
     pub(super) inbound_priority: TransportPriority,
     pub(super) target_amount: usize,
     pub(super) allow_export: bool,
+    pub(super) inbound_route: Option<InboundSupplyRouteFields>,
+    pub(super) outbound_routes: usize,
+    pub(super) supply_route_source: Option<Entity>,
     pub(super) common: String,
 }
 
-pub(super) const fn stockpile_resource_label(resource_type: ResourceType) -> &'static str {
-    resource_type.display_name()
-}
-
-pub(super) fn stockpile_acceptance_summary(acceptance: StockpileAcceptance) -> String {
-    let allowed = acceptance.allowed_count();
-    if acceptance.is_all() {
-        format!(
-            "Allowed: All ({allowed}/{})",
-            STOCKPILE_ACCEPTANCE_RESOURCES.len()
-        )
+pub(super) fn stockpile_acceptance_summary(
+    acceptance: StockpileAcceptance,
+    text: &Localization,
+) -> String {
+    let id = if acceptance.is_all() {
+        "ui.info_panel.allowed_all"
     } else if acceptance.is_none() {
-        format!(
-            "Allowed: None ({allowed}/{})",
-            STOCKPILE_ACCEPTANCE_RESOURCES.len()
-        )
+        "ui.info_panel.allowed_none"
     } else {
-        format!(
-            "Allowed: {allowed}/{}",
-            STOCKPILE_ACCEPTANCE_RESOURCES.len()
-        )
-    }
+        "ui.info_panel.allowed"
+    };
+    text.format(
+        id,
+        &[
+            ("allowed", &acceptance.allowed_count()),
+            ("total", &STOCKPILE_ACCEPTANCE_RESOURCES.len()),
+        ],
+    )
 }
 
 pub(super) fn stockpile_acceptance_row_label(
     acceptance: StockpileAcceptance,
     resource_type: ResourceType,
+    text: &Localization,
 ) -> String {
     let checked = if acceptance.accepts(resource_type) {
         "[x]"
     } else {
         "[ ]"
     };
-    format!("{checked} {}", stockpile_resource_label(resource_type))
+    format!("{checked} {}", text.text(resource_type.text_id()))
 }
 
 pub(super) const fn next_stockpile_priority(current: TransportPriority) -> TransportPriority {
@@ -95,7 +102,131 @@ pub(super) const fn next_stockpile_priority(current: TransportPriority) -> Trans
     }
 }
 
-pub(super) fn to_view_model(model: EntityInspectionModel) -> InfoPanelViewModel {
+fn supply_route_resources_label(resources: StockpileResourceSet, text: &Localization) -> String {
+    if resources == StockpileResourceSet::all() {
+        text.text("ui.info_panel.all").to_string()
+    } else if resources.is_empty() {
+        text.text("ui.info_panel.none").to_string()
+    } else {
+        STOCKPILE_ACCEPTANCE_RESOURCES
+            .into_iter()
+            .filter(|resource_type| resources.contains(*resource_type))
+            .map(|resource_type| text.text(resource_type.text_id()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// 補給ルート欄の説明。搬入側の状態、このセルから出ているルート数、仮指定中の搬出元をまとめる。
+pub(super) fn supply_route_summary(
+    stockpile: &StockpileInfoViewModel,
+    text: &Localization,
+) -> String {
+    let mut lines = Vec::new();
+    match stockpile.inbound_route {
+        Some(route) => {
+            lines.push(text.format(
+                "ui.info_panel.route.supplied_from",
+                &[
+                    ("cells", &route.source_cells),
+                    (
+                        "resources",
+                        &supply_route_resources_label(route.resources, text),
+                    ),
+                ],
+            ));
+            lines.push(text.format(
+                "ui.info_panel.route.stocked",
+                &[
+                    ("level", &route.level),
+                    ("target", &route.target_amount),
+                    ("cells", &route.destination_cells),
+                ],
+            ));
+            lines.push(text.format(
+                "ui.info_panel.route.throughput",
+                &[
+                    ("rate", &route.deliveries_per_minute),
+                    ("delivered", &route.delivered_total),
+                ],
+            ));
+        }
+        None => lines.push(text.text("ui.info_panel.route.not_supplied").to_string()),
+    }
+    if stockpile.outbound_routes > 0 {
+        lines.push(text.format(
+            "ui.info_panel.route.feeds",
+            &[("count", &stockpile.outbound_routes)],
+        ));
+    }
+    match stockpile.supply_route_source {
+        Some(source) if source == stockpile.entity => {
+            lines.push(text.text("ui.info_panel.route.marked_source").to_string());
+        }
+        Some(_) => lines.push(
+            text.text("ui.info_panel.route.source_elsewhere")
+                .to_string(),
+        ),
+        None => {}
+    }
+    lines.join("\n")
+}
+
+/// 仮指定済みの搬出元から、このセルへのルートを作れるときの搬出元。
+///
+/// 同一 Yard・別クラスタ等の判定は domain 側が行い、ここでは明らかに無効な操作だけを隠す。
+pub(super) fn supply_route_create_source(stockpile: &StockpileInfoViewModel) -> Option<Entity> {
+    stockpile
+        .supply_route_source
+        .filter(|source| *source != stockpile.entity && stockpile.inbound_route.is_none())
+}
+
+pub(super) fn life_log_text(lines: &[String], text: &Localization) -> String {
+    if lines.is_empty() {
+        text.text("ui.info_panel.life_log_empty").to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+/// 複数選択の見出し
+pub(super) fn group_header(summary: &SelectionGroupSummary, text: &Localization) -> String {
+    text.format("ui.info_panel.group.selected", &[("count", &summary.total)])
+}
+
+/// 複数選択の内訳（0 件の種類は省く）
+pub(super) fn group_breakdown(summary: &SelectionGroupSummary, text: &Localization) -> String {
+    let mut lines = Vec::new();
+    for (id, count) in [
+        ("ui.info_panel.group.familiars", summary.familiars),
+        ("ui.info_panel.group.souls", summary.souls),
+    ] {
+        if count > 0 {
+            lines.push(text.format(id, &[("count", &count)]));
+        }
+    }
+    if summary.doors > 0 {
+        lines.push(text.format(
+            "ui.info_panel.group.doors",
+            &[("count", &summary.doors), ("locked", &summary.locked_doors)],
+        ));
+    }
+    for (id, count) in [
+        ("ui.info_panel.group.designations", summary.designations),
+        ("ui.info_panel.group.stockpiles", summary.stockpiles),
+        ("ui.info_panel.group.others", summary.others()),
+    ] {
+        if count > 0 {
+            lines.push(text.format(id, &[("count", &count)]));
+        }
+    }
+    lines.join("\n")
+}
+
+pub(super) fn to_view_model(
+    model: EntityInspectionModel,
+    text: &Localization,
+) -> InfoPanelViewModel {
     if let Some(stockpile) = model.stockpile {
         InfoPanelViewModel::Stockpile(stockpile_view_model(
             model.entity,
@@ -112,8 +243,11 @@ pub(super) fn to_view_model(model: EntityInspectionModel) -> InfoPanelViewModel
             stress: soul.stress,
             fatigue: soul.fatigue,
             dream: soul.dream,
+            penance: soul.penance,
             task: soul.task,
             inventory: soul.inventory,
+            relationships: soul.relationships,
+            life_log: life_log_text(&soul.life_log, text),
             common: soul.common,
         })
     } else {
@@ -142,6 +276,9 @@ fn stockpile_view_model(
         inbound_priority: fields.inbound_priority,
         target_amount: fields.target_amount,
         allow_export: fields.allow_export,
+        inbound_route: fields.inbound_route,
+        outbound_routes: fields.outbound_routes,
+        supply_route_source: fields.supply_route_source,
         common,
     }
 }
@@ -149,6 +286,7 @@ fn stockpile_view_model(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hw_core::locale::Locale;
 
     #[test]
     fn stockpile_acceptance_checklist_labels_every_resource_without_cycling() {
@@ -156,44 +294,71 @@ mod tests {
             .with_resource(ResourceType::Wood, true)
             .with_resource(ResourceType::Bone, true);
 
+        let text = Localization::shipped(Locale::En);
         let labels = STOCKPILE_ACCEPTANCE_RESOURCES
-            .map(|resource| stockpile_acceptance_row_label(acceptance, resource));
+            .map(|resource| stockpile_acceptance_row_label(acceptance, resource, &text));
         assert_eq!(labels[0], "[x] Wood");
         assert_eq!(labels[1], "[ ] Rock");
         assert_eq!(labels[6], "[x] Bone");
         assert_eq!(labels[8], "[ ] Wheelbarrow");
-        assert_eq!(stockpile_acceptance_summary(acceptance), "Allowed: 2/9");
         assert_eq!(
-            stockpile_acceptance_summary(StockpileAcceptance::Any),
+            stockpile_acceptance_summary(acceptance, &text),
+            "Allowed: 2/9"
+        );
+        assert_eq!(
+            stockpile_acceptance_summary(StockpileAcceptance::Any, &text),
             "Allowed: All (9/9)"
         );
         assert_eq!(
-            stockpile_acceptance_summary(StockpileAcceptance::none()),
+            stockpile_acceptance_summary(StockpileAcceptance::none(), &text),
             "Allowed: None (0/9)"
         );
     }
 
+    #[test]
+    fn group_breakdown_lists_only_present_kinds() {
+        let summary = SelectionGroupSummary {
+            total: 6,
+            familiars: 2,
+            doors: 3,
+            locked_doors: 1,
+            ..default()
+        };
+        let text = Localization::shipped(Locale::En);
+        assert_eq!(group_header(&summary, &text), "6 Selected");
+        assert_eq!(
+            group_breakdown(&summary, &text),
+            "Familiars: 2\nDoors: 3 (1 locked)\nOther: 1"
+        );
+    }
+
     #[test]
     fn stockpile_inspection_maps_to_an_entity_bound_editor_model() {
         let entity = Entity::from_raw_u32(7).expect("valid entity");
-        let view = to_view_model(EntityInspectionModel {
-            entity,
-            header: "Stockpile".to_string(),
-            common_text: "Managed".to_string(),
-            tooltip_lines: Vec::new(),
-            soul: None,
-            stockpile: Some(StockpileInspectionFields {
-                state: StockpilePolicyState::Draining,
-                current_amount: 4,
-                incoming_amount: 1,
-                capacity: 10,
-                current_resource: Some(ResourceType::Bone),
-                acceptance: StockpileAcceptance::Only(ResourceType::Wood),
-                inbound_priority: TransportPriority::Critical,
-                target_amount: 6,
-                allow_export: false,
-            }),
-        });
+        let view = to_view_model(
+            EntityInspectionModel {
+                entity,
+                header: "Stockpile".to_string(),
+                common_text: "Managed".to_string(),
+                tooltip_lines: Vec::new(),
+                soul: None,
+                stockpile: Some(StockpileInspectionFields {
+                    state: StockpilePolicyState::Draining,
+                    current_amount: 4,
+                    incoming_amount: 1,
+                    capacity: 10,
+                    current_resource: Some(ResourceType::Bone),
+                    acceptance: StockpileAcceptance::Only(ResourceType::Wood),
+                    inbound_priority: TransportPriority::Critical,
+                    target_amount: 6,
+                    allow_export: false,
+                    inbound_route: None,
+                    outbound_routes: 0,
+                    supply_route_source: None,
+                }),
+            },
+            &Localization::default(),
+        );
 
         let InfoPanelViewModel::Stockpile(stockpile) = view else {
             panic!("expected stockpile view model");
@@ -210,6 +375,66 @@ mod tests {
         assert!(!stockpile.allow_export);
     }
 
+    #[test]
+    fn supply_route_summary_reports_level_throughput_and_create_gating() {
+        let entity = Entity::from_raw_u32(7).expect("valid entity");
+        let source = Entity::from_raw_u32(8).expect("valid entity");
+        let text = Localization::shipped(Locale::En);
+        let InfoPanelViewModel::Stockpile(mut stockpile) = to_view_model(
+            EntityInspectionModel {
+                entity,
+                header: "Stockpile".to_string(),
+                common_text: String::new(),
+                tooltip_lines: Vec::new(),
+                soul: None,
+                stockpile: Some(StockpileInspectionFields {
+                    state: StockpilePolicyState::Accepting,
+                    current_amount: 2,
+                    incoming_amount: 0,
+                    capacity: 10,
+                    current_resource: Some(ResourceType::Sand),
+                    acceptance: StockpileAcceptance::Any,
+                    inbound_priority: TransportPriority::Normal,
+                    target_amount: 10,
+                    allow_export: true,
+                    inbound_route: None,
+                    outbound_routes: 1,
+                    supply_route_source: Some(source),
+                }),
+            },
+            &text,
+        ) else {
+            panic!("expected stockpile view model");
+        };
+
+        assert_eq!(supply_route_create_source(&stockpile), Some(source));
+        assert_eq!(
+            supply_route_summary(&stockpile, &text),
+            "Not supplied by a route\nFeeds 1 route(s)\nRoute source marked elsewhere"
+        );
+
+        stockpile.inbound_route = Some(InboundSupplyRouteFields {
+            route: source,
+            resources: StockpileResourceSet::empty()
+                .with(ResourceType::Sand, true)
+                .with(ResourceType::Rock, true),
+            destination_acceptance: StockpileResourceSet::all(),
+            level: 3,
+            target_amount: 8,
+            capacity: 20,
+            source_cells: 4,
+            destination_cells: 2,
+            deliveries_per_minute: 5,
+            delivered_total: 12,
+        });
+        assert_eq!(supply_route_create_source(&stockpile), None);
+        assert!(
+            supply_route_summary(&stockpile, &text).starts_with(
+                "Supplied from 4 cell(s): Rock, Sand\nStocked: 3/8 across 2 cell(s)\nThroughput: 5/min (12 delivered)"
+            )
+        );
+    }
+
     #[test]
     fn stockpile_priority_cycle_is_exhaustive() {
         assert_eq!(
@@ -0,0 +1,71 @@
+use crate::components::{InfoPanelNodes, InfoPanelTab, InfoPanelTabButton, UiInputState};
+use crate::theme::UiTheme;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+#[derive(SystemParam)]
+pub struct InfoPanelTabNodeQueries<'w, 's> {
+    tab_buttons: Query<'w, 's, (Entity, &'static InfoPanelTabButton, &'static Children)>,
+    text_colors: Query<'w, 's, &'static mut TextColor>,
+    border_colors: Query<'w, 's, &'static mut BorderColor>,
+    q_node: Query<'w, 's, &'static mut Node>,
+}
+
+/// Soul 情報パネルの Status / Life Log タブ切替
+pub fn info_panel_tab_system(
+    mut tab: ResMut<InfoPanelTab>,
+    theme: Res<UiTheme>,
+    info_nodes: Res<InfoPanelNodes>,
+    interactions: Query<(&Interaction, &InfoPanelTabButton), Changed<Interaction>>,
+    mut nodes: InfoPanelTabNodeQueries,
+    ui_input_state: Res<UiInputState>,
+) {
+    if ui_input_state.world_input_captured {
+        return;
+    }
+    for (interaction, button) in &interactions {
+        if *interaction == Interaction::Pressed && *tab != button.0 {
+            *tab = button.0;
+        }
+    }
+
+    if !tab.is_changed() {
+        return;
+    }
+
+    for (button_entity, button, children) in &nodes.tab_buttons {
+        let is_active = button.0 == *tab;
+        if let Some(child) = children.iter().next()
+            && let Ok(mut color) = nodes.text_colors.get_mut(child)
+        {
+            color.0 = if is_active {
+                theme.colors.text_accent_semantic
+            } else {
+                theme.colors.text_secondary_semantic
+            };
+        }
+        if let Ok(mut border) = nodes.border_colors.get_mut(button_entity) {
+            *border = BorderColor::all(if is_active {
+                theme.colors.text_accent_semantic
+            } else {
+                Color::NONE
+            });
+        }
+    }
+
+    let (status, life_log) = match *tab {
+        InfoPanelTab::Status => (Display::Flex, Display::None),
+        InfoPanelTab::LifeLog => (Display::None, Display::Flex),
+    };
+    for (entity, display) in [
+        (info_nodes.status_body, status),
+        (info_nodes.life_log_body, life_log),
+    ] {
+        if let Some(entity) = entity
+            && let Ok(mut node) = nodes.q_node.get_mut(entity)
+            && node.display != display
+        {
+            node.display = display;
+        }
+    }
+}
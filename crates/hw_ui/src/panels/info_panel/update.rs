@@ -1,6 +1,7 @@
 use super::model::{
-    InfoPanelViewModel, next_stockpile_priority, stockpile_acceptance_row_label,
-    stockpile_acceptance_summary, to_view_model,
+    InfoPanelViewModel, StockpileInfoViewModel, group_breakdown, group_header,
+    next_stockpile_priority, stockpile_acceptance_row_label, stockpile_acceptance_summary,
+    supply_route_create_source, supply_route_summary, to_view_model,
 };
 use super::state::{InfoPanelPinState, InfoPanelState};
 use crate::components::{
@@ -12,7 +13,10 @@ use crate::selection::SelectedEntity;
 use crate::setup::UiAssets;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use hw_logistics::{StockpileAcceptance, StockpilePolicyPatch, StockpilePolicyState};
+use hw_core::locale::Localization;
+use hw_logistics::{
+    StockpileAcceptance, StockpilePolicyPatch, StockpilePolicyState, SupplyRouteChangeRequest,
+};
 
 #[derive(SystemParam)]
 pub struct InfoPanelRes<'w, A: UiAssets + Resource + 'static> {
@@ -20,6 +24,7 @@ pub struct InfoPanelRes<'w, A: UiAssets + Resource + 'static> {
     pub info_nodes: Res<'w, InfoPanelNodes>,
     pub ui_nodes: Res<'w, UiNodeRegistry>,
     pub inspection_view_model: Res<'w, EntityInspectionViewModel>,
+    pub localization: Res<'w, Localization>,
 }
 
 #[derive(SystemParam)]
@@ -156,6 +161,110 @@ fn update_gender_icon<A: UiAssets>(
     }
 }
 
+fn update_supply_route_section(
+    info_nodes: &InfoPanelNodes,
+    queries: &mut InfoPanelNodeQueries,
+    localization: &Localization,
+    stockpile: &StockpileInfoViewModel,
+) {
+    set_text_entity(
+        info_nodes.stockpile_route_text,
+        &mut queries.q_text,
+        &supply_route_summary(stockpile, localization),
+    );
+
+    let is_marked = stockpile.supply_route_source == Some(stockpile.entity);
+    set_text_entity(
+        info_nodes.stockpile_route_source_text,
+        &mut queries.q_text,
+        localization.text(if is_marked {
+            "ui.info_panel.unmark_source"
+        } else {
+            "ui.info_panel.mark_source"
+        }),
+    );
+    set_menu_action(
+        info_nodes.stockpile_route_source_button,
+        &mut queries.q_menu_button,
+        MenuAction::SetSupplyRouteSource((!is_marked).then_some(stockpile.entity)),
+    );
+
+    let create_source = supply_route_create_source(stockpile);
+    set_node_display(
+        info_nodes.stockpile_route_create_button,
+        &mut queries.q_node,
+        if create_source.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        },
+    );
+    if let Some(source_cell) = create_source {
+        set_menu_action(
+            info_nodes.stockpile_route_create_button,
+            &mut queries.q_menu_button,
+            MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::Create {
+                source_cell,
+                destination_cell: stockpile.entity,
+            }),
+        );
+    }
+
+    let Some(route) = stockpile.inbound_route else {
+        set_node_display(
+            info_nodes.stockpile_route_controls,
+            &mut queries.q_node,
+            Display::None,
+        );
+        return;
+    };
+    set_node_display(
+        info_nodes.stockpile_route_controls,
+        &mut queries.q_node,
+        Display::Flex,
+    );
+    set_text_entity(
+        info_nodes.stockpile_route_target_text,
+        &mut queries.q_text,
+        &localization.format(
+            "ui.info_panel.route_target",
+            &[
+                ("amount", &route.target_amount),
+                ("capacity", &route.capacity),
+            ],
+        ),
+    );
+    set_menu_action(
+        info_nodes.stockpile_route_target_decrease_button,
+        &mut queries.q_menu_button,
+        MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::SetTargetAmount {
+            route: route.route,
+            target_amount: route.target_amount.saturating_sub(1),
+        }),
+    );
+    set_menu_action(
+        info_nodes.stockpile_route_target_increase_button,
+        &mut queries.q_menu_button,
+        MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::SetTargetAmount {
+            route: route.route,
+            target_amount: route.target_amount.saturating_add(1).min(route.capacity),
+        }),
+    );
+    set_menu_action(
+        info_nodes.stockpile_route_filter_button,
+        &mut queries.q_menu_button,
+        MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::SetResources {
+            route: route.route,
+            resources: route.destination_acceptance,
+        }),
+    );
+    set_menu_action(
+        info_nodes.stockpile_route_remove_button,
+        &mut queries.q_menu_button,
+        MenuAction::EditSupplyRoute(SupplyRouteChangeRequest::Remove { route: route.route }),
+    );
+}
+
 pub fn info_panel_system<A: UiAssets + Resource>(
     res: InfoPanelRes<A>,
     _selected: Res<SelectedEntity>,
@@ -164,7 +273,15 @@ pub fn info_panel_system<A: UiAssets + Resource>(
     rename_state: Res<SoulRenameState>,
     mut queries: InfoPanelNodeQueries,
 ) {
-    let next_model = res.inspection_view_model.model.clone().map(to_view_model);
+    let localization = &*res.localization;
+    let next_model = match res.inspection_view_model.group {
+        Some(summary) => Some(InfoPanelViewModel::Group(summary)),
+        None => res
+            .inspection_view_model
+            .model
+            .clone()
+            .map(|model| to_view_model(model, localization)),
+    };
 
     let pinned = pin_state.entity.is_some();
     let rename_target = match &next_model {
@@ -181,6 +298,7 @@ pub fn info_panel_system<A: UiAssets + Resource>(
     if panel_state.last == next_model
         && panel_state.last_pinned == pinned
         && panel_state.last_rename_target == rename_target
+        && !res.localization.is_changed()
     {
         return;
     }
@@ -203,6 +321,15 @@ pub fn info_panel_system<A: UiAssets + Resource>(
         UiSlot::InfoPanelUnpinButton,
         if pinned { Display::Flex } else { Display::None },
     );
+    set_node_display(
+        res.info_nodes.group_section,
+        &mut queries.q_node,
+        if matches!(next_model, Some(InfoPanelViewModel::Group(_))) {
+            Display::Flex
+        } else {
+            Display::None
+        },
+    );
 
     match &next_model {
         Some(InfoPanelViewModel::Soul(soul)) => {
@@ -281,6 +408,7 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 UiSlot::StatDream,
                 &soul.dream,
             );
+            set_text_entity(res.info_nodes.penance, &mut queries.q_text, &soul.penance);
             set_text_slot(
                 &res.info_nodes,
                 &res.ui_nodes,
@@ -295,6 +423,16 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 UiSlot::InventoryText,
                 &soul.inventory,
             );
+            set_text_entity(
+                res.info_nodes.relationships,
+                &mut queries.q_text,
+                &soul.relationships,
+            );
+            set_text_entity(
+                res.info_nodes.life_log_text,
+                &mut queries.q_text,
+                &soul.life_log,
+            );
             set_text_slot(
                 &res.info_nodes,
                 &res.ui_nodes,
@@ -364,56 +502,70 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 None,
             );
 
-            let state_label = match stockpile.state {
-                StockpilePolicyState::Accepting => "Accepting",
-                StockpilePolicyState::TargetReached => "Target Reached",
-                StockpilePolicyState::Draining => "Draining",
-                StockpilePolicyState::Disabled => "Disabled",
-            };
             let resource_label = stockpile
                 .current_resource
-                .map(|resource| resource.display_name().to_string())
-                .unwrap_or_else(|| "Empty".to_string());
+                .map(|resource| localization.text(resource.text_id()))
+                .unwrap_or_else(|| localization.text("ui.info_panel.empty"));
             set_text_entity(
                 res.info_nodes.stockpile_state,
                 &mut queries.q_text,
-                &format!("State: {state_label}"),
+                &localization.format(
+                    "ui.info_panel.stockpile_state",
+                    &[("state", &localization.text(stockpile.state.text_id()))],
+                ),
             );
             set_text_entity(
                 res.info_nodes.stockpile_current,
                 &mut queries.q_text,
-                &format!(
-                    "Stored: {}/{} ({resource_label}) | Incoming: {}",
-                    stockpile.current_amount, stockpile.capacity, stockpile.incoming_amount
+                &localization.format(
+                    "ui.info_panel.stockpile_stored",
+                    &[
+                        ("current", &stockpile.current_amount),
+                        ("capacity", &stockpile.capacity),
+                        ("resource", &resource_label),
+                        ("incoming", &stockpile.incoming_amount),
+                    ],
                 ),
             );
             set_text_entity(
                 res.info_nodes.stockpile_acceptance_summary,
                 &mut queries.q_text,
-                &stockpile_acceptance_summary(stockpile.acceptance),
+                &stockpile_acceptance_summary(stockpile.acceptance, localization),
             );
             set_text_entity(
                 res.info_nodes.stockpile_target_text,
                 &mut queries.q_text,
-                &format!("Target: {}/{}", stockpile.target_amount, stockpile.capacity),
+                &localization.format(
+                    "ui.info_panel.target",
+                    &[
+                        ("amount", &stockpile.target_amount),
+                        ("capacity", &stockpile.capacity),
+                    ],
+                ),
             );
             set_text_entity(
                 res.info_nodes.stockpile_priority_text,
                 &mut queries.q_text,
-                &format!("Inbound Priority: {:?} (cycle)", stockpile.inbound_priority),
+                &localization.format(
+                    "ui.info_panel.inbound_priority",
+                    &[(
+                        "priority",
+                        &localization.text(stockpile.inbound_priority.text_id()),
+                    )],
+                ),
             );
             let export_label =
                 if stockpile.state == StockpilePolicyState::Draining && !stockpile.allow_export {
-                    "Export: Off (Draining override)"
+                    "ui.info_panel.export_off_draining"
                 } else if stockpile.allow_export {
-                    "Export: On"
+                    "ui.info_panel.export_on"
                 } else {
-                    "Export: Off"
+                    "ui.info_panel.export_off"
                 };
             set_text_entity(
                 res.info_nodes.stockpile_export_text,
                 &mut queries.q_text,
-                export_label,
+                localization.text(export_label),
             );
 
             let single = StockpilePolicyEditTarget::Single(stockpile.entity);
@@ -443,7 +595,11 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 set_text_entity(
                     Some(row.text),
                     &mut queries.q_text,
-                    &stockpile_acceptance_row_label(stockpile.acceptance, row.resource_type),
+                    &stockpile_acceptance_row_label(
+                        stockpile.acceptance,
+                        row.resource_type,
+                        localization,
+                    ),
                 );
                 set_menu_action(
                     Some(row.button),
@@ -509,6 +665,7 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                     },
                 },
             );
+            update_supply_route_section(&res.info_nodes, &mut queries, localization, stockpile);
             set_menu_action(
                 res.info_nodes.stockpile_area_button,
                 &mut queries.q_menu_button,
@@ -595,6 +752,7 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 UiSlot::StatDream,
                 "",
             );
+            set_text_entity(res.info_nodes.penance, &mut queries.q_text, "");
             set_text_slot(
                 &res.info_nodes,
                 &res.ui_nodes,
@@ -609,6 +767,77 @@ pub fn info_panel_system<A: UiAssets + Resource>(
                 UiSlot::InventoryText,
                 "",
             );
+            set_text_entity(res.info_nodes.relationships, &mut queries.q_text, "");
+            set_text_entity(res.info_nodes.life_log_text, &mut queries.q_text, "");
+        }
+        Some(InfoPanelViewModel::Group(summary)) => {
+            set_node_display(
+                res.info_nodes.stockpile_group,
+                &mut queries.q_node,
+                Display::None,
+            );
+            set_node_display(
+                res.info_nodes.rename_button,
+                &mut queries.q_node,
+                Display::None,
+            );
+            set_node_display(
+                res.info_nodes.rename_field_container,
+                &mut queries.q_node,
+                Display::None,
+            );
+            set_display_slot(
+                &res.info_nodes,
+                &res.ui_nodes,
+                &mut queries.q_node,
+                UiSlot::InfoPanelStatsGroup,
+                Display::None,
+            );
+            set_display_slot(
+                &res.info_nodes,
+                &res.ui_nodes,
+                &mut queries.q_node,
+                UiSlot::Header,
+                Display::Flex,
+            );
+            set_text_slot(
+                &res.info_nodes,
+                &res.ui_nodes,
+                &mut queries.q_text,
+                UiSlot::Header,
+                &group_header(summary, localization),
+            );
+            set_text_slot(
+                &res.info_nodes,
+                &res.ui_nodes,
+                &mut queries.q_text,
+                UiSlot::CommonText,
+                &group_breakdown(summary, localization),
+            );
+            update_gender_icon(
+                &res.info_nodes,
+                &res.ui_nodes,
+                &mut queries.q_gender,
+                &mut queries.q_node,
+                &*res.game_assets,
+                None,
+            );
+            for (row, count) in [
+                (res.info_nodes.group_familiar_row, summary.familiars),
+                (res.info_nodes.group_door_row, summary.doors),
+                (res.info_nodes.group_designation_row, summary.designations),
+                (res.info_nodes.group_stockpile_row, summary.stockpiles),
+            ] {
+                set_node_display(
+                    row,
+                    &mut queries.q_node,
+                    if count > 0 {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    },
+                );
+            }
         }
         None => {
             set_node_display(
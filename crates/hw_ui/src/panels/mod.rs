@@ -3,7 +3,10 @@ pub mod menu;
 pub mod task_list;
 pub mod tooltip_builder;
 
-pub use info_panel::{InfoPanelPinState, InfoPanelState, info_panel_system, spawn_info_panel_ui};
+pub use info_panel::{
+    InfoPanelPinState, InfoPanelState, info_panel_system, info_panel_tab_system,
+    spawn_info_panel_ui,
+};
 pub use menu::menu_visibility_system;
 pub use task_list::{
     TaskEntry, TaskListDirty, left_panel_tab_system, left_panel_visibility_system,
@@ -7,7 +7,7 @@ pub use interaction::{
     left_panel_tab_system, left_panel_visibility_system, task_dashboard_action_state_sync_system,
     task_dashboard_control_system, task_list_click_system, task_list_visual_feedback_system,
 };
-pub use render::rebuild_task_list_ui;
+pub use render::{TaskListRenderContext, rebuild_task_list_ui};
 pub use types::{
     PendingTaskCancellation, TaskActionButton, TaskActionButtonKind, TaskActionCapabilities,
     TaskBlockerReason, TaskCancelKind, TaskDashboardActionState, TaskDashboardControl,
@@ -15,4 +15,4 @@ pub use types::{
     TaskPriorityFilter, TaskPriorityTier, TaskSortDirection, TaskSortKey, TaskStatusFilter,
     TaskStatusSummary, TaskWorkTypeFilter, TaskWorkerFilter,
 };
-pub use work_type_icon::{work_type_icon, work_type_label};
+pub use work_type_icon::work_type_icon;
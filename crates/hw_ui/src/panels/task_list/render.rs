@@ -4,12 +4,20 @@ use super::types::{
     TaskPriorityAdjustment, TaskPriorityFilter, TaskPriorityTier, TaskSortDirection, TaskSortKey,
     TaskStatusFilter, TaskStatusSummary, TaskWorkTypeFilter, TaskWorkerFilter,
 };
-use super::work_type_icon::{work_type_icon, work_type_label};
+use super::work_type_icon::work_type_icon;
 use crate::components::TaskListItem;
 use crate::setup::UiAssets;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 use hw_core::jobs::WorkType;
+use hw_core::locale::Localization;
+
+/// Shared rendering resources for the task dashboard rows.
+pub struct TaskListRenderContext<'a> {
+    pub game_assets: &'a dyn UiAssets,
+    pub theme: &'a UiTheme,
+    pub text: &'a Localization,
+}
 
 pub fn rebuild_task_list_ui(
     parent: &mut ChildSpawnerCommands,
@@ -17,20 +25,24 @@ pub fn rebuild_task_list_ui(
     view_state: &TaskDashboardViewState,
     pinned_entity: Option<Entity>,
     action_state: &TaskDashboardActionState,
-    game_assets: &dyn UiAssets,
-    theme: &UiTheme,
+    context: &TaskListRenderContext,
 ) {
-    spawn_toolbar(parent, view_state, game_assets, theme);
+    let &TaskListRenderContext {
+        game_assets,
+        theme,
+        text,
+    } = context;
+    spawn_toolbar(parent, view_state, game_assets, theme, text);
 
     let visible = view_state.visible_entries(snapshot);
     if visible.is_empty() {
         parent.spawn((
             TaskListDynamicNode,
-            Text::new(if snapshot.is_empty() {
-                "No designations"
+            Text::new(text.text(if snapshot.is_empty() {
+                "ui.task_list.empty"
             } else {
-                "No matching designations"
-            }),
+                "ui.task_list.no_matches"
+            })),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: crate::theme::font_size_rem(theme.typography.font_size_small),
@@ -49,7 +61,7 @@ pub fn rebuild_task_list_ui(
                 .iter()
                 .filter(|candidate| candidate.work_type == entry.work_type)
                 .count();
-            spawn_group_header(parent, entry.work_type, count, game_assets, theme);
+            spawn_group_header(parent, entry.work_type, count, game_assets, theme, text);
             previous_work_type = Some(entry.work_type);
         }
         spawn_task_row(
@@ -59,6 +71,7 @@ pub fn rebuild_task_list_ui(
             action_state,
             game_assets,
             theme,
+            text,
         );
     }
 }
@@ -68,7 +81,9 @@ fn spawn_toolbar(
     state: &TaskDashboardViewState,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
+    let labelled = |id: &str, value_id: &str| text.format(id, &[("value", &text.text(value_id))]);
     parent
         .spawn((
             TaskListDynamicNode,
@@ -86,45 +101,57 @@ fn spawn_toolbar(
             spawn_control(
                 toolbar,
                 TaskDashboardControl::WorkTypeFilter,
-                &format!("Type: {}", work_type_filter_label(state.work_type)),
+                &labelled(
+                    "ui.task_list.filter.type",
+                    work_type_filter_label(state.work_type),
+                ),
                 game_assets,
                 theme,
             );
             spawn_control(
                 toolbar,
                 TaskDashboardControl::StatusFilter,
-                &format!("State: {}", status_filter_label(state.status)),
+                &labelled(
+                    "ui.task_list.filter.state",
+                    status_filter_label(state.status),
+                ),
                 game_assets,
                 theme,
             );
             spawn_control(
                 toolbar,
                 TaskDashboardControl::PriorityFilter,
-                &format!("Priority: {}", priority_filter_label(state.priority)),
+                &labelled(
+                    "ui.task_list.filter.priority",
+                    priority_filter_label(state.priority),
+                ),
                 game_assets,
                 theme,
             );
             spawn_control(
                 toolbar,
                 TaskDashboardControl::WorkerFilter,
-                &format!("Workers: {}", worker_filter_label(state.workers)),
+                &labelled(
+                    "ui.task_list.filter.workers",
+                    worker_filter_label(state.workers),
+                ),
                 game_assets,
                 theme,
             );
             spawn_control(
                 toolbar,
                 TaskDashboardControl::SortKey,
-                &format!("Sort: {}", sort_key_label(state.sort_key)),
+                &labelled("ui.task_list.filter.sort", sort_key_label(state.sort_key)),
                 game_assets,
                 theme,
             );
             spawn_control(
                 toolbar,
                 TaskDashboardControl::SortDirection,
-                match state.direction {
-                    TaskSortDirection::Ascending => "Order: Asc",
-                    TaskSortDirection::Descending => "Order: Desc",
-                },
+                text.text(match state.direction {
+                    TaskSortDirection::Ascending => "ui.task_list.order.ascending",
+                    TaskSortDirection::Descending => "ui.task_list.order.descending",
+                }),
                 game_assets,
                 theme,
             );
@@ -165,6 +192,7 @@ fn spawn_group_header(
     count: usize,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let (header_icon, header_color) = work_type_icon(&work_type, game_assets, theme);
     parent
@@ -198,7 +226,7 @@ fn spawn_group_header(
                 },
             ));
             row.spawn((
-                Text::new(format!("{} ({count})", work_type_label(&work_type))),
+                Text::new(format!("{} ({count})", text.text(work_type.text_id()))),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
@@ -217,6 +245,7 @@ fn spawn_task_row(
     action_state: &TaskDashboardActionState,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let (item_icon, item_color) = work_type_icon(&entry.work_type, game_assets, theme);
     let desc_color = match entry.priority_tier() {
@@ -286,7 +315,7 @@ fn spawn_task_row(
                                 TextColor(desc_color),
                             ));
                             text_column.spawn((
-                                Text::new(entry.status.label()),
+                                Text::new(entry.status.label(text)),
                                 TextFont {
                                     font: game_assets.font_ui().clone().into(),
                                     font_size: crate::theme::font_size_rem(
@@ -313,7 +342,7 @@ fn spawn_task_row(
                 });
 
             if is_pinned && entry.actions.has_actions() {
-                spawn_action_bar(wrapper, entry, action_state, game_assets, theme);
+                spawn_action_bar(wrapper, entry, action_state, game_assets, theme, text);
             }
         });
 }
@@ -332,6 +361,7 @@ fn spawn_action_bar(
     action_state: &TaskDashboardActionState,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     parent
         .spawn(Node {
@@ -353,7 +383,7 @@ fn spawn_action_bar(
                             TaskPriorityAdjustment::Decrease,
                         ),
                     },
-                    "Priority -",
+                    text.text("ui.task_list.priority_down"),
                     theme.colors.button_default,
                     game_assets,
                     theme,
@@ -367,7 +397,7 @@ fn spawn_action_bar(
                             TaskPriorityAdjustment::Increase,
                         ),
                     },
-                    "Priority +",
+                    text.text("ui.task_list.priority_up"),
                     theme.colors.button_default,
                     game_assets,
                     theme,
@@ -379,15 +409,11 @@ fn spawn_action_bar(
                     expected_work_type: entry.work_type,
                     kind,
                 };
-                let label = if action_state.confirmation == Some(pending) {
-                    match kind {
-                        super::types::TaskCancelKind::FloorSite(_)
-                        | super::types::TaskCancelKind::WallSite(_) => "Confirm cancel site",
-                        _ => "Confirm cancel",
-                    }
+                let label = text.text(if action_state.confirmation == Some(pending) {
+                    kind.confirm_text_id()
                 } else {
-                    kind.label()
-                };
+                    kind.text_id()
+                });
                 spawn_action_button(
                     bar,
                     TaskActionButton {
@@ -433,45 +459,45 @@ fn spawn_action_button(
         ));
 }
 
-fn work_type_filter_label(filter: TaskWorkTypeFilter) -> &'static str {
+const fn work_type_filter_label(filter: TaskWorkTypeFilter) -> &'static str {
     match filter {
-        TaskWorkTypeFilter::All => "All",
-        TaskWorkTypeFilter::Only(work_type) => work_type_label(&work_type),
+        TaskWorkTypeFilter::All => "ui.task_list.filter.all",
+        TaskWorkTypeFilter::Only(work_type) => work_type.text_id(),
     }
 }
 
 const fn status_filter_label(filter: TaskStatusFilter) -> &'static str {
     match filter {
-        TaskStatusFilter::All => "All",
-        TaskStatusFilter::Working => "Working",
-        TaskStatusFilter::Blocked => "Blocked",
-        TaskStatusFilter::Pending => "Pending",
+        TaskStatusFilter::All => "ui.task_list.filter.all",
+        TaskStatusFilter::Working => "ui.task_list.filter.working",
+        TaskStatusFilter::Blocked => "ui.task_list.filter.blocked",
+        TaskStatusFilter::Pending => "ui.task_list.filter.pending",
     }
 }
 
 const fn priority_filter_label(filter: TaskPriorityFilter) -> &'static str {
     match filter {
-        TaskPriorityFilter::All => "All",
-        TaskPriorityFilter::Normal => "Normal",
-        TaskPriorityFilter::High => "High",
-        TaskPriorityFilter::Critical => "Critical",
+        TaskPriorityFilter::All => "ui.task_list.filter.all",
+        TaskPriorityFilter::Normal => "ui.task_list.priority.normal",
+        TaskPriorityFilter::High => "ui.task_list.priority.high",
+        TaskPriorityFilter::Critical => "ui.task_list.priority.critical",
     }
 }
 
 const fn worker_filter_label(filter: TaskWorkerFilter) -> &'static str {
     match filter {
-        TaskWorkerFilter::All => "All",
-        TaskWorkerFilter::Assigned => "Assigned",
-        TaskWorkerFilter::Unassigned => "Unassigned",
+        TaskWorkerFilter::All => "ui.task_list.filter.all",
+        TaskWorkerFilter::Assigned => "ui.task_list.filter.assigned",
+        TaskWorkerFilter::Unassigned => "ui.task_list.filter.unassigned",
     }
 }
 
 const fn sort_key_label(key: TaskSortKey) -> &'static str {
     match key {
-        TaskSortKey::WorkType => "Type",
-        TaskSortKey::Status => "State",
-        TaskSortKey::Priority => "Priority",
-        TaskSortKey::WorkerCount => "Workers",
+        TaskSortKey::WorkType => "ui.task_list.sort.type",
+        TaskSortKey::Status => "ui.task_list.sort.state",
+        TaskSortKey::Priority => "ui.task_list.sort.priority",
+        TaskSortKey::WorkerCount => "ui.task_list.sort.workers",
     }
 }
 
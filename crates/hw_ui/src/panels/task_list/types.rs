@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 
 use bevy::prelude::*;
 use hw_core::jobs::WorkType;
+use hw_core::locale::Localization;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TaskBlockerReason {
@@ -14,13 +15,13 @@ pub enum TaskBlockerReason {
 
 impl TaskBlockerReason {
     #[must_use]
-    pub const fn label(self) -> &'static str {
+    pub const fn text_id(self) -> &'static str {
         match self {
-            Self::NoEligibleFamiliar => "No eligible familiar",
-            Self::MissingResourceOrSource => "Missing resource or source",
-            Self::Unreachable => "Unreachable",
-            Self::TemporaryContention => "Waiting for reservation",
-            Self::DependencyWaiting => "Waiting for dependency",
+            Self::NoEligibleFamiliar => "ui.task_list.blocker.no_eligible_familiar",
+            Self::MissingResourceOrSource => "ui.task_list.blocker.missing_resource",
+            Self::Unreachable => "ui.task_list.blocker.unreachable",
+            Self::TemporaryContention => "ui.task_list.blocker.reservation",
+            Self::DependencyWaiting => "ui.task_list.blocker.dependency",
         }
     }
 }
@@ -34,11 +35,14 @@ pub enum TaskStatusSummary {
 
 impl TaskStatusSummary {
     #[must_use]
-    pub fn label(self) -> String {
+    pub fn label(self, text: &Localization) -> String {
         match self {
-            Self::Working => "Working".to_string(),
-            Self::Blocked(reason) => format!("Blocked: {}", reason.label()),
-            Self::PendingEvaluation => "Evaluating...".to_string(),
+            Self::Working => text.text("ui.task_list.status.working").to_string(),
+            Self::Blocked(reason) => text.format(
+                "ui.task_list.status.blocked",
+                &[("reason", &text.text(reason.text_id()))],
+            ),
+            Self::PendingEvaluation => text.text("ui.task_list.status.evaluating").to_string(),
         }
     }
 
@@ -69,11 +73,11 @@ impl TaskPriorityTier {
     }
 
     #[must_use]
-    pub const fn label(self) -> &'static str {
+    pub const fn text_id(self) -> &'static str {
         match self {
-            Self::Normal => "Normal",
-            Self::High => "High",
-            Self::Critical => "Critical",
+            Self::Normal => "ui.task_list.priority.normal",
+            Self::High => "ui.task_list.priority.high",
+            Self::Critical => "ui.task_list.priority.critical",
         }
     }
 
@@ -107,10 +111,23 @@ pub enum TaskCancelKind {
 
 impl TaskCancelKind {
     #[must_use]
-    pub const fn label(self) -> &'static str {
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::FloorSite(_) | Self::WallSite(_) => "ui.task_list.cancel_site",
+            Self::GenericDesignation | Self::Blueprint | Self::ManualTransportRequest => {
+                "ui.task_list.cancel"
+            }
+        }
+    }
+
+    /// 確認待ちのキャンセルボタンに出す文言 ID
+    #[must_use]
+    pub const fn confirm_text_id(self) -> &'static str {
         match self {
-            Self::FloorSite(_) | Self::WallSite(_) => "Cancel site",
-            Self::GenericDesignation | Self::Blueprint | Self::ManualTransportRequest => "Cancel",
+            Self::FloorSite(_) | Self::WallSite(_) => "ui.task_list.confirm_cancel_site",
+            Self::GenericDesignation | Self::Blueprint | Self::ManualTransportRequest => {
+                "ui.task_list.confirm_cancel"
+            }
         }
     }
 }
@@ -454,6 +471,7 @@ impl TaskListDirty {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hw_core::locale::Locale;
 
     fn entry(index: u32, status: TaskStatusSummary, priority: u32, workers: usize) -> TaskEntry {
         entry_with_work_type(index, WorkType::Chop, status, priority, workers)
@@ -549,29 +567,30 @@ mod tests {
 
     #[test]
     fn every_status_has_the_exact_dashboard_label() {
-        assert_eq!(TaskStatusSummary::Working.label(), "Working");
+        let text = Localization::shipped(Locale::En);
+        assert_eq!(TaskStatusSummary::Working.label(&text), "Working");
         assert_eq!(
-            TaskStatusSummary::PendingEvaluation.label(),
+            TaskStatusSummary::PendingEvaluation.label(&text),
             "Evaluating..."
         );
         assert_eq!(
-            TaskStatusSummary::Blocked(TaskBlockerReason::NoEligibleFamiliar).label(),
+            TaskStatusSummary::Blocked(TaskBlockerReason::NoEligibleFamiliar).label(&text),
             "Blocked: No eligible familiar"
         );
         assert_eq!(
-            TaskStatusSummary::Blocked(TaskBlockerReason::MissingResourceOrSource).label(),
+            TaskStatusSummary::Blocked(TaskBlockerReason::MissingResourceOrSource).label(&text),
             "Blocked: Missing resource or source"
         );
         assert_eq!(
-            TaskStatusSummary::Blocked(TaskBlockerReason::Unreachable).label(),
+            TaskStatusSummary::Blocked(TaskBlockerReason::Unreachable).label(&text),
             "Blocked: Unreachable"
         );
         assert_eq!(
-            TaskStatusSummary::Blocked(TaskBlockerReason::TemporaryContention).label(),
+            TaskStatusSummary::Blocked(TaskBlockerReason::TemporaryContention).label(&text),
             "Blocked: Waiting for reservation"
         );
         assert_eq!(
-            TaskStatusSummary::Blocked(TaskBlockerReason::DependencyWaiting).label(),
+            TaskStatusSummary::Blocked(TaskBlockerReason::DependencyWaiting).label(&text),
             "Blocked: Waiting for dependency"
         );
     }
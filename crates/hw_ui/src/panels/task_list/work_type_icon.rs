@@ -3,27 +3,6 @@ use crate::theme::UiTheme;
 use bevy::prelude::*;
 use hw_core::jobs::WorkType;
 
-pub fn work_type_label(wt: &WorkType) -> &'static str {
-    match wt {
-        WorkType::Chop => "Chop",
-        WorkType::Mine => "Mine",
-        WorkType::Build => "Build",
-        WorkType::Move => "Move",
-        WorkType::Haul => "Haul",
-        WorkType::HaulToMixer => "Haul (Mixer)",
-        WorkType::GatherWater => "Water",
-        WorkType::CollectBone => "Bone",
-        WorkType::Refine => "Refine",
-        WorkType::HaulWaterToMixer => "Water (Mixer)",
-        WorkType::WheelbarrowHaul => "Wheelbarrow",
-        WorkType::ReinforceFloorTile => "Reinforce",
-        WorkType::PourFloorTile => "Pour",
-        WorkType::FrameWallTile => "Frame",
-        WorkType::CoatWall => "Coat",
-        WorkType::GeneratePower => "Generate",
-    }
-}
-
 pub fn work_type_icon(
     wt: &WorkType,
     assets: &dyn UiAssets,
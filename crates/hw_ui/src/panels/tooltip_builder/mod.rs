@@ -10,12 +10,14 @@ use crate::models::inspection::EntityInspectionModel;
 use crate::setup::UiAssets;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 
 pub use text_wrap::{TOOLTIP_WRAP_LIMIT_BODY, TOOLTIP_WRAP_LIMIT_ICON_ROW, wrap_tooltip_text};
 
 /// ツールチップ再構築の内容指定
 pub struct TooltipBuildPayload<'a> {
     pub template: TooltipTemplate,
+    pub text: &'a Localization,
     pub model: Option<&'a EntityInspectionModel>,
     pub ui_tooltip: Option<&'a UiTooltip>,
 }
@@ -30,6 +32,7 @@ pub fn rebuild_tooltip_content(
 ) {
     let TooltipBuildPayload {
         template,
+        text,
         model,
         ui_tooltip,
     } = payload;
@@ -39,25 +42,30 @@ pub fn rebuild_tooltip_content(
         .entity(tooltip_root)
         .with_children(|parent| match template {
             TooltipTemplate::Soul => {
-                templates::build_soul_tooltip(parent, model, game_assets, theme)
+                templates::build_soul_tooltip(parent, model, game_assets, theme, text)
             }
             TooltipTemplate::Building => {
-                templates::build_building_tooltip(parent, model, game_assets, theme)
+                templates::build_building_tooltip(parent, model, game_assets, theme, text)
             }
             TooltipTemplate::Resource => {
-                templates::build_resource_tooltip(parent, model, game_assets, theme)
+                templates::build_resource_tooltip(parent, model, game_assets, theme, text)
             }
             TooltipTemplate::UiButton => {
-                templates::build_ui_button_tooltip(parent, ui_tooltip, game_assets, theme)
+                templates::build_ui_button_tooltip(parent, ui_tooltip, game_assets, theme, text)
             }
             TooltipTemplate::PlacementRejected => {
-                templates::build_placement_tooltip(parent, model, false, game_assets, theme)
+                templates::build_placement_tooltip(parent, model, false, game_assets, theme, text)
             }
             TooltipTemplate::PlacementPartial => {
-                templates::build_placement_tooltip(parent, model, true, game_assets, theme)
-            }
-            TooltipTemplate::Generic => {
-                templates::build_generic_tooltip(parent, model, ui_tooltip, game_assets, theme)
+                templates::build_placement_tooltip(parent, model, true, game_assets, theme, text)
             }
+            TooltipTemplate::Generic => templates::build_generic_tooltip(
+                parent,
+                model,
+                ui_tooltip,
+                game_assets,
+                theme,
+                text,
+            ),
         });
 }
@@ -5,6 +5,7 @@ use crate::models::inspection::EntityInspectionModel;
 use crate::setup::UiAssets;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
+use hw_core::locale::Localization;
 
 use super::widgets::{
     spawn_body_line, spawn_divider, spawn_header, spawn_header_colored, spawn_icon_text_row,
@@ -17,6 +18,7 @@ pub fn build_placement_tooltip(
     partial: bool,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let color = if partial {
         theme.colors.status_warning
@@ -26,10 +28,12 @@ pub fn build_placement_tooltip(
     let title = model
         .map(|model| model.header.as_str())
         .filter(|title| !title.is_empty())
-        .unwrap_or(if partial {
-            "Some tiles will be skipped"
-        } else {
-            "Cannot place"
+        .unwrap_or_else(|| {
+            text.text(if partial {
+                "ui.tooltip.placement_partial"
+            } else {
+                "ui.tooltip.placement_rejected"
+            })
         });
     spawn_header_colored(parent, title, color, game_assets, theme);
     if let Some(model) = model {
@@ -59,20 +63,21 @@ pub fn build_soul_tooltip(
     model: Option<&EntityInspectionModel>,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let Some(model) = model else {
-        build_generic_tooltip(parent, None, None, game_assets, theme);
+        build_generic_tooltip(parent, None, None, game_assets, theme, text);
         return;
     };
 
     let Some(soul) = &model.soul else {
-        build_generic_tooltip(parent, Some(model), None, game_assets, theme);
+        build_generic_tooltip(parent, Some(model), None, game_assets, theme, text);
         return;
     };
 
     spawn_header(
         parent,
-        &format!("Soul: {}", model.header),
+        &text.format("ui.tooltip.soul", &[("name", &model.header)]),
         game_assets,
         theme,
     );
@@ -90,16 +95,23 @@ pub fn build_soul_tooltip(
 
     spawn_progress_bar(
         parent,
-        "Motivation",
+        text.text("ui.tooltip.motivation"),
         motivation,
         theme.colors.status_healthy,
         game_assets,
         theme,
     );
-    spawn_progress_bar(parent, "Stress", stress, stress_color, game_assets, theme);
     spawn_progress_bar(
         parent,
-        "Fatigue",
+        text.text("ui.tooltip.stress"),
+        stress,
+        stress_color,
+        game_assets,
+        theme,
+    );
+    spawn_progress_bar(
+        parent,
+        text.text("ui.tooltip.fatigue"),
         fatigue,
         theme.colors.status_info,
         game_assets,
@@ -107,8 +119,20 @@ pub fn build_soul_tooltip(
     );
 
     spawn_divider(parent, theme);
-    spawn_icon_text_row(parent, "TASK", &soul.task, game_assets, theme);
-    spawn_icon_text_row(parent, "BAG", &soul.inventory, game_assets, theme);
+    spawn_icon_text_row(
+        parent,
+        text.text("ui.tooltip.icon.task"),
+        &soul.task,
+        game_assets,
+        theme,
+    );
+    spawn_icon_text_row(
+        parent,
+        text.text("ui.tooltip.icon.bag"),
+        &soul.inventory,
+        game_assets,
+        theme,
+    );
 
     for line in soul
         .common
@@ -125,28 +149,35 @@ pub fn build_building_tooltip(
     model: Option<&EntityInspectionModel>,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let Some(model) = model else {
-        build_generic_tooltip(parent, None, None, game_assets, theme);
+        build_generic_tooltip(parent, None, None, game_assets, theme, text);
         return;
     };
 
     let title = if model.header.is_empty() {
-        "Building".to_string()
+        text.text("ui.tooltip.building").to_string()
     } else {
         model.header.clone()
     };
     spawn_header(parent, &title, game_assets, theme);
 
+    // 進捗行は文言テンプレートの `{percent}` より前の部分で見分ける
+    let progress_prefix = text
+        .text("ui.inspect.progress")
+        .split("{percent}")
+        .next()
+        .unwrap_or_default();
     let mut progress_added = false;
     for line in &model.tooltip_lines {
         if !progress_added
-            && line.contains("Progress")
+            && line.starts_with(progress_prefix)
             && let Some(progress) = parse_percent_value(line)
         {
             spawn_progress_bar(
                 parent,
-                "Progress",
+                text.text("ui.tooltip.progress"),
                 progress,
                 theme.colors.status_info,
                 game_assets,
@@ -173,14 +204,15 @@ pub fn build_resource_tooltip(
     model: Option<&EntityInspectionModel>,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let Some(model) = model else {
-        build_generic_tooltip(parent, None, None, game_assets, theme);
+        build_generic_tooltip(parent, None, None, game_assets, theme, text);
         return;
     };
 
     let title = if model.header.is_empty() {
-        "Resource".to_string()
+        text.text("ui.tooltip.resource").to_string()
     } else {
         model.header.clone()
     };
@@ -191,7 +223,13 @@ pub fn build_resource_tooltip(
         .iter()
         .filter(|line| !line.trim().is_empty())
     {
-        spawn_icon_text_row(parent, "RES", line, game_assets, theme);
+        spawn_icon_text_row(
+            parent,
+            text.text("ui.tooltip.icon.resource"),
+            line,
+            game_assets,
+            theme,
+        );
     }
 
     for line in model
@@ -209,10 +247,16 @@ pub fn build_ui_button_tooltip(
     tooltip: Option<&UiTooltip>,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     let Some(tooltip) = tooltip else {
-        spawn_header(parent, "UI Action", game_assets, theme);
-        spawn_body_line(parent, "No tooltip text", game_assets, theme);
+        spawn_header(
+            parent,
+            text.text("ui.tooltip.ui_action"),
+            game_assets,
+            theme,
+        );
+        spawn_body_line(parent, text.text("ui.tooltip.no_text"), game_assets, theme);
         return;
     };
 
@@ -221,8 +265,8 @@ pub fn build_ui_button_tooltip(
         spawn_divider(parent, theme);
         spawn_icon_text_row(
             parent,
-            "KEY",
-            &format!("Shortcut: {}", shortcut),
+            text.text("ui.tooltip.icon.key"),
+            &text.format("ui.tooltip.shortcut", &[("keys", &shortcut)]),
             game_assets,
             theme,
         );
@@ -235,10 +279,11 @@ pub fn build_generic_tooltip(
     ui_tooltip: Option<&UiTooltip>,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
+    text: &Localization,
 ) {
     if let Some(model) = model {
         let title = if model.header.is_empty() {
-            "Entity".to_string()
+            text.text("ui.tooltip.entity").to_string()
         } else {
             model.header.clone()
         };
@@ -267,7 +312,7 @@ pub fn build_generic_tooltip(
         if let Some(shortcut) = tooltip.shortcut.as_deref() {
             spawn_body_line(
                 parent,
-                &format!("Shortcut: {}", shortcut),
+                &text.format("ui.tooltip.shortcut", &[("keys", &shortcut)]),
                 game_assets,
                 theme,
             );
@@ -275,5 +320,5 @@ pub fn build_generic_tooltip(
         return;
     }
 
-    spawn_header(parent, "Tooltip", game_assets, theme);
+    spawn_header(parent, text.text("ui.tooltip.fallback"), game_assets, theme);
 }
@@ -7,6 +7,7 @@ use crate::interaction::{
     reset_text_input_consumed_keyboard_system, text_input_focus_sync_system,
 };
 use crate::list::search::EntityListSearchState;
+use crate::locale::sync_localized_text_system;
 use crate::theme::UiTheme;
 use bevy::input_focus::InputFocusSystems;
 use bevy::prelude::*;
@@ -27,6 +28,7 @@ impl Plugin for UiFoundationPlugin {
         app.add_observer(on_text_field_keyboard_input)
             .add_observer(on_text_field_focus_gained)
             .add_observer(on_text_field_focus_lost)
+            .add_systems(Update, sync_localized_text_system)
             .add_systems(
                 PreUpdate,
                 reset_text_input_consumed_keyboard_system.before(InputFocusSystems::Dispatch),
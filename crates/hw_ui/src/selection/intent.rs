@@ -6,6 +6,12 @@ use bevy::prelude::*;
 pub enum SelectionIntent {
     /// Select the given entity.
     Select(Entity),
+    /// Add the entity to the multi-selection, or remove it if already selected (Shift+click).
+    ToggleSelection(Entity),
+    /// Add the entities to the multi-selection (box selection release).
+    AddToSelection(Vec<Entity>),
+    /// Start a box-selection drag at the given world position.
+    BeginBoxSelection { origin: Vec2 },
     /// Deselect whatever is currently selected.
     ClearSelection,
     /// Begin task-area resize for a familiar.
@@ -17,18 +17,41 @@ pub use placement::{
 };
 
 // 型定義は hw_core に移動し、ここでは re-export して既存コードを壊さない
-pub use hw_core::selection::{HoveredEntity, SelectedEntity, SelectionIndicator};
+pub use hw_core::selection::{
+    HoveredEntity, SelectedEntities, SelectedEntity, SelectionBoxDrag, SelectionBoxVisual,
+    SelectionIndicator, SelectionWriter,
+};
 
-/// Clears stale `SelectedEntity` / `HoveredEntity` references when the target entity is despawned.
+/// Clears stale `SelectedEntity` / `SelectedEntities` / `HoveredEntity` references when the
+/// target entity is despawned, and keeps the multi-selection set anchored on `SelectedEntity`.
+///
+/// Selection writers go through `SelectionWriter`, which updates both resources together; the
+/// primary-vs-set check below only repairs state left behind by despawns and direct test setup.
 pub fn cleanup_selection_references_system(
     mut selected_entity: ResMut<SelectedEntity>,
+    mut selected_entities: ResMut<SelectedEntities>,
     mut hovered_entity: ResMut<HoveredEntity>,
     q_exists: Query<(), ()>,
 ) {
+    if selected_entities
+        .iter()
+        .any(|entity| q_exists.get(entity).is_err())
+    {
+        selected_entities.retain(|&entity| q_exists.get(entity).is_ok());
+    }
+
     if let Some(entity) = selected_entity.0
         && q_exists.get(entity).is_err()
     {
-        selected_entity.0 = None;
+        selected_entity.0 = selected_entities.primary();
+    }
+
+    match selected_entity.0 {
+        Some(entity) if !selected_entities.contains(entity) => {
+            selected_entities.set_single(Some(entity));
+        }
+        None if !selected_entities.is_empty() => selected_entities.set_single(None),
+        _ => {}
     }
 
     if let Some(entity) = hovered_entity.0
@@ -15,6 +15,7 @@ pub use self::validation::{
 
 use bevy::prelude::*;
 use bevy::time::Real;
+use hw_core::locale::Localization;
 use std::time::Duration;
 
 pub const TANK_NEARBY_BUCKET_STORAGE_TILES: i32 = 3;
@@ -63,47 +64,29 @@ impl PlacementRejectReason {
         Self::NotStraightLine,
     ];
 
-    pub fn message(&self, gx: i32, gy: i32) -> String {
+    /// 却下理由の文言 ID。`{x}` / `{y}` に対象タイルを埋める
+    pub const fn text_id(self) -> &'static str {
         match self {
-            Self::NotWalkable => format!("Tile ({},{}) is not walkable", gx, gy),
-            Self::OccupiedByBuilding => {
-                format!("Tile ({},{}) is already occupied by a building", gx, gy)
-            }
-            Self::OccupiedByStockpile => {
-                format!("Tile ({},{}) is already occupied by a stockpile", gx, gy)
-            }
-            Self::OutOfBounds => format!("Tile ({},{}) is out of bounds", gx, gy),
-            Self::NotRiverTile => format!("Tile ({},{}) is not a river tile", gx, gy),
-            Self::NoDoorAdjacentWall => {
-                format!("Tile ({},{}) has no adjacent wall pair for door", gx, gy)
-            }
-            Self::NotInSite => {
-                format!("Tile ({},{}) is not inside a construction site", gx, gy)
-            }
-            Self::NotInYard => format!("Tile ({},{}) is not inside a yard", gx, gy),
-            Self::AlreadyHasFloorBlueprint => {
-                format!("Tile ({},{}) already has a floor blueprint", gx, gy)
-            }
-            Self::AlreadyHasCompletedFloor => {
-                format!("Tile ({},{}) already has a completed floor", gx, gy)
-            }
-            Self::NoCompletedFloor => {
-                format!("Tile ({},{}) has no completed floor", gx, gy)
-            }
-            Self::AreaTooLarge => {
-                format!("Placement area starting at ({},{}) is too large", gx, gy)
-            }
-            Self::TooFarFromParent => {
-                format!("Tile ({},{}) is too far from parent building", gx, gy)
-            }
-            Self::NotStraightLine => {
-                format!(
-                    "Wall must be placed as a straight 1xn line (tile {},{} is in a non-linear area)",
-                    gx, gy
-                )
-            }
+            Self::NotWalkable => "ui.placement.reject.not_walkable",
+            Self::OccupiedByBuilding => "ui.placement.reject.occupied_by_building",
+            Self::OccupiedByStockpile => "ui.placement.reject.occupied_by_stockpile",
+            Self::OutOfBounds => "ui.placement.reject.out_of_bounds",
+            Self::NotRiverTile => "ui.placement.reject.not_river_tile",
+            Self::NoDoorAdjacentWall => "ui.placement.reject.no_door_adjacent_wall",
+            Self::NotInSite => "ui.placement.reject.not_in_site",
+            Self::NotInYard => "ui.placement.reject.not_in_yard",
+            Self::AlreadyHasFloorBlueprint => "ui.placement.reject.already_has_floor_blueprint",
+            Self::AlreadyHasCompletedFloor => "ui.placement.reject.already_has_completed_floor",
+            Self::NoCompletedFloor => "ui.placement.reject.no_completed_floor",
+            Self::AreaTooLarge => "ui.placement.reject.area_too_large",
+            Self::TooFarFromParent => "ui.placement.reject.too_far_from_parent",
+            Self::NotStraightLine => "ui.placement.reject.not_straight_line",
         }
     }
+
+    pub fn message(&self, text: &Localization, gx: i32, gy: i32) -> String {
+        text.format(self.text_id(), &[("x", &gx), ("y", &gy)])
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -139,20 +122,27 @@ impl PlacementFeedback {
         }
     }
 
-    pub fn header(&self) -> &'static str {
+    /// 見出しの文言 ID
+    pub const fn header_text_id(&self) -> &'static str {
         match self.status {
-            PlacementFeedbackStatus::Rejected => "Cannot place",
-            PlacementFeedbackStatus::Partial => "Some tiles will be skipped",
+            PlacementFeedbackStatus::Rejected => "ui.tooltip.placement_rejected",
+            PlacementFeedbackStatus::Partial => "ui.tooltip.placement_partial",
         }
     }
 
-    pub fn body(&self) -> String {
-        let reason = self.reason.message(self.target_grid.0, self.target_grid.1);
+    pub fn body(&self, text: &Localization) -> String {
+        let reason = self
+            .reason
+            .message(text, self.target_grid.0, self.target_grid.1);
         match self.status {
             PlacementFeedbackStatus::Rejected => reason,
-            PlacementFeedbackStatus::Partial => format!(
-                "{reason}. {} valid, {} skipped",
-                self.valid_tile_count, self.rejected_tile_count
+            PlacementFeedbackStatus::Partial => text.format(
+                "ui.placement.partial",
+                &[
+                    ("reason", &reason),
+                    ("valid", &self.valid_tile_count),
+                    ("skipped", &self.rejected_tile_count),
+                ],
             ),
         }
     }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use hw_core::locale::Locale;
 use hw_jobs::BuildingType;
 use std::collections::{HashMap, HashSet};
 
@@ -167,8 +168,9 @@ fn bucket_storage_rejects_parent_footprint_overlap_during_preview() {
 #[test]
 fn every_reject_reason_has_non_empty_display_text() {
     assert_eq!(PlacementRejectReason::ALL.len(), 14);
+    let text = Localization::shipped(Locale::En);
     for reason in PlacementRejectReason::ALL {
-        assert!(!reason.message(3, 4).trim().is_empty());
+        assert!(!reason.message(&text, 3, 4).trim().is_empty());
     }
 }
 
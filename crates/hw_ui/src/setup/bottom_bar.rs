@@ -4,7 +4,8 @@ use super::UiAssets;
 use crate::components::{
     MenuAction, MenuButton, UiInputBlocker, UiNodeRegistry, UiSlot, UiTooltip,
 };
-use crate::help::HelpPanelChrome;
+use crate::help::{HelpChromeText, HelpPanelChrome};
+use crate::locale::{LocalizedText, LocalizedTooltip};
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
@@ -53,33 +54,78 @@ pub fn spawn_bottom_bar(
     commands.entity(bottom_bar).with_children(|parent| {
         let buttons = [
             (
-                "Architect",
-                "建築モード切替 (B)",
+                "ui.bottom_bar.architect",
+                "ui.bottom_bar.architect.tooltip",
                 MenuAction::ToggleArchitect,
                 Some("B"),
             ),
             (
-                "Zones",
-                "ゾーンモード切替 (Z)",
+                "ui.bottom_bar.zones",
+                "ui.bottom_bar.zones.tooltip",
                 MenuAction::ToggleZones,
                 Some("Z"),
             ),
-            ("Orders", "命令メニュー切替", MenuAction::ToggleOrders, None),
-            ("Dream", "Dreamメニュー切替", MenuAction::ToggleDream, None),
-            ("Settings", "設定", MenuAction::ToggleSettings, None),
+            (
+                "ui.bottom_bar.orders",
+                "ui.bottom_bar.orders.tooltip",
+                MenuAction::ToggleOrders,
+                None,
+            ),
+            (
+                "ui.bottom_bar.dream",
+                "ui.bottom_bar.dream.tooltip",
+                MenuAction::ToggleDream,
+                None,
+            ),
+            (
+                "ui.bottom_bar.stats",
+                "ui.bottom_bar.stats.tooltip",
+                MenuAction::ToggleStatistics,
+                None,
+            ),
+            (
+                "ui.bottom_bar.alerts",
+                "ui.bottom_bar.alerts.tooltip",
+                MenuAction::ToggleAlerts,
+                None,
+            ),
+            (
+                "ui.bottom_bar.settings",
+                "ui.bottom_bar.settings.tooltip",
+                MenuAction::ToggleSettings,
+                None,
+            ),
         ];
 
         for (label, tooltip, action, shortcut) in buttons {
-            spawn_bottom_bar_button(parent, game_assets, theme, label, tooltip, action, shortcut);
+            spawn_bottom_bar_button(
+                parent,
+                game_assets,
+                theme,
+                LocalizedText(label),
+                (
+                    LocalizedTooltip(tooltip),
+                    UiTooltip {
+                        text: default(),
+                        shortcut: shortcut.map(Into::into),
+                    },
+                ),
+                action,
+            );
         }
         spawn_bottom_bar_button(
             parent,
             game_assets,
             theme,
-            help_chrome.copy().launcher_label(),
-            help_chrome.copy().launcher_tooltip(),
+            (
+                Text::new(help_chrome.copy().launcher_label()),
+                HelpChromeText::LauncherLabel,
+            ),
+            UiTooltip::with_shortcut(
+                help_chrome.copy().launcher_tooltip().to_owned(),
+                help_chrome.launcher_shortcut().to_owned(),
+            ),
             MenuAction::OpenHelp { opener: None },
-            Some(help_chrome.launcher_shortcut()),
         );
 
         // Mode Display
@@ -108,10 +154,9 @@ fn spawn_bottom_bar_button(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    label: &str,
-    tooltip: &str,
+    label: impl Bundle,
+    tooltip: impl Bundle,
     action: MenuAction,
-    shortcut: Option<&str>,
 ) {
     parent
         .spawn((
@@ -126,14 +171,11 @@ fn spawn_bottom_bar_button(
             },
             BackgroundColor(theme.colors.button_default),
             MenuButton(action),
-            match shortcut {
-                Some(shortcut) => UiTooltip::with_shortcut(tooltip.to_owned(), shortcut.to_owned()),
-                None => UiTooltip::new(tooltip.to_owned()),
-            },
+            tooltip,
         ))
         .with_children(|button| {
             button.spawn((
-                Text::new(label),
+                label,
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: crate::theme::font_size_rem(theme.typography.font_size_base),
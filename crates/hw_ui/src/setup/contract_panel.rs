@@ -0,0 +1,77 @@
+//! King of Hell の契約パネル（画面上部中央）
+
+use super::UiAssets;
+use crate::components::{UiInputBlocker, UiNodeRegistry, UiSlot};
+use crate::theme::UiTheme;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+const CONTRACT_PANEL_WIDTH: f32 = 360.0;
+
+/// 契約パネルをスポーン。受注中の契約が無い間は非表示
+pub fn spawn_contract_panel(
+    commands: &mut Commands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    parent_entity: Entity,
+    ui_nodes: &mut UiNodeRegistry,
+) {
+    let panel = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Px(theme.sizes.time_control_top),
+                width: Val::Px(CONTRACT_PANEL_WIDTH),
+                margin: UiRect::left(Val::Px(-CONTRACT_PANEL_WIDTH / 2.0)),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(theme.colors.time_control_bg),
+            BorderColor::all(theme.colors.time_control_border),
+            RelativeCursorPosition::default(),
+            UiInputBlocker,
+            UiSlot::ContractPanel,
+        ))
+        .id();
+    commands.entity(parent_entity).add_child(panel);
+    ui_nodes.set_slot(UiSlot::ContractPanel, panel);
+
+    commands.entity(panel).with_children(|panel| {
+        let header = panel
+            .spawn((
+                Text::default(),
+                TextFont {
+                    font: game_assets.font_ui().clone().into(),
+                    font_size: crate::theme::font_size_rem(theme.typography.font_size_status),
+                    ..default()
+                },
+                TextColor(theme.colors.accent_sulfur),
+                UiSlot::ContractHeaderText,
+                Node {
+                    margin: UiRect::bottom(Val::Px(4.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        ui_nodes.set_slot(UiSlot::ContractHeaderText, header);
+
+        let list = panel
+            .spawn((
+                Text::new(""),
+                TextFont {
+                    font: game_assets.font_ui().clone().into(),
+                    font_size: crate::theme::font_size_rem(theme.typography.font_size_small),
+                    ..default()
+                },
+                TextColor(theme.colors.text_primary_semantic),
+                UiSlot::ContractListText,
+            ))
+            .id();
+        ui_nodes.set_slot(UiSlot::ContractListText, list);
+    });
+}
@@ -5,6 +5,7 @@ use crate::components::{
     LoadConfirmDialog, MenuAction, MenuButton, OperationDialog, UiInputBlocker, UiInputCapture,
     UiNodeRegistry, UiSlot,
 };
+use crate::locale::LocalizedText;
 use crate::overlay::{LOAD_CONFIRM_LAYER, OPERATION_DIALOG_LAYER};
 use crate::theme::UiTheme;
 use bevy::picking::Pickable;
@@ -88,7 +89,7 @@ fn spawn_operation_dialog(
             })
             .with_children(|header| {
                 header.spawn((
-                    Text::new("Familiar Operation"),
+                    LocalizedText("ui.dialog.familiar.title"),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_xl),
@@ -126,7 +127,7 @@ fn spawn_operation_dialog(
         // Familiar Name
         let familiar_name = parent
             .spawn((
-                Text::new("Familiar Name"),
+                Text::new(""),
                 TextFont {
                     font: game_assets.font_familiar().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_title),
@@ -144,7 +145,7 @@ fn spawn_operation_dialog(
 
         // Section Label
         parent.spawn((
-            Text::new("Work Standards:"),
+            LocalizedText("ui.dialog.familiar.fatigue_threshold"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_dialog_small),
@@ -241,7 +242,7 @@ fn spawn_operation_dialog(
 
         // Max Controlled Souls Adjustment
         parent.spawn((
-            Text::new("Max Controlled Souls:"),
+            LocalizedText("ui.dialog.familiar.max_souls"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_dialog_small),
@@ -339,7 +340,7 @@ fn spawn_operation_dialog(
 
         // Future slot hint
         parent.spawn((
-            Text::new("(Settings automatically synced)"),
+            LocalizedText("ui.dialog.familiar.auto_sync"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_dialog_tiny),
@@ -409,7 +410,7 @@ fn spawn_load_confirm_dialog(
 
     commands.entity(dialog_panel).with_children(|parent| {
         parent.spawn((
-            Text::new("Load saved game?"),
+            LocalizedText("ui.dialog.load.title"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_xl),
@@ -423,7 +424,7 @@ fn spawn_load_confirm_dialog(
         ));
 
         parent.spawn((
-            Text::new("Current progress will be lost. This cannot be undone."),
+            LocalizedText("ui.dialog.load.body"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_dialog_small),
@@ -459,7 +460,7 @@ fn spawn_load_confirm_dialog(
                 ))
                 .with_children(|btn| {
                     btn.spawn((
-                        Text::new("Cancel"),
+                        LocalizedText("ui.dialog.load.cancel"),
                         TextFont {
                             font: game_assets.font_ui().clone().into(),
                             font_size: FontSize::Px(theme.typography.font_size_dialog_small),
@@ -483,7 +484,7 @@ fn spawn_load_confirm_dialog(
                 ))
                 .with_children(|btn| {
                     btn.spawn((
-                        Text::new("Load"),
+                        LocalizedText("ui.dialog.load.confirm"),
                         TextFont {
                             font: game_assets.font_ui().clone().into(),
                             font_size: FontSize::Px(theme.typography.font_size_dialog_small),
@@ -2,6 +2,7 @@
 
 use super::UiAssets;
 use crate::components::*;
+use crate::locale::LocalizedText;
 use crate::theme::UiTheme;
 use crate::widgets::{TextFieldConfig, TextFieldRole, spawn_text_field};
 use bevy::prelude::*;
@@ -113,7 +114,7 @@ pub fn spawn_entity_list_panel(
             ))
             .with_children(|row| {
                 row.spawn((
-                    Text::new("Search"),
+                    LocalizedText("ui.entity_list.search"),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
@@ -201,7 +202,7 @@ pub fn spawn_entity_list_panel(
                                 UnassignedSectionArrowIcon,
                             ));
                             button.spawn((
-                                Text::new("Unassigned Souls"),
+                                LocalizedText("ui.entity_list.unassigned"),
                                 TextFont {
                                     font: game_assets.font_ui().clone().into(),
                                     font_size: crate::theme::font_size_rem(
@@ -261,7 +262,7 @@ pub fn spawn_entity_list_panel(
 
                 // スクロール可能であることを示す固定ヒント
                 body.spawn((
-                    Text::new("Scroll: Mouse Wheel"),
+                    LocalizedText("ui.entity_list.scroll_hint"),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: crate::theme::font_size_rem(theme.typography.font_size_xs),
@@ -3,8 +3,8 @@
 use super::UiAssets;
 use crate::components::{MenuButton, UiInputBlocker, UiInputCapture};
 use crate::help::{
-    HelpNavigationScrollArea, HelpPanel, HelpPanelChrome, HelpPanelContent, HelpScrollArea,
-    HelpTopicBody, HelpTopicButton,
+    HelpCatalogText, HelpChromeText, HelpEntryShortcutText, HelpNavigationScrollArea, HelpPanel,
+    HelpPanelChrome, HelpPanelContent, HelpScrollArea, HelpTopicBody, HelpTopicButton,
 };
 use crate::intents::UiIntent;
 use crate::overlay::HELP_LAYER;
@@ -91,6 +91,7 @@ pub fn spawn_help_panel(
 
         parent.spawn((
             Text::new(chrome.footer_text()),
+            HelpChromeText::Footer,
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_xs),
@@ -123,6 +124,7 @@ fn spawn_header(
         .with_children(|header| {
             header.spawn((
                 Text::new(chrome.copy().panel_title()),
+                HelpChromeText::PanelTitle,
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_xl),
@@ -151,6 +153,7 @@ fn spawn_header(
                 .with_children(|button| {
                     button.spawn((
                         Text::new(chrome.close_button_text()),
+                        HelpChromeText::CloseButton,
                         TextFont {
                             font: game_assets.font_ui().clone().into(),
                             font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -192,6 +195,7 @@ fn spawn_navigation(
             for section in content.sections() {
                 navigation.spawn((
                     Text::new(section.title()),
+                    HelpCatalogText::SectionTitle(section.id()),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -225,6 +229,7 @@ fn spawn_navigation(
                         .with_children(|button| {
                             button.spawn((
                                 Text::new(topic.title()),
+                                HelpCatalogText::TopicTitle(topic.id()),
                                 TextFont {
                                     font: game_assets.font_ui().clone().into(),
                                     font_size: FontSize::Px(theme.typography.font_size_xs),
@@ -297,6 +302,7 @@ fn spawn_content(
                         .with_children(|topic_parent| {
                             topic_parent.spawn((
                                 Text::new(topic.title()),
+                                HelpCatalogText::TopicTitle(topic.id()),
                                 TextFont {
                                     font: game_assets.font_ui().clone().into(),
                                     font_size: FontSize::Px(theme.typography.font_size_lg),
@@ -359,6 +365,7 @@ fn spawn_entry(
         .with_children(|entry_parent| {
             entry_parent.spawn((
                 Text::new(entry.title()),
+                HelpCatalogText::EntryTitle(entry.id()),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_base),
@@ -371,6 +378,7 @@ fn spawn_entry(
             if let Some(shortcut) = entry.shortcut() {
                 entry_parent.spawn((
                     Text::new(chrome.entry_shortcut_text(shortcut)),
+                    HelpEntryShortcutText(entry.id()),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_xs),
@@ -381,9 +389,10 @@ fn spawn_entry(
                     TextLayout::new(Justify::Left, LineBreak::WordOrCharacter),
                 ));
             }
-            for paragraph in entry.paragraphs() {
+            for (index, paragraph) in entry.paragraphs().iter().enumerate() {
                 entry_parent.spawn((
                     Text::new(paragraph),
+                    HelpCatalogText::EntryParagraph(entry.id(), index),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -3,6 +3,7 @@
 //! UIの初期構造を構築します。
 
 mod bottom_bar;
+mod contract_panel;
 mod dialogs;
 mod entity_list;
 mod help_panel;
@@ -35,7 +36,7 @@ pub trait UiAssets {
 }
 
 pub use root::{SetupUiParams, setup_ui};
-pub use settings_panel::{SettingsPanelInitial, spawn_settings_panel};
+pub use settings_panel::{KeyBindingRowSpec, SettingsPanelInitial, spawn_settings_panel};
 
 #[cfg(test)]
 pub(super) mod test_support {
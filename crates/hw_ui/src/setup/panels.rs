@@ -7,6 +7,7 @@ use crate::components::{
     HoverActionOverlay, HoverTooltip, MenuAction, MenuButton, UiInputBlocker, UiNodeRegistry,
     UiSlot,
 };
+use crate::locale::LocalizedText;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 use bevy::ui_widgets::popover::{Popover, PopoverAlign, PopoverPlacement, PopoverSide};
@@ -54,7 +55,7 @@ fn spawn_hover_action_overlay(
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Move"),
+                LocalizedText("ui.panels.move"),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: crate::theme::font_size_rem(theme.typography.font_size_base),
@@ -4,7 +4,8 @@ use super::UiAssets;
 use crate::components::{
     MenuAction, MenuButton, PauseMenu, UiInputBlocker, UiInputCapture, UiTooltip,
 };
-use crate::help::HelpPanelChrome;
+use crate::help::{HelpChromeText, HelpPanelChrome};
+use crate::locale::{LocalizedText, LocalizedTooltip};
 use crate::overlay::PAUSE_LAYER;
 use crate::theme::UiTheme;
 use bevy::picking::Pickable;
@@ -15,10 +16,9 @@ fn spawn_menu_button(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    label: &str,
-    tooltip: &str,
+    label: impl Bundle,
+    tooltip: impl Bundle,
     action: MenuAction,
-    shortcut: Option<&str>,
 ) {
     parent
         .spawn((
@@ -36,14 +36,11 @@ fn spawn_menu_button(
             BackgroundColor(theme.colors.button_default),
             BorderColor::all(theme.colors.dialog_border),
             MenuButton(action),
-            match shortcut {
-                Some(shortcut) => UiTooltip::with_shortcut(tooltip.to_owned(), shortcut.to_owned()),
-                None => UiTooltip::new(tooltip.to_owned()),
-            },
+            tooltip,
         ))
         .with_children(|button| {
             button.spawn((
-                Text::new(label),
+                label,
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_base),
@@ -110,7 +107,7 @@ pub fn spawn_pause_menu(
 
     commands.entity(panel).with_children(|parent| {
         parent.spawn((
-            Text::new("Paused"),
+            LocalizedText("ui.pause.title"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_xl),
@@ -124,50 +121,35 @@ pub fn spawn_pause_menu(
             },
         ));
 
+        let buttons = [
+            ("ui.pause.resume", MenuAction::TogglePause),
+            ("ui.pause.save", MenuAction::SaveGame),
+            ("ui.pause.load", MenuAction::RequestLoadGame),
+            ("ui.pause.settings", MenuAction::ToggleSettings),
+        ];
+        for (label, action) in buttons {
+            spawn_menu_button(
+                parent,
+                game_assets,
+                theme,
+                LocalizedText(label),
+                LocalizedTooltip(label),
+                action,
+            );
+        }
         spawn_menu_button(
             parent,
             game_assets,
             theme,
-            "Resume",
-            "Resume",
-            MenuAction::TogglePause,
-            None,
-        );
-        spawn_menu_button(
-            parent,
-            game_assets,
-            theme,
-            "Save Game",
-            "Save Game",
-            MenuAction::SaveGame,
-            None,
-        );
-        spawn_menu_button(
-            parent,
-            game_assets,
-            theme,
-            "Load Game",
-            "Load Game",
-            MenuAction::RequestLoadGame,
-            None,
-        );
-        spawn_menu_button(
-            parent,
-            game_assets,
-            theme,
-            "Settings",
-            "Settings",
-            MenuAction::ToggleSettings,
-            None,
-        );
-        spawn_menu_button(
-            parent,
-            game_assets,
-            theme,
-            help_chrome.copy().launcher_label(),
-            help_chrome.copy().launcher_tooltip(),
+            (
+                Text::new(help_chrome.copy().launcher_label()),
+                HelpChromeText::LauncherLabel,
+            ),
+            UiTooltip::with_shortcut(
+                help_chrome.copy().launcher_tooltip().to_owned(),
+                help_chrome.launcher_shortcut().to_owned(),
+            ),
             MenuAction::OpenHelp { opener: None },
-            Some(help_chrome.launcher_shortcut()),
         );
     });
 }
@@ -5,8 +5,8 @@ use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use super::{
-    UiAssets, bottom_bar, dialogs, entity_list, help_panel, panels, settings_panel, submenus,
-    time_control,
+    UiAssets, bottom_bar, contract_panel, dialogs, entity_list, help_panel, panels, settings_panel,
+    submenus, time_control,
 };
 
 fn spawn_area_edit_preview(
@@ -234,7 +234,13 @@ pub fn setup_ui<F, G>(
         &mut ui_nodes,
         help_chrome,
     );
-    submenus::spawn_submenus(&mut commands, game_assets, theme, bottom_slot);
+    submenus::spawn_submenus(
+        &mut commands,
+        game_assets,
+        theme,
+        bottom_slot,
+        &mut ui_nodes,
+    );
     panels::spawn_panels(
         &mut commands,
         game_assets,
@@ -250,6 +256,13 @@ pub fn setup_ui<F, G>(
         top_right_slot,
         &mut ui_nodes,
     );
+    contract_panel::spawn_contract_panel(
+        &mut commands,
+        game_assets,
+        theme,
+        top_right_slot,
+        &mut ui_nodes,
+    );
     crate::notifications::spawn_notification_ui(
         &mut commands,
         game_assets.font_ui().clone(),
@@ -257,6 +270,24 @@ pub fn setup_ui<F, G>(
         top_right_slot,
         overlay_slot,
     );
+    crate::minimap::spawn_minimap_ui(
+        &mut commands,
+        game_assets.font_ui().clone(),
+        theme,
+        bottom_slot,
+    );
+    crate::statistics::spawn_statistics_panel(
+        &mut commands,
+        game_assets.font_ui().clone(),
+        theme,
+        bottom_slot,
+    );
+    crate::alerts::spawn_alert_panel(
+        &mut commands,
+        game_assets.font_ui().clone(),
+        theme,
+        bottom_slot,
+    );
     spawn_area_edit_preview(
         &mut commands,
         game_assets,
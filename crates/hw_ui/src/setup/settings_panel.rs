@@ -3,20 +3,23 @@
 use super::UiAssets;
 use crate::components::{
     MenuAction, MenuButton, SettingsCheckboxMarker, SettingsCheckmarkMarker,
-    SettingsDefaultSpeedButton, SettingsField, SettingsPanel, SettingsSliderMarker,
-    SettingsSliderThumbMarker, UiInputBlocker, UiInputCapture,
+    SettingsDefaultSpeedButton, SettingsField, SettingsKeyBindingLabel,
+    SettingsKeyBindingStatusText, SettingsKeyBindingText, SettingsLocaleButton, SettingsPanel,
+    SettingsSliderMarker, SettingsSliderThumbMarker, UiInputBlocker, UiInputCapture,
 };
+use crate::locale::LocalizedText;
 use crate::overlay::SETTINGS_LAYER;
 use crate::theme::UiTheme;
 use bevy::picking::Pickable;
 use bevy::prelude::*;
 use bevy::scene::bsn;
 use bevy::ui::{FocusPolicy, RelativeCursorPosition};
-use bevy::ui_widgets::{Checkbox, Slider, SliderRange, SliderStep, SliderValue};
+use bevy::ui_widgets::{Checkbox, ScrollArea, Slider, SliderRange, SliderStep, SliderValue};
 use hw_core::game_state::TimeSpeed;
+use hw_core::locale::Locale;
 
 /// 設定パネル初期値（hw_core::GameSettings への依存を避ける DTO）
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct SettingsPanelInitial {
     pub ui_scale: f32,
     pub camera_pan_speed: f32,
@@ -24,10 +27,20 @@ pub struct SettingsPanelInitial {
     pub default_time_speed: TimeSpeed,
     pub debug_gizmos_enabled: bool,
     pub fps_display_enabled: bool,
+    pub locale: Locale,
+    /// root 側バインドテーブルの行順。行番号が `UiIntent::BeginKeyRebind` の値になる
+    pub key_bindings: Vec<KeyBindingRowSpec>,
 }
 
-struct SliderRowSpec<'a> {
-    label: &'a str,
+/// キーバインド編集の 1 行（現在のキー表記）。操作名は `SettingsKeyBindingLabel` へ後から書き込む
+#[derive(Clone, Debug)]
+pub struct KeyBindingRowSpec {
+    pub chord: String,
+}
+
+struct SliderRowSpec {
+    /// ラベルの文言 ID
+    label: &'static str,
     field: SettingsField,
     value: f32,
     min: f32,
@@ -74,7 +87,7 @@ pub fn spawn_settings_panel(
                 width: Val::Px(380.0),
                 position_type: PositionType::Absolute,
                 left: Val::Percent(50.0),
-                top: Val::Percent(45.0),
+                top: Val::Percent(12.0),
                 margin: UiRect::left(Val::Px(-190.0)),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(16.0)),
@@ -95,7 +108,7 @@ pub fn spawn_settings_panel(
 
     commands.entity(panel).with_children(|parent| {
         parent.spawn((
-            Text::new("Settings"),
+            LocalizedText("ui.settings.title"),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_xl),
@@ -114,7 +127,7 @@ pub fn spawn_settings_panel(
             game_assets,
             theme,
             SliderRowSpec {
-                label: "UI Scale",
+                label: "ui.settings.ui_scale",
                 field: SettingsField::UiScale,
                 value: initial.ui_scale,
                 min: 0.85,
@@ -127,7 +140,7 @@ pub fn spawn_settings_panel(
             game_assets,
             theme,
             SliderRowSpec {
-                label: "Camera Pan Speed",
+                label: "ui.settings.camera_pan_speed",
                 field: SettingsField::CameraPanSpeed,
                 value: initial.camera_pan_speed,
                 min: 200.0,
@@ -140,18 +153,19 @@ pub fn spawn_settings_panel(
             parent,
             game_assets,
             theme,
-            "Mouse Drag Pan",
+            "ui.settings.mouse_drag_pan",
             SettingsField::CameraMousePan,
             initial.camera_mouse_pan_enabled,
         );
 
         spawn_default_speed_row(parent, game_assets, theme, initial.default_time_speed);
+        spawn_locale_row(parent, game_assets, theme, initial.locale);
 
         spawn_checkbox_row(
             parent,
             game_assets,
             theme,
-            "Debug Gizmos",
+            "ui.settings.debug_gizmos",
             SettingsField::DebugGizmos,
             initial.debug_gizmos_enabled,
         );
@@ -159,11 +173,13 @@ pub fn spawn_settings_panel(
             parent,
             game_assets,
             theme,
-            "Show FPS",
+            "ui.settings.show_fps",
             SettingsField::FpsDisplay,
             initial.fps_display_enabled,
         );
 
+        spawn_key_binding_section(parent, game_assets, theme, &initial.key_bindings);
+
         parent
             .spawn((
                 Button,
@@ -183,7 +199,7 @@ pub fn spawn_settings_panel(
             ))
             .with_children(|button| {
                 button.spawn((
-                    Text::new("Close"),
+                    LocalizedText("ui.settings.close"),
                     TextFont {
                         font: game_assets.font_ui().clone().into(),
                         font_size: FontSize::Px(theme.typography.font_size_base),
@@ -195,11 +211,133 @@ pub fn spawn_settings_panel(
     });
 }
 
+fn spawn_key_binding_section(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    rows: &[KeyBindingRowSpec],
+) {
+    let text_font = |size: f32| TextFont {
+        font: game_assets.font_ui().clone().into(),
+        font_size: FontSize::Px(size),
+        ..default()
+    };
+
+    parent.spawn((
+        LocalizedText("ui.settings.key_bindings"),
+        text_font(theme.typography.font_size_sm),
+        TextColor(theme.colors.text_primary_semantic),
+    ));
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                max_height: Val::Px(220.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(3.0)),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            BackgroundColor(theme.colors.bg_elevated),
+            BorderColor::all(theme.colors.border_default),
+            RelativeCursorPosition::default(),
+            ScrollArea,
+        ))
+        .with_children(|list| {
+            for (index, row) in rows.iter().enumerate() {
+                list.spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    flex_shrink: 0.0,
+                    ..default()
+                })
+                .with_children(|line| {
+                    line.spawn((
+                        Text::new(""),
+                        text_font(theme.typography.font_size_xs),
+                        TextColor(theme.colors.text_primary_semantic),
+                        SettingsKeyBindingLabel(index),
+                    ));
+                    line.spawn((
+                        Button,
+                        Node {
+                            min_width: Val::Px(96.0),
+                            height: Val::Px(22.0),
+                            padding: UiRect::horizontal(Val::Px(6.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        BackgroundColor(theme.colors.button_default),
+                        BorderColor::all(theme.colors.dialog_border),
+                        MenuButton(MenuAction::BeginKeyRebind(index)),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(row.chord.clone()),
+                            text_font(theme.typography.font_size_xs),
+                            TextColor(theme.colors.text_accent),
+                            SettingsKeyBindingText(index),
+                        ));
+                    });
+                });
+            }
+        });
+
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|footer| {
+            footer.spawn((
+                Text::new(""),
+                text_font(theme.typography.font_size_xs),
+                TextColor(theme.colors.text_muted),
+                SettingsKeyBindingStatusText,
+            ));
+            footer
+                .spawn((
+                    Button,
+                    Node {
+                        height: Val::Px(24.0),
+                        padding: UiRect::horizontal(Val::Px(8.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        border_radius: BorderRadius::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.colors.button_default),
+                    BorderColor::all(theme.colors.dialog_border),
+                    MenuButton(MenuAction::ResetKeyBindings),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        LocalizedText("ui.settings.reset_keys"),
+                        text_font(theme.typography.font_size_xs),
+                        TextColor(theme.colors.text_primary_semantic),
+                    ));
+                });
+        });
+}
+
 fn spawn_slider_row(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    spec: SliderRowSpec<'_>,
+    spec: SliderRowSpec,
 ) {
     let SliderRowSpec {
         label,
@@ -218,7 +356,7 @@ fn spawn_slider_row(
         })
         .with_children(|row| {
             row.spawn((
-                Text::new(label),
+                LocalizedText(label),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -273,7 +411,7 @@ fn spawn_checkbox_row(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    label: &str,
+    label: &'static str,
     field: SettingsField,
     checked: bool,
 ) {
@@ -325,7 +463,7 @@ fn spawn_checkbox_row(
         });
 
         row.spawn((
-            Text::new(label),
+            LocalizedText(label),
             TextFont {
                 font: game_assets.font_ui().clone().into(),
                 font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -351,7 +489,7 @@ fn spawn_default_speed_row(
         })
         .with_children(|row| {
             row.spawn((
-                Text::new("Default Game Speed"),
+                LocalizedText("ui.settings.default_speed"),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: FontSize::Px(theme.typography.font_size_sm),
@@ -411,3 +549,69 @@ fn spawn_default_speed_row(
             });
         });
 }
+
+fn spawn_locale_row(
+    parent: &mut ChildSpawnerCommands,
+    game_assets: &dyn UiAssets,
+    theme: &UiTheme,
+    selected: Locale,
+) {
+    let text_font = TextFont {
+        font: game_assets.font_ui().clone().into(),
+        font_size: FontSize::Px(theme.typography.font_size_sm),
+        ..default()
+    };
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                LocalizedText("ui.settings.language"),
+                text_font.clone(),
+                TextColor(theme.colors.text_primary_semantic),
+            ));
+
+            row.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|locale_row| {
+                for locale in Locale::ALL {
+                    // 言語名は選択中のロケールに依らず各言語の表記で出す
+                    locale_row
+                        .spawn((
+                            Button,
+                            Node {
+                                height: Val::Px(28.0),
+                                padding: UiRect::horizontal(Val::Px(10.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(1.0)),
+                                border_radius: BorderRadius::all(Val::Px(3.0)),
+                                ..default()
+                            },
+                            BackgroundColor(if locale == selected {
+                                theme.colors.speed_button_active
+                            } else {
+                                theme.colors.button_default
+                            }),
+                            BorderColor::all(theme.colors.time_control_border),
+                            SettingsLocaleButton(locale),
+                            MenuButton(MenuAction::SetLocale(locale)),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new(locale.native_name()),
+                                text_font.clone(),
+                                TextColor(theme.colors.text_primary_semantic),
+                            ));
+                        });
+                }
+            });
+        });
+}
@@ -1,14 +1,16 @@
-//! サブメニュー UI (Architect, Zones, Orders)
+//! サブメニュー UI (Architect, Zones, Orders, Dream)
 
 use super::UiAssets;
 use crate::components::{
     ArchitectBuildingPanel, ArchitectCategoryListPanel, ArchitectSubMenu, DreamSubMenu, MenuAction,
-    MenuButton, OrdersSubMenu, UiInputBlocker, ZonesSubMenu,
+    MenuButton, OrdersSubMenu, UiInputBlocker, UiNodeRegistry, UiSlot, ZonesSubMenu,
 };
+use crate::locale::LocalizedText;
 use crate::theme::UiTheme;
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
+use hw_core::edicts::EdictKind;
 use hw_core::game_state::TaskMode;
 use hw_jobs::{BuildingCategory, BuildingType};
 use hw_logistics::zone::ZoneType;
@@ -19,21 +21,23 @@ pub fn spawn_submenus(
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
     parent_entity: Entity,
+    ui_nodes: &mut UiNodeRegistry,
 ) {
     spawn_architect_submenu(commands, game_assets, theme, parent_entity);
     spawn_zones_submenu(commands, game_assets, theme, parent_entity);
     spawn_orders_submenu(commands, game_assets, theme, parent_entity);
-    spawn_dream_submenu(commands, game_assets, theme, parent_entity);
+    spawn_dream_submenu(commands, game_assets, theme, parent_entity, ui_nodes);
 }
 
-struct MenuEntrySpec<'a> {
-    label: &'a str,
+struct MenuEntrySpec {
+    /// ボタン表示の文言 ID
+    label: &'static str,
     action: MenuAction,
     background_color: Color,
 }
 
-impl<'a> MenuEntrySpec<'a> {
-    fn new(label: &'a str, action: MenuAction, background_color: Color) -> Self {
+impl MenuEntrySpec {
+    fn new(label: &'static str, action: MenuAction, background_color: Color) -> Self {
         Self {
             label,
             action,
@@ -95,7 +99,7 @@ fn spawn_menu_entries(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    entries: Vec<MenuEntrySpec<'static>>,
+    entries: Vec<MenuEntrySpec>,
 ) {
     for entry in entries {
         spawn_menu_button(
@@ -113,7 +117,7 @@ fn spawn_menu_button(
     parent: &mut ChildSpawnerCommands,
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
-    label: &str,
+    label: &'static str,
     action: MenuAction,
     bg_color: Color,
 ) {
@@ -133,7 +137,7 @@ fn spawn_menu_button(
         ))
         .with_children(|button| {
             button.spawn((
-                Text::new(label),
+                LocalizedText(label),
                 TextFont {
                     font: game_assets.font_ui().clone().into(),
                     font_size: crate::theme::font_size_rem(theme.typography.font_size_title),
@@ -154,7 +158,7 @@ fn spawn_category_button(
         parent,
         game_assets,
         theme,
-        category.label(),
+        category.text_id(),
         MenuAction::SelectArchitectCategory(Some(category)),
         theme.colors.button_default,
     );
@@ -165,7 +169,7 @@ fn spawn_building_panel(
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
     category: BuildingCategory,
-    items: Vec<MenuEntrySpec<'static>>,
+    items: Vec<MenuEntrySpec>,
 ) {
     parent
         .spawn((
@@ -291,6 +295,7 @@ fn spawn_dream_submenu(
     game_assets: &dyn UiAssets,
     theme: &UiTheme,
     parent_entity: Entity,
+    ui_nodes: &mut UiNodeRegistry,
 ) {
     let submenu = spawn_submenu_container(
         commands,
@@ -298,7 +303,7 @@ fn spawn_dream_submenu(
         parent_entity,
         SubmenuContainerSpec {
             left: Val::Px(theme.sizes.submenu_left_dream),
-            width: Val::Px(theme.sizes.submenu_width),
+            width: Val::Px(theme.sizes.dream_submenu_width),
             flex_direction: FlexDirection::Column,
             align_items: None,
             marker: DreamSubMenu,
@@ -308,6 +313,36 @@ fn spawn_dream_submenu(
     commands.entity(submenu).with_children(|parent| {
         let entries = dream_menu_specs(theme);
         spawn_menu_entries(parent, game_assets, theme, entries);
+
+        // 勅令パネル: 発令ボタンと発令状況
+        parent.spawn((
+            LocalizedText("ui.menu.dream.edicts"),
+            TextFont {
+                font: game_assets.font_ui().clone().into(),
+                font_size: crate::theme::font_size_rem(theme.typography.font_size_status),
+                ..default()
+            },
+            TextColor(theme.colors.accent_sulfur),
+            Node {
+                margin: UiRect::vertical(Val::Px(4.0)),
+                ..default()
+            },
+        ));
+        let entries = edict_menu_specs(theme);
+        spawn_menu_entries(parent, game_assets, theme, entries);
+        let status = parent
+            .spawn((
+                Text::new(""),
+                TextFont {
+                    font: game_assets.font_ui().clone().into(),
+                    font_size: crate::theme::font_size_rem(theme.typography.font_size_small),
+                    ..default()
+                },
+                TextColor(theme.colors.text_primary_semantic),
+                UiSlot::EdictStatusText,
+            ))
+            .id();
+        ui_nodes.set_slot(UiSlot::EdictStatusText, status);
     });
 }
 
@@ -322,71 +357,89 @@ fn architect_categories() -> &'static [BuildingCategory] {
     &ARCHITECT_CATEGORIES
 }
 
-fn architect_building_specs(
-    category: BuildingCategory,
-    button_color: Color,
-) -> Vec<MenuEntrySpec<'static>> {
+fn architect_building_specs(category: BuildingCategory, button_color: Color) -> Vec<MenuEntrySpec> {
     match category {
         BuildingCategory::Structure => vec![
             MenuEntrySpec::new(
-                "Wall",
+                "ui.menu.build.wall",
                 MenuAction::SelectBuild(BuildingType::Wall),
                 button_color,
             ),
-            MenuEntrySpec::new("Floor", MenuAction::SelectFloorPlace, button_color),
             MenuEntrySpec::new(
-                "Bridge",
+                "ui.menu.build.floor",
+                MenuAction::SelectFloorPlace,
+                button_color,
+            ),
+            MenuEntrySpec::new(
+                "ui.menu.build.bridge",
                 MenuAction::SelectBuild(BuildingType::Bridge),
                 button_color,
             ),
+            MenuEntrySpec::new(
+                "ui.menu.build.layout_export",
+                MenuAction::SelectTaskMode(TaskMode::LayoutExport(None)),
+                button_color,
+            ),
+            MenuEntrySpec::new(
+                "ui.menu.build.layout_import",
+                MenuAction::SelectTaskMode(TaskMode::LayoutImport),
+                button_color,
+            ),
         ],
         BuildingCategory::Architecture => {
-            vec![MenuEntrySpec::new(
-                "Door",
-                MenuAction::SelectBuild(BuildingType::Door),
-                button_color,
-            )]
+            vec![
+                MenuEntrySpec::new(
+                    "ui.menu.build.door",
+                    MenuAction::SelectBuild(BuildingType::Door),
+                    button_color,
+                ),
+                MenuEntrySpec::new(
+                    "ui.menu.build.shrine",
+                    MenuAction::SelectBuild(BuildingType::RedemptionShrine),
+                    button_color,
+                ),
+            ]
         }
         BuildingCategory::Plant => vec![
             MenuEntrySpec::new(
-                "Tank",
+                "ui.menu.build.tank",
                 MenuAction::SelectBuild(BuildingType::Tank),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "MudMixer",
+                "ui.menu.build.mud_mixer",
                 MenuAction::SelectBuild(BuildingType::MudMixer),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "Soul Spa",
+                "ui.menu.build.soul_spa",
                 MenuAction::SelectTaskMode(TaskMode::SoulSpaPlace(None)),
                 button_color,
             ),
         ],
         BuildingCategory::Temporary => vec![
             MenuEntrySpec::new(
-                "RestArea",
+                "ui.menu.build.rest_area",
                 MenuAction::SelectBuild(BuildingType::RestArea),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "WB Parking",
+                "ui.menu.build.wheelbarrow_parking",
                 MenuAction::SelectBuild(BuildingType::WheelbarrowParking),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "SandPile",
+                "ui.menu.build.sand_pile",
                 MenuAction::SelectBuild(BuildingType::SandPile),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "BonePile",
+                "ui.menu.build.bone_pile",
                 MenuAction::SelectBuild(BuildingType::BonePile),
                 button_color,
             ),
             MenuEntrySpec::new(
-                "Outdoor Lamp",
+                "ui.menu.build.outdoor_lamp",
                 MenuAction::SelectBuild(BuildingType::OutdoorLamp),
                 button_color,
             ),
@@ -394,60 +447,83 @@ fn architect_building_specs(
     }
 }
 
-fn zones_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec<'static>> {
+fn zones_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec> {
     vec![
         MenuEntrySpec::new(
-            "Stockpile",
+            "ui.menu.zone.stockpile",
             MenuAction::SelectZone(ZoneType::Stockpile),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Yard を拡張",
+            "ui.menu.zone.yard",
             MenuAction::SelectZone(ZoneType::Yard),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Remove",
+            "ui.menu.zone.forbidden",
+            MenuAction::SelectZone(ZoneType::Forbidden),
+            theme.colors.button_default,
+        ),
+        MenuEntrySpec::new(
+            "ui.menu.zone.remove_forbidden",
+            MenuAction::RemoveZone(ZoneType::Forbidden),
+            theme.colors.status_danger,
+        ),
+        MenuEntrySpec::new(
+            "ui.menu.zone.remove_stockpile",
             MenuAction::RemoveZone(ZoneType::Stockpile),
             theme.colors.status_danger,
         ),
     ]
 }
 
-fn orders_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec<'static>> {
+fn orders_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec> {
     vec![
         MenuEntrySpec::new(
-            "Chop",
+            "ui.menu.orders.chop",
             MenuAction::SelectTaskMode(TaskMode::DesignateChop(None)),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Mine",
+            "ui.menu.orders.mine",
             MenuAction::SelectTaskMode(TaskMode::DesignateMine(None)),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Haul",
+            "ui.menu.orders.haul",
             MenuAction::SelectTaskMode(TaskMode::DesignateHaul(None)),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Cancel",
+            "ui.menu.orders.cancel",
             MenuAction::SelectTaskMode(TaskMode::CancelDesignation(None)),
             theme.colors.button_default,
         ),
         MenuEntrySpec::new(
-            "Area",
+            "ui.menu.orders.area",
             MenuAction::SelectAreaTask,
             theme.colors.button_default,
         ),
     ]
 }
 
-fn dream_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec<'static>> {
+fn dream_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec> {
     vec![MenuEntrySpec::new(
-        "Plant Trees",
+        "ui.menu.dream.plant_trees",
         MenuAction::SelectDreamPlanting,
         theme.colors.button_default,
     )]
 }
+
+fn edict_menu_specs(theme: &UiTheme) -> Vec<MenuEntrySpec> {
+    EdictKind::ALL
+        .into_iter()
+        .map(|kind| {
+            MenuEntrySpec::new(
+                kind.text_id(),
+                MenuAction::ToggleEdict(kind),
+                theme.colors.button_default,
+            )
+        })
+        .collect()
+}
@@ -7,6 +7,7 @@ use crate::components::{
     DreamPoolPulse, MenuAction, MenuButton, SpeedButtonMarker, UiInputBlocker, UiNodeRegistry,
     UiSlot, UiTooltip,
 };
+use crate::locale::LocalizedTooltip;
 use crate::theme::UiTheme;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
@@ -70,10 +71,10 @@ pub fn spawn_time_control(
             })
             .with_children(|speed_row| {
                 let speeds = [
-                    (TimeSpeed::Paused, "||", "一時停止", "1"),
-                    (TimeSpeed::Normal, ">", "通常速度 (x1)", "2"),
-                    (TimeSpeed::Fast, ">>", "高速 (x2)", "3"),
-                    (TimeSpeed::Super, ">>>", "超高速 (x4)", "4"),
+                    (TimeSpeed::Paused, "||", "ui.time.paused.tooltip", "1"),
+                    (TimeSpeed::Normal, ">", "ui.time.normal.tooltip", "2"),
+                    (TimeSpeed::Fast, ">>", "ui.time.fast.tooltip", "3"),
+                    (TimeSpeed::Super, ">>>", "ui.time.super.tooltip", "4"),
                 ];
 
                 for (speed, label, tooltip, shortcut) in speeds {
@@ -93,7 +94,8 @@ pub fn spawn_time_control(
                             BorderColor::all(Color::NONE),
                             MenuButton(MenuAction::SetTimeSpeed(speed)),
                             SpeedButtonMarker(speed),
-                            UiTooltip::with_shortcut(tooltip, shortcut),
+                            LocalizedTooltip(tooltip),
+                            UiTooltip::with_shortcut(String::new(), shortcut),
                         ))
                         .with_children(|btn| {
                             btn.spawn((
@@ -140,6 +142,25 @@ pub fn spawn_time_control(
             .id();
         ui_nodes.set_slot(UiSlot::TaskSummaryText, task_text_entity);
 
+        // ── Population ──
+        let population_text_entity = panel
+            .spawn((
+                Text::new("Souls: 0/0 | Redeemed: 0 | Escaped: 0"),
+                TextFont {
+                    font: game_assets.font_ui().clone().into(),
+                    font_size: crate::theme::font_size_rem(theme.typography.font_size_status),
+                    ..default()
+                },
+                TextColor(theme.colors.panel_accent_time_control),
+                UiSlot::PopulationText,
+                Node {
+                    margin: UiRect::bottom(Val::Px(4.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        ui_nodes.set_slot(UiSlot::PopulationText, population_text_entity);
+
         // ── Dream Pool ──
         panel
             .spawn((Node {
@@ -0,0 +1,18 @@
+//! コロニー統計グラフのパネル
+//!
+//! `hw_core::statistics::ColonyStatistics` の履歴から選択中のグラフを CPU で折れ線画像へ描き、
+//! 凡例に最新値を表示する。サンプルの収集は root の統計システムが行う。
+
+mod model;
+mod ui;
+
+pub use model::{
+    ChartSeries, ChartView, STATISTICS_CHART_HEIGHT, STATISTICS_CHART_WIDTH, STATISTICS_MAX_SERIES,
+    StatisticsChart, StatisticsPanelState, StatisticsRange, build_chart_view, render_chart_rgba,
+};
+pub(crate) use ui::spawn_statistics_panel;
+pub use ui::{
+    StatisticsAxisText, StatisticsChartButton, StatisticsChartImage, StatisticsLegendEntry,
+    StatisticsPanelRoot, StatisticsRangeButton, render_statistics_chart_system,
+    statistics_panel_intent_system, statistics_panel_interaction_system,
+};
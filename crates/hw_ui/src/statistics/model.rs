@@ -0,0 +1,416 @@
+use bevy::prelude::*;
+use hw_core::jobs::WorkType;
+use hw_core::locale::Localization;
+use hw_core::logistics::ResourceType;
+use hw_core::statistics::ColonySample;
+
+/// グラフ画像の幅（ピクセル）
+pub const STATISTICS_CHART_WIDTH: u32 = 320;
+/// グラフ画像の高さ（ピクセル）
+pub const STATISTICS_CHART_HEIGHT: u32 = 120;
+/// 1 つのグラフに描く系列数の上限
+pub const STATISTICS_MAX_SERIES: usize = 6;
+
+const BACKGROUND: [u8; 4] = [14, 12, 16, 230];
+const GRID: [u8; 4] = [60, 56, 64, 255];
+
+const SERIES_COLORS: [[u8; 3]; STATISTICS_MAX_SERIES] = [
+    [235, 120, 70],
+    [110, 190, 235],
+    [150, 220, 110],
+    [235, 205, 90],
+    [200, 130, 225],
+    [225, 225, 225],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatisticsChart {
+    Population,
+    Dream,
+    Resources,
+    Tasks,
+    Power,
+    Vitals,
+}
+
+impl StatisticsChart {
+    pub const ALL: [Self; 6] = [
+        Self::Population,
+        Self::Dream,
+        Self::Resources,
+        Self::Tasks,
+        Self::Power,
+        Self::Vitals,
+    ];
+
+    /// ボタン表示の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::Population => "ui.statistics.chart.population",
+            Self::Dream => "ui.statistics.chart.dream",
+            Self::Resources => "ui.statistics.chart.resources",
+            Self::Tasks => "ui.statistics.chart.tasks",
+            Self::Power => "ui.statistics.chart.power",
+            Self::Vitals => "ui.statistics.chart.vitals",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatisticsRange {
+    TenMinutes,
+    OneHour,
+    All,
+}
+
+impl StatisticsRange {
+    pub const ALL: [Self; 3] = [Self::TenMinutes, Self::OneHour, Self::All];
+
+    /// ボタン表示の文言 ID
+    pub const fn text_id(self) -> &'static str {
+        match self {
+            Self::TenMinutes => "ui.statistics.range.ten_minutes",
+            Self::OneHour => "ui.statistics.range.one_hour",
+            Self::All => "ui.statistics.range.all",
+        }
+    }
+
+    /// 表示する期間（ゲーム内秒）。`None` は履歴全体。
+    pub const fn seconds(self) -> Option<f32> {
+        match self {
+            Self::TenMinutes => Some(600.0),
+            Self::OneHour => Some(3600.0),
+            Self::All => None,
+        }
+    }
+}
+
+/// 1 本の折れ線
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    pub color: Color,
+    rgb: [u8; 3],
+    /// (ゲーム内秒, 値)
+    pub points: Vec<(f32, f32)>,
+}
+
+impl ChartSeries {
+    pub fn latest(&self) -> Option<f32> {
+        self.points.last().map(|(_, value)| *value)
+    }
+
+    fn peak(&self) -> f32 {
+        self.points
+            .iter()
+            .map(|(_, value)| *value)
+            .fold(0.0, f32::max)
+    }
+}
+
+/// 描画対象の系列と軸範囲
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartView {
+    pub series: Vec<ChartSeries>,
+    pub start: f32,
+    pub end: f32,
+    pub y_max: f32,
+}
+
+type SampleValue = Box<dyn Fn(&ColonySample) -> f32>;
+
+fn candidates(chart: StatisticsChart, text: &Localization) -> Vec<(String, SampleValue)> {
+    let entry = |id: &str, value: SampleValue| (text.text(id).to_string(), value);
+    match chart {
+        StatisticsChart::Population => vec![
+            entry(
+                "ui.statistics.series.population",
+                Box::new(|s| s.population as f32),
+            ),
+            entry(
+                "ui.statistics.series.population_cap",
+                Box::new(|s| s.population_cap as f32),
+            ),
+            entry(
+                "ui.statistics.series.escaped",
+                Box::new(|s| s.escaped as f32),
+            ),
+        ],
+        StatisticsChart::Dream => vec![entry(
+            "ui.statistics.series.dream_pool",
+            Box::new(|s| s.dream_points),
+        )],
+        StatisticsChart::Resources => ResourceType::ALL
+            .into_iter()
+            .map(|kind| entry(kind.text_id(), Box::new(move |s| s.stocked(kind) as f32)))
+            .collect(),
+        StatisticsChart::Tasks => std::iter::once(entry(
+            "ui.statistics.series.tasks_total",
+            Box::new(|s: &ColonySample| s.completed_total() as f32),
+        ))
+        .chain(WorkType::ALL.into_iter().map(|kind| {
+            entry(
+                kind.text_id(),
+                Box::new(move |s: &ColonySample| s.completed(kind) as f32),
+            )
+        }))
+        .collect(),
+        StatisticsChart::Power => vec![
+            entry(
+                "ui.statistics.series.power_generation",
+                Box::new(|s| s.power_generation),
+            ),
+            entry(
+                "ui.statistics.series.power_consumption",
+                Box::new(|s| s.power_consumption),
+            ),
+        ],
+        StatisticsChart::Vitals => vec![
+            entry("ui.statistics.series.stress", Box::new(|s| s.vitals.stress)),
+            entry(
+                "ui.statistics.series.fatigue",
+                Box::new(|s| s.vitals.fatigue),
+            ),
+            entry(
+                "ui.statistics.series.motivation",
+                Box::new(|s| s.vitals.motivation),
+            ),
+            entry(
+                "ui.statistics.series.laziness",
+                Box::new(|s| s.vitals.laziness),
+            ),
+        ],
+    }
+}
+
+/// 選択中のグラフと期間から描画対象を組み立てる。
+///
+/// 備蓄と作業は期間内ずっと 0 の系列を省き、ピークの大きい順に `STATISTICS_MAX_SERIES` 本まで残す。
+/// 作業グラフの「合計」は常に先頭に置く。
+pub fn build_chart_view(
+    chart: StatisticsChart,
+    range: StatisticsRange,
+    samples: &[ColonySample],
+    text: &Localization,
+) -> ChartView {
+    let end = samples.last().map_or(0.0, |sample| sample.time);
+    let start = match range.seconds() {
+        Some(seconds) => end - seconds,
+        None => samples.first().map_or(0.0, |sample| sample.time),
+    };
+    let first = samples.partition_point(|sample| sample.time < start);
+    let visible = &samples[first..];
+
+    let mut series: Vec<ChartSeries> = candidates(chart, text)
+        .into_iter()
+        .map(|(label, value)| ChartSeries {
+            label,
+            color: Color::WHITE,
+            rgb: [0; 3],
+            points: visible
+                .iter()
+                .map(|sample| (sample.time, value(sample)))
+                .collect(),
+        })
+        .collect();
+    if matches!(chart, StatisticsChart::Resources | StatisticsChart::Tasks) {
+        let pinned = usize::from(chart == StatisticsChart::Tasks);
+        let mut rest = series.split_off(pinned);
+        rest.retain(|entry| entry.peak() > 0.0);
+        rest.sort_by(|a, b| b.peak().total_cmp(&a.peak()));
+        series.extend(rest);
+        series.truncate(STATISTICS_MAX_SERIES);
+    }
+    for (entry, rgb) in series.iter_mut().zip(SERIES_COLORS) {
+        entry.rgb = rgb;
+        entry.color = Color::srgb_u8(rgb[0], rgb[1], rgb[2]);
+    }
+
+    let y_max = if chart == StatisticsChart::Vitals {
+        1.0
+    } else {
+        series
+            .iter()
+            .map(ChartSeries::peak)
+            .fold(0.0, f32::max)
+            .max(1.0)
+    };
+    ChartView {
+        series,
+        start,
+        end,
+        y_max,
+    }
+}
+
+fn put_pixel(rgba: &mut [u8], x: i32, y: i32, color: [u8; 4]) {
+    let (width, height) = (
+        STATISTICS_CHART_WIDTH as i32,
+        STATISTICS_CHART_HEIGHT as i32,
+    );
+    if x < 0 || y < 0 || x >= width || y >= height {
+        return;
+    }
+    let offset = ((y * width + x) * 4) as usize;
+    rgba[offset..offset + 4].copy_from_slice(&color);
+}
+
+fn draw_line(rgba: &mut [u8], from: IVec2, to: IVec2, color: [u8; 4]) {
+    let delta = (to - from).abs();
+    let step = IVec2::new((to.x - from.x).signum(), (to.y - from.y).signum());
+    let mut current = from;
+    let mut error = delta.x - delta.y;
+    loop {
+        put_pixel(rgba, current.x, current.y, color);
+        if current == to {
+            break;
+        }
+        let doubled = error * 2;
+        if doubled > -delta.y {
+            error -= delta.y;
+            current.x += step.x;
+        }
+        if doubled < delta.x {
+            error += delta.x;
+            current.y += step.y;
+        }
+    }
+}
+
+/// 折れ線グラフを RGBA8 画像として描く（上端が `y_max`）。
+pub fn render_chart_rgba(view: &ChartView) -> Vec<u8> {
+    let (width, height) = (
+        STATISTICS_CHART_WIDTH as i32,
+        STATISTICS_CHART_HEIGHT as i32,
+    );
+    let mut rgba = BACKGROUND.repeat((width * height) as usize);
+    for quarter in 1..4 {
+        let y = height * quarter / 4;
+        for x in 0..width {
+            put_pixel(&mut rgba, x, y, GRID);
+        }
+    }
+
+    let span = (view.end - view.start).max(f32::EPSILON);
+    let to_pixel = |(time, value): (f32, f32)| {
+        let x = ((time - view.start) / span * (width - 1) as f32).round() as i32;
+        let y = (value / view.y_max).clamp(0.0, 1.0) * (height - 1) as f32;
+        IVec2::new(x.clamp(0, width - 1), height - 1 - y.round() as i32)
+    };
+    for series in view.series.iter().rev() {
+        let color = [series.rgb[0], series.rgb[1], series.rgb[2], 255];
+        let mut points = series.points.iter().copied().map(to_pixel);
+        let Some(mut previous) = points.next() else {
+            continue;
+        };
+        put_pixel(&mut rgba, previous.x, previous.y, color);
+        for point in points {
+            draw_line(&mut rgba, previous, point, color);
+            previous = point;
+        }
+    }
+    rgba
+}
+
+/// 統計パネルの表示状態
+#[derive(Resource, Debug)]
+pub struct StatisticsPanelState {
+    pub open: bool,
+    pub chart: StatisticsChart,
+    pub range: StatisticsRange,
+    pub(super) texture: Option<Handle<Image>>,
+    /// 最後に描いた (グラフ, 期間, サンプル数, 最新時刻)
+    pub(super) drawn: Option<(StatisticsChart, StatisticsRange, usize, f32)>,
+}
+
+impl Default for StatisticsPanelState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            chart: StatisticsChart::Population,
+            range: StatisticsRange::OneHour,
+            texture: None,
+            drawn: None,
+        }
+    }
+}
+
+impl StatisticsPanelState {
+    /// ロード後に必ず描き直すよう描画済みの記録を捨てる。
+    pub fn invalidate(&mut self) {
+        self.drawn = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hw_core::statistics::{ResourceStock, WorkCompletions};
+
+    fn sample(time: f32, wood: u32, chopped: u32) -> ColonySample {
+        ColonySample {
+            time,
+            population: 4,
+            stocked: vec![ResourceStock {
+                resource: ResourceType::Wood,
+                count: wood,
+            }],
+            completed: vec![WorkCompletions {
+                work_type: WorkType::Chop,
+                count: chopped,
+            }],
+            ..default()
+        }
+    }
+
+    #[test]
+    fn ranges_clip_samples_and_sparse_series_are_dropped() {
+        let samples: Vec<_> = (0..=40)
+            .map(|step| sample(step as f32 * 30.0, step, 1))
+            .collect();
+
+        let text = Localization::default();
+        let view = build_chart_view(
+            StatisticsChart::Resources,
+            StatisticsRange::TenMinutes,
+            &samples,
+            &text,
+        );
+        assert_eq!(view.end - view.start, 600.0);
+        assert_eq!(view.series.len(), 1);
+        assert_eq!(view.series[0].label, "Wood");
+        assert_eq!(view.series[0].points.len(), 21);
+        assert_eq!(view.y_max, 40.0);
+
+        let tasks = build_chart_view(
+            StatisticsChart::Tasks,
+            StatisticsRange::All,
+            &samples,
+            &text,
+        );
+        let labels: Vec<_> = tasks.series.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, ["合計", "伐採"]);
+        assert_eq!(tasks.start, 0.0);
+    }
+
+    #[test]
+    fn rendered_chart_draws_series_pixels_over_the_background() {
+        let samples = [sample(0.0, 0, 0), sample(30.0, 10, 0)];
+        let view = build_chart_view(
+            StatisticsChart::Resources,
+            StatisticsRange::All,
+            &samples,
+            &Localization::default(),
+        );
+        let rgba = render_chart_rgba(&view);
+        assert_eq!(
+            rgba.len(),
+            (STATISTICS_CHART_WIDTH * STATISTICS_CHART_HEIGHT * 4) as usize
+        );
+        let top_right = ((STATISTICS_CHART_WIDTH - 1) * 4) as usize;
+        assert_eq!(&rgba[top_right..top_right + 3], &SERIES_COLORS[0]);
+        let bottom_left = ((STATISTICS_CHART_HEIGHT - 1) * STATISTICS_CHART_WIDTH * 4) as usize;
+        assert_eq!(&rgba[bottom_left..bottom_left + 3], &SERIES_COLORS[0]);
+        assert_eq!(&rgba[4..8], &BACKGROUND);
+    }
+}
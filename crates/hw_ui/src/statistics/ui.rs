@@ -0,0 +1,408 @@
+use super::model::{
+    STATISTICS_CHART_HEIGHT, STATISTICS_CHART_WIDTH, STATISTICS_MAX_SERIES, StatisticsChart,
+    StatisticsPanelState, StatisticsRange, build_chart_view, render_chart_rgba,
+};
+use crate::UiIntent;
+use crate::components::UiInputBlocker;
+use crate::locale::LocalizedText;
+use crate::theme::{UiTheme, font_size_rem};
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::FocusPolicy;
+use hw_core::locale::Localization;
+use hw_core::statistics::ColonyStatistics;
+
+/// グラフの表示幅（論理ピクセル）
+const STATISTICS_DISPLAY_WIDTH: f32 = 320.0;
+/// グラフの表示高さ（論理ピクセル）
+const STATISTICS_DISPLAY_HEIGHT: f32 = 120.0;
+/// ミニマップ（表示 200px + 余白）の左に並べるための右端オフセット
+const STATISTICS_RIGHT_OFFSET: f32 = 220.0;
+
+#[derive(Component)]
+pub struct StatisticsPanelRoot;
+
+#[derive(Component)]
+pub struct StatisticsChartImage;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct StatisticsChartButton(pub StatisticsChart);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct StatisticsRangeButton(pub StatisticsRange);
+
+/// 縦軸の最大値と表示期間
+#[derive(Component)]
+pub struct StatisticsAxisText;
+
+/// 凡例の 1 行（系列の並び順）
+#[derive(Component, Clone, Copy, Debug)]
+pub struct StatisticsLegendEntry(pub usize);
+
+fn spawn_button_row(
+    commands: &mut Commands,
+    font: &Handle<Font>,
+    theme: &UiTheme,
+    buttons: impl IntoIterator<Item = (&'static str, impl Bundle)>,
+) -> Entity {
+    let row = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(4.0),
+            ..default()
+        })
+        .id();
+    for (text_id, marker) in buttons {
+        let button = commands
+            .spawn((
+                Button,
+                Node {
+                    flex_grow: 1.0,
+                    height: Val::Px(22.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    border_radius: BorderRadius::all(Val::Px(3.0)),
+                    ..default()
+                },
+                BackgroundColor(theme.colors.button_default),
+                BorderColor::all(theme.colors.border_default),
+                marker,
+                Name::new(format!("Statistics Button {text_id}")),
+            ))
+            .id();
+        commands.entity(button).with_children(|button| {
+            button.spawn((
+                LocalizedText(text_id),
+                TextFont {
+                    font: font.clone().into(),
+                    font_size: font_size_rem(theme.typography.font_size_xs),
+                    ..default()
+                },
+                TextColor(theme.colors.text_primary_semantic),
+            ));
+        });
+        commands.entity(row).add_child(button);
+    }
+    row
+}
+
+pub(crate) fn spawn_statistics_panel(
+    commands: &mut Commands,
+    font: Handle<Font>,
+    theme: &UiTheme,
+    parent: Entity,
+) {
+    let text_font = TextFont {
+        font: font.clone().into(),
+        font_size: font_size_rem(theme.typography.font_size_xs),
+        ..default()
+    };
+    let root = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                right: Val::Px(theme.spacing.panel_margin_x + STATISTICS_RIGHT_OFFSET),
+                bottom: Val::Px(theme.spacing.bottom_bar_height + theme.spacing.panel_padding),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(theme.sizes.panel_border_width)),
+                border_radius: BorderRadius::all(Val::Px(theme.sizes.panel_corner_radius)),
+                ..default()
+            },
+            BackgroundColor(theme.colors.bg_overlay),
+            BorderColor::all(theme.colors.border_default),
+            UiInputBlocker,
+            FocusPolicy::Block,
+            StatisticsPanelRoot,
+            Name::new("Statistics Panel"),
+        ))
+        .id();
+
+    let title = commands
+        .spawn((
+            LocalizedText("ui.statistics.title"),
+            text_font.clone(),
+            TextColor(theme.colors.text_primary_semantic),
+        ))
+        .id();
+    let chart_row = spawn_button_row(
+        commands,
+        &font,
+        theme,
+        StatisticsChart::ALL.map(|chart| (chart.text_id(), StatisticsChartButton(chart))),
+    );
+    let range_row = spawn_button_row(
+        commands,
+        &font,
+        theme,
+        StatisticsRange::ALL.map(|range| (range.text_id(), StatisticsRangeButton(range))),
+    );
+    let image = commands
+        .spawn((
+            Node {
+                width: Val::Px(STATISTICS_DISPLAY_WIDTH),
+                height: Val::Px(STATISTICS_DISPLAY_HEIGHT),
+                ..default()
+            },
+            ImageNode::default(),
+            StatisticsChartImage,
+            Name::new("Statistics Chart"),
+        ))
+        .id();
+    let axis = commands
+        .spawn((
+            Text::default(),
+            text_font.clone(),
+            TextColor(theme.colors.text_primary_semantic),
+            StatisticsAxisText,
+        ))
+        .id();
+    let legend = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(8.0),
+            max_width: Val::Px(STATISTICS_DISPLAY_WIDTH),
+            ..default()
+        })
+        .id();
+    for slot in 0..STATISTICS_MAX_SERIES {
+        let entry = commands
+            .spawn((
+                Text::default(),
+                text_font.clone(),
+                TextColor(theme.colors.text_primary_semantic),
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                StatisticsLegendEntry(slot),
+            ))
+            .id();
+        commands.entity(legend).add_child(entry);
+    }
+
+    commands
+        .entity(root)
+        .add_children(&[title, chart_row, range_row, image, axis, legend]);
+    commands.entity(parent).add_child(root);
+}
+
+/// `UiIntent::ToggleStatistics` でパネルを開閉する。
+pub fn statistics_panel_intent_system(
+    mut intents: MessageReader<UiIntent>,
+    mut state: ResMut<StatisticsPanelState>,
+    mut q_root: Query<&mut Node, With<StatisticsPanelRoot>>,
+) {
+    for intent in intents.read() {
+        if matches!(intent, UiIntent::ToggleStatistics) {
+            state.open = !state.open;
+        }
+    }
+    let display = if state.open {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut node in &mut q_root {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+fn selection_button_color(interaction: Interaction, selected: bool, theme: &UiTheme) -> Color {
+    match (interaction, selected) {
+        (Interaction::Hovered, _) => theme.colors.button_hover,
+        (_, true) => theme.colors.button_pressed,
+        _ => theme.colors.button_default,
+    }
+}
+
+/// グラフ種別・期間ボタンの選択を反映する。
+pub fn statistics_panel_interaction_system(
+    mut state: ResMut<StatisticsPanelState>,
+    theme: Res<UiTheme>,
+    mut q_chart_buttons: Query<
+        (&Interaction, &StatisticsChartButton, &mut BackgroundColor),
+        Without<StatisticsRangeButton>,
+    >,
+    mut q_range_buttons: Query<
+        (&Interaction, &StatisticsRangeButton, &mut BackgroundColor),
+        Without<StatisticsChartButton>,
+    >,
+) {
+    for (interaction, button, _) in &q_chart_buttons {
+        if *interaction == Interaction::Pressed && state.chart != button.0 {
+            state.chart = button.0;
+        }
+    }
+    for (interaction, button, _) in &q_range_buttons {
+        if *interaction == Interaction::Pressed && state.range != button.0 {
+            state.range = button.0;
+        }
+    }
+    for (interaction, button, mut color) in &mut q_chart_buttons {
+        let next = selection_button_color(*interaction, state.chart == button.0, &theme);
+        color.set_if_neq(BackgroundColor(next));
+    }
+    for (interaction, button, mut color) in &mut q_range_buttons {
+        let next = selection_button_color(*interaction, state.range == button.0, &theme);
+        color.set_if_neq(BackgroundColor(next));
+    }
+}
+
+fn new_statistics_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: STATISTICS_CHART_WIDTH,
+            height: STATISTICS_CHART_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+fn format_span(text: &Localization, seconds: f32) -> String {
+    let minutes = (seconds / 60.0).round() as u32;
+    if minutes >= 60 {
+        text.format(
+            "ui.statistics.span_hours",
+            &[("hours", &(minutes / 60)), ("minutes", &(minutes % 60))],
+        )
+    } else {
+        text.format("ui.statistics.span_minutes", &[("minutes", &minutes)])
+    }
+}
+
+/// パネル表示中、グラフ・期間・サンプルが変わったときだけグラフと凡例を描き直す。
+pub fn render_statistics_chart_system(
+    mut state: ResMut<StatisticsPanelState>,
+    statistics: Option<Res<ColonyStatistics>>,
+    localization: Res<Localization>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut q_image: Query<&mut ImageNode, With<StatisticsChartImage>>,
+    mut q_axis: Query<&mut Text, (With<StatisticsAxisText>, Without<StatisticsLegendEntry>)>,
+    mut q_legend: Query<(&StatisticsLegendEntry, &mut Text, &mut TextColor, &mut Node)>,
+) {
+    if !state.open {
+        return;
+    }
+    let (Some(statistics), Some(mut images)) = (statistics, images) else {
+        return;
+    };
+    let samples = statistics.samples();
+    let key = (
+        state.chart,
+        state.range,
+        samples.len(),
+        samples.last().map_or(0.0, |sample| sample.time),
+    );
+    if state.drawn == Some(key) && !localization.is_changed() {
+        return;
+    }
+    state.drawn = Some(key);
+
+    let handle = state
+        .texture
+        .get_or_insert_with(|| images.add(new_statistics_image()))
+        .clone();
+    for mut image_node in &mut q_image {
+        if image_node.image != handle {
+            image_node.image = handle.clone();
+        }
+    }
+    let view = build_chart_view(state.chart, state.range, samples, &localization);
+    if let Some(mut image) = images.get_mut(&handle) {
+        image.data = Some(render_chart_rgba(&view));
+    }
+
+    for mut text in &mut q_axis {
+        text.0 = if samples.is_empty() {
+            localization.text("ui.statistics.empty").to_string()
+        } else {
+            localization.format(
+                "ui.statistics.axis",
+                &[
+                    ("max", &format!("{:.1}", view.y_max)),
+                    ("span", &format_span(&localization, view.end - view.start)),
+                ],
+            )
+        };
+    }
+    for (entry, mut text, mut color, mut node) in &mut q_legend {
+        let Some(series) = view.series.get(entry.0) else {
+            node.display = Display::None;
+            continue;
+        };
+        node.display = Display::Flex;
+        color.0 = series.color;
+        text.0 = match series.latest() {
+            Some(value) => format!("■ {} {value:.1}", series.label),
+            None => format!("■ {}", series.label),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_intent_opens_the_panel_and_buttons_select_chart_and_range() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<UiIntent>()
+            .init_resource::<UiTheme>()
+            .init_resource::<StatisticsPanelState>()
+            .add_systems(
+                Update,
+                (
+                    statistics_panel_intent_system,
+                    statistics_panel_interaction_system,
+                ),
+            );
+        let root = app
+            .world_mut()
+            .spawn((
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                StatisticsPanelRoot,
+            ))
+            .id();
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            BackgroundColor::default(),
+            StatisticsChartButton(StatisticsChart::Power),
+        ));
+        app.world_mut().spawn((
+            Interaction::Pressed,
+            BackgroundColor::default(),
+            StatisticsRangeButton(StatisticsRange::All),
+        ));
+        app.world_mut().write_message(UiIntent::ToggleStatistics);
+
+        app.update();
+
+        let state = app.world().resource::<StatisticsPanelState>();
+        assert!(state.open);
+        assert_eq!(state.chart, StatisticsChart::Power);
+        assert_eq!(state.range, StatisticsRange::All);
+        assert_eq!(
+            app.world().get::<Node>(root).unwrap().display,
+            Display::Flex
+        );
+    }
+}
@@ -195,6 +195,7 @@ pub struct ThemeSizes {
     pub submenu_left_zones: f32,
     pub submenu_left_orders: f32,
     pub submenu_left_dream: f32,
+    pub dream_submenu_width: f32,
     pub time_control_top: f32,
     pub fps_left: f32,
     pub fps_top: f32,
@@ -388,6 +389,7 @@ impl Default for UiTheme {
                 submenu_left_zones: 110.0,
                 submenu_left_orders: 220.0,
                 submenu_left_dream: 330.0,
+                dream_submenu_width: 240.0,
                 time_control_top: 20.0,
                 fps_left: 20.0,
                 fps_top: 20.0,
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use hw_core::constants::{TILE_SIZE, Z_FORBIDDEN_ZONE_OVERLAY};
+use hw_world::{WorldMap, WorldMapRead};
+use std::collections::HashSet;
+
+/// 禁止区域タイル 1 枚分のオーバーレイ
+#[derive(Component)]
+pub struct ForbiddenZoneTileVisual {
+    pub grid: (i32, i32),
+}
+
+const FORBIDDEN_ZONE_COLOR: Color = Color::srgba(0.9, 0.12, 0.1, 0.28);
+
+/// `WorldMap::forbidden_tiles` とオーバーレイの差分だけを spawn / despawn する。
+pub fn sync_forbidden_zone_overlay_system(
+    mut commands: Commands,
+    world_map: WorldMapRead,
+    q_existing: Query<(Entity, &ForbiddenZoneTileVisual)>,
+) {
+    if !world_map.is_changed() {
+        return;
+    }
+
+    let mut shown = HashSet::new();
+    for (entity, visual) in q_existing.iter() {
+        if world_map.is_forbidden(visual.grid) && shown.insert(visual.grid) {
+            continue;
+        }
+        commands.entity(entity).despawn();
+    }
+
+    for &(gx, gy) in world_map.forbidden_tile_entries() {
+        if shown.contains(&(gx, gy)) {
+            continue;
+        }
+        let pos = WorldMap::grid_to_world(gx, gy);
+        commands.spawn((
+            ForbiddenZoneTileVisual { grid: (gx, gy) },
+            Sprite {
+                color: FORBIDDEN_ZONE_COLOR,
+                custom_size: Some(Vec2::splat(TILE_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, Z_FORBIDDEN_ZONE_OVERLAY),
+            Name::new("ForbiddenZoneTile"),
+        ));
+    }
+}
@@ -5,6 +5,7 @@ pub mod fade;
 pub mod familiar;
 pub mod floating_text;
 pub mod floor_construction;
+pub mod forbidden_zone_visual;
 pub mod gather;
 pub mod handles;
 pub mod haul;
@@ -25,7 +26,7 @@ pub mod wall_connection;
 pub mod wall_construction;
 pub mod worker_icon;
 
-pub use selection_indicator::update_selection_indicator;
+pub use selection_indicator::{update_selection_box_visual, update_selection_indicator};
 pub use speech::familiar_idle_visual_apply_system;
 pub use speech::max_soul_visual_system;
 pub use speech::squad_visual_system;
@@ -121,6 +122,7 @@ impl Plugin for HwVisualPlugin {
                 material::sync_terrain_feature_lut_uniforms_system,
                 wall_connection::wall_connections_system,
                 site_yard_visual::sync_site_yard_boundaries_system,
+                forbidden_zone_visual::sync_forbidden_zone_overlay_system,
             )
                 .in_set(GameSystemSet::Visual),
         );
@@ -339,6 +341,9 @@ fn collect_transient_visual_entities(world: &mut World) -> HashSet<Entity> {
     let mut entities: HashSet<_> = query.iter(world).collect();
     let mut floating_text = world.query_filtered::<Entity, With<floating_text::FloatingText>>();
     entities.extend(floating_text.iter(world));
+    let mut forbidden_tiles =
+        world.query_filtered::<Entity, With<forbidden_zone_visual::ForbiddenZoneTileVisual>>();
+    entities.extend(forbidden_tiles.iter(world));
     entities
 }
 
@@ -1,40 +1,73 @@
 use bevy::prelude::*;
 use hw_core::constants::*;
-use hw_core::selection::{SelectedEntity, SelectionIndicator};
+use hw_core::selection::{
+    SelectedEntities, SelectedEntity, SelectionBoxDrag, SelectionBoxVisual, SelectionIndicator,
+};
 
+/// 選択中エンティティ（複数選択時は全員）の足元にハイライトを置く。
 pub fn update_selection_indicator(
     selected: Res<SelectedEntity>,
+    selected_entities: Res<SelectedEntities>,
     mut q_indicator: Query<(Entity, &mut Transform), With<SelectionIndicator>>,
     q_transforms: Query<&GlobalTransform>,
     mut commands: Commands,
 ) {
-    if let Some(entity) = selected.0 {
-        if let Ok(target_transform) = q_transforms.get(entity) {
-            if let Ok((_, mut indicator_transform)) = q_indicator.single_mut() {
-                indicator_transform.translation = target_transform
-                    .translation()
-                    .truncate()
-                    .extend(Z_SELECTION);
-            } else {
-                commands.spawn((
-                    SelectionIndicator,
-                    Sprite {
-                        color: Color::srgba(1.0, 1.0, 0.4, 1.0),
-                        custom_size: Some(Vec2::splat(TILE_SIZE * 1.1)),
-                        ..default()
-                    },
-                    Transform::from_translation(
-                        target_transform
-                            .translation()
-                            .truncate()
-                            .extend(Z_SELECTION),
-                    ),
-                ));
-            }
+    let single = selected.0.filter(|_| selected_entities.is_empty());
+    let targets: Vec<Vec3> = selected_entities
+        .iter()
+        .chain(single)
+        .filter_map(|entity| q_transforms.get(entity).ok())
+        .map(|transform| transform.translation().truncate().extend(Z_SELECTION))
+        .collect();
+
+    let mut indicators = q_indicator.iter_mut();
+    for translation in &targets {
+        if let Some((_, mut indicator_transform)) = indicators.next() {
+            indicator_transform.translation = *translation;
+        } else {
+            commands.spawn((
+                SelectionIndicator,
+                Sprite {
+                    color: Color::srgba(1.0, 1.0, 0.4, 1.0),
+                    custom_size: Some(Vec2::splat(TILE_SIZE * 1.1)),
+                    ..default()
+                },
+                Transform::from_translation(*translation),
+            ));
         }
-    } else {
-        for (indicator_entity, _) in q_indicator.iter() {
-            commands.entity(indicator_entity).despawn();
+    }
+    for (indicator_entity, _) in indicators {
+        commands.entity(indicator_entity).despawn();
+    }
+}
+
+/// 範囲選択ドラッグ中の矩形を半透明スプライトで表示する。
+pub fn update_selection_box_visual(
+    drag: Res<SelectionBoxDrag>,
+    mut q_visual: Query<(Entity, &mut Transform, &mut Sprite), With<SelectionBoxVisual>>,
+    mut commands: Commands,
+) {
+    let Some((min, max)) = drag.bounds() else {
+        for (entity, _, _) in q_visual.iter() {
+            commands.entity(entity).despawn();
         }
+        return;
+    };
+
+    let center = ((min + max) / 2.0).extend(Z_SELECTION);
+    let size = (max - min).max(Vec2::splat(1.0));
+    if let Ok((_, mut transform, mut sprite)) = q_visual.single_mut() {
+        transform.translation = center;
+        sprite.custom_size = Some(size);
+    } else {
+        commands.spawn((
+            SelectionBoxVisual,
+            Sprite {
+                color: Color::srgba(1.0, 1.0, 0.4, 0.18),
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_translation(center),
+        ));
     }
 }
@@ -72,6 +72,12 @@ pub fn idle_visual_system(
             IdleBehavior::Drifting => {
                 set_sprite_color(sprite_opt.as_deref_mut(), Color::srgba(0.9, 0.9, 1.0, 0.85));
             }
+            IdleBehavior::Redeeming => {
+                set_sprite_color(
+                    sprite_opt.as_deref_mut(),
+                    Color::srgba(1.0, 0.95, 0.75, 1.0),
+                );
+            }
             IdleBehavior::Gathering | IdleBehavior::ExhaustedGathering => {
                 let gathering_center = if let Some(p) = participating_in {
                     q_spots.get(p.0).ok().map(|s| s.center)
@@ -161,6 +167,7 @@ pub fn idle_visual_system(
                     | IdleBehavior::Resting
                     | IdleBehavior::GoingToRest
                     | IdleBehavior::Drifting
+                    | IdleBehavior::Redeeming
             )
             && task_vs.phase == SoulTaskPhaseVisual::None
         {
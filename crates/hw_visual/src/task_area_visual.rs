@@ -29,7 +29,11 @@ impl Material2d for TaskAreaMaterial {
 }
 
 /// タスクエリア表示用コンポーネント（メッシュエンティティ側に付与）
+///
+/// 複数矩形の TaskArea は部分矩形ごとに 1 つのメッシュを持つ。
 #[derive(Component)]
 pub struct TaskAreaVisual {
     pub familiar: Entity,
+    /// 担当する部分矩形の index（`TaskArea::rect`）
+    pub rect_index: usize,
 }
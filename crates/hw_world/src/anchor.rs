@@ -5,6 +5,8 @@ use hw_core::constants::{
 use hw_core::world::GridPos;
 use std::fmt;
 
+use crate::mapgen::template::MapTemplate;
+
 /// 矩形グリッド領域（両端 inclusive）
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GridRect {
@@ -180,12 +182,38 @@ impl AnchorLayout {
     /// `master_seed` は `generate_world_layout` と同じ値を渡すこと。
     /// マップ端では `delta_y` を clamp し、Site/Yard がはみ出さないようにする。
     pub fn aligned_to_worldgen_seed(master_seed: u64) -> Self {
-        /// 川の南端（grid 最小 y）からさらに南へ何マス空けて Site 北辺を置くか
+        Self::aligned_below_water(crate::river::preview_river_min_y(master_seed))
+    }
+
+    /// テンプレートに応じて Site/Yard を配置する。
+    ///
+    /// - `Classic` / `TwinRivers`: 北側の川に合わせる（`aligned_to_worldgen_seed` と同じ）
+    /// - `NorthLake` / `Badlands`: 湖・池の南岸に合わせる
+    /// - `IslandSite`: 堀をアンカー基準で生成するため固定位置
+    pub fn for_template(master_seed: u64, template: MapTemplate) -> Self {
+        use crate::river::{BADLANDS_POND, NORTH_LAKE, preview_lake_min_y};
+
+        match template {
+            MapTemplate::Classic | MapTemplate::TwinRivers => {
+                Self::aligned_to_worldgen_seed(master_seed)
+            }
+            MapTemplate::NorthLake => {
+                Self::aligned_below_water(preview_lake_min_y(master_seed, NORTH_LAKE))
+            }
+            MapTemplate::Badlands => {
+                Self::aligned_below_water(preview_lake_min_y(master_seed, BADLANDS_POND))
+            }
+            MapTemplate::IslandSite => Self::fixed(),
+        }
+    }
+
+    /// Site 北辺を `water_south_y` の南に置くよう固定配置を縦シフトする。
+    fn aligned_below_water(water_south_y: i32) -> Self {
+        /// 水域の南端（grid 最小 y）からさらに南へ何マス空けて Site 北辺を置くか
         const SITE_NORTH_EDGE_BELOW_RIVER_SOUTH: i32 = 4;
 
         let base = Self::fixed();
-        let river_south_y = crate::river::preview_river_min_y(master_seed);
-        let target_site_max_y = river_south_y - SITE_NORTH_EDGE_BELOW_RIVER_SOUTH;
+        let target_site_max_y = water_south_y - SITE_NORTH_EDGE_BELOW_RIVER_SOUTH;
         let mut delta_y = target_site_max_y - base.site.max_y;
 
         let block_max_y = base.site.max_y.max(base.yard.max_y);
@@ -0,0 +1,207 @@
+//! マップ生成の seed サーベイと PNG 描画（CPU のみ、ウィンドウを開かない）。
+//!
+//! ```text
+//! cargo run -p hw_world --release --features mapgen-report --bin hw_mapgen_report -- survey [--start <seed>] [--count <n>] [--template <name|seed>] [--csv <file>] [--json <file>]
+//! cargo run -p hw_world --release --features mapgen-report --bin hw_mapgen_report -- render <seed> <png> [--template <name|seed>] [--scale <px>]
+//! ```
+//!
+//! `--template` は `classic`・`twin-rivers`・`north-lake`・`island-site`・`badlands` か `seed`。
+//! 省略時は survey が `seed`（seed ごとに `MapTemplate::from_seed`）、render が `classic`。
+//!
+//! 終了コードは 0 = 問題なし、1 = fallback に到達した seed がある、2 = 引数か入出力の誤り。
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::process::ExitCode;
+
+use hw_world::mapgen::report::render::{DEFAULT_RENDER_SCALE, render_layout_png};
+use hw_world::mapgen::report::{SurveySummary, reports_to_csv, reports_to_json, survey};
+use hw_world::mapgen::validate::ValidationWarningKind;
+use hw_world::{MapTemplate, generate_world_layout_with_template};
+
+const DEFAULT_SURVEY_COUNT: u64 = 100;
+
+const USAGE: &str = "usage: hw_mapgen_report <command> ...
+  survey [--start <seed>] [--count <n>]  generate <n> consecutive seeds (default 0, 100) and
+                                         print attempts, fallbacks and warning totals
+      [--csv <file>] [--json <file>]     write the per-seed report as CSV and/or JSON
+  render <seed> <png> [--scale <px>]     draw terrain (left) and masks (right) to a PNG;
+                                         <px> pixels per cell (default 4)
+  --template <name|seed>                 classic, twin-rivers, north-lake, island-site,
+                                         badlands, or `seed` to pick per seed
+                                         (default: survey `seed`, render `classic`)";
+
+/// `--template seed`: seed ごとに `MapTemplate::from_seed` で選ぶ。
+const TEMPLATE_FROM_SEED: &str = "seed";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("hw_mapgen_report: {error}");
+            if error.usage {
+                eprintln!("{USAGE}");
+            }
+            ExitCode::from(2)
+        }
+    }
+}
+
+struct ToolError {
+    message: String,
+    usage: bool,
+}
+
+impl ToolError {
+    fn usage(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            usage: true,
+        }
+    }
+}
+
+impl std::fmt::Display for ToolError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+impl<E: std::error::Error> From<E> for ToolError {
+    fn from(error: E) -> Self {
+        Self {
+            message: error.to_string(),
+            usage: false,
+        }
+    }
+}
+
+/// `--name value` 形式のオプション。
+#[derive(Default)]
+struct Options {
+    positional: Vec<String>,
+    start: Option<u64>,
+    count: Option<u64>,
+    scale: Option<u32>,
+    csv: Option<String>,
+    json: Option<String>,
+    /// `None` は `--template seed`。
+    template: Option<Option<MapTemplate>>,
+}
+
+fn run(args: &[String]) -> Result<ExitCode, ToolError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err(ToolError::usage("missing command"));
+    };
+    let options = parse_options(rest)?;
+    match (command.as_str(), options.positional.as_slice()) {
+        ("survey", []) if options.scale.is_none() => run_survey(&options),
+        ("render", [seed, path])
+            if options.start.is_none()
+                && options.count.is_none()
+                && options.csv.is_none()
+                && options.json.is_none() =>
+        {
+            let seed = parse_number(seed, "<seed>")?;
+            let template = match options.template {
+                Some(Some(template)) => template,
+                Some(None) => MapTemplate::from_seed(seed),
+                None => MapTemplate::Classic,
+            };
+            let layout = generate_world_layout_with_template(seed, template);
+            let scale = options.scale.unwrap_or(DEFAULT_RENDER_SCALE);
+            render_layout_png(&layout, scale, BufWriter::new(File::create(path)?))?;
+            println!(
+                "wrote {path} (seed {seed}, template {template}, attempt {}, fallback {})",
+                layout.generation_attempt, layout.used_fallback
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        (command, _) => Err(ToolError::usage(format!(
+            "unknown command or wrong arguments: `{command}`"
+        ))),
+    }
+}
+
+fn run_survey(options: &Options) -> Result<ExitCode, ToolError> {
+    let start = options.start.unwrap_or(0);
+    let count = options.count.unwrap_or(DEFAULT_SURVEY_COUNT);
+    let reports = survey(start, count, options.template.flatten());
+    if let Some(path) = &options.csv {
+        std::fs::write(path, reports_to_csv(&reports))?;
+        println!("wrote {path}");
+    }
+    if let Some(path) = &options.json {
+        std::fs::write(path, reports_to_json(&reports))?;
+        println!("wrote {path}");
+    }
+
+    let summary = SurveySummary::from_reports(&reports);
+    println!(
+        "seeds {}..{}: {} layouts, {} retried, {} fallback, max attempt {}, mean attempt {:.2}, {} with an unreachable river/forest/rock",
+        start,
+        start.wrapping_add(count),
+        summary.seeds,
+        summary.retried,
+        summary.fallbacks,
+        summary.max_attempt,
+        summary.mean_attempt,
+        summary.unreachable,
+    );
+    for (kind, total) in ValidationWarningKind::ALL
+        .iter()
+        .zip(summary.warning_totals)
+    {
+        println!("  {:<32} {total}", kind.as_str());
+    }
+    for report in reports.iter().filter(|report| report.used_fallback) {
+        println!("  fallback seed {} ({})", report.seed, report.template);
+    }
+
+    Ok(if summary.fallbacks == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn parse_options(args: &[String]) -> Result<Options, ToolError> {
+    let mut options = Options::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with("--") {
+            options.positional.push(arg.clone());
+            continue;
+        }
+        let value = iter
+            .next()
+            .ok_or_else(|| ToolError::usage(format!("{arg} needs a value")))?;
+        match arg.as_str() {
+            "--start" => options.start = Some(parse_number(value, arg)?),
+            "--count" => options.count = Some(parse_number(value, arg)?),
+            "--scale" => options.scale = Some(parse_number(value, arg)?),
+            "--csv" => options.csv = Some(value.clone()),
+            "--json" => options.json = Some(value.clone()),
+            "--template" => options.template = Some(parse_template(value)?),
+            other => return Err(ToolError::usage(format!("unknown option `{other}`"))),
+        }
+    }
+    Ok(options)
+}
+
+fn parse_template(value: &str) -> Result<Option<MapTemplate>, ToolError> {
+    if value == TEMPLATE_FROM_SEED {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .map_err(|error| ToolError::usage(format!("--template: {error}")))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, ToolError> {
+    value
+        .parse()
+        .map_err(|_| ToolError::usage(format!("{name} expects a number, got `{value}`")))
+}
@@ -31,17 +31,17 @@ pub use door_systems::{
     soul_keeps_door_open,
 };
 pub use layout::{RIVER_X_MAX, RIVER_X_MIN, RIVER_Y_MAX, RIVER_Y_MIN, SAND_WIDTH};
-pub use map::{WorldMap, WorldMapRead, WorldMapWrite};
+pub use map::{ForbiddenAwarePathWorld, WorldMap, WorldMapRead, WorldMapWrite};
 pub use mapgen::generate_base_terrain_tiles;
-pub use mapgen::generate_world_layout;
 pub use mapgen::types::{GeneratedWorldLayout, ResourceSpawnCandidates, WfcForestZone};
+pub use mapgen::{MapTemplate, generate_world_layout, generate_world_layout_with_template};
 #[cfg(feature = "profiling")]
 pub use pathfinding::RuntimePathSearchMetrics;
 pub use pathfinding::{
     PathGoalPolicy, PathNode, PathSearchCaller, PathSearchResult, PathWorld, PathfindingContext,
     RuntimePathSearchBudget, WalkabilityConnectivityCache, find_path_to_adjacent_with_budget,
     find_path_to_boundary_with_budget, find_path_with_budget,
-    find_path_world_waypoints_with_budget,
+    find_path_world_waypoints_with_budget, targets_cut_off_by_forbidden,
 };
 pub use query::{find_nearest_river_grid, find_nearest_walkable_grid};
 pub use regrowth::{ForestZone, default_forest_zones, find_regrowth_position};
@@ -0,0 +1,157 @@
+use super::WorldMap;
+use crate::pathfinding::PathWorld;
+use hw_core::GridPos;
+
+impl WorldMap {
+    pub fn is_forbidden(&self, grid: (i32, i32)) -> bool {
+        self.forbidden_tiles.contains(&grid)
+    }
+
+    pub fn has_forbidden_tiles(&self) -> bool {
+        !self.forbidden_tiles.is_empty()
+    }
+
+    pub fn forbidden_tile_entries(&self) -> impl Iterator<Item = &(i32, i32)> {
+        self.forbidden_tiles.iter()
+    }
+
+    /// マップ内のタイルを禁止区域に追加し、新たに追加した数を返す。
+    ///
+    /// Soul の経路が変わるため、1 枚でも増えれば `obstacle_version` を進めて
+    /// 既存パスの再検証を促す。
+    pub fn add_forbidden_tiles<I>(&mut self, grids: I) -> usize
+    where
+        I: IntoIterator<Item = (i32, i32)>,
+    {
+        let mut added = 0;
+        for grid in grids {
+            if self.pos_to_idx(grid.0, grid.1).is_some() && self.forbidden_tiles.insert(grid) {
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.bump_obstacle_version();
+        }
+        added
+    }
+
+    /// 禁止区域からタイルを外し、実際に外した数を返す。
+    pub fn remove_forbidden_tiles<I>(&mut self, grids: I) -> usize
+    where
+        I: IntoIterator<Item = (i32, i32)>,
+    {
+        let mut removed = 0;
+        for grid in grids {
+            if self.forbidden_tiles.remove(&grid) {
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            self.bump_obstacle_version();
+        }
+        removed
+    }
+
+    /// 禁止区域を常に避ける経路探索ビュー（怠惰行動の目的地選定用）。
+    pub fn avoiding_forbidden(&self) -> ForbiddenAwarePathWorld<'_> {
+        ForbiddenAwarePathWorld {
+            world_map: self,
+            avoid_forbidden: self.has_forbidden_tiles(),
+        }
+    }
+
+    /// `start` から `goal` への移動に使う経路探索ビュー。
+    ///
+    /// 禁止区域は原則として通行不可だが、割り当て済み作業の目的地が禁止区域内に
+    /// ある場合と、すでに禁止区域内に立っている場合（脱出）は通行を許可する。
+    pub fn path_world_for(
+        &self,
+        start: GridPos,
+        goal: GridPos,
+        assigned_work: bool,
+    ) -> ForbiddenAwarePathWorld<'_> {
+        let exempt = self.is_forbidden(start) || (assigned_work && self.is_forbidden(goal));
+        ForbiddenAwarePathWorld {
+            world_map: self,
+            avoid_forbidden: self.has_forbidden_tiles() && !exempt,
+        }
+    }
+}
+
+/// 禁止区域（`ZoneType::Forbidden`）を通行不可として扱う [`WorldMap`] のビュー。
+#[derive(Clone, Copy)]
+pub struct ForbiddenAwarePathWorld<'a> {
+    world_map: &'a WorldMap,
+    avoid_forbidden: bool,
+}
+
+impl PathWorld for ForbiddenAwarePathWorld<'_> {
+    fn pos_to_idx(&self, x: i32, y: i32) -> Option<usize> {
+        self.world_map.pos_to_idx(x, y)
+    }
+
+    fn idx_to_pos(&self, idx: usize) -> GridPos {
+        WorldMap::idx_to_pos(idx)
+    }
+
+    fn is_walkable(&self, x: i32, y: i32) -> bool {
+        self.world_map.is_walkable(x, y)
+            && !(self.avoid_forbidden && self.world_map.is_forbidden((x, y)))
+    }
+
+    fn get_door_cost(&self, x: i32, y: i32) -> i32 {
+        self.world_map.get_door_cost(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forbidden_tiles_block_idle_routes_but_not_assigned_work_inside_the_zone() {
+        let mut world_map = WorldMap::default();
+        assert_eq!(
+            world_map.add_forbidden_tiles([(10, 10), (10, 10), (-1, 0)]),
+            1
+        );
+
+        assert!(!world_map.avoiding_forbidden().is_walkable(10, 10));
+        assert!(world_map.avoiding_forbidden().is_walkable(11, 10));
+        assert!(
+            !world_map
+                .path_world_for((5, 5), (10, 10), false)
+                .is_walkable(10, 10)
+        );
+        assert!(
+            world_map
+                .path_world_for((5, 5), (10, 10), true)
+                .is_walkable(10, 10)
+        );
+        assert!(
+            world_map
+                .path_world_for((10, 10), (5, 5), false)
+                .is_walkable(10, 10)
+        );
+        assert!(
+            !world_map
+                .path_world_for((5, 5), (6, 6), true)
+                .is_walkable(10, 10)
+        );
+    }
+
+    #[test]
+    fn forbidden_changes_advance_obstacle_version_only_when_tiles_change() {
+        let mut world_map = WorldMap::default();
+        let version = world_map.obstacle_version;
+
+        world_map.add_forbidden_tiles([(3, 3)]);
+        assert_ne!(world_map.obstacle_version, version);
+
+        let version = world_map.obstacle_version;
+        assert_eq!(world_map.remove_forbidden_tiles([(4, 4)]), 0);
+        assert_eq!(world_map.obstacle_version, version);
+        assert_eq!(world_map.remove_forbidden_tiles([(3, 3)]), 1);
+        assert!(!world_map.has_forbidden_tiles());
+    }
+}
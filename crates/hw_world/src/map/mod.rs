@@ -2,11 +2,13 @@ mod access;
 mod bridges;
 mod buildings;
 mod doors;
+mod forbidden;
 mod obstacles;
 mod stockpiles;
 mod tiles;
 
 pub use access::{WorldMapRead, WorldMapWrite};
+pub use forbidden::ForbiddenAwarePathWorld;
 
 use crate::TerrainType;
 use crate::pathfinding::PathWorld;
@@ -48,6 +50,9 @@ pub struct WorldMap {
     /// 障害物・扉・建物占有など歩行可否に影響する変更の世代番号。
     #[serde(default)]
     pub obstacle_version: u64,
+    /// 禁止区域（`ZoneType::Forbidden`）に塗られたタイル。
+    #[serde(default)]
+    pub forbidden_tiles: HashSet<(i32, i32)>,
 }
 
 impl Default for WorldMap {
@@ -64,6 +69,7 @@ impl Default for WorldMap {
             bridged_tiles: HashSet::new(),
             obstacles: vec![false; size],
             obstacle_version: 0,
+            forbidden_tiles: HashSet::new(),
         }
     }
 }
@@ -89,7 +95,8 @@ fn map_world_map_entities<M: EntityMapper>(this: &mut WorldMap, mapper: &mut M)
     for entity in this.stockpiles.values_mut() {
         *entity = mapper.get_mapped(*entity);
     }
-    // `door_states`, `bridged_tiles`, `tiles`, `obstacles` carry no Entity references.
+    // `door_states`, `bridged_tiles`, `forbidden_tiles`, `tiles`, `obstacles` carry no
+    // Entity references.
 }
 
 impl PathWorld for WorldMap {
@@ -1,5 +1,8 @@
 pub(crate) mod pipeline;
+#[cfg(feature = "mapgen-report")]
+pub mod report;
 pub mod resources;
+pub mod template;
 pub mod types;
 pub mod validate;
 pub mod wfc_adapter;
@@ -7,7 +10,8 @@ pub mod wfc_adapter;
 use crate::river::{generate_fixed_river_tiles, generate_sand_tiles};
 use crate::terrain::TerrainType;
 
-pub use pipeline::generate_world_layout;
+pub use pipeline::{generate_world_layout, generate_world_layout_with_template};
+pub use template::MapTemplate;
 
 /// レガシー固定地形生成（`GeneratedWorldLayout::stub` および visual_test で使用）。
 pub fn generate_base_terrain_tiles(
@@ -8,6 +8,7 @@ use crate::anchor::AnchorLayout;
 use crate::world_masks::WorldMasks;
 
 use super::resources;
+use super::template::MapTemplate;
 use super::types::GeneratedWorldLayout;
 use super::validate;
 use super::wfc_adapter::{
@@ -16,15 +17,25 @@ use super::wfc_adapter::{
 
 /// WFC 地形生成のエントリポイント（MS-WFC-2c）。
 ///
-/// `AnchorLayout::aligned_to_worldgen_seed(master_seed)` で Site/Yard を川の縦位置に合わせ、
+/// `MapTemplate::Classic` で [`generate_world_layout_with_template`] を呼ぶ。
+pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
+    generate_world_layout_with_template(master_seed, MapTemplate::Classic)
+}
+
+/// テンプレートを指定した WFC 地形生成。
+///
+/// `AnchorLayout::for_template(master_seed, template)` で Site/Yard を水域の縦位置に合わせ、
 /// `WorldMasks`（anchor + river_mask）を構築し、WFC ソルバーで地形グリッドを生成する。
 /// 収束失敗時は `MAX_WFC_RETRIES` まで deterministic retry し、retry 内で
 /// `validate::lightweight_validate()`・資源配置・`validate_post_resource` を通過したレイアウトのみ採用する。
 /// 全試行で通過できない場合のみ fallback（River マスクを維持した Grass マップ）を返す。
-pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
-    let anchors = AnchorLayout::aligned_to_worldgen_seed(master_seed);
+pub fn generate_world_layout_with_template(
+    master_seed: u64,
+    template: MapTemplate,
+) -> GeneratedWorldLayout {
+    let anchors = AnchorLayout::for_template(master_seed, template);
     let mut masks = WorldMasks::from_anchor(&anchors);
-    masks.fill_river_from_seed(master_seed);
+    masks.fill_water_for_template(master_seed, template);
     masks.fill_sand_from_river_seed(master_seed);
     masks.fill_terrain_zones_from_seed(master_seed);
     fix_zone_mask_crosses(&mut masks);
@@ -33,7 +44,7 @@ pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
     let layout = (0..=MAX_WFC_RETRIES)
         .find_map(|attempt| {
             let sub_seed = derive_sub_seed(master_seed, attempt);
-            let terrain_tiles = run_wfc(&mut masks, sub_seed, attempt).ok()?;
+            let terrain_tiles = run_wfc(&mut masks, template, sub_seed, attempt).ok()?;
 
             // ─ Step 3: 地形フェーズ検証 ─
             let candidate = GeneratedWorldLayout::initial(
@@ -41,6 +52,7 @@ pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
                 anchors.clone(),
                 masks.clone(),
                 master_seed,
+                template,
                 attempt,
                 false,
             );
@@ -62,12 +74,15 @@ pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
             Some(candidate.with_resources(res, water, sand))
         })
         .unwrap_or_else(|| {
-            eprintln!("WFC: fallback terrain used for master_seed={master_seed}");
+            eprintln!(
+                "WFC: fallback terrain used for master_seed={master_seed} template={template}"
+            );
             let fallback_candidate = GeneratedWorldLayout::initial(
                 fallback_terrain(&masks, master_seed),
                 anchors,
                 masks,
                 master_seed,
+                template,
                 MAX_WFC_RETRIES + 1,
                 true,
             );
@@ -106,7 +121,9 @@ pub fn generate_world_layout(master_seed: u64) -> GeneratedWorldLayout {
 
 #[cfg(test)]
 mod tests {
-    use super::generate_world_layout;
+    use super::{generate_world_layout, generate_world_layout_with_template};
+    use crate::mapgen::template::MapTemplate;
+    use crate::mapgen::validate::lightweight_validate;
     use crate::terrain::TerrainType;
     use crate::test_seeds::{GOLDEN_SEED_PRIMARY, GOLDEN_SEED_SECONDARY};
     use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
@@ -174,6 +191,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn classic_template_matches_generate_world_layout() {
+        let classic =
+            generate_world_layout_with_template(GOLDEN_SEED_PRIMARY, MapTemplate::Classic);
+        let default = generate_world_layout(GOLDEN_SEED_PRIMARY);
+        assert_eq!(classic.terrain_tiles, default.terrain_tiles);
+        assert_eq!(
+            classic.initial_tree_positions,
+            default.initial_tree_positions
+        );
+        assert_eq!(
+            classic.initial_rock_positions,
+            default.initial_rock_positions
+        );
+        assert_eq!(classic.masks.isolated_land_mask.count_set(), 0);
+    }
+
+    #[test]
+    fn every_template_validates_without_fallback() {
+        for template in MapTemplate::ALL {
+            for seed in [GOLDEN_SEED_PRIMARY, GOLDEN_SEED_SECONDARY] {
+                let layout = generate_world_layout_with_template(seed, template);
+                assert_eq!(layout.template, template);
+                assert!(
+                    !layout.used_fallback,
+                    "seed={seed} template={template}: fallback"
+                );
+                assert!(
+                    lightweight_validate(&layout).is_ok(),
+                    "seed={seed} template={template}: lightweight_validate failed"
+                );
+                assert!(
+                    !layout.initial_tree_positions.is_empty(),
+                    "seed={seed} template={template}: no trees"
+                );
+                for &pos in layout
+                    .initial_tree_positions
+                    .iter()
+                    .chain(&layout.initial_rock_positions)
+                {
+                    assert!(
+                        !layout.masks.isolated_land_mask.get(pos),
+                        "seed={seed} template={template}: resource on isolated land at {pos:?}"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_sand_matches_final_sand_mask() {
         let layout = generate_world_layout(GOLDEN_SEED_PRIMARY);
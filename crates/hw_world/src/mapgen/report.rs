@@ -0,0 +1,324 @@
+//! seed 横断のマップ生成サーベイ（`mapgen-report` feature）。
+//!
+//! `generate_world_layout_with_template` を seed ごとに実行し、収束 attempt・fallback・
+//! `debug_validate` の警告種別ごとの件数・資源数・Yard からの距離を [`SeedReport`] に集める。
+//! 結果は CSV / JSON に書き出せる。PNG 描画は [`render`] が担当する。
+//! CLI は `src/bin/hw_mapgen_report.rs`。
+
+pub mod render;
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+
+use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
+use hw_core::world::GridPos;
+
+use super::generate_world_layout_with_template;
+use super::template::MapTemplate;
+use super::types::GeneratedWorldLayout;
+use super::validate::{ValidationWarningKind, debug_validate};
+use crate::terrain::TerrainType;
+use crate::world_masks::BitGrid;
+
+const CARDINAL_DIRS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+/// 1 seed 分の生成結果の要約。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedReport {
+    pub seed: u64,
+    pub template: MapTemplate,
+    /// 採用された試行（0-indexed）。fallback 時は `MAX_WFC_RETRIES + 1`。
+    pub generation_attempt: u32,
+    pub used_fallback: bool,
+    /// `ValidationWarningKind::ALL` と同じ順の警告件数。
+    pub warning_counts: [usize; ValidationWarningKind::ALL.len()],
+    pub river_tiles: usize,
+    pub sand_tiles: usize,
+    pub rock_field_tiles: usize,
+    pub trees: usize,
+    pub rocks: usize,
+    pub forest_zones: usize,
+    pub water_candidates: usize,
+    pub sand_candidates: usize,
+    /// Yard から、川に隣接する歩行可能セルまでの 4 近傍歩数。到達不能なら `None`。
+    pub yard_to_river: Option<u32>,
+    /// Yard から、初期木に隣接する歩行可能セルまでの 4 近傍歩数。
+    pub yard_to_forest: Option<u32>,
+    /// Yard から、初期岩に隣接する歩行可能セルまでの 4 近傍歩数。
+    pub yard_to_rock: Option<u32>,
+}
+
+impl SeedReport {
+    /// `seed` と `template` でレイアウトを生成して要約する。
+    pub fn generate(seed: u64, template: MapTemplate) -> Self {
+        Self::from_layout(&generate_world_layout_with_template(seed, template))
+    }
+
+    pub fn from_layout(layout: &GeneratedWorldLayout) -> Self {
+        let mut warning_counts = [0; ValidationWarningKind::ALL.len()];
+        for warning in debug_validate(layout) {
+            if let Some(index) = ValidationWarningKind::ALL
+                .iter()
+                .position(|kind| *kind == warning.kind)
+            {
+                warning_counts[index] += 1;
+            }
+        }
+
+        let count_terrain = |terrain: TerrainType| {
+            layout
+                .terrain_tiles
+                .iter()
+                .filter(|tile| **tile == terrain)
+                .count()
+        };
+        let distances = yard_distance_field(layout);
+        let river_tiles = map_cells()
+            .filter(|&(x, y)| {
+                layout.terrain_tiles[(y * MAP_WIDTH + x) as usize] == TerrainType::River
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            seed: layout.master_seed,
+            template: layout.template,
+            generation_attempt: layout.generation_attempt,
+            used_fallback: layout.used_fallback,
+            warning_counts,
+            river_tiles: river_tiles.len(),
+            sand_tiles: count_terrain(TerrainType::Sand),
+            rock_field_tiles: layout.masks.rock_field_mask.count_set(),
+            trees: layout.initial_tree_positions.len(),
+            rocks: layout.initial_rock_positions.len(),
+            forest_zones: layout.forest_regrowth_zones.len(),
+            water_candidates: layout.resource_spawn_candidates.water_tiles.len(),
+            sand_candidates: layout.resource_spawn_candidates.sand_tiles.len(),
+            yard_to_river: nearest_adjacent(&distances, &river_tiles),
+            yard_to_forest: nearest_adjacent(&distances, &layout.initial_tree_positions),
+            yard_to_rock: nearest_adjacent(&distances, &layout.initial_rock_positions),
+        }
+    }
+}
+
+/// `start` から `count` 個の連続 seed を要約する。
+///
+/// `template` が `None` のときは seed ごとに `MapTemplate::from_seed` で選ぶ。
+pub fn survey(start: u64, count: u64, template: Option<MapTemplate>) -> Vec<SeedReport> {
+    (0..count)
+        .map(|offset| {
+            let seed = start.wrapping_add(offset);
+            SeedReport::generate(
+                seed,
+                template.unwrap_or_else(|| MapTemplate::from_seed(seed)),
+            )
+        })
+        .collect()
+}
+
+/// 複数 seed の集計値。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SurveySummary {
+    pub seeds: usize,
+    /// 最初の試行で収束しなかった（fallback を含む）seed 数。
+    pub retried: usize,
+    pub fallbacks: usize,
+    pub max_attempt: u32,
+    pub mean_attempt: f64,
+    pub warning_totals: [usize; ValidationWarningKind::ALL.len()],
+    /// 川・森・岩のいずれかに Yard から到達できない seed 数。
+    pub unreachable: usize,
+}
+
+impl SurveySummary {
+    pub fn from_reports(reports: &[SeedReport]) -> Self {
+        let mut summary = Self {
+            seeds: reports.len(),
+            ..Self::default()
+        };
+        let mut attempt_sum = 0u64;
+        for report in reports {
+            attempt_sum += u64::from(report.generation_attempt);
+            summary.max_attempt = summary.max_attempt.max(report.generation_attempt);
+            summary.retried += usize::from(report.generation_attempt > 0);
+            summary.fallbacks += usize::from(report.used_fallback);
+            summary.unreachable += usize::from(
+                report.yard_to_river.is_none()
+                    || report.yard_to_forest.is_none()
+                    || report.yard_to_rock.is_none(),
+            );
+            for (total, count) in summary.warning_totals.iter_mut().zip(report.warning_counts) {
+                *total += count;
+            }
+        }
+        if !reports.is_empty() {
+            summary.mean_attempt = attempt_sum as f64 / reports.len() as f64;
+        }
+        summary
+    }
+}
+
+/// 1 seed 1 行の CSV。到達不能の距離は空欄にする。
+pub fn reports_to_csv(reports: &[SeedReport]) -> String {
+    let mut csv = String::from("seed,template,generation_attempt,used_fallback");
+    for kind in ValidationWarningKind::ALL {
+        let _ = write!(csv, ",warn_{}", kind.as_str());
+    }
+    csv.push_str(
+        ",river_tiles,sand_tiles,rock_field_tiles,trees,rocks,forest_zones,\
+         water_candidates,sand_candidates,yard_to_river,yard_to_forest,yard_to_rock\n",
+    );
+    for report in reports {
+        let _ = write!(
+            csv,
+            "{},{},{},{}",
+            report.seed, report.template, report.generation_attempt, report.used_fallback
+        );
+        for count in report.warning_counts {
+            let _ = write!(csv, ",{count}");
+        }
+        let distance = |value: Option<u32>| value.map_or_else(String::new, |d| d.to_string());
+        let _ = writeln!(
+            csv,
+            ",{},{},{},{},{},{},{},{},{},{},{}",
+            report.river_tiles,
+            report.sand_tiles,
+            report.rock_field_tiles,
+            report.trees,
+            report.rocks,
+            report.forest_zones,
+            report.water_candidates,
+            report.sand_candidates,
+            distance(report.yard_to_river),
+            distance(report.yard_to_forest),
+            distance(report.yard_to_rock),
+        );
+    }
+    csv
+}
+
+/// `{"summary": {...}, "seeds": [...]}` 形式の JSON。到達不能の距離は `null`。
+pub fn reports_to_json(reports: &[SeedReport]) -> String {
+    let summary = SurveySummary::from_reports(reports);
+    let mut json = String::from("{\n  \"summary\": {");
+    let _ = write!(
+        json,
+        "\"seeds\": {}, \"retried\": {}, \"fallbacks\": {}, \"max_attempt\": {}, \
+         \"mean_attempt\": {:.4}, \"unreachable\": {}, \"warnings\": {}",
+        summary.seeds,
+        summary.retried,
+        summary.fallbacks,
+        summary.max_attempt,
+        summary.mean_attempt,
+        summary.unreachable,
+        warnings_json(&summary.warning_totals),
+    );
+    json.push_str("},\n  \"seeds\": [");
+    for (index, report) in reports.iter().enumerate() {
+        let distance =
+            |value: Option<u32>| value.map_or_else(|| "null".to_string(), |d| d.to_string());
+        if index > 0 {
+            json.push(',');
+        }
+        // seed は u64 全域を取るため、JS の数値精度で丸められないよう文字列にする。
+        let _ = write!(
+            json,
+            "\n    {{\"seed\": \"{}\", \"template\": \"{}\", \"generation_attempt\": {}, \"used_fallback\": {}, \
+             \"warnings\": {}, \"river_tiles\": {}, \"sand_tiles\": {}, \"rock_field_tiles\": {}, \
+             \"trees\": {}, \"rocks\": {}, \"forest_zones\": {}, \"water_candidates\": {}, \
+             \"sand_candidates\": {}, \"yard_to_river\": {}, \"yard_to_forest\": {}, \
+             \"yard_to_rock\": {}}}",
+            report.seed,
+            report.template,
+            report.generation_attempt,
+            report.used_fallback,
+            warnings_json(&report.warning_counts),
+            report.river_tiles,
+            report.sand_tiles,
+            report.rock_field_tiles,
+            report.trees,
+            report.rocks,
+            report.forest_zones,
+            report.water_candidates,
+            report.sand_candidates,
+            distance(report.yard_to_river),
+            distance(report.yard_to_forest),
+            distance(report.yard_to_rock),
+        );
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+fn warnings_json(counts: &[usize; ValidationWarningKind::ALL.len()]) -> String {
+    let fields = ValidationWarningKind::ALL
+        .iter()
+        .zip(counts)
+        .map(|(kind, count)| format!("\"{}\": {count}", kind.as_str()))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(", "))
+}
+
+fn map_cells() -> impl Iterator<Item = GridPos> {
+    (0..MAP_HEIGHT).flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y)))
+}
+
+/// Yard 全セルを起点にした 4 近傍 BFS 歩数。
+///
+/// River と初期木・岩を通行不能として扱う（`validate_post_resource` と同じ障害物の扱い）。
+fn yard_distance_field(layout: &GeneratedWorldLayout) -> Vec<Option<u32>> {
+    let mut blocked = BitGrid::map_sized();
+    for pos in map_cells() {
+        let terrain = layout.terrain_tiles[(pos.1 * MAP_WIDTH + pos.0) as usize];
+        if !terrain.is_walkable() {
+            blocked.set(pos, true);
+        }
+    }
+    for &pos in layout
+        .initial_tree_positions
+        .iter()
+        .chain(&layout.initial_rock_positions)
+    {
+        blocked.set(pos, true);
+    }
+
+    let mut distances = vec![None; (MAP_WIDTH * MAP_HEIGHT) as usize];
+    let mut queue = VecDeque::new();
+    for pos in layout.anchors.yard.iter_cells() {
+        if !blocked.get(pos) {
+            distances[(pos.1 * MAP_WIDTH + pos.0) as usize] = Some(0);
+            queue.push_back(pos);
+        }
+    }
+    while let Some((x, y)) = queue.pop_front() {
+        let next = distances[(y * MAP_WIDTH + x) as usize].unwrap_or(0) + 1;
+        for (dx, dy) in CARDINAL_DIRS {
+            let neighbor = (x + dx, y + dy);
+            if !in_bounds(neighbor) || blocked.get(neighbor) {
+                continue;
+            }
+            let slot = &mut distances[(neighbor.1 * MAP_WIDTH + neighbor.0) as usize];
+            if slot.is_none() {
+                *slot = Some(next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    distances
+}
+
+/// `targets` のいずれかに 4 近傍で隣接する到達済みセルの最小歩数。
+fn nearest_adjacent(distances: &[Option<u32>], targets: &[GridPos]) -> Option<u32> {
+    targets
+        .iter()
+        .flat_map(|&(x, y)| CARDINAL_DIRS.map(|(dx, dy)| (x + dx, y + dy)))
+        .filter(|&pos| in_bounds(pos))
+        .filter_map(|(x, y)| distances[(y * MAP_WIDTH + x) as usize])
+        .min()
+}
+
+fn in_bounds((x, y): GridPos) -> bool {
+    (0..MAP_WIDTH).contains(&x) && (0..MAP_HEIGHT).contains(&y)
+}
+
+#[cfg(test)]
+mod tests;
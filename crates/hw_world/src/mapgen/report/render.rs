@@ -0,0 +1,137 @@
+//! 生成レイアウトの PNG 描画（CPU のみ、目視レビュー用）。
+//!
+//! 左パネルに地形・初期木・初期岩・Site/Yard 外周、右パネルに主要マスクを描く。
+//! 画像の上端が `y = MAP_HEIGHT - 1`（ゲーム画面と同じ向き）。
+
+use std::io::Write;
+
+use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
+use hw_core::world::GridPos;
+
+use crate::anchor::GridRect;
+use crate::mapgen::types::GeneratedWorldLayout;
+use crate::terrain::TerrainType;
+
+/// 1 セルあたりの既定ピクセル数。
+pub const DEFAULT_RENDER_SCALE: u32 = 4;
+
+type Rgb = [u8; 3];
+
+const GRASS: Rgb = [106, 153, 78];
+const DIRT: Rgb = [150, 111, 74];
+const RIVER: Rgb = [52, 101, 164];
+const SAND: Rgb = [222, 201, 140];
+const TREE: Rgb = [26, 71, 32];
+const ROCK: Rgb = [120, 120, 128];
+const SITE_OUTLINE: Rgb = [240, 240, 240];
+const YARD_OUTLINE: Rgb = [250, 200, 40];
+const PANEL_GAP: Rgb = [0, 0, 0];
+
+const MASK_EMPTY: Rgb = [32, 32, 36];
+const MASK_GRASS_ZONE: Rgb = [56, 96, 56];
+const MASK_DIRT_ZONE: Rgb = [104, 78, 52];
+const MASK_PROTECTION: Rgb = [120, 48, 56];
+const MASK_ROCK_FIELD: Rgb = [150, 150, 160];
+const MASK_INLAND_SAND: Rgb = [230, 150, 60];
+const MASK_SAND_CARVE: Rgb = [170, 60, 170];
+const MASK_FINAL_SAND: Rgb = [236, 216, 150];
+const MASK_RIVER: Rgb = [64, 128, 220];
+const MASK_CENTERLINE: Rgb = [200, 240, 255];
+const MASK_ANCHOR: Rgb = [240, 240, 240];
+
+/// `layout` を 2 パネルの RGB PNG として `writer` に書き出す。`scale` は 1 以上に丸める。
+pub fn render_layout_png<W: Write>(
+    layout: &GeneratedWorldLayout,
+    scale: u32,
+    writer: W,
+) -> Result<(), png::EncodingError> {
+    let scale = scale.max(1);
+    let cells_wide = MAP_WIDTH as u32 * 2 + 1;
+    let cells_high = MAP_HEIGHT as u32;
+    let cells = render_cells(layout);
+
+    let width = cells_wide * scale;
+    let height = cells_high * scale;
+    let mut data = Vec::with_capacity((width * height * 3) as usize);
+    for py in 0..height {
+        let row = &cells[((py / scale) * cells_wide) as usize..][..cells_wide as usize];
+        for px in 0..width {
+            data.extend_from_slice(&row[(px / scale) as usize]);
+        }
+    }
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&data)
+}
+
+/// セル単位の色（上端 = `MAP_HEIGHT - 1`）。左パネル・1 セルの区切り・右パネルの順に並ぶ。
+fn render_cells(layout: &GeneratedWorldLayout) -> Vec<Rgb> {
+    let cells_wide = (MAP_WIDTH * 2 + 1) as usize;
+    let mut cells = vec![PANEL_GAP; cells_wide * MAP_HEIGHT as usize];
+    let mut put = |panel: i32, (x, y): GridPos, color: Rgb| {
+        let row = (MAP_HEIGHT - 1 - y) as usize;
+        let column = (panel * (MAP_WIDTH + 1) + x) as usize;
+        cells[row * cells_wide + column] = color;
+    };
+
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            put(0, (x, y), terrain_color(layout, (x, y)));
+            put(1, (x, y), mask_color(layout, (x, y)));
+        }
+    }
+    for &pos in &layout.initial_tree_positions {
+        put(0, pos, TREE);
+    }
+    for &pos in &layout.initial_rock_positions {
+        put(0, pos, ROCK);
+    }
+    for pos in rect_outline(&layout.anchors.site) {
+        put(0, pos, SITE_OUTLINE);
+    }
+    for pos in rect_outline(&layout.anchors.yard) {
+        put(0, pos, YARD_OUTLINE);
+    }
+    for &pos in &layout.masks.river_centerline {
+        if layout.masks.river_mask.get(pos) {
+            put(1, pos, MASK_CENTERLINE);
+        }
+    }
+    cells
+}
+
+fn terrain_color(layout: &GeneratedWorldLayout, (x, y): GridPos) -> Rgb {
+    match layout.terrain_tiles[(y * MAP_WIDTH + x) as usize] {
+        TerrainType::Grass => GRASS,
+        TerrainType::Dirt => DIRT,
+        TerrainType::River => RIVER,
+        TerrainType::Sand => SAND,
+    }
+}
+
+/// 後段で確定するマスクほど優先して 1 色を選ぶ。
+fn mask_color(layout: &GeneratedWorldLayout, pos: GridPos) -> Rgb {
+    let masks = &layout.masks;
+    let layers = [
+        (&masks.anchor_mask, MASK_ANCHOR),
+        (&masks.river_mask, MASK_RIVER),
+        (&masks.final_sand_mask, MASK_FINAL_SAND),
+        (&masks.sand_carve_mask, MASK_SAND_CARVE),
+        (&masks.inland_sand_mask, MASK_INLAND_SAND),
+        (&masks.rock_field_mask, MASK_ROCK_FIELD),
+        (&masks.river_protection_band, MASK_PROTECTION),
+        (&masks.dirt_zone_mask, MASK_DIRT_ZONE),
+        (&masks.grass_zone_mask, MASK_GRASS_ZONE),
+    ];
+    layers
+        .into_iter()
+        .find(|(mask, _)| mask.get(pos))
+        .map_or(MASK_EMPTY, |(_, color)| color)
+}
+
+fn rect_outline(rect: &GridRect) -> impl Iterator<Item = GridPos> + '_ {
+    rect.iter_cells()
+        .filter(|&(x, y)| x == rect.min_x || x == rect.max_x || y == rect.min_y || y == rect.max_y)
+}
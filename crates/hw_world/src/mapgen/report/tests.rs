@@ -0,0 +1,92 @@
+use super::render::render_layout_png;
+use super::*;
+use crate::mapgen::generate_world_layout;
+use crate::test_seeds::{GOLDEN_SEED_PRIMARY, GOLDEN_SEED_SECONDARY};
+
+#[test]
+fn seed_report_matches_layout() {
+    let layout = generate_world_layout(GOLDEN_SEED_PRIMARY);
+    let report = SeedReport::from_layout(&layout);
+
+    assert_eq!(report.seed, GOLDEN_SEED_PRIMARY);
+    assert_eq!(report.template, MapTemplate::Classic);
+    assert_eq!(report.generation_attempt, layout.generation_attempt);
+    assert_eq!(report.used_fallback, layout.used_fallback);
+    assert_eq!(report.trees, layout.initial_tree_positions.len());
+    assert_eq!(report.rocks, layout.initial_rock_positions.len());
+    assert_eq!(
+        report.river_tiles,
+        layout
+            .terrain_tiles
+            .iter()
+            .filter(|tile| **tile == TerrainType::River)
+            .count()
+    );
+    assert_eq!(
+        report.warning_counts.iter().sum::<usize>(),
+        debug_validate(&layout).len()
+    );
+    // validate_post_resource を通過したレイアウトは Yard から水・岩に到達できる。
+    assert!(report.yard_to_river.is_some());
+    assert!(report.yard_to_rock.is_some());
+}
+
+#[test]
+fn survey_csv_and_json_have_one_entry_per_seed() {
+    let reports = [
+        SeedReport::generate(GOLDEN_SEED_PRIMARY, MapTemplate::Classic),
+        SeedReport::generate(GOLDEN_SEED_SECONDARY, MapTemplate::TwinRivers),
+    ];
+
+    let csv = reports_to_csv(&reports);
+    let mut lines = csv.lines();
+    let header_columns = lines.next().unwrap().split(',').count();
+    assert!(csv.starts_with("seed,template,generation_attempt,used_fallback,warn_"));
+    assert!(csv.contains(&format!("\n{GOLDEN_SEED_SECONDARY},twin-rivers,")));
+    for line in lines.clone() {
+        assert_eq!(line.split(',').count(), header_columns);
+    }
+    assert_eq!(lines.count(), reports.len());
+
+    let json = reports_to_json(&reports);
+    assert!(json.contains("\"seeds\": 2,"));
+    assert!(json.contains(&format!(
+        "\"seed\": \"{GOLDEN_SEED_PRIMARY}\", \"template\": \"classic\""
+    )));
+    assert_eq!(
+        json.matches("\"generation_attempt\"").count(),
+        reports.len()
+    );
+}
+
+#[test]
+fn summary_counts_retries_and_fallbacks() {
+    let mut retried = SeedReport::generate(GOLDEN_SEED_PRIMARY, MapTemplate::Classic);
+    retried.generation_attempt = 3;
+    let mut fallback = retried.clone();
+    fallback.generation_attempt = 7;
+    fallback.used_fallback = true;
+    fallback.yard_to_forest = None;
+
+    let summary = SurveySummary::from_reports(&[retried, fallback]);
+    assert_eq!(summary.seeds, 2);
+    assert_eq!(summary.retried, 2);
+    assert_eq!(summary.fallbacks, 1);
+    assert_eq!(summary.max_attempt, 7);
+    assert_eq!(summary.mean_attempt, 5.0);
+    assert_eq!(summary.unreachable, 1);
+}
+
+#[test]
+fn render_writes_two_panel_png() {
+    let layout = generate_world_layout(GOLDEN_SEED_PRIMARY);
+    let mut bytes = Vec::new();
+    render_layout_png(&layout, 2, &mut bytes).unwrap();
+
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    // IHDR: width / height（big endian）
+    let width = u32::from_be_bytes(bytes[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(bytes[20..24].try_into().unwrap());
+    assert_eq!(width, (MAP_WIDTH as u32 * 2 + 1) * 2);
+    assert_eq!(height, MAP_HEIGHT as u32 * 2);
+}
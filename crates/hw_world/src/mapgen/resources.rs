@@ -16,7 +16,7 @@ use crate::world_masks::BitGrid;
 
 // ── 定数 ─────────────────────────────────────────────────────────────────────
 
-// 森林ゾーン
+// 森林ゾーン（テンプレート別の値は `MapTemplate::forest_zone_count_range`）
 /// ゾーン数の下限（0 は許容しない）
 pub const FOREST_ZONE_COUNT_MIN: u32 = 2;
 /// ゾーン数の上限
@@ -81,7 +81,8 @@ pub(crate) fn generate_resource_layout(
     seed: u64,
 ) -> Option<ResourceLayout> {
     let mut rng = StdRng::seed_from_u64(seed);
-    generate_resource_layout_inner(layout, &mut rng, FOREST_ZONE_COUNT_MIN)
+    let (zone_count_min, _) = layout.template.forest_zone_count_range();
+    generate_resource_layout_inner(layout, &mut rng, zone_count_min)
 }
 
 /// terrain fallback 用の縮退版。下限を緩め、`None` を返さないことを優先する。
@@ -91,7 +92,8 @@ pub(crate) fn generate_resource_layout_fallback(
     seed: u64,
 ) -> Option<ResourceLayout> {
     let mut rng = StdRng::seed_from_u64(seed ^ 0xfb7c_3a91_d5e2_4608);
-    if let Some(res) = generate_resource_layout_inner(layout, &mut rng, FOREST_ZONE_COUNT_MIN) {
+    let (zone_count_min, _) = layout.template.forest_zone_count_range();
+    if let Some(res) = generate_resource_layout_inner(layout, &mut rng, zone_count_min) {
         return Some(res);
     }
     // 下限を 1 に緩めて再試行（fallback terrain で Grass が少ない場合への対処）
@@ -155,7 +157,8 @@ fn generate_forest_zones(
         return None;
     }
 
-    let zone_count = rng.gen_range(FOREST_ZONE_COUNT_MIN..=FOREST_ZONE_COUNT_MAX) as usize;
+    let (count_min, count_max) = layout.template.forest_zone_count_range();
+    let zone_count = rng.gen_range(count_min..=count_max) as usize;
     candidates.shuffle(rng);
 
     let mut centers: Vec<GridPos> = Vec::new();
@@ -288,11 +291,13 @@ fn place_rocks(layout: &GeneratedWorldLayout) -> Option<Vec<GridPos>> {
 
 // ── exclusion マスク構築 ──────────────────────────────────────────────────────
 
-/// anchor_mask | tree_dense_protection_band | river_mask | final_sand_mask | inland_sand_mask
+/// anchor_mask | tree_dense_protection_band | river_mask | isolated_land_mask |
+/// final_sand_mask | inland_sand_mask
 fn build_tree_exclusion(layout: &GeneratedWorldLayout) -> BitGrid {
     let mut ex = layout.masks.anchor_mask.clone();
     ex |= &layout.masks.tree_dense_protection_band;
     ex |= &layout.masks.river_mask;
+    ex |= &layout.masks.isolated_land_mask;
     ex |= &layout.masks.final_sand_mask;
     ex |= &layout.masks.inland_sand_mask;
     ex
@@ -1,6 +1,7 @@
 use super::*;
 use crate::anchor::AnchorLayout;
 use crate::mapgen::generate_world_layout;
+use crate::mapgen::template::MapTemplate;
 use crate::mapgen::types::ResourceSpawnCandidates;
 use crate::mapgen::validate::validate_post_resource;
 use crate::mapgen::wfc_adapter::fallback_terrain;
@@ -24,6 +25,7 @@ fn make_fallback_layout(seed: u64) -> GeneratedWorldLayout {
         forest_regrowth_zones: Vec::new(),
         initial_rock_positions: Vec::new(),
         master_seed: seed,
+        template: MapTemplate::Classic,
         generation_attempt: 65,
         used_fallback: true,
     };
@@ -0,0 +1,173 @@
+//! マップテンプレート（水域の構成と地形バイアスの組み合わせ）。
+//!
+//! テンプレートは `generate_world_layout_with_template` の入力で、次を切り替える:
+//! - 水域（`river_mask` に載る川・湖・堀・池）と Site/Yard の縦位置
+//! - WFC の Grass / Dirt 重み
+//! - 森林ゾーン数と debug の水域タイル数レンジ
+//!
+//! `Classic` は従来の単一横断川で、同じ seed から従来と同一の地形を生成する。
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// 地形生成のテンプレート。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MapTemplate {
+    /// 北側を横断する 1 本の川（従来の生成）
+    #[default]
+    Classic,
+    /// Site/Yard を挟んで南北に 2 本の川
+    TwinRivers,
+    /// 横断川の代わりに北側の湖
+    NorthLake,
+    /// Site/Yard を囲む堀。陸橋で本土とつながる
+    IslandSite,
+    /// 小さな池だけの乾いた土地。Dirt が多く森が少ない
+    Badlands,
+}
+
+impl MapTemplate {
+    pub const ALL: [Self; 5] = [
+        Self::Classic,
+        Self::TwinRivers,
+        Self::NorthLake,
+        Self::IslandSite,
+        Self::Badlands,
+    ];
+
+    /// 環境変数・CLI・レポートで使う kebab-case 名。
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Classic => "classic",
+            Self::TwinRivers => "twin-rivers",
+            Self::NorthLake => "north-lake",
+            Self::IslandSite => "island-site",
+            Self::Badlands => "badlands",
+        }
+    }
+
+    /// seed から deterministic にテンプレートを選ぶ。
+    ///
+    /// `Classic` が 2/5、残りが 3/20 ずつ。地形生成の RNG とは独立した hash を使う。
+    pub const fn from_seed(seed: u64) -> Self {
+        let mut h = seed ^ TEMPLATE_SEED_SALT;
+        h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        h ^= h >> 31;
+        match h % 20 {
+            0..=7 => Self::Classic,
+            8..=10 => Self::TwinRivers,
+            11..=13 => Self::NorthLake,
+            14..=16 => Self::IslandSite,
+            _ => Self::Badlands,
+        }
+    }
+
+    /// WFC の `(Grass, Dirt)` 重み。Sand / River は全テンプレート共通。
+    pub const fn terrain_weights(self) -> (u32, u32) {
+        match self {
+            Self::Badlands => (BADLANDS_WEIGHT_GRASS, BADLANDS_WEIGHT_DIRT),
+            _ => (
+                super::wfc_adapter::WEIGHT_GRASS,
+                super::wfc_adapter::WEIGHT_DIRT,
+            ),
+        }
+    }
+
+    /// 森林ゾーン数の `(下限, 上限)`。
+    pub const fn forest_zone_count_range(self) -> (u32, u32) {
+        match self {
+            Self::Badlands => (1, 2),
+            _ => (
+                super::resources::FOREST_ZONE_COUNT_MIN,
+                super::resources::FOREST_ZONE_COUNT_MAX,
+            ),
+        }
+    }
+
+    /// `river_mask` のセル数として妥当な範囲（debug 診断用）。
+    pub const fn water_tile_range(self) -> (usize, usize) {
+        match self {
+            Self::Classic => (
+                crate::river::RIVER_TOTAL_TILES_TARGET_MIN,
+                crate::river::RIVER_TOTAL_TILES_TARGET_MAX,
+            ),
+            Self::TwinRivers => (
+                crate::river::RIVER_TOTAL_TILES_TARGET_MIN * 2,
+                crate::river::RIVER_TOTAL_TILES_TARGET_MAX * 2,
+            ),
+            Self::NorthLake => (150, 650),
+            Self::IslandSite => (400, 900),
+            Self::Badlands => (20, 110),
+        }
+    }
+
+    /// 水域で Site/Yard から切り離された陸地にゾーン・岩場を置かないか。
+    ///
+    /// `Classic` は従来の生成結果を保つため制限しない。
+    pub const fn keeps_resources_on_anchor_land(self) -> bool {
+        !matches!(self, Self::Classic)
+    }
+}
+
+/// Badlands の Grass 重み
+pub const BADLANDS_WEIGHT_GRASS: u32 = 4;
+/// Badlands の Dirt 重み
+pub const BADLANDS_WEIGHT_DIRT: u32 = 7;
+
+const TEMPLATE_SEED_SALT: u64 = 0x3c6e_f372_fe94_f82b;
+
+impl fmt::Display for MapTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 未知のテンプレート名。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMapTemplate(pub String);
+
+impl fmt::Display for UnknownMapTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = MapTemplate::ALL.map(MapTemplate::as_str).join(", ");
+        write!(
+            f,
+            "unknown map template `{}` (expected one of: {names})",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnknownMapTemplate {}
+
+impl FromStr for MapTemplate {
+    type Err = UnknownMapTemplate;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| UnknownMapTemplate(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        for template in MapTemplate::ALL {
+            assert_eq!(template.as_str().parse::<MapTemplate>(), Ok(template));
+        }
+        assert!("volcano".parse::<MapTemplate>().is_err());
+    }
+
+    #[test]
+    fn from_seed_reaches_every_template() {
+        let picked: std::collections::HashSet<_> = (0..200).map(MapTemplate::from_seed).collect();
+        assert_eq!(picked.len(), MapTemplate::ALL.len());
+    }
+}
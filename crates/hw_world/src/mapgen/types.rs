@@ -1,5 +1,6 @@
 use hw_core::world::GridPos;
 
+use super::template::MapTemplate;
 use crate::anchor::AnchorLayout;
 use crate::terrain::TerrainType;
 use crate::world_masks::WorldMasks;
@@ -35,6 +36,8 @@ pub struct GeneratedWorldLayout {
 
     // ── メタ ─────────────────────────────────────
     pub master_seed: u64,
+    /// 生成に使ったマップテンプレート
+    pub template: MapTemplate,
     /// 何回目の試行（0-indexed）で収束したか
     pub generation_attempt: u32,
     /// MAX_WFC_RETRIES 後に deterministic fallback へ入ったか
@@ -48,6 +51,7 @@ impl GeneratedWorldLayout {
         anchors: AnchorLayout,
         masks: WorldMasks,
         master_seed: u64,
+        template: MapTemplate,
         generation_attempt: u32,
         used_fallback: bool,
     ) -> Self {
@@ -60,6 +64,7 @@ impl GeneratedWorldLayout {
             forest_regrowth_zones: Vec::new(),
             initial_rock_positions: Vec::new(),
             master_seed,
+            template,
             generation_attempt,
             used_fallback,
         }
@@ -90,6 +95,7 @@ impl GeneratedWorldLayout {
             forest_regrowth_zones: Vec::new(),
             initial_rock_positions: Vec::new(),
             master_seed,
+            template: MapTemplate::Classic,
             generation_attempt: 0,
             used_fallback: false,
         }
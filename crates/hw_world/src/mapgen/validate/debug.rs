@@ -1,10 +1,9 @@
-//! Debug diagnostic validators — compiled only in test/debug builds.
+//! Debug diagnostic validators — compiled only in test/debug builds or with `mapgen-report`.
 
 use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
 
 use crate::mapgen::types::GeneratedWorldLayout;
 use crate::mapgen::wfc_adapter::CARDINAL_DIRS;
-use crate::river::{RIVER_TOTAL_TILES_TARGET_MAX, RIVER_TOTAL_TILES_TARGET_MIN};
 use crate::terrain::TerrainType;
 
 use super::{ValidationWarning, ValidationWarningKind};
@@ -39,14 +38,16 @@ fn check_protection_band_clean(
     }
 }
 
-/// river_mask のセル数が RIVER_TOTAL_TILES_TARGET_MIN/MAX の範囲外なら警告する。
+/// river_mask のセル数が `MapTemplate::water_tile_range()` の範囲外なら警告する。
 fn check_river_tile_count(layout: &GeneratedWorldLayout, warnings: &mut Vec<ValidationWarning>) {
     let count = layout.masks.river_mask.count_set();
-    if !(RIVER_TOTAL_TILES_TARGET_MIN..=RIVER_TOTAL_TILES_TARGET_MAX).contains(&count) {
+    let (min, max) = layout.template.water_tile_range();
+    if !(min..=max).contains(&count) {
         warnings.push(ValidationWarning {
             kind: ValidationWarningKind::RiverTileCountOutOfRange,
             message: format!(
-                "River tile count {count} outside [{RIVER_TOTAL_TILES_TARGET_MIN}, {RIVER_TOTAL_TILES_TARGET_MAX}]"
+                "River tile count {count} outside [{min}, {max}] for template {}",
+                layout.template
             ),
         });
     }
@@ -2,10 +2,10 @@
 //!
 //! - `lightweight_validate()`: 起動時必須チェック。失敗した試行は retry される。
 //!   成功時は到達確認済み `ResourceSpawnCandidates` を返す。
-//! - `debug_validate()`: `#[cfg(any(test, debug_assertions))]` で有効な追加診断。
-//!   `Vec<ValidationWarning>` を返すだけで地形を変更しない。
+//! - `debug_validate()`: `#[cfg(any(test, debug_assertions))]` と `mapgen-report` feature で
+//!   有効な追加診断。`Vec<ValidationWarning>` を返すだけで地形を変更しない。
 
-#[cfg(any(test, debug_assertions))]
+#[cfg(any(test, debug_assertions, feature = "mapgen-report"))]
 pub(crate) mod debug;
 pub(crate) mod post_resource;
 pub(crate) mod terrain;
@@ -20,6 +20,8 @@ pub enum ValidationError {
     SiteYardNotReachable,
     RequiredResourceNotReachable,
     YardAnchorOutOfBounds(GridPos),
+    /// Yard から隣接到達できない水域（代表タイル）
+    WaterBodyNotReachable(GridPos),
 }
 
 impl std::fmt::Display for ValidationError {
@@ -35,6 +37,12 @@ impl std::fmt::Display for ValidationError {
             Self::YardAnchorOutOfBounds(pos) => {
                 write!(f, "Yard anchor not in Yard bounds: {pos:?}")
             }
+            Self::WaterBodyNotReachable(pos) => {
+                write!(
+                    f,
+                    "Water body containing {pos:?} is not reachable from Yard"
+                )
+            }
         }
     }
 }
@@ -47,7 +55,7 @@ pub struct ValidationWarning {
     pub message: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ValidationWarningKind {
     ProtectionBandViolation,
     RiverTileCountOutOfRange,
@@ -56,12 +64,33 @@ pub enum ValidationWarningKind {
     SandMaskMismatch,
 }
 
+impl ValidationWarningKind {
+    pub const ALL: [Self; 5] = [
+        Self::ProtectionBandViolation,
+        Self::RiverTileCountOutOfRange,
+        Self::FallbackReached,
+        Self::ForbiddenPattern,
+        Self::SandMaskMismatch,
+    ];
+
+    /// レポートの列名などに使う snake_case 名。
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ProtectionBandViolation => "protection_band_violation",
+            Self::RiverTileCountOutOfRange => "river_tile_count_out_of_range",
+            Self::FallbackReached => "fallback_reached",
+            Self::ForbiddenPattern => "forbidden_pattern",
+            Self::SandMaskMismatch => "sand_mask_mismatch",
+        }
+    }
+}
+
 // ── re-exports ────────────────────────────────────────────────────────────────
 
-#[cfg(any(test, debug_assertions))]
+#[cfg(any(test, debug_assertions, feature = "mapgen-report"))]
 pub use debug::debug_validate;
 pub(crate) use post_resource::validate_post_resource;
-pub use terrain::lightweight_validate;
+pub use terrain::{WATER_BODY_MIN_TILES, lightweight_validate};
 
 // ── テスト ────────────────────────────────────────────────────────────────────
 
@@ -50,6 +50,8 @@ impl PathWorld for ResourceObstaclePathWorld<'_> {
 /// `layout` は `lightweight_validate` 通過済み（`water_tiles` / `sand_tiles` が入っている）。
 /// `resource` の木・岩座標を歩行不可障害物として重ね、
 /// Site↔Yard、Yard→水源、Yard→砂源、Yard→岩（隣接）を再確認する。
+/// `MapTemplate::Classic` 以外では Yard→木（隣接）も要求する
+/// （水域で陸地が分かれるテンプレートで木材の供給源を保証するため）。
 ///
 /// 岩は障害物なので `can_reach_target(..., false)` で隣接到達を要求する点が
 /// 地形フェーズの `collect_required_resource_candidates` と異なる（意図的）。
@@ -99,5 +101,15 @@ pub(crate) fn validate_post_resource(
         return Err(ValidationError::RequiredResourceNotReachable);
     }
 
+    if layout.template.keeps_resources_on_anchor_land() {
+        let has_tree = resource
+            .initial_tree_positions
+            .iter()
+            .any(|&p| can_reach_target(&world, &mut ctx, yard_rep, p, false));
+        if !has_tree {
+            return Err(ValidationError::RequiredResourceNotReachable);
+        }
+    }
+
     Ok(())
 }
@@ -1,3 +1,5 @@
+use std::collections::{HashSet, VecDeque};
+
 use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
 use hw_core::world::GridPos;
 
@@ -7,6 +9,9 @@ use crate::terrain::TerrainType;
 
 use super::ValidationError;
 
+/// 到達確認の対象にする水域の最小タイル数
+pub const WATER_BODY_MIN_TILES: usize = 8;
+
 // ── ValidatorPathWorld（内部ヘルパー） ────────────────────────────────────────
 
 /// validate 内部専用。`terrain_tiles` スライスのみで PathWorld を実現する。
@@ -49,6 +54,9 @@ pub fn lightweight_validate(
     check_site_yard_no_river_sand(layout)?;
     check_site_yard_reachable(layout)?;
     let resource_spawn_candidates = collect_required_resource_candidates(layout)?;
+    if layout.template.keeps_resources_on_anchor_land() {
+        check_water_bodies_reachable(layout, &resource_spawn_candidates.water_tiles)?;
+    }
     check_yard_anchors_present(layout)?;
     Ok(resource_spawn_candidates)
 }
@@ -153,6 +161,51 @@ fn collect_required_resource_candidates(
     Ok(validated)
 }
 
+/// `MapTemplate::Classic` 以外で、`WATER_BODY_MIN_TILES` 以上の各水域（River の 4 近傍連結成分）に
+/// Yard から隣接到達できるタイルが 1 つ以上あることを確認する。
+///
+/// `reachable_water` は `collect_required_resource_candidates` が確認済みの水源タイル。
+/// 保護帯で切り取られた数タイルの欠片は対象外にする。
+fn check_water_bodies_reachable(
+    layout: &GeneratedWorldLayout,
+    reachable_water: &[GridPos],
+) -> Result<(), ValidationError> {
+    const DIRS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+    let is_water = |(x, y): GridPos| {
+        (0..MAP_WIDTH).contains(&x)
+            && (0..MAP_HEIGHT).contains(&y)
+            && layout.terrain_tiles[(y * MAP_WIDTH + x) as usize] == TerrainType::River
+    };
+    let reachable: HashSet<GridPos> = reachable_water.iter().copied().collect();
+    let mut visited = vec![false; (MAP_WIDTH * MAP_HEIGHT) as usize];
+
+    for start in (0..MAP_HEIGHT).flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y))) {
+        if !is_water(start) || visited[(start.1 * MAP_WIDTH + start.0) as usize] {
+            continue;
+        }
+        visited[(start.1 * MAP_WIDTH + start.0) as usize] = true;
+        let mut queue = VecDeque::from([start]);
+        let mut size = 0usize;
+        let mut has_reachable = false;
+        while let Some((x, y)) = queue.pop_front() {
+            size += 1;
+            has_reachable |= reachable.contains(&(x, y));
+            for (dx, dy) in DIRS {
+                let next = (x + dx, y + dy);
+                if is_water(next) && !visited[(next.1 * MAP_WIDTH + next.0) as usize] {
+                    visited[(next.1 * MAP_WIDTH + next.0) as usize] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        if size >= WATER_BODY_MIN_TILES && !has_reachable {
+            return Err(ValidationError::WaterBodyNotReachable(start));
+        }
+    }
+    Ok(())
+}
+
 fn check_yard_anchors_present(layout: &GeneratedWorldLayout) -> Result<(), ValidationError> {
     for &pos in &layout.anchors.initial_wood_positions {
         if !layout.anchors.yard.contains(pos) {
@@ -23,6 +23,7 @@ use wfc::{
     PatternTable, RunOwn, Size,
 };
 
+use super::template::MapTemplate;
 use crate::terrain::TerrainType;
 use crate::world_masks::WorldMasks;
 
@@ -69,17 +70,20 @@ pub enum WfcError {
 /// WFC 完了後に `post_process_tiles()` でポスト処理する。
 ///
 /// # 引数
-/// - `masks`: `fill_river_from_seed()` 適用済みの WorldMasks
+/// - `masks`: `fill_river_from_seed()` / `fill_water_for_template()` 適用済みの WorldMasks
+/// - `template`: Grass / Dirt の重みを決めるマップテンプレート
 /// - `seed`: サブシード（caller が `derive_sub_seed` で計算する）
 /// - `attempt`: 試行回数（将来のログ用）
 pub fn run_wfc(
     masks: &mut WorldMasks,
+    template: MapTemplate,
     seed: u64,
     attempt: u32,
 ) -> Result<Vec<TerrainType>, WfcError> {
     let _ = attempt; // 将来 tracing::debug! に差し替え可
 
-    let table = build_pattern_table();
+    let weights = template.terrain_weights();
+    let table = build_pattern_table(weights);
     let global_stats = GlobalStats::new(table);
     let constraints = WorldConstraints::from_masks(masks);
     let size = Size::new(MAP_WIDTH as u32, MAP_HEIGHT as u32);
@@ -102,7 +106,7 @@ pub fn run_wfc(
         })
         .collect::<Vec<TerrainType>>();
 
-    post_process_tiles(&mut tiles, masks, &mut rng);
+    post_process_tiles(&mut tiles, masks, weights, &mut rng);
     Ok(tiles)
 }
 
@@ -150,14 +150,16 @@ fn apply_zone_post_process(tiles: &mut [TerrainType], masks: &WorldMasks, rng: &
 /// 処理順:
 /// 1. river_mask セルは常に River のまま（WFC で固定済み）
 /// 2. final_sand_mask セルは強制 Sand（WFC 結果に関わらず上書き）
-/// 3. final_sand_mask 外で terrain == Sand の stray Sand を Grass/Dirt に置換
+/// 3. final_sand_mask 外で terrain == Sand の stray Sand を `(weight_grass, weight_dirt)` の比で
+///    Grass/Dirt に置換
 /// 4. ゾーンバイアス + inland sand（apply_zone_post_process に委譲）
 pub(super) fn post_process_tiles(
     tiles: &mut [TerrainType],
     masks: &mut WorldMasks,
+    (weight_grass, weight_dirt): (u32, u32),
     rng: &mut StdRng,
 ) {
-    let total = WEIGHT_GRASS + WEIGHT_DIRT;
+    let total = weight_grass + weight_dirt;
     for y in 0..MAP_HEIGHT {
         for x in 0..MAP_WIDTH {
             let idx = (y * MAP_WIDTH + x) as usize;
@@ -171,7 +173,7 @@ pub(super) fn post_process_tiles(
             } else if tiles[idx] == TerrainType::Sand {
                 // マスク外の stray Sand を Grass/Dirt に落とす
                 let r = rng.gen_range(0..total);
-                tiles[idx] = if r < WEIGHT_GRASS {
+                tiles[idx] = if r < weight_grass {
                     TerrainType::Grass
                 } else {
                     TerrainType::Dirt
@@ -13,7 +13,9 @@ pub(super) const NEUTRAL_REGION_SIZE: i32 = 8;
 pub(super) const NEUTRAL_REGION_BIAS_PERCENT: u32 = 20;
 
 // ── タイル重み定数 ────────────────────────────────────────────────────────────
+/// Grass の重み（Badlands 以外のテンプレート）
 pub const WEIGHT_GRASS: u32 = 9;
+/// Dirt の重み（Badlands 以外のテンプレート）
 pub const WEIGHT_DIRT: u32 = 2;
 /// Sand の重み（F4: 川隣接セルにしか Sand を配置しない前提のバイアス値）
 pub const WEIGHT_SAND: u32 = SAND_ADJACENT_TO_RIVER_WEIGHT;
@@ -63,7 +65,10 @@ impl TerrainTileMapping {
 ///
 /// River ↔ Grass / River ↔ Dirt は禁止。
 /// River のマスク外伝播は `WorldConstraints` で別途抑制する。
-pub fn build_pattern_table() -> PatternTable<PatternDescription> {
+/// `(weight_grass, weight_dirt)` は `MapTemplate::terrain_weights()` を渡す。
+pub fn build_pattern_table(
+    (weight_grass, weight_dirt): (u32, u32),
+) -> PatternTable<PatternDescription> {
     let allowed_pairs: &[(PatternId, PatternId)] = &[
         (TERRAIN_PATTERN_GRASS, TERRAIN_PATTERN_GRASS),
         (TERRAIN_PATTERN_GRASS, TERRAIN_PATTERN_DIRT),
@@ -88,8 +93,8 @@ pub fn build_pattern_table() -> PatternTable<PatternDescription> {
     // observer の初期エントリが有効に保たれる。マスク外への出現は
     // river_forbidden_cells の forbid_pattern で防ぐ。
     let weights: [Option<NonZeroU32>; 4] = [
-        NonZeroU32::new(WEIGHT_GRASS),
-        NonZeroU32::new(WEIGHT_DIRT),
+        NonZeroU32::new(weight_grass),
+        NonZeroU32::new(weight_dirt),
         NonZeroU32::new(WEIGHT_SAND),
         None, // River: unweighted（hard constraint が出現先を制御）
     ];
@@ -138,6 +143,8 @@ pub const CARDINAL_DIRS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
 
 impl WorldConstraints {
     /// `fill_river_from_seed()` 適用済みの `WorldMasks` から制約を構築する。
+    ///
+    /// テンプレートの湖・堀・池も `river_mask` に載るため、水域の固定はこの制約だけで足りる。
     pub fn from_masks(masks: &WorldMasks) -> Self {
         let mut fixed_river = Vec::new();
         let mut river_forbidden_cells = Vec::new();
@@ -1,6 +1,14 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::Resource;
 use hw_core::constants::MAX_PATHFINDS_PER_FRAME;
 
+use super::GridPos;
+
+/// Upper bound of denied goals kept between two drains. Older entries are
+/// dropped first so an undrained log cannot grow without limit.
+pub const PATH_DENIAL_LOG_CAPACITY: usize = 1024;
+
 /// Outcome of a budgeted pathfinding request.
 ///
 /// `Deferred` is intentionally distinct from `Unreachable`: callers must keep
@@ -92,11 +100,12 @@ impl RuntimePathSearchMetrics {
 /// The phase ceiling can be tightened temporarily while retaining the same
 /// frame-wide usage counter. This reserves capacity for a later phase without
 /// treating a composite path request as one search.
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
 pub struct RuntimePathSearchBudget {
     hard_limit: usize,
     phase_limit: usize,
     used: usize,
+    denied_goals: VecDeque<GridPos>,
     #[cfg(feature = "profiling")]
     metrics: RuntimePathSearchMetrics,
 }
@@ -113,6 +122,7 @@ impl RuntimePathSearchBudget {
             hard_limit,
             phase_limit: hard_limit,
             used: 0,
+            denied_goals: VecDeque::new(),
             #[cfg(feature = "profiling")]
             metrics: RuntimePathSearchMetrics {
                 actor_new_core_searches: 0,
@@ -164,6 +174,21 @@ impl RuntimePathSearchBudget {
         claimed
     }
 
+    /// Records the goal of a core search that was deferred or proved
+    /// unreachable. Unlike `RuntimePathSearchMetrics`, which only counts per
+    /// caller, this keeps the tile so analytics can locate denial hotspots.
+    pub fn record_denied_goal(&mut self, goal: GridPos) {
+        if self.denied_goals.len() >= PATH_DENIAL_LOG_CAPACITY {
+            self.denied_goals.pop_front();
+        }
+        self.denied_goals.push_back(goal);
+    }
+
+    /// Takes every denied goal recorded since the previous drain.
+    pub fn drain_denied_goals(&mut self) -> Vec<GridPos> {
+        self.denied_goals.drain(..).collect()
+    }
+
     pub const fn used(&self) -> usize {
         self.used
     }
@@ -237,6 +262,17 @@ mod tests {
         assert_eq!(budget.used(), 8);
     }
 
+    #[test]
+    fn denied_goals_survive_frame_reset_until_drained() {
+        let mut budget = RuntimePathSearchBudget::new(1);
+        budget.record_denied_goal((3, 4));
+        budget.reset();
+        budget.record_denied_goal((5, 6));
+
+        assert_eq!(budget.drain_denied_goals(), vec![(3, 4), (5, 6)]);
+        assert!(budget.drain_denied_goals().is_empty());
+    }
+
     #[test]
     fn reset_restores_the_full_frame_limit() {
         let mut budget = RuntimePathSearchBudget::new(3);
@@ -0,0 +1,102 @@
+//! Reachability check used to warn when a forbidden zone splits the colony.
+
+use super::core::{PATHFINDING_DIRECTIONS, PathWorld, can_cross_diagonal_move};
+use crate::map::WorldMap;
+use hw_core::GridPos;
+
+/// Returns the task targets that some stockpile can reach on the plain map
+/// but no stockpile can reach once forbidden tiles are avoided.
+///
+/// Targets that lie inside a forbidden zone are skipped: assigned work may
+/// still enter the zone, so only work outside it can be cut off. Blocked
+/// targets (trees, rocks, blueprints) count as reached when any neighbouring
+/// tile is reached, matching the adjacent-goal pathfinding contract.
+pub fn targets_cut_off_by_forbidden(
+    world_map: &WorldMap,
+    stockpiles: &[GridPos],
+    targets: &[GridPos],
+) -> Vec<GridPos> {
+    if !world_map.has_forbidden_tiles() || stockpiles.is_empty() || targets.is_empty() {
+        return Vec::new();
+    }
+
+    let reachable = flood_from(world_map, world_map.tiles.len(), stockpiles);
+    let restricted = flood_from(
+        &world_map.avoiding_forbidden(),
+        world_map.tiles.len(),
+        stockpiles,
+    );
+
+    targets
+        .iter()
+        .copied()
+        .filter(|&target| !world_map.is_forbidden(target))
+        .filter(|&target| {
+            is_reached_near(world_map, &reachable, target)
+                && !is_reached_near(world_map, &restricted, target)
+        })
+        .collect()
+}
+
+fn flood_from(world: &impl PathWorld, len: usize, sources: &[GridPos]) -> Vec<bool> {
+    let mut reached = vec![false; len];
+    let mut queue = Vec::new();
+    for &(x, y) in sources {
+        if let Some(idx) = world.pos_to_idx(x, y)
+            && !reached[idx]
+        {
+            reached[idx] = true;
+            queue.push(idx);
+        }
+    }
+
+    let mut head = 0;
+    while let Some(&current_idx) = queue.get(head) {
+        head += 1;
+        let current = world.idx_to_pos(current_idx);
+        for (dx, dy) in PATHFINDING_DIRECTIONS {
+            let next = (current.0 + dx, current.1 + dy);
+            let Some(next_idx) = world.pos_to_idx(next.0, next.1) else {
+                continue;
+            };
+            if reached[next_idx]
+                || !world.is_walkable(next.0, next.1)
+                || !can_cross_diagonal_move(world, current, next)
+            {
+                continue;
+            }
+            reached[next_idx] = true;
+            queue.push(next_idx);
+        }
+    }
+    reached
+}
+
+fn is_reached_near(world_map: &WorldMap, reached: &[bool], target: GridPos) -> bool {
+    std::iter::once((0, 0))
+        .chain(PATHFINDING_DIRECTIONS)
+        .filter_map(|(dx, dy)| world_map.pos_to_idx(target.0 + dx, target.1 + dy))
+        .any(|idx| reached[idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hw_core::constants::MAP_HEIGHT;
+
+    #[test]
+    fn wall_of_forbidden_tiles_cuts_off_work_on_the_far_side() {
+        let mut world_map = WorldMap::default();
+        let stockpiles = [(10, 10)];
+        let targets = [(60, 10), (50, 20), (20, 20)];
+
+        assert!(targets_cut_off_by_forbidden(&world_map, &stockpiles, &targets).is_empty());
+
+        world_map.add_forbidden_tiles((0..MAP_HEIGHT).map(|y| (50, y)));
+
+        assert_eq!(
+            targets_cut_off_by_forbidden(&world_map, &stockpiles, &targets),
+            vec![(60, 10)]
+        );
+    }
+}
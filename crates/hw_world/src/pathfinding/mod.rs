@@ -1,15 +1,19 @@
 mod budget;
 mod connectivity;
 mod core;
+mod forbidden;
 
 #[cfg(feature = "profiling")]
 pub use budget::RuntimePathSearchMetrics;
-pub use budget::{PathSearchCaller, PathSearchResult, RuntimePathSearchBudget};
+pub use budget::{
+    PATH_DENIAL_LOG_CAPACITY, PathSearchCaller, PathSearchResult, RuntimePathSearchBudget,
+};
 pub use connectivity::WalkabilityConnectivityCache;
 pub use core::{
     MOVE_COST_DIAGONAL, MOVE_COST_STRAIGHT, PathGoalPolicy, PathNode, PathWorld, PathfindingContext,
 };
 use core::{PathPolicy, can_cross_diagonal_move, find_path_with_policy, path_cost_heuristic};
+pub use forbidden::targets_cut_off_by_forbidden;
 
 use hw_core::GridPos;
 
@@ -75,11 +79,15 @@ pub fn find_path_with_budget(
         return PathSearchResult::Unreachable;
     }
     if !budget.try_claim_for(caller) {
+        budget.record_denied_goal(goal);
         return PathSearchResult::Deferred;
     }
 
     let result = find_path(world_map, context, start, goal, goal_policy)
         .map_or(PathSearchResult::Unreachable, PathSearchResult::Found);
+    if result == PathSearchResult::Unreachable {
+        budget.record_denied_goal(goal);
+    }
     #[cfg(feature = "profiling")]
     budget.record_expanded_nodes(context.expanded_nodes());
     result
@@ -132,11 +140,15 @@ pub fn find_path_to_adjacent_with_budget(
         return PathSearchResult::Unreachable;
     }
     if !budget.try_claim_for(caller) {
+        budget.record_denied_goal(target);
         return PathSearchResult::Deferred;
     }
 
     let result = find_path_to_adjacent(world_map, context, start, target, allow_goal_blocked)
         .map_or(PathSearchResult::Unreachable, PathSearchResult::Found);
+    if result == PathSearchResult::Unreachable {
+        budget.record_denied_goal(target);
+    }
     #[cfg(feature = "profiling")]
     budget.record_expanded_nodes(context.expanded_nodes());
     result
@@ -328,7 +340,7 @@ pub fn find_path_to_boundary_with_budget(
 /// A direct route and the adjacent-goal fallback each claim an independent
 /// core A* slot. Callers must preserve their state on `Deferred`.
 pub fn find_path_world_waypoints_with_budget(
-    world_map: &crate::map::WorldMap,
+    world_map: &impl PathWorld,
     pf_context: &mut PathfindingContext,
     budget: &mut RuntimePathSearchBudget,
     caller: PathSearchCaller,
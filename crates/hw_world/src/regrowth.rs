@@ -1,3 +1,5 @@
+use crate::map::WorldMap;
+use crate::pathfinding::PathWorld;
 use std::collections::HashSet;
 
 #[derive(Clone, Debug)]
@@ -132,13 +134,40 @@ pub fn default_forest_zones() -> Vec<ForestZone> {
     ]
 }
 
+/// 再生先の候補（初期配置位置）から、空いていて歩行可能な位置を選ぶ。
+///
+/// 禁止区域に塗られたタイルには再生させない。
 pub fn find_regrowth_position(
     zone: &ForestZone,
     occupied_positions: &HashSet<(i32, i32)>,
-    mut is_walkable: impl FnMut(i32, i32) -> bool,
+    world_map: &WorldMap,
 ) -> Option<(i32, i32)> {
+    let world = world_map.avoiding_forbidden();
     zone.tree_positions
         .iter()
         .copied()
-        .find(|&(x, y)| !occupied_positions.contains(&(x, y)) && is_walkable(x, y))
+        .find(|&(x, y)| !occupied_positions.contains(&(x, y)) && world.is_walkable(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regrowth_skips_occupied_and_forbidden_positions() {
+        let zone = ForestZone {
+            min: (0, 0),
+            max: (10, 10),
+            initial_count: 3,
+            tree_positions: vec![(1, 1), (2, 2), (3, 3)],
+        };
+        let mut world_map = WorldMap::default();
+        world_map.add_forbidden_tiles([(2, 2)]);
+        let occupied = HashSet::from([(1, 1)]);
+
+        assert_eq!(
+            find_regrowth_position(&zone, &occupied, &world_map),
+            Some((3, 3))
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::layout::{RIVER_X_MAX, RIVER_X_MIN, RIVER_Y_MAX, RIVER_Y_MIN};
+use crate::mapgen::template::MapTemplate;
 use crate::world_masks::BitGrid;
 use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
 use hw_core::world::GridPos;
@@ -9,10 +10,17 @@ use rand::seq::SliceRandom;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
+mod bodies;
 mod channel;
 mod legacy;
 mod sand;
 
+pub use bodies::{
+    BADLANDS_POND, LakeShape, MOAT_BRIDGE_COUNT_MAX, MOAT_BRIDGE_COUNT_MIN, MOAT_BRIDGE_HALF_WIDTH,
+    MOAT_INNER_DISTANCE, MOAT_WIDTH_MAX, MOAT_WIDTH_MIN, NORTH_LAKE, SOUTH_RIVER_START_Y_MAX,
+    SOUTH_RIVER_START_Y_MIN, SOUTH_RIVER_Y_CLAMP_MAX, SOUTH_RIVER_Y_CLAMP_MIN, generate_lake_mask,
+    generate_moat_mask, generate_south_river_mask, preview_lake_min_y,
+};
 pub use channel::{
     RIVER_MAX_WIDTH, RIVER_MIN_WIDTH, RIVER_START_Y_MAX, RIVER_START_Y_MIN,
     RIVER_TOTAL_TILES_TARGET_MAX, RIVER_TOTAL_TILES_TARGET_MIN, RIVER_Y_CLAMP_MAX,
@@ -30,5 +38,50 @@ pub use sand::{
 #[cfg(test)]
 use sand::CARDINAL_DIRS_4;
 
+/// テンプレートの水域をすべて `river_mask` として生成する。
+///
+/// 戻り値は `generate_river_mask` と同じ `(river_mask, river_centerline)`。
+/// 中心線は横断川の分だけを持ち、湖・池・堀では空。
+/// 湖・池はアンカー非依存で生成し、アンカーと保護帯に掛かるセルを取り除く。
+pub fn generate_template_water_mask(
+    seed: u64,
+    template: MapTemplate,
+    anchor_mask: &BitGrid,
+    river_protection_band: &BitGrid,
+) -> (BitGrid, Vec<GridPos>) {
+    match template {
+        MapTemplate::Classic => generate_river_mask(seed, anchor_mask, river_protection_band),
+        MapTemplate::TwinRivers => {
+            let (mut water, mut centerline) =
+                generate_river_mask(seed, anchor_mask, river_protection_band);
+            let (south, south_centerline) =
+                generate_south_river_mask(seed, anchor_mask, river_protection_band);
+            water |= &south;
+            centerline.extend(south_centerline);
+            (water, centerline)
+        }
+        MapTemplate::NorthLake | MapTemplate::Badlands => {
+            let shape = if template == MapTemplate::NorthLake {
+                NORTH_LAKE
+            } else {
+                BADLANDS_POND
+            };
+            let mut lake = generate_lake_mask(seed, shape);
+            for y in 0..MAP_HEIGHT {
+                for x in 0..MAP_WIDTH {
+                    if anchor_mask.get((x, y)) || river_protection_band.get((x, y)) {
+                        lake.set((x, y), false);
+                    }
+                }
+            }
+            (lake, Vec::new())
+        }
+        MapTemplate::IslandSite => (
+            generate_moat_mask(seed, anchor_mask, river_protection_band),
+            Vec::new(),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests;
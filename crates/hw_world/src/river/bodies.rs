@@ -0,0 +1,214 @@
+use super::channel::{RiverRows, carve_river};
+use super::sand::CARDINAL_DIRS_4;
+use super::*;
+
+// ── 南側の川（TwinRivers）────────────────────────────────────────────────────
+// 岩場の下側クラスター（`ROCK_FIELD_TOP_Y_MIN..`）より南に収め、
+// Site/Yard と同じ陸地に岩場を残す。
+/// 南側の川の開始 y の範囲
+pub const SOUTH_RIVER_START_Y_MIN: i32 = 6;
+pub const SOUTH_RIVER_START_Y_MAX: i32 = 9;
+/// 南側の川の蛇行 clamp 範囲
+pub const SOUTH_RIVER_Y_CLAMP_MIN: i32 = 3;
+pub const SOUTH_RIVER_Y_CLAMP_MAX: i32 = 11;
+const SOUTH_RIVER_SEED_SALT: u64 = 0x2545_f491_4f6c_dd1d;
+
+// ── 湖・池（NorthLake / Badlands）──────────────────────────────────────────────
+/// 湖岸の凹凸の振幅（半径に対する比）
+const LAKE_SHORE_WOBBLE: f32 = 0.15;
+/// 湖岸の凹凸の山数の範囲
+const LAKE_SHORE_LOBES_MIN: i32 = 2;
+const LAKE_SHORE_LOBES_MAX: i32 = 4;
+
+/// 楕円ベースの湖の生成範囲。各範囲は両端 inclusive。
+#[derive(Debug, Clone, Copy)]
+pub struct LakeShape {
+    pub center_x: (i32, i32),
+    pub center_y: (i32, i32),
+    pub radius_x: (i32, i32),
+    pub radius_y: (i32, i32),
+    /// river RNG と区別するための seed XOR マスク
+    pub salt: u64,
+}
+
+/// NorthLake の湖。中心はマップ北側、南岸の下に Site/Yard を置く。
+pub const NORTH_LAKE: LakeShape = LakeShape {
+    center_x: (32, 68),
+    center_y: (78, 86),
+    radius_x: (11, 15),
+    radius_y: (6, 9),
+    salt: 0x6a09_e667_f3bc_c908,
+};
+
+/// Badlands の池。唯一の水源なので小さく、Site/Yard の北に置く。
+pub const BADLANDS_POND: LakeShape = LakeShape {
+    center_x: (22, 78),
+    center_y: (74, 84),
+    radius_x: (3, 5),
+    radius_y: (3, 4),
+    salt: 0xbb67_ae85_84ca_a73b,
+};
+
+// ── 堀（IslandSite）────────────────────────────────────────────────────────────
+/// アンカー外接矩形から堀の内縁までの距離（保護帯より外）
+pub const MOAT_INNER_DISTANCE: i32 = 6;
+/// 堀の幅の範囲
+pub const MOAT_WIDTH_MIN: i32 = 3;
+pub const MOAT_WIDTH_MAX: i32 = 4;
+/// 陸橋の本数の範囲（西・南・北の辺から重複なく選ぶ）
+pub const MOAT_BRIDGE_COUNT_MIN: usize = 2;
+pub const MOAT_BRIDGE_COUNT_MAX: usize = 3;
+/// 陸橋の半幅（幅 = 2 * 半幅 + 1）
+pub const MOAT_BRIDGE_HALF_WIDTH: i32 = 1;
+const MOAT_SEED_SALT: u64 = 0x3c6e_f372_fe94_f82c;
+
+/// マップ南端寄りに、北側の川と同じアルゴリズムで 2 本目の横断川を生成する。
+pub fn generate_south_river_mask(
+    seed: u64,
+    anchor_mask: &BitGrid,
+    river_protection_band: &BitGrid,
+) -> (BitGrid, Vec<GridPos>) {
+    let rows = RiverRows {
+        start_min: SOUTH_RIVER_START_Y_MIN,
+        start_max: SOUTH_RIVER_START_Y_MAX,
+        clamp_min: SOUTH_RIVER_Y_CLAMP_MIN,
+        clamp_max: SOUTH_RIVER_Y_CLAMP_MAX,
+    };
+    carve_river(
+        seed ^ SOUTH_RIVER_SEED_SALT,
+        rows,
+        anchor_mask,
+        river_protection_band,
+    )
+}
+
+/// アンカーに依存しない湖マスクを生成する。
+///
+/// 楕円に角度方向の凹凸を重ねた星形なので中心から連結している。
+/// 4 近傍に水のない孤立セルは取り除く。
+pub fn generate_lake_mask(seed: u64, shape: LakeShape) -> BitGrid {
+    let mut rng = StdRng::seed_from_u64(seed ^ shape.salt);
+    let cx = rng.gen_range(shape.center_x.0..=shape.center_x.1);
+    let cy = rng.gen_range(shape.center_y.0..=shape.center_y.1);
+    let rx = rng.gen_range(shape.radius_x.0..=shape.radius_x.1) as f32;
+    let ry = rng.gen_range(shape.radius_y.0..=shape.radius_y.1) as f32;
+    let lobes = rng.gen_range(LAKE_SHORE_LOBES_MIN..=LAKE_SHORE_LOBES_MAX) as f32;
+    let phase = rng.gen_range(0.0..std::f32::consts::TAU);
+
+    let mut lake = BitGrid::map_sized();
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            let dx = (x - cx) as f32 / rx;
+            let dy = (y - cy) as f32 / ry;
+            let limit = 1.0 + LAKE_SHORE_WOBBLE * (lobes * dy.atan2(dx) + phase).sin();
+            if dx.hypot(dy) <= limit {
+                lake.set((x, y), true);
+            }
+        }
+    }
+    drop_isolated_cells(&mut lake);
+    lake
+}
+
+/// 湖の最小 y（南岸）。アンカーの縦位置合わせに使う。
+pub fn preview_lake_min_y(seed: u64, shape: LakeShape) -> i32 {
+    let lake = generate_lake_mask(seed, shape);
+    mask_bounds(&lake).map_or(RIVER_Y_CLAMP_MIN, |(_, min_y, _, _)| min_y)
+}
+
+/// アンカー外接矩形を角丸矩形状に囲む堀を生成し、西・南・北の辺に陸橋を開ける。
+///
+/// 東辺は Yard がマップ端に近く外側の陸地が細いため陸橋を置かない。
+pub fn generate_moat_mask(
+    seed: u64,
+    anchor_mask: &BitGrid,
+    river_protection_band: &BitGrid,
+) -> BitGrid {
+    let mut moat = BitGrid::map_sized();
+    let Some((min_x, min_y, max_x, max_y)) = mask_bounds(anchor_mask) else {
+        return moat;
+    };
+    let mut rng = StdRng::seed_from_u64(seed ^ MOAT_SEED_SALT);
+    let width = rng.gen_range(MOAT_WIDTH_MIN..=MOAT_WIDTH_MAX);
+    let inner = MOAT_INNER_DISTANCE as f32;
+    let outer = (MOAT_INNER_DISTANCE + width) as f32;
+
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            let dx = (min_x - x).max(x - max_x).max(0) as f32;
+            let dy = (min_y - y).max(y - max_y).max(0) as f32;
+            let d = dx.hypot(dy);
+            let pos = (x, y);
+            if (inner..outer).contains(&d)
+                && !anchor_mask.get(pos)
+                && !river_protection_band.get(pos)
+            {
+                moat.set(pos, true);
+            }
+        }
+    }
+
+    let mut sides = [MoatSide::West, MoatSide::South, MoatSide::North];
+    sides.shuffle(&mut rng);
+    let bridge_count = rng.gen_range(MOAT_BRIDGE_COUNT_MIN..=MOAT_BRIDGE_COUNT_MAX);
+    for side in &sides[..bridge_count] {
+        let (along_min, along_max) = match side {
+            MoatSide::West => (min_y, max_y),
+            MoatSide::South | MoatSide::North => (min_x, max_x),
+        };
+        let at =
+            rng.gen_range(along_min + MOAT_BRIDGE_HALF_WIDTH..=along_max - MOAT_BRIDGE_HALF_WIDTH);
+        for y in 0..MAP_HEIGHT {
+            for x in 0..MAP_WIDTH {
+                let on_bridge = match side {
+                    MoatSide::West => x < min_x && (y - at).abs() <= MOAT_BRIDGE_HALF_WIDTH,
+                    MoatSide::South => y < min_y && (x - at).abs() <= MOAT_BRIDGE_HALF_WIDTH,
+                    MoatSide::North => y > max_y && (x - at).abs() <= MOAT_BRIDGE_HALF_WIDTH,
+                };
+                if on_bridge {
+                    moat.set((x, y), false);
+                }
+            }
+        }
+    }
+    moat
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MoatSide {
+    West,
+    South,
+    North,
+}
+
+/// mask の true セルの外接矩形 `(min_x, min_y, max_x, max_y)`。空なら `None`。
+fn mask_bounds(mask: &BitGrid) -> Option<(i32, i32, i32, i32)> {
+    let mut bounds: Option<(i32, i32, i32, i32)> = None;
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            if !mask.get((x, y)) {
+                continue;
+            }
+            bounds = Some(match bounds {
+                None => (x, y, x, y),
+                Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            });
+        }
+    }
+    bounds
+}
+
+fn drop_isolated_cells(mask: &mut BitGrid) {
+    let isolated: Vec<GridPos> = (0..MAP_HEIGHT)
+        .flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            mask.get((x, y))
+                && !CARDINAL_DIRS_4
+                    .iter()
+                    .any(|&(dx, dy)| mask.get((x + dx, y + dy)))
+        })
+        .collect();
+    for pos in isolated {
+        mask.set(pos, false);
+    }
+}
@@ -36,11 +36,36 @@ pub fn generate_river_mask(
     seed: u64,
     anchor_mask: &BitGrid,
     river_protection_band: &BitGrid,
+) -> (BitGrid, Vec<GridPos>) {
+    let rows = RiverRows {
+        start_min: RIVER_START_Y_MIN,
+        start_max: RIVER_START_Y_MAX,
+        clamp_min: RIVER_Y_CLAMP_MIN,
+        clamp_max: RIVER_Y_CLAMP_MAX,
+    };
+    carve_river(seed, rows, anchor_mask, river_protection_band)
+}
+
+/// 横断川の開始 y 範囲と蛇行 clamp 範囲。
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RiverRows {
+    pub start_min: i32,
+    pub start_max: i32,
+    pub clamp_min: i32,
+    pub clamp_max: i32,
+}
+
+/// `rows` の範囲で左端→右端の横断川を 1 本生成する（`generate_river_mask` の本体）。
+pub(super) fn carve_river(
+    seed: u64,
+    rows: RiverRows,
+    anchor_mask: &BitGrid,
+    river_protection_band: &BitGrid,
 ) -> (BitGrid, Vec<GridPos>) {
     let mut rng = StdRng::seed_from_u64(seed);
     let map_width = MAP_WIDTH as usize;
 
-    let start_y = rng.gen_range(RIVER_START_Y_MIN..=RIVER_START_Y_MAX);
+    let start_y = rng.gen_range(rows.start_min..=rows.start_max);
     let mut current_y = start_y;
 
     // 蛇行バイアス: -1 が 2/7, 0 が 3/7, +1 が 2/7（期待値 0、標準偏差 ≈ 0.93）
@@ -52,7 +77,7 @@ pub fn generate_river_mask(
 
     for x in 0..MAP_WIDTH {
         let step = *steps.choose(&mut rng).unwrap();
-        let mut next_y = (current_y + step).clamp(RIVER_Y_CLAMP_MIN, RIVER_Y_CLAMP_MAX);
+        let mut next_y = (current_y + step).clamp(rows.clamp_min, rows.clamp_max);
 
         // next_y が禁止セルなら直進（current_y を維持）
         if river_protection_band.get((x, next_y)) || anchor_mask.get((x, next_y)) {
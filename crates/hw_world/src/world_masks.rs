@@ -3,6 +3,8 @@ use std::collections::VecDeque;
 use hw_core::constants::{MAP_HEIGHT, MAP_WIDTH};
 use hw_core::world::GridPos;
 
+use crate::mapgen::template::MapTemplate;
+
 // ── Protection band widths (wfc-ms0 §3.1) ────────────────────────────────────
 /// アンカー外周の River 禁止帯幅（4 近傍 BFS 距離）
 pub const PROTECTION_BAND_RIVER_WIDTH: u32 = 3;
@@ -83,10 +85,14 @@ pub struct WorldMasks {
     pub rock_protection_band: BitGrid,
     /// anchor 外周の高密度木禁止帯（wfc-ms0 §3.1: PROTECTION_BAND_TREE_DENSE_WIDTH）
     pub tree_dense_protection_band: BitGrid,
-    /// 川タイル（WFC hard constraint として渡す）
+    /// 川タイル（WFC hard constraint として渡す）。テンプレートの湖・堀・池もここに載る
     pub river_mask: BitGrid,
-    /// 川の中心線点列（デバッグ表示・砂配置計算に使う）
+    /// 川の中心線点列（デバッグ表示・砂配置計算に使う）。湖・堀・池の分は含まない
     pub river_centerline: Vec<GridPos>,
+    /// 水域を渡らずにアンカーへ 4 近傍でつながらない陸地。
+    /// `MapTemplate::keeps_resources_on_anchor_land` のテンプレートでだけ埋め、
+    /// ゾーン・岩場の生成から除外する
+    pub isolated_land_mask: BitGrid,
     /// distance-field + growth を合成した「砂にしてよい元候補」
     /// （dist 1..=2 の base shoreline + dist==1 frontier からの bounded growth）
     pub sand_candidate_mask: BitGrid,
@@ -145,6 +151,7 @@ impl WorldMasks {
             ),
             river_mask: BitGrid::map_sized(), // fill_river_from_seed で設定
             river_centerline: Vec::new(),     // fill_river_from_seed で設定
+            isolated_land_mask: BitGrid::map_sized(), // fill_water_for_template で設定
             sand_candidate_mask: BitGrid::map_sized(), // fill_sand_from_river_seed で設定
             sand_carve_mask: BitGrid::map_sized(), // fill_sand_from_river_seed で設定
             final_sand_mask: BitGrid::map_sized(), // fill_sand_from_river_seed で設定
@@ -173,6 +180,30 @@ impl WorldMasks {
         self.river_centerline = centerline;
     }
 
+    /// `fill_river_from_seed` のテンプレート版。水域を `river_mask` に設定し、
+    /// 必要なテンプレートでは `isolated_land_mask` も埋める。
+    ///
+    /// `MapTemplate::Classic` では `fill_river_from_seed` と同じ結果になる。
+    pub fn fill_water_for_template(&mut self, seed: u64, template: MapTemplate) {
+        debug_assert!(
+            self.anchor_mask.count_set() > 0,
+            "fill_water_for_template は from_anchor の後に呼ぶこと"
+        );
+        let (river_mask, centerline) = crate::river::generate_template_water_mask(
+            seed,
+            template,
+            &self.anchor_mask,
+            &self.river_protection_band,
+        );
+        self.river_mask = river_mask;
+        self.river_centerline = centerline;
+        self.isolated_land_mask = if template.keeps_resources_on_anchor_land() {
+            compute_isolated_land(&self.anchor_mask, &self.river_mask)
+        } else {
+            BitGrid::map_sized()
+        };
+    }
+
     /// `fill_river_from_seed()` 適用済みの `river_mask` を参照し、
     /// seed から deterministic に 3 つの砂マスクを生成して設定する。
     ///
@@ -205,10 +236,11 @@ impl WorldMasks {
             self.final_sand_mask.count_set() > 0,
             "fill_terrain_zones_from_seed は fill_sand_from_river_seed の後に呼ぶこと（final_sand_mask 非空を期待）"
         );
+        let blocked = self.water_or_isolated_mask();
         let (grass, dirt, inland_sand) = crate::terrain_zones::generate_terrain_zone_masks(
             seed,
             &self.anchor_mask,
-            &self.river_mask,
+            &blocked,
             &self.river_protection_band,
             &self.final_sand_mask,
         );
@@ -224,16 +256,24 @@ impl WorldMasks {
     /// `fill_terrain_zones_from_seed()` 適用済みの inland_sand を含むマスク群を参照し、
     /// seed から deterministic に岩場マスクを生成して設定する。
     pub fn fill_rock_fields_from_seed(&mut self, seed: u64) {
+        let blocked = self.water_or_isolated_mask();
         self.rock_field_mask = crate::rock_fields::generate_rock_field_mask(
             seed,
             &self.anchor_mask,
             &self.rock_protection_band,
-            &self.river_mask,
+            &blocked,
             &self.final_sand_mask,
             &self.inland_sand_mask,
         );
     }
 
+    /// ゾーン・岩場を置かないセル（`river_mask | isolated_land_mask`）。
+    fn water_or_isolated_mask(&self) -> BitGrid {
+        let mut blocked = self.river_mask.clone();
+        blocked |= &self.isolated_land_mask;
+        blocked
+    }
+
     /// debug report 用の合成保護帯。
     /// wfc-ms0 でいう `protection_band` はこの合成結果に相当する。
     pub fn combined_protection_band(&self) -> BitGrid {
@@ -244,6 +284,40 @@ impl WorldMasks {
     }
 }
 
+/// `water_mask` を通らずに `anchor_mask` から 4 近傍で到達できない陸地セルを返す。
+fn compute_isolated_land(anchor_mask: &BitGrid, water_mask: &BitGrid) -> BitGrid {
+    const DIRS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+    let mut reached = anchor_mask.clone();
+    let mut queue: VecDeque<GridPos> = (0..MAP_HEIGHT)
+        .flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y)))
+        .filter(|&pos| anchor_mask.get(pos))
+        .collect();
+    while let Some((x, y)) = queue.pop_front() {
+        for (dx, dy) in DIRS {
+            let next = (x + dx, y + dy);
+            if !(0..MAP_WIDTH).contains(&next.0) || !(0..MAP_HEIGHT).contains(&next.1) {
+                continue;
+            }
+            if reached.get(next) || water_mask.get(next) {
+                continue;
+            }
+            reached.set(next, true);
+            queue.push_back(next);
+        }
+    }
+
+    let mut isolated = BitGrid::map_sized();
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            if !reached.get((x, y)) && !water_mask.get((x, y)) {
+                isolated.set((x, y), true);
+            }
+        }
+    }
+    isolated
+}
+
 /// anchor_mask の外周から 4 近傍 BFS で距離変換し、
 /// 距離 1..=width のセルを true にした BitGrid を返す。
 ///
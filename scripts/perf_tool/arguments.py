@@ -3,7 +3,7 @@ from __future__ import annotations
 from .model import *
 
 def add_run_arguments(parser: argparse.ArgumentParser, *, fixed_step_audit: bool = False) -> None:
-    parser.add_argument("--workload", default="gather", choices=["gather", "path-door", "construction", "ui-gpu"])
+    parser.add_argument("--workload", default="gather", choices=["gather", "path-door", "construction", "ui-gpu", "logistics", "save-load", "mapgen"],)
     parser.add_argument("--sizes", default="medium", help="comma-separated: small,medium,large")
     parser.add_argument("--renders", default="cpu", help="comma-separated: cpu,gpu")
     parser.add_argument("--seed", type=int, default=DEFAULT_SEED)
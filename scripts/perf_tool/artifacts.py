@@ -296,6 +296,10 @@ def validate_run(
             reasons.append("summary samples is invalid")
         if samples is not None and samples <= 0:
             reasons.append("summary samples must be greater than zero")
+        if summary.get("invariant_violations", "0") != "0":
+            reasons.append(
+                f"summary invariant_violations is {summary.get('invariant_violations')!r}, expected '0'"
+            )
         reasons.extend(read_frames(data_dir / "frames.csv", samples))
 
     if summary is not None and scene_roots is not None:
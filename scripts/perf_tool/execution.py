@@ -236,7 +236,10 @@ def run_one(
         expected_case=case,
         expected_adapter=args.adapter,
         expected_backend=args.backend,
-        allow_log_patterns=args.allow_log_pattern,
+        allow_log_patterns=[
+            *args.allow_log_pattern,
+            *WORKLOAD_ALLOWED_LOG_PATTERNS.get(case.workload, ()),
+        ],
         capture_kind=args.capture_kind,
         expected_warmup_secs=getattr(args, "warmup_secs", None),
         expected_measure_secs=getattr(args, "measure_secs", None),
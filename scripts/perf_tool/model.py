@@ -29,7 +29,7 @@ from typing import Any, Iterable
 SCRIPT_DIR = Path(__file__).resolve().parent.parent
 REPO_ROOT = SCRIPT_DIR.parent
 PERF_DESCRIPTION = __doc__
-SUMMARY_SCHEMA_VERSION = "10"
+SUMMARY_SCHEMA_VERSION = "12"
 DETERMINISM_SCHEMA_VERSION = "1"
 DEFAULT_SEED = 20_260_712
 SCENE_ROOT_COLUMNS = (
@@ -121,12 +121,33 @@ EXPECTED_SUMMARY_COLUMNS = {
     "energy_grid_recalc_runs",
     "energy_lamp_steps",
     "energy_lamp_candidates_scanned",
+    "invariant_checks",
+    "invariant_violations",
+    "save_load_saves",
+    "save_load_save_elapsed_micros",
+    "save_load_save_max_elapsed_micros",
+    "save_load_save_bytes",
+    "save_load_loads",
+    "save_load_load_elapsed_micros",
+    "save_load_load_max_elapsed_micros",
+    "save_load_rehydrate_elapsed_micros",
+    "mapgen_layouts",
+    "mapgen_elapsed_micros",
+    "mapgen_max_elapsed_micros",
+    "mapgen_retries",
+    "mapgen_fallbacks",
+    "peak_rss_kib",
 }
 ADAPTER_RE = re.compile(
     r'AdapterInfo \{ name: "(?P<name>[^"]+)".*?driver: "(?P<driver>[^"]*)", '
     r'driver_info: "(?P<driver_info>[^"]*)", backend: (?P<backend>[A-Za-z0-9_]+)'
 )
 LOG_LEVEL_RE = re.compile(r"\b(?:WARN|ERROR)\b|bevy_ecs::error::handler")
+# workload 自体が意図的に引き起こす既知の WARN。save-load は大きなワールドを
+# 繰り返し保存するため、100ms 超過の警告は計測対象であって異常ではない。
+WORKLOAD_ALLOWED_LOG_PATTERNS = {
+    "save-load": (r"Save took .* \(>100ms, \d+ bytes\)",),
+}
 CHECKSUM_POLICY_REASON_PREFIXES = (
     "initial_state_checksum differs across repeated runs:",
     "warmup_state_checksum differs across repeated runs:",
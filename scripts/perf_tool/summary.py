@@ -228,6 +228,46 @@ def summarize_session(
         "energy_lamp_steps_mad",
         "energy_lamp_candidates_scanned_median",
         "energy_lamp_candidates_scanned_mad",
+        "invariant_checks_median",
+        "invariant_checks_mad",
+        "save_load_saves_median",
+        "save_load_saves_mad",
+        "save_load_save_elapsed_micros_median",
+        "save_load_save_elapsed_micros_mad",
+        "save_load_save_max_elapsed_micros_median",
+        "save_load_save_max_elapsed_micros_mad",
+        "save_load_save_bytes_median",
+        "save_load_save_bytes_mad",
+        "save_load_loads_median",
+        "save_load_loads_mad",
+        "save_load_load_elapsed_micros_median",
+        "save_load_load_elapsed_micros_mad",
+        "save_load_load_max_elapsed_micros_median",
+        "save_load_load_max_elapsed_micros_mad",
+        "save_load_rehydrate_elapsed_micros_median",
+        "save_load_rehydrate_elapsed_micros_mad",
+        "mapgen_layouts_median",
+        "mapgen_layouts_mad",
+        "mapgen_elapsed_micros_median",
+        "mapgen_elapsed_micros_mad",
+        "mapgen_max_elapsed_micros_median",
+        "mapgen_max_elapsed_micros_mad",
+        "mapgen_retries_median",
+        "mapgen_retries_mad",
+        "mapgen_fallbacks_median",
+        "mapgen_fallbacks_mad",
+        "peak_rss_kib_median",
+        "peak_rss_kib_mad",
+        "save_load_save_mean_micros_median",
+        "save_load_save_mean_micros_mad",
+        "save_load_load_mean_micros_median",
+        "save_load_load_mean_micros_mad",
+        "mapgen_mean_micros_median",
+        "mapgen_mean_micros_mad",
+        "mapgen_retries_per_layout_median",
+        "mapgen_retries_per_layout_mad",
+        "mapgen_fallback_pct_median",
+        "mapgen_fallback_pct_mad",
         "adapter",
     ]
     aggregate_rows: list[dict[str, str]] = []
@@ -283,6 +323,21 @@ def summarize_session(
             "energy_grid_recalc_runs",
             "energy_lamp_steps",
             "energy_lamp_candidates_scanned",
+            "invariant_checks",
+            "save_load_saves",
+            "save_load_save_elapsed_micros",
+            "save_load_save_max_elapsed_micros",
+            "save_load_save_bytes",
+            "save_load_loads",
+            "save_load_load_elapsed_micros",
+            "save_load_load_max_elapsed_micros",
+            "save_load_rehydrate_elapsed_micros",
+            "mapgen_layouts",
+            "mapgen_elapsed_micros",
+            "mapgen_max_elapsed_micros",
+            "mapgen_retries",
+            "mapgen_fallbacks",
+            "peak_rss_kib",
         ):
             # schema v3 以前の既存baselineはreservation counterを持たない。
             # frame-time aggregateの再集約・比較は維持し、存在しないcounterを
@@ -339,6 +394,28 @@ def summarize_session(
                 median, mad = median_and_mad(values)
                 row[f"{ratio}_median"] = f"{median:.6f}"
                 row[f"{ratio}_mad"] = f"{mad:.6f}"
+        # save-load / mapgen 以外の workload では分母が 0 になるため、比率列は空のまま残す。
+        per_operation_ratios = {
+            "save_load_save_mean_micros": ("save_load_save_elapsed_micros", "save_load_saves", 1.0),
+            "save_load_load_mean_micros": ("save_load_load_elapsed_micros", "save_load_loads", 1.0),
+            "mapgen_mean_micros": ("mapgen_elapsed_micros", "mapgen_layouts", 1.0),
+            "mapgen_retries_per_layout": ("mapgen_retries", "mapgen_layouts", 1.0),
+            "mapgen_fallback_pct": ("mapgen_fallbacks", "mapgen_layouts", 100.0),
+        }
+        for ratio, (numerator, denominator, scale) in per_operation_ratios.items():
+            if not all(
+                denominator in work_counter_values and float(validation.summary[denominator]) > 0
+                for validation in validations
+            ):
+                continue
+            median, mad = median_and_mad(
+                [
+                    scale * float(validation.summary[numerator]) / float(validation.summary[denominator])
+                    for validation in validations
+                ]
+            )
+            row[f"{ratio}_median"] = f"{median:.6f}"
+            row[f"{ratio}_mad"] = f"{mad:.6f}"
         aggregate_rows.append(row)
 
     with (session_dir / "aggregate.csv").open("w", newline="", encoding="utf-8") as handle: